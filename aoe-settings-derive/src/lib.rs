@@ -22,8 +22,11 @@
 //! `min`, `max`, `step`, `multiline`, `mono`, `options` ("v:Label,v2:Label2"),
 //! `web` ("allow" | "elevation:reason" | "local_only:reason"),
 //! `validate` ("none" | "range:min[:max]" | "nonempty" | "memory_limit" |
-//!   "volume_list" | "env_list" | "port_mapping_list"),
+//!   "cpu_limit" | "volume_list" | "env_list" | "port_mapping_list" |
+//!   "config_sync_exclude_list" | "launch_wrapper" | "timezone" |
+//!   "datetime_format"),
 //! `global_only` (flag: field is shown but not profile-overridable),
+//! `no_repo` (flag: profile-overridable, but a repo config may not set it),
 //! `skip` (flag: exclude the field from the schema entirely).
 //! When `desc` is omitted, the field's doc comment is used.
 
@@ -81,6 +84,7 @@ fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         let web = build_web(field, &attrs)?;
         let validation = build_validation(field, &attrs)?;
         let overridable = !attrs.global_only;
+        let repo_overridable = overridable && !attrs.no_repo;
         let advanced = attrs.advanced;
         let label = attrs.label.unwrap_or_else(|| humanize(&field_name));
         let description = attrs
@@ -99,6 +103,7 @@ fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 widget: #widget,
                 web_write: #web,
                 profile_overridable: #overridable,
+                repo_overridable: #repo_overridable,
                 validation: #validation,
                 advanced: #advanced,
             }
@@ -151,6 +156,7 @@ fn parse_section_meta(input: &DeriveInput) -> syn::Result<SectionMeta> {
 struct FieldAttrs {
     skip: bool,
     global_only: bool,
+    no_repo: bool,
     advanced: bool,
     label: Option<String>,
     desc: Option<String>,
@@ -183,6 +189,7 @@ fn parse_field_attrs(field: &syn::Field, field_name: &str) -> syn::Result<FieldA
             match key.as_str() {
                 "skip" => out.skip = true,
                 "global_only" => out.global_only = true,
+                "no_repo" => out.no_repo = true,
                 "advanced" => out.advanced = true,
                 "multiline" => out.multiline = true,
                 "mono" => out.mono = true,
@@ -301,9 +308,14 @@ fn build_validation(
         "none" => quote!(ValidationKind::None),
         "nonempty" => quote!(ValidationKind::NonEmptyString),
         "memory_limit" => quote!(ValidationKind::MemoryLimit),
+        "cpu_limit" => quote!(ValidationKind::CpuLimit),
         "volume_list" => quote!(ValidationKind::VolumeList),
         "env_list" => quote!(ValidationKind::EnvList),
         "port_mapping_list" => quote!(ValidationKind::PortMappingList),
+        "config_sync_exclude_list" => quote!(ValidationKind::ConfigSyncExcludeList),
+        "launch_wrapper" => quote!(ValidationKind::LaunchWrapper),
+        "timezone" => quote!(ValidationKind::Timezone),
+        "datetime_format" => quote!(ValidationKind::DatetimeFormat),
         range if range.starts_with("range:") => {
             let parts: Vec<&str> = range.trim_start_matches("range:").split(':').collect();
             if parts.is_empty() || parts.len() > 2 {
@@ -6514,6 +6514,8 @@ mod tests {
             container_name: "aoe-sandbox-abc12345".into(),
             extra_env: Some(vec!["MY_LITERAL=hello".into()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let config = SpawnConfig {
             agent_key: "claude".into(),
@@ -6580,6 +6582,8 @@ mod tests {
             container_name: "aoe-sandbox-abc12345".into(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let config = SpawnConfig {
             agent_key: "claude".into(),
@@ -6650,6 +6654,8 @@ mod tests {
             container_name: "aoe-sandbox-cfgdir".into(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let config = SpawnConfig {
             agent_key: "claude".into(),
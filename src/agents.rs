@@ -98,6 +98,24 @@ pub struct SidecarHooks {
     pub post_install_host: Option<fn()>,
 }
 
+/// Regex patterns matched against the recent pane lines after the agent's
+/// `detect_status` heuristic runs (see
+/// [`status_detection::apply_status_patterns`]).
+#[derive(Debug, Clone, Copy)]
+pub struct StatusPatterns {
+    /// A prompt that blocks on the user; a match reports `Waiting`.
+    pub waiting: Option<&'static str>,
+    /// An error the agent printed before going quiet; a match reports `Error`.
+    pub error: Option<&'static str>,
+}
+
+impl StatusPatterns {
+    pub const NONE: Self = Self {
+        waiting: None,
+        error: None,
+    };
+}
+
 /// Everything we know about a single agent CLI.
 pub struct AgentDef {
     /// Canonical name: `"claude"`, `"opencode"`, etc.
@@ -117,6 +135,10 @@ pub struct AgentDef {
     pub set_default_command: bool,
     /// Status detection function pointer. Takes raw (non-lowercased) pane content.
     pub detect_status: fn(&str) -> Status,
+    /// Built-in regexes layered on top of `detect_status`. Users can replace
+    /// them per agent via `session.agent_waiting_patterns` /
+    /// `session.agent_error_patterns`.
+    pub status_patterns: StatusPatterns,
     /// Environment variables always injected into the container for this agent.
     pub container_env: &'static [(&'static str, &'static str)],
     /// Hook configuration for file-based status detection. If set, AoE installs
@@ -312,6 +334,12 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: Some("--append-system-prompt {}"),
         set_default_command: false,
         detect_status: status_detection::detect_claude_status,
+        status_patterns: StatusPatterns {
+            // First-launch folder trust dialog, which the approval-prompt
+            // heuristic does not cover.
+            waiting: Some(r"(?i)do you trust the files in this folder\?|yes, i trust this folder"),
+            error: Some(r"(?m)^\s*⎿\s+API Error"),
+        },
         container_env: &[("CLAUDE_CONFIG_DIR", "/root/.claude")],
         hook_config: Some(AgentHookConfig {
             settings_rel_path: ".claude/settings.json",
@@ -336,6 +364,10 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: true,
         detect_status: status_detection::detect_opencode_status,
+        status_patterns: StatusPatterns {
+            waiting: Some(r"(?i)permission required"),
+            error: Some(r"AI_APICallError|ProviderAuthError|ProviderModelNotFoundError"),
+        },
         container_env: &[],
         hook_config: None,
         sidecar_hooks: None,
@@ -353,6 +385,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_vibe_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         hook_config: None,
         sidecar_hooks: None,
@@ -372,6 +405,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: Some("--config developer_instructions={}"),
         set_default_command: true,
         detect_status: status_detection::detect_codex_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         hook_config: Some(AgentHookConfig {
             settings_rel_path: ".codex/config.toml",
@@ -398,6 +432,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_gemini_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         hook_config: Some(AgentHookConfig {
             settings_rel_path: ".gemini/settings.json",
@@ -444,6 +479,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_cursor_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[("CURSOR_CONFIG_DIR", "/root/.cursor")],
         hook_config: Some(AgentHookConfig {
             settings_rel_path: ".cursor/settings.json",
@@ -465,6 +501,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_copilot_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[("COPILOT_CONFIG_DIR", "/root/.copilot")],
         hook_config: None,
         sidecar_hooks: None,
@@ -483,6 +520,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_pi_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[("PI_CODING_AGENT_DIR", "/root/.pi/agent")],
         hook_config: None,
         sidecar_hooks: None,
@@ -500,6 +538,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_droid_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         hook_config: None,
         sidecar_hooks: None,
@@ -517,6 +556,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_settl_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         // settl uses TOML config (`[[hooks]]` entries), not the JSON
         // settings.json schema, so it installs via a sidecar hook. host_only,
@@ -546,6 +586,7 @@ pub const AGENTS: &[AgentDef] = &[
         // installed by hooks::install_hermes_hooks(); the stub here just
         // returns Idle as a fallback before the first hook fires.
         detect_status: status_detection::detect_hermes_status,
+        status_patterns: StatusPatterns::NONE,
         // HERMES_ACCEPT_HOOKS bypasses the first-use TTY consent prompt for
         // shell hooks. Hermes still gates each (event, command) on its
         // allowlist file, which AoE pre-populates in install_hermes_hooks.
@@ -576,6 +617,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_kiro_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[("KIRO_CONFIG_DIR", "/root/.kiro")],
         // Kiro uses a per-agent JSON config (lowercase event names, flat
         // {command} objects) rather than the JSON settings.json schema shared
@@ -605,6 +647,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: Some("--append-system-prompt {}"),
         set_default_command: false,
         detect_status: status_detection::detect_qwen_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         hook_config: Some(AgentHookConfig {
             settings_rel_path: ".qwen/settings.json",
@@ -629,6 +672,7 @@ pub const AGENTS: &[AgentDef] = &[
         instruction_flag: None,
         set_default_command: false,
         detect_status: status_detection::detect_antigravity_status,
+        status_patterns: StatusPatterns::NONE,
         container_env: &[],
         hook_config: None,
         sidecar_hooks: None,
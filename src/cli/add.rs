@@ -6,6 +6,9 @@ use std::io::IsTerminal;
 use std::path::PathBuf;
 
 use crate::containers::{self, ContainerRuntimeInterface};
+use crate::git::error::GitError;
+use crate::git::OnExists;
+use crate::session::activity::{self, ActivityKind};
 use crate::session::builder;
 use crate::session::repo_config;
 use crate::session::{civilizations, GroupTree, Instance, SandboxInfo, Storage};
@@ -61,6 +64,12 @@ pub struct AddArgs {
     #[arg(long = "base-branch")]
     base_branch: Option<String>,
 
+    /// What to do when the worktree path already exists: fail (`error`),
+    /// reuse it if it is a worktree of this repo on the same branch
+    /// (`adopt`), or force-remove and recreate it (`recreate`)
+    #[arg(long = "on-exists", value_enum, default_value_t = OnExists::Error)]
+    on_exists: OnExists,
+
     /// Additional repositories for multi-repo workspace (use with --worktree)
     #[arg(long = "repo", short = 'r')]
     extra_repos: Vec<PathBuf>,
@@ -85,10 +94,25 @@ pub struct AddArgs {
     #[arg(long = "sandbox-image")]
     sandbox_image: Option<String>,
 
+    /// Extra container environment entry (repeatable): `KEY` passes the
+    /// host value through, `KEY=VALUE` sets it. Requires a sandbox.
+    #[arg(long = "env", value_name = "KEY[=VALUE]")]
+    env: Vec<String>,
+
+    /// Read the agent's initial instruction from a file (`-` for stdin),
+    /// replacing `sandbox.custom_instruction` in a sandbox.
+    #[arg(long = "prompt-file", value_name = "PATH")]
+    prompt_file: Option<PathBuf>,
+
     /// Enable YOLO mode (skip permission prompts)
     #[arg(short = 'y', long)]
     yolo: bool,
 
+    /// Allow YOLO mode (from --yolo or `session.yolo_mode_default`) without a
+    /// sandbox, giving the agent unprompted access to the host
+    #[arg(long = "i-know-what-im-doing")]
+    i_know_what_im_doing: bool,
+
     /// Automatically trust this repository's hooks and project-local MCP
     /// servers without prompting
     #[arg(long = "trust-hooks")]
@@ -133,6 +157,7 @@ pub struct AddArgs {
             "worktree_branch",
             "create_branch",
             "base_branch",
+            "on_exists",
             "extra_repos",
             "projects",
             "no_submodules",
@@ -150,6 +175,21 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
         bail!("--interactive requires a terminal; pass --title for non-interactive naming");
     }
 
+    // Read the instruction up front so a bad file fails before any
+    // worktree or scratch directory is created.
+    let custom_instruction = match args.prompt_file.as_deref() {
+        Some(p) if p.as_os_str() == "-" => {
+            if args.interactive {
+                bail!(
+                    "--prompt-file - reads stdin, which --interactive needs for the session name"
+                );
+            }
+            Some(builder::read_instruction(std::io::stdin().lock(), "stdin")?)
+        }
+        Some(p) => Some(builder::read_instruction_file(p)?),
+        None => None,
+    };
+
     // Scratch sessions have no project path; the scratch directory is
     // provisioned below once we know the instance id. Reject an
     // explicitly-passed path loudly so `aoe add /some/repo --scratch` does
@@ -165,7 +205,11 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
         // `scratch::provision_scratch_dir` returns a fresh scratch dir.
         PathBuf::new()
     } else {
-        let raw = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+        let raw = args
+            .path
+            .as_deref()
+            .map(|p| PathBuf::from(crate::wsl::normalize_host_path(&p.to_string_lossy())))
+            .unwrap_or_else(|| PathBuf::from("."));
         if raw.as_os_str() == "." {
             std::env::current_dir()?
         } else {
@@ -229,8 +273,99 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     // check still runs later, once `path` points at the worktree.
     let storage = Storage::new_unwatched(profile)?;
     let (instances, _groups) = storage.load_with_groups()?;
+    config
+        .session
+        .check_session_limit(crate::session::config::count_active_sessions(&instances), 1)?;
     let final_title = resolve_session_title(&args, &instances)?;
 
+    // Resolve the agent before any side effects: the install checks below
+    // and the YOLO sandbox gate must be able to refuse the add cleanly.
+    let mut tool_command = String::new();
+    let tool = if let Some(tool) = &args.tool {
+        let selection = resolve_named_tool(tool, &config)?;
+        if selection.is_custom() && args.cmd_override.is_some() {
+            bail!("--cmd-override cannot be used with configured custom agent --tool selections");
+        }
+        selection.name().to_string()
+    } else if let Some(cmd) = &args.command {
+        let tool_name = detect_tool(cmd)?;
+        // Verify the binary that will actually launch is on PATH before
+        // creating the session. A configured session.agent_command_override
+        // (or custom_agents) entry replaces the built-in binary, so check the
+        // resolved command, not the built-in name, otherwise `--cmd opencode`
+        // falsely bails when only the override binary (e.g.
+        // opencode-plannotator) is installed. See #1910.
+        match override_launch_binary(&tool_name, &config.session) {
+            Some(bin) => {
+                // Use the same detection as tmux (login-shell PATH fallback
+                // included) so an override binary visible only after shell
+                // init isn't rejected here while the non-override path accepts
+                // it. See #1910.
+                if !crate::tmux::is_binary_on_path(&bin) {
+                    bail!(
+                        "'{}' (from session.agent_command_override) is not installed or not on $PATH.\n\
+                         See all supported agents: aoe agents",
+                        bin
+                    );
+                }
+            }
+            None => {
+                if let Some(agent_def) = crate::agents::get_agent(&tool_name) {
+                    if !crate::tmux::is_agent_available(agent_def) {
+                        bail!(
+                            "'{}' is not installed or not on $PATH.\n\
+                             Install with: {}\n\
+                             See all supported agents: aoe agents",
+                            agent_def.binary,
+                            agent_def.install_hint
+                        );
+                    }
+                }
+            }
+        }
+        // Only store a custom command when the user passed extra args
+        // (e.g. "claude --resume xyz"). A bare tool name/alias should resolve
+        // through the agent definition so the correct binary is used.
+        if cmd.trim().contains(' ') {
+            tool_command = cmd.clone();
+        }
+        tool_name
+    } else {
+        // Use default_tool from resolved config, then first available tool, then "claude".
+        // Check custom_agents first (exact match) before resolve_tool_name (substring match),
+        // so names like "lenovo-claude" resolve as the custom agent, not built-in "claude".
+        let available_tools = crate::tmux::AvailableTools::detect();
+        let tools_list = available_tools.available_list();
+        config
+            .session
+            .default_tool
+            .as_deref()
+            .and_then(|name| {
+                if config.session.custom_agents.contains_key(name) {
+                    Some(name)
+                } else {
+                    crate::agents::resolve_tool_name(name)
+                }
+            })
+            .or_else(|| tools_list.first().map(|s| s.as_str()))
+            .unwrap_or("claude")
+            .to_string()
+    };
+
+    let use_sandbox = args.sandbox || args.sandbox_image.is_some();
+    let want_sandbox = use_sandbox || config.sandbox.enabled_by_default;
+    let runtime = containers::get_container_runtime();
+    let runtime_available = want_sandbox && runtime.is_available();
+
+    // Refuse unsandboxed YOLO before any worktree, workspace or scratch dir
+    // exists, so a rejected add leaves nothing behind.
+    builder::check_yolo_sandbox(
+        &tool,
+        args.yolo || config.session.yolo_mode_default,
+        runtime_available,
+        args.i_know_what_im_doing,
+    )?;
+
     if let Some(branch_raw) = &args.worktree_branch {
         use crate::git::GitWorktree;
         use crate::session::WorktreeInfo;
@@ -273,6 +408,7 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
                 args.create_branch,
                 &config.worktree.workspace_path_template,
                 init_submodules,
+                config.worktree.root_path(),
             )?;
 
             for repo in &ws_result.workspace_info.repos {
@@ -297,8 +433,9 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
             }
 
             let main_repo_path = GitWorktree::find_main_repo(&path)?;
-            let git_wt =
-                GitWorktree::new(main_repo_path.clone())?.with_init_submodules(init_submodules);
+            let git_wt = GitWorktree::new(main_repo_path.clone())?
+                .with_init_submodules(init_submodules)
+                .with_worktree_root(config.worktree.root_path());
 
             // Attach mode: when `-b` is not passed, mirror the TUI's "Attach
             // to existing branch" behavior. If a worktree already exists
@@ -351,48 +488,71 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
                 };
                 let worktree_path = git_wt.compute_path(leaf_seed, template, session_id_short)?;
 
-                if worktree_path.exists() {
-                    bail!(
-                        "Worktree already exists at {}\nTip: Use 'aoe add {}' to add the existing worktree",
+                let recreating = worktree_path.exists();
+                let adopted = match git_wt.resolve_existing_path(
+                    branch,
+                    &worktree_path,
+                    args.on_exists,
+                ) {
+                    Ok(adopted) => adopted,
+                    Err(GitError::WorktreeAlreadyExists(_)) => bail!(
+                        "Worktree already exists at {}\nTip: Use 'aoe add {}' to add the existing worktree, or pass --on-exists adopt|recreate",
                         worktree_path.display(),
                         worktree_path.display()
-                    );
-                }
-
-                println!("Creating worktree at: {}", worktree_path.display());
-                // Single-repo sessions only have the launch repo, so fall back
-                // from the explicit session base to the global/profile default.
-                let base = if args.create_branch {
-                    builder::resolve_base_branch(
-                        args.base_branch.as_deref(),
-                        None,
-                        config.worktree.default_base_branch.as_deref(),
-                    )
-                } else {
-                    None
+                    ),
+                    Err(e) => return Err(e.into()),
                 };
-                let warnings = git_wt.create_worktree(
-                    branch,
-                    &worktree_path,
-                    args.create_branch,
-                    base.as_deref(),
-                )?;
-
-                path = worktree_path;
 
-                worktree_info_opt = Some(WorktreeInfo {
-                    branch: branch.to_string(),
-                    main_repo_path: main_repo_path.to_string_lossy().to_string(),
-                    managed_by_aoe: true,
-                    created_at: Utc::now(),
-                    base_branch: base,
-                });
+                if adopted {
+                    println!("Adopting existing worktree: {}", worktree_path.display());
+                    path = worktree_path;
+                    worktree_info_opt = Some(WorktreeInfo {
+                        branch: branch.to_string(),
+                        main_repo_path: main_repo_path.to_string_lossy().to_string(),
+                        managed_by_aoe: false,
+                        created_at: Utc::now(),
+                        base_branch: None,
+                    });
+                } else {
+                    println!("Creating worktree at: {}", worktree_path.display());
+                    // Single-repo sessions only have the launch repo, so fall back
+                    // from the explicit session base to the global/profile default.
+                    let base = if args.create_branch {
+                        builder::resolve_base_branch(
+                            args.base_branch.as_deref(),
+                            None,
+                            config.worktree.default_base_branch.as_deref(),
+                        )
+                    } else {
+                        None
+                    };
+                    // A recreated worktree leaves its branch behind; check it
+                    // out again rather than failing to create it a second time.
+                    let create_branch =
+                        args.create_branch && !(recreating && git_wt.branch_exists(branch));
+                    let warnings = git_wt.create_worktree(
+                        branch,
+                        &worktree_path,
+                        create_branch,
+                        base.as_deref(),
+                    )?;
+
+                    path = worktree_path;
+
+                    worktree_info_opt = Some(WorktreeInfo {
+                        branch: branch.to_string(),
+                        main_repo_path: main_repo_path.to_string_lossy().to_string(),
+                        managed_by_aoe: true,
+                        created_at: Utc::now(),
+                        base_branch: base,
+                    });
+
+                    for w in &warnings {
+                        eprintln!("⚠ {}", w);
+                    }
 
-                for w in &warnings {
-                    eprintln!("⚠ {}", w);
+                    println!("✓ Worktree created successfully");
                 }
-
-                println!("✓ Worktree created successfully");
             }
         }
     }
@@ -430,6 +590,7 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
 
     let mut instance = Instance::new(&final_title, path.to_str().unwrap_or(""));
     instance.source_profile = profile.to_string();
+    instance.auto_create_terminal = config.session.auto_create_terminal;
 
     // Scratch sessions: provision a fresh scratch directory keyed on the
     // freshly-generated instance id. The session layer owns the location
@@ -443,82 +604,19 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
 
     if let Some(group) = &group_path {
         instance.group_path = group.trim().to_string();
+    } else if !args.scratch {
+        if let Some(group) = crate::session::builder::remote_group(&instance.project_path, &config)
+        {
+            instance.group_path = group;
+        }
     }
 
     if let Some(parent) = parent_id {
         instance.parent_session_id = Some(parent);
     }
 
-    if let Some(tool) = &args.tool {
-        let selection = resolve_named_tool(tool, &config)?;
-        if selection.is_custom() && args.cmd_override.is_some() {
-            bail!("--cmd-override cannot be used with configured custom agent --tool selections");
-        }
-        instance.tool = selection.name().to_string();
-    } else if let Some(cmd) = &args.command {
-        let tool_name = detect_tool(cmd)?;
-        // Verify the binary that will actually launch is on PATH before
-        // creating the session. A configured session.agent_command_override
-        // (or custom_agents) entry replaces the built-in binary, so check the
-        // resolved command, not the built-in name, otherwise `--cmd opencode`
-        // falsely bails when only the override binary (e.g.
-        // opencode-plannotator) is installed. See #1910.
-        match override_launch_binary(&tool_name, &config.session) {
-            Some(bin) => {
-                // Use the same detection as tmux (login-shell PATH fallback
-                // included) so an override binary visible only after shell
-                // init isn't rejected here while the non-override path accepts
-                // it. See #1910.
-                if !crate::tmux::is_binary_on_path(&bin) {
-                    bail!(
-                        "'{}' (from session.agent_command_override) is not installed or not on $PATH.\n\
-                         See all supported agents: aoe agents",
-                        bin
-                    );
-                }
-            }
-            None => {
-                if let Some(agent_def) = crate::agents::get_agent(&tool_name) {
-                    if !crate::tmux::is_agent_available(agent_def) {
-                        bail!(
-                            "'{}' is not installed or not on $PATH.\n\
-                             Install with: {}\n\
-                             See all supported agents: aoe agents",
-                            agent_def.binary,
-                            agent_def.install_hint
-                        );
-                    }
-                }
-            }
-        }
-        instance.tool = tool_name;
-        // Only store a custom command when the user passed extra args
-        // (e.g. "claude --resume xyz"). A bare tool name/alias should resolve
-        // through the agent definition so the correct binary is used.
-        if cmd.trim().contains(' ') {
-            instance.command = cmd.clone();
-        }
-    } else {
-        // Use default_tool from resolved config, then first available tool, then "claude".
-        // Check custom_agents first (exact match) before resolve_tool_name (substring match),
-        // so names like "lenovo-claude" resolve as the custom agent, not built-in "claude".
-        let available_tools = crate::tmux::AvailableTools::detect();
-        let tools_list = available_tools.available_list();
-        instance.tool = config
-            .session
-            .default_tool
-            .as_deref()
-            .and_then(|name| {
-                if config.session.custom_agents.contains_key(name) {
-                    Some(name)
-                } else {
-                    crate::agents::resolve_tool_name(name)
-                }
-            })
-            .or_else(|| tools_list.first().map(|s| s.as_str()))
-            .unwrap_or("claude")
-            .to_string();
-    }
+    instance.tool = tool;
+    instance.command = tool_command;
 
     // Set detect_as for status detection (resolved once, avoids config load in poll loop)
     instance.detect_as = config
@@ -690,11 +788,8 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
     }
 
     // Handle sandbox setup
-    let use_sandbox = args.sandbox || args.sandbox_image.is_some();
-
-    let runtime = containers::get_container_runtime();
-    if use_sandbox || config.sandbox.enabled_by_default {
-        if !runtime.is_available() {
+    if want_sandbox {
+        if !runtime_available {
             if use_sandbox {
                 bail!(
                     "Container runtime is not installed or not accessible.\n\
@@ -721,12 +816,37 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
                 container_id: None,
                 image,
                 container_name,
-                extra_env: None,
-                custom_instruction: config.sandbox.custom_instruction.clone(),
+                extra_env: (!args.env.is_empty()).then(|| args.env.clone()),
+                custom_instruction: custom_instruction
+                    .clone()
+                    .or_else(|| config.sandbox.custom_instruction.clone()),
+                cpu_limit: None,
+                memory_limit: None,
             });
         }
     }
 
+    if !args.env.is_empty() && instance.sandbox_info.is_none() {
+        cleanup_partial_session(
+            &path,
+            instance.worktree_info.as_ref(),
+            instance.workspace_info.as_ref(),
+            args.create_branch,
+            if instance.scratch {
+                Some(std::path::Path::new(&instance.project_path))
+            } else {
+                None
+            },
+        );
+        bail!("--env sets container environment and requires a sandbox\nTip: add --sandbox");
+    }
+    if instance.sandbox_info.is_none() {
+        instance.host_instruction = custom_instruction;
+    }
+    for w in crate::session::validate_env_entries(&args.env) {
+        eprintln!("⚠ {}", w);
+    }
+
     // Check for repository hooks.
     // Use the original project path for trust checking (not the worktree/workspace
     // path, which won't contain `.agent-of-empires/config.toml`).
@@ -936,6 +1056,13 @@ pub async fn run(profile: &str, args: AddArgs) -> Result<()> {
         }
     }
 
+    let detail = instance
+        .worktree_info
+        .as_ref()
+        .map(|wt| format!("branch {}", wt.branch))
+        .unwrap_or_default();
+    activity::record(ActivityKind::SessionCreated, &instance, detail);
+
     println!("✓ Added session: {}", final_title);
     println!("  Profile: {}", storage.profile());
     println!("  Path:    {}", path.display());
@@ -3,6 +3,8 @@
 //! Lists all supported agents, shows which are installed, and prints
 //! install commands for missing ones.
 
+use std::io::IsTerminal;
+
 use anyhow::Result;
 
 #[tracing::instrument(target = "cli.agents", skip_all)]
@@ -12,14 +14,22 @@ pub fn run() -> Result<()> {
 
     println!("Supported AI coding agents:\n");
 
+    // Plain marks when piped or when the user opts out of color.
+    let color = std::io::stdout().is_terminal() && !crate::session::config::no_color_env();
+    let (yes, no) = if color {
+        ("\x1b[32m✓\x1b[0m", "\x1b[31m✗\x1b[0m")
+    } else {
+        ("✓", "✗")
+    };
+
     for agent in crate::agents::AGENTS {
         let installed = available_list.iter().any(|s| s == agent.name);
         if installed {
-            println!("  \x1b[32m✓\x1b[0m {:<12} installed", agent.name);
+            println!("  {} {:<12} installed", yes, agent.name);
         } else {
             println!(
-                "  \x1b[31m✗\x1b[0m {:<12} not installed -- {}",
-                agent.name, agent.install_hint
+                "  {} {:<12} not installed -- {}",
+                no, agent.name, agent.install_hint
             );
         }
     }
@@ -0,0 +1,35 @@
+//! `aoe cache` subcommands: inspect and clear regenerable cached data.
+
+use anyhow::Result;
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Delete cached data that aoe regenerates on demand (update check cache)
+    Clear,
+    /// Print the cache directory (`app.cache_dir`, or the app directory)
+    Dir,
+}
+
+#[tracing::instrument(target = "cli.session", skip_all)]
+pub fn run(command: CacheCommands) -> Result<()> {
+    match command {
+        CacheCommands::Clear => run_clear(),
+        CacheCommands::Dir => {
+            println!("{}", crate::session::get_cache_dir()?.display());
+            Ok(())
+        }
+    }
+}
+
+fn run_clear() -> Result<()> {
+    let removed = crate::update::clear_cache()?;
+    if removed.is_empty() {
+        println!("Nothing to clear.");
+    } else {
+        for path in &removed {
+            println!("Removed {}", path.display());
+        }
+    }
+    Ok(())
+}
@@ -9,6 +9,7 @@ use clap_complete::Shell;
 #[cfg(feature = "serve")]
 use super::acp::AcpCommands;
 use super::add::AddArgs;
+use super::cache::CacheCommands;
 use super::extract_session_id::ExtractSessionIdArgs;
 use super::group::GroupCommands;
 use super::init::InitArgs;
@@ -146,6 +147,12 @@ pub enum Commands {
         command: ThemeCommands,
     },
 
+    /// Manage cached data (update check cache)
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
     /// Manage anonymous opt-in usage telemetry
     Telemetry {
         #[command(subcommand)]
@@ -186,10 +193,16 @@ pub enum Commands {
     #[command(name = "__extract-session-id", hide = true)]
     ExtractSessionId(ExtractSessionIdArgs),
 
+    /// Internal: relaunch loop for `session.on_agent_exit = "respawn"`,
+    /// the tmux pane's command in place of the agent. Hidden from help.
+    #[command(name = "__respawn-agent", hide = true)]
+    RespawnAgent(super::respawn_agent::RespawnAgentArgs),
+
     /// Uninstall Agent of Empires
     Uninstall(UninstallArgs),
 
     /// Update aoe to the latest release
+    #[command(alias = "self-update")]
     Update(UpdateArgs),
 
     /// Generate shell completions
@@ -227,6 +240,7 @@ pub const CLI_COMMAND_NAMES: &[&str] = &[
     "tmux",
     "sounds",
     "theme",
+    "cache",
     "telemetry",
     "mcp",
     "serve",
@@ -267,6 +281,7 @@ pub fn command_name(command: &Commands) -> Option<&'static str> {
         Commands::Tmux { .. } => "tmux",
         Commands::Sounds { .. } => "sounds",
         Commands::Theme { .. } => "theme",
+        Commands::Cache { .. } => "cache",
         Commands::Telemetry { .. } => "telemetry",
         Commands::Mcp { .. } => "mcp",
         #[cfg(feature = "serve")]
@@ -279,6 +294,7 @@ pub fn command_name(command: &Commands) -> Option<&'static str> {
         #[cfg(feature = "serve")]
         Commands::AcpRunner(_) => return None,
         Commands::ExtractSessionId(_) => return None,
+        Commands::RespawnAgent(_) => return None,
         Commands::Uninstall(_) => "uninstall",
         Commands::Update(_) => "update",
         Commands::Completion { .. } => "completion",
@@ -304,6 +320,7 @@ mod tests {
             (&["aoe", "rm", "demo"], "remove"),
             (&["aoe", "session", "current"], "session"),
             (&["aoe", "telemetry", "status"], "telemetry"),
+            (&["aoe", "cache", "clear"], "cache"),
             (&["aoe", "update"], "update"),
             (&["aoe", "completion", "bash"], "completion"),
         ];
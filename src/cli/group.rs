@@ -20,6 +20,9 @@ pub enum GroupCommands {
 
     /// Move session to group
     Move(GroupMoveArgs),
+
+    /// Rename a group, moving its sessions and subgroups along with it
+    Rename(GroupRenameArgs),
 }
 
 #[derive(Args)]
@@ -58,6 +61,15 @@ pub struct GroupMoveArgs {
     group: String,
 }
 
+#[derive(Args)]
+pub struct GroupRenameArgs {
+    /// Current group path
+    old_path: String,
+
+    /// New group path
+    new_path: String,
+}
+
 #[derive(Serialize)]
 struct GroupInfo {
     name: String,
@@ -73,6 +85,7 @@ pub async fn run(profile: &str, command: GroupCommands) -> Result<()> {
         GroupCommands::Create(args) => create_group(profile, args).await,
         GroupCommands::Delete(args) => delete_group(profile, args).await,
         GroupCommands::Move(args) => move_session(profile, args).await,
+        GroupCommands::Rename(args) => rename_group(profile, args).await,
     }
 }
 
@@ -224,3 +237,17 @@ async fn move_session(profile: &str, args: GroupMoveArgs) -> Result<()> {
 
     Ok(())
 }
+
+async fn rename_group(profile: &str, args: GroupRenameArgs) -> Result<()> {
+    let storage = Storage::new_unwatched(profile)?;
+    let old_path = args.old_path.trim().to_string();
+    let new_path = args.new_path.trim().to_string();
+
+    let moved = storage.rename_group(&old_path, &new_path)?;
+
+    println!("✓ Renamed group '{}' to '{}'", old_path, new_path);
+    if moved > 0 {
+        println!("  Moved {} sessions", moved);
+    }
+    Ok(())
+}
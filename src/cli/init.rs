@@ -38,6 +38,13 @@ pub async fn run(args: InitArgs) -> Result<()> {
             config_path.display()
         );
     }
+    let single_file = path.join(crate::session::repo_config::REPO_CONFIG_FILE);
+    if single_file.exists() {
+        bail!(
+            ".aoe.toml already exists at {}\nEdit it directly to make changes.",
+            single_file.display()
+        );
+    }
     if legacy_path.exists() {
         bail!(
             "Legacy .aoe/config.toml found at {}\nRename .aoe/ to .agent-of-empires/ to use the new path, or edit it directly.",
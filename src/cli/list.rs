@@ -148,6 +148,7 @@ pub async fn run(profile: &str, args: ListArgs) -> Result<()> {
         return Ok(());
     }
 
+    let update_notice = crate::update::UpdateNotice::spawn();
     println!("Profile: {}\n", storage.profile());
     print_table_header();
     for inst in &instances {
@@ -155,7 +156,7 @@ pub async fn run(profile: &str, args: ListArgs) -> Result<()> {
     }
     println!("\nTotal: {} sessions", instances.len());
 
-    crate::update::print_update_notice().await;
+    update_notice.show().await;
 
     Ok(())
 }
@@ -4,6 +4,7 @@
 pub mod acp;
 pub mod add;
 pub mod agents;
+pub mod cache;
 pub mod definition;
 pub mod extract_session_id;
 pub mod group;
@@ -17,6 +18,7 @@ pub mod output;
 pub mod profile;
 pub mod project;
 pub mod remove;
+pub mod respawn_agent;
 pub mod send;
 #[cfg(feature = "serve")]
 pub mod serve;
@@ -66,7 +66,11 @@ pub async fn run(profile: &str, args: RemoveArgs) -> Result<()> {
         .worktree_info
         .as_ref()
         .is_some_and(|wt| wt.managed_by_aoe)
-        && (args.delete_branch || (delete_worktree && config.worktree.delete_branch_on_cleanup));
+        && (args.delete_branch
+            || (delete_worktree && config.worktree.delete_branch_on_cleanup.deletes()));
+    // An explicit --delete-branch deletes regardless of merge state.
+    let keep_unmerged_branch = !args.delete_branch
+        && config.worktree.delete_branch_on_cleanup == crate::session::BranchCleanup::IfMerged;
     let delete_sandbox = inst.sandbox_info.as_ref().is_some_and(|s| s.enabled)
         && !args.keep_container
         && config.sandbox.auto_cleanup;
@@ -81,6 +85,7 @@ pub async fn run(profile: &str, args: RemoveArgs) -> Result<()> {
             force_delete: args.force,
             detach_hooks: false,
             keep_scratch: args.keep_scratch,
+            keep_unmerged_branch,
         });
 
     for msg in &result.messages {
@@ -0,0 +1,105 @@
+//! Hidden `aoe __respawn-agent` subcommand.
+//!
+//! Wraps an agent launch command for `session.on_agent_exit = "respawn"`.
+//! The command runs under `sh -c` and is relaunched whenever it exits after
+//! running for at least [`MIN_RUNTIME`]. A shorter run is treated as a crash
+//! (e.g. a bad `--resume`): this process then exits with the agent's status
+//! and the pane is left dead for the resume-fallback probe and the user.
+//!
+//! The loop lives in a named binary rather than a shell `while` loop because
+//! tmux reports the pane's foreground process-group leader as
+//! `#{pane_current_command}`. A `sh` there reads as "the agent exited to a
+//! shell", which restarts a healthy agent on every attach.
+
+use std::process::{Command, ExitStatus};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::Args;
+
+/// Shortest run that is relaunched.
+pub const MIN_RUNTIME: Duration = Duration::from_secs(5);
+
+/// Pause before relaunching, so the exit message is readable.
+const RESPAWN_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Args)]
+pub struct RespawnAgentArgs {
+    /// Agent launch command, run with `sh -c`.
+    command: String,
+}
+
+pub fn run(args: RespawnAgentArgs) -> Result<()> {
+    // The agent shares this process group, so Ctrl+C in the pane reaches
+    // both. Catch (rather than ignore) SIGINT/SIGQUIT: a caught signal is
+    // reset to its default in the exec'd agent, an ignored one would be
+    // inherited.
+    catch_terminal_signals();
+    let code = respawn_loop(&args.command, MIN_RUNTIME);
+    std::process::exit(code);
+}
+
+fn respawn_loop(command: &str, min_runtime: Duration) -> i32 {
+    loop {
+        let started = Instant::now();
+        let code = match Command::new("sh").arg("-c").arg(command).status() {
+            Ok(status) => exit_code(status),
+            Err(e) => {
+                eprintln!("[aoe] failed to launch agent: {e}");
+                return 127;
+            }
+        };
+        if started.elapsed() < min_runtime {
+            return code;
+        }
+        println!("\n[aoe] agent exited with status {code}, respawning");
+        std::thread::sleep(RESPAWN_DELAY);
+    }
+}
+
+/// Shell-style exit code: the status, or 128 plus the terminating signal.
+fn exit_code(status: ExitStatus) -> i32 {
+    use std::os::unix::process::ExitStatusExt;
+    status
+        .code()
+        .unwrap_or_else(|| 128 + status.signal().unwrap_or(0))
+}
+
+fn catch_terminal_signals() {
+    use nix::sys::signal::{sigaction, SaFlags, SigAction, SigHandler, SigSet, Signal};
+
+    extern "C" fn noop(_: nix::libc::c_int) {}
+
+    let action = SigAction::new(SigHandler::Handler(noop), SaFlags::empty(), SigSet::empty());
+    for signal in [Signal::SIGINT, Signal::SIGQUIT] {
+        // SAFETY: `noop` is async-signal-safe; it does nothing.
+        if let Err(e) = unsafe { sigaction(signal, &action) } {
+            tracing::debug!(target: "cli.respawn", "sigaction {signal}: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_exit_is_not_respawned() {
+        assert_eq!(respawn_loop("exit 3", MIN_RUNTIME), 3);
+        assert_eq!(respawn_loop("kill -TERM $$", MIN_RUNTIME), 128 + 15);
+    }
+
+    #[test]
+    fn test_long_run_is_respawned_until_it_exits_fast() {
+        let dir = tempfile::tempdir().unwrap();
+        let runs = dir.path().join("runs");
+        // The first run outlives `min_runtime` and is relaunched; the second
+        // exits at once and ends the loop with its status.
+        let command = format!(
+            "echo run >> '{0}'; [ $(wc -l < '{0}') -ge 2 ] && exit 4; sleep 0.3",
+            runs.display()
+        );
+        assert_eq!(respawn_loop(&command, Duration::from_millis(200)), 4);
+        assert_eq!(std::fs::read_to_string(&runs).unwrap().lines().count(), 2);
+    }
+}
@@ -39,6 +39,11 @@ pub enum SessionCommands {
     /// the session must not be running. See #1723.
     SetWorktreeName(SetWorktreeNameArgs),
 
+    /// Convert a plain (non-worktree) session into a worktree-backed one.
+    /// Creates a managed worktree for the given branch and re-points the
+    /// session at it; the session must not be running.
+    ConvertToWorktree(ConvertToWorktreeArgs),
+
     /// Capture tmux pane output
     Capture(CaptureArgs),
 
@@ -163,6 +168,26 @@ pub struct SetWorktreeNameArgs {
     rename_branch: bool,
 }
 
+#[derive(Args)]
+pub struct ConvertToWorktreeArgs {
+    /// Session ID or title (optional, auto-detects in tmux)
+    identifier: Option<String>,
+
+    /// Branch to check out in the new worktree (created if missing)
+    #[arg(long, short = 'b')]
+    branch: String,
+
+    /// Base ref for a newly created branch (defaults to the configured or
+    /// detected default branch)
+    #[arg(long)]
+    base: Option<String>,
+
+    /// Carry uncommitted changes from the current checkout into the new
+    /// worktree instead of refusing
+    #[arg(long)]
+    carry_changes: bool,
+}
+
 #[derive(Args)]
 pub struct ShowArgs {
     /// Session ID or title (optional, auto-detects in tmux)
@@ -261,6 +286,7 @@ pub async fn run(profile: &str, command: SessionCommands) -> Result<()> {
         SessionCommands::Capture(args) => capture_session(profile, args).await,
         SessionCommands::Rename(args) => rename_session(profile, args).await,
         SessionCommands::SetWorktreeName(args) => set_worktree_name(profile, args).await,
+        SessionCommands::ConvertToWorktree(args) => convert_to_worktree(profile, args).await,
         SessionCommands::Current(args) => current_session(args).await,
         SessionCommands::SetSessionId(args) => set_session_id(profile, args).await,
         SessionCommands::SetBase(args) => set_base(profile, args).await,
@@ -829,6 +855,9 @@ async fn attach_session(profile: &str, args: SessionIdArgs) -> Result<()> {
         );
     }
 
+    if let Some((cols, rows)) = crate::terminal::get_size() {
+        tmux_session.resize(cols, rows);
+    }
     tmux_session.attach()?;
     Ok(())
 }
@@ -950,7 +979,7 @@ async fn capture_session(profile: &str, args: CaptureArgs) -> Result<()> {
             }
         } else {
             tmux_session
-                .detect_status(detection_tool)
+                .detect_status(detection_tool, &inst.effective_profile())
                 .unwrap_or_default()
         };
         let content = if args.strip_ansi {
@@ -1254,6 +1283,95 @@ async fn set_worktree_name(profile: &str, args: SetWorktreeNameArgs) -> Result<(
     Ok(())
 }
 
+async fn convert_to_worktree(profile: &str, args: ConvertToWorktreeArgs) -> Result<()> {
+    let storage = Storage::new_unwatched(profile)?;
+    let (instances, _groups) = storage.load_with_groups()?;
+    let inst = if let Some(id) = &args.identifier {
+        super::resolve_session(id, &instances)?
+    } else {
+        let current_session = std::env::var("TMUX_PANE")
+            .ok()
+            .and_then(|_| crate::tmux::get_current_session_name());
+        if let Some(session_name) = current_session {
+            instances
+                .iter()
+                .find(|i| {
+                    let tmux_name = crate::tmux::Session::generate_name(&i.id, &i.title);
+                    tmux_name == session_name
+                })
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Current tmux session is not an Agent of Empires session")
+                })?
+        } else {
+            bail!("Not in a tmux session. Specify a session ID or run inside tmux.");
+        }
+    };
+
+    if inst.worktree_info.is_some() {
+        bail!("Session already uses a worktree");
+    }
+    if inst.is_workspace() {
+        bail!("Workspace sessions cannot be converted to a single worktree");
+    }
+    if inst.scratch {
+        bail!("Scratch sessions have no repository to create a worktree from");
+    }
+    let id = inst.id.clone();
+    let current_path = inst.project_path.clone();
+
+    // The tmux pane and any sandbox container are rooted at the old path;
+    // re-pointing them requires a restart, so refuse while either is live.
+    let mut live = inst.clone();
+    crate::tmux::refresh_session_cache();
+    live.update_status();
+    if live.status.blocks_worktree_edit()
+        || crate::session::worktree_edit::sandbox_container_holds_worktree(&id, live.is_sandboxed())
+    {
+        bail!("Cannot convert the session while it is active; stop it first");
+    }
+
+    let config = crate::session::profile_config::resolve_config_or_warn(profile);
+    let outcome = crate::session::worktree_convert::convert_to_worktree(
+        crate::session::worktree_convert::WorktreeConvertRequest {
+            current_path: std::path::Path::new(&current_path),
+            branch: &args.branch,
+            base_branch: args.base.as_deref(),
+            carry_changes: args.carry_changes,
+            profile,
+            config: &config.worktree,
+            session_id: &id,
+        },
+    )?;
+    // The container's mounts point at the old checkout; drop it so the next
+    // start recreates it against the worktree.
+    crate::session::worktree_edit::discard_sandbox_container_after_move(&id, live.is_sandboxed());
+    let new_path = outcome.new_path.to_string_lossy().to_string();
+    let worktree_info = outcome.worktree_info.clone();
+
+    storage
+        .update(|instances, _groups| {
+            let inst = instances
+                .iter_mut()
+                .find(|i| i.id == id)
+                .ok_or_else(|| anyhow::anyhow!("Session not found: {}", id))?;
+            inst.project_path = new_path.clone();
+            inst.worktree_info = Some(worktree_info.clone());
+            Ok(())
+        })
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Worktree was created at {new_path}, but persisting the new session metadata failed: {e}. Remove the worktree with 'git worktree remove' before retrying."
+            )
+        })?;
+
+    for warning in &outcome.warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    println!("✓ Session now uses worktree: {}", new_path);
+    println!("  Branch: {}", outcome.worktree_info.branch);
+    Ok(())
+}
+
 async fn current_session(args: CurrentArgs) -> Result<()> {
     // Auto-detect profile and session from tmux
     let current_session = std::env::var("TMUX_PANE")
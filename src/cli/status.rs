@@ -59,6 +59,10 @@ pub async fn run(profile: &str, args: StatusArgs) -> Result<()> {
         return Ok(());
     }
 
+    // Start the update check now so it overlaps the status probes below
+    // (skip for JSON/quiet output).
+    let update_notice = (!args.json && !args.quiet).then(crate::update::UpdateNotice::spawn);
+
     // Refresh tmux session cache
     crate::tmux::refresh_session_cache();
 
@@ -104,9 +108,8 @@ pub async fn run(profile: &str, args: StatusArgs) -> Result<()> {
         );
     }
 
-    // Show update notice if available (skip for JSON/quiet output)
-    if !args.json && !args.quiet {
-        crate::update::print_update_notice().await;
+    if let Some(update_notice) = update_notice {
+        update_notice.show().await;
     }
 
     Ok(())
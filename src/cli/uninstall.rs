@@ -49,7 +49,7 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
     // always included (it is the macOS default and the pre-XDG Linux location),
     // alongside the XDG path, so either layout is cleaned up.
     #[cfg(any(target_os = "linux", target_os = "macos"))]
-    let data_dirs = {
+    let mut data_dirs = {
         let mut dirs = vec![home_dir.join(".agent-of-empires")];
         if let Ok(base) = crate::session::xdg_config_base() {
             dirs.push(base.join("agent-of-empires"));
@@ -57,7 +57,23 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
         dirs
     };
     #[cfg(not(any(target_os = "linux", target_os = "macos")))]
-    let data_dirs = vec![home_dir.join(".agent-of-empires")];
+    let mut data_dirs = vec![home_dir.join(".agent-of-empires")];
+
+    // AOE_CONFIG_DIR / AOE_DATA_DIR point somewhere else entirely; clean
+    // those up as well.
+    if crate::session::dir_override_set() {
+        for dir in [
+            crate::session::get_config_dir_path(),
+            crate::session::get_app_dir_path(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !data_dirs.contains(&dir) {
+                data_dirs.push(dir);
+            }
+        }
+    }
 
     let mut found_items: Vec<FoundItem> = Vec::new();
 
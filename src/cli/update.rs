@@ -18,18 +18,32 @@ pub struct UpdateArgs {
     yes: bool,
 
     /// Print update status and exit (no install)
-    #[arg(long)]
+    #[arg(long, alias = "check-only")]
     check: bool,
 
     /// Detect install method and print what would happen, no download
     #[arg(long)]
     dry_run: bool,
+
+    /// Check GitHub even when `updates.offline` is set
+    #[arg(long)]
+    force: bool,
 }
 
 #[tracing::instrument(target = "cli.session", skip_all)]
 pub async fn run(args: UpdateArgs) -> Result<()> {
     let current_version = env!("CARGO_PKG_VERSION");
 
+    if crate::session::get_update_settings().offline {
+        if !args.force {
+            bail!(
+                "updates.offline is set, so aoe will not contact GitHub. \
+                 Re-run with --force to check anyway."
+            );
+        }
+        eprintln!("updates.offline is set; checking GitHub anyway because of --force.");
+    }
+
     // Force-fresh check; the user explicitly asked.
     let info = check_for_update(current_version, true)
         .await
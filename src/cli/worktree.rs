@@ -5,8 +5,9 @@ use clap::Subcommand;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+use crate::git::cleanup::{self, RepoPruneOutcome};
 use crate::git::GitWorktree;
-use crate::session::Storage;
+use crate::session::{worktree_lock, Storage};
 
 #[derive(Subcommand)]
 pub enum WorktreeCommands {
@@ -26,6 +27,21 @@ pub enum WorktreeCommands {
         #[arg(short = 'f', long = "force")]
         force: bool,
     },
+
+    /// Prune stale worktree entries in every repo used by this profile's sessions
+    Prune,
+
+    /// Lock a session's worktree so it is never cleaned up, idle-stopped, or deleted
+    Lock {
+        /// Session ID or title
+        identifier: String,
+    },
+
+    /// Unlock a session's worktree
+    Unlock {
+        /// Session ID or title
+        identifier: String,
+    },
 }
 
 #[tracing::instrument(target = "cli.session", skip_all, fields(profile = %profile))]
@@ -34,6 +50,9 @@ pub async fn run(profile: &str, command: WorktreeCommands) -> Result<()> {
         WorktreeCommands::List => list_worktrees().await,
         WorktreeCommands::Info { identifier } => show_info(profile, &identifier).await,
         WorktreeCommands::Cleanup { force } => cleanup_orphaned(profile, force).await,
+        WorktreeCommands::Prune => prune_session_repos(profile).await,
+        WorktreeCommands::Lock { identifier } => set_locked(profile, &identifier, true).await,
+        WorktreeCommands::Unlock { identifier } => set_locked(profile, &identifier, false).await,
     }
 }
 
@@ -54,7 +73,7 @@ async fn list_worktrees() -> Result<()> {
     println!("{}", "=".repeat(80));
 
     for wt in &worktrees {
-        let branch = wt.branch.clone().unwrap_or_else(|| {
+        let branch = wt.head.clone().unwrap_or_else(|| {
             if wt.is_detached {
                 "(detached)".to_string()
             } else {
@@ -83,6 +102,7 @@ async fn show_info(profile: &str, identifier: &str) -> Result<()> {
     let (instances, _) = storage.load_with_groups()?;
 
     let session = super::resolve_session(identifier, &instances)?;
+    let display = crate::session::Config::load_or_warn().display;
 
     if let Some(wt_info) = &session.worktree_info {
         println!("Worktree Information:\n");
@@ -96,7 +116,7 @@ async fn show_info(profile: &str, identifier: &str) -> Result<()> {
         );
         println!(
             "  Created at:    {}",
-            wt_info.created_at.format("%Y-%m-%d %H:%M:%S")
+            display.format_timestamp(wt_info.created_at)
         );
 
         // Check if worktree still exists
@@ -123,7 +143,7 @@ async fn show_info(profile: &str, identifier: &str) -> Result<()> {
         );
         println!(
             "  Created at:    {}",
-            ws_info.created_at.format("%Y-%m-%d %H:%M:%S")
+            display.format_timestamp(ws_info.created_at)
         );
         println!();
         for repo in &ws_info.repos {
@@ -160,8 +180,9 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
     let mut orphaned_sessions = Vec::new();
     let mut orphaned_worktrees = Vec::new();
 
-    // Find sessions with missing worktrees
-    for inst in &instances {
+    // Find sessions with missing worktrees. Locked sessions are kept even
+    // when their worktree is gone; the user unlocks them explicitly.
+    for inst in instances.iter().filter(|i| !i.worktree_locked) {
         if let Some(_wt_info) = &inst.worktree_info {
             let worktree_path = PathBuf::from(&inst.project_path);
             if !worktree_path.exists() {
@@ -294,6 +315,48 @@ async fn cleanup_orphaned(profile: &str, force: bool) -> Result<()> {
     Ok(())
 }
 
+async fn prune_session_repos(profile: &str) -> Result<()> {
+    let storage = Storage::new_unwatched(profile)?;
+    let (instances, _) = storage.load_with_groups()?;
+
+    let results = cleanup::prune_session_repos(&instances);
+    for r in &results {
+        let repo = shorten_path(&r.repo);
+        match &r.outcome {
+            RepoPruneOutcome::Pruned(n) => println!("✓ {}: pruned {}", repo, n),
+            RepoPruneOutcome::Missing => println!("- {}: repo missing, skipped", repo),
+            RepoPruneOutcome::Failed(e) => eprintln!("✗ {}: {}", repo, e),
+        }
+    }
+    if !results.is_empty() {
+        println!();
+    }
+    println!("{}", cleanup::summarize_prune(&results));
+
+    Ok(())
+}
+
+async fn set_locked(profile: &str, identifier: &str, locked: bool) -> Result<()> {
+    let storage = Storage::new_unwatched(profile)?;
+    let title = storage.update(|instances, _groups| {
+        super::patch_instance(instances, identifier, |inst| {
+            match worktree_lock::set_worktree_locked(inst, locked) {
+                Ok(()) => {}
+                // Nothing left on disk to protect; clear the flag so the
+                // session can still be deleted.
+                Err(e @ crate::git::error::GitError::WorktreeNotFound(_)) if !locked => {
+                    eprintln!("Warning: {e}; unlocking the session without touching git");
+                }
+                Err(e) => return Err(e.into()),
+            }
+            inst.worktree_locked = locked;
+            Ok(inst.title.clone())
+        })
+    })?;
+    println!("{}: {}", if locked { "Locked" } else { "Unlocked" }, title);
+    Ok(())
+}
+
 fn shorten_path(path: &Path) -> String {
     let path_str = path.to_string_lossy();
     if let Some(home) = dirs::home_dir() {
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 
 use super::error::Result;
 
@@ -15,6 +16,27 @@ pub struct NamedVolumeMount {
     pub container_path: String,
 }
 
+/// A host directory copied into the container after create rather than
+/// bind-mounted (`sandbox.mount_excludes`). The copy is a one-time snapshot:
+/// edits on either side are not synced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeededCopy {
+    pub host_path: String,
+    pub container_path: String,
+    /// `tar --exclude` patterns left out of the copy.
+    pub excludes: Vec<String>,
+}
+
+/// Non-root user a container runs as (`sandbox.match_host_user`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerUser {
+    /// `--user` value (`uid:gid`).
+    pub id: String,
+    /// `HOME` inside the container. The agent config mounts live under it,
+    /// and it is handed to the user after create.
+    pub home: String,
+}
+
 /// An environment variable entry for a container.
 ///
 /// `Inherit` entries use Docker's `-e KEY` form (no value in argv), which reads
@@ -93,6 +115,8 @@ pub struct ContainerConfig {
     pub anonymous_volumes: Vec<String>,
     /// Named volumes for volume_ignores when strategy = "named". Cleaned up explicitly on session delete.
     pub named_ignore_volumes: Vec<NamedVolumeMount>,
+    /// Project directories copied in after create instead of bind-mounted.
+    pub seeded_copies: Vec<SeededCopy>,
     pub environment: Vec<EnvEntry>,
     pub cpu_limit: Option<String>,
     pub memory_limit: Option<String>,
@@ -101,8 +125,28 @@ pub struct ContainerConfig {
     /// can access them on SELinux-enforcing hosts (Fedora, RHEL). Set from
     /// `sandbox.selinux_relabel`; only emitted for runtimes that support it.
     pub selinux_relabel: bool,
+    /// `--restart` policy (`on-failure`, `unless-stopped`). `None` keeps the
+    /// runtime default of never restarting.
+    pub restart_policy: Option<String>,
+    /// User the container runs as. `None` runs as the image's user (root for
+    /// the aoe sandbox image).
+    pub user: Option<ContainerUser>,
+    /// `--entrypoint` override. `None` keeps the image's entrypoint.
+    pub entrypoint: Option<String>,
+    /// Startup command after the image. Empty runs the default keepalive
+    /// (`sleep infinity`); agents are launched separately via exec.
+    pub command: Vec<String>,
+    /// Host Docker socket bind-mounted read-write at
+    /// [`CONTAINER_DOCKER_SOCKET`], from `sandbox.mount_docker_socket`. The
+    /// docker CLI is installed in the container after create if the image
+    /// lacks it. Dropped on runtimes that can't share a host socket.
+    pub docker_socket: Option<String>,
 }
 
+/// Where the host Docker socket lands in the container: the docker CLI's
+/// default, so no `DOCKER_HOST` is needed inside.
+pub const CONTAINER_DOCKER_SOCKET: &str = "/var/run/docker.sock";
+
 pub trait ContainerRuntimeInterface {
     /// Check if the container runtime CLI is available
     fn is_available(&self) -> bool;
@@ -123,6 +167,14 @@ pub trait ContainerRuntimeInterface {
 
     fn image_exists_locally(&self, image: &str) -> bool;
 
+    /// Whether `image` was present at the last recent probe or pull, without
+    /// running the runtime. `None` when nothing recent is recorded.
+    fn cached_image_presence(&self, image: &str) -> Option<bool>;
+
+    /// Start a background probe of `image` so a later
+    /// `cached_image_presence` has an answer. No-op while one is cached.
+    fn prefetch_image_presence(&self, image: &str);
+
     /// The manifest digest (`sha256:...`) of the locally-stored image, read
     /// from its repo digest. `None` when the image isn't present locally, was
     /// built rather than pulled (no repo digest), or the runtime can't report
@@ -152,9 +204,23 @@ pub trait ContainerRuntimeInterface {
 
     fn exec(&self, name: &str, cmd: &[&str]) -> Result<std::process::Output>;
 
+    /// Copy `container_path` out of the container to `host_path`. When
+    /// `host_path` is an existing directory the source lands inside it under
+    /// its own name; otherwise it is created at `host_path`.
+    fn copy_from(&self, name: &str, container_path: &str, host_path: &Path) -> Result<()>;
+
+    /// Copy `host_path` into the container at `container_path`, with the same
+    /// directory-destination rule as [`Self::copy_from`].
+    fn copy_into(&self, name: &str, host_path: &Path, container_path: &str) -> Result<()>;
+
     /// Check running state of all containers matching a name prefix in a single call.
     /// Returns a map of container name -> is_running.
     fn batch_running_states(&self, prefix: &str) -> HashMap<String, bool>;
+
+    /// Sample CPU usage of all running containers matching a name prefix in a
+    /// single call. Returns a map of container name -> CPU percent; runtimes
+    /// without a stats command return an empty map.
+    fn batch_cpu_percent(&self, prefix: &str) -> HashMap<String, f64>;
 }
 
 #[cfg(test)]
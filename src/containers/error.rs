@@ -43,6 +43,9 @@ pub enum DockerError {
     #[error("Failed to remove container: {0}")]
     RemoveFailed(String),
 
+    #[error("Failed to copy files: {0}")]
+    CopyFailed(String),
+
     #[error("Docker command failed: {0}")]
     CommandFailed(String),
 
@@ -0,0 +1,117 @@
+//! Remembers whether a sandbox image is present in a runtime's local store.
+//!
+//! `image inspect` is cheap on its own but slow enough to notice when the
+//! new-session dialog runs it on open, so results are kept for
+//! [`IMAGE_PRESENCE_TTL`]. `RuntimeBase` records every probe and marks an
+//! image present after a successful pull; images removed behind aoe's back
+//! are picked up once the entry expires.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How long a probe result is reused.
+pub(crate) const IMAGE_PRESENCE_TTL: Duration = Duration::from_secs(300);
+
+/// Runtime binary plus image reference: Docker and Podman keep separate
+/// stores, so the same image can be present in one and not the other.
+type Key = (&'static str, String);
+
+enum Entry {
+    Known {
+        present: bool,
+        at: Instant,
+    },
+    /// A background probe is running; don't start another.
+    Probing,
+}
+
+pub(crate) struct ImagePresenceCache {
+    entries: Mutex<HashMap<Key, Entry>>,
+}
+
+impl ImagePresenceCache {
+    fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The recorded answer, if it is younger than `ttl`.
+    pub(crate) fn get(&self, binary: &'static str, image: &str, ttl: Duration) -> Option<bool> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(&(binary, image.to_string())) {
+            Some(Entry::Known { present, at }) if at.elapsed() < ttl => Some(*present),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn record(&self, binary: &'static str, image: &str, present: bool) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            (binary, image.to_string()),
+            Entry::Known {
+                present,
+                at: Instant::now(),
+            },
+        );
+    }
+
+    /// Claim the probe for `image`. Returns false when a fresh answer is
+    /// already recorded or another caller is probing; otherwise marks the
+    /// image as being probed and returns true. The caller must `record` the
+    /// result.
+    pub(crate) fn begin_probe(&self, binary: &'static str, image: &str, ttl: Duration) -> bool {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (binary, image.to_string());
+        match entries.get(&key) {
+            Some(Entry::Probing) => false,
+            Some(Entry::Known { at, .. }) if at.elapsed() < ttl => false,
+            _ => {
+                entries.insert(key, Entry::Probing);
+                true
+            }
+        }
+    }
+}
+
+pub(crate) fn cache() -> &'static ImagePresenceCache {
+    static CACHE: OnceLock<ImagePresenceCache> = OnceLock::new();
+    CACHE.get_or_init(ImagePresenceCache::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_expire_and_are_per_runtime() {
+        let cache = ImagePresenceCache::new();
+        let ttl = Duration::from_secs(60);
+        assert_eq!(cache.get("docker", "img:1", ttl), None);
+
+        cache.record("docker", "img:1", false);
+        assert_eq!(cache.get("docker", "img:1", ttl), Some(false));
+        assert_eq!(cache.get("podman", "img:1", ttl), None);
+
+        // A pull overwrites the earlier miss.
+        cache.record("docker", "img:1", true);
+        assert_eq!(cache.get("docker", "img:1", ttl), Some(true));
+
+        assert_eq!(cache.get("docker", "img:1", Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_begin_probe_claims_once_until_recorded() {
+        let cache = ImagePresenceCache::new();
+        let ttl = Duration::from_secs(60);
+        assert!(cache.begin_probe("docker", "img:1", ttl));
+        assert!(!cache.begin_probe("docker", "img:1", ttl));
+        assert_eq!(cache.get("docker", "img:1", ttl), None);
+
+        cache.record("docker", "img:1", true);
+        assert!(!cache.begin_probe("docker", "img:1", ttl));
+        // Stale answers are re-probed.
+        assert!(cache.begin_probe("docker", "img:1", Duration::ZERO));
+    }
+}
@@ -264,6 +264,26 @@ pub fn pick_repo_digest(image: &str, repo_digests: &str) -> Option<String> {
     first
 }
 
+/// Pin `image` to `digest` as `image@sha256:...`, keeping the tag in front
+/// so the friendly name stays readable (docker resolves by the digest and
+/// ignores the tag). An already-pinned reference is returned unchanged.
+pub fn pinned_image_ref(image: &str, digest: &str) -> String {
+    if image.contains('@') {
+        image.to_string()
+    } else {
+        format!("{image}@{digest}")
+    }
+}
+
+/// Split a possibly-pinned reference into the friendly `name:tag` and the
+/// `sha256:...` digest, if any.
+pub fn split_pinned_image(image: &str) -> (&str, Option<&str>) {
+    match image.split_once('@') {
+        Some((name, digest)) => (name, Some(digest)),
+        None => (image, None),
+    }
+}
+
 /// Check whether the configured sandbox `image` has a newer digest in its
 /// registry than the copy stored locally. Returns:
 /// - `Ok(Some(_))` when a different digest is available to pull,
@@ -324,6 +344,18 @@ pub async fn check_for_image_update(image: &str) -> Result<Option<ImageUpdate>>
 mod tests {
     use super::*;
 
+    #[test]
+    fn pinned_image_ref_keeps_tag_and_is_idempotent() {
+        let pinned = pinned_image_ref("ubuntu:latest", "sha256:abc");
+        assert_eq!(pinned, "ubuntu:latest@sha256:abc");
+        assert_eq!(pinned_image_ref(&pinned, "sha256:def"), pinned);
+        assert_eq!(
+            split_pinned_image(&pinned),
+            ("ubuntu:latest", Some("sha256:abc"))
+        );
+        assert_eq!(split_pinned_image("ubuntu:latest"), ("ubuntu:latest", None));
+    }
+
     #[test]
     fn parses_ghcr_reference_with_tag() {
         let r = RegistryRef::parse("ghcr.io/agent-of-empires/aoe-sandbox:latest").unwrap();
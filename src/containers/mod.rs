@@ -1,47 +1,64 @@
 pub mod container_interface;
 pub mod error;
+pub(crate) mod image_presence;
 pub mod image_update;
 mod runtime;
 pub(crate) mod runtime_base;
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use crate::cli::truncate_id;
 use crate::session::{Config, ContainerRuntimeName};
 pub use container_interface::{
-    ContainerConfig, ContainerRuntimeInterface, EnvEntry, NamedVolumeMount, VolumeMount,
+    ContainerConfig, ContainerRuntimeInterface, ContainerUser, EnvEntry, NamedVolumeMount,
+    SeededCopy, VolumeMount,
 };
-use error::Result;
+use error::{DockerError, Result};
 pub use runtime::ContainerRuntime;
 
+/// Returns the container runtime selected in the global config (Docker if
+/// the config can't be loaded).
+pub fn configured_runtime_name() -> ContainerRuntimeName {
+    Config::load()
+        .map(|cfg| cfg.sandbox.container_runtime)
+        .unwrap_or_default()
+}
+
 /// Returns the CLI binary name for the configured container runtime.
 pub fn runtime_binary() -> &'static str {
-    if let Ok(cfg) = Config::load() {
-        match cfg.sandbox.container_runtime {
-            ContainerRuntimeName::AppleContainer => "container",
-            ContainerRuntimeName::Docker => "docker",
-            ContainerRuntimeName::Podman => "podman",
-        }
-    } else {
-        "docker"
+    match configured_runtime_name() {
+        ContainerRuntimeName::AppleContainer => "container",
+        ContainerRuntimeName::Docker => "docker",
+        ContainerRuntimeName::Podman => "podman",
     }
 }
 
 pub fn get_container_runtime() -> ContainerRuntime {
-    if let Ok(cfg) = Config::load() {
-        match cfg.sandbox.container_runtime {
-            ContainerRuntimeName::AppleContainer => ContainerRuntime::apple_container(),
-            ContainerRuntimeName::Docker => ContainerRuntime::docker(),
-            ContainerRuntimeName::Podman => ContainerRuntime::podman(),
-        }
-    } else {
-        ContainerRuntime::default()
+    match configured_runtime_name() {
+        ContainerRuntimeName::AppleContainer => ContainerRuntime::apple_container(),
+        ContainerRuntimeName::Docker => ContainerRuntime::docker(),
+        ContainerRuntimeName::Podman => ContainerRuntime::podman(),
     }
 }
 
+#[cfg(test)]
+thread_local! {
+    static HEALTH_PROBES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Container health listings spawned so far on the calling thread. Test-only
+/// bookkeeping so poll passes can assert they skip the runtime entirely.
+#[cfg(test)]
+pub(crate) fn health_probes() -> usize {
+    HEALTH_PROBES.with(|n| n.get())
+}
+
 /// Check running state of all aoe sandbox containers in a single subprocess call.
 /// Returns a map of container name -> is_running.
 pub fn batch_container_health() -> HashMap<String, bool> {
+    #[cfg(test)]
+    HEALTH_PROBES.with(|n| n.set(n.get() + 1));
     let start = std::time::Instant::now();
     let map = get_container_runtime().batch_running_states("aoe-sandbox-");
     tracing::debug!(
@@ -53,6 +70,20 @@ pub fn batch_container_health() -> HashMap<String, bool> {
     map
 }
 
+/// Sample CPU usage of all running aoe sandbox containers in a single
+/// subprocess call. Returns a map of container name -> CPU percent.
+pub fn batch_container_cpu() -> HashMap<String, f64> {
+    let start = std::time::Instant::now();
+    let map = get_container_runtime().batch_cpu_percent("aoe-sandbox-");
+    tracing::debug!(
+        target: "containers.runtime",
+        count = map.len(),
+        duration_ms = start.elapsed().as_millis() as u64,
+        "batch container cpu fetched",
+    );
+    map
+}
+
 pub struct DockerContainer {
     pub name: String,
     pub image: String,
@@ -158,6 +189,48 @@ impl DockerContainer {
         self.runtime.exec_command(&self.name, options, cmd)
     }
 
+    /// Copy `container_path` out of the container to `host_path` and return
+    /// the host path the copy landed at. Like `docker cp`, copying into an
+    /// existing directory places the source inside it.
+    #[tracing::instrument(target = "containers.runtime", skip_all, fields(name = %self.name, %container_path))]
+    pub fn copy_from(&self, container_path: &str, host_path: &Path) -> Result<PathBuf> {
+        self.ensure_copyable()?;
+        let dest = copy_destination(container_path, host_path);
+        self.runtime
+            .copy_from(&self.name, container_path, host_path)
+            .inspect_err(
+                |e| tracing::warn!(target: "containers.runtime", error = %e, "copy out failed"),
+            )?;
+        Ok(dest)
+    }
+
+    /// Copy `host_path` into the container at `container_path`.
+    #[tracing::instrument(target = "containers.runtime", skip_all, fields(name = %self.name, %container_path))]
+    pub fn copy_into(&self, host_path: &Path, container_path: &str) -> Result<()> {
+        self.ensure_copyable()?;
+        if !host_path.exists() {
+            return Err(DockerError::CopyFailed(format!(
+                "{} does not exist",
+                host_path.display()
+            )));
+        }
+        self.runtime
+            .copy_into(&self.name, host_path, container_path)
+            .inspect_err(
+                |e| tracing::warn!(target: "containers.runtime", error = %e, "copy in failed"),
+            )
+    }
+
+    fn ensure_copyable(&self) -> Result<()> {
+        if !self.runtime.is_daemon_running() {
+            return Err(DockerError::DaemonNotRunning);
+        }
+        if !self.exists()? {
+            return Err(DockerError::ContainerNotFound(self.name.clone()));
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(target = "containers.exec", skip_all, fields(name = %self.name, cmd = ?cmd))]
     pub fn exec(&self, cmd: &[&str]) -> Result<std::process::Output> {
         let result = self.runtime.exec(&self.name, cmd);
@@ -175,10 +248,39 @@ impl DockerContainer {
     }
 }
 
+/// Where `docker cp` puts `container_path` when copying it to `host_path`:
+/// inside `host_path` when that is an existing directory, otherwise at
+/// `host_path` itself.
+fn copy_destination(container_path: &str, host_path: &Path) -> PathBuf {
+    if host_path.is_dir() {
+        let base = Path::new(container_path.trim_end_matches('/'))
+            .file_name()
+            .unwrap_or_default();
+        host_path.join(base)
+    } else {
+        host_path.to_path_buf()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_copy_destination_lands_inside_existing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            copy_destination("/tmp/out/report.txt", dir.path()),
+            dir.path().join("report.txt")
+        );
+        assert_eq!(
+            copy_destination("/tmp/out/", dir.path()),
+            dir.path().join("out")
+        );
+        let file = dir.path().join("renamed.txt");
+        assert_eq!(copy_destination("/tmp/out/report.txt", &file), file);
+    }
+
     #[test]
     fn test_container_generate_name_short_id() {
         let name = DockerContainer::generate_name("abc");
@@ -4,6 +4,8 @@
 //! batch status query) on a `RuntimeKind` discriminant.
 
 use std::collections::HashMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
 
 use serde_json::Value;
 
@@ -46,6 +48,145 @@ impl ContainerRuntime {
     }
 }
 
+/// Unpacks a tar stream on stdin to `$1`, where the archive holds a single
+/// entry named `$2`. Mirrors `docker cp`: an existing directory receives the
+/// entry under its own name, anything else is replaced by it.
+const APPLE_UNPACK_SCRIPT: &str = r#"set -e
+if [ -d "$1" ]; then
+  tar -xf - -C "$1"
+else
+  tmp=$(mktemp -d "$(dirname "$1")/.aoe-cp.XXXXXX")
+  tar -xf - -C "$tmp"
+  mv "$tmp/$2" "$1"
+  rmdir "$tmp"
+fi"#;
+
+impl ContainerRuntime {
+    /// Apple Container has no `cp` subcommand, so both directions stream a tar
+    /// archive through `exec`, which only works on a running container.
+    fn require_running_for_copy(&self, name: &str) -> Result<()> {
+        if self.is_container_running(name)? {
+            Ok(())
+        } else {
+            Err(DockerError::CopyFailed(format!(
+                "container {name} is not running; start the session first"
+            )))
+        }
+    }
+
+    fn apple_copy_from(&self, name: &str, container_path: &str, host_path: &Path) -> Result<()> {
+        self.require_running_for_copy(name)?;
+        let (parent, base) = split_container_path(container_path)?;
+        let (extract_dir, dest) = if host_path.is_dir() {
+            (host_path.to_path_buf(), host_path.join(base))
+        } else {
+            let parent = host_path
+                .parent()
+                .filter(|p| !p.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            (parent.to_path_buf(), host_path.to_path_buf())
+        };
+        // Unpack into a scratch dir beside the destination so the final
+        // rename stays on one filesystem.
+        let scratch = tempfile::tempdir_in(&extract_dir)?;
+        let mut pack = self
+            .base
+            .command()
+            .args(["exec", name, "tar", "-C", parent, "-cf", "-", base])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let archive = pack.stdout.take().ok_or_else(|| {
+            DockerError::CopyFailed("container tar produced no output".to_string())
+        })?;
+        let unpack = Command::new("tar")
+            .arg("-xf")
+            .arg("-")
+            .arg("-C")
+            .arg(scratch.path())
+            .stdin(archive)
+            .output()?;
+        let pack = pack.wait_with_output()?;
+        for out in [&pack, &unpack] {
+            if !out.status.success() {
+                let stderr = String::from_utf8_lossy(&out.stderr);
+                return Err(DockerError::CopyFailed(stderr.trim().to_string()));
+            }
+        }
+        std::fs::rename(scratch.path().join(base), dest)?;
+        Ok(())
+    }
+
+    fn apple_copy_into(&self, name: &str, host_path: &Path, container_path: &str) -> Result<()> {
+        self.require_running_for_copy(name)?;
+        let base = host_path
+            .file_name()
+            .ok_or_else(|| {
+                DockerError::CopyFailed(format!("nothing to copy at {}", host_path.display()))
+            })?
+            .to_string_lossy()
+            .into_owned();
+        let parent = host_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let mut pack = Command::new("tar")
+            .arg("-C")
+            .arg(parent)
+            .arg("-cf")
+            .arg("-")
+            .arg(&base)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let archive = pack
+            .stdout
+            .take()
+            .ok_or_else(|| DockerError::CopyFailed("host tar produced no output".to_string()))?;
+        let unpack = self
+            .base
+            .command()
+            .args([
+                "exec",
+                "-i",
+                name,
+                "sh",
+                "-c",
+                APPLE_UNPACK_SCRIPT,
+                "sh",
+                container_path,
+                &base,
+            ])
+            .stdin(archive)
+            .output()?;
+        let pack = pack.wait_with_output()?;
+        for out in [&pack, &unpack] {
+            if !out.status.success() {
+                let stderr = String::from_utf8_lossy(&out.stderr);
+                return Err(DockerError::CopyFailed(stderr.trim().to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Split a container path into the directory to `tar -C` into and the entry
+/// name to archive. Relative paths resolve against the exec working dir.
+fn split_container_path(path: &str) -> Result<(&str, &str)> {
+    let trimmed = path.trim_end_matches('/');
+    let (parent, base) = match trimmed.rsplit_once('/') {
+        Some(("", base)) => ("/", base),
+        Some((parent, base)) => (parent, base),
+        None => (".", trimmed),
+    };
+    if base.is_empty() || base == "." || base == ".." {
+        return Err(DockerError::CopyFailed(format!(
+            "'{path}' does not name a file or directory"
+        )));
+    }
+    Ok((parent, base))
+}
+
 impl Default for ContainerRuntime {
     fn default() -> Self {
         Self::docker()
@@ -69,6 +210,14 @@ impl ContainerRuntimeInterface for ContainerRuntime {
         self.base.image_exists_locally(image)
     }
 
+    fn cached_image_presence(&self, image: &str) -> Option<bool> {
+        self.base.cached_image_presence(image)
+    }
+
+    fn prefetch_image_presence(&self, image: &str) {
+        self.base.prefetch_image_presence(image)
+    }
+
     fn local_image_digest(&self, image: &str) -> Option<String> {
         match self.kind {
             RuntimeKind::Docker | RuntimeKind::Podman => {
@@ -236,6 +385,26 @@ impl ContainerRuntimeInterface for ContainerRuntime {
         self.base.exec(name, cmd)
     }
 
+    fn copy_from(&self, name: &str, container_path: &str, host_path: &Path) -> Result<()> {
+        match self.kind {
+            RuntimeKind::Docker | RuntimeKind::Podman => self.base.copy(
+                &format!("{name}:{container_path}"),
+                &host_path.to_string_lossy(),
+            ),
+            RuntimeKind::AppleContainer => self.apple_copy_from(name, container_path, host_path),
+        }
+    }
+
+    fn copy_into(&self, name: &str, host_path: &Path, container_path: &str) -> Result<()> {
+        match self.kind {
+            RuntimeKind::Docker | RuntimeKind::Podman => self.base.copy(
+                &host_path.to_string_lossy(),
+                &format!("{name}:{container_path}"),
+            ),
+            RuntimeKind::AppleContainer => self.apple_copy_into(name, host_path, container_path),
+        }
+    }
+
     fn batch_running_states(&self, prefix: &str) -> HashMap<String, bool> {
         match self.kind {
             RuntimeKind::Docker | RuntimeKind::Podman => {
@@ -279,6 +448,53 @@ impl ContainerRuntimeInterface for ContainerRuntime {
             }
         }
     }
+
+    fn batch_cpu_percent(&self, prefix: &str) -> HashMap<String, f64> {
+        match self.kind {
+            RuntimeKind::Docker | RuntimeKind::Podman => {
+                let output = self
+                    .base
+                    .command()
+                    .args([
+                        "stats",
+                        "--no-stream",
+                        "--format",
+                        "{{.Name}}\t{{.CPUPerc}}",
+                    ])
+                    .output();
+
+                let output = match output {
+                    Ok(o) if o.status.success() => o,
+                    _ => return HashMap::new(),
+                };
+
+                parse_cpu_stats(&String::from_utf8_lossy(&output.stdout), prefix)
+            }
+            RuntimeKind::AppleContainer => {
+                let _ = prefix;
+                HashMap::new()
+            }
+        }
+    }
+}
+
+/// Parse `stats --format '{{.Name}}\t{{.CPUPerc}}'` output into a map of
+/// container name -> CPU percent, keeping only names that start with `prefix`.
+/// Rows whose percentage doesn't parse (e.g. `--` for a container that is
+/// shutting down) are dropped.
+fn parse_cpu_stats(stdout: &str, prefix: &str) -> HashMap<String, f64> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '\t');
+            let name = parts.next()?.trim();
+            let cpu = parts.next()?.trim().trim_end_matches('%');
+            if name.is_empty() || !name.starts_with(prefix) {
+                return None;
+            }
+            Some((name.to_string(), cpu.parse::<f64>().ok()?))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -396,10 +612,39 @@ mod tests {
         assert!(rt.base.supports_read_only_volumes);
         assert!(rt.base.supports_remove_volumes);
         assert!(rt.base.supports_named_volumes);
+        assert!(rt.base.supports_restart_policy);
         assert_eq!(rt.base.remove_subcommand, "rm");
         assert_eq!(rt.base.pull_prefix, &["pull"]);
     }
 
+    #[test]
+    fn test_split_container_path() {
+        assert_eq!(
+            split_container_path("/workspace/out/report.pdf").unwrap(),
+            ("/workspace/out", "report.pdf")
+        );
+        assert_eq!(
+            split_container_path("/tmp/build/").unwrap(),
+            ("/tmp", "build")
+        );
+        assert_eq!(split_container_path("/etc").unwrap(), ("/", "etc"));
+        assert_eq!(
+            split_container_path("notes.txt").unwrap(),
+            (".", "notes.txt")
+        );
+        assert!(split_container_path("/").is_err());
+        assert!(split_container_path("/tmp/..").is_err());
+    }
+
+    #[test]
+    fn test_parse_cpu_stats() {
+        let out =
+            "aoe-sandbox-aaaa1111\t0.42%\nother-container\t55.00%\naoe-sandbox-bbbb2222\t--\n";
+        let map = parse_cpu_stats(out, "aoe-sandbox-");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("aoe-sandbox-aaaa1111"), Some(&0.42));
+    }
+
     #[test]
     fn test_podman_exec_command_format_matches_docker() {
         // The CLI surfaces this string to the user via tmux; it must not
@@ -1,6 +1,9 @@
-use super::container_interface::{docker_env_args, ContainerConfig};
+use super::container_interface::{
+    docker_env_args, ContainerConfig, ContainerUser, SeededCopy, CONTAINER_DOCKER_SOCKET,
+};
 use super::error::{DockerError, Result};
-use std::process::Command;
+use super::image_presence::{self, IMAGE_PRESENCE_TTL};
+use std::process::{Command, Stdio};
 
 /// Shared implementation for container runtimes.
 ///
@@ -8,6 +11,7 @@ use std::process::Command;
 /// as configuration, then provides a single implementation of all the shared logic.
 /// Runtime-specific methods (like container existence checks or running state detection)
 /// remain in the individual runtime impls.
+#[derive(Clone, Copy)]
 pub(crate) struct RuntimeBase {
     /// CLI binary name (e.g., "docker", "container")
     pub binary: &'static str,
@@ -28,6 +32,22 @@ pub(crate) struct RuntimeBase {
     /// Whether this runtime supports the `:z`/`:Z` SELinux relabel volume flag
     /// (Docker and Podman do; Apple Container does not).
     pub supports_selinux_relabel: bool,
+    /// Whether `run` accepts `--restart` (Docker and Podman do; Apple
+    /// Container does not).
+    pub supports_restart_policy: bool,
+    /// Whether a host unix socket can be bind-mounted into the container
+    /// (Docker and Podman; Apple Container runs each container in its own
+    /// VM, which can't reach the host daemon's socket).
+    pub supports_docker_socket: bool,
+    /// Whether `run` needs `--userns=keep-id` to map `--user` to the host
+    /// user when the runtime is rootless (Podman). Without it a rootless
+    /// container's UIDs are shifted into the subordinate range, so files
+    /// the matched user writes would not be owned by the host user.
+    pub keep_id_when_rootless: bool,
+    /// Whether `--cpus` only takes a whole number and `-m` only megabyte
+    /// granularity with uppercase units (Apple Container). Docker-style
+    /// limits are translated at create time.
+    pub whole_unit_limits: bool,
 }
 
 impl RuntimeBase {
@@ -41,6 +61,10 @@ impl RuntimeBase {
         supports_remove_volumes: true,
         supports_named_volumes: true,
         supports_selinux_relabel: true,
+        supports_restart_policy: true,
+        supports_docker_socket: true,
+        keep_id_when_rootless: false,
+        whole_unit_limits: false,
     };
 
     pub const APPLE_CONTAINER: Self = Self {
@@ -53,6 +77,10 @@ impl RuntimeBase {
         supports_remove_volumes: false,
         supports_named_volumes: false,
         supports_selinux_relabel: false,
+        supports_restart_policy: false,
+        supports_docker_socket: false,
+        keep_id_when_rootless: false,
+        whole_unit_limits: true,
     };
 
     pub const PODMAN: Self = Self {
@@ -68,6 +96,10 @@ impl RuntimeBase {
         supports_remove_volumes: true,
         supports_named_volumes: true,
         supports_selinux_relabel: true,
+        supports_restart_policy: true,
+        supports_docker_socket: true,
+        keep_id_when_rootless: true,
+        whole_unit_limits: false,
     };
 
     pub fn command(&self) -> Command {
@@ -101,11 +133,33 @@ impl RuntimeBase {
     }
 
     pub fn image_exists_locally(&self, image: &str) -> bool {
-        self.command()
+        let present = self
+            .command()
             .args(["image", "inspect", image])
             .output()
             .map(|o| o.status.success())
-            .unwrap_or(false)
+            .unwrap_or(false);
+        image_presence::cache().record(self.binary, image, present);
+        present
+    }
+
+    /// The last probe's answer for `image`, if recent. Never spawns the
+    /// runtime.
+    pub fn cached_image_presence(&self, image: &str) -> Option<bool> {
+        image_presence::cache().get(self.binary, image, IMAGE_PRESENCE_TTL)
+    }
+
+    /// Probe `image` on a background thread unless a recent answer is
+    /// cached or a probe is already running.
+    pub fn prefetch_image_presence(&self, image: &str) {
+        if !image_presence::cache().begin_probe(self.binary, image, IMAGE_PRESENCE_TTL) {
+            return;
+        }
+        let base = *self;
+        let image = image.to_string();
+        std::thread::spawn(move || {
+            base.image_exists_locally(&image);
+        });
     }
 
     pub fn pull_image(&self, image: &str) -> Result<()> {
@@ -141,6 +195,7 @@ impl RuntimeBase {
             duration_ms = dur_ms,
             "image pull completed"
         );
+        image_presence::cache().record(self.binary, image, true);
         Ok(())
     }
 
@@ -212,6 +267,21 @@ impl RuntimeBase {
             args.push(mount);
         }
 
+        if let Some(socket) = &config.docker_socket {
+            if self.supports_docker_socket {
+                // No SELinux relabel even with `selinux_relabel` on:
+                // relabeling the daemon's socket would change it for the host.
+                args.push("-v".to_string());
+                args.push(format!("{socket}:{CONTAINER_DOCKER_SOCKET}"));
+            } else {
+                tracing::warn!(
+                    target: "containers.runtime",
+                    runtime = %self.name,
+                    "mount_docker_socket is not supported; the host Docker socket is not mounted"
+                );
+            }
+        }
+
         for path in &config.anonymous_volumes {
             args.push("-v".to_string());
             args.push(path.clone());
@@ -245,21 +315,81 @@ impl RuntimeBase {
 
         if let Some(cpu) = &config.cpu_limit {
             args.push("--cpus".to_string());
-            args.push(cpu.clone());
+            args.push(self.cpu_limit_arg(cpu));
         }
 
         if let Some(mem) = &config.memory_limit {
             args.push("-m".to_string());
-            args.push(mem.clone());
+            args.push(self.memory_limit_arg(mem));
+        }
+
+        if let Some(policy) = &config.restart_policy {
+            if self.supports_restart_policy {
+                args.push("--restart".to_string());
+                args.push(policy.clone());
+            } else {
+                tracing::warn!(
+                    target: "containers.runtime",
+                    runtime = %self.name,
+                    %policy,
+                    "restart policies are not supported; container will not restart automatically"
+                );
+            }
+        }
+
+        if let Some(user) = &config.user {
+            args.extend(self.user_args(user, !nix::unistd::geteuid().is_root()));
+        }
+
+        if let Some(entrypoint) = &config.entrypoint {
+            args.push("--entrypoint".to_string());
+            args.push(entrypoint.clone());
         }
 
         args.push(image.to_string());
-        args.push("sleep".to_string());
-        args.push("infinity".to_string());
+        if config.command.is_empty() {
+            args.push("sleep".to_string());
+            args.push("infinity".to_string());
+        } else {
+            args.extend(config.command.iter().cloned());
+        }
 
         args
     }
 
+    /// Translate a configured CPU limit into this runtime's `--cpus` value.
+    /// Whole-unit runtimes round a fractional count up so the container never
+    /// gets less than asked for.
+    fn cpu_limit_arg(&self, cpu: &str) -> String {
+        if !self.whole_unit_limits {
+            return cpu.to_string();
+        }
+        match cpu.parse::<f64>() {
+            Ok(n) if n > 0.0 => (n.ceil() as u64).to_string(),
+            _ => cpu.to_string(),
+        }
+    }
+
+    /// Translate a Docker-style memory limit (`512m`, `8g`) into this
+    /// runtime's `-m` value. Whole-unit runtimes get an uppercase unit, and a
+    /// byte count is rounded up to whole megabytes.
+    fn memory_limit_arg(&self, mem: &str) -> String {
+        if !self.whole_unit_limits {
+            return mem.to_string();
+        }
+        let Some(unit) = mem.chars().last() else {
+            return mem.to_string();
+        };
+        let Ok(amount) = mem[..mem.len() - unit.len_utf8()].parse::<u64>() else {
+            return mem.to_string();
+        };
+        match unit.to_ascii_lowercase() {
+            'b' => format!("{}M", amount.div_ceil(1024 * 1024).max(1)),
+            'k' | 'm' | 'g' => format!("{amount}{}", unit.to_ascii_uppercase()),
+            _ => mem.to_string(),
+        }
+    }
+
     /// Run the container creation command (after existence has already been checked by the caller).
     pub fn run_create(&self, name: &str, image: &str, config: &ContainerConfig) -> Result<String> {
         let args = self.build_create_args(name, image, config);
@@ -291,9 +421,166 @@ impl RuntimeBase {
         }
 
         let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        for copy in &config.seeded_copies {
+            // A half-seeded container would be reused as-is by the next
+            // start (a running container is never recreated), so drop it
+            // and let the retry create it from scratch.
+            if let Err(e) = self.seed_copy(name, copy) {
+                if let Err(rm) = self.remove(name, true) {
+                    tracing::warn!(target: "containers.runtime", %name,
+                        "failed to remove container after seeding failed: {}", rm);
+                }
+                return Err(e);
+            }
+        }
+        if let Some(user) = &config.user {
+            self.hand_home_to_user(name, user, config);
+        }
+        if config.docker_socket.is_some() && self.supports_docker_socket {
+            self.ensure_docker_client(name);
+        }
         Ok(container_id)
     }
 
+    /// Stream `copy.host_path` (minus its excludes) into the container as a
+    /// tar archive. Runs as root so the target dir can be created anywhere;
+    /// tar keeps the host file ownership, which matches the container user
+    /// when `match_host_user` is on.
+    fn seed_copy(&self, name: &str, copy: &SeededCopy) -> Result<()> {
+        tracing::info!(target: "containers.runtime", runtime = %self.name, %name,
+            host = %copy.host_path, container = %copy.container_path, "seeding project copy");
+        let mut pack = Command::new("tar");
+        pack.arg("-C").arg(&copy.host_path);
+        for pattern in &copy.excludes {
+            pack.arg(format!("--exclude={pattern}"));
+        }
+        let mut pack = pack
+            .args(["-cf", "-", "."])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let archive = pack
+            .stdout
+            .take()
+            .ok_or_else(|| DockerError::CopyFailed("host tar produced no output".to_string()))?;
+        // Drain tar's stderr while the archive streams: a chatty tar (one
+        // warning per unreadable file) would otherwise fill the pipe and
+        // block before it finishes writing stdout.
+        let pack_stderr = pack.stderr.take().map(|mut stderr| {
+            std::thread::spawn(move || {
+                let mut buf = String::new();
+                let _ = std::io::Read::read_to_string(&mut stderr, &mut buf);
+                buf
+            })
+        });
+        let unpack = self
+            .command()
+            .args([
+                "exec",
+                "-i",
+                "-u",
+                "0",
+                name,
+                "sh",
+                "-c",
+                r#"mkdir -p "$1" && tar -xf - -C "$1""#,
+                "sh",
+                &copy.container_path,
+            ])
+            .stdin(archive)
+            .output()?;
+        let pack_status = pack.wait()?;
+        let pack_stderr = pack_stderr
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default();
+        let unpack_stderr = String::from_utf8_lossy(&unpack.stderr);
+        for (status, stderr) in [
+            (pack_status, pack_stderr.as_str()),
+            (unpack.status, unpack_stderr.as_ref()),
+        ] {
+            if !status.success() {
+                return Err(DockerError::CopyFailed(format!(
+                    "seeding {} into {}: {}",
+                    copy.host_path,
+                    copy.container_path,
+                    stderr.trim()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// `run` args for the matched host user. A rootless runtime also needs
+    /// its user namespace to keep the host UID (see `keep_id_when_rootless`).
+    fn user_args(&self, user: &ContainerUser, rootless: bool) -> Vec<String> {
+        let mut args = Vec::new();
+        if rootless && self.keep_id_when_rootless {
+            args.push("--userns=keep-id".to_string());
+        }
+        args.push("--user".to_string());
+        args.push(user.id.clone());
+        args
+    }
+
+    /// Give a non-root container user its home and keep the image's agent
+    /// CLIs, installed under `/root`, reachable. Mount points are pruned and
+    /// `-xdev` keeps `find` on the container filesystem, so host files behind
+    /// the config mounts are never re-owned. Best-effort: a failure leaves the
+    /// agent unable to write outside its mounted config dirs, which surfaces at
+    /// launch rather than here.
+    fn hand_home_to_user(&self, name: &str, user: &ContainerUser, config: &ContainerConfig) {
+        let output = self
+            .command()
+            .args(hand_home_args(name, user, config))
+            .output();
+        match output {
+            Ok(out) if out.status.success() => {}
+            Ok(out) => tracing::warn!(target: "containers.runtime",
+                %name,
+                "failed to hand {} to {}: {}",
+                user.home,
+                user.id,
+                String::from_utf8_lossy(&out.stderr).trim()
+            ),
+            Err(e) => tracing::warn!(target: "containers.runtime",
+                %name,
+                "failed to hand {} to {}: {}",
+                user.home,
+                user.id,
+                e
+            ),
+        }
+    }
+
+    /// Install the docker CLI in the container when the image lacks one, so a
+    /// mounted host socket is usable. Runs on a background thread: an
+    /// `apt-get` round trip must not hold up session creation, and the agent
+    /// only needs the CLI once it decides to call docker. Best effort: images
+    /// without apt, or without network access, keep working and just can't
+    /// run docker.
+    fn ensure_docker_client(&self, name: &str) {
+        const INSTALL: &str = "command -v docker >/dev/null 2>&1 || { \
+            apt-get update -qq && \
+            { apt-get install -y -qq --no-install-recommends docker-cli \
+              || apt-get install -y -qq --no-install-recommends docker.io; }; }";
+        let mut cmd = self.command();
+        cmd.args(["exec", "-u", "0", name, "sh", "-c", INSTALL]);
+        let name = name.to_string();
+        std::thread::spawn(move || match cmd.output() {
+            Ok(out) if out.status.success() => {}
+            Ok(out) => tracing::warn!(target: "containers.runtime",
+                %name,
+                "failed to install the docker CLI: {}",
+                String::from_utf8_lossy(&out.stderr).trim()
+            ),
+            Err(e) => tracing::warn!(target: "containers.runtime",
+                %name,
+                "failed to install the docker CLI: {}",
+                e
+            ),
+        });
+    }
+
     pub fn start_container(&self, name: &str) -> Result<()> {
         tracing::info!(target: "containers.runtime", runtime = %self.name, %name, "starting container");
         let output = self.command().args(["start", name]).output()?;
@@ -410,6 +697,19 @@ impl RuntimeBase {
         }
     }
 
+    /// `cp SRC DST` where one side is `container:path`. Docker and Podman
+    /// share `docker cp` semantics: a directory destination receives the
+    /// source under its own name, anything else is created or overwritten.
+    pub fn copy(&self, src: &str, dst: &str) -> Result<()> {
+        tracing::debug!(target: "containers.runtime", runtime = %self.name, %src, %dst, "copying");
+        let output = self.command().args(["cp", src, dst]).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(DockerError::CopyFailed(stderr.trim().to_string()));
+        }
+        Ok(())
+    }
+
     pub fn exec(&self, name: &str, cmd: &[&str]) -> Result<std::process::Output> {
         let mut args = vec!["exec", name];
         args.extend(cmd);
@@ -420,6 +720,50 @@ impl RuntimeBase {
     }
 }
 
+/// `exec` args that create `user.home`, chown it to the user without
+/// touching any mount under it, and open `/root` for traversal.
+fn hand_home_args(name: &str, user: &ContainerUser, config: &ContainerConfig) -> Vec<String> {
+    let prefix = format!("{}/", user.home.trim_end_matches('/'));
+    let mounts: Vec<&str> = config
+        .volumes
+        .iter()
+        .map(|v| v.container_path.as_str())
+        .chain(config.anonymous_volumes.iter().map(String::as_str))
+        .chain(
+            config
+                .named_ignore_volumes
+                .iter()
+                .map(|v| v.container_path.as_str()),
+        )
+        .filter(|path| path.starts_with(&prefix))
+        .collect();
+    let mut args: Vec<String> = [
+        "exec",
+        "-u",
+        "0",
+        name,
+        "sh",
+        "-c",
+        r#"mkdir -p "$0" && chmod a+x /root && find "$0" -xdev "$@""#,
+        &user.home,
+    ]
+    .iter()
+    .map(|a| a.to_string())
+    .collect();
+    if !mounts.is_empty() {
+        args.push("(".to_string());
+        for (i, path) in mounts.iter().enumerate() {
+            if i > 0 {
+                args.push("-o".to_string());
+            }
+            args.extend(["-path".to_string(), path.to_string()]);
+        }
+        args.extend([")", "-prune", "-o"].map(String::from));
+    }
+    args.extend(["-exec", "chown", &user.id, "{}", "+"].map(String::from));
+    args
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -450,6 +794,116 @@ mod tests {
         assert!(args.contains(&"/host/path:/container/path:ro".to_string()));
     }
 
+    #[test]
+    fn test_build_create_args_default_keepalive() {
+        let base = RuntimeBase::DOCKER;
+        let config = ContainerConfig {
+            working_dir: "/workspace".to_string(),
+            ..Default::default()
+        };
+
+        let args = base.build_create_args("test-container", "alpine:latest", &config);
+
+        assert!(!args.contains(&"--entrypoint".to_string()));
+        assert!(!args.contains(&"--user".to_string()));
+        assert_eq!(
+            args[args.len() - 3..],
+            ["alpine:latest", "sleep", "infinity"]
+        );
+    }
+
+    #[test]
+    fn test_build_create_args_user_entrypoint_and_command_override() {
+        let base = RuntimeBase::DOCKER;
+        let config = ContainerConfig {
+            working_dir: "/workspace".to_string(),
+            user: Some(ContainerUser {
+                id: "1000:1000".to_string(),
+                home: "/home/aoe".to_string(),
+            }),
+            entrypoint: Some("/usr/bin/tini".to_string()),
+            command: vec!["--".to_string(), "sleep".to_string(), "1d".to_string()],
+            ..Default::default()
+        };
+
+        let args = base.build_create_args("test-container", "alpine:latest", &config);
+
+        let image = args.iter().position(|a| a == "alpine:latest").unwrap();
+        assert_eq!(
+            args[image - 4..image],
+            ["--user", "1000:1000", "--entrypoint", "/usr/bin/tini"]
+        );
+        assert_eq!(args[image + 1..], ["--", "sleep", "1d"]);
+    }
+
+    #[test]
+    fn test_user_args_keep_host_uid_on_rootless_podman() {
+        let user = ContainerUser {
+            id: "1000:1000".to_string(),
+            home: "/home/aoe".to_string(),
+        };
+        assert_eq!(
+            RuntimeBase::PODMAN.user_args(&user, true),
+            ["--userns=keep-id", "--user", "1000:1000"]
+        );
+        assert_eq!(
+            RuntimeBase::PODMAN.user_args(&user, false),
+            ["--user", "1000:1000"]
+        );
+        assert_eq!(
+            RuntimeBase::DOCKER.user_args(&user, true),
+            ["--user", "1000:1000"]
+        );
+    }
+
+    #[test]
+    fn test_hand_home_args_prune_mounts_under_home() {
+        let user = ContainerUser {
+            id: "1000:1000".to_string(),
+            home: "/home/aoe".to_string(),
+        };
+        let config = ContainerConfig {
+            volumes: vec![
+                VolumeMount {
+                    host_path: "/host/.claude".to_string(),
+                    container_path: "/home/aoe/.claude".to_string(),
+                    read_only: false,
+                },
+                VolumeMount {
+                    host_path: "/host/project".to_string(),
+                    container_path: "/workspace/project".to_string(),
+                    read_only: false,
+                },
+            ],
+            anonymous_volumes: vec!["/home/aoe/.cache".to_string()],
+            ..Default::default()
+        };
+
+        let args = hand_home_args("test-container", &user, &config);
+
+        let find = args.iter().position(|a| a == "/home/aoe").unwrap();
+        assert_eq!(
+            args[find + 1..],
+            [
+                "(",
+                "-path",
+                "/home/aoe/.claude",
+                "-o",
+                "-path",
+                "/home/aoe/.cache",
+                ")",
+                "-prune",
+                "-o",
+                "-exec",
+                "chown",
+                "1000:1000",
+                "{}",
+                "+"
+            ]
+        );
+        assert!(!args.contains(&"/workspace/project".to_string()));
+    }
+
     #[test]
     fn test_build_create_args_read_only_not_supported() {
         let base = RuntimeBase::APPLE_CONTAINER;
@@ -525,6 +979,51 @@ mod tests {
         assert!(!args.iter().any(|a| a.contains(":z")));
     }
 
+    #[test]
+    fn test_build_create_args_restart_policy() {
+        let config = ContainerConfig {
+            working_dir: "/workspace".to_string(),
+            restart_policy: Some("unless-stopped".to_string()),
+            ..Default::default()
+        };
+
+        let args = RuntimeBase::DOCKER.build_create_args("c", "alpine:latest", &config);
+        let pos = args.iter().position(|a| a == "--restart").unwrap();
+        assert_eq!(args[pos + 1], "unless-stopped");
+        assert!(pos < args.iter().position(|a| a == "alpine:latest").unwrap());
+
+        // Apple Container has no restart policies; the flag is dropped.
+        let args = RuntimeBase::APPLE_CONTAINER.build_create_args("c", "alpine:latest", &config);
+        assert!(!args.contains(&"--restart".to_string()));
+
+        let args = RuntimeBase::DOCKER.build_create_args(
+            "c",
+            "alpine:latest",
+            &ContainerConfig {
+                working_dir: "/workspace".to_string(),
+                ..Default::default()
+            },
+        );
+        assert!(!args.contains(&"--restart".to_string()));
+    }
+
+    #[test]
+    fn test_build_create_args_docker_socket() {
+        let config = ContainerConfig {
+            working_dir: "/workspace".to_string(),
+            docker_socket: Some("/run/user/1000/docker.sock".to_string()),
+            selinux_relabel: true,
+            ..Default::default()
+        };
+
+        let args = RuntimeBase::DOCKER.build_create_args("c", "alpine:latest", &config);
+        assert!(args.contains(&"/run/user/1000/docker.sock:/var/run/docker.sock".to_string()));
+
+        // Apple Container can't share a host socket; the mount is dropped.
+        let args = RuntimeBase::APPLE_CONTAINER.build_create_args("c", "alpine:latest", &config);
+        assert!(!args.iter().any(|a| a.contains("docker.sock")));
+    }
+
     #[test]
     fn test_exec_command_with_options() {
         let base = RuntimeBase::DOCKER;
@@ -590,6 +1089,48 @@ mod tests {
         assert!(args.contains(&"infinity".to_string()));
     }
 
+    fn limit_args(base: &RuntimeBase, cpu: &str, mem: &str) -> (String, String) {
+        let config = ContainerConfig {
+            working_dir: "/workspace".to_string(),
+            cpu_limit: Some(cpu.to_string()),
+            memory_limit: Some(mem.to_string()),
+            ..Default::default()
+        };
+        let args = base.build_create_args("test", "ubuntu:latest", &config);
+        let after = |flag: &str| {
+            let i = args.iter().position(|a| a == flag).unwrap();
+            args[i + 1].clone()
+        };
+        (after("--cpus"), after("-m"))
+    }
+
+    #[test]
+    fn test_docker_and_podman_pass_limits_through() {
+        for base in [RuntimeBase::DOCKER, RuntimeBase::PODMAN] {
+            assert_eq!(
+                limit_args(&base, "1.5", "512m"),
+                ("1.5".to_string(), "512m".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_apple_container_translates_limits() {
+        let base = RuntimeBase::APPLE_CONTAINER;
+        assert_eq!(
+            limit_args(&base, "2", "8g"),
+            ("2".to_string(), "8G".to_string())
+        );
+        assert_eq!(
+            limit_args(&base, "1.5", "512m"),
+            ("2".to_string(), "512M".to_string())
+        );
+        assert_eq!(limit_args(&base, "4", "2048K").1, "2048K");
+        // Bytes round up to whole megabytes.
+        assert_eq!(limit_args(&base, "4", "1048577b").1, "2M");
+        assert_eq!(limit_args(&base, "4", "10b").1, "1M");
+    }
+
     #[test]
     fn test_build_create_args_inherit_env_no_value_in_argv() {
         let base = RuntimeBase::DOCKER;
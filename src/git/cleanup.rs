@@ -1,6 +1,7 @@
 //! Shared worktree cleanup utilities used by both CLI and TUI deletion paths.
 
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 
 use crate::containers::DockerContainer;
 use crate::session::Instance;
@@ -157,6 +158,28 @@ pub fn is_dirty_worktree_error(error: &str) -> bool {
         || (lower.contains("--force") && lower.contains("contains"))
 }
 
+/// Returns true if a `git worktree remove` stderr indicates the worktree is
+/// locked (`git worktree lock`). Plain `--force` does not override a lock;
+/// the caller has to unlock first.
+pub fn is_locked_worktree_error(error: &str) -> bool {
+    error
+        .to_lowercase()
+        .contains("cannot remove a locked working tree")
+}
+
+/// Build a "worktree is locked" error message for the host-side pre-delete
+/// check in `perform_deletion`. Returns `None` if the worktree is not locked.
+/// Force delete unlocks the worktree before removing it.
+pub fn locked_worktree_message(worktree_path: &Path) -> Option<String> {
+    let reason = GitWorktree::lock_reason(worktree_path)?;
+    let mut out = String::from("worktree is locked");
+    if !reason.is_empty() {
+        out.push_str(&format!(" ({})", reason));
+    }
+    out.push_str(", use force delete to unlock and remove it");
+    Some(out)
+}
+
 /// Enumerate modified, staged, and untracked files inside a worktree using
 /// libgit2. Returns a vec of `"<status> <path>"` entries (e.g.
 /// `"modified src/foo.rs"`, `"untracked debug.log"`).
@@ -254,6 +277,9 @@ pub fn dirty_worktree_message(worktree_path: &Path) -> Option<String> {
 /// (capped at `MAX_DIRTY_FILES_LISTED`) so the user can decide whether
 /// re-running with "force delete" is safe.
 pub fn enrich_worktree_remove_error(stderr: &str, worktree_path: &Path) -> String {
+    if is_locked_worktree_error(stderr) {
+        return locked_worktree_message(worktree_path).unwrap_or_else(|| stderr.to_string());
+    }
     if !is_dirty_worktree_error(stderr) {
         return stderr.to_string();
     }
@@ -518,6 +544,15 @@ pub fn remove_managed_worktree(
         // .gitmodules.
         deinit_submodules_if_present(worktree_path);
 
+        // `--force` does not override `git worktree lock`; a forced delete
+        // lifts the lock first. Without force the lock is left alone and the
+        // remove below fails with a "locked" error the caller surfaces.
+        if force && GitWorktree::lock_reason(worktree_path).is_some() {
+            if let Err(e) = git_wt.unlock_worktree(worktree_path) {
+                tracing::debug!(target: "git.worktree", error = %e, "git worktree unlock failed");
+            }
+        }
+
         match git_wt.remove_worktree(worktree_path, force) {
             Ok(()) => {
                 worktree_removed = true;
@@ -635,6 +670,83 @@ fn try_sandbox_dir_cleanup(
     }
 }
 
+/// Result of pruning one repository in [`prune_session_repos`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepoPruneOutcome {
+    /// `git worktree prune` ran; holds the number of stale entries removed.
+    Pruned(usize),
+    /// The repo no longer exists on disk (or is no longer a git repo).
+    Missing,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct RepoPrune {
+    pub repo: PathBuf,
+    pub outcome: RepoPruneOutcome,
+}
+
+/// Prune stale worktree entries in every main repo referenced by the given
+/// sessions (worktree sessions and each repo of a workspace session). Each
+/// repo is pruned once no matter how many sessions point at it; results are
+/// ordered by repo path.
+pub fn prune_session_repos(instances: &[Instance]) -> Vec<RepoPrune> {
+    let mut repos = BTreeSet::new();
+    for inst in instances {
+        if let Some(wt) = &inst.worktree_info {
+            repos.insert(PathBuf::from(&wt.main_repo_path));
+        }
+        if let Some(ws) = &inst.workspace_info {
+            repos.extend(ws.repos.iter().map(|r| PathBuf::from(&r.main_repo_path)));
+        }
+    }
+
+    repos
+        .into_iter()
+        .map(|repo| {
+            let outcome = match GitWorktree::new(repo.clone()) {
+                Err(_) => RepoPruneOutcome::Missing,
+                Ok(git_wt) => match git_wt.prune_worktrees() {
+                    Ok(n) => RepoPruneOutcome::Pruned(n),
+                    Err(e) => RepoPruneOutcome::Failed(e.to_string()),
+                },
+            };
+            tracing::debug!(target: "git.worktree", repo = %repo.display(), ?outcome, "pruned session repo");
+            RepoPrune { repo, outcome }
+        })
+        .collect()
+}
+
+/// One-line summary of a [`prune_session_repos`] run for status dialogs.
+pub fn summarize_prune(results: &[RepoPrune]) -> String {
+    if results.is_empty() {
+        return "No sessions reference a git worktree.".to_string();
+    }
+    let mut pruned = 0;
+    let mut missing = 0;
+    let mut failed = 0;
+    for r in results {
+        match r.outcome {
+            RepoPruneOutcome::Pruned(n) => pruned += n,
+            RepoPruneOutcome::Missing => missing += 1,
+            RepoPruneOutcome::Failed(_) => failed += 1,
+        }
+    }
+    let mut summary = format!(
+        "Pruned {pruned} stale worktree {} across {} {}.",
+        if pruned == 1 { "entry" } else { "entries" },
+        results.len(),
+        if results.len() == 1 { "repo" } else { "repos" },
+    );
+    if missing > 0 {
+        summary.push_str(&format!(" {missing} missing on disk, skipped."));
+    }
+    if failed > 0 {
+        summary.push_str(&format!(" {failed} failed, see logs."));
+    }
+    summary
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -688,6 +800,8 @@ mod tests {
             container_name: "aoe-sandbox-doesnotexist".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         });
 
         let worktree = std::path::PathBuf::from("/tmp/aoe-cleanup-test-nonexistent");
@@ -760,6 +874,8 @@ mod tests {
             container_name: "aoe-cruft-doesnotexist".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         });
 
         let git_wt = GitWorktree::new(main_repo.clone()).unwrap();
@@ -1194,4 +1310,57 @@ mod tests {
         assert!(wrapper.exists(), "wrapper with stray file must survive");
         assert!(stray.exists(), "stray file must not be touched");
     }
+
+    #[test]
+    fn test_prune_session_repos_prunes_each_repo_once_and_skips_missing() {
+        use crate::session::WorktreeInfo;
+
+        let (_dir, repo_path) = init_repo_with_commit();
+        let git_wt = GitWorktree::new(repo_path.clone()).unwrap();
+        let wt_dir = tempfile::TempDir::new().unwrap();
+        // Create both before deleting either: `create_worktree` prunes first.
+        for name in ["a", "b"] {
+            let wt = wt_dir.path().join(name);
+            git_wt.create_worktree(name, &wt, true, None).unwrap();
+        }
+        for name in ["a", "b"] {
+            std::fs::remove_dir_all(wt_dir.path().join(name)).unwrap();
+        }
+
+        let session_on = |repo: &Path| {
+            let mut inst = Instance::new("s", "/tmp/aoe-prune-test");
+            inst.worktree_info = Some(WorktreeInfo {
+                branch: "a".to_string(),
+                main_repo_path: repo.to_string_lossy().to_string(),
+                managed_by_aoe: true,
+                created_at: chrono::Utc::now(),
+                base_branch: None,
+            });
+            inst
+        };
+        let missing = wt_dir.path().join("gone-repo");
+        let instances = vec![
+            session_on(&repo_path),
+            session_on(&repo_path),
+            session_on(&missing),
+        ];
+
+        let results = prune_session_repos(&instances);
+        assert_eq!(results.len(), 2, "each repo pruned once: {results:?}");
+        let outcome_for = |p: &Path| {
+            results
+                .iter()
+                .find(|r| r.repo == p)
+                .map(|r| r.outcome.clone())
+        };
+        assert_eq!(outcome_for(&repo_path), Some(RepoPruneOutcome::Pruned(2)));
+        assert_eq!(outcome_for(&missing), Some(RepoPruneOutcome::Missing));
+        assert_eq!(
+            summarize_prune(&results),
+            "Pruned 2 stale worktree entries across 2 repos. 1 missing on disk, skipped."
+        );
+
+        // A second run finds nothing left to prune.
+        assert_eq!(git_wt.prune_worktrees().unwrap(), 0);
+    }
 }
@@ -6,8 +6,42 @@
 
 use std::ffi::OsStr;
 use std::path::Path;
-use std::process::{Command, Output};
-use std::time::Instant;
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+/// Default for `worktree.git_timeout_secs`. Generous enough for a
+/// `git submodule update` on a large checkout; the limit exists to break
+/// hangs, not to bound normal work.
+pub const DEFAULT_GIT_TIMEOUT_SECS: u32 = 300;
+
+/// Process-wide limit applied by `run_git`, seeded from config at startup
+/// and on settings save. 0 disables the limit.
+static GIT_TIMEOUT_SECS: AtomicU32 = AtomicU32::new(DEFAULT_GIT_TIMEOUT_SECS);
+
+pub fn set_git_timeout_secs(secs: u32) {
+    GIT_TIMEOUT_SECS.store(secs, Ordering::Relaxed);
+}
+
+fn git_timeout() -> Option<Duration> {
+    match GIT_TIMEOUT_SECS.load(Ordering::Relaxed) {
+        0 => None,
+        secs => Some(Duration::from_secs(u64::from(secs))),
+    }
+}
+
+/// A `git` command that can never block on a credential prompt: terminal
+/// prompting is off and both askpass hooks are blanked (an empty
+/// `GIT_ASKPASS` also overrides `core.askPass`), so an auth failure exits
+/// with git's "terminal prompts disabled" error. Every git subprocess in
+/// this module starts here.
+pub(crate) fn git_command() -> Command {
+    let mut cmd = Command::new("git");
+    cmd.env("GIT_TERMINAL_PROMPT", "0")
+        .env("GIT_ASKPASS", "")
+        .env("SSH_ASKPASS", "");
+    cmd
+}
 
 /// Run `git <args>` in `cwd`, instrumented with target `git.command`.
 /// Logs a debug line before, then debug (success) or warn (failure)
@@ -15,7 +49,30 @@ use std::time::Instant;
 ///
 /// `args` may contain URLs with embedded credentials; we strip the
 /// userinfo before logging so tokens don't end up on disk.
+///
+/// Stdin is null and prompting is disabled (see `git_command`), so a
+/// credential prompt fails instead of blocking. A git process that still outlives
+/// the configured timeout is killed and reported as an
+/// `ErrorKind::TimedOut` error, which converts to `GitError::Timeout`.
 pub fn run_git<I, S>(cwd: &Path, args: I) -> std::io::Result<Output>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    run_git_with_env(cwd, args, &[])
+}
+
+/// [`run_git`] under the C locale, for callers that parse git's
+/// human-readable output, which is translated otherwise.
+pub fn run_git_c_locale<I, S>(cwd: &Path, args: I) -> std::io::Result<Output>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    run_git_with_env(cwd, args, &[("LC_ALL", "C")])
+}
+
+fn run_git_with_env<I, S>(cwd: &Path, args: I, env: &[(&str, &str)]) -> std::io::Result<Output>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<OsStr>,
@@ -29,7 +86,24 @@ where
         cwd = %cwd.display(),
         "running git"
     );
-    let output = Command::new("git").args(&argv).current_dir(cwd).output()?;
+    let mut cmd = git_command();
+    cmd.args(&argv).current_dir(cwd).envs(env.iter().copied());
+    let output = match output_with_timeout(cmd, git_timeout()) {
+        Ok(output) => output,
+        Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+            tracing::warn!(
+                target: "git.command",
+                args = ?redacted,
+                duration_ms = start.elapsed().as_millis() as u64,
+                "git command timed out"
+            );
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("git {} {e}", redacted.join(" ")),
+            ));
+        }
+        Err(e) => return Err(e),
+    };
     let dur = start.elapsed().as_millis() as u64;
     if output.status.success() {
         tracing::debug!(
@@ -54,6 +128,59 @@ where
     Ok(output)
 }
 
+/// `Command::output` with an optional deadline. Stdout and stderr are
+/// drained on worker threads so a chatty child can't block on a full pipe
+/// while we poll; on expiry the child is killed and reaped.
+fn output_with_timeout(mut cmd: Command, timeout: Option<Duration>) -> std::io::Result<Output> {
+    let Some(timeout) = timeout else {
+        return cmd.stdin(Stdio::null()).output();
+    };
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    // Most git calls finish in a few milliseconds, so start polling fast
+    // and back off instead of paying a fixed sleep on every call.
+    let deadline = Instant::now() + timeout;
+    let mut poll = Duration::from_millis(1);
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("timed out after {}s", timeout.as_secs()),
+            ));
+        }
+        std::thread::sleep(poll);
+        poll = (poll * 2).min(Duration::from_millis(50));
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn drain<R: std::io::Read + Send + 'static>(pipe: Option<R>) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut r) = pipe {
+            let _ = r.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
 fn redact(arg: &OsStr) -> String {
     let s = arg.to_string_lossy();
     if let Some(scheme_end) = s.find("://") {
@@ -92,6 +219,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn git_command_disables_credential_prompts() {
+        let cmd = git_command();
+        let envs: Vec<(&OsStr, Option<&OsStr>)> = cmd.get_envs().collect();
+        for (key, value) in [
+            ("GIT_TERMINAL_PROMPT", "0"),
+            ("GIT_ASKPASS", ""),
+            ("SSH_ASKPASS", ""),
+        ] {
+            assert!(
+                envs.contains(&(OsStr::new(key), Some(OsStr::new(value)))),
+                "{key} should be {value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn output_with_timeout_kills_a_hung_child() {
+        let mut cmd = Command::new("sleep");
+        cmd.arg("5");
+        let start = Instant::now();
+        let err = output_with_timeout(cmd, Some(Duration::from_millis(100))).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn output_with_timeout_captures_output() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo out; echo err >&2"]);
+        let output = output_with_timeout(cmd, Some(Duration::from_secs(5))).unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+    }
+
     #[test]
     fn redact_passes_non_url_args_through() {
         assert_eq!(redact(&OsString::from("--prune")), "--prune");
@@ -315,6 +315,29 @@ pub fn check_merge_base_status(repo_path: &Path, base_branch: &str) -> Option<St
     }
 }
 
+/// The commit HEAD points at. Cheap next to
+/// [`GitWorktree::commit_count_since_base`](super::GitWorktree::commit_count_since_base),
+/// so callers cache that by this id and only recount when HEAD moves.
+pub fn head_commit_id(repo_path: &Path) -> Result<git2::Oid> {
+    let repo = super::open_repo_at(repo_path)?;
+    let id = repo.head()?.peel_to_commit()?.id();
+    Ok(id)
+}
+
+/// The current branch's upstream as a revparse-able name (e.g.
+/// `origin/feature`). `None` when HEAD is detached or the branch tracks
+/// nothing.
+pub fn upstream_ref(repo_path: &Path) -> Option<String> {
+    let repo = super::open_repo_at(repo_path).ok()?;
+    let head = repo.head().ok()?;
+    if !head.is_branch() {
+        return None;
+    }
+    let upstream = git2::Branch::wrap(head).upstream().ok()?;
+    let name = upstream.name().ok().flatten()?;
+    Some(name.to_string())
+}
+
 /// Compute the full diff for a specific file.
 /// Uses the merge-base of HEAD and the base branch so only changes from
 /// the current branch are shown.
@@ -834,6 +857,16 @@ mod tests {
         (dir, repo)
     }
 
+    #[test]
+    fn test_head_commit_id_and_missing_upstream() {
+        let (dir, repo) = setup_branching_repo();
+        assert_eq!(
+            head_commit_id(dir.path()).unwrap(),
+            repo.head().unwrap().target().unwrap()
+        );
+        assert_eq!(upstream_ref(dir.path()), None);
+    }
+
     #[test]
     fn test_merge_base_excludes_main_only_changes() {
         let (dir, _repo) = setup_branching_repo();
@@ -9,12 +9,25 @@ pub enum GitError {
     #[error("Worktree already exists at {}", .0.display())]
     WorktreeAlreadyExists(PathBuf),
 
-    #[error("Branch '{0}' is already in use by another worktree")]
-    BranchAlreadyCheckedOut(String),
+    /// `existing_path` is the worktree holding the branch, when it could be
+    /// found. It is kept out of the message so the web API can forward it.
+    #[error("Branch '{branch}' is already in use by another worktree")]
+    BranchAlreadyCheckedOut {
+        branch: String,
+        existing_path: Option<PathBuf>,
+    },
 
     #[error("Worktree not found at {}", .0.display())]
     WorktreeNotFound(PathBuf),
 
+    /// Recreating the worktree would throw away `files`.
+    #[error(
+        "Worktree at {} has uncommitted changes ({}); commit or stash them before recreating it",
+        path.display(),
+        files.join(", ")
+    )]
+    WorktreeDirty { path: PathBuf, files: Vec<String> },
+
     #[error("Branch '{0}' not found")]
     BranchNotFound(String),
 
@@ -27,8 +40,24 @@ pub enum GitError {
     #[error("Git clone failed: {0}")]
     CloneFailed(String),
 
+    #[error("Git command timed out: {0}")]
+    Timeout(String),
+
     #[error("IO error: {0}")]
-    IoError(#[from] std::io::Error),
+    IoError(std::io::Error),
+}
+
+impl From<std::io::Error> for GitError {
+    /// `run_git` reports an expired deadline as `ErrorKind::TimedOut`;
+    /// surface that as its own variant so callers can tell a hang from an
+    /// ordinary IO failure.
+    fn from(e: std::io::Error) -> Self {
+        if e.kind() == std::io::ErrorKind::TimedOut {
+            Self::Timeout(e.to_string())
+        } else {
+            Self::IoError(e)
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, GitError>;
@@ -2,6 +2,7 @@
 //!
 //! Layout:
 //!   - `remote`   — repo cloning, origin-URL parsing
+//!   - `host`     — pull / merge request URLs for GitHub, GitLab, Bitbucket
 //!   - `worktree` — `GitWorktree` lifecycle, branch ops, template paths
 //!   - `diff`     — diff rendering for the UI
 //!   - `cleanup`  — stale-worktree cleanup
@@ -19,12 +20,14 @@ pub mod cleanup;
 pub(crate) mod command;
 pub mod diff;
 pub mod error;
+pub mod host;
 mod remote;
 pub mod template;
 mod worktree;
 
-pub use remote::{clone_bare_repo, clone_repo, get_remote_owner};
-pub use worktree::{GitWorktree, WorktreeEntry};
+pub use command::{set_git_timeout_secs, DEFAULT_GIT_TIMEOUT_SECS};
+pub use remote::{clone_bare_repo, clone_repo, get_remote_owner, get_remote_repo_slug};
+pub use worktree::{CommitsSinceBase, GitWorktree, OnExists, WorktreeEntry, WorktreePlan};
 
 /// Open a git repository at the given path without searching parent directories.
 /// Unlike `git2::Repository::discover`, this does not walk up the directory tree,
@@ -18,6 +18,26 @@ pub fn sanitize_branch_name(branch: &str) -> String {
     )
 }
 
+/// Rewrite a relative template for resolution under a shared worktree root
+/// instead of next to the repo: leading `.` / `..` components are dropped
+/// (they only made sense relative to the repo), and a template without
+/// `{repo-name}` is nested under a `{repo-name}/` directory so worktrees of
+/// different repos never collide. Absolute templates are returned unchanged.
+pub fn template_under_root(template: &str) -> String {
+    if template.starts_with('/') {
+        return template.to_string();
+    }
+    let mut rest = template;
+    while let Some(r) = rest.strip_prefix("../").or_else(|| rest.strip_prefix("./")) {
+        rest = r;
+    }
+    if rest.contains("{repo-name}") {
+        rest.to_string()
+    } else {
+        format!("{{repo-name}}/{rest}")
+    }
+}
+
 pub fn resolve_template(template: &str, vars: &TemplateVars) -> Result<PathBuf> {
     let sanitized_branch = sanitize_branch_name(&vars.branch);
 
@@ -62,6 +82,20 @@ mod tests {
         assert!(result.to_string_lossy().contains("feat-test"));
     }
 
+    #[test]
+    fn test_template_under_root() {
+        assert_eq!(
+            template_under_root("../{repo-name}-worktrees/{branch}"),
+            "{repo-name}-worktrees/{branch}"
+        );
+        assert_eq!(template_under_root("./{branch}"), "{repo-name}/{branch}");
+        assert_eq!(
+            template_under_root("../../{branch}-{session-id}"),
+            "{repo-name}/{branch}-{session-id}"
+        );
+        assert_eq!(template_under_root("/abs/{branch}"), "/abs/{branch}");
+    }
+
     #[test]
     fn test_sanitize_branch_name_replaces_slashes() {
         let sanitized = sanitize_branch_name("feat/my-feature");
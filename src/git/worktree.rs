@@ -11,7 +11,7 @@ use regex::Regex;
 
 use super::error::{GitError, Result};
 use super::open_repo_at;
-use super::template::{resolve_template, TemplateVars};
+use super::template::{resolve_template, template_under_root, TemplateVars};
 
 /// Strip embedded credentials from URL-style substrings before stderr
 /// gets logged or surfaced to the user. Git fetch errors typically echo
@@ -36,13 +36,17 @@ fn sanitize_remote_credentials(s: &str) -> String {
 /// The branch-already-checked-out case (git prints `'<branch>' is already
 /// used by worktree at '<path>'`, or `already checked out at '<path>'` on
 /// older git) becomes a clean `BranchAlreadyCheckedOut` that names the
-/// branch and drops the raw stderr (including the other worktree's path).
-/// Everything else stays `WorktreeCommandFailed`, with any credentialed
-/// remote URL redacted before it can be surfaced.
+/// branch and drops the raw stderr. The other worktree's path is not parsed
+/// out of git's wording; `create_worktree` fills it in from
+/// `list_worktrees`. Everything else stays `WorktreeCommandFailed`, with any
+/// credentialed remote URL redacted before it can be surfaced.
 fn classify_worktree_add_failure(combined: &str, branch: &str) -> GitError {
     let lower = combined.to_ascii_lowercase();
     if lower.contains("already used by worktree") || lower.contains("already checked out at") {
-        GitError::BranchAlreadyCheckedOut(branch.to_string())
+        GitError::BranchAlreadyCheckedOut {
+            branch: branch.to_string(),
+            existing_path: None,
+        }
     } else {
         GitError::WorktreeCommandFailed(sanitize_remote_credentials(combined))
     }
@@ -56,9 +60,38 @@ fn classify_worktree_add_failure(combined: &str, branch: &str) -> GitError {
 /// yet" path.
 const FETCH_REMOTE: &str = "origin";
 
+/// What session creation does when the computed worktree path already
+/// exists on disk.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum OnExists {
+    /// Fail with `WorktreeAlreadyExists`.
+    #[default]
+    Error,
+    /// Reuse the directory if it is a worktree of this repo checked out on
+    /// the requested branch.
+    Adopt,
+    /// Remove the existing worktree (force) and create it again. The branch
+    /// itself is kept.
+    Recreate,
+}
+
 pub struct WorktreeEntry {
     pub path: PathBuf,
+    /// Checked-out branch; `None` for a detached HEAD.
     pub branch: Option<String>,
+    /// Display label for HEAD: the branch, or `detached@<short-sha>`.
+    pub head: Option<String>,
     pub is_detached: bool,
 }
 
@@ -105,6 +138,69 @@ impl DefaultBranchInfo {
     }
 }
 
+/// What creating a worktree would do, from [`GitWorktree::plan_worktree`].
+/// Computed from the local repo only: no fetch, nothing written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreePlan {
+    /// Where the worktree would be checked out, from `compute_path`.
+    pub path: PathBuf,
+    /// Something already occupies `path`.
+    pub path_exists: bool,
+    pub branch_exists_locally: bool,
+    /// Remote with a `<remote>/<branch>` tracking ref, picked the same way
+    /// `create_worktree` picks one. `None` when no remote has the branch
+    /// (as of the last fetch).
+    pub branch_remote: Option<String>,
+    /// A new branch would be created rather than an existing one checked out.
+    pub creates_branch: bool,
+}
+
+impl WorktreePlan {
+    /// Why `create_worktree` would fail with this plan, if it would.
+    pub fn conflict(&self, branch: &str) -> Option<String> {
+        if self.path_exists {
+            Some(format!("{} already exists", self.path.display()))
+        } else if self.creates_branch && self.branch_exists_locally {
+            Some(format!("branch '{branch}' already exists"))
+        } else if !self.creates_branch
+            && !self.branch_exists_locally
+            && self.branch_remote.is_none()
+        {
+            Some(format!(
+                "branch '{branch}' not found locally or on a remote"
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// What [`GitWorktree::commit_count_since_base`] found when comparing a
+/// worktree's HEAD to its base.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitsSinceBase {
+    /// HEAD is `count` commits past its merge-base with the base ref.
+    /// `latest_summary` is HEAD's subject line when `count > 0`.
+    Ahead {
+        count: usize,
+        latest_summary: Option<String>,
+    },
+    /// HEAD is not on a branch, so there is no branch work to count.
+    DetachedHead,
+    /// HEAD and the base share no history.
+    NoCommonAncestor,
+}
+
+impl CommitsSinceBase {
+    /// Commits past the base, zero for the detached and unrelated cases.
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Ahead { count, .. } => *count,
+            Self::DetachedHead | Self::NoCommonAncestor => 0,
+        }
+    }
+}
+
 pub struct GitWorktree {
     pub repo_path: PathBuf,
     /// Whether `create_worktree` should run `git submodule update --init
@@ -113,6 +209,10 @@ pub struct GitWorktree {
     /// that respect a user-facing setting (see `WorktreeConfig::init_submodules`)
     /// should call `with_init_submodules` to override it per session.
     init_submodules: bool,
+    /// Directory `compute_path` resolves relative templates against instead
+    /// of the repo (`WorktreeConfig::root`). `None` keeps worktrees next to
+    /// the repo.
+    worktree_root: Option<PathBuf>,
 }
 
 impl GitWorktree {
@@ -123,9 +223,18 @@ impl GitWorktree {
         Ok(Self {
             repo_path,
             init_submodules: true,
+            worktree_root: None,
         })
     }
 
+    /// Resolve relative path templates under `root` (see
+    /// [`template_under_root`]) rather than next to the repo. `None` keeps
+    /// the default repo-relative behavior.
+    pub fn with_worktree_root(mut self, root: Option<PathBuf>) -> Self {
+        self.worktree_root = root;
+        self
+    }
+
     /// Configure whether `create_worktree` recursively initializes submodules
     /// for the new checkout. Defaults to true.
     pub fn with_init_submodules(mut self, init_submodules: bool) -> Self {
@@ -233,7 +342,7 @@ impl GitWorktree {
     /// Stdin is piped to null to prevent SSH passphrase prompts from
     /// hanging. Times out after 10 seconds.
     pub fn fetch_branch(&self, remote: &str, branch: &str) -> FetchOutcome {
-        let mut child = match std::process::Command::new("git")
+        let mut child = match super::command::git_command()
             .args(["fetch", remote, branch])
             .current_dir(&self.repo_path)
             .stdin(std::process::Stdio::null())
@@ -612,9 +721,21 @@ impl GitWorktree {
 
             let commit = repo.find_commit(commit_oid)?;
             repo.branch(branch, &commit, false)?;
-        } else {
-            let has_local = repo.find_branch(branch, git2::BranchType::Local).is_ok();
-            if !has_local {
+        } else if repo.find_branch(branch, git2::BranchType::Local).is_err() {
+            if let Some(remote) = self.pick_remote_for_branch(branch) {
+                // Attaching to a remote-only branch: create the local branch
+                // from the remote tip and set its upstream, as `git switch`
+                // does. Left to `git worktree add`, tracking is only set up
+                // when exactly one remote carries the name, and later pushes
+                // need `-u`.
+                let remote_ref = format!("{remote}/{branch}");
+                let commit = repo
+                    .find_branch(&remote_ref, git2::BranchType::Remote)?
+                    .get()
+                    .peel_to_commit()?;
+                let mut local = repo.branch(branch, &commit, false)?;
+                local.set_upstream(Some(&remote_ref))?;
+            } else {
                 let has_remote = repo
                     .branches(Some(git2::BranchType::Remote))
                     .ok()
@@ -677,7 +798,15 @@ impl GitWorktree {
                 tracing::warn!(target: "git.worktree", "worktree create: {}", warning);
                 warnings.push(warning);
             } else {
-                return Err(classify_worktree_add_failure(&combined, branch));
+                return Err(match classify_worktree_add_failure(&combined, branch) {
+                    GitError::BranchAlreadyCheckedOut { branch, .. } => {
+                        GitError::BranchAlreadyCheckedOut {
+                            existing_path: self.worktree_for_branch(&branch),
+                            branch,
+                        }
+                    }
+                    other => other,
+                });
             }
         }
 
@@ -712,8 +841,23 @@ impl GitWorktree {
         );
 
         let t = std::time::Instant::now();
+        // A failed submodule update leaves a usable worktree (only the
+        // submodule directories are empty), so surface it as a warning with
+        // git's output instead of aborting session creation.
         let submodule_status = if self.init_submodules {
-            Self::initialize_submodules(path)?
+            match Self::initialize_submodules(path) {
+                Ok(status) => status,
+                Err(e) => {
+                    let warning = format!(
+                        "submodule init failed for {} (worktree created, submodules left empty):\n{}",
+                        path.display(),
+                        sanitize_remote_credentials(&e.to_string())
+                    );
+                    tracing::warn!(target: "git.worktree", "worktree create: {}", warning);
+                    warnings.push(warning);
+                    "failed".to_string()
+                }
+            }
         } else {
             "disabled-by-config".to_string()
         };
@@ -735,15 +879,24 @@ impl GitWorktree {
     }
 
     /// Prune stale worktree entries whose directories no longer exist on disk.
-    pub fn prune_worktrees(&self) -> Result<()> {
-        let output = super::command::run_git(&self.repo_path, ["worktree", "prune"])?;
+    /// Returns how many entries git removed.
+    pub fn prune_worktrees(&self) -> Result<usize> {
+        // The C locale keeps the "Removing " prefix counted below from
+        // being translated.
+        let output =
+            super::command::run_git_c_locale(&self.repo_path, ["worktree", "prune", "-v"])?;
 
+        let stderr = String::from_utf8_lossy(&output.stderr);
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-            return Err(GitError::WorktreeCommandFailed(stderr));
+            return Err(GitError::WorktreeCommandFailed(stderr.trim().to_string()));
         }
 
-        Ok(())
+        // `-v` reports one "Removing worktrees/<name>: <reason>" line per
+        // pruned entry on stderr.
+        Ok(stderr
+            .lines()
+            .filter(|line| line.starts_with("Removing "))
+            .count())
     }
 
     /// Convert a worktree's .git file from absolute to relative path.
@@ -766,8 +919,10 @@ impl GitWorktree {
             return Ok(()); // No gitdir line found
         };
 
-        let absolute_path = gitdir_line.trim_start_matches("gitdir:").trim();
-        let absolute_path = Path::new(absolute_path);
+        // Windows git under WSL writes `C:/...`, which Linux sees as relative.
+        let absolute_path =
+            crate::wsl::normalize_host_path(gitdir_line.trim_start_matches("gitdir:").trim());
+        let absolute_path = Path::new(&absolute_path);
 
         if absolute_path.is_relative() {
             return Ok(()); // Already relative
@@ -837,8 +992,13 @@ impl GitWorktree {
     }
 
     /// Calculate a relative path from `base` to `target`.
-    /// Returns None if the paths have no common ancestor.
+    /// Returns None if the paths sit on different Windows drives (see
+    /// [`crate::wsl::drive_letter`]), where no relative path exists.
     pub(crate) fn diff_paths(target: &Path, base: &Path) -> Option<PathBuf> {
+        if crate::wsl::drive_letter(target) != crate::wsl::drive_letter(base) {
+            return None;
+        }
+
         let mut target_components = target.components().peekable();
         let mut base_components = base.components().peekable();
 
@@ -878,6 +1038,7 @@ impl GitWorktree {
             entries.push(WorktreeEntry {
                 path: self.repo_path.clone(),
                 branch: Self::get_current_branch(&self.repo_path).ok(),
+                head: Self::get_head_description(&self.repo_path).ok(),
                 is_detached: repo.head_detached()?,
             });
         }
@@ -886,9 +1047,12 @@ impl GitWorktree {
             if let Ok(wt) = repo.find_worktree(name_str) {
                 if let Ok(path) = wt.path().canonicalize() {
                     entries.push(WorktreeEntry {
-                        path: path.clone(),
                         branch: Self::get_current_branch(&path).ok(),
-                        is_detached: false,
+                        head: Self::get_head_description(&path).ok(),
+                        is_detached: open_repo_at(&path)
+                            .and_then(|r| r.head_detached())
+                            .unwrap_or(false),
+                        path,
                     });
                 }
             }
@@ -897,6 +1061,72 @@ impl GitWorktree {
         Ok(entries)
     }
 
+    /// Path of the worktree (main checkout included) that has `branch`
+    /// checked out, if any.
+    pub fn worktree_for_branch(&self, branch: &str) -> Option<PathBuf> {
+        self.list_worktrees()
+            .ok()?
+            .into_iter()
+            .find(|entry| entry.branch.as_deref() == Some(branch))
+            .map(|entry| entry.path)
+    }
+
+    /// Apply `on_exists` to a worktree target path before creation. Returns
+    /// `true` when the existing directory was adopted (the caller should use
+    /// it as-is instead of calling `create_worktree`), `false` when the path
+    /// is free to create (it did not exist, or `Recreate` removed it).
+    ///
+    /// `Adopt` and `Recreate` only ever act on a directory git lists as a
+    /// worktree of this repo; an unrelated directory at the path is reported
+    /// as `WorktreeAlreadyExists` rather than reused or deleted.
+    pub fn resolve_existing_path(
+        &self,
+        branch: &str,
+        path: &Path,
+        on_exists: OnExists,
+    ) -> Result<bool> {
+        if !path.exists() {
+            return Ok(false);
+        }
+        let exists_err = || GitError::WorktreeAlreadyExists(path.to_path_buf());
+        let adopt = match on_exists {
+            OnExists::Error => return Err(exists_err()),
+            OnExists::Adopt => true,
+            OnExists::Recreate => false,
+        };
+        // git may list either side through a symlink (macOS /tmp), so
+        // compare canonical forms throughout.
+        let canon = |p: &Path| p.canonicalize().unwrap_or_else(|_| p.to_path_buf());
+        let canonical = path.canonicalize()?;
+        let repo_path = canon(&self.repo_path);
+        let entry = self
+            .list_worktrees()?
+            .into_iter()
+            .find(|wt| {
+                let wt_path = canon(&wt.path);
+                wt_path == canonical && wt_path != repo_path
+            })
+            .ok_or_else(exists_err)?;
+        if adopt {
+            return if entry.branch.as_deref() == Some(branch) {
+                Ok(true)
+            } else {
+                Err(exists_err())
+            };
+        }
+        // `--force` below would also discard uncommitted work; only a clean
+        // checkout is recreated.
+        let files = super::cleanup::list_dirty_files(path);
+        if !files.is_empty() {
+            return Err(GitError::WorktreeDirty {
+                path: path.to_path_buf(),
+                files,
+            });
+        }
+        self.remove_worktree(path, true)?;
+        Ok(false)
+    }
+
     pub fn remove_worktree(&self, path: &Path, force: bool) -> Result<()> {
         if !path.exists() {
             return Err(GitError::WorktreeNotFound(path.to_path_buf()));
@@ -922,6 +1152,59 @@ impl GitWorktree {
         Ok(())
     }
 
+    /// Lock reason for the linked worktree at `worktree_path`. `None` when the
+    /// worktree is unlocked (or is not a linked worktree); `Some("")` when it
+    /// is locked without a reason.
+    pub fn lock_reason(worktree_path: &Path) -> Option<String> {
+        let repo = open_repo_at(worktree_path).ok()?;
+        let wt = git2::Worktree::open_from_repository(&repo).ok()?;
+        match wt.is_locked().ok()? {
+            git2::WorktreeLockStatus::Locked(reason) => {
+                Some(reason.unwrap_or_default().trim().to_string())
+            }
+            git2::WorktreeLockStatus::Unlocked => None,
+        }
+    }
+
+    /// Lock a linked worktree so git refuses to prune, move, or remove it.
+    pub fn lock_worktree(&self, path: &Path, reason: Option<&str>) -> Result<()> {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid path"))?;
+
+        let mut args = vec!["worktree", "lock"];
+        if let Some(reason) = reason {
+            args.extend(["--reason", reason]);
+        }
+        args.push(path_str);
+
+        let output = super::command::run_git(&self.repo_path, &args)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(GitError::WorktreeCommandFailed(stderr));
+        }
+
+        Ok(())
+    }
+
+    /// Remove the lock on a linked worktree so `git worktree remove` will
+    /// accept it.
+    pub fn unlock_worktree(&self, path: &Path) -> Result<()> {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid path"))?;
+
+        let output = super::command::run_git(&self.repo_path, ["worktree", "unlock", path_str])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(GitError::WorktreeCommandFailed(stderr));
+        }
+
+        Ok(())
+    }
+
     /// Delete a local git branch.
     ///
     /// Idempotent: if the branch does not exist, returns Ok(()) — the caller
@@ -988,6 +1271,85 @@ impl GitWorktree {
         Ok(())
     }
 
+    /// Whether every commit on local `branch` is reachable from `base`
+    /// (default: this repo's default branch), locally or on the remote
+    /// `base` would be fetched from. A branch that no longer exists counts
+    /// as merged: there is nothing left on it to lose.
+    pub fn is_branch_merged(&self, branch: &str, base: Option<&str>) -> Result<bool> {
+        let repo = open_repo_at(&self.repo_path)?;
+        let tip = match repo.find_branch(branch, git2::BranchType::Local) {
+            Ok(b) => b.get().peel_to_commit()?.id(),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(true),
+            Err(e) => return Err(e.into()),
+        };
+        let base = match base {
+            Some(b) => b.to_string(),
+            None => self.detect_default_branch_info()?.name,
+        };
+        let mut candidates = vec![(base.clone(), git2::BranchType::Local)];
+        if let Some(remote) = self.pick_remote_for_branch(&base) {
+            candidates.push((format!("{remote}/{base}"), git2::BranchType::Remote));
+        }
+        let mut found_base = false;
+        for (name, kind) in candidates {
+            let Ok(base_branch) = repo.find_branch(&name, kind) else {
+                continue;
+            };
+            found_base = true;
+            let base_tip = base_branch.get().peel_to_commit()?.id();
+            if base_tip == tip || repo.graph_descendant_of(base_tip, tip)? {
+                return Ok(true);
+            }
+        }
+        if found_base {
+            Ok(false)
+        } else {
+            Err(GitError::BranchNotFound(base))
+        }
+    }
+
+    /// Count the commits `worktree_path`'s HEAD has beyond `base_ref`
+    /// (default: this repo's default branch, see
+    /// [`Self::detect_default_branch_info`]), measured from their
+    /// merge-base with a revwalk, plus HEAD's summary line.
+    pub fn commit_count_since_base(
+        &self,
+        worktree_path: &Path,
+        base_ref: Option<&str>,
+    ) -> Result<CommitsSinceBase> {
+        let repo = open_repo_at(worktree_path)?;
+        if repo.head_detached()? {
+            return Ok(CommitsSinceBase::DetachedHead);
+        }
+        let head = repo.head()?.peel_to_commit()?;
+        let base_ref = match base_ref {
+            Some(r) => r.to_string(),
+            None => self.detect_default_branch_info()?.qualified_ref(),
+        };
+        let base = repo
+            .revparse_single(&base_ref)
+            .and_then(|obj| obj.peel_to_commit())
+            .map_err(|_| GitError::BranchNotFound(base_ref.clone()))?;
+        let merge_base = match repo.merge_base(head.id(), base.id()) {
+            Ok(oid) => oid,
+            Err(e) if e.code() == git2::ErrorCode::NotFound => {
+                return Ok(CommitsSinceBase::NoCommonAncestor)
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut walk = repo.revwalk()?;
+        walk.push(head.id())?;
+        walk.hide(merge_base)?;
+        let count = walk.collect::<std::result::Result<Vec<_>, _>>()?.len();
+        Ok(CommitsSinceBase::Ahead {
+            count,
+            latest_summary: (count > 0)
+                .then(|| head.summary().ok().flatten().map(str::to_string))
+                .flatten(),
+        })
+    }
+
     /// Whether a local branch `refs/heads/<branch>` exists in this repo.
     pub fn branch_exists(&self, branch: &str) -> bool {
         let refname = format!("refs/heads/{branch}");
@@ -1057,26 +1419,65 @@ impl GitWorktree {
             .unwrap_or("repo")
             .to_string();
 
+        let (template, base_path) = match &self.worktree_root {
+            Some(root) => (template_under_root(template), root.clone()),
+            None => (template.to_string(), self.repo_path.clone()),
+        };
         let vars = TemplateVars {
             repo_name,
             branch: branch.to_string(),
             session_id: session_id.to_string(),
-            base_path: self.repo_path.clone(),
+            base_path,
         };
 
-        resolve_template(template, &vars)
+        resolve_template(&template, &vars)
     }
 
+    /// Dry run of `compute_path` + `create_worktree`: where the worktree would
+    /// go, whether that path is taken, and whether `branch` exists locally or
+    /// on a remote. Reads refs only (no fetch, no disk writes), so it is
+    /// cheap enough to call on every keystroke.
+    pub fn plan_worktree(
+        &self,
+        branch: &str,
+        template: &str,
+        session_id: &str,
+        create_branch: bool,
+    ) -> Result<WorktreePlan> {
+        let path = self.compute_path(branch, template, session_id)?;
+        let repo = open_repo_at(&self.repo_path)?;
+        let branch_exists_locally = repo.find_branch(branch, git2::BranchType::Local).is_ok();
+        Ok(WorktreePlan {
+            path_exists: path.exists(),
+            path,
+            branch_exists_locally,
+            branch_remote: self.pick_remote_for_branch(branch),
+            creates_branch: create_branch,
+        })
+    }
+
+    /// Branch checked out at `path`. A detached HEAD has no branch and
+    /// returns `NotAGitRepo`; use `get_head_description` for display.
     pub fn get_current_branch(path: &Path) -> Result<String> {
         let repo = open_repo_at(path)?;
         let head = repo.head()?;
 
-        if let Ok(branch_name) = head.shorthand() {
-            Ok(branch_name.to_string())
-        } else {
-            Err(GitError::NotAGitRepo)
+        match head.shorthand() {
+            Ok(branch_name) if head.is_branch() => Ok(branch_name.to_string()),
+            _ => Err(GitError::NotAGitRepo),
         }
     }
+
+    /// Human-readable HEAD for `path`: the branch name, or
+    /// `detached@<short-sha>` when HEAD is detached.
+    pub fn get_head_description(path: &Path) -> Result<String> {
+        if let Ok(branch) = Self::get_current_branch(path) {
+            return Ok(branch);
+        }
+        let repo = open_repo_at(path)?;
+        let oid = repo.head()?.peel_to_commit()?.id().to_string();
+        Ok(format!("detached@{}", &oid[..7]))
+    }
 }
 
 /// One default-branch candidate considered by
@@ -1343,6 +1744,99 @@ mod tests {
         (dir, repo)
     }
 
+    fn commit_on_head(repo: &git2::Repository, message: &str) -> git2::Oid {
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_diff_paths_refuses_cross_drive_paths() {
+        assert_eq!(
+            GitWorktree::diff_paths(
+                Path::new("/repo/.bare/worktrees/feat"),
+                Path::new("/repo/feat")
+            ),
+            Some(PathBuf::from("../.bare/worktrees/feat"))
+        );
+        assert_eq!(
+            GitWorktree::diff_paths(
+                Path::new("C:/repo/.git/worktrees/feat"),
+                Path::new("D:/wt/feat")
+            ),
+            None
+        );
+        assert_eq!(
+            GitWorktree::diff_paths(Path::new("C:/repo/.git"), Path::new("C:/repo/wt")),
+            Some(PathBuf::from("../.git"))
+        );
+    }
+
+    #[test]
+    fn test_commit_count_since_base_counts_branch_commits() {
+        let (dir, repo) = setup_test_repo();
+        let base = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("main", &base, true).unwrap();
+        repo.branch("feature", &base, false).unwrap();
+        repo.set_head("refs/heads/feature").unwrap();
+        let git_wt = GitWorktree::new(dir.path().to_path_buf()).unwrap();
+
+        assert_eq!(
+            git_wt
+                .commit_count_since_base(dir.path(), Some("main"))
+                .unwrap(),
+            CommitsSinceBase::Ahead {
+                count: 0,
+                latest_summary: None
+            }
+        );
+
+        commit_on_head(&repo, "add lexer");
+        commit_on_head(&repo, "fix parser bug");
+        assert_eq!(
+            git_wt
+                .commit_count_since_base(dir.path(), Some("main"))
+                .unwrap(),
+            CommitsSinceBase::Ahead {
+                count: 2,
+                latest_summary: Some("fix parser bug".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_commit_count_since_base_reports_detached_and_unrelated() {
+        let (dir, repo) = setup_test_repo();
+        let git_wt = GitWorktree::new(dir.path().to_path_buf()).unwrap();
+
+        // An orphan commit shares no history with the initial one.
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let orphan = repo.commit(None, &sig, &sig, "orphan", &tree, &[]).unwrap();
+        repo.branch("orphan", &repo.find_commit(orphan).unwrap(), false)
+            .unwrap();
+        assert_eq!(
+            git_wt
+                .commit_count_since_base(dir.path(), Some("orphan"))
+                .unwrap(),
+            CommitsSinceBase::NoCommonAncestor
+        );
+
+        let head = repo.head().unwrap().target().unwrap();
+        repo.set_head_detached(head).unwrap();
+        assert_eq!(
+            git_wt.commit_count_since_base(dir.path(), None).unwrap(),
+            CommitsSinceBase::DetachedHead
+        );
+    }
+
     #[test]
     fn test_is_git_repo_returns_true_for_git_directory() {
         let (_dir, repo) = setup_test_repo();
@@ -1388,6 +1882,35 @@ mod tests {
         assert!(worktrees.len() >= 2);
     }
 
+    #[test]
+    fn test_list_worktrees_describes_detached_head() {
+        let (dir, repo) = setup_test_repo();
+        let repo_path = repo.path().parent().unwrap();
+        let sha = repo.head().unwrap().target().unwrap().to_string();
+
+        let wt_path = dir.path().join("detached-worktree");
+        let output = std::process::Command::new("git")
+            .args(["worktree", "add", "--detach", wt_path.to_str().unwrap()])
+            .current_dir(repo_path)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        assert!(GitWorktree::get_current_branch(&wt_path).is_err());
+        let git_wt = GitWorktree::new(repo_path.to_path_buf()).unwrap();
+        let worktrees = git_wt.list_worktrees().unwrap();
+        let entry = worktrees
+            .iter()
+            .find(|wt| wt.path == wt_path.canonicalize().unwrap())
+            .unwrap();
+        assert!(entry.is_detached);
+        assert_eq!(entry.branch, None);
+        assert_eq!(
+            entry.head.as_deref(),
+            Some(format!("detached@{}", &sha[..7]).as_str())
+        );
+    }
+
     #[test]
     fn test_remove_worktree_deletes_worktree() {
         let (_dir, repo) = setup_test_repo();
@@ -1409,6 +1932,59 @@ mod tests {
         assert!(!wt_path.exists());
     }
 
+    #[test]
+    fn test_resolve_existing_path_applies_on_exists_policy() {
+        let (_dir, repo) = setup_test_repo();
+        let repo_path = repo.path().parent().unwrap();
+        let wt_dir = TempDir::new().unwrap();
+        let wt_path = wt_dir.path().join("feature-wt");
+        let git_wt = GitWorktree::new(repo_path.to_path_buf()).unwrap();
+        git_wt
+            .create_worktree("feature", &wt_path, true, None)
+            .unwrap();
+
+        assert!(!git_wt
+            .resolve_existing_path("feature", &wt_dir.path().join("free"), OnExists::Error)
+            .unwrap());
+        assert!(matches!(
+            git_wt.resolve_existing_path("feature", &wt_path, OnExists::Error),
+            Err(GitError::WorktreeAlreadyExists(_))
+        ));
+        // Adopting only reuses a worktree on the requested branch.
+        assert!(matches!(
+            git_wt.resolve_existing_path("other", &wt_path, OnExists::Adopt),
+            Err(GitError::WorktreeAlreadyExists(_))
+        ));
+        assert!(git_wt
+            .resolve_existing_path("feature", &wt_path, OnExists::Adopt)
+            .unwrap());
+
+        // A plain directory is never adopted or deleted.
+        let stray = wt_dir.path().join("stray");
+        std::fs::create_dir(&stray).unwrap();
+        assert!(matches!(
+            git_wt.resolve_existing_path("feature", &stray, OnExists::Recreate),
+            Err(GitError::WorktreeAlreadyExists(_))
+        ));
+        assert!(stray.exists());
+
+        // Uncommitted work blocks a recreate instead of being discarded.
+        let scratch = wt_path.join("notes.txt");
+        std::fs::write(&scratch, "wip").unwrap();
+        assert!(matches!(
+            git_wt.resolve_existing_path("feature", &wt_path, OnExists::Recreate),
+            Err(GitError::WorktreeDirty { ref files, .. }) if files == &["untracked notes.txt"]
+        ));
+        assert!(scratch.exists());
+        std::fs::remove_file(&scratch).unwrap();
+
+        assert!(!git_wt
+            .resolve_existing_path("feature", &wt_path, OnExists::Recreate)
+            .unwrap());
+        assert!(!wt_path.exists());
+        assert!(git_wt.branch_exists("feature"));
+    }
+
     #[test]
     fn test_compute_path_with_template() {
         let (_dir, repo) = setup_test_repo();
@@ -1424,6 +2000,88 @@ mod tests {
         assert!(path.to_string_lossy().contains("-worktrees"));
     }
 
+    #[test]
+    fn test_plan_worktree_reports_without_touching_disk() {
+        let (dir, repo) = setup_test_repo();
+        let repo_path = dir.path().to_path_buf();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("existing", &head, false).unwrap();
+        let git_wt = GitWorktree::new(repo_path.clone()).unwrap();
+        let template = "../{repo-name}-wt/{branch}";
+
+        let plan = git_wt
+            .plan_worktree("feat/new", template, "abc123", true)
+            .unwrap();
+        assert_eq!(
+            plan.path,
+            git_wt.compute_path("feat/new", template, "abc123").unwrap()
+        );
+        assert!(!plan.path_exists && !plan.branch_exists_locally);
+        assert_eq!(plan.branch_remote, None);
+        assert_eq!(plan.conflict("feat/new"), None);
+        assert!(!plan.path.exists());
+
+        let plan = git_wt
+            .plan_worktree("existing", template, "abc123", true)
+            .unwrap();
+        assert!(plan.branch_exists_locally);
+        assert_eq!(
+            plan.conflict("existing").as_deref(),
+            Some("branch 'existing' already exists")
+        );
+        let attach = git_wt
+            .plan_worktree("existing", template, "abc123", false)
+            .unwrap();
+        assert_eq!(attach.conflict("existing"), None);
+
+        let missing = git_wt
+            .plan_worktree("nope", template, "abc123", false)
+            .unwrap();
+        assert!(missing.conflict("nope").unwrap().contains("not found"));
+
+        std::fs::create_dir_all(&missing.path).unwrap();
+        let taken = git_wt
+            .plan_worktree("nope", template, "abc123", true)
+            .unwrap();
+        assert!(taken.path_exists);
+        assert!(taken.conflict("nope").unwrap().ends_with("already exists"));
+        std::fs::remove_dir_all(missing.path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_compute_path_under_worktree_root() {
+        let (dir, _repo) = setup_test_repo();
+        let repo_path = dir.path().to_path_buf();
+        let repo_name = repo_path.file_name().unwrap().to_str().unwrap().to_string();
+        let template = "../{repo-name}-worktrees/{branch}";
+
+        // Default: anchored on the repo, landing next to it.
+        let git_wt = GitWorktree::new(repo_path.clone()).unwrap();
+        assert_eq!(
+            git_wt.compute_path("feat/x", template, "abc").unwrap(),
+            repo_path.join(format!("../{repo_name}-worktrees/feat-x"))
+        );
+
+        // With a root: every repo's worktrees live under it, kept apart by
+        // repo name even when the template doesn't mention it.
+        let root = TempDir::new().unwrap();
+        let git_wt = git_wt.with_worktree_root(Some(root.path().to_path_buf()));
+        assert_eq!(
+            git_wt.compute_path("feat/x", template, "abc").unwrap(),
+            root.path().join(format!("{repo_name}-worktrees/feat-x"))
+        );
+        assert_eq!(
+            git_wt.compute_path("feat/x", "./{branch}", "abc").unwrap(),
+            root.path().join(&repo_name).join("feat-x")
+        );
+        assert_eq!(
+            git_wt
+                .compute_path("feat/x", "/abs/{branch}", "abc")
+                .unwrap(),
+            PathBuf::from("/abs/feat-x")
+        );
+    }
+
     /// Sets up a linked worktree bare repo structure:
     /// /tmp/xxx/
     ///   .bare/           <- bare git repository
@@ -1762,6 +2420,17 @@ mod tests {
 
         assert!(wt_path.exists());
         assert!(wt_path.join(".git").exists());
+
+        // The local branch tracks its remote, so a plain `git push` works.
+        let config = local_repo.config().unwrap().snapshot().unwrap();
+        assert_eq!(
+            config.get_str("branch.remote-only-branch.remote").unwrap(),
+            "origin"
+        );
+        assert_eq!(
+            config.get_str("branch.remote-only-branch.merge").unwrap(),
+            "refs/heads/remote-only-branch"
+        );
     }
 
     #[test]
@@ -1874,11 +2543,18 @@ mod tests {
         // Try creating again at a different path but same branch - git won't
         // allow two worktrees to check out the same branch. This is the
         // branch-already-checked-out case, so it maps to the typed
-        // BranchAlreadyCheckedOut error naming the branch.
+        // BranchAlreadyCheckedOut error naming the branch and the worktree
+        // that holds it.
         let wt_path2 = dir.path().join("fail-worktree-2");
         let result = git_wt.create_worktree("fail-branch", &wt_path2, false, None);
         match result {
-            Err(GitError::BranchAlreadyCheckedOut(branch)) => assert_eq!(branch, "fail-branch"),
+            Err(GitError::BranchAlreadyCheckedOut {
+                branch,
+                existing_path,
+            }) => {
+                assert_eq!(branch, "fail-branch");
+                assert_eq!(existing_path, Some(wt_path.canonicalize().unwrap()));
+            }
             other => panic!("Expected BranchAlreadyCheckedOut error, got: {other:?}"),
         }
     }
@@ -2720,14 +3396,20 @@ mod tests {
         let combined =
             "fatal: 'feature/foo' is already used by worktree at '/tmp/repo-worktrees/feature-foo'";
         match classify_worktree_add_failure(combined, "feature/foo") {
-            GitError::BranchAlreadyCheckedOut(b) => assert_eq!(b, "feature/foo"),
+            GitError::BranchAlreadyCheckedOut {
+                branch,
+                existing_path,
+            } => {
+                assert_eq!(branch, "feature/foo");
+                assert_eq!(existing_path, None);
+            }
             other => panic!("expected BranchAlreadyCheckedOut, got {other:?}"),
         }
         // Older git wording.
         let combined_old = "fatal: 'feature/foo' is already checked out at '/tmp/other'";
         assert!(matches!(
             classify_worktree_add_failure(combined_old, "feature/foo"),
-            GitError::BranchAlreadyCheckedOut(_)
+            GitError::BranchAlreadyCheckedOut { .. }
         ));
     }
 
@@ -2758,6 +3440,52 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_fetch_branch_fails_fast_when_remote_wants_credentials() {
+        // A remote that answers every request with a Basic auth challenge.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut stream = stream;
+                let mut buf = [0u8; 4096];
+                let _ = std::io::Read::read(&mut stream, &mut buf);
+                let _ = std::io::Write::write_all(
+                    &mut stream,
+                    b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Basic realm=\"aoe\"\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+            }
+        });
+
+        let (dir, repo) = setup_test_repo();
+        repo.remote("origin", &format!("http://127.0.0.1:{port}/repo.git"))
+            .unwrap();
+        let askpass = dir.path().join("hang-askpass.sh");
+        std::fs::write(&askpass, "#!/bin/sh\nsleep 30\n").unwrap();
+        std::fs::set_permissions(
+            &askpass,
+            std::os::unix::fs::PermissionsExt::from_mode(0o755),
+        )
+        .unwrap();
+        let mut config = repo.config().unwrap();
+        // Reset inherited credential helpers, and point core.askPass at a
+        // program that would hang if git were allowed to run it.
+        config.set_str("credential.helper", "").unwrap();
+        config
+            .set_str("core.askPass", askpass.to_str().unwrap())
+            .unwrap();
+
+        let git_wt = GitWorktree::new(dir.path().to_path_buf()).unwrap();
+        let start = std::time::Instant::now();
+        let outcome = git_wt.fetch_branch("origin", "main");
+        assert!(
+            matches!(outcome, FetchOutcome::Failed(_)),
+            "expected Failed, got {outcome:?}"
+        );
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+
     #[test]
     fn test_fetch_branch_returns_failed_when_branch_missing_on_remote() {
         let remote_dir = TempDir::new().unwrap();
@@ -3010,6 +3738,31 @@ mod tests {
         );
     }
 
+    #[test]
+    #[serial(submodule)]
+    fn test_create_worktree_warns_instead_of_failing_on_submodule_error() {
+        let _git_allow = AllowFileTransport::set();
+        let (_submodule_src, submodule_bare, repo_dir) =
+            build_repo_with_submodule_and_branch("test-feature");
+        // Make the submodule URL unreachable so `git submodule update` fails.
+        drop(submodule_bare);
+
+        let git_wt = GitWorktree::new(repo_dir.path().to_path_buf()).unwrap();
+        let worktree_parent = TempDir::new().unwrap();
+        let wt_path = worktree_parent.path().join("submodule-worktree");
+        let warnings = git_wt
+            .create_worktree("test-feature", &wt_path, false, None)
+            .unwrap();
+
+        assert!(wt_path.join(".git").exists());
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.starts_with("submodule init failed")),
+            "expected a submodule warning, got {warnings:?}"
+        );
+    }
+
     #[test]
     #[serial(submodule)]
     fn test_create_worktree_skips_submodules_when_disabled() {
@@ -50,8 +50,7 @@ pub fn codex_config_path() -> Result<PathBuf> {
         return Ok(PathBuf::from(codex_home).join("config.toml"));
     }
 
-    Ok(dirs::home_dir()
-        .ok_or_else(|| anyhow::anyhow!("Cannot determine home directory"))?
+    Ok(crate::session::home_dir()?
         .join(".codex")
         .join("config.toml"))
 }
@@ -112,9 +111,7 @@ pub(crate) fn agent_settings_path_for_host_environment(
             }
         }
     }
-    Ok(dirs::home_dir()
-        .ok_or_else(|| anyhow::anyhow!("Cannot determine home directory"))?
-        .join(hook_cfg.settings_rel_path))
+    Ok(crate::session::home_dir()?.join(hook_cfg.settings_rel_path))
 }
 
 /// Display variant of [`agent_settings_path_for_host_environment`] for UI
@@ -152,7 +149,7 @@ fn resolve_config_dir_override(var: &str, host_env: &[String]) -> Option<String>
 fn agent_settings_paths_for_uninstall(hook_cfg: &crate::agents::AgentHookConfig) -> Vec<PathBuf> {
     let mut paths = Vec::new();
 
-    if let Some(home) = dirs::home_dir() {
+    if let Ok(home) = crate::session::home_dir() {
         push_unique_path(&mut paths, Ok(home.join(hook_cfg.settings_rel_path)));
     }
 
@@ -1306,7 +1303,7 @@ pub fn uninstall_kiro_hooks(agent_config_path: &Path) -> Result<bool> {
 /// the hook status base directory. Called during `aoe uninstall`.
 pub fn uninstall_all_hooks() {
     // Remove sidecar hooks (settl TOML, hermes YAML, kiro per-agent JSON).
-    if let Some(home) = dirs::home_dir() {
+    if let Ok(home) = crate::session::home_dir() {
         for agent in crate::agents::AGENTS {
             if let Some(sidecar) = &agent.sidecar_hooks {
                 let config_path = home.join(sidecar.host_config_subpath);
@@ -1480,7 +1477,9 @@ mod tests {
     fn test_agent_settings_path_defaults_to_home_relative() {
         let _guard = EnvGuard::unset("CLAUDE_CONFIG_DIR");
         let path = agent_settings_path_for_host_environment(claude_hook_config(), &[]).unwrap();
-        let expected = dirs::home_dir().unwrap().join(".claude/settings.json");
+        let expected = crate::session::home_dir()
+            .unwrap()
+            .join(".claude/settings.json");
         assert_eq!(path, expected);
     }
 
@@ -1543,7 +1542,9 @@ mod tests {
         let host_env = vec!["CLAUDE_CONFIG_DIR=".to_string()];
         let path =
             agent_settings_path_for_host_environment(claude_hook_config(), &host_env).unwrap();
-        let expected = dirs::home_dir().unwrap().join(".claude/settings.json");
+        let expected = crate::session::home_dir()
+            .unwrap()
+            .join(".claude/settings.json");
         assert_eq!(path, expected);
     }
 
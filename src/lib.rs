@@ -13,6 +13,7 @@ pub mod github;
 pub mod hooks;
 pub mod logging;
 pub mod migrations;
+pub mod notifications;
 pub mod process;
 #[cfg(feature = "serve")]
 pub mod server;
@@ -25,3 +26,4 @@ pub mod terminal;
 pub mod tmux;
 pub mod tui;
 pub mod update;
+pub mod wsl;
@@ -231,6 +231,7 @@ async fn main() -> Result<()> {
         }
         Some(Commands::Init(args)) => return cli::init::run(args).await,
         Some(Commands::ExtractSessionId(args)) => return cli::extract_session_id::run(args).await,
+        Some(Commands::RespawnAgent(args)) => return cli::respawn_agent::run(args),
         Some(Commands::Tmux { command }) => {
             use cli::tmux::TmuxCommands;
             return match command {
@@ -255,6 +256,7 @@ async fn main() -> Result<()> {
                 ThemeCommands::Dir => cli::theme::run_dir(),
             };
         }
+        Some(Commands::Cache { command }) => return cli::cache::run(command),
         Some(Commands::Telemetry { command }) => return cli::telemetry::run(command),
         Some(Commands::Mcp { command }) => {
             let profile = cli.profile.clone().unwrap_or_default();
@@ -268,7 +270,8 @@ async fn main() -> Result<()> {
     let profile_explicit = cli.profile.is_some();
     let profile = cli.profile.unwrap_or_default();
 
-    // Seed the session-id poller cap from persisted config. Reached only
+    // Seed the session-id poller cap and the git subprocess timeout from
+    // persisted config. Reached only
     // for commands that may spawn sessions (early-return commands above
     // have already exited). Reuses the config loaded by the logging-init
     // block when available; otherwise loads now. Skips a redundant load
@@ -294,6 +297,7 @@ async fn main() -> Result<()> {
         agent_of_empires::session::poller::set_session_id_poller_max_threads(
             cfg.session.session_id_poller_max_threads,
         );
+        agent_of_empires::git::set_git_timeout_secs(cfg.worktree.git_timeout_secs);
     }
 
     // TUI mode handles migrations with a spinner; CLI runs them silently
@@ -182,6 +182,14 @@ fn set_version(version: u32) -> Result<()> {
 fn get_all_possible_dirs() -> Vec<PathBuf> {
     let mut dirs = Vec::new();
 
+    // An `AOE_DATA_DIR` / `AOE_CONFIG_DIR` override is the only location;
+    // reading the version from the platform dirs would rerun migrations on
+    // every start once `set_version` writes to the override.
+    if crate::session::dir_override_set() {
+        dirs.extend(crate::session::get_app_dir().ok());
+        return dirs;
+    }
+
     // Home-dotfile location: the macOS default, the pre-XDG Linux location, and
     // the only location on Windows.
     if let Some(home) = dirs::home_dir() {
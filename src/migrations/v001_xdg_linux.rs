@@ -17,6 +17,10 @@ pub fn run() -> Result<()> {
         debug!("Skipping v001 XDG migration in debug build (dev namespace is isolated from release data)");
         return Ok(());
     }
+    if crate::session::dir_override_set() {
+        debug!("Skipping v001 XDG migration: AOE_CONFIG_DIR / AOE_DATA_DIR relocates the app dir");
+        return Ok(());
+    }
 
     #[cfg(target_os = "linux")]
     {
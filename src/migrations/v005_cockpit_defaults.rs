@@ -15,7 +15,7 @@ use std::path::Path;
 use tracing::{debug, info};
 
 pub fn run() -> Result<()> {
-    let app_dir = crate::session::get_app_dir()?;
+    let app_dir = crate::session::get_config_dir()?;
     run_in(&app_dir)
 }
 
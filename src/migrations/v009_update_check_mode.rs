@@ -21,7 +21,7 @@ use std::path::PathBuf;
 use tracing::{debug, info};
 
 pub fn run() -> Result<()> {
-    let app_dir = crate::session::get_app_dir()?;
+    let app_dir = crate::session::get_config_dir()?;
 
     let global_config = app_dir.join("config.toml");
     migrate_config_file(&global_config)?;
@@ -19,7 +19,7 @@ const OLD_NAMESPACE: &str = "ghcr.io/njbrake/";
 const NEW_NAMESPACE: &str = "ghcr.io/agent-of-empires/";
 
 pub fn run() -> Result<()> {
-    let app_dir = crate::session::get_app_dir()?;
+    let app_dir = crate::session::get_config_dir()?;
 
     let global_config = app_dir.join("config.toml");
     migrate_config_file(&global_config)?;
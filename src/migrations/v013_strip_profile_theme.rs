@@ -21,7 +21,7 @@ use std::path::Path;
 use tracing::{debug, info};
 
 pub fn run() -> Result<()> {
-    let app_dir = crate::session::get_app_dir()?;
+    let app_dir = crate::session::get_config_dir()?;
     let profiles_dir = app_dir.join("profiles");
     if !profiles_dir.exists() {
         debug!("No profiles dir; nothing to strip for v013");
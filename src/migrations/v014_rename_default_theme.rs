@@ -14,7 +14,7 @@ use std::path::Path;
 use tracing::info;
 
 pub fn run() -> Result<()> {
-    let app_dir = crate::session::get_app_dir()?;
+    let app_dir = crate::session::get_config_dir()?;
     rename_theme(&app_dir.join("config.toml"))
 }
 
@@ -0,0 +1,225 @@
+//! Desktop notifications for session status transitions.
+//!
+//! Shells out to `notify-send` on Linux and `osascript` on macOS. Change
+//! detection and the per-state cooldown are shared with the sound feature
+//! (see [`crate::sound::should_play`]) so both fire on the same transitions.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use aoe_settings_derive::SettingsSection;
+use serde::{Deserialize, Serialize};
+
+use crate::session::{Instance, Status};
+use crate::sound::{should_play, SessionSoundHistory};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SettingsSection)]
+#[setting_section(name = "notifications", category = "Sound")]
+pub struct NotificationConfig {
+    /// Show a desktop notification when a session enters one of the states
+    /// below. Uses notify-send on Linux and osascript on macOS.
+    #[serde(default)]
+    #[setting(label = "Desktop Notifications", widget = "toggle")]
+    pub enabled: bool,
+
+    /// Notify when a session enters Running.
+    #[serde(default)]
+    #[setting(label = "Notify On Running", widget = "toggle")]
+    pub on_running: bool,
+
+    /// Notify when a session enters Waiting.
+    #[serde(default = "default_true")]
+    #[setting(label = "Notify On Waiting", widget = "toggle")]
+    pub on_waiting: bool,
+
+    /// Notify when a session enters Idle.
+    #[serde(default)]
+    #[setting(label = "Notify On Idle", widget = "toggle")]
+    pub on_idle: bool,
+
+    /// Notify when a session enters Error.
+    #[serde(default = "default_true")]
+    #[setting(label = "Notify On Error", widget = "toggle")]
+    pub on_error: bool,
+
+    /// Seconds before the same session may notify again for the same
+    /// state. Matches the sound cooldown's semantics; 0 disables it.
+    #[serde(default = "default_cooldown_seconds")]
+    #[setting(
+        label = "Notification Cooldown (s)",
+        widget = "number",
+        min = 0,
+        advanced
+    )]
+    pub cooldown_seconds: u64,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            on_running: false,
+            on_waiting: true,
+            on_idle: false,
+            on_error: true,
+            cooldown_seconds: default_cooldown_seconds(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_cooldown_seconds() -> u64 {
+    30
+}
+
+impl NotificationConfig {
+    fn wants(&self, status: Status) -> bool {
+        match status {
+            Status::Running => self.on_running,
+            Status::Waiting => self.on_waiting,
+            Status::Idle => self.on_idle,
+            Status::Error => self.on_error,
+            Status::Starting
+            | Status::Unknown
+            | Status::Stopped
+            | Status::Deleting
+            | Status::Creating => false,
+        }
+    }
+}
+
+fn notification_history() -> &'static Mutex<HashMap<String, SessionSoundHistory>> {
+    static HISTORY: OnceLock<Mutex<HashMap<String, SessionSoundHistory>>> = OnceLock::new();
+    HISTORY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Notification body for a transition into `new`.
+fn body_for(instance: &Instance, new: Status) -> String {
+    match new {
+        Status::Waiting => "Waiting for input".to_string(),
+        Status::Error => match instance.last_error.as_deref() {
+            Some(err) if !err.is_empty() => format!("Error: {err}"),
+            _ => "Error".to_string(),
+        },
+        Status::Running => "Running".to_string(),
+        Status::Idle => "Idle".to_string(),
+        other => other.as_str().to_string(),
+    }
+}
+
+/// Show a desktop notification for a session's state transition (if enabled,
+/// the new state is selected, and the state isn't inside its cooldown for
+/// this session).
+pub fn notify_for_transition(
+    instance: &Instance,
+    old: Status,
+    new: Status,
+    config: &NotificationConfig,
+) {
+    if !config.enabled || old == new {
+        return;
+    }
+
+    // Record every transition, including unselected ones, so a session that
+    // leaves Waiting and comes back is seen as a fresh transition.
+    let cooldown = Duration::from_secs(config.cooldown_seconds);
+    {
+        let mut history = notification_history()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if !should_play(&mut history, &instance.id, new, cooldown, Instant::now()) {
+            return;
+        }
+    }
+    if !config.wants(new) {
+        return;
+    }
+
+    let title = instance.title.clone();
+    let body = body_for(instance, new);
+    std::thread::spawn(move || {
+        let Some((cmd, args)) = notifier_command(&title, &body) else {
+            return;
+        };
+        match std::process::Command::new(cmd)
+            .args(&args)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+        {
+            Ok(status) if !status.success() => {
+                tracing::debug!(target: "tui.notifications", "{cmd} exited with {status}");
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(target: "tui.notifications", "Desktop notifier {cmd} unavailable: {e}");
+            }
+        }
+    });
+}
+
+/// The platform notifier invocation, or `None` on platforms without one.
+fn notifier_command(title: &str, body: &str) -> Option<(&'static str, Vec<String>)> {
+    if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {} subtitle \"Agent of Empires\"",
+            applescript_string(body),
+            applescript_string(title)
+        );
+        Some(("osascript", vec!["-e".to_string(), script]))
+    } else if cfg!(target_os = "linux") {
+        Some((
+            "notify-send",
+            vec![
+                "--app-name=Agent of Empires".to_string(),
+                title.to_string(),
+                body.to_string(),
+            ],
+        ))
+    } else {
+        None
+    }
+}
+
+/// Quote `s` as an AppleScript string literal.
+fn applescript_string(s: &str) -> String {
+    let escaped = s.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_notifies_on_waiting_and_error_only() {
+        let config = NotificationConfig::default();
+        assert!(!config.enabled);
+        assert!(config.wants(Status::Waiting));
+        assert!(config.wants(Status::Error));
+        assert!(!config.wants(Status::Running));
+        assert!(!config.wants(Status::Idle));
+        assert!(!config.wants(Status::Deleting));
+    }
+
+    #[test]
+    fn test_partial_section_keeps_state_defaults() {
+        let config: NotificationConfig = toml::from_str("enabled = true").unwrap();
+        assert!(config.enabled);
+        assert!(config.on_waiting);
+        assert!(config.on_error);
+        assert_eq!(config.cooldown_seconds, 30);
+    }
+
+    #[test]
+    fn test_applescript_string_escapes_quotes_and_backslashes() {
+        assert_eq!(
+            applescript_string(r#"fix "the" \bug"#),
+            r#""fix \"the\" \\bug""#
+        );
+    }
+}
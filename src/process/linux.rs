@@ -4,6 +4,10 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+/// Opener for `open_in_file_manager`; delegates to the desktop's default
+/// file manager via the freedesktop `xdg-open` helper.
+pub(super) const FILE_MANAGER_OPENER: &str = "xdg-open";
+
 /// Collect `pid` and every descendant by walking `/proc` once to build a
 /// parent -> children map, then descending it. One `/proc` scan regardless of
 /// tree depth.
@@ -3,6 +3,10 @@
 use std::collections::HashMap;
 use std::process::Command;
 
+/// Opener for `open_in_file_manager`; `open` on a directory reveals it in
+/// Finder.
+pub(super) const FILE_MANAGER_OPENER: &str = "open";
+
 /// Collect `pid` and every descendant by parsing `ps -A` once and walking the map.
 pub(super) fn collect_pid_tree(pid: u32) -> Vec<u32> {
     let children_map = build_children_map();
@@ -1,6 +1,7 @@
 //! Process utilities for tmux session management
 
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, Stdio};
 use std::time::Duration;
 
 #[cfg(any(target_os = "linux", target_os = "macos"))]
@@ -85,6 +86,39 @@ pub fn get_foreground_pid(shell_pid: u32) -> Option<u32> {
     pid
 }
 
+/// Open `path` in the OS file manager (`open` on macOS, `xdg-open` on Linux).
+/// The opener's stdio is nulled so it cannot write over the TUI, and it is
+/// reaped on a background thread; only a failure to spawn is reported.
+pub fn open_in_file_manager(path: &Path) -> std::io::Result<()> {
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    {
+        #[cfg(target_os = "linux")]
+        let opener = linux::FILE_MANAGER_OPENER;
+        #[cfg(target_os = "macos")]
+        let opener = macos::FILE_MANAGER_OPENER;
+
+        let mut child = Command::new(opener)
+            .arg(path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        let _ = path;
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "no file manager opener for this platform",
+        ))
+    }
+}
+
 /// Kill a process and all its descendants
 /// Sends SIGTERM first, then SIGKILL to any survivors
 pub fn kill_process_tree(pid: u32) {
@@ -473,14 +473,19 @@ fn should_auto_stop(
 /// is re-checked immediately before shutdown to avoid killing a worker a
 /// prompt started in the gap since the candidate snapshot.
 async fn reap_idle_workers(state: &Arc<AppState>) {
-    // Candidates: structured view sessions not already sunk/dormant. Snapshot
-    // (id, profile) under the read lock so we don't hold it across awaits.
+    // Candidates: structured view sessions not already sunk/dormant or
+    // locked. Snapshot (id, profile) under the read lock so we don't hold it
+    // across awaits.
     let candidates: Vec<(String, String)> = {
         let instances = state.instances.read().await;
         instances
             .iter()
             .filter(|i| {
-                i.is_structured() && !i.is_archived() && !i.is_snoozed() && !i.is_idle_dormant()
+                i.is_structured()
+                    && !i.worktree_locked
+                    && !i.is_archived()
+                    && !i.is_snoozed()
+                    && !i.is_idle_dormant()
             })
             .map(|i| (i.id.clone(), i.source_profile.clone()))
             .collect()
@@ -468,7 +468,7 @@ pub async fn list_sessions(State(state): State<Arc<AppState>>) -> Json<SessionsE
                 let cfg = crate::session::profile_config::resolve_config_or_warn(&session.profile);
                 CleanupDefaults {
                     delete_worktree: cfg.worktree.auto_cleanup,
-                    delete_branch: cfg.worktree.delete_branch_on_cleanup,
+                    delete_branch: cfg.worktree.delete_branch_on_cleanup.deletes(),
                     delete_sandbox: cfg.sandbox.auto_cleanup,
                 }
             });
@@ -2498,6 +2498,13 @@ pub async fn delete_session(
 
     // Run deletion on a blocking thread (may do git/docker/tmux operations)
     let deletion_id = id.clone();
+    let keep_unmerged_branch = crate::session::repo_config::resolve_config_with_repo_or_warn(
+        &profile,
+        std::path::Path::new(&instance.project_path),
+    )
+    .worktree
+    .delete_branch_on_cleanup
+        == crate::session::BranchCleanup::IfMerged;
     let deletion_result = tokio::task::spawn_blocking(move || {
         crate::session::deletion::perform_deletion(&crate::session::deletion::DeletionRequest {
             session_id: deletion_id,
@@ -2508,6 +2515,7 @@ pub async fn delete_session(
             force_delete: body.force_delete,
             detach_hooks: true,
             keep_scratch: body.keep_scratch,
+            keep_unmerged_branch,
         })
     })
     .await;
@@ -2634,6 +2642,10 @@ pub struct CreateSessionBody {
     pub group: String,
     #[serde(default)]
     pub yolo_mode: bool,
+    /// Accept `yolo_mode` without `sandbox`. Creation is refused otherwise;
+    /// see `builder::check_yolo_sandbox`.
+    #[serde(default)]
+    pub allow_unsandboxed_yolo: bool,
     pub worktree_branch: Option<String>,
     #[serde(default)]
     pub create_new_branch: bool,
@@ -2643,6 +2655,10 @@ pub struct CreateSessionBody {
     /// default branch. See #948.
     #[serde(default)]
     pub base_branch: Option<String>,
+    /// What to do when the worktree path already exists: `error` (the
+    /// default), `adopt`, or `recreate`.
+    #[serde(default)]
+    pub on_exists: crate::git::OnExists,
     #[serde(default)]
     pub sandbox: bool,
     #[serde(default)]
@@ -3087,8 +3103,40 @@ pub async fn create_session(
             .into_response();
     }
 
+    // The builder enforces this too; checking here turns it into a 400
+    // instead of a builder bail surfaced as 500.
+    if let Err(e) = crate::session::builder::check_yolo_sandbox(
+        &body.tool,
+        body.yolo_mode,
+        body.sandbox,
+        body.allow_unsandboxed_yolo,
+    ) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "validation_failed",
+                "message": e.to_string(),
+            })),
+        )
+            .into_response();
+    }
+
     let profile = body.profile.unwrap_or_else(|| state.profile.clone());
+    let session_config = crate::session::profile_config::resolve_config_or_warn(&profile).session;
     let instances = state.instances.read().await;
+    let active = crate::session::config::count_active_sessions(
+        instances.iter().filter(|i| i.source_profile == profile),
+    );
+    if let Err(e) = session_config.check_session_limit(active, 1) {
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": "session_limit",
+                "message": e.to_string(),
+            })),
+        )
+            .into_response();
+    }
     let existing_titles: Vec<String> = instances.iter().map(|i| i.title.clone()).collect();
     let existing_branches: Vec<String> = instances
         .iter()
@@ -3167,14 +3215,22 @@ pub async fn create_session(
             } else {
                 None
             },
+            worktree_on_exists: body.on_exists,
             sandbox: body.sandbox,
             sandbox_image,
             yolo_mode: body.yolo_mode,
+            allow_unsandboxed_yolo: body.allow_unsandboxed_yolo,
             extra_env: body.extra_env,
+            cpu_limit: None,
+            memory_limit: None,
+            custom_instruction: None,
             extra_args: body.extra_args,
             command_override: body.command_override,
             extra_repo_paths,
             scratch: body.scratch,
+            auto_create_terminal: crate::session::profile_config::resolve_config_or_warn(&profile)
+                .session
+                .auto_create_terminal,
         };
 
         let build_result = builder::build_instance(params, &title_refs, &branch_refs, &profile)?;
@@ -3544,12 +3600,14 @@ fn public_create_session_error(e: &anyhow::Error) -> String {
     if let Some(git_err) = e.chain().find_map(|c| c.downcast_ref::<GitError>()) {
         match git_err {
             GitError::WorktreeAlreadyExists(_)
-            | GitError::BranchAlreadyCheckedOut(_)
+            | GitError::WorktreeDirty { .. }
+            | GitError::BranchAlreadyCheckedOut { .. }
             | GitError::BranchNotFound(_)
             | GitError::NotAGitRepo => return git_err.to_string(),
             // Raw command output / libgit2 / IO: not safe to expose.
             GitError::WorktreeCommandFailed(_)
             | GitError::CloneFailed(_)
+            | GitError::Timeout(_)
             | GitError::WorktreeNotFound(_)
             | GitError::Git2Error(_)
             | GitError::IoError(_) => {}
@@ -4635,8 +4693,11 @@ mod tests {
             "Worktree already exists at /tmp/repo-worktrees/foo"
         );
 
-        let in_use: anyhow::Error =
-            GitError::BranchAlreadyCheckedOut("feature/foo".to_string()).into();
+        let in_use: anyhow::Error = GitError::BranchAlreadyCheckedOut {
+            branch: "feature/foo".to_string(),
+            existing_path: Some(std::path::PathBuf::from("/home/alice/repo-worktrees/foo")),
+        }
+        .into();
         assert_eq!(
             public_create_session_error(&in_use),
             "Branch 'feature/foo' is already in use by another worktree"
@@ -0,0 +1,258 @@
+//! Activity log: what aoe did to sessions, worktrees, and containers.
+//!
+//! Records are appended where the shared create / stop / delete paths mutate
+//! state, so the CLI, TUI, and `aoe serve` all feed the same log. With
+//! `session.persist_activity_log` on (the default) every record goes to
+//! `activity.jsonl` in the app dir, capped at [`MAX_RECORDS`] entries.
+//! Destructive records (deletions, force removals, branch deletes) are
+//! written there regardless of the setting, so the audit trail for anything
+//! that cannot be undone always survives; the rest stay in a per-process
+//! in-memory ring buffer when persistence is off.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+
+use super::Instance;
+
+/// Records kept on disk and in memory. Older ones are dropped.
+pub const MAX_RECORDS: usize = 500;
+
+const LOG_FILENAME: &str = "activity.jsonl";
+
+/// Records that were not persisted, newest last.
+static MEMORY: Mutex<VecDeque<ActivityRecord>> = Mutex::new(VecDeque::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    SessionCreated,
+    SessionStopped,
+    SessionDeleted,
+    ContainerCreated,
+    ContainerStarted,
+    ContainerStopped,
+    ContainerRebuilt,
+    ContainerRemoved,
+    WorktreeRemoved,
+    BranchDeleted,
+}
+
+impl ActivityKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ActivityKind::SessionCreated => "session created",
+            ActivityKind::SessionStopped => "session stopped",
+            ActivityKind::SessionDeleted => "session deleted",
+            ActivityKind::ContainerCreated => "container created",
+            ActivityKind::ContainerStarted => "container started",
+            ActivityKind::ContainerStopped => "container stopped",
+            ActivityKind::ContainerRebuilt => "container rebuilt",
+            ActivityKind::ContainerRemoved => "container removed",
+            ActivityKind::WorktreeRemoved => "worktree removed",
+            ActivityKind::BranchDeleted => "branch deleted",
+        }
+    }
+
+    /// Whether the action threw data away. These are always persisted.
+    pub fn is_destructive(self) -> bool {
+        matches!(
+            self,
+            ActivityKind::SessionDeleted
+                | ActivityKind::ContainerRebuilt
+                | ActivityKind::ContainerRemoved
+                | ActivityKind::WorktreeRemoved
+                | ActivityKind::BranchDeleted
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityRecord {
+    pub at: DateTime<Utc>,
+    pub kind: ActivityKind,
+    pub session_id: String,
+    pub title: String,
+    /// Free-form specifics: the branch name, worktree path, `forced`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub detail: String,
+}
+
+impl ActivityRecord {
+    pub fn new(kind: ActivityKind, instance: &Instance, detail: impl Into<String>) -> Self {
+        Self {
+            at: Utc::now(),
+            kind,
+            session_id: instance.id.clone(),
+            title: instance.title.clone(),
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Record `kind` for `instance`. Never fails: a log that cannot be written
+/// is reported through tracing and must not block the action it describes.
+pub fn record(kind: ActivityKind, instance: &Instance, detail: impl Into<String>) {
+    record_entry(ActivityRecord::new(kind, instance, detail));
+}
+
+pub fn record_entry(entry: ActivityRecord) {
+    tracing::info!(
+        target: "session.activity",
+        kind = entry.kind.label(),
+        session_id = %entry.session_id,
+        title = %entry.title,
+        detail = %entry.detail,
+        "activity"
+    );
+    let persist = entry.kind.is_destructive()
+        || super::config::Config::load_or_warn()
+            .session
+            .persist_activity_log;
+    if persist {
+        match log_path().and_then(|path| append_to(&path, &entry, MAX_RECORDS)) {
+            Ok(()) => return,
+            Err(e) => {
+                tracing::warn!(target: "session.activity", error = %e, "failed to persist activity record");
+            }
+        }
+    }
+    let mut memory = MEMORY.lock().unwrap_or_else(|e| e.into_inner());
+    if memory.len() == MAX_RECORDS {
+        memory.pop_front();
+    }
+    memory.push_back(entry);
+}
+
+/// The most recent records, newest first: the persisted log merged with
+/// this process's unpersisted ones.
+pub fn recent() -> Vec<ActivityRecord> {
+    let mut records = log_path().map(|path| read_from(&path)).unwrap_or_default();
+    records.extend(
+        MEMORY
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned(),
+    );
+    records.sort_by_key(|r| std::cmp::Reverse(r.at));
+    records.truncate(MAX_RECORDS);
+    records
+}
+
+fn log_path() -> Result<PathBuf> {
+    Ok(super::get_app_dir()?.join(LOG_FILENAME))
+}
+
+/// Append `entry` under an exclusive lock so concurrent aoe processes never
+/// interleave lines. Once the file holds twice `max` records it is rewritten
+/// down to the newest `max`, which keeps appends cheap between trims.
+fn append_to(path: &Path, entry: &ActivityRecord, max: usize) -> Result<()> {
+    let mut file = open_log(path)?;
+    file.lock_exclusive()?;
+    let result = (|| -> Result<()> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        let count = content.lines().count();
+        if count < 2 * max {
+            file.seek(SeekFrom::End(0))?;
+            file.write_all(line.as_bytes())?;
+            return Ok(());
+        }
+
+        let kept: Vec<&str> = content.lines().skip(count + 1 - max).collect();
+        let mut rewritten = kept.join("\n");
+        rewritten.push('\n');
+        rewritten.push_str(&line);
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(rewritten.as_bytes())?;
+        Ok(())
+    })();
+    let _ = FileExt::unlock(&file);
+    result
+}
+
+fn open_log(path: &Path) -> Result<File> {
+    let mut options = OpenOptions::new();
+    options.read(true).write(true).create(true).truncate(false);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    Ok(options.open(path)?)
+}
+
+/// Parse the persisted log, skipping lines that do not parse (a record
+/// written by a newer aoe with an unknown kind, or a torn write).
+fn read_from(path: &Path) -> Vec<ActivityRecord> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: ActivityKind, n: usize) -> ActivityRecord {
+        ActivityRecord {
+            at: Utc::now(),
+            kind,
+            session_id: format!("id-{n}"),
+            title: format!("session {n}"),
+            detail: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_append_keeps_newest_records_once_past_twice_the_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILENAME);
+        for n in 0..8 {
+            append_to(&path, &entry(ActivityKind::SessionCreated, n), 3).unwrap();
+        }
+        let records = read_from(&path);
+        // The 7th append found 6 lines and trimmed to the newest 3 (4, 5 and
+        // itself); the 8th appended normally.
+        let ids: Vec<_> = records.iter().map(|r| r.session_id.as_str()).collect();
+        assert_eq!(ids, ["id-4", "id-5", "id-6", "id-7"]);
+    }
+
+    #[test]
+    fn test_read_skips_unparseable_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILENAME);
+        append_to(&path, &entry(ActivityKind::BranchDeleted, 1), 10).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "{{\"kind\":\"something_new\"}}").unwrap();
+        writeln!(file, "not json").unwrap();
+        let records = read_from(&path);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, ActivityKind::BranchDeleted);
+    }
+
+    #[test]
+    fn test_destructive_kinds() {
+        assert!(ActivityKind::SessionDeleted.is_destructive());
+        assert!(ActivityKind::BranchDeleted.is_destructive());
+        assert!(ActivityKind::WorktreeRemoved.is_destructive());
+        assert!(!ActivityKind::SessionCreated.is_destructive());
+        assert!(!ActivityKind::ContainerStopped.is_destructive());
+    }
+}
@@ -0,0 +1,143 @@
+//! Render a session as the `aoe add` command line that recreates it.
+//!
+//! Pure serialization of the persisted `Instance` / `SandboxInfo` fields
+//! into flags, shell-quoted so the result pastes into a POSIX shell on
+//! another machine. Fields that `aoe add` derives on its own (container
+//! names, ids, timestamps) are left out.
+
+use crate::session::Instance;
+
+/// Build the `aoe add ...` command that recreates `inst`.
+pub fn add_command_for(inst: &Instance, profile: &str) -> String {
+    let mut args: Vec<String> = vec!["aoe".into()];
+    if !profile.is_empty() && profile != "default" {
+        args.extend(["--profile".into(), profile.to_string()]);
+    }
+    args.push("add".into());
+
+    if inst.scratch {
+        args.push("--scratch".into());
+    } else if let Some(ws) = &inst.workspace_info {
+        let mut repos = ws.repos.iter();
+        if let Some(primary) = repos.next() {
+            args.push(primary.source_path.clone());
+        }
+        args.extend(["-w".into(), ws.branch.clone()]);
+        for repo in repos {
+            args.extend(["-r".into(), repo.source_path.clone()]);
+        }
+    } else if let Some(wt) = &inst.worktree_info {
+        args.push(wt.main_repo_path.clone());
+        args.extend(["-w".into(), wt.branch.clone()]);
+        // Only a branch aoe created records its base, so that is the
+        // signal the original add passed `--new-branch`.
+        if let Some(base) = &wt.base_branch {
+            args.extend(["-b".into(), "--base-branch".into(), base.clone()]);
+        }
+    } else {
+        args.push(inst.project_path.clone());
+    }
+
+    args.extend(["-t".into(), inst.title.clone()]);
+    if !inst.group_path.is_empty() {
+        args.extend(["-g".into(), inst.group_path.clone()]);
+    }
+    args.extend(["--tool".into(), inst.tool.clone()]);
+
+    let default_binary = crate::agents::get_agent(&inst.tool).map(|a| a.binary);
+    if !inst.command.is_empty() && Some(inst.command.as_str()) != default_binary {
+        args.extend(["--cmd-override".into(), inst.command.clone()]);
+    }
+    if !inst.extra_args.is_empty() {
+        args.push(format!("--extra-args={}", inst.extra_args));
+    }
+
+    if let Some(sandbox) = inst.sandbox_info.as_ref().filter(|s| s.enabled) {
+        args.extend(["--sandbox-image".into(), sandbox.image.clone()]);
+        for entry in sandbox.extra_env.iter().flatten() {
+            args.extend(["--env".into(), entry.clone()]);
+        }
+    }
+
+    if inst.yolo_mode {
+        args.push("-y".into());
+        if !inst.is_sandboxed() {
+            args.push("--i-know-what-im-doing".into());
+        }
+    }
+
+    // The view flags only exist in `serve` builds.
+    #[cfg(feature = "serve")]
+    if inst.is_structured() {
+        args.push("--structured-view".into());
+        if let Some(agent) = &inst.agent_name {
+            args.extend(["--agent".into(), agent.clone()]);
+        }
+        if let Some(model) = &inst.agent_model {
+            args.extend(["--model".into(), model.clone()]);
+        }
+    }
+
+    shell_words::join(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{SandboxInfo, WorktreeInfo};
+    use chrono::Utc;
+
+    #[test]
+    fn plain_session_renders_path_title_and_tool() {
+        let mut inst = Instance::new("my session", "/work/my repo");
+        inst.tool = "claude".into();
+        assert_eq!(
+            add_command_for(&inst, "default"),
+            "aoe add '/work/my repo' -t 'my session' --tool claude"
+        );
+    }
+
+    #[test]
+    fn worktree_sandbox_and_yolo_flags_round_trip() {
+        let mut inst = Instance::new("feat", "/work/repo-wt/feat");
+        inst.tool = "codex".into();
+        inst.group_path = "team/app".into();
+        inst.yolo_mode = true;
+        inst.extra_args = "--model o3".into();
+        inst.worktree_info = Some(WorktreeInfo {
+            branch: "feat/x".into(),
+            main_repo_path: "/work/repo".into(),
+            managed_by_aoe: true,
+            created_at: Utc::now(),
+            base_branch: Some("main".into()),
+        });
+        inst.sandbox_info = Some(SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ghcr.io/acme/dev:1".into(),
+            container_name: "aoe-sandbox-1234".into(),
+            extra_env: Some(vec!["GH_TOKEN".into(), "MODE=a b".into()]),
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        });
+
+        let rendered = add_command_for(&inst, "work");
+        assert_eq!(
+            rendered,
+            "aoe --profile work add /work/repo -w feat/x -b --base-branch main -t feat \
+             -g team/app --tool codex '--extra-args=--model o3' \
+             --sandbox-image ghcr.io/acme/dev:1 --env GH_TOKEN --env 'MODE=a b' -y"
+        );
+        let words = shell_words::split(&rendered).unwrap();
+        assert!(words.contains(&"MODE=a b".to_string()));
+    }
+
+    #[test]
+    fn unsandboxed_yolo_carries_the_override_flag() {
+        let mut inst = Instance::new("t", "/p");
+        inst.tool = "claude".into();
+        inst.yolo_mode = true;
+        assert!(add_command_for(&inst, "default").ends_with(" -y --i-know-what-im-doing"));
+    }
+}
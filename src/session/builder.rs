@@ -5,13 +5,13 @@
 
 use std::path::PathBuf;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use chrono::Utc;
 
 use crate::containers::{self, ContainerRuntimeInterface};
-use crate::git::error::GitError;
-use crate::git::GitWorktree;
+use crate::git::{GitWorktree, OnExists};
 
+use super::activity::{self, ActivityKind};
 use super::{
     civilizations, Config, Instance, SandboxInfo, WorkspaceInfo, WorkspaceRepo, WorktreeInfo,
 };
@@ -30,13 +30,27 @@ pub struct InstanceParams {
     /// when `create_new_branch` is true. `None` falls back to the
     /// repository's detected default branch. See #948.
     pub base_branch: Option<String>,
+    /// What to do when the computed worktree path already exists. Only
+    /// consulted for single-repo worktree sessions.
+    pub worktree_on_exists: OnExists,
     pub sandbox: bool,
     /// The sandbox image to use. Required when sandbox is true.
     pub sandbox_image: String,
     pub yolo_mode: bool,
+    /// Caller explicitly accepted YOLO mode without a sandbox. See
+    /// [`check_yolo_sandbox`].
+    pub allow_unsandboxed_yolo: bool,
     /// Additional environment entries for the container.
     /// `KEY` = pass through from host, `KEY=VALUE` = set explicitly.
     pub extra_env: Vec<String>,
+    /// Per-session container CPU limit, overriding `sandbox.cpu_limit`.
+    pub cpu_limit: Option<String>,
+    /// Per-session container memory limit, overriding `sandbox.memory_limit`.
+    pub memory_limit: Option<String>,
+    /// Initial instruction for the agent. Overrides
+    /// `sandbox.custom_instruction` for sandboxed sessions; host sessions
+    /// get it through the same instruction flag.
+    pub custom_instruction: Option<String>,
     /// Extra arguments to append after the agent binary
     pub extra_args: String,
     /// Command override for the agent binary (replaces the default binary)
@@ -48,6 +62,75 @@ pub struct InstanceParams {
     /// the deletion path removes the directory. Mutually exclusive with
     /// worktree/workspace and with non-empty `extra_repo_paths`.
     pub scratch: bool,
+    /// Open the paired terminal whenever the session starts. See
+    /// `Instance::auto_create_terminal`.
+    pub auto_create_terminal: bool,
+}
+
+/// Refuse YOLO mode for a session that will run directly on the host unless
+/// the caller passed an explicit override: an unsandboxed agent that skips
+/// permission prompts has full access to the host. Agents that always run in
+/// YOLO mode have no opt-in to refuse and are exempt. Every session-creation
+/// entry point calls this before any side effects.
+pub fn check_yolo_sandbox(
+    tool: &str,
+    yolo_mode: bool,
+    sandboxed: bool,
+    allow_unsandboxed: bool,
+) -> Result<()> {
+    let always_yolo = crate::agents::get_agent(tool)
+        .is_some_and(|a| matches!(a.yolo, Some(crate::agents::YoloMode::AlwaysYolo)));
+    if yolo_mode && !sandboxed && !allow_unsandboxed && !always_yolo {
+        bail!(
+            "Refusing to enable YOLO mode without a sandbox: the agent would skip \
+             permission prompts with full access to the host.\n\
+             Tip: enable the sandbox, or override with --i-know-what-im-doing (CLI) \
+             or allow_unsandboxed_yolo (API)"
+        );
+    }
+    Ok(())
+}
+
+/// Largest initial instruction accepted from a file or stdin. The text is
+/// passed on the agent's command line inside tmux's `new-session` command,
+/// which tmux rejects above roughly 16 KiB; 8 KiB leaves room for the rest
+/// of the launch command and shell quoting.
+pub const MAX_INSTRUCTION_BYTES: u64 = 8 * 1024;
+
+/// Read a session's initial instruction from `path`, rejecting missing
+/// files, directories, oversized or non-UTF-8 content, and blank files.
+pub fn read_instruction_file(path: &std::path::Path) -> Result<String> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Cannot read instruction file {}", path.display()))?;
+    if !file.metadata()?.is_file() {
+        bail!("Instruction file is not a regular file: {}", path.display());
+    }
+    read_instruction(file, &path.display().to_string())
+}
+
+/// Read an initial instruction from `reader` with the same checks as
+/// [`read_instruction_file`]. `source` names the input in error messages.
+pub fn read_instruction(reader: impl std::io::Read, source: &str) -> Result<String> {
+    use std::io::Read;
+
+    let mut buf = Vec::new();
+    reader
+        .take(MAX_INSTRUCTION_BYTES + 1)
+        .read_to_end(&mut buf)
+        .with_context(|| format!("Cannot read instruction from {source}"))?;
+    if buf.len() as u64 > MAX_INSTRUCTION_BYTES {
+        bail!(
+            "Instruction from {source} exceeds {} KiB",
+            MAX_INSTRUCTION_BYTES / 1024
+        );
+    }
+    let text = String::from_utf8(buf)
+        .map_err(|_| anyhow::anyhow!("Instruction from {source} is not valid UTF-8"))?;
+    let text = text.trim();
+    if text.is_empty() {
+        bail!("Instruction from {source} is empty");
+    }
+    Ok(text.to_string())
 }
 
 /// Result of building an instance, tracking what was created for cleanup purposes.
@@ -103,7 +186,7 @@ pub(crate) fn resolve_base_branch(
 /// the lookup keys on `find_main_repo(repo_path)`; `repo_path` itself may be a
 /// subdirectory or a worktree, which would miss a root-keyed entry. Precedence:
 /// explicit session base > per-project default > global/profile default.
-fn resolve_repo_base_branch(
+pub(crate) fn resolve_repo_base_branch(
     repo_path: &std::path::Path,
     session: Option<&str>,
     project_bases: &std::collections::HashMap<String, String>,
@@ -116,6 +199,16 @@ fn resolve_repo_base_branch(
     resolve_base_branch(session, project, global)
 }
 
+/// Group derived from the project's `origin` remote when
+/// `session.auto_group_from_remote` is on, for sessions created without an
+/// explicit group. Shared by the TUI/web builder and `aoe add`.
+pub(crate) fn remote_group(project_path: &str, config: &Config) -> Option<String> {
+    if !config.session.auto_group_from_remote {
+        return None;
+    }
+    crate::git::get_remote_repo_slug(std::path::Path::new(project_path))
+}
+
 /// Map of canonical repo path to configured default base branch for every
 /// registered project (global + profile) that sets one. Used to fill in the
 /// per-project layer of `resolve_repo_base_branch` for the launch repo and any
@@ -167,9 +260,10 @@ pub fn create_workspace(
     create_new_branch: bool,
     workspace_template: &str,
     init_submodules: bool,
+    worktree_root: Option<PathBuf>,
 ) -> Result<WorkspaceResult> {
     let primary_main_repo = GitWorktree::find_main_repo(&primary.path)?;
-    let primary_git_wt = GitWorktree::new(primary_main_repo)?;
+    let primary_git_wt = GitWorktree::new(primary_main_repo)?.with_worktree_root(worktree_root);
 
     let session_id = uuid::Uuid::new_v4().to_string();
     let session_id_short = &session_id[..8];
@@ -390,6 +484,13 @@ pub fn build_instance(
         bail!("{} does not support worktree mode.", params.tool);
     }
 
+    check_yolo_sandbox(
+        &params.tool,
+        params.yolo_mode,
+        params.sandbox,
+        params.allow_unsandboxed_yolo,
+    )?;
+
     if params.scratch {
         if params.worktree_enabled {
             bail!("Cannot combine --scratch with worktree mode");
@@ -519,6 +620,7 @@ pub fn build_instance(
                 params.create_new_branch,
                 &config.worktree.workspace_path_template,
                 config.worktree.init_submodules,
+                config.worktree.root_path(),
             )?;
 
             final_path = ws_result.workspace_path.to_string_lossy().to_string();
@@ -536,7 +638,8 @@ pub fn build_instance(
                 .canonicalize()
                 .unwrap_or(main_repo_path_raw);
             let git_wt = GitWorktree::new(main_repo_path.clone())?
-                .with_init_submodules(config.worktree.init_submodules);
+                .with_init_submodules(config.worktree.init_submodules)
+                .with_worktree_root(config.worktree.root_path());
 
             // Choose appropriate template based on repo type (bare vs regular)
             // Use main_repo_path (not path) to correctly detect bare repos when running from a worktree
@@ -565,10 +668,79 @@ pub fn build_instance(
                     let session_id = uuid::Uuid::new_v4().to_string();
                     let worktree_path = git_wt.compute_path(branch, template, &session_id[..8])?;
 
-                    let w = git_wt.create_worktree(branch, &worktree_path, false, None)?;
+                    if git_wt.resolve_existing_path(
+                        branch,
+                        &worktree_path,
+                        params.worktree_on_exists,
+                    )? {
+                        final_path = worktree_path.to_string_lossy().to_string();
+                        worktree_info = Some(WorktreeInfo {
+                            branch: branch.clone(),
+                            main_repo_path: main_repo_path.to_string_lossy().to_string(),
+                            managed_by_aoe: false,
+                            created_at: Utc::now(),
+                            base_branch: None,
+                        });
+                    } else {
+                        let w = git_wt.create_worktree(branch, &worktree_path, false, None)?;
+                        warnings.extend(w);
+
+                        final_path = worktree_path.to_string_lossy().to_string();
+                        created_worktree = Some(CreatedWorktree {
+                            path: worktree_path,
+                            main_repo_path: main_repo_path.clone(),
+                        });
+                        worktree_info = Some(WorktreeInfo {
+                            branch: branch.clone(),
+                            main_repo_path: main_repo_path.to_string_lossy().to_string(),
+                            managed_by_aoe: true,
+                            created_at: Utc::now(),
+                            base_branch: None,
+                        });
+                    }
+                }
+            } else {
+                let session_id = uuid::Uuid::new_v4().to_string();
+                let worktree_path = git_wt.compute_path(branch, template, &session_id[..8])?;
+
+                let recreating = worktree_path.exists();
+                let adopted = git_wt.resolve_existing_path(
+                    branch,
+                    &worktree_path,
+                    params.worktree_on_exists,
+                )?;
+                final_path = worktree_path.to_string_lossy().to_string();
+                if adopted {
+                    worktree_info = Some(WorktreeInfo {
+                        branch: branch.clone(),
+                        main_repo_path: main_repo_path.to_string_lossy().to_string(),
+                        managed_by_aoe: false,
+                        created_at: Utc::now(),
+                        base_branch: None,
+                    });
+                } else {
+                    // The launch repo forks from its registered per-project default
+                    // when no explicit session base is given (then global/profile,
+                    // then auto-detect). Keyed by repo root via the shared helper.
+                    let project_bases = project_base_branches(profile);
+                    let base = resolve_repo_base_branch(
+                        &main_repo_path,
+                        params.base_branch.as_deref(),
+                        &project_bases,
+                        config.worktree.default_base_branch.as_deref(),
+                    );
+
+                    // A recreated worktree leaves its branch behind; check it
+                    // out again rather than failing to create it a second time.
+                    let create_branch = !(recreating && git_wt.branch_exists(branch));
+                    let w = git_wt.create_worktree(
+                        branch,
+                        &worktree_path,
+                        create_branch,
+                        base.as_deref(),
+                    )?;
                     warnings.extend(w);
 
-                    final_path = worktree_path.to_string_lossy().to_string();
                     created_worktree = Some(CreatedWorktree {
                         path: worktree_path,
                         main_repo_path: main_repo_path.clone(),
@@ -578,43 +750,9 @@ pub fn build_instance(
                         main_repo_path: main_repo_path.to_string_lossy().to_string(),
                         managed_by_aoe: true,
                         created_at: Utc::now(),
-                        base_branch: None,
+                        base_branch: base,
                     });
                 }
-            } else {
-                let session_id = uuid::Uuid::new_v4().to_string();
-                let worktree_path = git_wt.compute_path(branch, template, &session_id[..8])?;
-
-                if worktree_path.exists() {
-                    return Err(GitError::WorktreeAlreadyExists(worktree_path.clone()).into());
-                }
-
-                // The launch repo forks from its registered per-project default
-                // when no explicit session base is given (then global/profile,
-                // then auto-detect). Keyed by repo root via the shared helper.
-                let project_bases = project_base_branches(profile);
-                let base = resolve_repo_base_branch(
-                    &main_repo_path,
-                    params.base_branch.as_deref(),
-                    &project_bases,
-                    config.worktree.default_base_branch.as_deref(),
-                );
-
-                let w = git_wt.create_worktree(branch, &worktree_path, true, base.as_deref())?;
-                warnings.extend(w);
-
-                final_path = worktree_path.to_string_lossy().to_string();
-                created_worktree = Some(CreatedWorktree {
-                    path: worktree_path,
-                    main_repo_path: main_repo_path.clone(),
-                });
-                worktree_info = Some(WorktreeInfo {
-                    branch: branch.clone(),
-                    main_repo_path: main_repo_path.to_string_lossy().to_string(),
-                    managed_by_aoe: true,
-                    created_at: Utc::now(),
-                    base_branch: base,
-                });
             }
         }
     }
@@ -641,6 +779,11 @@ pub fn build_instance(
         instance.scratch = true;
     }
     instance.group_path = params.group;
+    if !params.scratch && instance.group_path.trim().is_empty() {
+        if let Some(group) = remote_group(&instance.project_path, &config) {
+            instance.group_path = group;
+        }
+    }
     instance.tool = params.tool.clone();
     instance.detect_as = config
         .session
@@ -655,6 +798,7 @@ pub fn build_instance(
     instance.worktree_info = worktree_info;
     instance.workspace_info = workspace_info;
     instance.yolo_mode = params.yolo_mode;
+    instance.auto_create_terminal = params.auto_create_terminal;
 
     // Apply command overrides and custom agent commands from resolved config.
     // Priority: per-session params > agent_command_override > custom_agents > AgentDef default.
@@ -702,10 +846,24 @@ pub fn build_instance(
             } else {
                 Some(params.extra_env.clone())
             },
-            custom_instruction: config.sandbox.custom_instruction.clone(),
+            custom_instruction: params
+                .custom_instruction
+                .clone()
+                .or_else(|| config.sandbox.custom_instruction.clone()),
+            cpu_limit: params.cpu_limit.clone(),
+            memory_limit: params.memory_limit.clone(),
         });
+    } else {
+        instance.host_instruction = params.custom_instruction.clone();
     }
 
+    let detail = instance
+        .worktree_info
+        .as_ref()
+        .map(|wt| format!("branch {}", wt.branch))
+        .unwrap_or_default();
+    activity::record(ActivityKind::SessionCreated, &instance, detail);
+
     Ok(BuildResult {
         instance,
         created_worktree,
@@ -973,6 +1131,35 @@ pub(crate) fn branch_name_from_title(title: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_instruction_file_trims_and_rejects_bad_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let prompt = dir.path().join("prompt.md");
+        std::fs::write(&prompt, "  fix the flaky test\n").unwrap();
+        assert_eq!(
+            read_instruction_file(&prompt).unwrap(),
+            "fix the flaky test"
+        );
+
+        assert!(read_instruction_file(&dir.path().join("missing.md")).is_err());
+        assert!(read_instruction_file(dir.path()).is_err());
+
+        std::fs::write(&prompt, " \n\t").unwrap();
+        let err = read_instruction_file(&prompt).unwrap_err().to_string();
+        assert!(err.contains("is empty"), "{err}");
+
+        let oversized = vec![b'a'; MAX_INSTRUCTION_BYTES as usize + 1];
+        let err = read_instruction(oversized.as_slice(), "stdin")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("exceeds 8 KiB"), "{err}");
+
+        let err = read_instruction(&[0xff, 0xfe][..], "stdin")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("UTF-8"), "{err}");
+    }
+
     #[test]
     fn test_empty_title_with_worktree_uses_branch_name() {
         let title = resolve_title("", Some("feature-auth"), true, &[]);
@@ -995,6 +1182,16 @@ mod tests {
         assert_eq!(title, "My Session");
     }
 
+    #[test]
+    fn test_check_yolo_sandbox_requires_sandbox_or_override() {
+        assert!(check_yolo_sandbox("claude", true, false, false).is_err());
+        assert!(check_yolo_sandbox("claude", true, true, false).is_ok());
+        assert!(check_yolo_sandbox("claude", true, false, true).is_ok());
+        assert!(check_yolo_sandbox("claude", false, false, false).is_ok());
+        // pi has no YOLO opt-in, so there is nothing to refuse.
+        assert!(check_yolo_sandbox("pi", true, false, false).is_ok());
+    }
+
     #[test]
     fn test_provided_title_without_worktree_keeps_title() {
         let title = resolve_title("Custom Name", None, false, &[]);
@@ -1186,6 +1383,7 @@ mod tests {
             false,
             &template,
             true,
+            None,
         );
 
         let err = match result {
@@ -1231,6 +1429,7 @@ mod tests {
             false,
             &template,
             true,
+            None,
         );
 
         let err = match result {
@@ -1276,6 +1475,30 @@ mod tests {
         assert_eq!(resolve_base_branch(Some("  "), None, None), None);
     }
 
+    #[test]
+    fn remote_group_follows_origin_only_when_enabled() {
+        let (parent, _tip) = init_repo_with_branch("proj", "release");
+        let root = parent.path().join("proj");
+        let root_str = root.to_string_lossy().to_string();
+        git2::Repository::open(&root)
+            .unwrap()
+            .remote("origin", "git@github.com:org/repo.git")
+            .unwrap();
+
+        let mut config = Config::default();
+        assert_eq!(remote_group(&root_str, &config), None);
+
+        config.session.auto_group_from_remote = true;
+        assert_eq!(
+            remote_group(&root_str, &config),
+            Some("org/repo".to_string())
+        );
+
+        // A directory with no repo (and so no origin) stays ungrouped.
+        let plain = tempfile::TempDir::new().unwrap();
+        assert_eq!(remote_group(&plain.path().to_string_lossy(), &config), None);
+    }
+
     #[test]
     fn resolve_repo_base_branch_keys_launch_repo_by_root() {
         let (parent, _tip) = init_repo_with_branch("proj", "release");
@@ -1399,6 +1622,7 @@ mod tests {
             true,
             &template,
             true,
+            None,
         )
         .expect("workspace creation should succeed");
 
@@ -1440,14 +1664,20 @@ mod tests {
             worktree_branch: None,
             create_new_branch: false,
             base_branch: None,
+            worktree_on_exists: OnExists::Error,
             sandbox: false,
             sandbox_image: "ubuntu:latest".to_string(),
             yolo_mode: false,
+            allow_unsandboxed_yolo: false,
             extra_env: Vec::new(),
+            cpu_limit: None,
+            memory_limit: None,
+            custom_instruction: None,
             extra_args: String::new(),
             command_override: String::new(),
             extra_repo_paths: Vec::new(),
             scratch: false,
+            auto_create_terminal: false,
         }
     }
 
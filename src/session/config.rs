@@ -2,10 +2,11 @@
 
 use super::get_app_dir;
 use super::repo_config::HooksConfig;
-use anyhow::Result;
+use super::Instance;
+use anyhow::{Context, Result};
 use aoe_settings_derive::SettingsSection;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
 
@@ -17,9 +18,15 @@ pub struct Config {
     #[serde(default)]
     pub theme: ThemeConfig,
 
+    #[serde(default)]
+    pub display: DisplayConfig,
+
     #[serde(default)]
     pub updates: UpdatesConfig,
 
+    #[serde(default)]
+    pub app: AppConfig,
+
     #[serde(default)]
     pub telemetry: TelemetryConfig,
 
@@ -47,6 +54,9 @@ pub struct Config {
     #[serde(default)]
     pub status_hooks: crate::status_hooks::StatusHookConfig,
 
+    #[serde(default)]
+    pub notifications: crate::notifications::NotificationConfig,
+
     #[serde(default)]
     pub app_state: AppStateConfig,
 
@@ -538,7 +548,7 @@ fn default_replay_bytes() -> u64 {
 }
 
 /// Session list sort order
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SortOrder {
     #[default]
@@ -548,6 +558,9 @@ pub enum SortOrder {
     Oldest,
     AZ,
     ZA,
+    /// Groups in the order the user arranged them (see
+    /// `GroupTree::move_group`); sessions oldest first.
+    Custom,
 }
 
 impl SortOrder {
@@ -558,18 +571,20 @@ impl SortOrder {
             SortOrder::LastActivity => SortOrder::Oldest,
             SortOrder::Oldest => SortOrder::AZ,
             SortOrder::AZ => SortOrder::ZA,
-            SortOrder::ZA => SortOrder::Newest,
+            SortOrder::ZA => SortOrder::Custom,
+            SortOrder::Custom => SortOrder::Newest,
         }
     }
 
     pub fn cycle_reverse(self) -> Self {
         match self {
-            SortOrder::Newest => SortOrder::ZA,
+            SortOrder::Newest => SortOrder::Custom,
             SortOrder::Attention => SortOrder::Newest,
             SortOrder::LastActivity => SortOrder::Attention,
             SortOrder::Oldest => SortOrder::LastActivity,
             SortOrder::AZ => SortOrder::Oldest,
             SortOrder::ZA => SortOrder::AZ,
+            SortOrder::Custom => SortOrder::ZA,
         }
     }
 
@@ -581,12 +596,13 @@ impl SortOrder {
             SortOrder::Oldest => "Oldest",
             SortOrder::AZ => "A-Z",
             SortOrder::ZA => "Z-A",
+            SortOrder::Custom => "Custom",
         }
     }
 }
 
 /// Session list grouping mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GroupByMode {
     #[default]
@@ -610,6 +626,29 @@ impl GroupByMode {
     }
 }
 
+/// Which panes the TUI home view shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HomeLayout {
+    /// Session list beside (or above, on narrow terminals) the preview.
+    #[default]
+    Split,
+    /// Session list only, at the full width.
+    ListOnly,
+    /// The selected session's preview only, at the full width.
+    DetailOnly,
+}
+
+impl HomeLayout {
+    pub fn cycle(self) -> Self {
+        match self {
+            HomeLayout::Split => HomeLayout::ListOnly,
+            HomeLayout::ListOnly => HomeLayout::DetailOnly,
+            HomeLayout::DetailOnly => HomeLayout::Split,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AppStateConfig {
     #[serde(default)]
@@ -653,6 +692,11 @@ pub struct AppStateConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub show_preview_info: Option<bool>,
 
+    /// Home view layout per profile (keyed by profile name). Profiles
+    /// without an entry use the list + detail split.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub home_layout: std::collections::BTreeMap<String, HomeLayout>,
+
     /// True once the user has answered the telemetry opt-in prompt (in any
     /// surface, either by enabling or declining). Gates the one-time
     /// standalone consent popup shown to users who completed the walkthrough
@@ -765,6 +809,18 @@ pub struct SessionConfig {
     #[setting(label = "Mouse Capture", widget = "toggle", category = "Interaction")]
     pub mouse_capture: bool,
 
+    /// Pause background status and container polling while a session is
+    /// attached, resuming on return to the list, so refresh subprocesses
+    /// never compete with the attached terminal. Configured status hooks
+    /// still fire while attached.
+    #[serde(default = "default_true")]
+    #[setting(
+        label = "Pause Refresh While Attached",
+        widget = "toggle",
+        category = "Interaction"
+    )]
+    pub pause_refresh_while_attached: bool,
+
     /// User-defined agents: name=command (e.g. lenovo-claude=ssh -t lenovo
     /// claude). Custom agent names appear in the TUI agent picker alongside
     /// built-in agents.
@@ -789,6 +845,31 @@ pub struct SessionConfig {
     )]
     pub agent_detect_as: HashMap<String, String>,
 
+    /// Waiting-for-input regex per agent: agent=pattern (e.g.
+    /// my-agent=Continue\? \[y/N\]). Matched against the last pane lines
+    /// when status comes from pane content; a match reports Waiting. Keyed by
+    /// the agent used for detection (the Agent Detect As target if set) and
+    /// replaces that agent's built-in waiting pattern.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[setting(
+        label = "Agent Waiting Patterns",
+        widget = "list",
+        web = "local_only:agent-keyed map, edited locally alongside Agent Detect As",
+        category = "Agents"
+    )]
+    pub agent_waiting_patterns: HashMap<String, String>,
+
+    /// Error regex per agent: agent=pattern (e.g. my-agent=^fatal:). Same
+    /// matching and keying as Agent Waiting Patterns; a match reports Error.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[setting(
+        label = "Agent Error Patterns",
+        widget = "list",
+        web = "local_only:agent-keyed map, edited locally alongside Agent Detect As",
+        category = "Agents"
+    )]
+    pub agent_error_patterns: HashMap<String, String>,
+
     /// ACP launch command for a custom agent, enabling it to run in the
     /// structured acp UI (e.g., "oc-superpowers" = "ocp run sp acp").
     /// A custom agent with an entry here is acp-capable; without one it
@@ -978,6 +1059,45 @@ pub struct SessionConfig {
     #[setting(label = "Confirm Before Quit", widget = "toggle", global_only)]
     pub confirm_before_quit: bool,
 
+    /// Delete a Stopped session right away, skipping the delete dialog, when
+    /// there is nothing else to clean up: no aoe-managed worktree, no sandbox
+    /// container, and not a scratch session. Anything with side effects still
+    /// opens the dialog. Off by default.
+    #[serde(default)]
+    #[setting(
+        label = "Quick Delete Clean Sessions",
+        widget = "toggle",
+        category = "Interaction"
+    )]
+    pub quick_delete_clean_sessions: bool,
+
+    /// Show a warning in the TUI footer once a profile has more than this
+    /// many active (non-archived) sessions, as a nudge to archive or clean
+    /// up. 0 disables the warning.
+    #[serde(default)]
+    #[setting(label = "Session Count Warning", widget = "number", min = 0, advanced)]
+    pub max_sessions_warn: u32,
+
+    /// Refuse to create a session once a profile already has this many
+    /// active (non-archived) sessions. 0 (default) means unlimited.
+    #[serde(default)]
+    #[setting(label = "Max Sessions", widget = "number", min = 0, advanced)]
+    pub max_sessions: u32,
+
+    /// Keep the activity log (sessions created and deleted, containers
+    /// started, worktrees removed) in `activity.jsonl` so every aoe process
+    /// shares it and it survives restarts. When off, only destructive
+    /// actions are written to disk; the rest stay in memory for the current
+    /// process.
+    #[serde(default = "default_true")]
+    #[setting(
+        label = "Persist Activity Log",
+        widget = "toggle",
+        global_only,
+        advanced
+    )]
+    pub persist_activity_log: bool,
+
     /// Keep an aoe-managed worktree session's directory leaf in sync with its
     /// title. When enabled (default), renaming the session also moves its
     /// worktree directory, and new sessions derive the directory leaf from the
@@ -991,6 +1111,66 @@ pub struct SessionConfig {
         category = "Worktree"
     )]
     pub tie_workdir_to_name: bool,
+
+    /// File new sessions into a group derived from the project's `origin`
+    /// remote (e.g. `git@github.com:org/repo.git` becomes group `org/repo`)
+    /// when no group is chosen explicitly. Sessions without a parseable
+    /// remote stay ungrouped.
+    #[serde(default)]
+    #[setting(label = "Auto-Group From Remote", widget = "toggle")]
+    pub auto_group_from_remote: bool,
+
+    /// What happens to a tmux agent pane when the agent process exits.
+    /// `hold` (default) keeps the dead pane and its scrollback for
+    /// post-mortem debugging, `close` ends the tmux session, and `respawn`
+    /// relaunches the agent in place. Applies to sessions started after the
+    /// change.
+    #[serde(default)]
+    #[setting(
+        label = "On Agent Exit",
+        widget = "select",
+        options = "hold:Hold,close:Close,respawn:Respawn",
+        category = "Agents"
+    )]
+    pub on_agent_exit: AgentExitBehavior,
+
+    /// Wrapper template for host agent launches, e.g. `nice -n 10 {cmd}`.
+    /// `{cmd}` is replaced with the agent command (including extra args and
+    /// session flags) as a single `sh -c '...'` argument. Sandboxed sessions
+    /// ignore it; use the sandbox resource limits there instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Launch Wrapper",
+        widget = "optional_text",
+        validate = "launch_wrapper",
+        web = "local_only:wraps every host agent launch, a host execution surface",
+        category = "Agents"
+    )]
+    pub launch_wrapper: Option<String>,
+
+    /// Open the paired terminal alongside the agent when a session starts,
+    /// so it is ready without a separate step. Sandboxed sessions get the
+    /// container terminal instead. This is the default for the "Terminal"
+    /// checkbox in the new-session dialog.
+    #[serde(default)]
+    #[setting(label = "Auto-Create Terminal", widget = "toggle")]
+    pub auto_create_terminal: bool,
+}
+
+/// What the agent's tmux pane does when the agent exits. See
+/// `SessionConfig::on_agent_exit`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentExitBehavior {
+    /// Keep the dead pane (tmux `remain-on-exit on`) so the exit status and
+    /// scrollback stay visible. The historical behavior.
+    #[default]
+    Hold,
+    /// Let the tmux session close with the agent.
+    Close,
+    /// Relaunch the agent in the same pane. A run that exits within a few
+    /// seconds is not respawned, so a crash loop falls back to `Hold`.
+    Respawn,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -1084,8 +1264,11 @@ impl Default for SessionConfig {
             agent_command_override: HashMap::new(),
             agent_status_hooks: true,
             mouse_capture: true,
+            pause_refresh_while_attached: true,
             custom_agents: HashMap::new(),
             agent_detect_as: HashMap::new(),
+            agent_waiting_patterns: HashMap::new(),
+            agent_error_patterns: HashMap::new(),
             agent_acp_cmd: HashMap::new(),
             acp_defaults: HashMap::new(),
             strict_hotkeys: false,
@@ -1100,7 +1283,15 @@ impl Default for SessionConfig {
             default_attach_mode: NewSessionAttachMode::default(),
             click_action: ClickAction::default(),
             confirm_before_quit: true,
+            quick_delete_clean_sessions: false,
+            max_sessions_warn: 0,
+            max_sessions: 0,
+            persist_activity_log: true,
             tie_workdir_to_name: true,
+            auto_group_from_remote: false,
+            on_agent_exit: AgentExitBehavior::default(),
+            launch_wrapper: None,
+            auto_create_terminal: false,
         }
     }
 }
@@ -1157,7 +1348,32 @@ fn default_session_id_poller_max_threads() -> u32 {
     crate::session::poller::DEFAULT_SESSION_ID_POLLER_MAX_THREADS
 }
 
+/// Sessions that count toward `session.max_sessions_warn` and
+/// `session.max_sessions`: every row except archived ones, since archiving
+/// is the cleanup those limits nudge toward.
+pub fn count_active_sessions<'a>(instances: impl IntoIterator<Item = &'a Instance>) -> usize {
+    instances.into_iter().filter(|i| !i.is_archived()).count()
+}
+
 impl SessionConfig {
+    /// Whether `active` sessions is past the `max_sessions_warn` threshold.
+    pub fn exceeds_session_warn(&self, active: usize) -> bool {
+        self.max_sessions_warn > 0 && active > self.max_sessions_warn as usize
+    }
+
+    /// Refuse `adding` more sessions on top of `active` when that would go
+    /// past `max_sessions`.
+    pub fn check_session_limit(&self, active: usize, adding: usize) -> Result<()> {
+        let max = self.max_sessions as usize;
+        if max > 0 && active + adding > max {
+            anyhow::bail!(
+                "This profile has {active} active sessions and session.max_sessions is {max}. \
+                 Archive or delete sessions before creating more."
+            );
+        }
+        Ok(())
+    }
+
     /// Resolve the command override for a tool, checking agent_command_override first,
     /// then falling back to custom_agents. Returns empty string if no override found.
     pub fn resolve_tool_command(&self, tool: &str) -> String {
@@ -1207,6 +1423,21 @@ impl SessionConfig {
                 );
             }
         }
+        for (key, patterns) in [
+            ("agent_waiting_patterns", &self.agent_waiting_patterns),
+            ("agent_error_patterns", &self.agent_error_patterns),
+        ] {
+            for (name, pattern) in patterns {
+                if let Err(e) = regex::Regex::new(pattern) {
+                    tracing::warn!(target: "session.store",
+                        "{}: '{}' has an invalid regex and will be ignored: {}",
+                        key,
+                        name,
+                        e
+                    );
+                }
+            }
+        }
         for (name, command) in &self.agent_acp_cmd {
             if name.is_empty() {
                 tracing::warn!(target: "session.store", "agent_acp_cmd: entry with empty agent name will be ignored");
@@ -1372,6 +1603,10 @@ pub enum ColorMode {
     /// aoe already use 256-palette via ansi-to-tui, so palette mode renders
     /// chrome through the same escape path and survives the same transports.
     Palette,
+    /// No color at all: the terminal's default foreground and background,
+    /// with bold, underline and reverse video for focus, errors and
+    /// selection. Also forced by a non-empty `NO_COLOR` environment variable.
+    Monochrome,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, SettingsSection)]
@@ -1383,13 +1618,14 @@ pub struct ThemeConfig {
     #[serde(default)]
     #[setting(label = "Theme", widget = "custom:theme-name", global_only)]
     pub name: String,
-    /// Truecolor (24-bit RGB) or palette (xterm-256). Use palette if your
-    /// terminal mangles RGB escapes. Global, like the theme itself.
+    /// Truecolor (24-bit RGB), palette (xterm-256) or monochrome. Use palette
+    /// if your terminal mangles RGB escapes, monochrome if theme colors are
+    /// hard to read. Global, like the theme itself.
     #[serde(default)]
     #[setting(
         label = "Color Mode",
         widget = "select",
-        options = "truecolor:truecolor,palette:palette",
+        options = "truecolor:truecolor,palette:palette,monochrome:monochrome",
         global_only
     )]
     pub color_mode: ColorMode,
@@ -1401,6 +1637,11 @@ pub struct ThemeConfig {
     #[serde(default = "default_idle_decay_minutes")]
     #[setting(label = "Idle Decay (minutes)", widget = "number", min = 0)]
     pub idle_decay_minutes: u64,
+    /// Per-field color overrides layered on the selected theme, keyed by
+    /// theme field name (e.g. `accent = "#ff8700"`). Config-file only.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[setting(skip)]
+    pub overrides: BTreeMap<String, String>,
 }
 
 impl Default for ThemeConfig {
@@ -1409,6 +1650,7 @@ impl Default for ThemeConfig {
             name: String::new(),
             color_mode: ColorMode::default(),
             idle_decay_minutes: default_idle_decay_minutes(),
+            overrides: BTreeMap::new(),
         }
     }
 }
@@ -1457,6 +1699,123 @@ impl UpdateCheckMode {
     }
 }
 
+/// How absolute timestamps (session creation, release dates) are shown.
+/// Timestamps are stored in UTC; this only affects display. Global only,
+/// like the theme.
+#[derive(Debug, Clone, Serialize, Deserialize, SettingsSection)]
+#[setting_section(name = "display", category = "Theme")]
+pub struct DisplayConfig {
+    /// Timezone for displayed timestamps: `local` (the system timezone),
+    /// `utc`, or a fixed offset such as `+05:30` or `-08:00`.
+    #[serde(default = "default_display_timezone")]
+    #[setting(
+        label = "Timezone",
+        widget = "text",
+        validate = "timezone",
+        global_only
+    )]
+    pub timezone: String,
+    /// strftime-style format for displayed timestamps, e.g. `%Y-%m-%d %H:%M`
+    /// or `%d %b %Y %I:%M %p`.
+    #[serde(default = "default_datetime_format")]
+    #[setting(
+        label = "Date/Time Format",
+        widget = "text",
+        validate = "datetime_format",
+        global_only
+    )]
+    pub datetime_format: String,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            timezone: default_display_timezone(),
+            datetime_format: default_datetime_format(),
+        }
+    }
+}
+
+fn default_display_timezone() -> String {
+    "local".to_string()
+}
+
+fn default_datetime_format() -> String {
+    "%Y-%m-%d %H:%M".to_string()
+}
+
+impl DisplayConfig {
+    /// Format a UTC timestamp for display. The single formatting path for
+    /// every absolute timestamp the TUI and CLI show. A hand-edited invalid
+    /// timezone or format falls back to the default rather than failing.
+    pub fn format_timestamp(&self, ts: chrono::DateTime<chrono::Utc>) -> String {
+        let format = if super::validate_datetime_format(&self.datetime_format).is_ok() {
+            self.datetime_format.clone()
+        } else {
+            default_datetime_format()
+        };
+        match parse_display_timezone(&self.timezone) {
+            Some(DisplayTimezone::Utc) => ts.format(&format).to_string(),
+            Some(DisplayTimezone::Fixed(offset)) => {
+                ts.with_timezone(&offset).format(&format).to_string()
+            }
+            Some(DisplayTimezone::Local) | None => {
+                ts.with_timezone(&chrono::Local).format(&format).to_string()
+            }
+        }
+    }
+}
+
+/// A parsed `display.timezone` value.
+pub(crate) enum DisplayTimezone {
+    Local,
+    Utc,
+    Fixed(chrono::FixedOffset),
+}
+
+/// Parse `local`, `utc` or a `+HH:MM` / `-HH:MM` offset (case-insensitive).
+pub(crate) fn parse_display_timezone(value: &str) -> Option<DisplayTimezone> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("local") {
+        return Some(DisplayTimezone::Local);
+    }
+    if value.eq_ignore_ascii_case("utc") {
+        return Some(DisplayTimezone::Utc);
+    }
+    let (sign, rest) = match value.as_bytes().first()? {
+        b'+' => (1, &value[1..]),
+        b'-' => (-1, &value[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = rest.split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if minutes >= 60 {
+        return None;
+    }
+    chrono::FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).map(DisplayTimezone::Fixed)
+}
+
+/// Where aoe keeps regenerable data. Global only.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SettingsSection)]
+#[setting_section(name = "app", category = "Updates")]
+pub struct AppConfig {
+    /// Directory for caches that `aoe cache clear` may delete (the update
+    /// check cache). Absolute or `~/`-relative; created if missing. Empty
+    /// keeps them in the app directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Cache Directory",
+        widget = "optional_text",
+        global_only,
+        advanced
+    )]
+    pub cache_dir: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, SettingsSection)]
 #[setting_section(name = "updates", category = "Updates")]
 pub struct UpdatesConfig {
@@ -1485,6 +1844,18 @@ pub struct UpdatesConfig {
     #[setting(label = "Notify in CLI", widget = "toggle")]
     pub notify_in_cli: bool,
 
+    /// Never contact GitHub. Update notices use only the last cached check,
+    /// however old; `aoe update --force` still checks on request.
+    #[serde(default)]
+    #[setting(label = "Offline", widget = "toggle")]
+    pub offline: bool,
+
+    /// Fetch releases from this JSON manifest instead of GitHub, for forks
+    /// and internal mirrors. Empty uses GitHub.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(label = "Release Manifest URL", widget = "optional_text", advanced)]
+    pub manifest_url: Option<String>,
+
     /// How often the web dashboard re-polls for new releases. Server-side
     /// cache is governed by `check_interval_hours`; this knob only controls
     /// how aggressively the frontend asks. Keep it lower than
@@ -1500,6 +1871,8 @@ impl Default for UpdatesConfig {
             update_check_mode: UpdateCheckMode::default(),
             check_interval_hours: 24,
             notify_in_cli: true,
+            offline: false,
+            manifest_url: None,
             web_poll_interval_minutes: 60,
         }
     }
@@ -1566,6 +1939,18 @@ pub struct WorktreeConfig {
     )]
     pub bare_repo_path_template: String,
 
+    /// Directory all worktrees live under (e.g. ~/.aoe-worktrees). Relative
+    /// templates resolve against it instead of the repo, nested by
+    /// {repo-name}. Empty keeps worktrees next to each repo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Worktree Root",
+        widget = "optional_text",
+        web = "elevation:worktree config affects host filesystem",
+        advanced
+    )]
+    pub root: Option<String>,
+
     /// Automatically clean up worktrees on session delete.
     #[serde(default = "default_true")]
     #[setting(
@@ -1580,16 +1965,18 @@ pub struct WorktreeConfig {
     #[setting(label = "Show Branch in TUI", widget = "toggle", advanced)]
     pub show_branch_in_tui: bool,
 
-    /// Also delete the git branch when deleting a worktree. Default: false
-    /// (unchecked in the delete dialog).
+    /// Also delete the git branch when deleting a worktree: never (the
+    /// default, unchecked in the delete dialog), only if it is merged into
+    /// the session's base branch, or always (force-deleting unmerged work).
     #[serde(default)]
     #[setting(
         label = "Delete Branch on Cleanup",
-        widget = "toggle",
+        widget = "select",
+        options = "never:Never,if-merged:If merged,always:Always",
         web = "elevation:worktree config affects host filesystem",
         advanced
     )]
-    pub delete_branch_on_cleanup: bool,
+    pub delete_branch_on_cleanup: BranchCleanup,
 
     /// Template for multi-repo workspace directories ({branch}, {session-id}).
     #[serde(default = "default_workspace_template")]
@@ -1627,6 +2014,28 @@ pub struct WorktreeConfig {
         advanced
     )]
     pub default_base_branch: Option<String>,
+
+    /// Seconds a `git` subprocess (worktree add/remove, prune, branch
+    /// delete, submodule update) may run before it is killed and reported
+    /// as timed out, so a hung git can't freeze the TUI. 0 disables the
+    /// limit.
+    #[serde(default = "default_git_timeout_secs")]
+    #[setting(
+        label = "Git Timeout (seconds)",
+        widget = "number",
+        min = 0,
+        global_only,
+        advanced
+    )]
+    pub git_timeout_secs: u32,
+
+    /// Self-hosted git hosts for pull request links, keyed by host
+    /// (optionally with :port or a /path prefix). Values are a provider
+    /// (github, gitlab, bitbucket) or a URL template using {web}, {repo},
+    /// {branch}, {base}.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[setting(label = "Git Hosts", widget = "list", advanced)]
+    pub git_hosts: HashMap<String, String>,
 }
 
 impl Default for WorktreeConfig {
@@ -1635,16 +2044,97 @@ impl Default for WorktreeConfig {
             enabled: false,
             path_template: default_worktree_template(),
             bare_repo_path_template: default_bare_repo_template(),
+            root: None,
             auto_cleanup: true,
             show_branch_in_tui: true,
-            delete_branch_on_cleanup: false,
+            delete_branch_on_cleanup: BranchCleanup::Never,
             workspace_path_template: default_workspace_template(),
             init_submodules: true,
             default_base_branch: None,
+            git_timeout_secs: default_git_timeout_secs(),
+            git_hosts: HashMap::new(),
+        }
+    }
+}
+
+impl WorktreeConfig {
+    /// `root` as a directory, with `~/` expanded. `None` when unset, blank,
+    /// or not absolute (logged), which keeps worktrees next to the repo.
+    pub fn root_path(&self) -> Option<PathBuf> {
+        let root = self
+            .root
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty())?;
+        let path = match root.strip_prefix("~/") {
+            Some(rest) => match super::home_dir() {
+                Ok(home) => home.join(rest),
+                Err(e) => {
+                    tracing::warn!(target: "session.profile", "Ignoring worktree.root {root}: {e}");
+                    return None;
+                }
+            },
+            None => PathBuf::from(root),
+        };
+        if !path.is_absolute() {
+            tracing::warn!(target: "session.profile",
+                "Ignoring worktree.root {root}: must be an absolute path or start with ~/"
+            );
+            return None;
         }
+        Some(path)
     }
 }
 
+/// When deleting a worktree session also deletes its branch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BranchCleanup {
+    #[default]
+    Never,
+    /// Delete only a branch fully merged into the session's base branch;
+    /// an unmerged one is kept and reported.
+    IfMerged,
+    Always,
+}
+
+impl BranchCleanup {
+    /// Whether the delete dialog's branch checkbox starts ticked.
+    pub fn deletes(self) -> bool {
+        self != Self::Never
+    }
+}
+
+impl<'de> Deserialize<'de> for BranchCleanup {
+    /// Also accepts the boolean this setting used to be: `true` is
+    /// `always`, `false` is `never`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Bool(bool),
+            Name(String),
+        }
+        match Raw::deserialize(deserializer)? {
+            Raw::Bool(true) => Ok(Self::Always),
+            Raw::Bool(false) => Ok(Self::Never),
+            Raw::Name(name) => match name.as_str() {
+                "never" => Ok(Self::Never),
+                "if-merged" => Ok(Self::IfMerged),
+                "always" => Ok(Self::Always),
+                other => Err(serde::de::Error::unknown_variant(
+                    other,
+                    &["never", "if-merged", "always"],
+                )),
+            },
+        }
+    }
+}
+
+fn default_git_timeout_secs() -> u32 {
+    crate::git::DEFAULT_GIT_TIMEOUT_SECS
+}
+
 fn default_worktree_template() -> String {
     "../{repo-name}-worktrees/{branch}".to_string()
 }
@@ -1678,7 +2168,8 @@ pub struct SandboxConfig {
     )]
     pub default_image: String,
 
-    /// Additional volume mounts (host:container or host:container:ro).
+    /// Additional volume mounts (host:container[:ro|rw]). Relative host
+    /// paths resolve against the project directory, `~/` against home.
     #[serde(default, deserialize_with = "super::serde_helpers::string_or_vec")]
     #[setting(
         label = "Extra Volumes",
@@ -1689,8 +2180,20 @@ pub struct SandboxConfig {
     )]
     pub extra_volumes: Vec<String>,
 
+    /// Refuse to create a container when an `extra_volumes` entry is
+    /// malformed or its host path does not exist, listing every problem.
+    /// Off: problems are logged and the container is still created.
+    #[serde(default)]
+    #[setting(
+        label = "Strict Extra Volumes",
+        widget = "toggle",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub extra_volumes_strict: bool,
+
     /// Env vars injected into the container: KEY=value (literal, appears in
-    /// argv), KEY=$VAR (passthrough from host, hidden from argv), KEY=$$literal
+    /// argv), KEY=$VAR or KEY=${VAR} (passthrough from host, hidden from argv), KEY=$$literal
     /// (escape a leading $), or bare KEY (passthrough). For host (non-sandboxed)
     /// sessions, see Session > Host Environment instead.
     #[serde(
@@ -1706,6 +2209,19 @@ pub struct SandboxConfig {
     )]
     pub environment: Vec<String>,
 
+    /// Extra container-only env per agent (e.g. claude=FOO=1 BAR="a b"):
+    /// whitespace-separated KEY=VALUE pairs, applied after the agent's
+    /// built-in container env so these values win. Values are literal and
+    /// appear in argv; use Sandbox Environment to pass host values through.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[setting(
+        label = "Agent Container Env",
+        widget = "list",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub agent_container_env: HashMap<String, String>,
+
     /// Remove containers when sessions are deleted.
     #[serde(default = "default_true")]
     #[setting(
@@ -1715,11 +2231,38 @@ pub struct SandboxConfig {
     )]
     pub auto_cleanup: bool,
 
-    /// CPU limit for containers (e.g. "4").
+    /// Stop (not remove) the container of a sandboxed session that has sat
+    /// idle, with no attached client and near-zero container CPU, for
+    /// `idle_container_stop_secs`. The session and worktree stay; the
+    /// container starts again on the next attach.
+    #[serde(default)]
+    #[setting(
+        label = "Auto-Stop Idle Containers",
+        widget = "toggle",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub auto_stop_idle_containers: bool,
+
+    /// Seconds a sandboxed session must stay idle before
+    /// `auto_stop_idle_containers` stops its container.
+    #[serde(default = "default_idle_container_stop_secs")]
+    #[setting(
+        label = "Idle Container Stop (s)",
+        widget = "number",
+        min = 60,
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub idle_container_stop_secs: u32,
+
+    /// CPU limit for containers (e.g. "4"). Apple Container only accepts
+    /// whole CPUs.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[setting(
         label = "CPU Limit",
         widget = "optional_text",
+        validate = "cpu_limit",
         web = "elevation:sandbox config affects host isolation",
         advanced
     )]
@@ -1751,6 +2294,29 @@ pub struct SandboxConfig {
     )]
     pub port_mappings: Vec<String>,
 
+    /// Override the container image's entrypoint (e.g. /usr/bin/tini). Empty
+    /// keeps the image default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Container Entrypoint",
+        widget = "optional_text",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub entrypoint: Option<String>,
+
+    /// Command the container starts with (default: sleep infinity). Agents
+    /// run via exec, so it must keep the container alive. Empty keeps the
+    /// default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Container Command",
+        widget = "optional_text",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub container_command: Option<String>,
+
     /// Default terminal for sandboxed sessions (toggle with 'c' key).
     #[serde(default)]
     #[setting(
@@ -1771,6 +2337,39 @@ pub struct SandboxConfig {
     )]
     pub volume_ignores: Vec<String>,
 
+    /// Extra agent config entries to leave out of the sandbox sync, as
+    /// <agent config dir>/<name> (e.g. .claude/projects). Merged with the
+    /// built-in skip list, not replacing it.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "super::serde_helpers::string_or_vec"
+    )]
+    #[setting(
+        label = "Config Sync Excludes",
+        widget = "list",
+        validate = "config_sync_exclude_list",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub config_sync_excludes: Vec<String>,
+
+    /// Paths to hide from the sandbox (e.g. .env, secrets/*). When set, the
+    /// project is copied into the container minus these instead of
+    /// bind-mounted: no live sync with the host. See the sandbox guide.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "super::serde_helpers::string_or_vec"
+    )]
+    #[setting(
+        label = "Mount Excludes",
+        widget = "list",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub mount_excludes: Vec<String>,
+
     /// anonymous: default, works on Linux. named: use deterministic
     /// Docker/Podman named volumes, required on macOS/VirtioFS to reliably
     /// shadow bind-mount subdirectories.
@@ -1793,6 +2392,31 @@ pub struct SandboxConfig {
     )]
     pub mount_ssh: bool,
 
+    /// Mount the host Docker socket read-write so agents can run docker.
+    /// WARNING: this hands the sandbox root-equivalent control of the host
+    /// daemon and every container on it. Unsupported on Apple Container.
+    #[serde(default)]
+    #[setting(
+        label = "Mount Docker Socket",
+        widget = "toggle",
+        web = "elevation:sandbox config affects host isolation",
+        no_repo,
+        advanced
+    )]
+    pub mount_docker_socket: bool,
+
+    /// Host socket for `mount_docker_socket`. Empty uses a unix:// DOCKER_HOST
+    /// when set, otherwise /var/run/docker.sock.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Docker Socket Path",
+        widget = "optional_text",
+        web = "elevation:sandbox config affects host isolation",
+        no_repo,
+        advanced
+    )]
+    pub docker_socket_path: Option<String>,
+
     /// Append the :z SELinux relabel flag to sandbox bind mounts (needed on
     /// Fedora/RHEL; relabels host paths). Off by default; only emitted for
     /// Docker/Podman.
@@ -1805,6 +2429,42 @@ pub struct SandboxConfig {
     )]
     pub selinux_relabel: bool,
 
+    /// Run sandbox containers as your host UID/GID so files the agent writes
+    /// into the project are owned by you, not root. Slower container
+    /// creation; see the sandbox guide for tradeoffs.
+    #[serde(default)]
+    #[setting(
+        label = "Match Host User",
+        widget = "toggle",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub match_host_user: bool,
+
+    /// Docker restart policy for sandbox containers, so they come back after
+    /// a daemon restart. Not supported by Apple Container (ignored there).
+    #[serde(default)]
+    #[setting(
+        label = "Restart Policy",
+        widget = "select",
+        options = "no:No,on-failure:On Failure,unless-stopped:Unless Stopped",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub restart_policy: RestartPolicy,
+
+    /// Resolve the sandbox image tag to its digest when a session's container
+    /// is first created and keep using that exact image on rebuilds, so
+    /// sessions created days apart don't drift with :latest.
+    #[serde(default)]
+    #[setting(
+        label = "Pin Image Digest",
+        widget = "toggle",
+        web = "elevation:sandbox config affects host isolation",
+        advanced
+    )]
+    pub pin_image_digest: bool,
+
     /// Custom instruction text appended to the agent's system prompt in
     /// sandboxed sessions (Claude, Codex only).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1852,28 +2512,71 @@ pub enum VolumeIgnoresStrategy {
     Named,
 }
 
+/// `--restart` policy for sandbox containers.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    /// Never restart (default; matches a plain `docker run`).
+    #[default]
+    No,
+    /// Restart when the container exits non-zero.
+    OnFailure,
+    /// Always restart, including after a daemon restart, unless the
+    /// container was explicitly stopped.
+    UnlessStopped,
+}
+
+impl RestartPolicy {
+    /// The `--restart` value to pass at create time; `None` for the runtime
+    /// default so existing argv stays unchanged.
+    pub fn create_arg(self) -> Option<&'static str> {
+        match self {
+            RestartPolicy::No => None,
+            RestartPolicy::OnFailure => Some("on-failure"),
+            RestartPolicy::UnlessStopped => Some("unless-stopped"),
+        }
+    }
+}
+
 impl Default for SandboxConfig {
     fn default() -> Self {
         Self {
             enabled_by_default: false,
             default_image: default_sandbox_image(),
             extra_volumes: Vec::new(),
+            extra_volumes_strict: false,
             environment: default_sandbox_environment(),
+            agent_container_env: HashMap::new(),
             auto_cleanup: true,
+            auto_stop_idle_containers: false,
+            idle_container_stop_secs: default_idle_container_stop_secs(),
             cpu_limit: None,
             memory_limit: None,
             port_mappings: Vec::new(),
+            entrypoint: None,
+            container_command: None,
             default_terminal_mode: DefaultTerminalMode::default(),
             volume_ignores: Vec::new(),
+            config_sync_excludes: Vec::new(),
+            mount_excludes: Vec::new(),
             volume_ignores_strategy: VolumeIgnoresStrategy::default(),
             mount_ssh: false,
+            mount_docker_socket: false,
+            docker_socket_path: None,
             selinux_relabel: false,
+            match_host_user: false,
+            restart_policy: RestartPolicy::default(),
+            pin_image_digest: false,
             custom_instruction: None,
             container_runtime: ContainerRuntimeName::default(),
         }
     }
 }
 
+fn default_idle_container_stop_secs() -> u32 {
+    1800
+}
+
 fn default_sandbox_image() -> String {
     "ghcr.io/agent-of-empires/aoe-sandbox:latest".to_string()
 }
@@ -1933,6 +2636,19 @@ pub enum TmuxClipboardMode {
     Disabled,
 }
 
+/// Pane layout applied to a freshly created paired terminal session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TmuxTerminalSplit {
+    /// A single pane
+    #[default]
+    None,
+    /// Two panes side by side (`split-window -h`)
+    Horizontal,
+    /// Two panes stacked top and bottom (`split-window -v`)
+    Vertical,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, SettingsSection)]
 #[setting_section(name = "tmux", category = "Tmux")]
 pub struct TmuxConfig {
@@ -1964,6 +2680,27 @@ pub struct TmuxConfig {
         options = "auto:Auto,enabled:Enabled,disabled:Disabled"
     )]
     pub clipboard: TmuxClipboardMode,
+
+    /// Command typed into new paired terminal sessions once they start. It
+    /// runs from the session's working directory (the container workdir for
+    /// sandboxed terminals). Leave empty for a bare shell.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setting(
+        label = "Terminal Startup Command",
+        widget = "optional_text",
+        web = "local_only:typed into a host shell, a host execution surface"
+    )]
+    pub terminal_startup_command: Option<String>,
+
+    /// Split new paired terminal sessions into two panes. The first pane stays
+    /// a plain shell; the startup command, if any, runs in the second.
+    #[serde(default)]
+    #[setting(
+        label = "Terminal Split",
+        widget = "select",
+        options = "none:None,horizontal:Side by Side,vertical:Stacked"
+    )]
+    pub terminal_split: TmuxTerminalSplit,
 }
 
 impl Default for TmuxConfig {
@@ -1972,6 +2709,8 @@ impl Default for TmuxConfig {
             status_bar: TmuxStatusBarMode::Auto,
             mouse: TmuxMouseMode::Auto,
             clipboard: TmuxClipboardMode::Auto,
+            terminal_startup_command: None,
+            terminal_split: TmuxTerminalSplit::None,
         }
     }
 }
@@ -2029,7 +2768,7 @@ pub fn should_apply_tmux_clipboard() -> bool {
 }
 
 pub(crate) fn config_path() -> Result<PathBuf> {
-    Ok(get_app_dir()?.join("config.toml"))
+    Ok(super::get_config_dir()?.join("config.toml"))
 }
 
 impl Config {
@@ -2082,6 +2821,12 @@ impl Config {
     pub fn theme_palette_mode(&self) -> bool {
         matches!(self.theme.color_mode, ColorMode::Palette)
     }
+
+    /// Whether the TUI should render without color, either by config or
+    /// because the environment sets `NO_COLOR` (see [`no_color_env`]).
+    pub fn theme_monochrome(&self) -> bool {
+        matches!(self.theme.color_mode, ColorMode::Monochrome) || no_color_env()
+    }
 }
 
 pub fn load_config() -> Result<Option<Config>> {
@@ -2113,10 +2858,21 @@ pub fn resolve_theme_name() -> String {
     Config::load_or_warn().effective_theme_name()
 }
 
-/// Whether the global theme requests xterm-256 palette downsampling. Reads the
-/// global config only, for the same reason as [`resolve_theme_name`].
-pub fn resolve_theme_palette_mode() -> bool {
-    Config::load_or_warn().theme_palette_mode()
+/// Theme name to paint in the TUI while the home view is filtered to
+/// `profile`: the profile's own `theme_name` when it sets one, otherwise the
+/// global theme from [`resolve_theme_name`]. `None` (all-profiles mode) always
+/// paints the global theme.
+pub fn resolve_theme_name_for_profile(profile: Option<&str>) -> String {
+    profile
+        .and_then(|p| super::load_profile_config(p).ok())
+        .and_then(|c| c.theme_name)
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(resolve_theme_name)
+}
+
+/// Whether `NO_COLOR` is set to a non-empty value, per <https://no-color.org>.
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
 }
 
 /// Resolve the active profile name.
@@ -2173,10 +2929,54 @@ pub fn get_telemetry_settings() -> TelemetryConfig {
     Config::load_or_warn().telemetry
 }
 
+/// Directory for regenerable caches: `app.cache_dir` when set, the app dir
+/// otherwise. Created if missing. A bad override is an error rather than a
+/// silent fallback, so caches never land somewhere the user did not ask for.
+pub fn get_cache_dir() -> Result<PathBuf> {
+    match Config::load_or_warn().app.cache_dir.as_deref() {
+        Some(raw) if !raw.trim().is_empty() => resolve_cache_dir(raw),
+        _ => get_app_dir(),
+    }
+}
+
+fn resolve_cache_dir(raw: &str) -> Result<PathBuf> {
+    let raw = raw.trim();
+    let path = match raw.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .context("app.cache_dir uses ~ but the home directory is unknown")?
+            .join(rest),
+        None => PathBuf::from(raw),
+    };
+    if !path.is_absolute() {
+        anyhow::bail!("app.cache_dir must be an absolute path or start with ~/, got {raw:?}");
+    }
+    fs::create_dir_all(&path)
+        .with_context(|| format!("creating app.cache_dir {}", path.display()))?;
+    if !path.is_dir() {
+        anyhow::bail!("app.cache_dir {} is not a directory", path.display());
+    }
+    Ok(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn resolve_cache_dir_creates_absolute_dirs_and_rejects_bad_overrides() {
+        let tmp = tempfile::tempdir().unwrap();
+        let wanted = tmp.path().join("nested").join("cache");
+        assert_eq!(resolve_cache_dir(wanted.to_str().unwrap()).unwrap(), wanted);
+        assert!(wanted.is_dir());
+
+        let err = resolve_cache_dir("relative/cache").unwrap_err();
+        assert!(err.to_string().contains("absolute"), "{err}");
+
+        let file = tmp.path().join("a-file");
+        std::fs::write(&file, "").unwrap();
+        assert!(resolve_cache_dir(file.to_str().unwrap()).is_err());
+    }
+
     #[test]
     fn test_effective_profile_returns_input_when_non_empty() {
         // Non-empty input is passed through verbatim, regardless of what's
@@ -2342,6 +3142,26 @@ mod tests {
         assert!(!updates.notify_in_cli);
     }
 
+    #[test]
+    fn test_delete_branch_on_cleanup_accepts_legacy_bool() {
+        let parse = |value: &str| {
+            toml::from_str::<WorktreeConfig>(&format!("delete_branch_on_cleanup = {value}"))
+                .map(|c| c.delete_branch_on_cleanup)
+        };
+        assert_eq!(parse("true").unwrap(), BranchCleanup::Always);
+        assert_eq!(parse("false").unwrap(), BranchCleanup::Never);
+        assert_eq!(parse("\"if-merged\"").unwrap(), BranchCleanup::IfMerged);
+        assert!(parse("\"sometimes\"").is_err());
+
+        let config = WorktreeConfig {
+            delete_branch_on_cleanup: BranchCleanup::IfMerged,
+            ..WorktreeConfig::default()
+        };
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("delete_branch_on_cleanup = \"if-merged\""));
+    }
+
     #[test]
     fn test_updates_config_partial_deserialize() {
         let toml = r#"update_check_mode = "auto""#;
@@ -2862,6 +3682,21 @@ mod tests {
         assert!(session.confirm_before_quit);
     }
 
+    #[test]
+    fn test_session_limits_default_off_and_apply_past_threshold() {
+        let mut session = SessionConfig::default();
+        assert!(!session.exceeds_session_warn(1000));
+        assert!(session.check_session_limit(1000, 1).is_ok());
+
+        session.max_sessions_warn = 10;
+        session.max_sessions = 12;
+        assert!(!session.exceeds_session_warn(10));
+        assert!(session.exceeds_session_warn(11));
+        assert!(session.check_session_limit(11, 1).is_ok());
+        assert!(session.check_session_limit(12, 1).is_err());
+        assert!(session.check_session_limit(10, 3).is_err());
+    }
+
     #[test]
     fn test_session_config_default_session_id_poller_max_threads() {
         let cfg = SessionConfig::default();
@@ -3131,6 +3966,21 @@ volume_ignores_strategy = "named"
         );
     }
 
+    #[test]
+    fn test_restart_policy_parses_docker_names() {
+        assert_eq!(SandboxConfig::default().restart_policy, RestartPolicy::No);
+        assert_eq!(RestartPolicy::No.create_arg(), None);
+
+        let config: SandboxConfig = toml::from_str(r#"restart_policy = "on-failure""#).unwrap();
+        assert_eq!(config.restart_policy, RestartPolicy::OnFailure);
+        assert_eq!(config.restart_policy.create_arg(), Some("on-failure"));
+
+        let config: SandboxConfig = toml::from_str(r#"restart_policy = "unless-stopped""#).unwrap();
+        assert_eq!(config.restart_policy.create_arg(), Some("unless-stopped"));
+
+        assert!(toml::from_str::<SandboxConfig>(r#"restart_policy = "always""#).is_err());
+    }
+
     #[test]
     fn test_volume_ignores_strategy_anonymous_roundtrip() {
         let toml_str = r#"volume_ignores_strategy = "anonymous""#;
@@ -8,9 +8,11 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 
-use crate::containers::{ContainerConfig, EnvEntry, NamedVolumeMount, VolumeMount};
+use crate::containers::{
+    ContainerConfig, ContainerUser, EnvEntry, NamedVolumeMount, SeededCopy, VolumeMount,
+};
 use crate::git::GitWorktree;
-use crate::session::config::VolumeIgnoresStrategy;
+use crate::session::config::{SandboxConfig, VolumeIgnoresStrategy};
 
 use super::environment::collect_environment;
 use super::instance::SandboxInfo;
@@ -104,9 +106,14 @@ const AGENT_CONFIG_MOUNTS: &[AgentConfigMount] = &[
         ],
         seed_files: &[],
         copy_dirs: &[],
+        // OpenCode keeps provider logins (API keys and OAuth tokens) in
+        // auth.json and MCP server OAuth tokens in mcp-auth.json, both in
+        // this data dir on every platform; it does not use the Keychain.
         keychain_credential: None,
         home_seed_files: &[],
-        preserve_files: &[],
+        // OAuth providers refresh these in place, so once the container has
+        // its own copy a re-sync from the host would log it out.
+        preserve_files: &["auth.json", "mcp-auth.json"],
         clean_files: &["opencode.db", "opencode.db-wal", "opencode.db-shm"],
     },
     AgentConfigMount {
@@ -128,9 +135,17 @@ const AGENT_CONFIG_MOUNTS: &[AgentConfigMount] = &[
         skip_entries: &["sandbox"],
         seed_files: &[],
         copy_dirs: &[],
+        // Codex keeps its login in ~/.codex/auth.json by default on every
+        // platform, so the top-level file sync carries it in. The opt-in
+        // keyring store keys entries by a hash of CODEX_HOME rather than the
+        // user account, which the extractor cannot address; users of that mode
+        // pass OPENAI_API_KEY via `sandbox.environment` instead.
         keychain_credential: None,
         home_seed_files: &[],
-        preserve_files: &[],
+        // Codex rotates its refresh token on use. Once the container has
+        // refreshed, the host copy is stale, and re-copying it would log the
+        // sandbox out.
+        preserve_files: &["auth.json"],
         clean_files: &[],
     },
     AgentConfigMount {
@@ -140,9 +155,15 @@ const AGENT_CONFIG_MOUNTS: &[AgentConfigMount] = &[
         skip_entries: &["sandbox"],
         seed_files: &[],
         copy_dirs: &[],
+        // Gemini CLI stores its Google OAuth login on disk
+        // (oauth_creds.json, plus the signed-in account in
+        // google_accounts.json), not in the Keychain, so the top-level file
+        // sync carries it in.
         keychain_credential: None,
         home_seed_files: &[],
-        preserve_files: &[],
+        // Same refresh-rotation concern as codex: keep the container's copy
+        // once it exists.
+        preserve_files: &["oauth_creds.json", "google_accounts.json"],
         clean_files: &[],
     },
     AgentConfigMount {
@@ -271,6 +292,24 @@ const AGENT_CONFIG_MOUNTS: &[AgentConfigMount] = &[
     },
 ];
 
+/// Whether `host_rel` is the config dir of a sandbox-synced agent (e.g.
+/// `.claude`). Used to validate `sandbox.config_sync_excludes` entries.
+pub(crate) fn is_agent_config_dir(host_rel: &str) -> bool {
+    AGENT_CONFIG_MOUNTS.iter().any(|m| m.host_rel == host_rel)
+}
+
+/// The `sandbox.config_sync_excludes` names that apply to one agent config
+/// dir. Entries are `<host_rel>/<name>`; only exact `host_rel` matches count,
+/// so `.gemini/x` does not reach the nested `.gemini/antigravity-cli` mount.
+fn configured_skip_entries<'a>(host_rel: &str, excludes: &'a [String]) -> Vec<&'a str> {
+    excludes
+        .iter()
+        .filter_map(|entry| entry.trim().rsplit_once('/'))
+        .filter(|(dir, name)| *dir == host_rel && !name.is_empty())
+        .map(|(_, name)| name)
+        .collect()
+}
+
 /// Sync host agent config into the shared sandbox directory. Copies top-level files
 /// and `copy_dirs` from the host (always overwritten on refresh). Seed files are
 /// write-once: only created if they don't already exist, so container-accumulated
@@ -570,7 +609,13 @@ fn extract_keychain_credential(_service: &str, _dest: &Path) -> Result<bool> {
 
 /// Sync a single agent's host config into its shared sandbox directory.
 /// Handles config file sync, keychain credential extraction, and home-level seed files.
-fn prepare_sandbox_dir(mount: &AgentConfigMount, home: &Path) -> Result<std::path::PathBuf> {
+/// `sync_excludes` is `sandbox.config_sync_excludes`; matching names are skipped
+/// on top of the mount's built-in `skip_entries`.
+fn prepare_sandbox_dir(
+    mount: &AgentConfigMount,
+    home: &Path,
+    sync_excludes: &[String],
+) -> Result<std::path::PathBuf> {
     let host_dir = home.join(mount.host_rel);
     let sandbox_dir = home.join(mount.host_rel).join(SANDBOX_SUBDIR);
 
@@ -587,10 +632,12 @@ fn prepare_sandbox_dir(mount: &AgentConfigMount, home: &Path) -> Result<std::pat
     }
 
     if host_dir.exists() {
+        let mut skip_entries: Vec<&str> = mount.skip_entries.to_vec();
+        skip_entries.extend(configured_skip_entries(mount.host_rel, sync_excludes));
         sync_agent_config(
             &host_dir,
             &sandbox_dir,
-            mount.skip_entries,
+            &skip_entries,
             mount.seed_files,
             mount.copy_dirs,
             mount.preserve_files,
@@ -644,6 +691,15 @@ pub(crate) fn compute_volume_paths(
     project_path: &Path,
     project_path_str: &str,
 ) -> Result<(Vec<VolumeMount>, String)> {
+    // Under WSL a Windows-form path (`C:\...`) would produce an invalid
+    // Docker mount spec; use its `/mnt/c/...` form throughout.
+    let normalized = crate::wsl::normalize_host_path(project_path_str);
+    let (project_path, project_path_str) = if normalized != project_path_str {
+        (Path::new(&normalized), normalized.as_str())
+    } else {
+        (project_path, project_path_str)
+    };
+
     // Only look for a main repo if the project path itself has a .git entry (file or
     // directory). This prevents git2::Repository::discover from walking up the directory
     // tree and finding an unrelated ancestor repo (e.g., a dotfile-managed home directory),
@@ -765,7 +821,7 @@ fn compute_workspace_volume_paths(
     // Collect all unique main repo paths
     let mut main_repo_paths: Vec<PathBuf> = Vec::new();
     for repo in &ws_info.repos {
-        let main_path = PathBuf::from(&repo.main_repo_path);
+        let main_path = PathBuf::from(crate::wsl::normalize_host_path(&repo.main_repo_path));
         let canonical = main_path
             .canonicalize()
             .unwrap_or_else(|_| main_path.clone());
@@ -813,53 +869,169 @@ fn compute_workspace_volume_paths(
 }
 
 /// Re-sync shared sandbox directories from the host so the container picks up
-/// any credential changes (e.g. re-auth) since it was created.
-pub(crate) fn refresh_agent_configs() {
-    let Some(home) = dirs::home_dir() else {
-        return;
+/// any credential changes (e.g. re-auth) since it was created. `profile`
+/// selects the config that supplies the hook and sync-exclude settings.
+pub(crate) fn refresh_agent_configs(profile: &str) {
+    let home = match super::home_dir() {
+        Ok(home) => home,
+        Err(e) => {
+            tracing::warn!(target: "session.profile", "Skipping agent config refresh: {e}");
+            return;
+        }
     };
 
-    let hooks_enabled = super::config::Config::load()
-        .map(|c| c.session.agent_status_hooks)
-        .unwrap_or(true);
+    let (hooks_enabled, sync_excludes) = refresh_settings(profile);
 
     for mount in AGENT_CONFIG_MOUNTS {
-        let refresh_codex_hooks = hooks_enabled && should_refresh_codex_hooks(mount, &home);
-        let preserved_codex_state = if refresh_codex_hooks {
-            let config_path = home
-                .join(mount.host_rel)
-                .join(SANDBOX_SUBDIR)
-                .join("config.toml");
-            match crate::hooks::snapshot_codex_hooks_state(&config_path) {
-                Ok(state) => state,
-                Err(e) => {
-                    tracing::warn!(target: "session.profile",
-                        "Failed to read Codex sandbox hook state from {}: {}",
-                        config_path.display(),
-                        e
-                    );
-                    None
-                }
-            }
-        } else {
-            None
-        };
+        if let Err(e) = refresh_mount(mount, &home, hooks_enabled, &sync_excludes) {
+            tracing::warn!(target: "session.profile",
+                "Failed to refresh agent config for {}: {}",
+                mount.host_rel,
+                e
+            );
+        }
+    }
+}
 
-        match prepare_sandbox_dir(mount, &home) {
-            Ok(sandbox_dir) => {
-                if refresh_codex_hooks {
-                    refresh_codex_sandbox_hooks(&sandbox_dir, preserved_codex_state);
-                }
-            }
+/// Re-sync the shared sandbox directories for one agent right away and
+/// report which files changed, as paths relative to the home directory
+/// (e.g. `.claude/sandbox/settings.json`).
+///
+/// The sandbox dirs are bind-mounted into every container for that agent,
+/// so a running container sees the result immediately. Agents that cache
+/// their config still need a restart to read it.
+pub(crate) fn sync_agent_config_now(tool: &str, profile: &str) -> Result<Vec<String>> {
+    let home = super::home_dir()?;
+    let mounts: Vec<&AgentConfigMount> = AGENT_CONFIG_MOUNTS
+        .iter()
+        .filter(|m| m.tool_name == tool)
+        .collect();
+    if mounts.is_empty() {
+        anyhow::bail!("{tool} has no agent config to sync into the sandbox");
+    }
+
+    let (hooks_enabled, sync_excludes) = refresh_settings(profile);
+    let mut changed = Vec::new();
+    for mount in mounts {
+        let sandbox_dir = home.join(mount.host_rel).join(SANDBOX_SUBDIR);
+        let before = fingerprint_files(&sandbox_dir);
+        refresh_mount(mount, &home, hooks_enabled, &sync_excludes)
+            .with_context(|| format!("Failed to sync {}", mount.host_rel))?;
+        let after = fingerprint_files(&sandbox_dir);
+        let prefix = Path::new(mount.host_rel).join(SANDBOX_SUBDIR);
+        changed.extend(
+            changed_files(&before, &after)
+                .into_iter()
+                .map(|rel| prefix.join(rel).to_string_lossy().into_owned()),
+        );
+    }
+    Ok(changed)
+}
+
+/// `session.agent_status_hooks` and `sandbox.config_sync_excludes` from
+/// `profile`'s resolved config.
+fn refresh_settings(profile: &str) -> (bool, Vec<String>) {
+    let config = super::profile_config::resolve_config_or_warn(profile);
+    (
+        config.session.agent_status_hooks,
+        config.sandbox.config_sync_excludes,
+    )
+}
+
+/// Sync one mount's sandbox dir from the host, keeping the Codex hook state
+/// the container already has.
+fn refresh_mount(
+    mount: &AgentConfigMount,
+    home: &Path,
+    hooks_enabled: bool,
+    sync_excludes: &[String],
+) -> Result<PathBuf> {
+    let refresh_codex_hooks = hooks_enabled && should_refresh_codex_hooks(mount, home);
+    let preserved_codex_state = if refresh_codex_hooks {
+        let config_path = home
+            .join(mount.host_rel)
+            .join(SANDBOX_SUBDIR)
+            .join("config.toml");
+        match crate::hooks::snapshot_codex_hooks_state(&config_path) {
+            Ok(state) => state,
             Err(e) => {
                 tracing::warn!(target: "session.profile",
-                    "Failed to refresh agent config for {}: {}",
-                    mount.host_rel,
+                    "Failed to read Codex sandbox hook state from {}: {}",
+                    config_path.display(),
                     e
                 );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let sandbox_dir = prepare_sandbox_dir(mount, home, sync_excludes)?;
+    if refresh_codex_hooks {
+        refresh_codex_sandbox_hooks(&sandbox_dir, preserved_codex_state);
+    }
+    Ok(sandbox_dir)
+}
+
+/// Files above this size are compared by length and mtime instead of
+/// content, so a sync doesn't read large session logs end to end.
+const FINGERPRINT_HASH_LIMIT: u64 = 4 * 1024 * 1024;
+
+/// Content fingerprint of every regular file under `dir`, keyed by path
+/// relative to `dir`. Symlinks are not followed.
+fn fingerprint_files(dir: &Path) -> std::collections::BTreeMap<PathBuf, Vec<u8>> {
+    use sha2::{Digest, Sha256};
+
+    let mut out = std::collections::BTreeMap::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(meta) = std::fs::symlink_metadata(&path) else {
+                continue;
+            };
+            if meta.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            if !meta.is_file() {
+                continue;
+            }
+            let mut hasher = Sha256::new();
+            if meta.len() > FINGERPRINT_HASH_LIMIT {
+                hasher.update(meta.len().to_le_bytes());
+                if let Ok(modified) = meta.modified() {
+                    hasher.update(format!("{modified:?}").as_bytes());
+                }
+            } else if let Ok(content) = std::fs::read(&path) {
+                hasher.update(&content);
+            }
+            if let Ok(rel) = path.strip_prefix(dir) {
+                out.insert(rel.to_path_buf(), hasher.finalize().to_vec());
             }
         }
     }
+    out
+}
+
+/// Paths added, removed, or rewritten with different content between two
+/// [`fingerprint_files`] snapshots, in sorted order.
+fn changed_files(
+    before: &std::collections::BTreeMap<PathBuf, Vec<u8>>,
+    after: &std::collections::BTreeMap<PathBuf, Vec<u8>>,
+) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, hash)| before.get(*path) != Some(*hash))
+        .map(|(path, _)| path.clone())
+        .chain(before.keys().filter(|p| !after.contains_key(*p)).cloned())
+        .collect();
+    changed.sort();
+    changed
 }
 
 fn should_refresh_codex_hooks(mount: &AgentConfigMount, home: &Path) -> bool {
@@ -914,6 +1086,15 @@ fn resolve_active_agent(
         })
 }
 
+/// Move `path` from under `from_home` to the same place under `to_home`.
+/// Paths outside `from_home` are returned unchanged.
+fn rehome(path: &str, from_home: &str, to_home: &str) -> String {
+    match path.strip_prefix(from_home) {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => format!("{to_home}{rest}"),
+        _ => path.to_string(),
+    }
+}
+
 fn agent_config_container_path(
     mount: &AgentConfigMount,
     container_home: &str,
@@ -1109,6 +1290,77 @@ fn named_volume_for(session_id: &str, container_path: &str) -> String {
     format!("aoe-vi-{}-{}-{}", session_id, slug, hash12)
 }
 
+/// Parse `sandbox.extra_volumes` into mounts, collecting every problem
+/// instead of stopping at the first. Malformed entries and unknown modes are
+/// dropped; an entry whose host path is missing is still returned (Docker
+/// creates it) but reported, since other runtimes fail on it at create time.
+///
+/// The mode is `ro` or `rw` (the default when omitted). A host path of `~`
+/// or `~/...` resolves against `home`; any other relative host path resolves
+/// against `project_path`.
+fn check_extra_volumes(
+    entries: &[String],
+    project_path: &Path,
+    home: &Path,
+) -> (Vec<VolumeMount>, Vec<String>) {
+    let mut mounts = Vec::new();
+    let mut problems = Vec::new();
+    for entry in entries {
+        if let Err(e) = super::profile_config::validate_volume_format(entry) {
+            problems.push(format!("`{entry}`: {e}"));
+            continue;
+        }
+        let parts: Vec<&str> = entry.splitn(3, ':').collect();
+        // `validate_volume_format` already limited the mode to ro/rw.
+        let read_only = parts.get(2) == Some(&"ro");
+        let host_path = match parts[0] {
+            "~" => home.to_path_buf(),
+            p if p.starts_with("~/") => home.join(&p[2..]),
+            p if Path::new(p).is_relative() => project_path.join(p),
+            p => PathBuf::from(p),
+        };
+        if !host_path.exists() {
+            problems.push(format!(
+                "`{entry}`: host path {} does not exist",
+                host_path.display()
+            ));
+        }
+        mounts.push(VolumeMount {
+            host_path: host_path.to_string_lossy().to_string(),
+            container_path: parts[1].to_string(),
+            read_only,
+        });
+    }
+    (mounts, problems)
+}
+
+/// The `[sandbox]` section for `resolved_profile` merged with the project's
+/// repo config, or the defaults (with a warning) if it can't be loaded.
+fn resolve_sandbox_config(resolved_profile: &str, project_path: &Path) -> SandboxConfig {
+    match super::repo_config::resolve_config_with_repo(resolved_profile, project_path) {
+        Ok(c) => {
+            tracing::debug!(target: "session.profile",
+                "Loaded sandbox config: extra_volumes={:?}, mount_ssh={}, volume_ignores={:?}",
+                c.sandbox.extra_volumes,
+                c.sandbox.mount_ssh,
+                c.sandbox.volume_ignores
+            );
+            c.sandbox
+        }
+        Err(e) => {
+            tracing::warn!(target: "session.profile", "Failed to load config, using defaults: {}", e);
+            Default::default()
+        }
+    }
+}
+
+/// Whether `sandbox.pin_image_digest` is on for a session's profile and
+/// project.
+pub(crate) fn pin_image_digest_enabled(project_path_str: &str, profile: &str) -> bool {
+    let resolved_profile = super::config::effective_profile(profile);
+    resolve_sandbox_config(&resolved_profile, Path::new(project_path_str)).pin_image_digest
+}
+
 /// Build a full `ContainerConfig` for creating a sandboxed container.
 ///
 /// `profile` selects which profile's overrides (volumes, mount_ssh, volume_ignores)
@@ -1123,7 +1375,10 @@ pub(crate) fn build_container_config(
     workspace_info: Option<&super::WorkspaceInfo>,
     profile: &str,
 ) -> Result<ContainerConfig> {
-    let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+    // Every agent config mount, credential file, and hook install below is
+    // home-relative; without a home the container would start with none of
+    // them, so refuse up front.
+    let home = super::home_dir()?;
 
     let project_path = Path::new(project_path_str);
     let resolved_profile = super::config::effective_profile(profile);
@@ -1160,28 +1415,24 @@ pub(crate) fn build_container_config(
     // (host, container) roots for expanding glob volume_ignores against the live
     // filesystem. Captured before `project_volumes` is moved into `volumes`.
     let glob_roots = glob_roots(&project_volumes);
+    let project_mounts: std::collections::HashSet<String> = project_volumes
+        .iter()
+        .map(|v| v.container_path.clone())
+        .collect();
 
     let mut volumes = project_volumes;
 
-    let sandbox_config = {
-        match super::repo_config::resolve_config_with_repo(&resolved_profile, project_path) {
-            Ok(c) => {
-                tracing::debug!(target: "session.profile",
-                    "Loaded sandbox config: extra_volumes={:?}, mount_ssh={}, volume_ignores={:?}",
-                    c.sandbox.extra_volumes,
-                    c.sandbox.mount_ssh,
-                    c.sandbox.volume_ignores
-                );
-                c.sandbox
-            }
-            Err(e) => {
-                tracing::warn!(target: "session.profile", "Failed to load config, using defaults: {}", e);
-                Default::default()
-            }
-        }
-    };
+    let sandbox_config = resolve_sandbox_config(&resolved_profile, project_path);
 
     const CONTAINER_HOME: &str = "/root";
+    /// Home for a matched host user. The image's `/root` stays root-owned;
+    /// the config mounts move here so the user can write them.
+    const MATCHED_USER_HOME: &str = "/home/aoe";
+    let container_home = if sandbox_config.match_host_user {
+        MATCHED_USER_HOME
+    } else {
+        CONTAINER_HOME
+    };
 
     let mut environment = collect_environment(&sandbox_config, sandbox_info);
 
@@ -1189,7 +1440,7 @@ pub(crate) fn build_container_config(
     if gitconfig.exists() {
         volumes.push(VolumeMount {
             host_path: gitconfig.to_string_lossy().to_string(),
-            container_path: format!("{}/.gitconfig", CONTAINER_HOME),
+            container_path: format!("{}/.gitconfig", container_home),
             read_only: true,
         });
     }
@@ -1199,7 +1450,7 @@ pub(crate) fn build_container_config(
         if ssh_dir.exists() {
             volumes.push(VolumeMount {
                 host_path: ssh_dir.to_string_lossy().to_string(),
-                container_path: format!("{}/.ssh", CONTAINER_HOME),
+                container_path: format!("{}/.ssh", container_home),
                 read_only: true,
             });
         }
@@ -1214,7 +1465,7 @@ pub(crate) fn build_container_config(
     if agent_selection.tool == "claude" && super::environment::host_vertex_enabled() {
         let container_cred_path = format!(
             "{}/.config/gcloud/application_default_credentials.json",
-            CONTAINER_HOME
+            container_home
         );
         if let Ok(cred_path) = std::env::var("GOOGLE_APPLICATION_CREDENTIALS") {
             let cred_file = std::path::Path::new(&cred_path);
@@ -1249,19 +1500,20 @@ pub(crate) fn build_container_config(
         .iter()
         .filter(|m| m.tool_name == config_tool)
     {
-        let container_path = agent_config_container_path(mount, CONTAINER_HOME, &environment);
+        let container_path = agent_config_container_path(mount, container_home, &environment);
 
-        let sandbox_dir = match prepare_sandbox_dir(mount, &home) {
-            Ok(dir) => dir,
-            Err(e) => {
-                tracing::warn!(target: "session.profile",
-                    "Failed to prepare sandbox dir for {}, skipping: {}",
-                    mount.host_rel,
-                    e
-                );
-                continue;
-            }
-        };
+        let sandbox_dir =
+            match prepare_sandbox_dir(mount, &home, &sandbox_config.config_sync_excludes) {
+                Ok(dir) => dir,
+                Err(e) => {
+                    tracing::warn!(target: "session.profile",
+                        "Failed to prepare sandbox dir for {}, skipping: {}",
+                        mount.host_rel,
+                        e
+                    );
+                    continue;
+                }
+            };
 
         tracing::debug!(target: "session.profile",
             "Sandbox dir ready for {}, binding {} -> {}",
@@ -1282,7 +1534,7 @@ pub(crate) fn build_container_config(
             if file_path.exists() {
                 volumes.push(VolumeMount {
                     host_path: file_path.to_string_lossy().to_string(),
-                    container_path: format!("{}/{}", CONTAINER_HOME, filename),
+                    container_path: format!("{}/{}", container_home, filename),
                     read_only: false,
                 });
             }
@@ -1355,9 +1607,21 @@ pub(crate) fn build_container_config(
         for &(key, value) in agent.container_env {
             environment.push(EnvEntry::Literal {
                 key: key.to_string(),
-                value: value.to_string(),
+                value: rehome(value, CONTAINER_HOME, container_home),
             });
         }
+        if let Some(spec) = sandbox_config.agent_container_env.get(agent.name) {
+            let pairs = super::parse_agent_container_env(spec).map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid sandbox.agent_container_env for {}: {e}",
+                    agent.name
+                )
+            })?;
+            for (key, value) in pairs {
+                environment.retain(|entry| entry.key() != key);
+                environment.push(EnvEntry::Literal { key, value });
+            }
+        }
         if is_yolo_mode {
             if let Some(crate::agents::YoloMode::EnvVar(key, value)) = &agent.yolo {
                 environment.push(EnvEntry::Literal {
@@ -1374,26 +1638,30 @@ pub(crate) fn build_container_config(
         "extra_volumes from config: {:?}",
         sandbox_config.extra_volumes
     );
-    let mut extra_volume_container_paths: std::collections::HashSet<String> =
-        std::collections::HashSet::new();
-    for entry in &sandbox_config.extra_volumes {
-        let parts: Vec<&str> = entry.splitn(3, ':').collect();
-        if parts.len() >= 2 {
-            tracing::info!(target: "session.profile",
-                "Mounting extra volume: {} -> {} (ro: {})",
-                parts[0],
-                parts[1],
-                parts.get(2) == Some(&"ro")
+    let (extra_mounts, volume_problems) =
+        check_extra_volumes(&sandbox_config.extra_volumes, project_path, &home);
+    if !volume_problems.is_empty() {
+        if sandbox_config.extra_volumes_strict {
+            anyhow::bail!(
+                "Invalid sandbox.extra_volumes:\n  - {}\nTip: fix the entries, or turn off sandbox.extra_volumes_strict",
+                volume_problems.join("\n  - ")
             );
-            extra_volume_container_paths.insert(parts[1].to_string());
-            volumes.push(VolumeMount {
-                host_path: parts[0].to_string(),
-                container_path: parts[1].to_string(),
-                read_only: parts.get(2) == Some(&"ro"),
-            });
-        } else {
-            tracing::warn!(target: "session.profile", "Ignoring malformed extra_volume entry: {}", entry);
         }
+        for problem in &volume_problems {
+            tracing::warn!(target: "session.profile", "extra_volumes: {}", problem);
+        }
+    }
+    let mut extra_volume_container_paths: std::collections::HashSet<String> =
+        std::collections::HashSet::new();
+    for mount in extra_mounts {
+        tracing::info!(target: "session.profile",
+            "Mounting extra volume: {} -> {} (ro: {})",
+            mount.host_path,
+            mount.container_path,
+            mount.read_only
+        );
+        extra_volume_container_paths.insert(mount.container_path.clone());
+        volumes.push(mount);
     }
 
     // Resolve volume_ignores into concrete container mount paths. Literal entries
@@ -1458,19 +1726,129 @@ pub(crate) fn build_container_config(
     }
     deduped.reverse();
 
+    // `mount_excludes` swaps the project bind mounts for one-time copies so
+    // the excluded paths never exist in the container (a bind mount can't
+    // hide individual files). extra_volumes and agent config mounts stay
+    // bind-mounted.
+    let mut seeded_copies = Vec::new();
+    if !sandbox_config.mount_excludes.is_empty() {
+        deduped.retain(|vol| {
+            if !project_mounts.contains(&vol.container_path) {
+                return true;
+            }
+            seeded_copies.push(SeededCopy {
+                host_path: vol.host_path.clone(),
+                container_path: vol.container_path.clone(),
+                excludes: sandbox_config.mount_excludes.clone(),
+            });
+            false
+        });
+    }
+
+    let (entrypoint, command) = container_startup_override(&sandbox_config)?;
+    let docker_socket = docker_socket_mount(&sandbox_config);
+    let user = sandbox_config.match_host_user.then(|| {
+        // A UID with no passwd entry gets HOME=/ from the runtime; point it
+        // at the home the agent configs are mounted under.
+        environment.push(EnvEntry::Literal {
+            key: "HOME".to_string(),
+            value: container_home.to_string(),
+        });
+        ContainerUser {
+            id: format!(
+                "{}:{}",
+                nix::unistd::getuid().as_raw(),
+                nix::unistd::getgid().as_raw()
+            ),
+            home: container_home.to_string(),
+        }
+    });
+
     Ok(ContainerConfig {
         working_dir: workspace_path,
         volumes: deduped,
         anonymous_volumes,
         named_ignore_volumes,
+        seeded_copies,
         environment,
-        cpu_limit: sandbox_config.cpu_limit,
-        memory_limit: sandbox_config.memory_limit,
+        // Per-session limits from the new-session dialog win over the profile.
+        cpu_limit: sandbox_info.cpu_limit.clone().or(sandbox_config.cpu_limit),
+        memory_limit: sandbox_info
+            .memory_limit
+            .clone()
+            .or(sandbox_config.memory_limit),
         port_mappings: sandbox_config.port_mappings.clone(),
         selinux_relabel: sandbox_config.selinux_relabel,
+        restart_policy: sandbox_config
+            .restart_policy
+            .create_arg()
+            .map(str::to_string),
+        user,
+        entrypoint,
+        command,
+        docker_socket,
     })
 }
 
+/// The host socket to mount for `sandbox.mount_docker_socket`: the configured
+/// `docker_socket_path`, else a `unix://` `DOCKER_HOST`, else the default
+/// `/var/run/docker.sock`. `None` when the option is off or the socket is
+/// missing, so a stopped daemon doesn't leave a dangling mount behind.
+fn docker_socket_mount(sandbox_config: &super::config::SandboxConfig) -> Option<String> {
+    if !sandbox_config.mount_docker_socket {
+        return None;
+    }
+    let socket = docker_socket_host_path(
+        sandbox_config.docker_socket_path.as_deref(),
+        std::env::var("DOCKER_HOST").ok().as_deref(),
+    );
+    if !Path::new(&socket).exists() {
+        tracing::warn!(target: "session.profile",
+            "mount_docker_socket is on but {} does not exist; not mounting it",
+            socket
+        );
+        return None;
+    }
+    tracing::warn!(target: "session.profile",
+        "Mounting host Docker socket {} into the sandbox; the agent can control the host daemon",
+        socket
+    );
+    Some(socket)
+}
+
+const DEFAULT_DOCKER_SOCKET: &str = "/var/run/docker.sock";
+
+fn docker_socket_host_path(configured: Option<&str>, docker_host: Option<&str>) -> String {
+    if let Some(path) = configured.map(str::trim).filter(|p| !p.is_empty()) {
+        return path.to_string();
+    }
+    docker_host
+        .and_then(|host| host.strip_prefix("unix://"))
+        .filter(|path| !path.is_empty())
+        .unwrap_or(DEFAULT_DOCKER_SOCKET)
+        .to_string()
+}
+
+/// Resolve `sandbox.entrypoint` / `sandbox.container_command` into the
+/// `--entrypoint` value and the argv after the image. Blank values fall back
+/// to the image entrypoint and the default keepalive.
+fn container_startup_override(
+    sandbox_config: &super::config::SandboxConfig,
+) -> Result<(Option<String>, Vec<String>)> {
+    let entrypoint = sandbox_config
+        .entrypoint
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string);
+    let command = match sandbox_config.container_command.as_deref().map(str::trim) {
+        Some(cmd) if !cmd.is_empty() => shell_words::split(cmd)
+            .map_err(|e| anyhow::anyhow!("Invalid sandbox.container_command {:?}: {}", cmd, e))?,
+        _ => Vec::new(),
+    };
+    Ok((entrypoint, command))
+}
+
 /// Find the longest common ancestor path of two absolute paths.
 fn common_ancestor(a: &Path, b: &Path) -> PathBuf {
     let mut result = PathBuf::new();
@@ -1947,7 +2325,7 @@ mod tests {
             .iter()
             .find(|m| m.tool_name == "hermes")
             .unwrap();
-        let sandbox = prepare_sandbox_dir(mount, dir.path()).unwrap();
+        let sandbox = prepare_sandbox_dir(mount, dir.path(), &[]).unwrap();
 
         assert!(sandbox.join("config.yaml").exists());
         assert!(sandbox.join(".env").exists());
@@ -2527,6 +2905,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_opencode_auth_preserved_across_resync() {
+        let mount = AGENT_CONFIG_MOUNTS
+            .iter()
+            .find(|m| m.tool_name == "opencode" && m.host_rel == ".local/share/opencode")
+            .unwrap();
+        let dir = TempDir::new().unwrap();
+        let host = setup_host_dir(&dir);
+        let sandbox = dir.path().join("sandbox");
+
+        // Host is logged in.
+        fs::write(host.join("auth.json"), r#"{"anthropic":"host"}"#).unwrap();
+
+        // First sync copies it in.
+        sync_agent_config(&host, &sandbox, &[], &[], &[], mount.preserve_files).unwrap();
+        assert_eq!(
+            fs::read_to_string(sandbox.join("auth.json")).unwrap(),
+            r#"{"anthropic":"host"}"#
+        );
+
+        // The container refreshes its token, then the host copy changes too.
+        fs::write(sandbox.join("auth.json"), r#"{"anthropic":"container"}"#).unwrap();
+        fs::write(host.join("auth.json"), r#"{"anthropic":"host-refreshed"}"#).unwrap();
+
+        // Re-sync (container restart) must keep the container's login.
+        sync_agent_config(&host, &sandbox, &[], &[], &[], mount.preserve_files).unwrap();
+        assert_eq!(
+            fs::read_to_string(sandbox.join("auth.json")).unwrap(),
+            r#"{"anthropic":"container"}"#
+        );
+    }
+
+    #[test]
+    fn test_changed_files_ignores_identical_rewrites() {
+        let dir = TempDir::new().unwrap();
+        let host = setup_host_dir(&dir);
+        let sandbox = dir.path().join("sandbox");
+        fs::create_dir_all(host.join("skills/review")).unwrap();
+        fs::write(host.join("skills/review/SKILL.md"), "v1").unwrap();
+
+        sync_agent_config(&host, &sandbox, &[], &[], &["skills"], &[]).unwrap();
+        let before = fingerprint_files(&sandbox);
+
+        fs::write(host.join("skills/review/SKILL.md"), "v2").unwrap();
+        sync_agent_config(&host, &sandbox, &[], &[], &["skills"], &[]).unwrap();
+        let after = fingerprint_files(&sandbox);
+
+        assert_eq!(
+            changed_files(&before, &after),
+            vec![PathBuf::from("skills/review/SKILL.md")]
+        );
+
+        // A second sync with nothing changed on the host rewrites the same
+        // bytes, which must not show up as a change.
+        sync_agent_config(&host, &sandbox, &[], &[], &["skills"], &[]).unwrap();
+        assert!(changed_files(&after, &fingerprint_files(&sandbox)).is_empty());
+    }
+
     #[test]
     fn test_has_prior_data_skips_general_file_copy() {
         let dir = TempDir::new().unwrap();
@@ -2559,6 +2995,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_codex_and_gemini_preserve_their_credential_files() {
+        let preserved = |tool: &str| -> Vec<&'static str> {
+            AGENT_CONFIG_MOUNTS
+                .iter()
+                .filter(|m| m.tool_name == tool)
+                .flat_map(|m| m.preserve_files.iter().copied())
+                .collect()
+        };
+        assert!(preserved("codex").contains(&"auth.json"));
+        assert!(preserved("gemini").contains(&"oauth_creds.json"));
+        assert!(preserved("gemini").contains(&"google_accounts.json"));
+    }
+
     #[test]
     fn test_preserve_files_seeded_when_missing() {
         let dir = TempDir::new().unwrap();
@@ -2679,6 +3129,8 @@ extra_volumes = ["/host/data:/container/data:ro"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let project_path_str = project_dir.path().to_str().unwrap();
@@ -2732,6 +3184,50 @@ extra_volumes = ["/host/data:/container/data:ro"]
             "extra_volumes should include /host/data:/container/data, got: {:?}",
             volume_pairs
         );
+
+        // Strict mode turns the missing /host/data into a create-time error.
+        fs::write(
+            config_dir.join("config.toml"),
+            r#"
+[sandbox]
+extra_volumes = ["/host/data:/container/data:ro"]
+extra_volumes_strict = true
+"#,
+        )
+        .unwrap();
+        let Err(err) = build_container_config(
+            project_path_str,
+            &sandbox_info,
+            ContainerAgentSelection::new("claude", None),
+            false,
+            "test-instance-id",
+            None,
+            "",
+        ) else {
+            panic!("strict mode should reject the missing host path");
+        };
+        assert!(
+            err.to_string().contains("/host/data does not exist"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    fn test_docker_socket_host_path_precedence() {
+        assert_eq!(
+            docker_socket_host_path(Some(" /srv/docker.sock "), Some("unix:///x.sock")),
+            "/srv/docker.sock"
+        );
+        assert_eq!(
+            docker_socket_host_path(Some(""), Some("unix:///run/user/1000/docker.sock")),
+            "/run/user/1000/docker.sock"
+        );
+        // A TCP DOCKER_HOST has no socket to mount; fall back to the default.
+        assert_eq!(
+            docker_socket_host_path(None, Some("tcp://10.0.0.1:2375")),
+            DEFAULT_DOCKER_SOCKET
+        );
+        assert_eq!(docker_socket_host_path(None, None), DEFAULT_DOCKER_SOCKET);
     }
 
     #[test]
@@ -2785,6 +3281,8 @@ volume_ignores = ["**/bin", "**/obj", "target"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let project_path_str = project_dir.path().to_str().unwrap();
@@ -2825,6 +3323,59 @@ volume_ignores = ["**/bin", "**/obj", "target"]
         );
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_agent_container_env_overrides_builtin_agent_env() {
+        let temp_home = TempDir::new().unwrap();
+        std::env::set_var("HOME", temp_home.path());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        std::env::set_var("XDG_CONFIG_HOME", temp_home.path().join(".config"));
+
+        let project_dir = TempDir::new().unwrap();
+        let config_dir = project_dir.path().join(".agent-of-empires");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            r#"
+[sandbox.agent_container_env]
+claude = "CLAUDE_CONFIG_DIR=/root/alt FEATURE='a b'"
+codex = "CODEX_ONLY=1"
+"#,
+        )
+        .unwrap();
+
+        let sandbox_info = super::super::instance::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "test:latest".to_string(),
+            container_name: "test-container".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        };
+        let config = build_container_config(
+            project_dir.path().to_str().unwrap(),
+            &sandbox_info,
+            ContainerAgentSelection::new("claude", None),
+            false,
+            "test-instance-id",
+            None,
+            "",
+        )
+        .unwrap();
+
+        let values: Vec<(&str, &str)> = config
+            .environment
+            .iter()
+            .map(|e| (e.key(), e.value()))
+            .collect();
+        assert!(values.contains(&("CLAUDE_CONFIG_DIR", "/root/alt")));
+        assert!(!values.contains(&("CLAUDE_CONFIG_DIR", "/root/.claude")));
+        assert!(values.contains(&("FEATURE", "a b")));
+        assert!(!values.iter().any(|(k, _)| *k == "CODEX_ONLY"));
+    }
+
     /// `preview_glob_volume_ignores` reports the same expansion the build performs,
     /// keeps a configured-but-unmatched pattern with an empty match list, and ignores
     /// literal entries entirely.
@@ -2925,6 +3476,8 @@ extra_volumes = ["/host/screenshots:/root/screenshots"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let config = build_container_config(
@@ -2950,6 +3503,154 @@ extra_volumes = ["/host/screenshots:/root/screenshots"]
         );
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_build_container_config_match_host_user() {
+        let temp_home = TempDir::new().unwrap();
+        std::env::set_var("HOME", temp_home.path());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        std::env::set_var("XDG_CONFIG_HOME", temp_home.path().join(".config"));
+
+        let project_dir = TempDir::new().unwrap();
+        git2::Repository::init(project_dir.path()).unwrap();
+        let sandbox_info = super::super::instance::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "test:latest".to_string(),
+            container_name: "test-container".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        };
+        let build = || {
+            build_container_config(
+                project_dir.path().to_str().unwrap(),
+                &sandbox_info,
+                ContainerAgentSelection::new("claude", None),
+                false,
+                "test-instance-id",
+                None,
+                "",
+            )
+            .unwrap()
+        };
+        let env = |config: &ContainerConfig, key: &str| {
+            config
+                .environment
+                .iter()
+                .find(|e| e.key() == key)
+                .map(|e| e.value().to_string())
+        };
+        let mounted_at = |config: &ContainerConfig, path: &str| {
+            config.volumes.iter().any(|v| v.container_path == path)
+        };
+
+        let config = build();
+        assert!(config.user.is_none());
+        assert_eq!(env(&config, "HOME"), None);
+        assert!(mounted_at(&config, "/root/.claude"));
+        assert_eq!(
+            env(&config, "CLAUDE_CONFIG_DIR").as_deref(),
+            Some("/root/.claude")
+        );
+
+        let config_dir = project_dir.path().join(".agent-of-empires");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[sandbox]\nmatch_host_user = true\n",
+        )
+        .unwrap();
+
+        let config = build();
+        let expected = ContainerUser {
+            id: format!(
+                "{}:{}",
+                nix::unistd::getuid().as_raw(),
+                nix::unistd::getgid().as_raw()
+            ),
+            home: "/home/aoe".to_string(),
+        };
+        assert_eq!(config.user, Some(expected));
+        assert_eq!(env(&config, "HOME").as_deref(), Some("/home/aoe"));
+        assert!(mounted_at(&config, "/home/aoe/.claude"));
+        assert!(!mounted_at(&config, "/root/.claude"));
+        assert_eq!(
+            env(&config, "CLAUDE_CONFIG_DIR").as_deref(),
+            Some("/home/aoe/.claude")
+        );
+    }
+
+    #[test]
+    fn test_rehome_only_moves_paths_under_the_old_home() {
+        assert_eq!(
+            rehome("/root/.claude", "/root", "/home/aoe"),
+            "/home/aoe/.claude"
+        );
+        assert_eq!(rehome("/root", "/root", "/home/aoe"), "/home/aoe");
+        assert_eq!(rehome("/rootfs/x", "/root", "/home/aoe"), "/rootfs/x");
+        assert_eq!(rehome("/opt/tool", "/root", "/home/aoe"), "/opt/tool");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_build_container_config_mount_excludes_copies_project() {
+        let temp_home = TempDir::new().unwrap();
+        std::env::set_var("HOME", temp_home.path());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        std::env::set_var("XDG_CONFIG_HOME", temp_home.path().join(".config"));
+
+        let project_dir = TempDir::new().unwrap();
+        git2::Repository::init(project_dir.path()).unwrap();
+        let project = project_dir.path().to_str().unwrap().to_string();
+        let sandbox_info = super::super::instance::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "test:latest".to_string(),
+            container_name: "test-container".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        };
+        let build = || {
+            build_container_config(
+                &project,
+                &sandbox_info,
+                ContainerAgentSelection::new("claude", None),
+                false,
+                "test-instance-id",
+                None,
+                "",
+            )
+            .unwrap()
+        };
+
+        let config = build();
+        assert!(config.seeded_copies.is_empty());
+        assert!(config.volumes.iter().any(|v| v.host_path == project));
+
+        let config_dir = project_dir.path().join(".agent-of-empires");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[sandbox]\nmount_excludes = [\".env\", \"secrets\"]\n",
+        )
+        .unwrap();
+
+        let config = build();
+        assert!(!config.volumes.iter().any(|v| v.host_path == project));
+        assert_eq!(
+            config.seeded_copies,
+            vec![SeededCopy {
+                host_path: project.clone(),
+                container_path: config.working_dir.clone(),
+                excludes: vec![".env".to_string(), "secrets".to_string()],
+            }]
+        );
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_build_container_config_installs_codex_hooks_files() {
@@ -2968,6 +3669,8 @@ extra_volumes = ["/host/screenshots:/root/screenshots"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let instance_id = "codex-sandbox-hooks-test";
         let config = build_container_config(
@@ -3047,6 +3750,8 @@ extra_volumes = ["/host/screenshots:/root/screenshots"]
                 container_name: "test-container".to_string(),
                 extra_env: None,
                 custom_instruction: None,
+                cpu_limit: None,
+                memory_limit: None,
             };
             let instance_id = format!("{}-sidecar-sandbox-test", agent.name);
             let config = build_container_config(
@@ -3106,6 +3811,8 @@ extra_volumes = ["/host/screenshots:/root/screenshots"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = build_container_config(
@@ -3154,6 +3861,8 @@ extra_volumes = ["/host/screenshots:/root/screenshots"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let instance_id = "codex-sandbox-hooks-disabled-test";
         let config = build_container_config(
@@ -3214,6 +3923,8 @@ agent_detect_as = { "wrapped-codex" = "codex" }
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let instance_id = "wrapped-codex-sandbox-hooks-test";
         let config = build_container_config(
@@ -3271,6 +3982,8 @@ agent_detect_as = { "wrapped-codex" = "codex" }
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let instance_id = "codex-sandbox-refresh-hooks-test";
         build_container_config(
@@ -3298,7 +4011,7 @@ trusted_hash = "keep"
         fs::write(&sandbox_config_path, sandbox_config).unwrap();
         fs::write(codex_dir.join("config.toml"), r#"model = "updated""#).unwrap();
 
-        refresh_agent_configs();
+        refresh_agent_configs("default");
 
         let config_text = fs::read_to_string(&sandbox_config_path).unwrap();
         let config: toml::Value = toml::from_str(&config_text).unwrap();
@@ -3330,6 +4043,8 @@ trusted_hash = "keep"
             container_name: "test-container".to_string(),
             extra_env: Some(vec!["CODEX_HOME=/root/custom-codex".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let instance_id = "codex-sandbox-extra-env-hooks-test";
         let config = build_container_config(
@@ -3355,6 +4070,48 @@ trusted_hash = "keep"
         crate::hooks::cleanup_hook_status_dir(instance_id);
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_build_container_config_prefers_session_resource_limits() {
+        let temp_home = TempDir::new().unwrap();
+        std::env::set_var("HOME", temp_home.path());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        std::env::set_var("XDG_CONFIG_HOME", temp_home.path().join(".config"));
+
+        let mut global = crate::session::config::Config::default();
+        global.sandbox.cpu_limit = Some("2".to_string());
+        global.sandbox.memory_limit = Some("4g".to_string());
+        crate::session::config::save_config(&global).unwrap();
+
+        let project_dir = TempDir::new().unwrap();
+        git2::Repository::init(project_dir.path()).unwrap();
+
+        let sandbox_info = super::super::instance::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "test:latest".to_string(),
+            container_name: "test-container".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: Some("16g".to_string()),
+        };
+        let config = build_container_config(
+            project_dir.path().to_str().unwrap(),
+            &sandbox_info,
+            ContainerAgentSelection::new("claude", None),
+            false,
+            "session-resource-limits-test",
+            None,
+            "",
+        )
+        .unwrap();
+
+        assert_eq!(config.memory_limit.as_deref(), Some("16g"));
+        assert_eq!(config.cpu_limit.as_deref(), Some("2"));
+        crate::hooks::cleanup_hook_status_dir("session-resource-limits-test");
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_build_container_config_mounts_codex_home_from_sandbox_environment() {
@@ -3383,6 +4140,8 @@ environment = ["CODEX_HOME=/root/profile-codex"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let instance_id = "codex-sandbox-config-env-hooks-test";
         let config = build_container_config(
@@ -3470,6 +4229,8 @@ extra_volumes = ["/host/personal-only:/container/personal-only:ro"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let has_volume = |config: &crate::containers::container_interface::ContainerConfig,
@@ -3620,6 +4381,8 @@ volume_ignores = ["target", "node_modules"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let project_path_str = worktree_path.to_str().unwrap();
@@ -3713,6 +4476,8 @@ volume_ignores = ["target"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let project_path_str = worktree_path.to_str().unwrap();
@@ -3781,13 +4546,63 @@ volume_ignores = ["target"]
             clean_files: &["opencode.db", "opencode.db-wal", "opencode.db-shm"],
         };
 
-        prepare_sandbox_dir(&mount, home.path()).unwrap();
+        prepare_sandbox_dir(&mount, home.path(), &[]).unwrap();
 
         assert!(!sandbox_dir.join("opencode.db").exists());
         assert!(!sandbox_dir.join("opencode.db-wal").exists());
         assert!(!sandbox_dir.join("opencode.db-shm").exists());
     }
 
+    #[test]
+    fn test_config_sync_excludes_extend_skip_entries() {
+        let home = TempDir::new().unwrap();
+        let host_dir = home.path().join(".claude");
+        let sandbox_dir = host_dir.join("sandbox");
+        fs::create_dir_all(host_dir.join("skills")).unwrap();
+        fs::write(host_dir.join("skills/skill.md"), "skill").unwrap();
+        fs::write(host_dir.join("big.jsonl"), "huge").unwrap();
+        fs::write(host_dir.join("settings.json"), "{}").unwrap();
+
+        let mount = AgentConfigMount {
+            tool_name: "claude",
+            host_rel: ".claude",
+            container_suffix: ".claude",
+            skip_entries: &["sandbox"],
+            seed_files: &[],
+            copy_dirs: &["skills"],
+            keychain_credential: None,
+            home_seed_files: &[],
+            preserve_files: &[],
+            clean_files: &[],
+        };
+        let excludes = vec![
+            ".claude/big.jsonl".to_string(),
+            ".claude/skills".to_string(),
+            // Keyed to another agent's dir: must not affect this mount.
+            ".codex/settings.json".to_string(),
+        ];
+
+        prepare_sandbox_dir(&mount, home.path(), &excludes).unwrap();
+
+        assert!(!sandbox_dir.join("big.jsonl").exists());
+        assert!(!sandbox_dir.join("skills").exists());
+        assert!(sandbox_dir.join("settings.json").exists());
+    }
+
+    #[test]
+    fn test_configured_skip_entries_match_exact_dir() {
+        let excludes = vec![
+            ".gemini/tmp".to_string(),
+            ".gemini/antigravity-cli/cache".to_string(),
+        ];
+        assert_eq!(configured_skip_entries(".gemini", &excludes), vec!["tmp"]);
+        assert_eq!(
+            configured_skip_entries(".gemini/antigravity-cli", &excludes),
+            vec!["cache"]
+        );
+        assert!(configured_skip_entries(".claude", &excludes).is_empty());
+    }
+
     #[test]
     fn test_skip_entries_prevents_host_db_copy() {
         let home = TempDir::new().unwrap();
@@ -3818,7 +4633,7 @@ volume_ignores = ["target"]
             clean_files: &[],
         };
 
-        prepare_sandbox_dir(&mount, home.path()).unwrap();
+        prepare_sandbox_dir(&mount, home.path(), &[]).unwrap();
 
         assert!(
             !sandbox_dir.join("opencode.db").exists(),
@@ -3850,7 +4665,7 @@ volume_ignores = ["target"]
         };
 
         // Should not panic or error when files don't exist
-        prepare_sandbox_dir(&mount, home.path()).unwrap();
+        prepare_sandbox_dir(&mount, home.path(), &[]).unwrap();
     }
 
     // --- GCP credential mount tests ---
@@ -3867,6 +4682,8 @@ volume_ignores = ["target"]
             container_name: "test-container".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         }
     }
 
@@ -4101,4 +4918,114 @@ volume_ignores = ["target"]
             vol_sess10
         );
     }
+
+    #[test]
+    fn test_check_extra_volumes_reports_every_problem() {
+        let host = TempDir::new().unwrap();
+        let host_path = host.path().to_str().unwrap();
+        let entries = vec![
+            format!("{host_path}:/data:ro"),
+            "/aoe-missing-host-dir:/missing".to_string(),
+            format!("{host_path}:relative"),
+            "no-separator".to_string(),
+        ];
+
+        let (mounts, problems) = check_extra_volumes(&entries, host.path(), host.path());
+
+        let pairs: Vec<(&str, bool)> = mounts
+            .iter()
+            .map(|m| (m.container_path.as_str(), m.read_only))
+            .collect();
+        assert_eq!(pairs, vec![("/data", true), ("/missing", false)]);
+        assert_eq!(problems.len(), 3, "got: {problems:?}");
+        assert!(problems[0].contains("/aoe-missing-host-dir does not exist"));
+        assert!(problems[1].contains("Container path must be absolute"));
+        assert!(problems[2].starts_with("`no-separator`"));
+    }
+
+    #[test]
+    fn test_check_extra_volumes_modes() {
+        let host = TempDir::new().unwrap();
+        let host_path = host.path().to_str().unwrap();
+        let entries = vec![
+            format!("{host_path}:/ro:ro"),
+            format!("{host_path}:/rw:rw"),
+            format!("{host_path}:/default"),
+            format!("{host_path}:/typo:readonly"),
+        ];
+
+        let (mounts, problems) = check_extra_volumes(&entries, host.path(), host.path());
+
+        let modes: Vec<(&str, bool)> = mounts
+            .iter()
+            .map(|m| (m.container_path.as_str(), m.read_only))
+            .collect();
+        assert_eq!(
+            modes,
+            vec![("/ro", true), ("/rw", false), ("/default", false)],
+            "an unknown mode must not fall back to a read-write mount"
+        );
+        assert_eq!(problems.len(), 1, "got: {problems:?}");
+        assert!(problems[0].contains("Unknown mode `readonly`"));
+    }
+
+    #[test]
+    fn test_check_extra_volumes_resolves_relative_host_paths() {
+        let project = TempDir::new().unwrap();
+        let home = TempDir::new().unwrap();
+        fs::create_dir_all(project.path().join("fixtures")).unwrap();
+        fs::create_dir_all(home.path().join(".cache")).unwrap();
+        let entries = vec![
+            "fixtures:/fixtures:ro".to_string(),
+            "~/.cache:/cache".to_string(),
+            "~:/home-mount".to_string(),
+        ];
+
+        let (mounts, problems) = check_extra_volumes(&entries, project.path(), home.path());
+
+        assert!(problems.is_empty(), "got: {problems:?}");
+        let hosts: Vec<PathBuf> = mounts.iter().map(|m| PathBuf::from(&m.host_path)).collect();
+        assert_eq!(
+            hosts,
+            vec![
+                project.path().join("fixtures"),
+                home.path().join(".cache"),
+                home.path().to_path_buf(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_container_startup_override_blank_keeps_defaults() {
+        let mut sandbox = crate::session::config::SandboxConfig::default();
+        assert_eq!(
+            container_startup_override(&sandbox).unwrap(),
+            (None, vec![])
+        );
+
+        sandbox.entrypoint = Some("  ".to_string());
+        sandbox.container_command = Some(String::new());
+        assert_eq!(
+            container_startup_override(&sandbox).unwrap(),
+            (None, vec![])
+        );
+    }
+
+    #[test]
+    fn test_container_startup_override_splits_command() {
+        let sandbox = crate::session::config::SandboxConfig {
+            entrypoint: Some("/usr/bin/tini".to_string()),
+            container_command: Some("-- sh -c 'sleep infinity'".to_string()),
+            ..Default::default()
+        };
+        let (entrypoint, command) = container_startup_override(&sandbox).unwrap();
+        assert_eq!(entrypoint.as_deref(), Some("/usr/bin/tini"));
+        assert_eq!(command, ["--", "sh", "-c", "sleep infinity"]);
+
+        let malformed = crate::session::config::SandboxConfig {
+            container_command: Some("sh -c 'unterminated".to_string()),
+            ..Default::default()
+        };
+        assert!(container_startup_override(&malformed).is_err());
+    }
 }
@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use crate::containers::DockerContainer;
 use crate::git::cleanup::remove_managed_worktree;
 use crate::git::GitWorktree;
+use crate::session::activity::{self, ActivityKind};
 use crate::session::repo_config;
 use crate::session::Instance;
 
@@ -24,6 +25,10 @@ pub struct DeletionRequest {
     /// info level and surfaced in the deletion result's messages. Has no
     /// effect on non-scratch sessions.
     pub keep_scratch: bool,
+    /// With `delete_branch`, only delete branches fully merged into their
+    /// base branch (`worktree.delete_branch_on_cleanup = "if-merged"`).
+    /// Unmerged branches are kept and reported in the messages.
+    pub keep_unmerged_branch: bool,
 }
 
 #[derive(Debug)]
@@ -52,6 +57,17 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
         "perform_deletion: starting"
     );
 
+    // A locked session is an explicit "keep this": refuse before any stage
+    // runs so nothing is torn down behind the lock.
+    if request.instance.worktree_locked {
+        return DeletionResult {
+            session_id: request.session_id.clone(),
+            success: false,
+            messages,
+            errors: vec![crate::session::worktree_lock::SESSION_LOCKED_ERROR.to_string()],
+        };
+    }
+
     // Stage 1: on_destroy hooks. The container and worktree are still
     // alive here so teardown commands have full access.
     tracing::debug!(target: "session.delete", session_id = %request.session_id, stage = "on_destroy_hooks", "perform_deletion: stage");
@@ -78,7 +94,8 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
     // worktree contents unconditionally, which would silently violate
     // the `force_delete=false` safety contract for users with untracked
     // or modified files. Walk every managed worktree we'd touch and
-    // collect the dirty ones; preclean is skipped if anything is dirty
+    // collect the dirty ones (locked worktrees count too: the lock is an
+    // explicit "keep this" that only force delete overrides); preclean is skipped if anything is dirty
     // (the `find -delete` runs at the workspace root and can't easily
     // skip subpaths), and host-side worktree removal is skipped per
     // path that's dirty. Container, branch, and hook stages still run
@@ -89,11 +106,11 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
         if let Some(wt_info) = &request.instance.worktree_info {
             if wt_info.managed_by_aoe {
                 let path = PathBuf::from(&request.instance.project_path);
-                if let Some(msg) = crate::git::cleanup::dirty_worktree_message(&path) {
+                if let Some(msg) = worktree_blocker_message(&path) {
                     tracing::debug!(target: "session.delete",
                         session_id = %request.session_id,
                         path = %path.display(),
-                        "perform_deletion: dirty worktree, skipping preclean + host remove"
+                        "perform_deletion: locked or dirty worktree, skipping preclean + host remove"
                     );
                     errors.push(format!("Worktree: {}", msg));
                     skip_worktree_paths.insert(path);
@@ -105,12 +122,12 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
                 for repo in &ws_info.repos {
                     if repo.managed_by_aoe {
                         let path = PathBuf::from(&repo.worktree_path);
-                        if let Some(msg) = crate::git::cleanup::dirty_worktree_message(&path) {
+                        if let Some(msg) = worktree_blocker_message(&path) {
                             tracing::debug!(target: "session.delete",
                                 session_id = %request.session_id,
                                 repo = %repo.name,
                                 path = %path.display(),
-                                "perform_deletion: dirty workspace repo, skipping preclean + host remove"
+                                "perform_deletion: locked or dirty workspace repo, skipping preclean + host remove"
                             );
                             errors.push(format!("Workspace ({}): {}", repo.name, msg));
                             skip_worktree_paths.insert(path);
@@ -148,6 +165,7 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
             if let Err(e) = container.remove(true) {
                 errors.push(format!("Container: {}", e));
             } else {
+                activity::record(ActivityKind::ContainerRemoved, &request.instance, "");
                 messages.push("Container removed".to_string());
             }
         }
@@ -167,11 +185,17 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
             .worktree_info
             .as_ref()
             .filter(|wt| wt.managed_by_aoe)
-            .map(|wt| (wt.branch.clone(), PathBuf::from(&wt.main_repo_path)))
+            .map(|wt| {
+                (
+                    wt.branch.clone(),
+                    PathBuf::from(&wt.main_repo_path),
+                    wt.base_branch.clone(),
+                )
+            })
     } else {
         None
     };
-    if let Some((b, r)) = branch_to_delete.as_ref() {
+    if let Some((b, r, _)) = branch_to_delete.as_ref() {
         tracing::debug!(target: "session.delete", branch = %b, main_repo = %r.display(), "perform_deletion: branch_to_delete resolved");
     }
 
@@ -194,6 +218,11 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
                             ) {
                                 errors.extend(errs);
                             } else {
+                                activity::record(
+                                    ActivityKind::WorktreeRemoved,
+                                    &request.instance,
+                                    removal_detail(&worktree_path, request.force_delete),
+                                );
                                 messages.push("Worktree removed".to_string());
                             }
                         }
@@ -233,6 +262,11 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
                                             .map(|e| format!("Workspace ({}): {}", repo.name, e)),
                                     );
                                 } else {
+                                    activity::record(
+                                        ActivityKind::WorktreeRemoved,
+                                        &request.instance,
+                                        removal_detail(&worktree_path, request.force_delete),
+                                    );
                                     messages.push(format!(
                                         "Workspace ({}) worktree removed",
                                         repo.name
@@ -265,17 +299,28 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
     // Stage 5: branch cleanup (if user opted to delete it and worktree
     // was successfully removed).
     tracing::debug!(target: "session.delete", session_id = %request.session_id, stage = "branch_delete", "perform_deletion: stage");
-    if let Some((branch, main_repo)) = branch_to_delete {
+    if let Some((branch, main_repo, base)) = branch_to_delete {
         let worktree_ok =
             !request.delete_worktree || !errors.iter().any(|e| e.starts_with("Worktree:"));
         tracing::debug!(target: "session.delete", branch = %branch, main_repo = %main_repo.display(), worktree_ok, "perform_deletion: attempting branch deletion");
         if worktree_ok {
             match GitWorktree::new(main_repo.clone()) {
                 Ok(git_wt) => {
-                    if let Err(e) = git_wt.delete_branch(&branch) {
+                    let kept = request
+                        .keep_unmerged_branch
+                        .then(|| unmerged_branch_note(&git_wt, &branch, base.as_deref()))
+                        .flatten();
+                    if let Some(note) = kept {
+                        messages.push(note);
+                    } else if let Err(e) = git_wt.delete_branch(&branch) {
                         tracing::debug!(target: "session.delete", branch = %branch, error = %e, "perform_deletion: delete_branch returned error");
                         errors.push(format!("Branch: {}", e));
                     } else {
+                        activity::record(
+                            ActivityKind::BranchDeleted,
+                            &request.instance,
+                            branch.as_str(),
+                        );
                         messages.push(format!("Branch '{}' deleted", branch));
                     }
                 }
@@ -301,9 +346,20 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
                     if repo.managed_by_aoe {
                         let main_repo = PathBuf::from(&repo.main_repo_path);
                         if let Ok(git_wt) = GitWorktree::new(main_repo) {
-                            if let Err(e) = git_wt.delete_branch(&repo.branch) {
+                            let kept = request
+                                .keep_unmerged_branch
+                                .then(|| unmerged_branch_note(&git_wt, &repo.branch, None))
+                                .flatten();
+                            if let Some(note) = kept {
+                                messages.push(format!("{note} ({})", repo.name));
+                            } else if let Err(e) = git_wt.delete_branch(&repo.branch) {
                                 errors.push(format!("Branch ({}): {}", repo.name, e));
                             } else {
+                                activity::record(
+                                    ActivityKind::BranchDeleted,
+                                    &request.instance,
+                                    format!("{} ({})", repo.branch, repo.name),
+                                );
                                 messages.push(format!(
                                     "Branch '{}' ({}) deleted",
                                     repo.branch, repo.name
@@ -409,6 +465,12 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
     } else {
         tracing::debug!(target: "session.delete", session_id = %request.session_id, "perform_deletion: completed successfully");
     }
+    // A failed deletion leaves the session in place (the TUI shows the
+    // errors and offers a force delete), so it is not a deletion yet.
+    if errors.is_empty() {
+        let detail = if request.force_delete { "forced" } else { "" };
+        activity::record(ActivityKind::SessionDeleted, &request.instance, detail);
+    }
 
     DeletionResult {
         session_id: request.session_id.clone(),
@@ -418,6 +480,46 @@ pub fn perform_deletion(request: &DeletionRequest) -> DeletionResult {
     }
 }
 
+/// Activity detail for a removed worktree: its path, flagged when the
+/// removal was forced past uncommitted changes or a lock.
+fn removal_detail(path: &Path, forced: bool) -> String {
+    if forced {
+        format!("{} (forced)", path.display())
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// Why `branch` is kept under `keep_unmerged_branch`: it has commits not
+/// merged into `base`, or the check itself failed (erring on the side of
+/// keeping work). `None` when it is safe to delete.
+fn unmerged_branch_note(git_wt: &GitWorktree, branch: &str, base: Option<&str>) -> Option<String> {
+    match git_wt.is_branch_merged(branch, base) {
+        Ok(true) => None,
+        Ok(false) => {
+            tracing::warn!(target: "session.delete", branch, "keeping unmerged branch");
+            Some(format!(
+                "Branch '{branch}' kept: it is not merged into {}",
+                base.map_or_else(|| "the default branch".to_string(), |b| format!("'{b}'"))
+            ))
+        }
+        Err(e) => {
+            tracing::warn!(target: "session.delete", branch, error = %e, "keeping branch, merge check failed");
+            Some(format!(
+                "Branch '{branch}' kept: could not check whether it is merged ({e})"
+            ))
+        }
+    }
+}
+
+/// Reason a non-forced delete must leave `path` alone: the worktree is locked
+/// or has uncommitted changes. Lock is checked first since it is the more
+/// deliberate of the two.
+fn worktree_blocker_message(path: &Path) -> Option<String> {
+    crate::git::cleanup::locked_worktree_message(path)
+        .or_else(|| crate::git::cleanup::dirty_worktree_message(path))
+}
+
 /// Run on_destroy hooks for an instance. Uses best-effort execution so all
 /// hooks are attempted even if some fail. Failures are logged as warnings
 /// and never prevent deletion.
@@ -499,13 +601,26 @@ fn run_on_destroy_hooks(instance: &Instance, detach: bool) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
+
+    /// Point the app dir at a temp home: every deletion appends to the
+    /// activity log, which must not land in the real one.
+    fn isolate_app_dir() -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().expect("create temp home for deletion tests");
+        std::env::set_var("HOME", tmp.path());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        std::env::set_var("XDG_CONFIG_HOME", tmp.path().join(".config"));
+        tmp
+    }
 
     fn create_test_instance() -> Instance {
         Instance::new("Test Session", "/tmp/test-project")
     }
 
     #[test]
+    #[serial]
     fn test_deletion_result_success_when_no_worktree_or_sandbox() {
+        let _home = isolate_app_dir();
         let instance = create_test_instance();
         let request = DeletionRequest {
             session_id: instance.id.clone(),
@@ -516,6 +631,7 @@ mod tests {
             force_delete: false,
             detach_hooks: true,
             keep_scratch: false,
+            keep_unmerged_branch: false,
         };
 
         let result = perform_deletion(&request);
@@ -526,7 +642,9 @@ mod tests {
     }
 
     #[test]
+    #[serial]
     fn test_deletion_result_success_even_with_delete_worktree_flag_when_no_worktree() {
+        let _home = isolate_app_dir();
         let instance = create_test_instance();
         let request = DeletionRequest {
             session_id: instance.id.clone(),
@@ -537,6 +655,7 @@ mod tests {
             force_delete: false,
             detach_hooks: true,
             keep_scratch: false,
+            keep_unmerged_branch: false,
         };
 
         let result = perform_deletion(&request);
@@ -546,7 +665,9 @@ mod tests {
     }
 
     #[test]
+    #[serial]
     fn test_deletion_request_preserves_session_id() {
+        let _home = isolate_app_dir();
         let instance = create_test_instance();
         let custom_id = "custom-session-id-123".to_string();
 
@@ -559,6 +680,7 @@ mod tests {
             force_delete: false,
             detach_hooks: true,
             keep_scratch: false,
+            keep_unmerged_branch: false,
         };
 
         let result = perform_deletion(&request);
@@ -681,7 +803,9 @@ mod tests {
         /// agent and produced flaky permission errors and dirty-tree
         /// failures.
         #[test]
+        #[serial]
         fn sandboxed_with_worktree_kills_tmux_and_container_before_worktree() {
+            let _home = isolate_app_dir();
             // Synthesize an instance with both worktree_info and an
             // (enabled) sandbox_info pointing at a non-existent
             // container. The container ops will no-op (container does
@@ -695,6 +819,8 @@ mod tests {
                 container_name: "aoe-sandbox-doesnotexist".to_string(),
                 extra_env: None,
                 custom_instruction: None,
+                cpu_limit: None,
+                memory_limit: None,
             });
 
             let request = DeletionRequest {
@@ -706,6 +832,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
 
             let stages = run_with_capture(|| {
@@ -754,7 +881,9 @@ mod tests {
         /// e2e test for the worktree-delete path without a real
         /// container runtime.
         #[test]
+        #[serial]
         fn e2e_real_worktree_is_removed_on_disk() {
+            let _home = isolate_app_dir();
             let tmp = tempfile::TempDir::new().unwrap();
             let main_repo = tmp.path().join("main");
             let worktree_path = tmp.path().join("worktree");
@@ -814,6 +943,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
 
             let result = perform_deletion(&request);
@@ -848,6 +978,92 @@ mod tests {
             );
         }
 
+        /// `keep_unmerged_branch` deletes a branch whose commits are all
+        /// on its base branch and keeps one carrying new work, while the
+        /// worktree itself is still removed.
+        #[test]
+        #[serial]
+        fn e2e_keep_unmerged_branch_only_deletes_merged_branches() {
+            let _home = isolate_app_dir();
+            let tmp = tempfile::TempDir::new().unwrap();
+            let main_repo = tmp.path().join("main");
+            let worktree_path = tmp.path().join("worktree");
+            std::fs::create_dir(&main_repo).unwrap();
+
+            let repo = git2::Repository::init(&main_repo).unwrap();
+            let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+            let tree_id = repo.index().unwrap().write_tree().unwrap();
+            let tree = repo.find_tree(tree_id).unwrap();
+            let head = repo
+                .commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+                .unwrap();
+            let base = repo.head().unwrap().shorthand().unwrap().to_string();
+            repo.branch("feature/merged", &repo.find_commit(head).unwrap(), false)
+                .unwrap();
+
+            let git = |dir: &Path, args: &[&str]| {
+                let out = std::process::Command::new("git")
+                    .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+                    .args(args)
+                    .current_dir(dir)
+                    .output()
+                    .unwrap();
+                assert!(out.status.success(), "git {args:?}: {out:?}");
+            };
+            git(
+                &main_repo,
+                &[
+                    "worktree",
+                    "add",
+                    "-b",
+                    "feature/wip",
+                    worktree_path.to_str().unwrap(),
+                ],
+            );
+            git(&worktree_path, &["commit", "--allow-empty", "-m", "wip"]);
+
+            let request_for = |branch: &str, path: &Path, delete_worktree: bool| {
+                let mut instance = Instance::new("Test", path.to_str().unwrap());
+                instance.worktree_info = Some(crate::session::WorktreeInfo {
+                    branch: branch.to_string(),
+                    main_repo_path: main_repo.to_string_lossy().to_string(),
+                    managed_by_aoe: true,
+                    created_at: chrono::Utc::now(),
+                    base_branch: Some(base.clone()),
+                });
+                DeletionRequest {
+                    session_id: instance.id.clone(),
+                    instance,
+                    delete_worktree,
+                    delete_branch: true,
+                    delete_sandbox: false,
+                    force_delete: false,
+                    detach_hooks: true,
+                    keep_scratch: false,
+                    keep_unmerged_branch: true,
+                }
+            };
+            let branch_exists =
+                |name: &str| repo.find_branch(name, git2::BranchType::Local).is_ok();
+
+            let result = perform_deletion(&request_for("feature/wip", &worktree_path, true));
+            assert!(result.success, "{:?}", result.errors);
+            assert!(!worktree_path.exists());
+            assert!(branch_exists("feature/wip"), "unmerged branch must be kept");
+            assert!(
+                result
+                    .messages
+                    .iter()
+                    .any(|m| m.contains("'feature/wip' kept")),
+                "{:?}",
+                result.messages
+            );
+
+            let result = perform_deletion(&request_for("feature/merged", &main_repo, false));
+            assert!(result.success, "{:?}", result.errors);
+            assert!(!branch_exists("feature/merged"), "merged branch is deleted");
+        }
+
         /// Race-condition repro: the agent left untracked files in the
         /// worktree (this is what triggered the original
         /// "fatal: '<path>' contains modified or untracked files"
@@ -856,7 +1072,9 @@ mod tests {
         /// fallback path the TUI takes when the user picks "force
         /// delete" after a normal delete failed.
         #[test]
+        #[serial]
         fn e2e_real_worktree_with_untracked_files_force_removed() {
+            let _home = isolate_app_dir();
             let tmp = tempfile::TempDir::new().unwrap();
             let main_repo = tmp.path().join("main");
             let worktree_path = tmp.path().join("worktree");
@@ -909,6 +1127,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
             let result = perform_deletion(&req_no_force);
             assert!(
@@ -930,6 +1149,7 @@ mod tests {
                 force_delete: true,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
             let result = perform_deletion(&req_force);
             assert!(
@@ -999,6 +1219,8 @@ mod tests {
                 container_name: "aoe-dirty-test-doesnotexist".to_string(),
                 extra_env: None,
                 custom_instruction: None,
+                cpu_limit: None,
+                memory_limit: None,
             });
 
             (tmp, main_repo, worktree_path, instance)
@@ -1013,7 +1235,9 @@ mod tests {
         /// skipped, the worktree must survive, and the error must
         /// describe what's dirty so the user can choose to force.
         #[test]
+        #[serial]
         fn sandboxed_with_dirty_worktree_skips_preclean_and_preserves_changes() {
+            let _home = isolate_app_dir();
             let (_tmp, main_repo, worktree_path, instance) =
                 build_sandboxed_worktree("feature/dirty-no-force");
 
@@ -1028,6 +1252,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
 
             // Stage assertions: preclean must not run when dirty.
@@ -1077,6 +1302,66 @@ mod tests {
             );
         }
 
+        /// A `git worktree lock` is an explicit "keep this": a normal delete
+        /// must skip preclean and leave the worktree alone with a message
+        /// pointing at force delete, and force delete must unlock and
+        /// remove it (plain `--force` alone does not override a lock).
+        #[test]
+        #[serial]
+        fn locked_worktree_needs_force_and_is_unlocked_on_force() {
+            let _home = isolate_app_dir();
+            let (_tmp, main_repo, worktree_path, instance) =
+                build_sandboxed_worktree("feature/locked");
+            let status = std::process::Command::new("git")
+                .args(["worktree", "lock", "--reason", "long-lived"])
+                .arg(&worktree_path)
+                .current_dir(&main_repo)
+                .output()
+                .unwrap();
+            assert!(status.status.success());
+
+            let mut request = DeletionRequest {
+                session_id: instance.id.clone(),
+                instance,
+                delete_worktree: true,
+                delete_branch: false,
+                delete_sandbox: false,
+                force_delete: false,
+                detach_hooks: true,
+                keep_scratch: false,
+                keep_unmerged_branch: false,
+            };
+            let result = perform_deletion(&request);
+            assert!(!result.success);
+            let err = result.errors.join("; ");
+            assert!(
+                err.contains("locked (long-lived)") && err.contains("force delete"),
+                "error should explain the lock and offer force: {}",
+                err
+            );
+            assert!(worktree_path.exists(), "locked worktree must survive");
+            let deleted = |id: &str| {
+                crate::session::activity::recent()
+                    .iter()
+                    .any(|r| r.kind == ActivityKind::SessionDeleted && r.session_id == id)
+            };
+            assert!(
+                !deleted(&request.session_id),
+                "a failed deletion must not be logged as one"
+            );
+
+            request.force_delete = true;
+            let result = perform_deletion(&request);
+            assert!(
+                result.success,
+                "force delete should unlock and remove: {:?}",
+                result.errors
+            );
+            assert!(!worktree_path.exists());
+            assert!(!main_repo.join(".git/worktrees/worktree").exists());
+            assert!(deleted(&request.session_id));
+        }
+
         /// Counterpart: with `force_delete=true` the user has explicitly
         /// opted into losing uncommitted changes, so preclean runs and
         /// the worktree is removed. Preclean is a docker no-op in this
@@ -1084,7 +1369,9 @@ mod tests {
         /// `git worktree remove --force` which correctly handles the
         /// untracked file.
         #[test]
+        #[serial]
         fn sandboxed_with_dirty_worktree_force_runs_preclean_and_removes() {
+            let _home = isolate_app_dir();
             let (_tmp, main_repo, worktree_path, instance) =
                 build_sandboxed_worktree("feature/dirty-force");
 
@@ -1099,6 +1386,7 @@ mod tests {
                 force_delete: true,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
 
             let stages = run_with_capture(|| {
@@ -1126,7 +1414,9 @@ mod tests {
         /// Non-sandboxed deletion: no preclean stage is emitted, but
         /// tmux still gets killed before worktree work.
         #[test]
+        #[serial]
         fn unsandboxed_kills_tmux_before_worktree() {
+            let _home = isolate_app_dir();
             let instance = Instance::new("Test", "/tmp/aoe-deletion-test-nonexistent");
             let request = DeletionRequest {
                 session_id: instance.id.clone(),
@@ -1137,6 +1427,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
 
             let stages = run_with_capture(|| {
@@ -1161,14 +1452,6 @@ mod tests {
         use serial_test::serial;
         use std::fs;
 
-        fn isolate_app_dir() -> tempfile::TempDir {
-            let tmp = tempfile::tempdir().expect("create temp home for scratch deletion tests");
-            std::env::set_var("HOME", tmp.path());
-            #[cfg(any(target_os = "linux", target_os = "macos"))]
-            std::env::set_var("XDG_CONFIG_HOME", tmp.path().join(".config"));
-            tmp
-        }
-
         fn scratch_instance() -> (Instance, PathBuf) {
             let id = format!("delete-test-{}", uuid::Uuid::new_v4());
             let dir = crate::session::scratch::provision_scratch_dir(&id)
@@ -1192,6 +1475,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
 
             let result = perform_deletion(&request);
@@ -1228,6 +1512,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
             let result = perform_deletion(&request);
             assert!(
@@ -1261,6 +1546,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
             let result = perform_deletion(&request);
 
@@ -1301,6 +1587,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: true,
+                keep_unmerged_branch: false,
             };
 
             let result = perform_deletion(&request);
@@ -1354,6 +1641,7 @@ mod tests {
                 force_delete: false,
                 detach_hooks: true,
                 keep_scratch: false,
+                keep_unmerged_branch: false,
             };
             let _ = perform_deletion(&request);
 
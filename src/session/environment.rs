@@ -190,8 +190,8 @@ pub(crate) fn shell_escape(val: &str) -> String {
 ///
 /// Entry grammar (identical to `sandbox.environment`):
 /// - `KEY=value`: literal value, passed through verbatim.
-/// - `KEY=$VAR`: read VAR from the host env at spawn time (skipped with a
-///   warning if VAR is not set).
+/// - `KEY=$VAR` or `KEY=${VAR}`: read VAR from the host env at spawn time
+///   (skipped with a warning if VAR is not set).
 /// - `KEY=$$literal`: escape; emits `KEY='$literal'`.
 /// - bare `KEY`: passthrough from the host env (skipped with a warning if
 ///   the var is not set).
@@ -203,17 +203,7 @@ pub(crate) fn host_environment_prefix(entries: &[String]) -> String {
     let mut out = String::new();
     for entry in entries {
         if let Some((key, value)) = entry.split_once('=') {
-            let resolved = if let Some(rest) = value.strip_prefix("$$") {
-                Some(format!("${}", rest))
-            } else if value.starts_with('$') {
-                match resolve_env_value(value) {
-                    Some(v) => Some(v),
-                    None => continue,
-                }
-            } else {
-                Some(value.to_string())
-            };
-            if let Some(v) = resolved {
+            if let Some(v) = resolve_env_value(value) {
                 out.push_str(&format!("{}={} ", key, shell_escape(&v)));
             }
         } else {
@@ -253,14 +243,39 @@ pub(crate) fn resolve_host_environment_value(
     resolved_value
 }
 
-/// Resolve an environment value. If the value starts with `$`, read the
-/// named variable from the host environment (use `$$` to escape a literal `$`).
-/// Otherwise return the literal value.
-pub(crate) fn resolve_env_value(val: &str) -> Option<String> {
+/// How an entry's value is interpreted, before the host env is consulted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvValueSource<'a> {
+    /// Passed through as-is (`$$` already unescaped to `$`).
+    Literal(std::borrow::Cow<'a, str>),
+    /// Read from the named host variable (`$VAR` or `${VAR}`).
+    HostVar(&'a str),
+}
+
+/// Classify an entry value. Pure, so the settings preview and the spawn
+/// paths agree on the grammar:
+/// - `$$rest` is the literal `$rest`.
+/// - `${VAR}` and `$VAR` reference the host variable `VAR` (whole value only).
+/// - anything else is a literal.
+pub fn parse_env_value(val: &str) -> EnvValueSource<'_> {
     if let Some(rest) = val.strip_prefix("$$") {
-        Some(format!("${}", rest))
-    } else if let Some(var_name) = val.strip_prefix('$') {
-        match std::env::var(var_name) {
+        EnvValueSource::Literal(format!("${}", rest).into())
+    } else if let Some(name) = val.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+        EnvValueSource::HostVar(name)
+    } else if let Some(name) = val.strip_prefix('$') {
+        EnvValueSource::HostVar(name)
+    } else {
+        EnvValueSource::Literal(val.into())
+    }
+}
+
+/// Resolve an environment value. `$VAR` and `${VAR}` read the named variable
+/// from the host environment (use `$$` to escape a literal `$`). Otherwise
+/// return the literal value.
+pub(crate) fn resolve_env_value(val: &str) -> Option<String> {
+    match parse_env_value(val) {
+        EnvValueSource::Literal(v) => Some(v.into_owned()),
+        EnvValueSource::HostVar(var_name) => match std::env::var(var_name) {
             Ok(v) => Some(v),
             Err(_) => {
                 tracing::warn!(target: "session.create",
@@ -269,12 +284,49 @@ pub(crate) fn resolve_env_value(val: &str) -> Option<String> {
                 );
                 None
             }
+        },
+    }
+}
+
+/// What an entry will expand to on this host, for the settings list editor.
+/// Host variables are reported as set or unset, never by value: they often
+/// hold tokens, and the settings screen is easy to screen-share.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvEntryPreview {
+    /// A literal the session will receive as written (after `$$` unescaping).
+    Value(String),
+    /// The referenced host variable is set and will be passed through.
+    Set(String),
+    /// The referenced host variable is not set (or the name is empty), so the
+    /// entry will be skipped.
+    Unset(String),
+}
+
+/// Preview `entry` (`KEY`, `KEY=value`, `KEY=$VAR`, `KEY=${VAR}`) against
+/// `is_set`, which stands in for the host environment.
+pub fn preview_env_entry(entry: &str, is_set: impl Fn(&str) -> bool) -> EnvEntryPreview {
+    let source = match entry.split_once('=') {
+        Some((_, value)) => parse_env_value(value),
+        None => EnvValueSource::HostVar(entry),
+    };
+    match source {
+        EnvValueSource::Literal(v) => EnvEntryPreview::Value(v.into_owned()),
+        EnvValueSource::HostVar(name) if !name.is_empty() && is_set(name) => {
+            EnvEntryPreview::Set(name.to_string())
         }
-    } else {
-        Some(val.to_string())
+        EnvValueSource::HostVar(name) => EnvEntryPreview::Unset(name.to_string()),
     }
 }
 
+/// Whether `name` is a valid env var name: `[A-Za-z_][A-Za-z0-9_]*`.
+pub fn is_valid_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 /// Validate every entry in a list and return any warnings.
 ///
 /// Mirrors what `collect_environment` will silently drop at container
@@ -310,18 +362,15 @@ where
 ///
 /// Entry formats:
 /// - `KEY` (bare): pass through from host
-/// - `KEY=$VAR`: resolve `$VAR` from host
+/// - `KEY=$VAR` or `KEY=${VAR}`: resolve `VAR` from host
 /// - `KEY=literal` (no `$`): always valid
 /// - `KEY=$$...`: escaped literal `$`, always valid
 pub fn validate_env_entry(entry: &str) -> Option<String> {
     if let Some((_, value)) = entry.split_once('=') {
-        if value.starts_with("$$") {
-            // Escaped literal $, always valid
-            None
-        } else if let Some(var_name) = value.strip_prefix('$') {
+        if let EnvValueSource::HostVar(var_name) = parse_env_value(value) {
             if var_name.is_empty() {
                 Some("Warning: bare '$' in value has no variable name".to_string())
-            } else if resolve_env_value(value).is_none() {
+            } else if std::env::var(var_name).is_err() {
                 Some(format!(
                     "Warning: ${} is not set on the host, so the value will be empty in the container",
                     var_name
@@ -404,27 +453,23 @@ pub(crate) fn collect_environment(
     for entry in entries {
         if let Some((key, value)) = entry.split_once('=') {
             if seen_keys.insert(key.to_string()) {
-                if let Some(rest) = value.strip_prefix("$$") {
-                    // Escaped literal $, e.g. KEY=$$FOO -> KEY=$FOO
-                    let literal = format!("${}", rest);
-                    result.push(EnvEntry::Literal {
-                        key: key.to_string(),
-                        value: literal,
-                    });
-                } else if value.starts_with('$') {
-                    // Host env reference, e.g. GH_TOKEN=$GH_TOKEN
-                    if let Some(resolved) = resolve_env_value(value) {
-                        result.push(EnvEntry::Inherit {
+                match parse_env_value(value) {
+                    // Host env reference, e.g. GH_TOKEN=$GH_TOKEN or ${GH_TOKEN}
+                    EnvValueSource::HostVar(_) => {
+                        if let Some(resolved) = resolve_env_value(value) {
+                            result.push(EnvEntry::Inherit {
+                                key: key.to_string(),
+                                value: resolved,
+                            });
+                        }
+                    }
+                    // Literal value, e.g. TERM=xterm-256color or KEY=$$FOO -> $FOO
+                    EnvValueSource::Literal(literal) => {
+                        result.push(EnvEntry::Literal {
                             key: key.to_string(),
-                            value: resolved,
+                            value: literal.into_owned(),
                         });
                     }
-                } else {
-                    // Literal value, e.g. TERM=xterm-256color
-                    result.push(EnvEntry::Literal {
-                        key: key.to_string(),
-                        value: value.to_string(),
-                    });
                 }
             }
         } else {
@@ -628,6 +673,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let project_path = temp_home.path().join("nonexistent_project");
 
@@ -881,6 +928,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -903,6 +952,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -921,6 +972,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -957,6 +1010,8 @@ environment = ["GH_TOKEN=write_token"]
                 "GIT_CONFIG_VALUE_1=/workspace/other".to_string(),
             ]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -986,6 +1041,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: Some(vec!["AOE_TEST_EXTRA".to_string(), "FOO=bar".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1011,6 +1068,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: Some(vec!["DUP_KEY=from_session".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1032,6 +1091,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1053,6 +1114,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1075,6 +1138,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1123,6 +1188,79 @@ environment = ["GH_TOKEN=write_token"]
         assert_eq!(validate_env_entry("MY_KEY=$$ESCAPED"), None);
     }
 
+    #[test]
+    fn test_parse_env_value_grammar() {
+        assert_eq!(parse_env_value("$FOO"), EnvValueSource::HostVar("FOO"));
+        assert_eq!(parse_env_value("${FOO}"), EnvValueSource::HostVar("FOO"));
+        assert_eq!(
+            parse_env_value("$$FOO"),
+            EnvValueSource::Literal("$FOO".into())
+        );
+        assert_eq!(parse_env_value("${FOO"), EnvValueSource::HostVar("{FOO"));
+        assert_eq!(
+            parse_env_value("plain"),
+            EnvValueSource::Literal("plain".into())
+        );
+    }
+
+    #[test]
+    fn test_preview_env_entry_marks_set_and_unset() {
+        let lookup = |name: &str| name == "HOME_DIR";
+        assert_eq!(
+            preview_env_entry("A=${HOME_DIR}", lookup),
+            EnvEntryPreview::Set("HOME_DIR".into())
+        );
+        assert_eq!(
+            preview_env_entry("A=$HOME_DIR", lookup),
+            EnvEntryPreview::Set("HOME_DIR".into())
+        );
+        assert_eq!(
+            preview_env_entry("HOME_DIR", lookup),
+            EnvEntryPreview::Set("HOME_DIR".into())
+        );
+        assert_eq!(
+            preview_env_entry("A=literal", lookup),
+            EnvEntryPreview::Value("literal".into())
+        );
+        assert_eq!(
+            preview_env_entry("A=$$HOME_DIR", lookup),
+            EnvEntryPreview::Value("$HOME_DIR".into())
+        );
+        assert_eq!(
+            preview_env_entry("A=${MISSING}", lookup),
+            EnvEntryPreview::Unset("MISSING".into())
+        );
+        assert_eq!(
+            preview_env_entry("A=$", lookup),
+            EnvEntryPreview::Unset(String::new())
+        );
+    }
+
+    #[test]
+    fn test_collect_environment_braced_ref() {
+        std::env::set_var("AOE_TEST_BRACED_REF", "braced");
+        let config = SandboxConfig {
+            environment: vec!["TARGET=${AOE_TEST_BRACED_REF}".to_string()],
+            ..Default::default()
+        };
+        let info = SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "test".to_string(),
+            container_name: "test".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        };
+
+        let result = collect_environment(&config, &info);
+        std::env::remove_var("AOE_TEST_BRACED_REF");
+        let entry = find_entry(&result, "TARGET").expect("TARGET not found");
+        assert_eq!(entry.value(), "braced");
+        assert!(matches!(entry, EnvEntry::Inherit { .. }));
+    }
+
     #[test]
     fn test_validate_env_entries_returns_one_warning_per_missing_var() {
         // Use unique names to avoid collisions with other tests' env state.
@@ -1205,6 +1343,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: Some(vec!["AOE_TEST_TOKEN=$AOE_TEST_TOKEN".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let result = build_docker_env_args("", &sandbox, std::path::Path::new("/nonexistent"));
         // docker_args should have the key but NOT the secret value
@@ -1240,6 +1380,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: Some(vec!["MY_MAPPED=$AOE_TEST_SOURCE".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let result = build_docker_env_args("", &sandbox, std::path::Path::new("/nonexistent"));
         assert!(
@@ -1275,6 +1417,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: Some(vec!["AOE_TEST_BARE".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let result = build_docker_env_args("", &sandbox, std::path::Path::new("/nonexistent"));
         assert!(
@@ -1309,6 +1453,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: Some(vec!["MY_LITERAL=some_value".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let result = build_docker_env_args("", &sandbox, std::path::Path::new("/nonexistent"));
         assert!(
@@ -1342,6 +1488,8 @@ environment = ["GH_TOKEN=write_token"]
                 "MY_LITERAL=public_val".to_string(),
             ]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
         let result = build_docker_env_args("", &sandbox, std::path::Path::new("/nonexistent"));
         // Secret: key only in docker_args, value in exports
@@ -1442,6 +1590,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1477,6 +1627,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1503,6 +1655,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1528,6 +1682,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
@@ -1556,6 +1712,8 @@ environment = ["GH_TOKEN=write_token"]
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let result = collect_environment(&config, &info);
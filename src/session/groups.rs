@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
 use super::config::SortOrder;
 use super::Instance;
@@ -50,6 +51,11 @@ pub struct Group {
     pub collapsed: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived_at: Option<DateTime<Utc>>,
+    /// Position among sibling groups under the Custom sort, lowest first.
+    /// Groups without one follow their positioned siblings in creation
+    /// order. Set by [`GroupTree::move_group`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<u32>,
     #[serde(skip)]
     pub children: Vec<Group>,
 }
@@ -61,6 +67,7 @@ impl Group {
             path: path.to_string(),
             collapsed: false,
             archived_at: None,
+            sort_order: None,
             children: Vec::new(),
         }
     }
@@ -328,6 +335,54 @@ impl GroupTree {
 
         self.rebuild_tree();
     }
+
+    /// Move a group one place up (`up == true`) or down among its siblings
+    /// in the Custom sort order. Renumbers every sibling's `sort_order` so
+    /// the result is stable on disk. Returns false when the group does not
+    /// exist or is already first/last.
+    pub fn move_group(&mut self, path: &str, up: bool) -> bool {
+        if !self.groups_by_path.contains_key(path) {
+            return false;
+        }
+        let parent = path.rsplit_once('/').map(|(p, _)| p);
+        let mut siblings: Vec<String> = self
+            .insertion_order
+            .iter()
+            .filter(|p| self.groups_by_path.contains_key(*p))
+            .filter(|p| p.rsplit_once('/').map(|(pp, _)| pp) == parent)
+            .cloned()
+            .collect();
+        siblings.sort_by_key(|p| custom_position(self.groups_by_path.get(p)));
+
+        let Some(idx) = siblings.iter().position(|p| p == path) else {
+            return false;
+        };
+        let target = if up {
+            match idx.checked_sub(1) {
+                Some(t) => t,
+                None => return false,
+            }
+        } else if idx + 1 < siblings.len() {
+            idx + 1
+        } else {
+            return false;
+        };
+        siblings.swap(idx, target);
+
+        for (pos, sibling) in siblings.iter().enumerate() {
+            if let Some(group) = self.groups_by_path.get_mut(sibling) {
+                group.sort_order = Some(pos as u32);
+            }
+        }
+        self.rebuild_tree();
+        true
+    }
+}
+
+/// Sort key for the Custom order: positioned groups first, the rest after
+/// in their (stable) base order.
+fn custom_position(group: Option<&Group>) -> u32 {
+    group.and_then(|g| g.sort_order).unwrap_or(u32::MAX)
 }
 
 /// Item represents either a group or an instance in the flattened tree view
@@ -368,14 +423,18 @@ where
     match sort_order {
         SortOrder::AZ => items.sort_by_key(|a| key(a).to_lowercase()),
         SortOrder::ZA => items.sort_by_key(|b| std::cmp::Reverse(key(b).to_lowercase())),
-        SortOrder::Newest | SortOrder::Oldest | SortOrder::LastActivity | SortOrder::Attention => {}
+        SortOrder::Newest
+        | SortOrder::Oldest
+        | SortOrder::LastActivity
+        | SortOrder::Attention
+        | SortOrder::Custom => {}
     }
 }
 
 /// Sort a slice of session references by `sort_order`.
 fn sort_sessions(sessions: &mut [&Instance], sort_order: SortOrder) {
     match sort_order {
-        SortOrder::Oldest => sessions.sort_by_key(|i| i.created_at),
+        SortOrder::Oldest | SortOrder::Custom => sessions.sort_by_key(|i| i.created_at),
         SortOrder::Newest => sessions.sort_by_key(|i| Reverse(i.created_at)),
         SortOrder::LastActivity => sessions.sort_by_key(|i| last_activity_session_key(i)),
         SortOrder::Attention => sessions.sort_by_key(|i| attention_session_key(i)),
@@ -386,18 +445,21 @@ fn sort_sessions(sessions: &mut [&Instance], sort_order: SortOrder) {
 /// Sort a slice of group references by `sort_order`, using `instances` for
 /// timestamp-based orderings. The `archived` closure returns the group's
 /// own `archived_at` (only consulted by the Attention sort for empty
-/// archived groups).
-fn sort_groups<T, N, P, A>(
+/// archived groups); `position` returns its `sort_order` (only consulted
+/// by the Custom sort).
+fn sort_groups<T, N, P, A, S>(
     items: &mut [T],
     sort_order: SortOrder,
     instances: &[Instance],
     name: N,
     path: P,
     archived: A,
+    position: S,
 ) where
     N: Fn(&T) -> &str,
     P: Fn(&T) -> &str,
     A: Fn(&T) -> Option<DateTime<Utc>>,
+    S: Fn(&T) -> Option<u32>,
 {
     match sort_order {
         SortOrder::Oldest => {
@@ -413,6 +475,7 @@ fn sort_groups<T, N, P, A>(
             items.sort_by_key(|g| attention_group_key(path(g), archived(g), instances));
         }
         SortOrder::AZ | SortOrder::ZA => sort_by_name(items, sort_order, name),
+        SortOrder::Custom => items.sort_by_key(|g| position(g).unwrap_or(u32::MAX)),
     }
 }
 
@@ -475,6 +538,20 @@ fn last_activity_group_key(
     (ts.is_none(), Reverse(ts))
 }
 
+/// Feed everything the `flatten_*` functions read from a session into
+/// `state`: identity, placement, the fields the name and age sorts key on,
+/// and the full attention key (status, archive/snooze, favorite, urgent,
+/// activity). Lets the TUI skip re-flattening when a refresh reloads an
+/// unchanged session list.
+pub fn hash_flatten_inputs<H: Hasher>(inst: &Instance, state: &mut H) {
+    inst.id.hash(state);
+    inst.source_profile.hash(state);
+    inst.group_path.hash(state);
+    inst.title.hash(state);
+    inst.created_at.hash(state);
+    attention_session_key(inst).hash(state);
+}
+
 /// Priority tier for the Attention sort. Lower = higher priority = closer to
 /// the top of the list. See `docs/plans/2026-04-21-aoe-attention-sort.md` for
 /// the full rationale on tier choices.
@@ -733,6 +810,14 @@ pub fn flatten_tree_all_profiles(
         SortOrder::AZ | SortOrder::ZA => {
             sort_by_name(&mut all_roots, sort_order, |(_, g, _)| &*g.name)
         }
+        // Custom positions are per-profile, so keep each profile's groups
+        // together rather than interleaving them by position.
+        SortOrder::Custom => {
+            all_roots.sort_by(|(pa, a, _), (pb, b, _)| {
+                pa.cmp(pb)
+                    .then(custom_position(Some(a)).cmp(&custom_position(Some(b))))
+            });
+        }
     }
 
     for (profile_name, root, profile_instances) in &all_roots {
@@ -805,6 +890,7 @@ pub fn flatten_tree(
         |g| &g.name,
         |g| &g.path,
         |g| g.archived_at,
+        |g| g.sort_order,
     );
 
     for root in roots_to_iterate {
@@ -864,6 +950,7 @@ fn flatten_group(
         |g| &g.name,
         |g| &g.path,
         |g| g.archived_at,
+        |g| g.sort_order,
     );
 
     for child in children_to_iterate {
@@ -1010,6 +1097,7 @@ pub fn append_archived_section_by_project(
 /// uses the min `archived_at` ascending. Attention falls back to
 /// most-recently-archived because archived rows are all tier 99 in the
 /// attention bucket and the tier offers no discriminator inside the shelf.
+/// Custom does too: synthetic sub-folders have no manual position.
 fn sort_archived_project_buckets(buckets: &mut [(String, Vec<&Instance>)], sort_order: SortOrder) {
     match sort_order {
         SortOrder::AZ => buckets.sort_by_key(|b| b.0.to_lowercase()),
@@ -1023,7 +1111,7 @@ fn sort_archived_project_buckets(buckets: &mut [(String, Vec<&Instance>)], sort_
                     .unwrap_or(DateTime::<Utc>::MAX_UTC)
             });
         }
-        SortOrder::Newest | SortOrder::Attention => {
+        SortOrder::Newest | SortOrder::Attention | SortOrder::Custom => {
             buckets.sort_by_key(|(_, sessions)| {
                 Reverse(
                     sessions
@@ -1406,12 +1494,14 @@ mod tests {
         assert_eq!(SortOrder::LastActivity.cycle(), SortOrder::Oldest);
         assert_eq!(SortOrder::Oldest.cycle(), SortOrder::AZ);
         assert_eq!(SortOrder::AZ.cycle(), SortOrder::ZA);
-        assert_eq!(SortOrder::ZA.cycle(), SortOrder::Newest);
+        assert_eq!(SortOrder::ZA.cycle(), SortOrder::Custom);
+        assert_eq!(SortOrder::Custom.cycle(), SortOrder::Newest);
     }
 
     #[test]
     fn test_sort_order_cycle_reverse() {
-        assert_eq!(SortOrder::Newest.cycle_reverse(), SortOrder::ZA);
+        assert_eq!(SortOrder::Newest.cycle_reverse(), SortOrder::Custom);
+        assert_eq!(SortOrder::Custom.cycle_reverse(), SortOrder::ZA);
         assert_eq!(SortOrder::ZA.cycle_reverse(), SortOrder::AZ);
         assert_eq!(SortOrder::AZ.cycle_reverse(), SortOrder::Oldest);
         assert_eq!(SortOrder::Oldest.cycle_reverse(), SortOrder::LastActivity);
@@ -1748,6 +1838,42 @@ mod tests {
         assert!(tree.group_exists("work"));
     }
 
+    fn group_paths(items: &[Item]) -> Vec<&str> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Group { path, .. } => Some(path.as_str()),
+                Item::Session { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_move_group_reorders_siblings_under_custom_sort() {
+        let mut tree = GroupTree::new_with_groups(&[], &[]);
+        for path in ["a", "b", "c", "b/x", "b/y"] {
+            tree.create_group(path);
+        }
+
+        assert!(tree.move_group("c", true));
+        assert!(tree.move_group("b/y", true));
+        // Already first among its siblings
+        assert!(!tree.move_group("b/y", true));
+        assert!(!tree.move_group("missing", false));
+
+        let items = flatten_tree(&tree, &[], SortOrder::Custom);
+        assert_eq!(group_paths(&items), ["a", "c", "b", "b/y", "b/x"]);
+
+        // The order survives a save/load round trip through groups.json
+        let reloaded = GroupTree::new_with_groups(&[], &tree.get_all_groups());
+        let items = flatten_tree(&reloaded, &[], SortOrder::Custom);
+        assert_eq!(group_paths(&items), ["a", "c", "b", "b/y", "b/x"]);
+
+        // Other sorts ignore the manual order
+        let items = flatten_tree(&reloaded, &[], SortOrder::AZ);
+        assert_eq!(group_paths(&items), ["a", "b", "b/x", "b/y", "c"]);
+    }
+
     // ─── Archive feature tests ───────────────────────────────────────────
 
     #[test]
@@ -7,11 +7,15 @@
 //! the live tmux attach state, then ask this predicate per session and claim
 //! the stop through `Storage::update` so concurrent reapers cannot double-stop.
 //!
+//! The idle container reaper (`sandbox.auto_stop_idle_containers`) reuses
+//! the same predicate for sandboxed sessions but only stops the container:
+//! the session stays `Idle` and the next attach relaunches it.
+//!
 //! The structured view has its own reaper (`server::acp_reconciler`,
 //! #1689) with dormancy and seamless respawn; this is the tmux-view analog
 //! where a stop kills the pane and leaves a restartable `Stopped` row.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
@@ -44,7 +48,71 @@ pub fn idle_reap_candidates(
 ) -> Vec<IdleReapCandidate> {
     let mut candidates = Vec::new();
     for inst in instances {
-        if inst.is_structured() {
+        // Locked sessions are never auto-stopped.
+        if inst.is_structured() || inst.worktree_locked {
+            continue;
+        }
+        let profile = inst.effective_profile();
+        let threshold_secs = resolve_threshold(&profile);
+        if threshold_secs == 0 {
+            continue;
+        }
+        let is_attached = inst
+            .tmux_session()
+            .ok()
+            .is_some_and(|s| attached.contains(s.name()));
+        if should_auto_stop_session(
+            now,
+            inst.status,
+            inst.idle_entered_at,
+            inst.last_accessed_at,
+            is_attached,
+            threshold_secs,
+        ) {
+            candidates.push(IdleReapCandidate {
+                session_id: inst.id.clone(),
+                profile,
+                threshold_secs,
+            });
+        }
+    }
+    candidates
+}
+
+/// CPU usage (percent of one core) below which a sandbox container counts as
+/// quiet enough for the idle container reaper.
+pub const IDLE_CONTAINER_CPU_PERCENT: f64 = 1.0;
+
+/// Select the sandboxed sessions whose containers the idle container reaper
+/// should stop.
+///
+/// Applies [`should_auto_stop_session`] with the same attach and idle-anchor
+/// rules as [`idle_reap_candidates`], and additionally requires the container
+/// to be running and below [`IDLE_CONTAINER_CPU_PERCENT`] in `cpu` (from
+/// [`crate::containers::batch_container_cpu`]). A container missing from
+/// `cpu` is either stopped already or unsampled, so it is left alone.
+/// `resolve_threshold` maps a profile to its `sandbox.idle_container_stop_secs`,
+/// or `0` when `sandbox.auto_stop_idle_containers` is off for it.
+pub fn idle_container_candidates(
+    instances: &[Instance],
+    now: DateTime<Utc>,
+    attached: &HashSet<String>,
+    cpu: &HashMap<String, f64>,
+    resolve_threshold: impl Fn(&str) -> u32,
+) -> Vec<IdleReapCandidate> {
+    let mut candidates = Vec::new();
+    for inst in instances {
+        if inst.is_structured() || inst.worktree_locked || inst.container_idle_stopped_at.is_some()
+        {
+            continue;
+        }
+        let Some(sandbox) = inst.sandbox_info.as_ref().filter(|s| s.enabled) else {
+            continue;
+        };
+        if !cpu
+            .get(&sandbox.container_name)
+            .is_some_and(|&pct| pct < IDLE_CONTAINER_CPU_PERCENT)
+        {
             continue;
         }
         let profile = inst.effective_profile();
@@ -147,10 +215,11 @@ pub fn claim_idle_stop(
         let Some(inst) = instances.iter_mut().find(|i| i.id == session_id) else {
             return Ok(None);
         };
-        // Defense in depth: never stop a structured view row through the plain-session
-        // path, even if a caller reached here without going through
-        // `idle_reap_candidates` (which already excludes structured view sessions).
-        if inst.is_structured() {
+        // Defense in depth: never stop a structured view row or a locked
+        // session through the plain-session path, even if a caller reached
+        // here without going through `idle_reap_candidates` (which already
+        // excludes both).
+        if inst.is_structured() || inst.worktree_locked {
             return Ok(None);
         }
         let eligible = should_auto_stop_session(
@@ -169,6 +238,48 @@ pub fn claim_idle_stop(
     })
 }
 
+/// Atomically claim a sandboxed session for the idle container reaper.
+///
+/// Same locking and re-check as [`claim_idle_stop`], but instead of flipping
+/// the status to `Stopped` it stamps `container_idle_stopped_at`, so the
+/// session stays `Idle` and the caller only stops the container via
+/// [`Instance::stop_container`]. Returns `Ok(None)` when the session is no
+/// longer eligible or its container was already claimed.
+pub fn claim_idle_container_stop(
+    profile: &str,
+    file_watch: Arc<FileWatchService>,
+    session_id: &str,
+    now: DateTime<Utc>,
+    threshold_secs: u32,
+) -> anyhow::Result<Option<Instance>> {
+    let storage = Storage::new(profile, file_watch)?;
+    storage.update(|instances, _groups| {
+        let Some(inst) = instances.iter_mut().find(|i| i.id == session_id) else {
+            return Ok(None);
+        };
+        if inst.is_structured()
+            || inst.worktree_locked
+            || !inst.is_sandboxed()
+            || inst.container_idle_stopped_at.is_some()
+        {
+            return Ok(None);
+        }
+        let eligible = should_auto_stop_session(
+            now,
+            inst.status,
+            inst.idle_entered_at,
+            inst.last_accessed_at,
+            false,
+            threshold_secs,
+        );
+        if !eligible {
+            return Ok(None);
+        }
+        inst.container_idle_stopped_at = Some(now);
+        Ok(Some(inst.clone()))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,6 +454,15 @@ mod tests {
         assert!(idle_reap_candidates(&instances, n, &attached, |_| 0).is_empty());
     }
 
+    #[test]
+    fn candidates_skip_locked_session() {
+        let n = now();
+        let mut inst = idle_instance("a");
+        inst.worktree_locked = true;
+        let attached = HashSet::new();
+        assert!(idle_reap_candidates(&[inst], n, &attached, |_| 60).is_empty());
+    }
+
     #[test]
     fn candidates_skip_running_session() {
         let n = now();
@@ -362,6 +482,75 @@ mod tests {
         assert!(idle_reap_candidates(&[inst], n, &attached, |_| 60).is_empty());
     }
 
+    fn idle_sandboxed_instance(title: &str) -> Instance {
+        let mut inst = idle_instance(title);
+        inst.sandbox_info = Some(crate::session::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: crate::containers::DockerContainer::generate_name(&inst.id),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        });
+        inst
+    }
+
+    fn cpu_for(inst: &Instance, pct: f64) -> HashMap<String, f64> {
+        let name = inst.sandbox_info.as_ref().unwrap().container_name.clone();
+        HashMap::from([(name, pct)])
+    }
+
+    #[test]
+    fn container_candidates_select_quiet_idle_container() {
+        let n = now();
+        let inst = idle_sandboxed_instance("a");
+        let cpu = cpu_for(&inst, 0.1);
+        let got = idle_container_candidates(&[inst], n, &HashSet::new(), &cpu, |_| 60);
+        assert_eq!(got.len(), 1);
+    }
+
+    #[test]
+    fn container_candidates_skip_busy_or_unsampled_container() {
+        let n = now();
+        let inst = idle_sandboxed_instance("a");
+        let busy = cpu_for(&inst, 25.0);
+        assert!(idle_container_candidates(
+            std::slice::from_ref(&inst),
+            n,
+            &HashSet::new(),
+            &busy,
+            |_| 60
+        )
+        .is_empty());
+        assert!(
+            idle_container_candidates(&[inst], n, &HashSet::new(), &HashMap::new(), |_| 60)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn container_candidates_skip_unsandboxed_and_already_stopped() {
+        let n = now();
+        let plain = idle_instance("plain");
+        let mut stopped = idle_sandboxed_instance("stopped");
+        let cpu = cpu_for(&stopped, 0.0);
+        stopped.container_idle_stopped_at = Some(n);
+        assert!(
+            idle_container_candidates(&[plain, stopped], n, &HashSet::new(), &cpu, |_| 60)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn container_candidates_skip_disabled_profile() {
+        let n = now();
+        let inst = idle_sandboxed_instance("a");
+        let cpu = cpu_for(&inst, 0.0);
+        assert!(idle_container_candidates(&[inst], n, &HashSet::new(), &cpu, |_| 0).is_empty());
+    }
+
     /// Saves and restores `HOME` / `XDG_CONFIG_HOME` so a test that points the
     /// app dir at a tempdir does not leak that into sibling tests sharing the
     /// process.
@@ -429,4 +618,41 @@ mod tests {
         let stored = storage.load().unwrap();
         assert_eq!(stored[0].status, Status::Stopped);
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn container_claim_keeps_session_idle() {
+        let _env = EnvGuard::capture();
+        let temp = tempfile::tempdir().unwrap();
+        std::env::set_var("HOME", temp.path());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join(".config"));
+
+        let inst = idle_sandboxed_instance("claimable");
+        let id = inst.id.clone();
+        let storage = Storage::new_unwatched("test-profile").unwrap();
+        storage
+            .update(|instances, _groups| {
+                instances.push(inst);
+                Ok(())
+            })
+            .unwrap();
+
+        let now = Utc::now();
+        let first =
+            claim_idle_container_stop("test-profile", FileWatchService::noop(), &id, now, 60)
+                .unwrap();
+        assert!(first.is_some(), "first claim should win");
+        let second =
+            claim_idle_container_stop("test-profile", FileWatchService::noop(), &id, now, 60)
+                .unwrap();
+        assert!(
+            second.is_none(),
+            "second claim must not re-stop the container"
+        );
+
+        let stored = storage.load().unwrap();
+        assert_eq!(stored[0].status, Status::Idle);
+        assert_eq!(stored[0].container_idle_stopped_at, Some(now));
+    }
 }
@@ -12,6 +12,8 @@ use uuid::Uuid;
 use crate::containers::{self, ContainerRuntimeInterface, DockerContainer};
 use crate::tmux;
 
+use super::activity::{self, ActivityKind};
+use super::config::AgentExitBehavior;
 use super::container_config;
 use super::environment::{build_docker_env_args, shell_escape};
 use super::poller::SessionPoller;
@@ -133,6 +135,18 @@ pub enum LaunchSidOutcome {
     Skipped,
 }
 
+/// Output of `Instance::prepare_launch`: the wrapped agent command plus the
+/// CAS baseline `finalize_launch` needs.
+struct PreparedLaunch {
+    cmd: Option<String>,
+    /// `build_launch_command` resumed a prior conversation.
+    is_existing: bool,
+    on_exit: AgentExitBehavior,
+    profile: String,
+    expected_prior_sid: Option<String>,
+    expected_prior_intent: ResumeIntent,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ProbeResult {
     Alive,
@@ -290,7 +304,7 @@ pub struct WorkspaceInfo {
     pub cleanup_on_delete: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SandboxInfo {
     pub enabled: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -304,6 +318,12 @@ pub struct SandboxInfo {
     /// Custom instruction text to inject into agent launch command
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_instruction: Option<String>,
+    /// Per-session CPU limit; overrides `sandbox.cpu_limit` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_limit: Option<String>,
+    /// Per-session memory limit; overrides `sandbox.memory_limit` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_limit: Option<String>,
 }
 
 /// Deserialize agent_session_id, treating empty/whitespace strings as None.
@@ -423,6 +443,15 @@ pub struct Instance {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub idle_dormant_since: Option<DateTime<Utc>>,
 
+    /// Set by the idle container reaper (`sandbox.auto_stop_idle_containers`)
+    /// when it stops this session's sandbox container for inactivity. The
+    /// session itself stays `Idle` and keeps its worktree; while the marker
+    /// is set, status polling leaves the row alone instead of reporting the
+    /// dead container as an error. Cleared on the next launch, which starts
+    /// the container again, so re-attaching is transparent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_idle_stopped_at: Option<DateTime<Utc>>,
+
     /// Web-only pin marker. Distinct from `favorited_at`: favorite is the
     /// TUI attention-sort within-tier pin, while pin is a hard top-of-sort
     /// surfacing primitive surfaced through the web sidebar (where the TUI's
@@ -444,10 +473,22 @@ pub struct Instance {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub scratch: bool,
 
+    /// Open the paired terminal (the container terminal when sandboxed)
+    /// whenever the agent session starts. Seeded from
+    /// `session.auto_create_terminal` by the new-session dialog.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_create_terminal: bool,
+
     // Git worktree integration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub worktree_info: Option<WorktreeInfo>,
 
+    /// Session lock. Mirrors a `git worktree lock` on the session's worktree
+    /// and exempts the session from idle auto-stop; deletion is refused
+    /// until it is unlocked. See `src/session/worktree_lock.rs`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub worktree_locked: bool,
+
     // Multi-repo workspace integration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workspace_info: Option<WorkspaceInfo>,
@@ -455,6 +496,15 @@ pub struct Instance {
     // Docker sandbox integration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sandbox_info: Option<SandboxInfo>,
+    /// Initial instruction for a host session's agent (`aoe add
+    /// --prompt-file`). Sandboxed sessions keep theirs on
+    /// [`SandboxInfo::custom_instruction`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_instruction: Option<String>,
+    /// Per-session override of `sandbox.default_terminal_mode`, set by the
+    /// home view's container/host toggle. `None` follows the config default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_mode: Option<super::config::DefaultTerminalMode>,
 
     // Paired terminal session
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -506,6 +556,12 @@ pub struct Instance {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub base_branch_override: Option<String>,
 
+    /// Free-text note describing what the session is for ("investigating
+    /// flaky test X"). Shown in the TUI preview header and matched by `/`
+    /// search alongside the title and path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
     /// How this session is rendered: `Structured` (ACP native rendering) or
     /// `Terminal` (raw tmux pane). When `Structured`, aoe spawns an ACP agent
     /// subprocess and renders structured events natively; tmux integration is
@@ -785,11 +841,16 @@ impl Instance {
             favorited_at: None,
             snoozed_until: None,
             idle_dormant_since: None,
+            container_idle_stopped_at: None,
             pinned_at: None,
             scratch: false,
+            auto_create_terminal: false,
             worktree_info: None,
+            worktree_locked: false,
             workspace_info: None,
             sandbox_info: None,
+            host_instruction: None,
+            terminal_mode: None,
             terminal_info: None,
             agent_session_id: None,
             resume_intent: ResumeIntent::Default,
@@ -798,6 +859,7 @@ impl Instance {
             notify_on_idle: None,
             notify_on_error: None,
             base_branch_override: None,
+            notes: None,
             #[cfg(feature = "serve")]
             view: View::Terminal,
             #[cfg(feature = "serve")]
@@ -1009,6 +1071,7 @@ impl Instance {
         self.status = src.status;
         self.last_accessed_at = self.last_accessed_at.max(src.last_accessed_at);
         self.idle_entered_at = src.idle_entered_at;
+        self.container_idle_stopped_at = src.container_idle_stopped_at;
     }
 
     /// Per-field-conditional splice: copy `post.X` onto `self.X` only when
@@ -1045,6 +1108,18 @@ impl Instance {
         if pre.base_branch_override != post.base_branch_override {
             self.base_branch_override = post.base_branch_override.clone();
         }
+        if pre.notes != post.notes {
+            self.notes = post.notes.clone();
+        }
+        if pre.worktree_locked != post.worktree_locked {
+            self.worktree_locked = post.worktree_locked;
+        }
+        if pre.yolo_mode != post.yolo_mode {
+            self.yolo_mode = post.yolo_mode;
+        }
+        if pre.terminal_mode != post.terminal_mode {
+            self.terminal_mode = post.terminal_mode;
+        }
         // Worktree workdir edit (move dir / rename branch) mutates these two;
         // both the TUI and the CLI can write them, so they go through the
         // same conditional-diff path as the triage fields. See #1723.
@@ -1054,6 +1129,11 @@ impl Instance {
         if pre.worktree_info != post.worktree_info {
             self.worktree_info = post.worktree_info.clone();
         }
+        // A container rebuild runs on a copy of the instance and hands back
+        // the recreated sandbox (fresh container id).
+        if pre.sandbox_info != post.sandbox_info {
+            self.sandbox_info = post.sandbox_info.clone();
+        }
         if pre.status != post.status {
             self.status = post.status;
         }
@@ -1277,6 +1357,13 @@ impl Instance {
         self.workspace_info.is_some()
     }
 
+    /// The agent's tmux session vanished with no more specific error (killed
+    /// outside aoe, tmux server restart). Recoverable by starting the session
+    /// again, so the TUI shows it as stopped rather than crashed.
+    pub fn is_tmux_gone(&self) -> bool {
+        self.last_error.as_deref() == Some(TMUX_SESSION_GONE_ERROR)
+    }
+
     pub fn is_sandboxed(&self) -> bool {
         self.sandbox_info.as_ref().is_some_and(|s| s.enabled)
     }
@@ -1548,6 +1635,20 @@ impl Instance {
         // Apply all configured tmux options to terminal sessions too
         if is_new {
             self.apply_terminal_tmux_options();
+            let tmux_config =
+                super::profile_config::resolve_config_or_warn(&self.effective_profile()).tmux;
+            let line = terminal_startup_line(
+                tmux_config.terminal_startup_command.as_deref(),
+                &self.project_path,
+            );
+            if let Err(e) = session.apply_startup_layout(
+                tmux_config.terminal_split,
+                line.as_deref(),
+                &self.project_path,
+                None,
+            ) {
+                tracing::warn!("Failed to apply terminal startup layout: {e}");
+            }
         }
 
         self.terminal_info = Some(TerminalInfo { created: true });
@@ -1555,6 +1656,21 @@ impl Instance {
         Ok(())
     }
 
+    /// Open the terminal that matches the session: the container terminal
+    /// for sandboxed sessions, the host terminal otherwise. Failures are
+    /// logged, not returned; the agent is already running and the user can
+    /// still open the terminal by hand.
+    fn auto_start_terminal(&mut self, size: Option<(u16, u16)>) {
+        let result = if self.is_sandboxed() {
+            self.start_container_terminal_with_size(size)
+        } else {
+            self.start_terminal_with_size(size)
+        };
+        if let Err(e) = result {
+            tracing::warn!(target: "session.store", "auto_create_terminal: {e}");
+        }
+    }
+
     pub fn kill_terminal(&self) -> Result<()> {
         let session = self.terminal_tmux_session()?;
         if session.exists() {
@@ -1599,7 +1715,7 @@ impl Instance {
             anyhow::bail!("Cannot create container terminal for non-sandboxed session");
         }
 
-        let container = self.get_container_for_instance()?;
+        let container = self.container_ready_for_exec()?;
         let sandbox = self
             .sandbox_info
             .as_ref()
@@ -1639,11 +1755,55 @@ impl Instance {
         if is_new {
             session.create_with_size(&self.project_path, Some(&session_cmd), size)?;
             self.apply_container_terminal_tmux_options();
+            let tmux_config =
+                super::profile_config::resolve_config_or_warn(&self.effective_profile()).tmux;
+            let line = terminal_startup_line(
+                tmux_config.terminal_startup_command.as_deref(),
+                &container_workdir,
+            );
+            if let Err(e) = session.apply_startup_layout(
+                tmux_config.terminal_split,
+                line.as_deref(),
+                &self.project_path,
+                Some(&session_cmd),
+            ) {
+                tracing::warn!("Failed to apply container terminal startup layout: {e}");
+            }
         }
 
         Ok(())
     }
 
+    /// Pre-flight for the container terminal: the runtime must be reachable,
+    /// the container running (started if it was stopped), and a trivial
+    /// `exec` must succeed. Without this a failed `docker exec` only shows up
+    /// as a tmux pane that dies the moment it opens.
+    fn container_ready_for_exec(&mut self) -> Result<DockerContainer> {
+        let runtime = containers::get_container_runtime();
+        if !runtime.is_available() {
+            anyhow::bail!("Container runtime is not installed; cannot open a container terminal");
+        }
+        if !runtime.is_daemon_running() {
+            anyhow::bail!(
+                "Container runtime daemon is not running; cannot open a container terminal"
+            );
+        }
+
+        let container = self.get_container_for_instance()?;
+        if !container.is_running()? {
+            anyhow::bail!("Container {} is not running", container.name);
+        }
+        let probe = container.exec(&["true"])?;
+        if !probe.status.success() {
+            anyhow::bail!(
+                "Cannot exec into container {}: {}",
+                container.name,
+                String::from_utf8_lossy(&probe.stderr).trim()
+            );
+        }
+        Ok(container)
+    }
+
     pub fn kill_container_terminal(&self) -> Result<()> {
         let session = self.container_terminal_tmux_session()?;
         if session.exists() {
@@ -1733,6 +1893,37 @@ impl Instance {
             return Ok(LaunchSidOutcome::Skipped);
         }
 
+        let launch = self.prepare_launch(skip_on_launch)?;
+        session.create_with_exit(
+            &self.project_path,
+            launch.cmd.as_deref(),
+            size,
+            launch.on_exit != AgentExitBehavior::Close,
+        )?;
+
+        self.finalize_launch(
+            session.name(),
+            &launch.profile,
+            launch.expected_prior_sid.as_deref(),
+            launch.expected_prior_intent,
+        );
+
+        if self.auto_create_terminal {
+            self.auto_start_terminal(size);
+        }
+
+        Ok(if launch.is_existing {
+            LaunchSidOutcome::Existing
+        } else {
+            LaunchSidOutcome::Fresh
+        })
+    }
+
+    /// Everything a launch needs before touching tmux, shared by
+    /// `start_with_size_opts` (new session) and `restart_agent_in_place`
+    /// (respawned pane): reconciles persisted state, builds the agent
+    /// command, and wraps it for the configured `on_agent_exit`.
+    fn prepare_launch(&mut self, skip_on_launch: bool) -> Result<PreparedLaunch> {
         // Refresh peer-writable persisted fields (`agent_session_id`,
         // `resume_intent`) from disk before the launch decision. Closes the
         // status-poll lag window for both the read side
@@ -1765,19 +1956,21 @@ impl Instance {
             }
         }
 
-        session.create_with_size(&self.project_path, cmd.as_deref(), size)?;
+        let on_exit = super::profile_config::resolve_config_or_warn(&profile)
+            .session
+            .on_agent_exit;
+        let cmd = match on_exit {
+            AgentExitBehavior::Respawn => cmd.map(|c| wrap_command_respawn(&c)),
+            AgentExitBehavior::Hold | AgentExitBehavior::Close => cmd,
+        };
 
-        self.finalize_launch(
-            session.name(),
-            &profile,
-            expected_prior_sid.as_deref(),
+        Ok(PreparedLaunch {
+            cmd,
+            is_existing,
+            on_exit,
+            profile,
+            expected_prior_sid,
             expected_prior_intent,
-        );
-
-        Ok(if is_existing {
-            LaunchSidOutcome::Existing
-        } else {
-            LaunchSidOutcome::Fresh
         })
     }
 
@@ -1835,18 +2028,7 @@ impl Instance {
             } else {
                 base_cmd
             };
-            if let Some(instruction) = self
-                .sandbox_info
-                .as_ref()
-                .and_then(|s| s.custom_instruction.as_ref())
-                .filter(|s| !s.is_empty())
-            {
-                if let Some(flag_template) = agent.and_then(|a| a.instruction_flag) {
-                    let escaped = shell_escape(instruction);
-                    let flag = flag_template.replace("{}", &escaped);
-                    tool_cmd = format!("{} {}", tool_cmd, flag);
-                }
-            }
+            self.apply_instruction(&mut tool_cmd, agent);
 
             let is_existing = self.apply_session_flags(&mut tool_cmd, "sandboxed");
             apply_agent_launch_env(&mut tool_cmd, agent);
@@ -1929,7 +2111,7 @@ impl Instance {
             // build_container_config. host_only agents (settl) are never
             // sandboxed, so the gate is a no-op for them.
             if !self.is_sandboxed() {
-                if let Some(home) = dirs::home_dir() {
+                if let Ok(home) = super::home_dir() {
                     let config_path = home.join(sidecar.host_config_subpath);
                     match (sidecar.install)(&config_path) {
                         Ok(()) => {
@@ -2029,6 +2211,11 @@ impl Instance {
             );
         }
 
+        let launch_wrapper =
+            super::profile_config::resolve_config_or_warn(&self.effective_profile())
+                .session
+                .launch_wrapper;
+
         if self.command.is_empty() {
             match crate::agents::get_agent(&self.tool) {
                 Some(a) => {
@@ -2041,8 +2228,10 @@ impl Instance {
                             apply_yolo_mode(&mut cmd, yolo, false);
                         }
                     }
+                    self.apply_instruction(&mut cmd, agent);
                     let is_existing = self.apply_session_flags(&mut cmd, "host agent");
                     apply_agent_launch_env(&mut cmd, agent);
+                    apply_launch_wrapper(&mut cmd, launch_wrapper.as_deref());
                     (
                         Some(wrap_command_ignore_suspend(&format!(
                             "{}{}",
@@ -2063,8 +2252,10 @@ impl Instance {
                     apply_yolo_mode(&mut cmd, yolo, false);
                 }
             }
+            self.apply_instruction(&mut cmd, agent);
             let is_existing = self.apply_session_flags(&mut cmd, "host custom");
             apply_agent_launch_env(&mut cmd, agent);
+            apply_launch_wrapper(&mut cmd, launch_wrapper.as_deref());
             (
                 Some(wrap_command_ignore_suspend(&format!(
                     "{}{}",
@@ -2075,6 +2266,28 @@ impl Instance {
         }
     }
 
+    /// The session's initial instruction: the sandbox's custom instruction,
+    /// or the host instruction for an unsandboxed session.
+    fn custom_instruction(&self) -> Option<&str> {
+        match &self.sandbox_info {
+            Some(sandbox) => sandbox.custom_instruction.as_deref(),
+            None => self.host_instruction.as_deref(),
+        }
+        .filter(|s| !s.is_empty())
+    }
+
+    /// Append the agent's instruction flag carrying [`Self::custom_instruction`].
+    /// Agents without an instruction flag ignore it.
+    fn apply_instruction(&self, cmd: &mut String, agent: Option<&crate::agents::AgentDef>) {
+        let Some(instruction) = self.custom_instruction() else {
+            return;
+        };
+        if let Some(flag_template) = agent.and_then(|a| a.instruction_flag) {
+            let flag = flag_template.replace("{}", &shell_escape(instruction));
+            *cmd = format!("{} {}", cmd, flag);
+        }
+    }
+
     /// Post-launch setup: persist state, start pollers, and apply tmux options.
     fn finalize_launch(
         &mut self,
@@ -2411,23 +2624,29 @@ impl Instance {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Cannot ensure container for non-sandboxed session"))?;
 
-        let image = &sandbox.image;
-        let container = DockerContainer::new(&self.id, image);
+        let image = sandbox.image.clone();
+        let container = DockerContainer::new(&self.id, &image);
 
         if container.is_running()? {
-            container_config::refresh_agent_configs();
+            container_config::refresh_agent_configs(&self.effective_profile());
             return Ok(container);
         }
 
         if container.exists()? {
-            container_config::refresh_agent_configs();
+            container_config::refresh_agent_configs(&self.effective_profile());
             container.start()?;
+            activity::record(ActivityKind::ContainerStarted, self, "");
             return Ok(container);
         }
 
         // Ensure image is available (always pulls to get latest)
         let runtime = containers::get_container_runtime();
-        runtime.ensure_image(image)?;
+        runtime.ensure_image(&image)?;
+
+        let container = match self.pin_sandbox_image(&runtime) {
+            Some(pinned) => DockerContainer::new(&self.id, &pinned),
+            None => container,
+        };
 
         let config = self.build_container_config()?;
         let container_id = container.create(&config)?;
@@ -2435,10 +2654,39 @@ impl Instance {
         if let Some(ref mut sandbox) = self.sandbox_info {
             sandbox.container_id = Some(container_id);
         }
+        activity::record(
+            ActivityKind::ContainerCreated,
+            self,
+            container.name.as_str(),
+        );
 
         Ok(container)
     }
 
+    /// With `sandbox.pin_image_digest` on, rewrite the session's image to
+    /// `tag@sha256:...` from the local copy just pulled, so later rebuilds of
+    /// this container use the same image. Returns the pinned reference when it
+    /// changed. Images without a repo digest (locally built, or a runtime
+    /// that can't report one) stay on the tag.
+    fn pin_sandbox_image(&mut self, runtime: &containers::ContainerRuntime) -> Option<String> {
+        let image = self.sandbox_info.as_ref()?.image.clone();
+        if image.contains('@')
+            || !container_config::pin_image_digest_enabled(&self.project_path, &self.source_profile)
+        {
+            return None;
+        }
+        let Some(digest) = runtime.local_image_digest(&image) else {
+            tracing::warn!(target: "containers.image", %image, "no repo digest available; image not pinned");
+            return None;
+        };
+        let pinned = containers::image_update::pinned_image_ref(&image, &digest);
+        tracing::info!(target: "containers.image", %pinned, "pinned sandbox image digest");
+        if let Some(sandbox) = self.sandbox_info.as_mut() {
+            sandbox.image = pinned.clone();
+        }
+        Some(pinned)
+    }
+
     /// Get the container working directory for this instance.
     pub fn container_workdir(&self) -> String {
         container_config::compute_volume_paths(Path::new(&self.project_path), &self.project_path)
@@ -2723,6 +2971,43 @@ impl Instance {
         self.start_with_resume_fallback(size, skip_on_launch)
     }
 
+    /// Relaunch the agent inside the existing tmux pane without tearing
+    /// down the session, worktree, or container. The command is rebuilt
+    /// from the instance's current tool, extra args, YOLO mode, and
+    /// instruction, so edits made since the last launch take effect.
+    /// on_launch hooks do not run again. Falls back to a normal start when
+    /// the tmux session is gone.
+    pub fn restart_agent_in_place(&mut self, size: Option<(u16, u16)>) -> Result<()> {
+        crate::session::validate_instance_id(&self.id)
+            .context("refusing to launch: AOE_INSTANCE_ID failed validation")?;
+
+        #[cfg(feature = "serve")]
+        if self.is_structured() {
+            anyhow::bail!("Structured view sessions have no terminal pane to restart");
+        }
+
+        let session = self.tmux_session()?;
+        if !session.exists() {
+            return self.start_with_size_opts(size, true).map(|_| ());
+        }
+
+        self.stop_poller();
+        self.session_id_poller = None;
+        let launch = self.prepare_launch(true)?;
+
+        session.respawn_pane(&self.project_path, launch.cmd.as_deref())?;
+        self.last_error = None;
+        self.last_error_check = None;
+        self.container_idle_stopped_at = None;
+        self.finalize_launch(
+            session.name(),
+            &launch.profile,
+            launch.expected_prior_sid.as_deref(),
+            launch.expected_prior_intent,
+        );
+        Ok(())
+    }
+
     /// Settle-based pane probe used by the resume-fallback cascade.
     ///
     /// Returns `Dead` immediately if the pane dies or the session evaporates
@@ -2832,6 +3117,9 @@ impl Instance {
             self.last_error = None;
             self.last_error_check = None;
         }
+        // The launch below starts the container again if the idle container
+        // reaper stopped it.
+        self.container_idle_stopped_at = None;
 
         #[cfg(feature = "serve")]
         if self.is_structured() {
@@ -3196,19 +3484,88 @@ impl Instance {
 
     /// Stop the session: kill the tmux session and stop the Docker container
     /// (if sandboxed). The container is stopped but not removed, so it can be
-    /// restarted on re-attach.
+    /// restarted on re-attach. The container terminal, which execs into the
+    /// container, is killed with it; the host terminal is kept.
     pub fn stop(&self) -> Result<()> {
+        let was_running = self.tmux_session().is_ok_and(|s| s.exists());
         self.kill()?;
 
+        let mut container_stopped = false;
         if self.is_sandboxed() {
+            if let Err(e) = self.kill_container_terminal() {
+                tracing::debug!(
+                    target: "session.tmux_cleanup",
+                    session_id = %self.id,
+                    kind = "container_terminal",
+                    error = %e,
+                    "stop: kill failed"
+                );
+            }
             let container = containers::DockerContainer::from_session_id(&self.id);
             if container.is_running().unwrap_or(false) {
                 container.stop()?;
+                container_stopped = true;
             }
         }
 
         crate::hooks::cleanup_hook_status_dir(&self.id);
 
+        // Stopping an already-stopped session is a no-op; don't log it.
+        if was_running || container_stopped {
+            let detail = if container_stopped {
+                "container stopped"
+            } else {
+                ""
+            };
+            activity::record(ActivityKind::SessionStopped, self, detail);
+        }
+
+        Ok(())
+    }
+
+    /// Stop only the sandbox container, without touching the session's tmux
+    /// panes or its status. Used by the idle container reaper, which parks
+    /// the container of a session that has sat idle; the agent pane exits
+    /// with the container and the next attach starts it again.
+    pub fn stop_container(&self) -> Result<()> {
+        if !self.is_sandboxed() {
+            anyhow::bail!("Cannot stop container for non-sandboxed session");
+        }
+        let container = containers::DockerContainer::from_session_id(&self.id);
+        if container.is_running().unwrap_or(false) {
+            container.stop()?;
+            activity::record(ActivityKind::ContainerStopped, self, "");
+        }
+        Ok(())
+    }
+
+    /// Rebuild the sandbox container from the current configuration: kill the
+    /// session's tmux panes, force-remove the existing container, re-sync the
+    /// agent config dirs, and create a fresh container via
+    /// `build_container_config`. The session record and worktree are left
+    /// untouched; anything written inside the old container outside the
+    /// mounted sandbox dirs is lost.
+    pub fn rebuild_container(&mut self) -> Result<()> {
+        if !self.is_sandboxed() {
+            anyhow::bail!("Cannot rebuild container for non-sandboxed session");
+        }
+
+        self.kill_all_tmux_sessions();
+
+        let container = containers::DockerContainer::from_session_id(&self.id);
+        if container.exists()? {
+            container.remove(true)?;
+        }
+        if let Some(ref mut sandbox) = self.sandbox_info {
+            sandbox.container_id = None;
+        }
+
+        container_config::refresh_agent_configs(&self.effective_profile());
+        self.get_container_for_instance()?;
+        activity::record(ActivityKind::ContainerRebuilt, self, "");
+
+        crate::hooks::cleanup_hook_status_dir(&self.id);
+
         Ok(())
     }
 
@@ -3236,6 +3593,12 @@ impl Instance {
             return;
         }
 
+        // The idle container reaper stopped the container on purpose; the
+        // dead agent pane is expected until the next attach relaunches it.
+        if self.container_idle_stopped_at.is_some() {
+            return;
+        }
+
         // Acp-mode sessions are not backed by a tmux pane; the structured view
         // worker supervisor owns their lifecycle and emits typed health
         // events over the broadcast. Probing tmux here only ever produces
@@ -3287,7 +3650,9 @@ impl Instance {
             }
         };
 
-        if !session.exists() {
+        // Pane metadata only lists live sessions, so its presence already
+        // answers existence without consulting tmux again.
+        if metadata.is_none() && !session.exists() {
             tracing::trace!(target: "session.store",
                 "status '{}': session.exists()=false (tmux name={}), setting Error",
                 self.title,
@@ -3375,7 +3740,11 @@ impl Instance {
         }
 
         let pane_content = session.capture_pane(50).unwrap_or_default();
-        let detected = tmux::detect_status_from_content(&pane_content, detection_tool);
+        let detected = tmux::detect_status_for_profile(
+            &pane_content,
+            detection_tool,
+            &self.effective_profile(),
+        );
         tracing::trace!(target: "session.store",
             "status '{}': detected={:?}, cmd_override={}, custom_cmd={}",
             self.title,
@@ -3502,6 +3871,14 @@ fn truncate_error_line(line: &str) -> String {
     }
 }
 
+/// The line typed into a new paired terminal for `tmux.terminal_startup_command`.
+/// It `cd`s into the (escaped) `workdir` first so a shell rc that changes
+/// directory can't move the command elsewhere. `None` when no command is set.
+fn terminal_startup_line(command: Option<&str>, workdir: &str) -> Option<String> {
+    let command = command.map(str::trim).filter(|c| !c.is_empty())?;
+    Some(format!("cd {} && {}", shell_escape(workdir), command))
+}
+
 /// Format an environment variable assignment as a shell-safe command prefix.
 ///
 /// Uses `shell_escape` (single-quote escaping) so the value is preserved
@@ -3530,6 +3907,28 @@ fn apply_agent_launch_env(cmd: &mut String, agent: Option<&'static crate::agents
     );
 }
 
+/// Apply `session.launch_wrapper` to a host launch command, substituting
+/// `sh -c '<cmd>'` for `{cmd}` (e.g. `nice -n 10 {cmd}`). The command goes in
+/// as one quoted argument, like `wrap_command_respawn` passes it, so a
+/// wrapper applies to all of it rather than to the first word of a
+/// compound command such as `cd x && claude`. The env prefix is kept outside
+/// so `env KEY=v nice ... agent` still hands the variables down. A template
+/// without `{cmd}` would silently drop the agent, so it is ignored with a
+/// warning instead.
+fn apply_launch_wrapper(cmd: &mut String, template: Option<&str>) {
+    let Some(template) = template.map(str::trim).filter(|t| !t.is_empty()) else {
+        return;
+    };
+    if super::profile_config::validate_launch_wrapper(template).is_err() {
+        tracing::warn!(target: "session.store",
+            "launch_wrapper {:?} has no {{cmd}} placeholder; launching without it",
+            template
+        );
+        return;
+    }
+    *cmd = template.replace("{cmd}", &format!("sh -c {}", shell_escape(cmd)));
+}
+
 /// Wrap a command to disable Ctrl-Z (SIGTSTP) suspension.
 ///
 /// Command run inside the sandbox container for the web Container terminal tab.
@@ -3580,6 +3979,24 @@ fn wrap_command_ignore_suspend(cmd: &str) -> String {
     )
 }
 
+/// Wrap a launch command so the agent is relaunched in the same pane when it
+/// exits (`session.on_agent_exit = "respawn"`).
+///
+/// The loop is `aoe __respawn-agent` (see `cli::respawn_agent`) rather than a
+/// shell loop, so the pane's command never reads as a shell to the
+/// attach-time restart check. The leading `exec` replaces the tmux default
+/// shell, as in `wrap_command_ignore_suspend`.
+fn wrap_command_respawn(cmd: &str) -> String {
+    let exe = std::env::current_exe()
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "aoe".to_string());
+    format!(
+        "exec {} __respawn-agent {}",
+        shell_escape(&exe),
+        shell_escape(cmd)
+    )
+}
+
 /// Prepend shell `export` statements to an already-wrapped sandbox command.
 ///
 /// `wrapped` MUST be the output of `wrap_command_ignore_suspend`, which
@@ -4624,6 +5041,72 @@ mod tests {
         assert_eq!(prepend_exports(&[], wrapped2.clone()), wrapped2);
     }
 
+    #[test]
+    fn test_terminal_startup_line_escapes_workdir() {
+        assert_eq!(
+            terminal_startup_line(Some("git status"), "/tmp/my repo"),
+            Some("cd '/tmp/my repo' && git status".to_string())
+        );
+        assert_eq!(
+            terminal_startup_line(Some("  ls  "), "/workspace/it's"),
+            Some("cd '/workspace/it'\\''s' && ls".to_string())
+        );
+    }
+
+    #[test]
+    fn test_terminal_startup_line_none_when_unset_or_blank() {
+        assert_eq!(terminal_startup_line(None, "/tmp"), None);
+        assert_eq!(terminal_startup_line(Some("   "), "/tmp"), None);
+    }
+
+    #[test]
+    fn test_apply_launch_wrapper_substitutes_cmd() {
+        let mut cmd = "claude --resume abc".to_string();
+        apply_launch_wrapper(&mut cmd, Some("nice -n 10 {cmd}"));
+        assert_eq!(cmd, "nice -n 10 sh -c 'claude --resume abc'");
+
+        // Unset, blank, and placeholder-less templates leave the command alone.
+        for template in [None, Some("  "), Some("nice -n 10")] {
+            let mut cmd = "claude".to_string();
+            apply_launch_wrapper(&mut cmd, template);
+            assert_eq!(cmd, "claude");
+        }
+    }
+
+    #[test]
+    fn test_apply_launch_wrapper_wraps_compound_command_as_a_whole() {
+        // Unquoted, `nice -n 10 cd / && ...` would hand `cd` to nice and run
+        // the rest outside the wrapper; quoted, the whole line runs under it
+        // with its quotes intact.
+        let mut cmd = r#"cd / && printf '%s\n' "a b" 'it'\''s' "$(pwd)""#.to_string();
+        apply_launch_wrapper(&mut cmd, Some("nice -n 10 {cmd}"));
+        let output = std::process::Command::new("sh")
+            .args(["-c", &cmd])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{cmd} failed");
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "a b\nit's\n/\n");
+    }
+
+    #[test]
+    fn test_wrap_command_respawn_passes_command_as_one_argument() {
+        // Swap the leading `exec` for `printf` to see the argv the tmux
+        // shell hands to the wrapper: the binary, the subcommand, and the
+        // agent command intact, quotes included.
+        let cmd = r"exec env claude --append-system-prompt 'it'\''s'";
+        let wrapped = wrap_command_respawn(cmd);
+        let args = wrapped.strip_prefix("exec ").unwrap();
+        let output = std::process::Command::new("sh")
+            .args(["-c", &format!("printf '%s\\n' {args}")])
+            .output()
+            .unwrap();
+        let exe = std::env::current_exe().unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            format!("{}\n__respawn-agent\n{cmd}\n", exe.display())
+        );
+    }
+
     #[test]
     #[serial_test::serial(shell_env)]
     fn test_wrap_command_starts_with_exec() {
@@ -4725,6 +5208,8 @@ mod tests {
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         });
         assert!(!inst.is_sandboxed());
     }
@@ -4739,6 +5224,8 @@ mod tests {
             container_name: "test".to_string(),
             extra_env: None,
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         });
         assert!(inst.is_sandboxed());
     }
@@ -4844,6 +5331,8 @@ mod tests {
             container_name: "test_container".to_string(),
             extra_env: Some(vec!["MY_VAR".to_string(), "OTHER_VAR".to_string()]),
             custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -5377,6 +5866,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_host_command_with_instruction() {
+        let mut inst = Instance::new("test", "/tmp/test");
+        inst.tool = "claude".to_string();
+        inst.host_instruction = Some("fix the flaky test".to_string());
+        let (cmd, _) = inst.build_host_command(crate::agents::get_agent("claude"), &None);
+        let cmd = cmd.unwrap();
+        // The instruction is shell-quoted, then quoted again by the
+        // `bash -lc` wrapper.
+        assert!(
+            cmd.contains(r"--append-system-prompt '\''fix the flaky test'\''"),
+            "{cmd}"
+        );
+    }
+
     #[test]
     fn test_build_host_command_with_resume() {
         let mut inst = Instance::new("test", "/tmp/test");
@@ -1,5 +1,7 @@
 //! Session management module
 
+pub mod activity;
+pub mod add_command;
 pub mod builder;
 pub(crate) mod capture;
 pub mod civilizations;
@@ -24,23 +26,27 @@ pub(crate) mod serde_helpers;
 pub mod settings_schema;
 pub mod stop;
 mod storage;
+pub mod worktree_convert;
 pub mod worktree_edit;
+pub mod worktree_lock;
 
 pub use crate::sound::SoundConfig;
 pub use crate::status_hooks::StatusHookConfig;
 pub(crate) use capture::is_valid_session_id;
 pub use config::{
-    get_telemetry_settings, get_update_settings, load_config, save_config,
-    validate_snooze_duration, ClickAction, Config, ContainerRuntimeName, DefaultTerminalMode,
-    GroupByMode, NewSessionAttachMode, RowTagMode, SandboxConfig, SessionConfig, TelemetryConfig,
-    ThemeConfig, TmuxClipboardMode, TmuxMouseMode, TmuxStatusBarMode, UpdatesConfig,
-    VolumeIgnoresStrategy, WorktreeConfig,
+    get_cache_dir, get_telemetry_settings, get_update_settings, load_config, save_config,
+    validate_snooze_duration, BranchCleanup, ClickAction, Config, ContainerRuntimeName,
+    DefaultTerminalMode, GroupByMode, NewSessionAttachMode, RestartPolicy, RowTagMode,
+    SandboxConfig, SessionConfig, TelemetryConfig, ThemeConfig, TmuxClipboardMode, TmuxMouseMode,
+    TmuxStatusBarMode, UpdatesConfig, VolumeIgnoresStrategy, WorktreeConfig,
 };
 pub(crate) use environment::user_shell;
-pub use environment::{validate_env_entries, validate_env_entry};
+pub use environment::{
+    is_valid_env_name, preview_env_entry, validate_env_entries, validate_env_entry, EnvEntryPreview,
+};
 pub use groups::{
     append_archived_section, append_archived_section_by_project, archived_project_sub_path,
-    flatten_sessions_by_attention, flatten_tree, flatten_tree_all_profiles,
+    flatten_sessions_by_attention, flatten_tree, flatten_tree_all_profiles, hash_flatten_inputs,
     is_archived_section_path, is_within_archived_section, Group, GroupTree, Item,
     ARCHIVED_SECTION_NAME, ARCHIVED_SECTION_PATH,
 };
@@ -51,8 +57,11 @@ pub use instance::{
     TMUX_SESSION_GONE_ERROR,
 };
 pub use profile_config::{
-    load_profile_config, merge_configs, resolve_config, resolve_config_or_warn,
-    save_profile_config, validate_check_interval, validate_env_format, validate_memory_limit,
+    load_profile_config, merge_configs, parse_agent_container_env, resolve_config,
+    resolve_config_or_warn, save_profile_config, validate_check_interval,
+    validate_config_sync_exclude, validate_cpu_limit, validate_cpu_limit_for_runtime,
+    validate_datetime_format, validate_display_timezone, validate_env_format,
+    validate_launch_wrapper, validate_memory_limit, validate_memory_limit_for_runtime,
     validate_port_mapping_format, validate_volume_format, ProfileConfig,
 };
 pub use projects::{Project, ProjectScope};
@@ -160,6 +169,60 @@ fn resolve_app_dir_with_fallback(xdg: PathBuf, legacy: PathBuf, xdg_env_set: boo
     }
 }
 
+/// Env var that relocates aoe's config files: the global `config.toml`,
+/// per-profile `config.toml`, `themes/`, and `sounds/`. When
+/// [`DATA_DIR_ENV`] is unset it relocates the data dir too, so one variable
+/// moves everything (e.g. a portable install on a USB stick).
+pub const CONFIG_DIR_ENV: &str = "AOE_CONFIG_DIR";
+
+/// Env var that relocates the app data dir returned by [`get_app_dir`]:
+/// sessions, groups, logs, scratch dirs, and the rest of aoe's state.
+pub const DATA_DIR_ENV: &str = "AOE_DATA_DIR";
+
+/// Read a directory override from `var`. Unset or empty means no override;
+/// a relative path is an error rather than something resolved against
+/// whatever directory aoe happened to start in.
+fn dir_override(var: &str) -> Result<Option<PathBuf>> {
+    let Some(value) = std::env::var_os(var).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    let dir = PathBuf::from(value);
+    if !dir.is_absolute() {
+        anyhow::bail!("{var} must be an absolute path, got {}", dir.display());
+    }
+    Ok(Some(dir))
+}
+
+/// Whether either directory override is set. The platform-layout checks
+/// (dev/release drift, migration discovery) only apply without one.
+pub(crate) fn dir_override_set() -> bool {
+    [CONFIG_DIR_ENV, DATA_DIR_ENV]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Env var that overrides the home directory aoe resolves agent configs and
+/// sandbox mounts against. Useful when `HOME` is unset or points somewhere
+/// other than the user's real home (some service managers and CI runners).
+pub const HOME_DIR_ENV: &str = "AOE_HOME";
+
+/// The user's home directory: [`HOME_DIR_ENV`] when set, otherwise `HOME`
+/// (via `dirs::home_dir`, which also consults the passwd entry on Unix and
+/// `USERPROFILE` on Windows). Errors instead of returning `None` so callers
+/// building something from home-relative paths fail with an actionable
+/// message rather than quietly skipping half of it.
+pub(crate) fn home_dir() -> Result<PathBuf> {
+    if let Some(dir) = dir_override(HOME_DIR_ENV)? {
+        return Ok(dir);
+    }
+    dirs::home_dir()
+        .filter(|dir| dir.is_absolute())
+        .ok_or_else(|| anyhow::anyhow!("could not determine home directory; set HOME"))
+}
+
+/// The app data dir. Every path under aoe's state goes through here (or
+/// [`get_config_dir`] for config files), so the env overrides apply
+/// everywhere.
 pub fn get_app_dir() -> Result<PathBuf> {
     let dir = get_app_dir_path()?;
     if !dir.exists() {
@@ -168,6 +231,17 @@ pub fn get_app_dir() -> Result<PathBuf> {
     Ok(dir)
 }
 
+/// The directory holding the global `config.toml` and per-profile config
+/// files. Same as [`get_app_dir`] unless `AOE_CONFIG_DIR` / `AOE_DATA_DIR`
+/// split them.
+pub fn get_config_dir() -> Result<PathBuf> {
+    let dir = get_config_dir_path()?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
 /// Whether the app data dir already exists, **without** creating it (unlike
 /// [`get_app_dir`], which auto-creates). Lets side-effect-sensitive callers
 /// probe install state cheaply: the per-command telemetry recorder uses it to
@@ -178,7 +252,22 @@ pub fn app_dir_exists() -> bool {
     get_app_dir_path().map(|p| p.exists()).unwrap_or(false)
 }
 
-fn get_app_dir_path() -> Result<PathBuf> {
+pub(crate) fn get_app_dir_path() -> Result<PathBuf> {
+    match dir_override(DATA_DIR_ENV)? {
+        Some(dir) => Ok(dir),
+        None => get_config_dir_path(),
+    }
+}
+
+pub(crate) fn get_config_dir_path() -> Result<PathBuf> {
+    match dir_override(CONFIG_DIR_ENV)? {
+        Some(dir) => Ok(dir),
+        None => platform_app_dir(),
+    }
+}
+
+/// The platform default app dir, used when no env override is set.
+fn platform_app_dir() -> Result<PathBuf> {
     #[cfg(target_os = "linux")]
     let dir = xdg_config_base()?.join(APP_DIR_NAME_XDG);
 
@@ -208,7 +297,7 @@ fn get_app_dir_path() -> Result<PathBuf> {
 /// when the release dir is absent or empty (user has no prior state to
 /// "lose visibility of"), or when the dev dir already exists.
 pub fn debug_namespace_drift() -> Option<(PathBuf, PathBuf)> {
-    if !cfg!(debug_assertions) {
+    if !cfg!(debug_assertions) || dir_override_set() {
         return None;
     }
 
@@ -258,16 +347,18 @@ pub fn format_debug_namespace_warning(release: &Path, dev: &Path) -> String {
     )
 }
 
+/// `profile`, or the default profile when it is empty.
+fn resolve_profile_name(profile: &str) -> std::borrow::Cow<'_, str> {
+    if profile.is_empty() {
+        config::resolve_default_profile().into()
+    } else {
+        profile.into()
+    }
+}
+
 pub fn get_profile_dir(profile: &str) -> Result<PathBuf> {
     let base = get_app_dir()?;
-    let resolved;
-    let profile_name = if profile.is_empty() {
-        resolved = config::resolve_default_profile();
-        resolved.as_str()
-    } else {
-        profile
-    };
-    let dir = base.join("profiles").join(profile_name);
+    let dir = base.join("profiles").join(&*resolve_profile_name(profile));
     if !dir.exists() {
         fs::create_dir_all(&dir)?;
     }
@@ -290,15 +381,30 @@ pub fn get_profile_dir(profile: &str) -> Result<PathBuf> {
 /// effect on a genuine first run; callers that want to avoid that should
 /// pass an explicit non-empty name.
 pub fn get_profile_dir_path(profile: &str) -> Result<PathBuf> {
-    let base = get_app_dir()?;
-    let resolved;
-    let profile_name = if profile.is_empty() {
-        resolved = config::resolve_default_profile();
-        resolved.as_str()
+    Ok(get_app_dir()?
+        .join("profiles")
+        .join(&*resolve_profile_name(profile)))
+}
+
+/// The directory holding `profile`'s `config.toml`, WITHOUT creating it.
+/// Same as [`get_profile_dir_path`] unless the config and data dirs are
+/// split (see [`CONFIG_DIR_ENV`]).
+pub fn get_profile_config_dir_path(profile: &str) -> Result<PathBuf> {
+    Ok(get_config_dir()?
+        .join("profiles")
+        .join(&*resolve_profile_name(profile)))
+}
+
+/// Every `profiles/` root: the data dir's, plus the config dir's when the
+/// two are split. A profile exists if it has a directory under either.
+fn profile_roots() -> Result<Vec<PathBuf>> {
+    let data = get_app_dir()?.join("profiles");
+    let config = get_config_dir()?.join("profiles");
+    Ok(if data == config {
+        vec![data]
     } else {
-        profile
-    };
-    Ok(base.join("profiles").join(profile_name))
+        vec![data, config]
+    })
 }
 
 pub fn list_profiles() -> Result<Vec<String>> {
@@ -310,14 +416,15 @@ pub fn list_profiles() -> Result<Vec<String>> {
     if FAIL_NEXT_LIST_PROFILES.swap(false, std::sync::atomic::Ordering::SeqCst) {
         anyhow::bail!("list_profiles failure injected for test");
     }
-    let base = get_app_dir()?;
-    let profiles_dir = base.join("profiles");
-
-    if !profiles_dir.exists() {
-        return Ok(vec![]);
+    let mut profiles = Vec::new();
+    for profiles_dir in profile_roots()? {
+        if profiles_dir.exists() {
+            profiles.extend(list_profile_names_in(&profiles_dir)?);
+        }
     }
-
-    list_profile_names_in(&profiles_dir)
+    profiles.sort();
+    profiles.dedup();
+    Ok(profiles)
 }
 
 #[cfg(test)]
@@ -507,10 +614,13 @@ pub fn create_profile(name: &str) -> Result<()> {
 pub fn delete_profile(name: &str) -> Result<()> {
     validate_profile_name(name)?;
 
-    let base = get_app_dir()?;
-    let profile_dir = base.join("profiles").join(name);
+    let profile_dirs: Vec<PathBuf> = profile_roots()?
+        .into_iter()
+        .map(|root| root.join(name))
+        .filter(|dir| dir.exists())
+        .collect();
 
-    if !profile_dir.exists() {
+    if profile_dirs.is_empty() {
         anyhow::bail!("Profile '{}' does not exist", name);
     }
 
@@ -520,7 +630,9 @@ pub fn delete_profile(name: &str) -> Result<()> {
         anyhow::bail!("Cannot delete '{}': at least one profile must exist", name);
     }
 
-    fs::remove_dir_all(&profile_dir)?;
+    for dir in profile_dirs {
+        fs::remove_dir_all(&dir)?;
+    }
     Ok(())
 }
 
@@ -532,18 +644,20 @@ pub fn rename_profile(old_name: &str, new_name: &str) -> Result<()> {
         anyhow::bail!("Profile name cannot contain path separators");
     }
 
-    let base = get_app_dir()?;
-    let old_dir = base.join("profiles").join(old_name);
-    let new_dir = base.join("profiles").join(new_name);
-
-    if !old_dir.exists() {
+    let roots = profile_roots()?;
+    if !roots.iter().any(|root| root.join(old_name).exists()) {
         anyhow::bail!("Profile '{}' does not exist", old_name);
     }
-    if new_dir.exists() {
+    if roots.iter().any(|root| root.join(new_name).exists()) {
         anyhow::bail!("Profile '{}' already exists", new_name);
     }
 
-    fs::rename(&old_dir, &new_dir)?;
+    for root in &roots {
+        let old_dir = root.join(old_name);
+        if old_dir.exists() {
+            fs::rename(&old_dir, root.join(new_name))?;
+        }
+    }
 
     // Update default profile if the renamed profile was the default
     if let Some(config) = load_config()? {
@@ -727,6 +841,108 @@ mod tests {
         assert_eq!(xdg_config_base().unwrap(), temp.path().join(".config"));
     }
 
+    /// Clears both dir overrides on drop so a failing test cannot leak them
+    /// into the rest of the suite.
+    struct DirOverrides;
+
+    impl DirOverrides {
+        fn set(config: Option<&Path>, data: Option<&Path>) -> Self {
+            for (var, value) in [(CONFIG_DIR_ENV, config), (DATA_DIR_ENV, data)] {
+                match value {
+                    Some(dir) => std::env::set_var(var, dir),
+                    None => std::env::remove_var(var),
+                }
+            }
+            DirOverrides
+        }
+    }
+
+    impl Drop for DirOverrides {
+        fn drop(&mut self) {
+            std::env::remove_var(CONFIG_DIR_ENV);
+            std::env::remove_var(DATA_DIR_ENV);
+        }
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_config_dir_override_relocates_everything() {
+        let _temp = isolate_app_dir();
+        let portable = tempfile::TempDir::new().unwrap();
+        let _overrides = DirOverrides::set(Some(portable.path()), None);
+
+        assert_eq!(get_config_dir().unwrap(), portable.path());
+        assert_eq!(get_app_dir().unwrap(), portable.path());
+        assert_eq!(
+            get_profile_dir_path("work").unwrap(),
+            portable.path().join("profiles").join("work")
+        );
+        assert!(debug_namespace_drift().is_none());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_data_dir_override_splits_state_from_config() {
+        let _temp = isolate_app_dir();
+        let config = tempfile::TempDir::new().unwrap();
+        let data = tempfile::TempDir::new().unwrap();
+        let _overrides = DirOverrides::set(Some(config.path()), Some(data.path()));
+
+        assert_eq!(get_config_dir().unwrap(), config.path());
+        assert_eq!(get_app_dir().unwrap(), data.path());
+        assert_eq!(
+            get_profile_config_dir_path("work").unwrap(),
+            config.path().join("profiles").join("work")
+        );
+        assert_eq!(
+            get_profile_dir_path("work").unwrap(),
+            data.path().join("profiles").join("work")
+        );
+
+        // A profile with only a config file, or only sessions, still exists.
+        fs::create_dir_all(config.path().join("profiles").join("work")).unwrap();
+        fs::create_dir_all(data.path().join("profiles").join("work")).unwrap();
+        fs::create_dir_all(data.path().join("profiles").join("home")).unwrap();
+        assert_eq!(list_profiles().unwrap(), vec!["home", "work"]);
+
+        rename_profile("work", "job").unwrap();
+        assert!(config.path().join("profiles").join("job").exists());
+        assert!(data.path().join("profiles").join("job").exists());
+        delete_profile("job").unwrap();
+        assert_eq!(list_profiles().unwrap(), vec!["home"]);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_home_dir_honors_aoe_home_over_home() {
+        let temp = isolate_app_dir();
+        assert_eq!(home_dir().unwrap(), temp.path());
+
+        let other = tempfile::TempDir::new().unwrap();
+        std::env::set_var(HOME_DIR_ENV, other.path());
+        let overridden = home_dir();
+        std::env::set_var(HOME_DIR_ENV, "relative/home");
+        let relative = home_dir();
+        std::env::remove_var(HOME_DIR_ENV);
+
+        assert_eq!(overridden.unwrap(), other.path());
+        let err = relative.unwrap_err().to_string();
+        assert!(
+            err.contains("AOE_HOME must be an absolute path"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_dir_override_rejects_relative_paths() {
+        let _temp = isolate_app_dir();
+        let _overrides = DirOverrides::set(None, Some(Path::new("relative/aoe")));
+
+        let err = get_app_dir().unwrap_err();
+        assert!(err.to_string().contains(DATA_DIR_ENV), "{err}");
+    }
+
     // Precedence behind the macOS read-fallback resolution (issue #1948). These
     // exercise the pure rule, so they run on every platform's CI, not just
     // macOS where `macos_app_dir` is compiled.
@@ -7,8 +7,7 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::fs;
 
-use super::config::Config;
-use super::get_profile_dir;
+use super::config::{Config, ContainerRuntimeName};
 
 /// Profile-specific settings, stored as a sparse override tree (#1692).
 ///
@@ -26,6 +25,14 @@ pub struct ProfileConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// TUI theme to paint while the home view is filtered to this profile,
+    /// so profiles are distinguishable at a glance. Profile-only and kept out
+    /// of the `[theme]` override section: the global theme pick stays the
+    /// single source for every other surface (web, tmux status bar,
+    /// all-profiles view), see [`super::config::resolve_theme_name`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme_name: Option<String>,
+
     /// Sparse overrides, keyed by config section. Flattened so the on-disk TOML
     /// keeps the historical `[section]` table layout (no migration needed).
     #[serde(flatten)]
@@ -47,7 +54,7 @@ impl ProfileConfig {
 /// (which the dashboard fires on mount before profiles resolve) does
 /// not pollute `profiles/` with a stub directory.
 pub fn load_profile_config(profile: &str) -> Result<ProfileConfig> {
-    let path = super::get_profile_dir_path(profile)?.join("config.toml");
+    let path = super::get_profile_config_dir_path(profile)?.join("config.toml");
     if !path.exists() {
         return Ok(ProfileConfig::default());
     }
@@ -83,17 +90,18 @@ pub fn save_profile_config(profile: &str, config: &ProfileConfig) -> Result<()>
     Ok(())
 }
 
-/// Get the path to a profile's config file. This goes through the
-/// creating [`get_profile_dir`] because the only remaining caller is
-/// [`save_profile_config`], which needs the directory to exist before
-/// the atomic write.
+/// Get the path to a profile's config file, creating its directory: the
+/// only remaining caller is [`save_profile_config`], which needs the
+/// directory to exist before the atomic write.
 pub fn get_profile_config_path(profile: &str) -> Result<std::path::PathBuf> {
-    Ok(get_profile_dir(profile)?.join("config.toml"))
+    let dir = super::get_profile_config_dir_path(profile)?;
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join("config.toml"))
 }
 
 /// Check if a profile has any overrides set
 pub fn profile_has_overrides(config: &ProfileConfig) -> bool {
-    config.description.is_some() || !config.overrides.is_empty()
+    config.description.is_some() || config.theme_name.is_some() || !config.overrides.is_empty()
 }
 
 /// Load effective config for a profile (global + profile overrides merged)
@@ -156,6 +164,16 @@ pub fn validate_volume_format(volume: &str) -> Result<(), String> {
         return Err("Host and container paths cannot be empty".to_string());
     }
 
+    if !parts[1].starts_with('/') {
+        return Err("Container path must be absolute".to_string());
+    }
+
+    if let Some(mode) = parts.get(2) {
+        if !matches!(*mode, "ro" | "rw") {
+            return Err(format!("Unknown mode `{mode}` (use ro or rw)"));
+        }
+    }
+
     Ok(())
 }
 
@@ -172,6 +190,25 @@ pub fn validate_env_format(entry: &str) -> Result<(), String> {
     }
 }
 
+/// Parse a `sandbox.agent_container_env` value: whitespace-separated
+/// `KEY=VALUE` pairs, shell-quoted when a value contains spaces.
+pub fn parse_agent_container_env(spec: &str) -> Result<Vec<(String, String)>, String> {
+    let key_re = regex::Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+    let words = shell_words::split(spec).map_err(|e| format!("Invalid quoting: {e}"))?;
+    if words.is_empty() {
+        return Err("Must list at least one KEY=VALUE pair".to_string());
+    }
+    words
+        .into_iter()
+        .map(|word| match word.split_once('=') {
+            Some((key, value)) if key_re.is_match(key) => Ok((key.to_string(), value.to_string())),
+            _ => Err(format!(
+                "'{word}' must be KEY=VALUE (letters, digits, underscores in KEY)"
+            )),
+        })
+        .collect()
+}
+
 /// Validate a `host:container` port mapping (digits only on both sides).
 pub fn validate_port_mapping_format(mapping: &str) -> Result<(), String> {
     let re = regex::Regex::new(r"^\d+:\d+$").unwrap();
@@ -182,6 +219,26 @@ pub fn validate_port_mapping_format(mapping: &str) -> Result<(), String> {
     }
 }
 
+/// Validate a `sandbox.config_sync_excludes` entry:
+/// `<agent config dir>/<top-level name>` (e.g. `.claude/projects`). The dir
+/// must be one of the agent config dirs synced into sandboxes, and the name a
+/// single entry directly inside it.
+pub fn validate_config_sync_exclude(entry: &str) -> Result<(), String> {
+    let (host_rel, name) = entry
+        .trim()
+        .rsplit_once('/')
+        .ok_or_else(|| "Must be <agent config dir>/<name> (e.g. .claude/projects)".to_string())?;
+    if !crate::session::container_config::is_agent_config_dir(host_rel) {
+        return Err(format!(
+            "'{host_rel}' is not an agent config dir (e.g. .claude, .codex, .gemini)"
+        ));
+    }
+    if name.is_empty() || name == "." || name == ".." || name.contains('\\') {
+        return Err("Name must be a single top-level entry (no path separators)".to_string());
+    }
+    Ok(())
+}
+
 /// Validate Docker memory limit format (e.g., "512m", "2g")
 pub fn validate_memory_limit(limit: &str) -> Result<(), String> {
     if limit.is_empty() {
@@ -200,6 +257,80 @@ pub fn validate_memory_limit(limit: &str) -> Result<(), String> {
     }
 }
 
+/// Validate a `session.launch_wrapper` template: it must place the agent
+/// command somewhere via `{cmd}` (e.g. "nice -n 10 {cmd}").
+pub fn validate_launch_wrapper(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() || template.contains("{cmd}") {
+        Ok(())
+    } else {
+        Err("Launch wrapper must contain {cmd} where the agent command goes".to_string())
+    }
+}
+
+/// Validate a `display.timezone` value: `local`, `utc`, or `+HH:MM`/`-HH:MM`.
+pub fn validate_display_timezone(value: &str) -> Result<(), String> {
+    match super::config::parse_display_timezone(value) {
+        Some(_) => Ok(()),
+        None => Err("Timezone must be local, utc, or an offset like +05:30".to_string()),
+    }
+}
+
+/// Validate a `display.datetime_format` strftime string: non-empty and made
+/// only of specifiers chrono understands.
+pub fn validate_datetime_format(format: &str) -> Result<(), String> {
+    use chrono::format::{Item, StrftimeItems};
+    if format.trim().is_empty() {
+        return Err("Format cannot be empty".to_string());
+    }
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("Invalid date/time format: {}", format));
+    }
+    Ok(())
+}
+
+/// Validate CPU limit format: a positive number of CPUs (e.g. "2", "1.5")
+pub fn validate_cpu_limit(limit: &str) -> Result<(), String> {
+    if limit.is_empty() {
+        return Ok(());
+    }
+
+    let re = regex::Regex::new(r"^\d+(\.\d+)?$").unwrap();
+    match limit.parse::<f64>() {
+        Ok(n) if re.is_match(limit) && n > 0.0 => Ok(()),
+        _ => Err("CPU limit must be a positive number of CPUs (e.g. 2, 1.5)".to_string()),
+    }
+}
+
+/// Validate a CPU limit for a specific runtime. Docker and Podman accept
+/// fractional CPUs; Apple Container's `--cpus` takes a whole number.
+pub fn validate_cpu_limit_for_runtime(
+    limit: &str,
+    runtime: ContainerRuntimeName,
+) -> Result<(), String> {
+    validate_cpu_limit(limit)?;
+    if runtime == ContainerRuntimeName::AppleContainer && limit.contains('.') {
+        return Err("Apple Container only accepts a whole number of CPUs (e.g. 2)".to_string());
+    }
+    Ok(())
+}
+
+/// Validate a memory limit for a specific runtime. Apple Container allocates
+/// memory in whole megabytes, so a byte-suffixed value is rejected there; the
+/// unit letter itself is translated at create time.
+pub fn validate_memory_limit_for_runtime(
+    limit: &str,
+    runtime: ContainerRuntimeName,
+) -> Result<(), String> {
+    validate_memory_limit(limit)?;
+    if runtime == ContainerRuntimeName::AppleContainer && limit.ends_with(['b', 'B']) {
+        return Err(
+            "Apple Container allocates memory in whole megabytes; use k, m, or g (e.g. 512m)"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
 /// Validate check interval is positive
 pub fn validate_check_interval(hours: u64) -> Result<(), String> {
     if hours == 0 {
@@ -219,6 +350,22 @@ mod tests {
         serde_json::from_value(overrides).expect("profile override deserializes")
     }
 
+    #[test]
+    fn test_parse_agent_container_env() {
+        assert_eq!(
+            parse_agent_container_env("FOO=1 BAR='a b' EMPTY=").unwrap(),
+            vec![
+                ("FOO".to_string(), "1".to_string()),
+                ("BAR".to_string(), "a b".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+        assert!(parse_agent_container_env("").is_err());
+        assert!(parse_agent_container_env("FOO").is_err());
+        assert!(parse_agent_container_env("1FOO=x").is_err());
+        assert!(parse_agent_container_env("FOO='unterminated").is_err());
+    }
+
     #[test]
     fn test_profile_config_default() {
         let config = ProfileConfig::default();
@@ -328,6 +475,9 @@ mod tests {
         assert!(validate_volume_format("/only-one").is_err());
         assert!(validate_volume_format(":/container").is_err());
         assert!(validate_volume_format("/host:").is_err());
+        assert!(validate_volume_format("/host:container").is_err());
+        assert!(validate_volume_format("/host:/container:rw").is_ok());
+        assert!(validate_volume_format("/host:/container:readonly").is_err());
     }
 
     #[test]
@@ -343,6 +493,34 @@ mod tests {
         assert!(validate_memory_limit("512mb").is_err());
     }
 
+    #[test]
+    fn test_validate_cpu_limit() {
+        assert!(validate_cpu_limit("").is_ok());
+        assert!(validate_cpu_limit("4").is_ok());
+        assert!(validate_cpu_limit("1.5").is_ok());
+        assert!(validate_cpu_limit("0").is_err());
+        assert!(validate_cpu_limit("-1").is_err());
+        assert!(validate_cpu_limit("two").is_err());
+        assert!(validate_cpu_limit("inf").is_err());
+        assert!(validate_cpu_limit("1e3").is_err());
+    }
+
+    #[test]
+    fn test_limits_are_validated_per_runtime() {
+        use ContainerRuntimeName::*;
+        assert!(validate_cpu_limit_for_runtime("1.5", Docker).is_ok());
+        assert!(validate_cpu_limit_for_runtime("1.5", Podman).is_ok());
+        assert!(validate_cpu_limit_for_runtime("1.5", AppleContainer).is_err());
+        assert!(validate_cpu_limit_for_runtime("2", AppleContainer).is_ok());
+
+        assert!(validate_memory_limit_for_runtime("104857600b", Docker).is_ok());
+        assert!(validate_memory_limit_for_runtime("104857600b", AppleContainer).is_err());
+        assert!(validate_memory_limit_for_runtime("8g", AppleContainer).is_ok());
+        assert!(validate_memory_limit_for_runtime("512M", AppleContainer).is_ok());
+        // The runtime-agnostic grammar still applies everywhere.
+        assert!(validate_memory_limit_for_runtime("512mb", AppleContainer).is_err());
+    }
+
     #[test]
     fn test_validate_check_interval() {
         assert!(validate_check_interval(1).is_ok());
@@ -55,13 +55,7 @@ impl RepoConfig {
 
     /// The overrides restricted to repo-allowed sections, as a JSON object.
     fn allowed_overrides(&self) -> serde_json::Value {
-        serde_json::Value::Object(
-            self.overrides
-                .iter()
-                .filter(|(k, _)| REPO_OVERRIDABLE_SECTIONS.contains(&k.as_str()))
-                .map(|(k, v)| (k.clone(), v.clone()))
-                .collect(),
-        )
+        serde_json::Value::Object(repo_overridable_overrides(&self.overrides))
     }
 }
 
@@ -115,30 +109,49 @@ impl HooksConfig {
 /// Path to the repo config file relative to the project root.
 const REPO_CONFIG_PATH: &str = ".agent-of-empires/config.toml";
 
+/// Single-file alternative to [`REPO_CONFIG_PATH`] for repos that don't want
+/// a directory. Same contents; the directory form wins if both exist.
+pub const REPO_CONFIG_FILE: &str = ".aoe.toml";
+
 /// Legacy path (pre-1.1) for backwards compatibility.
 const LEGACY_REPO_CONFIG_PATH: &str = ".aoe/config.toml";
 
-/// Load repo config from `<project_path>/.agent-of-empires/config.toml`.
-/// Falls back to the legacy `.aoe/config.toml` path with a deprecation warning.
-/// Returns `None` if neither file exists.
-pub fn load_repo_config(project_path: &Path) -> Result<Option<RepoConfig>> {
-    let config_path = project_path.join(REPO_CONFIG_PATH);
-    let (config_path, is_legacy) = if config_path.exists() {
-        (config_path, false)
-    } else {
-        let legacy_path = project_path.join(LEGACY_REPO_CONFIG_PATH);
-        if legacy_path.exists() {
-            (legacy_path, true)
-        } else {
-            return Ok(None);
+/// The repo config file in effect for `project_path`, in priority order:
+/// `.agent-of-empires/config.toml`, then `.aoe.toml`, then the legacy
+/// `.aoe/config.toml`. Only one is ever read; the others are not merged.
+pub fn repo_config_file(project_path: &Path) -> Option<PathBuf> {
+    let primary = project_path.join(REPO_CONFIG_PATH);
+    let single_file = project_path.join(REPO_CONFIG_FILE);
+    if primary.exists() {
+        if single_file.exists() {
+            tracing::warn!(target: "session.store",
+                "Both {REPO_CONFIG_PATH} and {REPO_CONFIG_FILE} exist in {}; ignoring {REPO_CONFIG_FILE}",
+                project_path.display()
+            );
         }
-    };
-
-    if is_legacy {
+        return Some(primary);
+    }
+    if single_file.exists() {
+        return Some(single_file);
+    }
+    let legacy = project_path.join(LEGACY_REPO_CONFIG_PATH);
+    if legacy.exists() {
         tracing::warn!(target: "session.store",
             "Found repo config at legacy path .aoe/config.toml -- please rename to .agent-of-empires/config.toml"
         );
+        return Some(legacy);
     }
+    None
+}
+
+/// Load repo config from `<project_path>/.agent-of-empires/config.toml` or
+/// `<project_path>/.aoe.toml` (see [`repo_config_file`]). Falls back to the
+/// legacy `.aoe/config.toml` path with a deprecation warning. Returns `None`
+/// if no repo config file exists.
+pub fn load_repo_config(project_path: &Path) -> Result<Option<RepoConfig>> {
+    let Some(config_path) = repo_config_file(project_path) else {
+        return Ok(None);
+    };
 
     let content = fs::read_to_string(&config_path)
         .with_context(|| format!("Failed to read {}", config_path.display()))?;
@@ -159,16 +172,22 @@ pub fn load_repo_config(project_path: &Path) -> Result<Option<RepoConfig>> {
     Ok(Some(config))
 }
 
-/// Save repo config to `<project_path>/.agent-of-empires/config.toml`.
-/// Creates the `.agent-of-empires/` directory if it does not exist.
+/// Save repo config to `<project_path>/.agent-of-empires/config.toml`, or
+/// back to `.aoe.toml` when that is the file in effect. Creates the
+/// `.agent-of-empires/` directory if it does not exist.
 /// If a legacy `.aoe/config.toml` exists, it is removed after a successful save
 /// to prevent stale config from silently reactivating.
 pub fn save_repo_config(project_path: &Path, config: &RepoConfig) -> Result<()> {
-    let config_dir = project_path.join(".agent-of-empires");
-    fs::create_dir_all(&config_dir)
-        .with_context(|| format!("Failed to create {}", config_dir.display()))?;
+    let single_file = project_path.join(REPO_CONFIG_FILE);
+    let config_path = if single_file.exists() && !project_path.join(REPO_CONFIG_PATH).exists() {
+        single_file
+    } else {
+        let config_dir = project_path.join(".agent-of-empires");
+        fs::create_dir_all(&config_dir)
+            .with_context(|| format!("Failed to create {}", config_dir.display()))?;
+        project_path.join(REPO_CONFIG_PATH)
+    };
 
-    let config_path = project_path.join(REPO_CONFIG_PATH);
     let content = toml::to_string_pretty(config)
         .with_context(|| "Failed to serialize repo config".to_string())?;
 
@@ -203,14 +222,28 @@ pub fn merge_repo_config(config: Config, repo: &RepoConfig) -> Config {
     super::profile_config::merge_configs_generic(&config, &repo.allowed_overrides())
 }
 
-/// Filter a sparse override map to the repo-allowed sections.
+/// Filter a sparse override map to the repo-allowed sections, dropping the
+/// fields inside them that a repo may not set (`#[setting(no_repo)]`, e.g.
+/// `sandbox.mount_docker_socket`).
 fn repo_overridable_overrides(
     overrides: &serde_json::Map<String, serde_json::Value>,
 ) -> serde_json::Map<String, serde_json::Value> {
+    let denied: Vec<_> = super::settings_schema::schema()
+        .into_iter()
+        .filter(|d| !d.repo_overridable)
+        .collect();
     overrides
         .iter()
         .filter(|(k, _)| REPO_OVERRIDABLE_SECTIONS.contains(&k.as_str()))
-        .map(|(k, v)| (k.clone(), v.clone()))
+        .map(|(k, v)| {
+            let mut v = v.clone();
+            if let Some(table) = v.as_object_mut() {
+                for d in denied.iter().filter(|d| d.section == *k) {
+                    table.remove(&d.field);
+                }
+            }
+            (k.clone(), v)
+        })
         .collect()
 }
 
@@ -221,6 +254,7 @@ fn repo_overridable_overrides(
 pub fn repo_config_to_profile(repo: &RepoConfig) -> ProfileConfig {
     ProfileConfig {
         description: None,
+        theme_name: None,
         overrides: repo_overridable_overrides(&repo.overrides),
     }
 }
@@ -250,7 +284,8 @@ pub fn repo_config_source_path(project_path: &Path) -> PathBuf {
     project_path.to_path_buf()
 }
 
-/// Resolve config with repo overrides: global -> profile -> repo.
+/// Resolve config with repo overrides: global -> profile -> repo, each layer
+/// overriding only the fields it sets.
 pub fn resolve_config_with_repo(profile: &str, project_path: &Path) -> Result<Config> {
     let config = super::profile_config::resolve_config(profile)?;
     let config_path = repo_config_source_path(project_path);
@@ -1465,6 +1500,27 @@ mod tests {
         assert_eq!(merged.sandbox.volume_ignores, vec!["node_modules"]);
     }
 
+    #[test]
+    fn test_merge_repo_config_ignores_docker_socket_mount() {
+        let repo: RepoConfig = serde_json::from_value(serde_json::json!({"sandbox": {
+            "mount_docker_socket": true,
+            "docker_socket_path": "/tmp/evil.sock",
+            "enabled_by_default": true
+        }}))
+        .unwrap();
+        let merged = merge_repo_config(Config::default(), &repo);
+        assert!(!merged.sandbox.mount_docker_socket);
+        assert_eq!(merged.sandbox.docker_socket_path, None);
+        // The rest of the section still applies.
+        assert!(merged.sandbox.enabled_by_default);
+
+        let stored = profile_to_repo_config(&repo_config_to_profile(&repo));
+        assert_eq!(
+            stored.overrides["sandbox"],
+            serde_json::json!({"enabled_by_default": true})
+        );
+    }
+
     #[test]
     fn test_merge_repo_config_worktree() {
         let config = Config::default();
@@ -1495,6 +1551,66 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_load_repo_config_single_file() {
+        let repo = tempfile::tempdir().unwrap();
+        fs::write(
+            repo.path().join(REPO_CONFIG_FILE),
+            "[session]\ndefault_tool = \"codex\"\n\n[sandbox]\nvolume_ignores = [\"target\"]\n",
+        )
+        .unwrap();
+
+        let merged = merge_repo_config(
+            Config::default(),
+            &load_repo_config(repo.path()).unwrap().unwrap(),
+        );
+        assert_eq!(merged.session.default_tool.as_deref(), Some("codex"));
+        assert_eq!(merged.sandbox.volume_ignores, vec!["target".to_string()]);
+
+        // Saving keeps writing the single file rather than creating the dir.
+        save_repo_config(
+            repo.path(),
+            &load_repo_config(repo.path()).unwrap().unwrap(),
+        )
+        .unwrap();
+        assert!(!repo.path().join(".agent-of-empires").exists());
+
+        // A wrong-typed value is rejected the same way as in the dir form.
+        fs::write(
+            repo.path().join(REPO_CONFIG_FILE),
+            "[sandbox]\nmount_ssh = \"yes\"\n",
+        )
+        .unwrap();
+        let err = load_repo_config(repo.path()).unwrap_err();
+        assert!(format!("{err:#}").contains(".aoe.toml"), "got: {err:#}");
+    }
+
+    #[test]
+    fn test_repo_config_dir_wins_over_single_file() {
+        let repo = tempfile::tempdir().unwrap();
+        fs::create_dir_all(repo.path().join(".agent-of-empires")).unwrap();
+        fs::write(
+            repo.path().join(REPO_CONFIG_PATH),
+            "[session]\ndefault_tool = \"claude\"\n",
+        )
+        .unwrap();
+        fs::write(
+            repo.path().join(REPO_CONFIG_FILE),
+            "[session]\ndefault_tool = \"codex\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            repo_config_file(repo.path()).unwrap(),
+            repo.path().join(REPO_CONFIG_PATH)
+        );
+        let merged = merge_repo_config(
+            Config::default(),
+            &load_repo_config(repo.path()).unwrap().unwrap(),
+        );
+        assert_eq!(merged.session.default_tool.as_deref(), Some("claude"));
+    }
+
     fn global_hooks_fixture() -> HooksConfig {
         HooksConfig {
             on_create: vec!["global-create".to_string()],
@@ -125,6 +125,8 @@ pub enum ValidationKind {
     NonEmptyString,
     /// Docker memory-limit grammar (`512m`, `2g`, ...). Empty allowed.
     MemoryLimit,
+    /// Positive number of CPUs (`2`, `1.5`). Empty allowed.
+    CpuLimit,
     /// Each list entry must be `host:container[:options]`.
     VolumeList,
     /// Each list entry must be a sandbox env entry: bare `KEY` or `KEY=VALUE`
@@ -132,6 +134,14 @@ pub enum ValidationKind {
     EnvList,
     /// Each list entry must be a `host:container` port mapping (digits only).
     PortMappingList,
+    /// Each list entry must be `<agent config dir>/<top-level name>`.
+    ConfigSyncExcludeList,
+    /// Agent launch wrapper template; must contain `{cmd}`. Empty allowed.
+    LaunchWrapper,
+    /// `local`, `utc`, or a fixed `+HH:MM` / `-HH:MM` offset.
+    Timezone,
+    /// A chrono strftime format string.
+    DatetimeFormat,
 }
 
 /// One configurable field, emitted by the `SettingsSection` derive. Owned
@@ -153,6 +163,11 @@ pub struct FieldDescriptor {
     /// Whether a profile/repo may override this field. `false` means the
     /// value is global-only (the field is still shown, but not overridable).
     pub profile_overridable: bool,
+    /// Whether a repo `.agent-of-empires/config.toml` may override this
+    /// field. Off for settings that widen what the sandbox can reach on the
+    /// host: a cloned repo must not be able to turn those on.
+    #[serde(default = "default_true")]
+    pub repo_overridable: bool,
     pub validation: ValidationKind,
     /// Operational tuning that sits under an "Advanced" fold in both surfaces.
     /// The web groups advanced fields into a collapsible section; the TUI
@@ -168,3 +183,7 @@ impl FieldDescriptor {
         format!("{}.{}", self.section, self.field)
     }
 }
+
+fn default_true() -> bool {
+    true
+}
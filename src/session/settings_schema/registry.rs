@@ -3,9 +3,11 @@
 //! This is the single list the TUI, web, and server all consume.
 
 use super::FieldDescriptor;
+use crate::notifications::NotificationConfig;
 use crate::session::config::{
-    AcpConfig, AuthConfig, DiffConfig, LoggingConfig, SandboxConfig, SessionConfig,
-    TelemetryConfig, ThemeConfig, TmuxConfig, UpdatesConfig, WebConfig, WorktreeConfig,
+    AcpConfig, AppConfig, AuthConfig, DiffConfig, DisplayConfig, LoggingConfig, SandboxConfig,
+    SessionConfig, TelemetryConfig, ThemeConfig, TmuxConfig, UpdatesConfig, WebConfig,
+    WorktreeConfig,
 };
 use crate::sound::SoundConfig;
 use crate::status_hooks::StatusHookConfig;
@@ -14,13 +16,16 @@ use crate::status_hooks::StatusHookConfig;
 pub fn schema() -> Vec<FieldDescriptor> {
     let mut out = Vec::new();
     out.extend(ThemeConfig::settings_descriptors());
+    out.extend(DisplayConfig::settings_descriptors());
     out.extend(UpdatesConfig::settings_descriptors());
+    out.extend(AppConfig::settings_descriptors());
     out.extend(TelemetryConfig::settings_descriptors());
     out.extend(WorktreeConfig::settings_descriptors());
     out.extend(SandboxConfig::settings_descriptors());
     out.extend(TmuxConfig::settings_descriptors());
     out.extend(SessionConfig::settings_descriptors());
     out.extend(SoundConfig::settings_descriptors());
+    out.extend(NotificationConfig::settings_descriptors());
     out.extend(StatusHookConfig::settings_descriptors());
     out.extend(WebConfig::settings_descriptors());
     out.extend(AuthConfig::settings_descriptors());
@@ -64,6 +64,12 @@ pub fn validate_value(kind: &ValidationKind, value: &Value) -> Result<(), Valida
                 .ok_or_else(|| ValidationError::new("expected a string"))?;
             crate::session::validate_memory_limit(s).map_err(ValidationError::new)
         }
+        ValidationKind::CpuLimit => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| ValidationError::new("expected a string"))?;
+            crate::session::validate_cpu_limit(s).map_err(ValidationError::new)
+        }
         ValidationKind::VolumeList => {
             validate_string_list(value, crate::session::validate_volume_format)
         }
@@ -71,6 +77,27 @@ pub fn validate_value(kind: &ValidationKind, value: &Value) -> Result<(), Valida
         ValidationKind::PortMappingList => {
             validate_string_list(value, crate::session::validate_port_mapping_format)
         }
+        ValidationKind::ConfigSyncExcludeList => {
+            validate_string_list(value, crate::session::validate_config_sync_exclude)
+        }
+        ValidationKind::LaunchWrapper => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| ValidationError::new("expected a string"))?;
+            crate::session::validate_launch_wrapper(s).map_err(ValidationError::new)
+        }
+        ValidationKind::Timezone => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| ValidationError::new("expected a string"))?;
+            crate::session::validate_display_timezone(s).map_err(ValidationError::new)
+        }
+        ValidationKind::DatetimeFormat => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| ValidationError::new("expected a string"))?;
+            crate::session::validate_datetime_format(s).map_err(ValidationError::new)
+        }
     }
 }
 
@@ -115,12 +142,43 @@ mod tests {
         assert!(validate_value(&kind, &json!(-1)).is_err());
     }
 
+    #[test]
+    fn launch_wrapper_requires_cmd_placeholder() {
+        let kind = ValidationKind::LaunchWrapper;
+        assert!(validate_value(&kind, &json!("nice -n 10 {cmd}")).is_ok());
+        assert!(validate_value(&kind, &json!("")).is_ok());
+        assert!(validate_value(&kind, &json!("nice -n 10")).is_err());
+    }
+
+    #[test]
+    fn display_timezone_and_format() {
+        let tz = ValidationKind::Timezone;
+        for ok in ["local", "UTC", "+05:30", "-08:00"] {
+            assert!(validate_value(&tz, &json!(ok)).is_ok(), "{ok}");
+        }
+        for bad in ["Europe/Paris", "+5", "+05:75", ""] {
+            assert!(validate_value(&tz, &json!(bad)).is_err(), "{bad}");
+        }
+        let fmt = ValidationKind::DatetimeFormat;
+        assert!(validate_value(&fmt, &json!("%d %b %Y %H:%M")).is_ok());
+        assert!(validate_value(&fmt, &json!("%Y-%Q")).is_err());
+        assert!(validate_value(&fmt, &json!(" ")).is_err());
+    }
+
     #[test]
     fn non_empty_string_trims() {
         assert!(validate_value(&ValidationKind::NonEmptyString, &json!("  ")).is_err());
         assert!(validate_value(&ValidationKind::NonEmptyString, &json!("x")).is_ok());
     }
 
+    #[test]
+    fn cpu_limit_grammar() {
+        assert!(validate_value(&ValidationKind::CpuLimit, &json!("1.5")).is_ok());
+        assert!(validate_value(&ValidationKind::CpuLimit, &json!("")).is_ok());
+        assert!(validate_value(&ValidationKind::CpuLimit, &json!("0")).is_err());
+        assert!(validate_value(&ValidationKind::CpuLimit, &json!(2)).is_err());
+    }
+
     #[test]
     fn memory_limit_grammar() {
         assert!(validate_value(&ValidationKind::MemoryLimit, &json!("512m")).is_ok());
@@ -144,6 +202,17 @@ mod tests {
         assert!(validate_value(&ValidationKind::EnvList, &json!("notalist")).is_err());
     }
 
+    #[test]
+    fn config_sync_exclude_list_grammar() {
+        let kind = ValidationKind::ConfigSyncExcludeList;
+        assert!(validate_value(&kind, &json!([".claude/projects"])).is_ok());
+        assert!(validate_value(&kind, &json!([".local/share/opencode/log"])).is_ok());
+        assert!(validate_value(&kind, &json!(["projects"])).is_err());
+        assert!(validate_value(&kind, &json!([".claude/"])).is_err());
+        assert!(validate_value(&kind, &json!([".claude/projects/big"])).is_err());
+        assert!(validate_value(&kind, &json!([".unknown/projects"])).is_err());
+    }
+
     #[test]
     fn port_mapping_list_grammar() {
         assert!(validate_value(&ValidationKind::PortMappingList, &json!(["3000:3000"])).is_ok());
@@ -1,15 +1,31 @@
 //! Shared session stop logic.
 //!
-//! Stopping a session kills its tmux pane and, for sandboxed sessions, stops
-//! (but does not remove) the Docker container so it can be restarted on
-//! re-attach. `container.stop()` can block for up to the Docker stop grace
-//! period (~10s), so the TUI runs this off the UI thread via `StopPoller`.
+//! Stopping a session kills its tmux pane and, for sandboxed sessions, its
+//! container terminal, and stops (but does not remove) the Docker container
+//! so it can be restarted on re-attach. `container.stop()` can block for up
+//! to the Docker stop grace period (~10s), so the TUI runs this off the UI
+//! thread via `StopPoller`.
+//!
+//! The same worker handles "rebuild container" requests, which tear the
+//! container down and recreate it from the current config instead of just
+//! stopping it.
+
+use crate::session::{Instance, SandboxInfo};
 
-use crate::session::Instance;
+/// What a [`StopRequest`] does to the session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopMode {
+    /// Kill the agent pane and container terminal and stop the container,
+    /// if any.
+    Session,
+    /// Remove and recreate the sandbox container instead of stopping it.
+    RebuildContainer,
+}
 
 pub struct StopRequest {
     pub session_id: String,
     pub instance: Instance,
+    pub mode: StopMode,
 }
 
 #[derive(Debug)]
@@ -17,16 +33,28 @@ pub struct StopResult {
     pub session_id: String,
     pub success: bool,
     pub error: Option<String>,
+    /// The recreated sandbox after a successful rebuild. The request works
+    /// on a copy of the instance, so the caller writes this back to persist
+    /// the new container id.
+    pub sandbox_info: Option<SandboxInfo>,
 }
 
 pub fn perform_stop(request: &StopRequest) -> StopResult {
-    match request.instance.stop() {
-        Ok(()) => {
+    let outcome = match request.mode {
+        StopMode::Session => request.instance.stop().map(|()| None),
+        StopMode::RebuildContainer => {
+            let mut instance = request.instance.clone();
+            instance.rebuild_container().map(|()| instance.sandbox_info)
+        }
+    };
+    match outcome {
+        Ok(sandbox_info) => {
             crate::tmux::refresh_session_cache();
             StopResult {
                 session_id: request.session_id.clone(),
                 success: true,
                 error: None,
+                sandbox_info,
             }
         }
         Err(e) => {
@@ -35,6 +63,7 @@ pub fn perform_stop(request: &StopRequest) -> StopResult {
                 session_id: request.session_id.clone(),
                 success: false,
                 error: Some(e.to_string()),
+                sandbox_info: None,
             }
         }
     }
@@ -54,6 +83,7 @@ mod tests {
         let request = StopRequest {
             session_id: instance.id.clone(),
             instance,
+            mode: StopMode::Session,
         };
 
         let result = perform_stop(&request);
@@ -70,9 +100,27 @@ mod tests {
         let request = StopRequest {
             session_id: custom_id.clone(),
             instance,
+            mode: StopMode::Session,
         };
 
         let result = perform_stop(&request);
         assert_eq!(result.session_id, custom_id);
     }
+
+    #[test]
+    fn test_rebuild_container_fails_for_unsandboxed_session() {
+        let instance = create_test_instance();
+        let request = StopRequest {
+            session_id: instance.id.clone(),
+            instance,
+            mode: StopMode::RebuildContainer,
+        };
+
+        let result = perform_stop(&request);
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .is_some_and(|e| e.contains("non-sandboxed")));
+    }
 }
@@ -59,12 +59,18 @@
 //! every peer process. The same hung-hook caveat documented in
 //! `recovery.rs` applies here.
 //!
+//! Every `update` first copies the outgoing `sessions.json` / `groups.json`
+//! to a `.bak` sibling (only when its locked load parsed it), and the
+//! loaders fall back to that copy when the main file is corrupt, so a torn
+//! or truncated write costs at most the last save rather than every session.
+//! The backup is best-effort: failing to write it never fails the save.
+//!
 //! `update_workspace_ordering` and `Storage::update` must NOT be called from
 //! inside each other's closures. They use distinct lock files but acquiring
 //! both in different orders across processes would deadlock cross-process.
 //! Today no caller does this; this comment is the invariant.
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use fs2::FileExt;
 use std::collections::HashMap;
 use std::fs;
@@ -75,7 +81,7 @@ use std::time::{Duration, Instant};
 
 use crate::file_watch::FileWatchService;
 
-use super::{get_app_dir, get_profile_dir, Group, Instance};
+use super::{get_app_dir, get_profile_dir, Group, GroupTree, Instance};
 
 /// Sidecar lock file name for per-profile storage. Lives next to
 /// `sessions.json` and `groups.json` and covers both: every code path that
@@ -117,6 +123,87 @@ pub(crate) fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Sibling path holding the last-known-good copy of a storage file
+/// (`sessions.json` -> `sessions.json.bak`).
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+/// Copy the file about to be replaced to its backup. `snapshot` is the
+/// content `update` just loaded and parsed from `path`; it is `None` when
+/// the file was missing, blank or recovered from the backup, so a corrupt
+/// main file never overwrites the last good backup. A failed write is
+/// logged and the save goes ahead.
+fn refresh_backup(path: &Path, snapshot: Option<&str>) {
+    let Some(content) = snapshot else {
+        return;
+    };
+    if let Err(e) = atomic_write(&backup_path(path), content.as_bytes()) {
+        tracing::warn!(target: "session.store",
+            path = %path.display(),
+            "Failed to refresh storage backup: {e}"
+        );
+    }
+}
+
+/// Parse a JSON array storage file, also returning its content when it is
+/// not blank. A missing or blank file is empty.
+fn read_json_rows_with_content<T: serde::de::DeserializeOwned>(
+    path: &Path,
+) -> Result<(Vec<T>, Option<String>)> {
+    if !path.exists() {
+        return Ok((Vec::new(), None));
+    }
+    let content = fs::read_to_string(path)?;
+    if content.trim().is_empty() {
+        return Ok((Vec::new(), None));
+    }
+    let rows = serde_json::from_str(&content)?;
+    Ok((rows, Some(content)))
+}
+
+/// Parse a JSON array storage file. A missing or blank file is empty.
+fn read_json_rows<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    read_json_rows_with_content(path).map(|(rows, _)| rows)
+}
+
+/// `read_json_rows`, recovering from the `.bak` copy when the main file is
+/// corrupt. The original parse error is returned if there is no usable
+/// backup.
+fn read_json_rows_or_backup<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    read_json_rows_or_backup_with_snapshot(path).map(|(rows, _)| rows)
+}
+
+/// `read_json_rows_or_backup`, also returning the main file's content when
+/// it parsed, for [`refresh_backup`]. Rows recovered from the backup come
+/// with no snapshot.
+fn read_json_rows_or_backup_with_snapshot<T: serde::de::DeserializeOwned>(
+    path: &Path,
+) -> Result<(Vec<T>, Option<String>)> {
+    let err = match read_json_rows_with_content(path) {
+        Ok(loaded) => return Ok(loaded),
+        Err(err) => err,
+    };
+    let backup = backup_path(path);
+    if !backup.exists() {
+        return Err(err);
+    }
+    match read_json_rows(&backup) {
+        Ok(rows) => {
+            tracing::warn!(target: "session.store",
+                path = %path.display(),
+                "Failed to parse storage file ({err}); recovered {} entries from {}",
+                rows.len(),
+                backup.display()
+            );
+            Ok((rows, None))
+        }
+        Err(_) => Err(err),
+    }
+}
+
 /// Process-wide registry of per-profile save mutexes. Every `Storage::new` for
 /// a given profile name resolves to the same `Arc<Mutex<()>>`, so independent
 /// `Storage` handles in different parts of the process serialise correctly.
@@ -297,36 +384,23 @@ impl Storage {
     }
 
     pub fn load(&self) -> Result<Vec<Instance>> {
-        if !self.sessions_path.exists() {
-            return Ok(Vec::new());
-        }
-
-        let content = fs::read_to_string(&self.sessions_path)?;
-        if content.trim().is_empty() {
-            return Ok(Vec::new());
-        }
+        Ok(self.load_with_snapshot()?.0)
+    }
 
-        let mut instances: Vec<Instance> = serde_json::from_str(&content)?;
+    /// `load`, plus the `sessions.json` content for [`refresh_backup`].
+    fn load_with_snapshot(&self) -> Result<(Vec<Instance>, Option<String>)> {
+        let (mut instances, snapshot): (Vec<Instance>, _) =
+            read_json_rows_or_backup_with_snapshot(&self.sessions_path)?;
         for inst in &mut instances {
             inst.set_file_watch(self.file_watch.clone());
         }
-        Ok(instances)
+        Ok((instances, snapshot))
     }
 
     pub fn load_with_groups(&self) -> Result<(Vec<Instance>, Vec<Group>)> {
         let instances = self.load()?;
 
-        let groups_path = self.sessions_path.with_file_name("groups.json");
-        let groups = if groups_path.exists() {
-            let content = fs::read_to_string(&groups_path)?;
-            if content.trim().is_empty() {
-                Vec::new()
-            } else {
-                serde_json::from_str(&content)?
-            }
-        } else {
-            Vec::new()
-        };
+        let groups = read_json_rows_or_backup(&self.sessions_path.with_file_name("groups.json"))?;
 
         Ok((instances, groups))
     }
@@ -351,7 +425,8 @@ impl Storage {
     /// loader (`GroupTree` accepts orphan group rows).
     ///
     /// This is the only public mutator entry point; all writes funnel
-    /// through here so both lock layers are always taken.
+    /// through here so both lock layers are always taken. Convenience
+    /// mutators such as [`Storage::rename_group`] are thin wrappers over it.
     pub fn update<F, R>(&self, f: F) -> Result<R>
     where
         F: FnOnce(&mut Vec<Instance>, &mut Vec<Group>) -> Result<R>,
@@ -367,7 +442,12 @@ impl Storage {
             )
         })?;
         let _flock = acquire_storage_flock(profile_dir, STORAGE_LOCK_FILENAME)?;
-        let (mut instances, mut groups) = self.load_with_groups()?;
+        // Load directly (rather than via `load_with_groups`) to keep the
+        // content just parsed; that is what gets snapshotted to `.bak`.
+        let groups_path = self.sessions_path.with_file_name("groups.json");
+        let (mut instances, sessions_snapshot) = self.load_with_snapshot()?;
+        let (mut groups, groups_snapshot): (Vec<Group>, _) =
+            read_json_rows_or_backup_with_snapshot(&groups_path)?;
         let groups_before = groups.clone();
         let result = f(&mut instances, &mut groups)?;
 
@@ -385,7 +465,7 @@ impl Storage {
         // (loader-tolerant) rather than instances pointing at a missing
         // group_path.
         if let Some(buf) = groups_buf {
-            let groups_path = self.sessions_path.with_file_name("groups.json");
+            refresh_backup(&groups_path, groups_snapshot.as_deref());
             atomic_write(&groups_path, &buf)?;
             // Surface the rename to in-process subscribers immediately;
             // the kernel echo arrives ~ms later for the same rename and
@@ -394,10 +474,56 @@ impl Storage {
             // notify is guaranteed to read the post-rename file.
             self.file_watch.notify_local_change(&groups_path);
         }
+        refresh_backup(&self.sessions_path, sessions_snapshot.as_deref());
         atomic_write(&self.sessions_path, &instances_buf)?;
         self.file_watch.notify_local_change(&self.sessions_path);
         Ok(result)
     }
+
+    /// Rename the group at `old_prefix` to `new_prefix` in one locked write.
+    /// Every session whose `group_path` is `old_prefix` or nested under it
+    /// is rewritten, and the group tree (including subgroups and their
+    /// collapsed/archived state) moves along with it. Fails if `old_prefix`
+    /// is not a group or `new_prefix` already exists. Returns the number of
+    /// sessions moved.
+    pub fn rename_group(&self, old_prefix: &str, new_prefix: &str) -> Result<usize> {
+        let old_prefix = old_prefix.trim().trim_matches('/');
+        let new_prefix = new_prefix.trim().trim_matches('/');
+        if old_prefix.is_empty() || new_prefix.is_empty() {
+            bail!("Group path cannot be empty");
+        }
+        if old_prefix == new_prefix {
+            return Ok(0);
+        }
+
+        self.update(|instances, groups| {
+            let mut tree = GroupTree::new_with_groups(instances, groups);
+            if !tree.group_exists(old_prefix) {
+                bail!("Group not found: {}", old_prefix);
+            }
+            if tree.group_exists(new_prefix) {
+                bail!("A group named '{}' already exists", new_prefix);
+            }
+
+            let nested = format!("{}/", old_prefix);
+            let mut moved = 0;
+            for inst in instances.iter_mut() {
+                if inst.group_path == old_prefix {
+                    inst.group_path = new_prefix.to_string();
+                } else if inst.group_path.starts_with(&nested) {
+                    inst.group_path =
+                        format!("{}{}", new_prefix, &inst.group_path[old_prefix.len()..]);
+                } else {
+                    continue;
+                }
+                moved += 1;
+            }
+
+            tree.rename_group(old_prefix, new_prefix);
+            *groups = tree.get_all_groups();
+            Ok(moved)
+        })
+    }
 }
 
 // Workspace ordering is stored at the app-data root, not per-profile:
@@ -717,6 +843,105 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn test_storage_load_recovers_truncated_file_from_backup() -> Result<()> {
+        let temp = tempdir()?;
+        setup_test_home(temp.path());
+
+        let storage = Storage::new_unwatched("test-backup")?;
+        for title in ["first", "second"] {
+            storage.update(|i, _| {
+                *i = vec![Instance::new(title, "/tmp/test")];
+                Ok(())
+            })?;
+        }
+
+        // Simulate a crash mid-write: keep only the first half of the file.
+        let content = fs::read(&storage.sessions_path)?;
+        fs::write(&storage.sessions_path, &content[..content.len() / 2])?;
+
+        let loaded = storage.load()?;
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].title, "first");
+
+        // Saving over the corrupt file must not replace the good backup.
+        storage.update(|i, _| {
+            i.push(Instance::new("third", "/tmp/test"));
+            Ok(())
+        })?;
+        let titles: Vec<_> = storage.load()?.into_iter().map(|i| i.title).collect();
+        assert_eq!(titles, ["first", "third"]);
+        let backup: Vec<Instance> =
+            serde_json::from_slice(&fs::read(backup_path(&storage.sessions_path))?)?;
+        assert_eq!(backup[0].title, "first");
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_succeeds_when_backup_write_fails() -> Result<()> {
+        let temp = tempdir()?;
+        setup_test_home(temp.path());
+
+        let storage = Storage::new_unwatched("test-backup-fail")?;
+        storage.update(|i, _| {
+            *i = vec![Instance::new("first", "/tmp/test")];
+            Ok(())
+        })?;
+
+        // A directory in the way makes every backup write fail.
+        fs::create_dir(backup_path(&storage.sessions_path))?;
+        storage.update(|i, _| {
+            i.push(Instance::new("second", "/tmp/test"));
+            Ok(())
+        })?;
+
+        let titles: Vec<_> = storage.load()?.into_iter().map(|i| i.title).collect();
+        assert_eq!(titles, ["first", "second"]);
+        Ok(())
+    }
+
+    /// Storage format versioning lives in `crate::migrations` (the
+    /// app-wide `.schema_version` pipeline); between migrations, new
+    /// `Instance` fields rely on serde defaults. These fixtures pin rows
+    /// written by older releases so a new required field can't strand them.
+    #[test]
+    #[serial]
+    fn test_storage_loads_session_rows_from_prior_releases() -> Result<()> {
+        let temp = tempdir()?;
+        setup_test_home(temp.path());
+        let storage = Storage::new_unwatched("test-fixtures")?;
+        fs::create_dir_all(storage.sessions_path.parent().unwrap())?;
+
+        let fixtures = [
+            // Earliest shape: only the fields every release has written.
+            r#"[{"id":"a1","title":"minimal","project_path":"/tmp/a",
+                "created_at":"2025-01-01T00:00:00Z"}]"#,
+            // Pre-v012 row still carrying cockpit_* keys (unknown keys are
+            // ignored if the migration has not run yet).
+            r#"[{"id":"b2","title":"cockpit","project_path":"/tmp/b","group_path":"work",
+                "command":"claude","tool":"claude","status":"idle",
+                "created_at":"2025-06-01T00:00:00Z","cockpit_mode":true,
+                "cockpit_agent":"claude-code"}]"#,
+            // Triage-era row with worktree and sandbox metadata.
+            r#"[{"id":"c3","title":"triage","project_path":"/tmp/c","tool":"codex",
+                "status":"stopped","created_at":"2026-01-01T00:00:00Z",
+                "favorited_at":"2026-01-02T00:00:00Z",
+                "worktree_info":{"branch":"feat","main_repo_path":"/tmp/repo",
+                    "managed_by_aoe":true,"created_at":"2026-01-01T00:00:00Z"},
+                "sandbox_info":{"enabled":true,"image":"ubuntu:latest",
+                    "container_name":"aoe-c3"}}]"#,
+        ];
+        for fixture in fixtures {
+            fs::write(&storage.sessions_path, fixture)?;
+            let loaded = storage.load()?;
+            assert_eq!(loaded.len(), 1, "fixture failed to load: {fixture}");
+            assert!(loaded[0].notes.is_none());
+        }
+        Ok(())
+    }
+
     #[test]
     #[serial]
     fn test_storage_preserves_instance_fields() -> Result<()> {
@@ -1311,6 +1536,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn test_rename_group_moves_nested_sessions_and_groups() -> Result<()> {
+        let temp = tempdir()?;
+        setup_test_home(temp.path());
+
+        let storage = Storage::new_unwatched("test-rename-group")?;
+        storage.update(|instances, groups| {
+            for (title, group) in [("a", "work"), ("b", "work/api"), ("c", "workshop")] {
+                let mut inst = Instance::new(title, "/tmp/x");
+                inst.group_path = group.to_string();
+                instances.push(inst);
+            }
+            let mut tree = GroupTree::new_with_groups(instances, &[]);
+            tree.create_group("work/empty");
+            tree.set_collapsed("work/api", true);
+            *groups = tree.get_all_groups();
+            Ok(())
+        })?;
+
+        assert_eq!(storage.rename_group("work", "projects")?, 2);
+
+        let (instances, groups) = storage.load_with_groups()?;
+        let path_of = |title: &str| {
+            instances
+                .iter()
+                .find(|i| i.title == title)
+                .map(|i| i.group_path.clone())
+                .unwrap()
+        };
+        assert_eq!(path_of("a"), "projects");
+        assert_eq!(path_of("b"), "projects/api");
+        assert_eq!(path_of("c"), "workshop");
+
+        let tree = GroupTree::new_with_groups(&instances, &groups);
+        assert!(tree.group_exists("projects/empty"));
+        assert!(!tree.group_exists("work"));
+        assert!(!tree.group_exists("work/api"));
+        let api = groups.iter().find(|g| g.path == "projects/api").unwrap();
+        assert!(api.collapsed);
+
+        assert!(storage.rename_group("missing", "other").is_err());
+        assert!(storage.rename_group("projects", "workshop").is_err());
+        Ok(())
+    }
+
     #[test]
     #[serial]
     fn test_save_lock_registry_recovers_from_poison() -> Result<()> {
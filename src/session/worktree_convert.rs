@@ -0,0 +1,388 @@
+//! Converting a plain (non-worktree) session into a worktree-backed one.
+//!
+//! A session created against a regular checkout points `project_path` at
+//! that checkout and carries no `worktree_info`. This module performs the
+//! conversion the user asks for later: create a managed worktree for a
+//! chosen branch and hand back the new path plus the `WorktreeInfo` the
+//! caller persists.
+//!
+//! Design notes:
+//!   - The worktree path comes from the same config template as creation
+//!     (`worktree.path_template`, or `bare_repo_path_template` for bare
+//!     repos), so a converted session lands where a freshly created one
+//!     would.
+//!   - An existing branch is checked out as-is; a missing branch is created
+//!     from the resolved base (explicit > per-project default > global
+//!     default > auto-detect), mirroring the new-session flow.
+//!   - Uncommitted changes in the original checkout block the conversion
+//!     unless the caller opts into carrying them over. Carrying uses a
+//!     `git stash push --include-untracked` in the original checkout and a
+//!     `git stash pop` in the new worktree (stashes are shared across the
+//!     worktrees of one repo). If the worktree cannot be created the stash
+//!     is popped back where it came from.
+//!   - The original checkout is never removed: it is the user's own
+//!     directory, not something aoe created.
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+
+use crate::git::error::GitError;
+use crate::git::GitWorktree;
+use crate::session::builder::{
+    git_sanitize_branch_name, project_base_branches, resolve_repo_base_branch,
+};
+use crate::session::config::WorktreeConfig;
+use crate::session::WorktreeInfo;
+
+const STASH_MESSAGE: &str = "aoe: carry changes into new worktree";
+
+/// Inputs for converting a session's checkout into a managed worktree.
+pub struct WorktreeConvertRequest<'a> {
+    /// The session's current `project_path` (a regular checkout).
+    pub current_path: &'a Path,
+    /// Branch to check out in the new worktree (raw; sanitized here).
+    pub branch: &'a str,
+    /// Base ref for a newly created branch. Ignored when `branch` exists.
+    pub base_branch: Option<&'a str>,
+    /// Move uncommitted changes from the original checkout into the new
+    /// worktree instead of refusing.
+    pub carry_changes: bool,
+    /// The session's profile; used to look up per-project base defaults.
+    pub profile: &'a str,
+    /// Resolved worktree settings for the session's profile.
+    pub config: &'a WorktreeConfig,
+    /// Session id; its short prefix seeds `{session-id}` in the template.
+    pub session_id: &'a str,
+}
+
+/// Result of a successful conversion: the values the caller must persist.
+#[derive(Debug)]
+pub struct WorktreeConvertOutcome {
+    /// New worktree directory; assign to `Instance.project_path`.
+    pub new_path: PathBuf,
+    /// Assign to `Instance.worktree_info`.
+    pub worktree_info: WorktreeInfo,
+    /// Non-fatal problems (hook failures, a stash that did not re-apply
+    /// cleanly) to surface to the user.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorktreeConvertError {
+    #[error("the branch name is empty")]
+    EmptyBranch,
+    #[error("{} is not in a git repository", .0.display())]
+    NotAGitRepo(PathBuf),
+    #[error(
+        "{} has {count} uncommitted change(s); commit or stash them, or carry them over into the new worktree",
+        path.display()
+    )]
+    DirtyCheckout { path: PathBuf, count: usize },
+    #[error("a directory already exists at {}", .0.display())]
+    TargetExists(PathBuf),
+    #[error("failed to stash uncommitted changes: {0}")]
+    StashFailed(String),
+    #[error(
+        "worktree creation failed ({create_err}), and restoring the stashed changes also failed ({restore_err}); they are still in `git stash list`"
+    )]
+    RestoreFailed {
+        create_err: String,
+        restore_err: String,
+    },
+    #[error(transparent)]
+    Git(#[from] GitError),
+}
+
+/// Validate and apply a conversion of a plain checkout into a worktree.
+///
+/// On success the worktree exists on disk (with any carried changes
+/// applied); the returned [`WorktreeConvertOutcome`] carries the values the
+/// caller must persist. On error no worktree is left behind and carried
+/// changes are restored to the original checkout.
+pub fn convert_to_worktree(
+    req: WorktreeConvertRequest,
+) -> Result<WorktreeConvertOutcome, WorktreeConvertError> {
+    if req.branch.trim().is_empty() {
+        return Err(WorktreeConvertError::EmptyBranch);
+    }
+    let branch = git_sanitize_branch_name(req.branch.trim());
+
+    if !GitWorktree::is_git_repo(req.current_path) {
+        return Err(WorktreeConvertError::NotAGitRepo(
+            req.current_path.to_path_buf(),
+        ));
+    }
+    let main_repo_raw = GitWorktree::find_main_repo(req.current_path)?;
+    let main_repo = main_repo_raw.canonicalize().unwrap_or(main_repo_raw);
+    let git = GitWorktree::new(main_repo.clone())?
+        .with_init_submodules(req.config.init_submodules)
+        .with_worktree_root(req.config.root_path());
+
+    let dirty = crate::git::cleanup::list_dirty_files(req.current_path).len();
+    if dirty > 0 && !req.carry_changes {
+        return Err(WorktreeConvertError::DirtyCheckout {
+            path: req.current_path.to_path_buf(),
+            count: dirty,
+        });
+    }
+
+    let template = if GitWorktree::is_bare_repo(&main_repo) {
+        &req.config.bare_repo_path_template
+    } else {
+        &req.config.path_template
+    };
+    let short_id = &req.session_id[..req.session_id.len().min(8)];
+    let new_path = git.compute_path(&branch, template, short_id)?;
+    if new_path.exists() {
+        return Err(WorktreeConvertError::TargetExists(new_path));
+    }
+
+    let create_branch = !git.branch_exists(&branch);
+    let base = if create_branch {
+        resolve_repo_base_branch(
+            &main_repo,
+            req.base_branch,
+            &project_base_branches(req.profile),
+            req.config.default_base_branch.as_deref(),
+        )
+    } else {
+        None
+    };
+
+    let stashed = dirty > 0 && stash_push(req.current_path)?;
+
+    let mut warnings = match git.create_worktree(&branch, &new_path, create_branch, base.as_deref())
+    {
+        Ok(w) => w,
+        Err(e) => {
+            if stashed {
+                if let Err(restore_err) = stash_pop(req.current_path) {
+                    return Err(WorktreeConvertError::RestoreFailed {
+                        create_err: e.to_string(),
+                        restore_err,
+                    });
+                }
+            }
+            return Err(e.into());
+        }
+    };
+
+    if stashed {
+        if let Err(e) = stash_pop(&new_path) {
+            tracing::warn!(
+                target: "git.worktree",
+                path = %new_path.display(),
+                "worktree convert: carried changes did not apply cleanly: {e}"
+            );
+            warnings.push(format!(
+                "Uncommitted changes did not apply cleanly in the new worktree ({e}); they remain in `git stash list`"
+            ));
+        }
+    }
+
+    Ok(WorktreeConvertOutcome {
+        new_path,
+        worktree_info: WorktreeInfo {
+            branch,
+            main_repo_path: main_repo.to_string_lossy().to_string(),
+            managed_by_aoe: true,
+            created_at: Utc::now(),
+            base_branch: base,
+        },
+        warnings,
+    })
+}
+
+/// Stash tracked and untracked changes in `path`. Returns whether a stash
+/// entry was created (git exits 0 without one when there is nothing to save).
+/// Detected by comparing the stash tip before and after, since git's
+/// "No local changes to save" message is localized.
+fn stash_push(path: &Path) -> Result<bool, WorktreeConvertError> {
+    let before = stash_tip(path);
+    let output = crate::git::command::run_git(
+        path,
+        ["stash", "push", "--include-untracked", "-m", STASH_MESSAGE],
+    )
+    .map_err(|e| WorktreeConvertError::StashFailed(e.to_string()))?;
+    if !output.status.success() {
+        return Err(WorktreeConvertError::StashFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(stash_tip(path) != before)
+}
+
+/// Commit id of `refs/stash`, or `None` when the stash is empty.
+fn stash_tip(path: &Path) -> Option<String> {
+    let output = crate::git::command::run_git(path, ["rev-parse", "-q", "--verify", "refs/stash"])
+        .ok()
+        .filter(|o| o.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn stash_pop(path: &Path) -> Result<(), String> {
+    let output = crate::git::command::run_git(path, ["stash", "pop"]).map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(status.status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        std::fs::create_dir(&repo).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "t"]);
+        std::fs::write(repo.join("README.md"), "hello\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-q", "-m", "init"]);
+        dir
+    }
+
+    fn config() -> WorktreeConfig {
+        WorktreeConfig {
+            path_template: "../{repo-name}-worktrees/{branch}".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn request<'a>(
+        path: &'a Path,
+        branch: &'a str,
+        carry: bool,
+        config: &'a WorktreeConfig,
+    ) -> WorktreeConvertRequest<'a> {
+        WorktreeConvertRequest {
+            current_path: path,
+            branch,
+            base_branch: None,
+            carry_changes: carry,
+            profile: "default",
+            config,
+            session_id: "abcdef0123456789",
+        }
+    }
+
+    #[test]
+    fn rejects_empty_branch() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        let cfg = config();
+        let err = convert_to_worktree(request(&repo, "  ", false, &cfg)).unwrap_err();
+        assert!(matches!(err, WorktreeConvertError::EmptyBranch));
+    }
+
+    #[test]
+    fn rejects_non_git_directory() {
+        let dir = TempDir::new().unwrap();
+        let cfg = config();
+        let err = convert_to_worktree(request(dir.path(), "feat", false, &cfg)).unwrap_err();
+        assert!(matches!(err, WorktreeConvertError::NotAGitRepo(_)));
+    }
+
+    #[test]
+    fn creates_managed_worktree_on_new_branch() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        let cfg = config();
+        let outcome = convert_to_worktree(request(&repo, "feat", false, &cfg)).unwrap();
+        assert!(outcome.new_path.join("README.md").exists());
+        assert_eq!(outcome.worktree_info.branch, "feat");
+        assert!(outcome.worktree_info.managed_by_aoe);
+        assert_eq!(
+            GitWorktree::get_current_branch(&outcome.new_path).unwrap(),
+            "feat"
+        );
+    }
+
+    #[test]
+    fn dirty_checkout_blocks_without_carry() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        std::fs::write(repo.join("README.md"), "changed\n").unwrap();
+        let cfg = config();
+        let err = convert_to_worktree(request(&repo, "feat", false, &cfg)).unwrap_err();
+        assert!(matches!(
+            err,
+            WorktreeConvertError::DirtyCheckout { count: 1, .. }
+        ));
+        assert!(!GitWorktree::new(repo.clone())
+            .unwrap()
+            .branch_exists("feat"));
+    }
+
+    #[test]
+    fn carry_moves_changes_into_new_worktree() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        std::fs::write(repo.join("README.md"), "changed\n").unwrap();
+        std::fs::write(repo.join("notes.txt"), "untracked\n").unwrap();
+        let cfg = config();
+        let outcome = convert_to_worktree(request(&repo, "feat", true, &cfg)).unwrap();
+        // The test repo has no remote, so only the fetch warning may appear.
+        assert!(
+            !outcome
+                .warnings
+                .iter()
+                .any(|w| w.contains("git stash list")),
+            "{:?}",
+            outcome.warnings
+        );
+        assert_eq!(
+            std::fs::read_to_string(outcome.new_path.join("README.md")).unwrap(),
+            "changed\n"
+        );
+        assert!(outcome.new_path.join("notes.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(repo.join("README.md")).unwrap(),
+            "hello\n"
+        );
+        assert!(!repo.join("notes.txt").exists());
+    }
+
+    #[test]
+    fn stash_push_reports_whether_an_entry_was_created() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        assert!(!stash_push(&repo).unwrap());
+        std::fs::write(repo.join("README.md"), "changed\n").unwrap();
+        assert!(stash_push(&repo).unwrap());
+        // A clean tree with an existing stash entry still creates nothing.
+        assert!(!stash_push(&repo).unwrap());
+    }
+
+    #[test]
+    fn failed_create_restores_carried_changes() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        std::fs::write(repo.join("README.md"), "changed\n").unwrap();
+        let cfg = config();
+        // `main` is checked out in the original checkout, so git refuses to
+        // add a second worktree for it.
+        let err = convert_to_worktree(request(&repo, "main", true, &cfg)).unwrap_err();
+        assert!(matches!(err, WorktreeConvertError::Git(_)), "{err:?}");
+        assert_eq!(
+            std::fs::read_to_string(repo.join("README.md")).unwrap(),
+            "changed\n"
+        );
+    }
+}
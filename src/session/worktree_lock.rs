@@ -0,0 +1,119 @@
+//! Per-session worktree lock (`Instance::worktree_locked`).
+//!
+//! Locking a session runs `git worktree lock` on its worktree, so git itself
+//! refuses to prune or remove the checkout, and sets the session flag that
+//! exempts it from idle auto-stop and makes deletion require an explicit
+//! unlock first. The git lock is what protects the files; the flag is what
+//! aoe's own cleanup paths consult.
+
+use std::path::{Path, PathBuf};
+
+use crate::git::error::GitError;
+use crate::git::GitWorktree;
+use crate::session::Instance;
+
+/// Reason recorded on the git lock so `git worktree list` explains it.
+pub const LOCK_REASON: &str = "locked by aoe";
+
+/// Error surfaced when deletion is attempted on a locked session.
+pub const SESSION_LOCKED_ERROR: &str = "Session is locked; unlock it before deleting";
+
+/// Apply the git side of locking or unlocking `inst`'s worktree. Idempotent:
+/// an already-locked worktree is not relocked. The caller persists
+/// `worktree_locked` once this succeeds.
+///
+/// A worktree directory that is missing from disk fails with
+/// [`GitError::WorktreeNotFound`]. Callers unlocking should still clear the
+/// flag then, since there is no checkout left to protect and a locked
+/// session cannot be deleted.
+pub fn set_worktree_locked(inst: &Instance, locked: bool) -> Result<(), GitError> {
+    let Some(wt_info) = &inst.worktree_info else {
+        return Err(GitError::WorktreeNotFound(PathBuf::from(
+            &inst.project_path,
+        )));
+    };
+    let path = Path::new(&inst.project_path);
+    if !path.exists() {
+        return Err(GitError::WorktreeNotFound(path.to_path_buf()));
+    }
+    let is_locked = GitWorktree::lock_reason(path).is_some();
+    if locked == is_locked {
+        return Ok(());
+    }
+    let git = GitWorktree::new(PathBuf::from(&wt_info.main_repo_path))?;
+    if locked {
+        git.lock_worktree(path, Some(LOCK_REASON))
+    } else {
+        git.unlock_worktree(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::WorktreeInfo;
+
+    #[test]
+    fn lock_round_trip_sets_and_clears_git_lock() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let main_repo = tmp.path().join("main");
+        let worktree_path = tmp.path().join("wt");
+        std::fs::create_dir(&main_repo).unwrap();
+        let repo = git2::Repository::init(&main_repo).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+            .unwrap();
+        GitWorktree::new(main_repo.clone())
+            .unwrap()
+            .create_worktree("feature", &worktree_path, true, None)
+            .unwrap();
+
+        let mut inst = Instance::new("s", worktree_path.to_str().unwrap());
+        inst.worktree_info = Some(WorktreeInfo {
+            branch: "feature".to_string(),
+            main_repo_path: main_repo.to_string_lossy().to_string(),
+            managed_by_aoe: true,
+            created_at: chrono::Utc::now(),
+            base_branch: None,
+        });
+
+        set_worktree_locked(&inst, true).unwrap();
+        assert_eq!(
+            GitWorktree::lock_reason(&worktree_path).as_deref(),
+            Some(LOCK_REASON)
+        );
+        // Locking again is a no-op rather than a git "already locked" error.
+        set_worktree_locked(&inst, true).unwrap();
+
+        set_worktree_locked(&inst, false).unwrap();
+        assert_eq!(GitWorktree::lock_reason(&worktree_path), None);
+    }
+
+    #[test]
+    fn unlocking_a_missing_worktree_reports_not_found() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut inst = Instance::new("s", tmp.path().join("gone").to_str().unwrap());
+        inst.worktree_info = Some(WorktreeInfo {
+            branch: "feature".to_string(),
+            main_repo_path: tmp.path().to_string_lossy().to_string(),
+            managed_by_aoe: true,
+            created_at: chrono::Utc::now(),
+            base_branch: None,
+        });
+        assert!(matches!(
+            set_worktree_locked(&inst, false),
+            Err(GitError::WorktreeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn non_worktree_session_cannot_be_locked() {
+        let inst = Instance::new("s", "/tmp/aoe-lock-test");
+        assert!(matches!(
+            set_worktree_locked(&inst, true),
+            Err(GitError::WorktreeNotFound(_))
+        ));
+    }
+}
@@ -66,6 +66,13 @@ pub struct SoundConfig {
     #[serde(default = "default_volume", skip_serializing_if = "is_default_volume")]
     #[setting(label = "Volume", widget = "custom:sound-volume")]
     pub volume: f64,
+
+    /// Seconds before the same session may replay the sound for the same
+    /// state. Stops a flapping agent from re-alerting on every bounce; the
+    /// first transition into a state always plays. 0 disables the cooldown.
+    #[serde(default = "default_cooldown_seconds")]
+    #[setting(label = "Cooldown (s)", widget = "number", min = 0, advanced)]
+    pub cooldown_seconds: u64,
 }
 
 impl Default for SoundConfig {
@@ -80,6 +87,7 @@ impl Default for SoundConfig {
             on_error: None,
             on_approval: None,
             volume: default_volume(),
+            cooldown_seconds: default_cooldown_seconds(),
         }
     }
 }
@@ -88,6 +96,10 @@ pub(super) fn default_volume() -> f64 {
     1.0
 }
 
+fn default_cooldown_seconds() -> u64 {
+    30
+}
+
 pub(super) fn is_default_volume(v: &f64) -> bool {
     (*v - 1.0).abs() < 1e-9
 }
@@ -125,6 +137,7 @@ mod tests {
         // Fresh installs load `Config::default()` when no config.toml exists;
         // a 0.0 default here would mute all playback on first run.
         assert!((config.volume - 1.0).abs() < 1e-9);
+        assert_eq!(config.cooldown_seconds, 30);
     }
 
     #[test]
@@ -3,11 +3,11 @@
 
 use std::path::PathBuf;
 
-use crate::session::get_app_dir;
+use crate::session::get_config_dir;
 
 /// Get the directory where sound files are stored
 pub fn get_sounds_dir() -> Option<PathBuf> {
-    get_app_dir().ok().map(|d| d.join("sounds"))
+    get_config_dir().ok().map(|d| d.join("sounds"))
 }
 
 /// List available sound files (names with extensions)
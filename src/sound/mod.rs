@@ -26,10 +26,80 @@ pub use config::{volume_from_option, volume_options, volume_to_index, SoundConfi
 pub use discovery::{get_sounds_dir, list_available_sounds, validate_sound_exists};
 pub use playback::{play_sound, play_sound_blocking};
 
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
 use rand::seq::IndexedRandom;
 
 use crate::session::Status;
 
+/// What a transition alert (sound or desktop notification) last saw for
+/// one session: the status it was last told about, and when each state's
+/// alert last fired.
+#[derive(Debug, Default)]
+pub(crate) struct SessionSoundHistory {
+    last_status: Option<Status>,
+    last_played: Vec<(Status, Instant)>,
+}
+
+fn sound_history() -> &'static Mutex<HashMap<String, SessionSoundHistory>> {
+    static HISTORY: OnceLock<Mutex<HashMap<String, SessionSoundHistory>>> = OnceLock::new();
+    HISTORY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Record a transition into `new` and decide whether its sound may play.
+/// Skips when the session was already known to be in `new` (a reload that
+/// replays the same status) or when `new`'s sound played less than
+/// `cooldown` ago. The first transition into a state always plays.
+/// [`crate::notifications`] keeps its own history but shares this rule so
+/// sounds and notifications fire on the same transitions.
+pub(crate) fn should_play(
+    history: &mut HashMap<String, SessionSoundHistory>,
+    session_id: &str,
+    new: Status,
+    cooldown: Duration,
+    now: Instant,
+) -> bool {
+    record_transition(history, session_id, new)
+        && cooldown_elapsed(history, session_id, new, cooldown, now)
+}
+
+/// Remember `new` as the session's last seen status. Returns false when it
+/// already was, i.e. the transition is a replay rather than a change.
+fn record_transition(
+    history: &mut HashMap<String, SessionSoundHistory>,
+    session_id: &str,
+    new: Status,
+) -> bool {
+    let entry = history.entry(session_id.to_string()).or_default();
+    entry.last_status.replace(new) != Some(new)
+}
+
+/// Whether `new`'s sound is outside its cooldown for the session, stamping
+/// it as played now when it is.
+fn cooldown_elapsed(
+    history: &mut HashMap<String, SessionSoundHistory>,
+    session_id: &str,
+    new: Status,
+    cooldown: Duration,
+    now: Instant,
+) -> bool {
+    let entry = history.entry(session_id.to_string()).or_default();
+    let last = entry.last_played.iter_mut().find(|(s, _)| *s == new);
+    match last {
+        Some((_, at)) if now.duration_since(*at) < cooldown => false,
+        Some((_, at)) => {
+            *at = now;
+            true
+        }
+        None => {
+            entry.last_played.push((new, now));
+            true
+        }
+    }
+}
+
 /// Resolve which sound name to play for the given config
 fn resolve_sound_name(override_name: Option<&str>, config: &SoundConfig) -> Option<String> {
     // Per-transition override takes priority
@@ -52,22 +122,60 @@ fn resolve_sound_name(override_name: Option<&str>, config: &SoundConfig) -> Opti
     }
 }
 
-/// Play a sound for a state transition (if enabled and sounds are available)
-pub fn play_for_transition(old: Status, new: Status, config: &SoundConfig) {
-    if !config.enabled || old == new {
+/// The per-transition sound override for `new`, or `None` for states that
+/// never play a sound.
+fn transition_override(new: Status, config: &SoundConfig) -> Option<Option<&str>> {
+    match new {
+        Status::Starting => Some(config.on_start.as_deref()),
+        Status::Running => Some(config.on_running.as_deref()),
+        Status::Waiting => Some(config.on_waiting.as_deref()),
+        Status::Idle => Some(config.on_idle.as_deref()),
+        Status::Error => Some(config.on_error.as_deref()),
+        Status::Unknown | Status::Stopped | Status::Deleting | Status::Creating => None,
+    }
+}
+
+/// Decide whether a transition into `new` plays a sound, returning its
+/// override when it does.
+fn sound_for_transition<'a>(
+    history: &mut HashMap<String, SessionSoundHistory>,
+    session_id: &str,
+    new: Status,
+    config: &'a SoundConfig,
+    muted: bool,
+    now: Instant,
+) -> Option<Option<&'a str>> {
+    // Record every transition, including silent, disabled and muted ones,
+    // so a session that leaves Waiting and comes back is seen as a fresh
+    // transition.
+    if !record_transition(history, session_id, new) || !config.enabled || muted {
+        return None;
+    }
+    let override_name = transition_override(new, config)?;
+    let cooldown = Duration::from_secs(config.cooldown_seconds);
+    cooldown_elapsed(history, session_id, new, cooldown, now).then_some(override_name)
+}
+
+/// Play a sound for a session's state transition (if enabled and not
+/// `muted`, sounds are available, and the state isn't inside its cooldown
+/// for this session)
+pub fn play_for_transition(
+    session_id: &str,
+    old: Status,
+    new: Status,
+    config: &SoundConfig,
+    muted: bool,
+) {
+    if old == new {
         return;
     }
 
-    let override_name = match new {
-        Status::Starting => config.on_start.as_deref(),
-        Status::Running => config.on_running.as_deref(),
-        Status::Waiting => config.on_waiting.as_deref(),
-        Status::Idle => config.on_idle.as_deref(),
-        Status::Error => config.on_error.as_deref(),
-        Status::Unknown => return,
-        Status::Stopped => return,
-        Status::Deleting => return,
-        Status::Creating => return,
+    let override_name = {
+        let mut history = sound_history().lock().unwrap_or_else(|e| e.into_inner());
+        match sound_for_transition(&mut history, session_id, new, config, muted, Instant::now()) {
+            Some(override_name) => override_name,
+            None => return,
+        }
     };
 
     if let Some(name) = resolve_sound_name(override_name, config) {
@@ -109,11 +217,102 @@ mod tests {
         assert_eq!(result, Some("wololo".to_string()));
     }
 
+    #[test]
+    fn test_first_transition_plays_then_cooldown_suppresses_replay() {
+        let mut history = HashMap::new();
+        let cooldown = Duration::from_secs(30);
+        let t0 = Instant::now();
+
+        assert!(should_play(
+            &mut history,
+            "a",
+            Status::Waiting,
+            cooldown,
+            t0
+        ));
+        assert!(should_play(
+            &mut history,
+            "a",
+            Status::Running,
+            cooldown,
+            t0
+        ));
+        // Flapping back into Waiting inside the cooldown stays silent.
+        let t1 = t0 + Duration::from_secs(5);
+        assert!(!should_play(
+            &mut history,
+            "a",
+            Status::Waiting,
+            cooldown,
+            t1
+        ));
+        assert!(!should_play(
+            &mut history,
+            "a",
+            Status::Running,
+            cooldown,
+            t1
+        ));
+        // Another session keeps its own cooldown.
+        assert!(should_play(
+            &mut history,
+            "b",
+            Status::Waiting,
+            cooldown,
+            t1
+        ));
+        // Once the cooldown elapses the state sound plays again.
+        let t2 = t0 + Duration::from_secs(31);
+        assert!(should_play(
+            &mut history,
+            "a",
+            Status::Waiting,
+            cooldown,
+            t2
+        ));
+    }
+
+    #[test]
+    fn test_unchanged_status_does_not_replay() {
+        let mut history = HashMap::new();
+        let t0 = Instant::now();
+        assert!(should_play(
+            &mut history,
+            "a",
+            Status::Waiting,
+            Duration::ZERO,
+            t0
+        ));
+        // A reload reporting the same status again is not a new transition,
+        // even with the cooldown disabled.
+        assert!(!should_play(
+            &mut history,
+            "a",
+            Status::Waiting,
+            Duration::ZERO,
+            t0
+        ));
+        assert!(should_play(
+            &mut history,
+            "a",
+            Status::Idle,
+            Duration::ZERO,
+            t0
+        ));
+        assert!(should_play(
+            &mut history,
+            "a",
+            Status::Waiting,
+            Duration::ZERO,
+            t0
+        ));
+    }
+
     #[test]
     fn test_play_for_transition_disabled() {
         let config = SoundConfig::default();
         // Should not panic even when disabled
-        play_for_transition(Status::Idle, Status::Running, &config);
+        play_for_transition("test", Status::Idle, Status::Running, &config, false);
     }
 
     #[test]
@@ -124,7 +323,7 @@ mod tests {
             ..Default::default()
         };
         // Same status - should be a no-op
-        play_for_transition(Status::Running, Status::Running, &config);
+        play_for_transition("test", Status::Running, Status::Running, &config, false);
     }
 
     #[test]
@@ -135,6 +334,27 @@ mod tests {
             ..Default::default()
         };
         // Deleting transitions should be skipped
-        play_for_transition(Status::Running, Status::Deleting, &config);
+        play_for_transition("test", Status::Running, Status::Deleting, &config, false);
+    }
+
+    #[test]
+    fn test_waiting_plays_again_after_a_silent_status() {
+        let config = SoundConfig {
+            enabled: true,
+            mode: SoundMode::Specific("wololo".to_string()),
+            cooldown_seconds: 0,
+            ..Default::default()
+        };
+        let mut history = HashMap::new();
+        let now = Instant::now();
+        let mut sounds = |new, muted| {
+            sound_for_transition(&mut history, "s", new, &config, muted, now).is_some()
+        };
+        assert!(sounds(Status::Waiting, false));
+        assert!(!sounds(Status::Stopped, false));
+        assert!(sounds(Status::Waiting, false));
+        // A transition seen while muted still counts as leaving Waiting.
+        assert!(!sounds(Status::Running, true));
+        assert!(sounds(Status::Waiting, false));
     }
 }
@@ -12,7 +12,7 @@ pub(crate) mod utils;
 
 pub use session::{PaneCursor, Session};
 pub use status_bar::{get_session_info_for_current, get_status_for_current_session};
-pub use status_detection::detect_status_from_content;
+pub use status_detection::{detect_status_for_profile, detect_status_from_content};
 pub(crate) use status_detection::{reconcile_claude_hook_status, reconcile_codex_hook_status};
 pub use terminal_session::{ContainerTerminalSession, TerminalSession};
 pub use tool_session::{kill_all_tool_sessions_for_id, ToolSession};
@@ -83,6 +83,7 @@ const FIELD_SEP: char = '|';
 
 pub fn refresh_session_cache() {
     let start = Instant::now();
+    note_existence_probe();
     let output = Command::new("tmux")
         .args(["list-sessions", "-F", "#{session_name}|#{session_activity}"])
         .output();
@@ -124,15 +125,43 @@ pub fn refresh_session_cache() {
         "session cache refreshed",
     );
 
+    store_session_cache(new_data);
+}
+
+fn store_session_cache(data: Option<HashMap<String, i64>>) {
     if let Ok(mut cache) = SESSION_CACHE.write() {
-        cache.data = new_data;
+        cache.data = data;
         cache.time = Some(Instant::now());
     }
 }
 
+#[cfg(test)]
+thread_local! {
+    static EXISTENCE_PROBES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Count a tmux subprocess spawned to learn which sessions exist. Test-only
+/// bookkeeping so poll passes can assert they stay at one probe.
+fn note_existence_probe() {
+    #[cfg(test)]
+    EXISTENCE_PROBES.with(|n| n.set(n.get() + 1));
+}
+
+/// Existence probes spawned so far on the calling thread.
+#[cfg(test)]
+pub(crate) fn existence_probes() -> usize {
+    EXISTENCE_PROBES.with(|n| n.get())
+}
+
 /// Batch-fetch pane metadata for all aoe sessions in a single tmux subprocess call.
 /// Returns a map from session name to metadata for the first window's first pane.
 ///
+/// The same `list-panes -a` output lists every session on the server, so it
+/// also refreshes the session cache: a poll pass that calls this needs no
+/// separate `refresh_session_cache`, and `session_exists` answers from the
+/// cache for every instance afterwards. One subprocess per pass regardless
+/// of session count.
+///
 /// Returns `Err` when the underlying `tmux list-panes` call fails to spawn or
 /// exits non-zero. Callers MUST distinguish this from `Ok(map)` where a missing
 /// key means the session is genuinely absent: `Err` means we don't know.
@@ -141,18 +170,20 @@ pub fn refresh_session_cache() {
 /// `unwrap_or_default()` because their semantics are unchanged by an empty map.
 pub fn batch_pane_metadata() -> anyhow::Result<HashMap<String, PaneMetadata>> {
     let start = Instant::now();
+    note_existence_probe();
     let output = Command::new("tmux")
         .args([
             "list-panes",
             "-a",
             "-F",
-            "#{session_name}|#{pane_index}|#{pane_dead}|#{pane_current_command}",
+            "#{session_name}|#{pane_index}|#{pane_dead}|#{pane_current_command}|#{session_activity}",
         ])
         .output();
 
     let result: anyhow::Result<HashMap<String, PaneMetadata>> = match output {
         Ok(out) if out.status.success() => {
             let stdout = String::from_utf8_lossy(&out.stdout);
+            store_session_cache(Some(parse_session_activity(&stdout)));
             Ok(parse_pane_metadata(&stdout))
         }
         Ok(out) => {
@@ -162,6 +193,7 @@ pub fn batch_pane_metadata() -> anyhow::Result<HashMap<String, PaneMetadata>> {
                 stderr_bytes = out.stderr.len(),
                 "list-panes returned non-zero",
             );
+            store_session_cache(None);
             Err(anyhow::anyhow!(
                 "tmux list-panes returned non-zero status: {:?}",
                 out.status
@@ -169,6 +201,7 @@ pub fn batch_pane_metadata() -> anyhow::Result<HashMap<String, PaneMetadata>> {
         }
         Err(e) => {
             tracing::warn!(target: "tmux.pane", error = %e, "list-panes spawn failed");
+            store_session_cache(None);
             Err(anyhow::anyhow!("tmux list-panes spawn failed: {}", e))
         }
     };
@@ -231,6 +264,103 @@ pub fn attached_session_names() -> anyhow::Result<HashSet<String>> {
     }
 }
 
+/// One pane of a tmux session, as reported by `tmux list-panes -s`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneInfo {
+    pub window_index: u32,
+    pub pane_index: u32,
+    /// `#{pane_current_command}`: the foreground process, e.g. `claude` or
+    /// `zsh`.
+    pub command: String,
+    /// The pane an attaching client lands on: the active pane of the
+    /// session's active window.
+    pub active: bool,
+    pub title: String,
+}
+
+impl PaneInfo {
+    /// Whether the pane is sitting at a shell prompt rather than running an
+    /// agent or other program.
+    pub fn is_shell(&self) -> bool {
+        utils::is_shell_command(&self.command)
+    }
+}
+
+/// Every pane in every window of `session_name`, in window then pane order.
+/// Read-only; an unknown session or a tmux failure yields an empty list,
+/// since callers only use this for display.
+pub fn list_panes(session_name: &str) -> Vec<PaneInfo> {
+    let output = Command::new("tmux")
+        .args([
+            "list-panes",
+            "-s",
+            "-t",
+            session_name,
+            "-F",
+            "#{window_index}|#{pane_index}|#{window_active}|#{pane_active}|#{pane_current_command}|#{pane_title}",
+        ])
+        .output();
+
+    match output {
+        Ok(out) if out.status.success() => parse_pane_list(&String::from_utf8_lossy(&out.stdout)),
+        Ok(out) => {
+            tracing::debug!(
+                target: "tmux.pane",
+                session = session_name,
+                status = ?out.status,
+                "list-panes (session) returned non-zero",
+            );
+            Vec::new()
+        }
+        Err(e) => {
+            tracing::debug!(target: "tmux.pane", error = %e, "list-panes (session) spawn failed");
+            Vec::new()
+        }
+    }
+}
+
+/// Parse `list_panes` output. The title is the last field and may itself
+/// contain the separator, so only the first five are split off.
+fn parse_pane_list(output: &str) -> Vec<PaneInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(6, FIELD_SEP);
+            let window_index = parts.next()?.parse().ok()?;
+            let pane_index = parts.next()?.parse().ok()?;
+            let window_active = parts.next()? == "1";
+            let pane_active = parts.next()? == "1";
+            let command = parts.next()?.to_string();
+            let title = parts.next().unwrap_or_default().to_string();
+            Some(PaneInfo {
+                window_index,
+                pane_index,
+                command,
+                active: window_active && pane_active,
+                title,
+            })
+        })
+        .collect()
+}
+
+/// Session name to `#{session_activity}` for every session in a
+/// `tmux list-panes -a` listing, the same shape `refresh_session_cache`
+/// builds from `list-sessions`. The activity field is the last one.
+fn parse_session_activity(output: &str) -> HashMap<String, i64> {
+    let mut map = HashMap::new();
+    for line in output.lines() {
+        let Some((name, rest)) = line.split_once(FIELD_SEP) else {
+            continue;
+        };
+        let activity = rest
+            .rsplit_once(FIELD_SEP)
+            .and_then(|(_, a)| a.parse().ok())
+            .unwrap_or(0);
+        map.entry(name.to_string()).or_insert(activity);
+    }
+    map
+}
+
 /// Parse the output of `tmux list-panes -a` into a map of session name to pane metadata.
 /// Filters to aoe sessions, pane index 0, and takes only the first window per session.
 fn parse_pane_metadata(output: &str) -> HashMap<String, PaneMetadata> {
@@ -305,6 +435,21 @@ pub fn session_exists_from_cache(name: &str) -> Option<bool> {
     cache.data.as_ref().map(|m| m.contains_key(name))
 }
 
+/// Whether the tmux session `name` exists. Answers from the session cache
+/// while it is warm and only spawns `tmux has-session` when it is not.
+pub(crate) fn session_exists(name: &str) -> bool {
+    if let Some(exists) = session_exists_from_cache(name) {
+        return exists;
+    }
+
+    note_existence_probe();
+    Command::new("tmux")
+        .args(["has-session", "-t", name])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
 pub fn get_current_session_name() -> Option<String> {
     let output = Command::new("tmux")
         .args(["display-message", "-p", "#{session_name}"])
@@ -374,18 +519,81 @@ pub(crate) fn is_agent_available(agent: &crate::agents::AgentDef) -> bool {
     }
 }
 
+/// How long a built-in agent probe result is reused. Probing spawns one
+/// `which`/`--version` (plus a login-shell fallback) per agent, which is slow
+/// enough to notice on every dialog open; installs rarely change mid-session.
+const TOOL_DETECTION_TTL: Duration = Duration::from_secs(60);
+
+static TOOL_DETECTION_CACHE: ToolDetectionCache = ToolDetectionCache::new();
+
+/// Memoized list of built-in agents found on this machine, in the same
+/// spirit as `SESSION_CACHE`. Custom agents are not cached; they come from
+/// config, which is cheap to read and may change between calls.
+struct ToolDetectionCache {
+    entry: RwLock<Option<(Vec<String>, Instant)>>,
+}
+
+impl ToolDetectionCache {
+    const fn new() -> Self {
+        Self {
+            entry: RwLock::new(None),
+        }
+    }
+
+    /// Return the cached list while it is younger than `ttl`, otherwise run
+    /// `probe` and store its result. `force` skips the cache.
+    fn get_or_detect(
+        &self,
+        ttl: Duration,
+        force: bool,
+        probe: impl FnOnce() -> Vec<String>,
+    ) -> Vec<String> {
+        if !force {
+            if let Ok(entry) = self.entry.read() {
+                if let Some((tools, at)) = entry.as_ref() {
+                    if at.elapsed() < ttl {
+                        return tools.clone();
+                    }
+                }
+            }
+        }
+        let tools = probe();
+        if let Ok(mut entry) = self.entry.write() {
+            *entry = Some((tools.clone(), Instant::now()));
+        }
+        tools
+    }
+}
+
+fn probe_builtin_agents() -> Vec<String> {
+    crate::agents::AGENTS
+        .iter()
+        .filter(|a| is_agent_available(a))
+        .map(|a| a.name.to_string())
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct AvailableTools {
     available: Vec<String>,
 }
 
 impl AvailableTools {
+    /// Detect installed agents, reusing a recent probe of the built-in
+    /// agents (see `TOOL_DETECTION_TTL`).
     pub fn detect() -> Self {
-        let mut available: Vec<String> = crate::agents::AGENTS
-            .iter()
-            .filter(|a| is_agent_available(a))
-            .map(|a| a.name.to_string())
-            .collect();
+        Self::detect_with(false)
+    }
+
+    /// Like `detect`, but always re-probes. For explicit "check again"
+    /// actions after the user has installed an agent.
+    pub fn redetect() -> Self {
+        Self::detect_with(true)
+    }
+
+    fn detect_with(force: bool) -> Self {
+        let mut available =
+            TOOL_DETECTION_CACHE.get_or_detect(TOOL_DETECTION_TTL, force, probe_builtin_agents);
 
         // Append user-defined custom agents (always considered available since the
         // command may target a remote host or a wrapper script).
@@ -426,6 +634,28 @@ mod tests {
     use super::test_helpers::TmuxTestSession;
     use super::*;
 
+    #[test]
+    fn tool_detection_cache_reuses_fresh_results_and_honors_force() {
+        let cache = ToolDetectionCache::new();
+        let probes = std::cell::Cell::new(0);
+        let probe = || {
+            probes.set(probes.get() + 1);
+            vec!["claude".to_string()]
+        };
+        let ttl = Duration::from_secs(60);
+
+        assert_eq!(cache.get_or_detect(ttl, false, probe), vec!["claude"]);
+        assert_eq!(cache.get_or_detect(ttl, false, probe), vec!["claude"]);
+        assert_eq!(probes.get(), 1);
+
+        cache.get_or_detect(ttl, true, probe);
+        assert_eq!(probes.get(), 2);
+
+        // An expired entry is re-probed.
+        cache.get_or_detect(Duration::ZERO, false, probe);
+        assert_eq!(probes.get(), 3);
+    }
+
     // Session names embed `SESSION_PREFIX`, which differs between release
     // (`aoe_`) and debug (`aoe_dev_`) builds. Use the constant so the same
     // test bodies cover both.
@@ -478,6 +708,17 @@ mod tests {
         assert_eq!(meta.pane_current_command.as_deref(), Some("claude"));
     }
 
+    #[test]
+    fn test_parse_session_activity_lists_every_session() {
+        let output = format!(
+            "{P}proj_abc12345|0|0|claude|1700000000\n{P}proj_abc12345|1|0|bash|1700000000\nuser_session|0|0|vim|1690000000\n"
+        );
+        let map = parse_session_activity(&output);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&format!("{P}proj_abc12345")), Some(&1_700_000_000));
+        assert_eq!(map.get("user_session"), Some(&1_690_000_000));
+    }
+
     #[test]
     fn test_parse_pane_metadata_empty_output() {
         assert!(parse_pane_metadata("").is_empty());
@@ -522,6 +763,24 @@ mod tests {
         assert!(map.get(&format!("{P}proj_c_ghi11111")).unwrap().pane_dead);
     }
 
+    #[test]
+    fn test_parse_pane_list_marks_the_focused_pane() {
+        let panes = parse_pane_list(
+            "0|0|1|0|claude|agent\n0|1|1|1|zsh|host|name\n1|0|0|1|-bash|\nnot|a|pane\n",
+        );
+        assert_eq!(panes.len(), 3);
+        assert_eq!(panes[0].command, "claude");
+        assert!(!panes[0].is_shell());
+        assert!(!panes[0].active);
+        // Active pane of the active window; the title keeps its separator.
+        assert!(panes[1].active);
+        assert_eq!(panes[1].title, "host|name");
+        // Active pane of an inactive window is not where a client lands.
+        assert_eq!((panes[2].window_index, panes[2].pane_index), (1, 0));
+        assert!(!panes[2].active);
+        assert!(panes[2].is_shell(), "login shells report a leading dash");
+    }
+
     fn tmux_available() -> bool {
         Command::new("tmux")
             .arg("-V")
@@ -6,10 +6,11 @@ use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use super::{
-    refresh_session_cache, session_exists_from_cache,
+    refresh_session_cache, session_exists,
     utils::{
         append_clipboard_passthrough_args, append_mouse_on_args, append_pane_base_index_args,
-        append_remain_on_exit_args, append_window_size_args, is_pane_dead, is_pane_running_shell,
+        append_remain_on_exit_setting_args, append_window_size_args, is_pane_dead,
+        is_pane_running_shell,
     },
     SESSION_PREFIX,
 };
@@ -94,15 +95,7 @@ impl Session {
     }
 
     pub fn exists(&self) -> bool {
-        if let Some(exists) = session_exists_from_cache(&self.name) {
-            return exists;
-        }
-
-        Command::new("tmux")
-            .args(["has-session", "-t", &self.name])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false)
+        session_exists(&self.name)
     }
 
     pub fn create(&self, working_dir: &str, command: Option<&str>) -> Result<()> {
@@ -114,13 +107,27 @@ impl Session {
         working_dir: &str,
         command: Option<&str>,
         size: Option<(u16, u16)>,
+    ) -> Result<()> {
+        self.create_with_exit(working_dir, command, size, true)
+    }
+
+    /// Like `create_with_size`, choosing what the pane does when its command
+    /// exits: `hold_on_exit` keeps the dead pane (`remain-on-exit on`), and
+    /// otherwise the session closes. Both are set in the `new-session`
+    /// invocation itself, so a command that exits at once cannot race them.
+    pub fn create_with_exit(
+        &self,
+        working_dir: &str,
+        command: Option<&str>,
+        size: Option<(u16, u16)>,
+        hold_on_exit: bool,
     ) -> Result<()> {
         if self.exists() {
             return Ok(());
         }
 
         let mut args = build_create_args(&self.name, working_dir, command, size);
-        append_remain_on_exit_args(&mut args, &self.name);
+        append_remain_on_exit_setting_args(&mut args, &self.name, hold_on_exit);
         append_pane_base_index_args(&mut args, &self.name);
         append_mouse_on_args(&mut args, &self.name);
         append_window_size_args(&mut args, &self.name);
@@ -209,6 +216,27 @@ impl Session {
         Ok(true)
     }
 
+    /// Replace the first window's pane process with `command`, live or
+    /// not, keeping the tmux session (and any extra windows) intact.
+    /// `respawn-pane -k` sends SIGHUP to the old process group before
+    /// starting the new one.
+    pub fn respawn_pane(&self, working_dir: &str, command: Option<&str>) -> Result<()> {
+        let target = format!("{}:^.0", self.name);
+        let mut args = vec!["respawn-pane", "-k", "-t", &target, "-c", working_dir];
+        if let Some(cmd) = command {
+            args.push(cmd);
+        }
+
+        let output = Command::new("tmux").args(&args).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to respawn pane: {}", stderr);
+        }
+
+        super::refresh_session_cache();
+        Ok(())
+    }
+
     pub fn kill(&self) -> Result<()> {
         if !self.exists() {
             return Ok(());
@@ -435,10 +463,10 @@ impl Session {
         process::get_foreground_pid(pane_pid).or(Some(pane_pid))
     }
 
-    pub fn detect_status(&self, tool: &str) -> Result<Status> {
+    pub fn detect_status(&self, tool: &str, profile: &str) -> Result<Status> {
         let content = self.capture_pane(50)?;
-        Ok(super::status_detection::detect_status_from_content(
-            &content, tool,
+        Ok(super::status_detection::detect_status_for_profile(
+            &content, tool, profile,
         ))
     }
 
@@ -566,6 +594,55 @@ impl Session {
             .output();
     }
 
+    /// Size the window to the attaching terminal (`cols`x`rows`) just before
+    /// an attach, so an agent that draws its own TUI renders at the client's
+    /// geometry on the first frame instead of waiting on a SIGWINCH reflow
+    /// from whichever terminal it was last attached from.
+    ///
+    /// `resize-window` pins the window to `manual` sizing, so on success the
+    /// `window-size latest` policy is restored right after; later resizes of
+    /// the attached terminal still drive the window. tmux < 2.9 has neither
+    /// `resize-window` nor `window-size`, so when the resize fails we enable
+    /// `aggressive-resize` instead, which makes the window follow the active
+    /// client on those versions. Best-effort like
+    /// [`resize_window`](Self::resize_window): errors never block the attach.
+    pub fn resize(&self, cols: u16, rows: u16) {
+        if cols == 0 || rows == 0 || !self.exists() {
+            return;
+        }
+        let resized = Command::new("tmux")
+            .args([
+                "resize-window",
+                "-t",
+                &self.name,
+                "-x",
+                &cols.to_string(),
+                "-y",
+                &rows.to_string(),
+            ])
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if resized {
+            self.reset_size_to_latest_client();
+            return;
+        }
+        tracing::debug!(
+            target: "tmux.command",
+            session = %self.name,
+            "resize-window unavailable; falling back to aggressive-resize"
+        );
+        let _ = Command::new("tmux")
+            .args([
+                "set-window-option",
+                "-q",
+                "-t",
+                &self.name,
+                "aggressive-resize",
+                "on",
+            ])
+            .output();
+    }
+
     /// Deliver `text` to `target` via tmux's load-buffer + paste-buffer.
     /// Buffer names are scoped by pid + a per-call counter so concurrent
     /// senders (and retries) cannot clobber each other. `-p` enables
@@ -781,6 +858,40 @@ mod tests {
         assert!(PaneCursor::parse("a b c d").is_none());
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn create_with_exit_holds_or_closes_an_instantly_exiting_pane() {
+        if !tmux_available() {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+
+        // `true` exits before any follow-up tmux call could land, so this
+        // only passes when the option is set in the `new-session` itself.
+        let held = TmuxTestSession::new("aoe_test_exit_hold");
+        let closed = TmuxTestSession::new("aoe_test_exit_close");
+        let held_session = Session::from_name(held.name());
+        let closed_session = Session::from_name(closed.name());
+        held_session
+            .create_with_exit("/tmp", Some("true"), None, true)
+            .unwrap();
+        closed_session
+            .create_with_exit("/tmp", Some("true"), None, false)
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+
+        let has_session = |name: &str| {
+            Command::new("tmux")
+                .args(["has-session", "-t", name])
+                .output()
+                .map(|o| o.status.success())
+                .unwrap_or(false)
+        };
+        assert!(has_session(held.name()));
+        assert!(held_session.is_pane_dead());
+        assert!(!has_session(closed.name()));
+    }
+
     #[test]
     #[serial_test::serial]
     fn capture_pane_with_cursor_returns_content_and_cursor() {
@@ -845,6 +956,58 @@ mod tests {
         );
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_resize_sizes_window_and_restores_latest_policy() {
+        if !tmux_available() {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+
+        let guard = TmuxTestSession::new("aoe_test_resize");
+        let name = guard.name().to_string();
+        let status = Command::new("tmux")
+            .args([
+                "new-session",
+                "-d",
+                "-s",
+                &name,
+                "-x",
+                "40",
+                "-y",
+                "10",
+                "sleep 60",
+            ])
+            .status()
+            .expect("tmux new-session");
+        assert!(status.success());
+
+        let session = Session::from_name(&name);
+        session.resize(100, 30);
+
+        let output = Command::new("tmux")
+            .args([
+                "display-message",
+                "-t",
+                &name,
+                "-p",
+                "#{window_width}x#{window_height}",
+            ])
+            .output()
+            .expect("tmux display-message");
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "100x30");
+
+        let output = Command::new("tmux")
+            .args(["show-options", "-v", "-t", &name, "window-size"])
+            .output()
+            .expect("tmux show-options");
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "latest",
+            "resize must not leave the window pinned to manual sizing"
+        );
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_remain_on_exit_and_pane_dead() {
@@ -949,6 +1112,47 @@ mod tests {
         assert!(!pane_dead, "Pane should be alive while command is running");
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_respawn_pane_replaces_live_process_in_same_session() {
+        if !tmux_available() {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+
+        let guard = TmuxTestSession::new("aoe_test_respawn_live");
+        let session_name = guard.name().to_string();
+        let output = Command::new("tmux")
+            .args([
+                "new-session",
+                "-d",
+                "-s",
+                &session_name,
+                "-x",
+                "80",
+                "-y",
+                "24",
+                "sleep 30",
+            ])
+            .output()
+            .expect("tmux new-session");
+        assert!(output.status.success());
+
+        let session = Session {
+            name: session_name.clone(),
+        };
+        let old_pid = session.get_pane_pid().expect("pane pid");
+        session
+            .respawn_pane("/tmp", Some("sleep 31"))
+            .expect("respawn live pane");
+        std::thread::sleep(std::time::Duration::from_millis(200));
+
+        assert!(session.exists(), "respawn must keep the tmux session");
+        assert!(!session.is_pane_dead());
+        let new_pid = session.get_pane_pid().expect("pane pid after respawn");
+        assert_ne!(old_pid, new_pid, "the old process must be replaced");
+    }
+
     /// Regression test for #435: with multiple tmux windows, pane health
     /// checks must target window 0 pane 0 explicitly so that a dead pane in
     /// a second window does not cause the agent pane to be killed.
@@ -1,5 +1,11 @@
 //! Status detection for agent sessions
 
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+use regex::Regex;
+
 use crate::session::Status;
 
 use super::utils::strip_ansi;
@@ -80,14 +86,168 @@ fn matches_input_prompt(non_empty_lines: &[&str], take_n: usize, tool_prompts: &
     false
 }
 
+/// Detect status with the built-in heuristics and patterns only. Use
+/// [`detect_status_for_profile`] to also apply the user's configured
+/// patterns.
 pub fn detect_status_from_content(content: &str, tool: &str) -> Status {
+    detect_status_with_patterns(content, tool, None)
+}
+
+/// Detect status, layering `profile`'s `session.agent_waiting_patterns` /
+/// `session.agent_error_patterns` over the built-in ones.
+pub fn detect_status_for_profile(content: &str, tool: &str, profile: &str) -> Status {
+    let configured = configured_status_patterns(profile);
+    detect_status_with_patterns(content, tool, configured.get(tool))
+}
+
+fn detect_status_with_patterns(
+    content: &str,
+    tool: &str,
+    user: Option<&CompiledStatusPatterns>,
+) -> Status {
     // Strip ANSI escape codes before passing to detectors. capture-pane is
     // called with -e (to preserve colors for the TUI preview), but color codes
     // interspersed in text like "esc interrupt" break plain substring matches.
     let clean = strip_ansi(content);
-    crate::agents::get_agent(tool)
+    let agent = crate::agents::get_agent(tool);
+    let detected = agent
         .map(|a| (a.detect_status)(&clean))
-        .unwrap_or(Status::Idle)
+        .unwrap_or(Status::Idle);
+
+    let builtin = agent.map(|a| builtin_status_patterns(a.name));
+    let waiting = user
+        .and_then(|p| p.waiting.as_ref())
+        .or_else(|| builtin.and_then(|p| p.waiting.as_ref()));
+    let error = user
+        .and_then(|p| p.error.as_ref())
+        .or_else(|| builtin.and_then(|p| p.error.as_ref()));
+    apply_status_patterns(detected, &clean, waiting, error)
+}
+
+/// How many trailing non-empty pane lines the waiting/error patterns see.
+/// Kept short so an error that has scrolled up past a fresh prompt no longer
+/// pins the session to Error.
+const STATUS_PATTERN_WINDOW: usize = 15;
+
+/// Layer an agent's waiting/error regexes over the heuristic result. Live
+/// activity wins (a Running detection is never overridden); otherwise an
+/// error match reports Error and a waiting match reports Waiting.
+pub fn apply_status_patterns(
+    detected: Status,
+    content: &str,
+    waiting: Option<&Regex>,
+    error: Option<&Regex>,
+) -> Status {
+    if detected == Status::Running || (waiting.is_none() && error.is_none()) {
+        return detected;
+    }
+    let non_empty: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    let start = non_empty.len().saturating_sub(STATUS_PATTERN_WINDOW);
+    let recent = non_empty[start..].join("\n");
+    if error.is_some_and(|re| re.is_match(&recent)) {
+        return Status::Error;
+    }
+    if waiting.is_some_and(|re| re.is_match(&recent)) {
+        return Status::Waiting;
+    }
+    detected
+}
+
+#[derive(Debug, Default)]
+struct CompiledStatusPatterns {
+    waiting: Option<Regex>,
+    error: Option<Regex>,
+}
+
+fn compile_pattern(pattern: Option<&str>) -> Option<Regex> {
+    // An empty regex matches every pane, so treat it as unset.
+    pattern
+        .filter(|p| !p.trim().is_empty())
+        .and_then(|p| Regex::new(p).ok())
+}
+
+fn builtin_status_patterns(agent: &str) -> &'static CompiledStatusPatterns {
+    static BUILTIN: OnceLock<HashMap<&'static str, CompiledStatusPatterns>> = OnceLock::new();
+    static EMPTY: CompiledStatusPatterns = CompiledStatusPatterns {
+        waiting: None,
+        error: None,
+    };
+    BUILTIN
+        .get_or_init(|| {
+            crate::agents::AGENTS
+                .iter()
+                .map(|a| {
+                    let compiled = CompiledStatusPatterns {
+                        waiting: compile_pattern(a.status_patterns.waiting),
+                        error: compile_pattern(a.status_patterns.error),
+                    };
+                    (a.name, compiled)
+                })
+                .collect()
+        })
+        .get(agent)
+        .unwrap_or(&EMPTY)
+}
+
+type UserStatusPatterns = Arc<HashMap<String, CompiledStatusPatterns>>;
+
+/// Modification times of the global and profile config files. Patterns are
+/// only reloaded when one of them changes, so an edit reaches running
+/// sessions without a restart while the status poll stays a pair of stats.
+type ConfigStamp = (Option<SystemTime>, Option<SystemTime>);
+
+fn config_stamp(profile: &str) -> ConfigStamp {
+    let mtime = |path: anyhow::Result<std::path::PathBuf>| {
+        path.ok()
+            .and_then(|p| std::fs::metadata(p).ok())
+            .and_then(|m| m.modified().ok())
+    };
+    (
+        mtime(crate::session::config::config_path()),
+        mtime(
+            crate::session::get_profile_config_dir_path(profile).map(|dir| dir.join("config.toml")),
+        ),
+    )
+}
+
+/// Compiled `session.agent_waiting_patterns` / `session.agent_error_patterns`
+/// for `profile`, keyed by agent name. Cached per profile until its config
+/// files change.
+fn configured_status_patterns(profile: &str) -> UserStatusPatterns {
+    static CACHE: OnceLock<Mutex<HashMap<String, (ConfigStamp, UserStatusPatterns)>>> =
+        OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+    let stamp = config_stamp(profile);
+    if let Some((loaded, patterns)) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(profile) {
+        if *loaded == stamp {
+            return Arc::clone(patterns);
+        }
+    }
+
+    // Load outside the lock so a slow config read doesn't stall other
+    // sessions' polls.
+    let patterns = Arc::new(compile_user_patterns(
+        &crate::session::resolve_config_or_warn(profile),
+    ));
+    cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(profile.to_string(), (stamp, Arc::clone(&patterns)));
+    patterns
+}
+
+/// Invalid regexes are dropped (config load warns about them).
+fn compile_user_patterns(
+    config: &crate::session::Config,
+) -> HashMap<String, CompiledStatusPatterns> {
+    let mut by_agent: HashMap<String, CompiledStatusPatterns> = HashMap::new();
+    for (agent, pattern) in &config.session.agent_waiting_patterns {
+        by_agent.entry(agent.clone()).or_default().waiting = compile_pattern(Some(pattern));
+    }
+    for (agent, pattern) in &config.session.agent_error_patterns {
+        by_agent.entry(agent.clone()).or_default().error = compile_pattern(Some(pattern));
+    }
+    by_agent
 }
 
 /// Spinner frame characters Claude Code rotates through next to its active
@@ -1417,6 +1577,113 @@ pub fn detect_antigravity_status(raw_content: &str) -> Status {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_status_patterns_override_idle_heuristic() {
+        let waiting = Regex::new(r"Continue\? \[y/N\]").unwrap();
+        let error = Regex::new(r"(?m)^fatal:").unwrap();
+        let prompt = "Planning changes\nwrote 3 files\nContinue? [y/N]\n";
+        let failed = "Planning changes\nfatal: upstream returned 500\n> \n";
+        let quiet = "Planning changes\nwrote 3 files\n> \n";
+
+        let apply =
+            |content| apply_status_patterns(Status::Idle, content, Some(&waiting), Some(&error));
+        assert_eq!(apply(prompt), Status::Waiting);
+        assert_eq!(apply(failed), Status::Error);
+        assert_eq!(apply(quiet), Status::Idle);
+        // Live activity outranks a stale prompt or error line.
+        assert_eq!(
+            apply_status_patterns(Status::Running, failed, Some(&waiting), Some(&error)),
+            Status::Running
+        );
+    }
+
+    #[test]
+    fn test_status_patterns_only_see_recent_lines() {
+        let error = Regex::new(r"(?m)^fatal:").unwrap();
+        let mut content = String::from("fatal: old failure\n");
+        for i in 0..STATUS_PATTERN_WINDOW {
+            content.push_str(&format!("line {i}\n"));
+        }
+        assert_eq!(
+            apply_status_patterns(Status::Idle, &content, None, Some(&error)),
+            Status::Idle
+        );
+    }
+
+    #[test]
+    fn test_user_status_patterns_override_builtin() {
+        let mut config = crate::session::Config::default();
+        config
+            .session
+            .agent_waiting_patterns
+            .insert("claude".to_string(), r"Deploy now\?".to_string());
+        config
+            .session
+            .agent_error_patterns
+            .insert("claude".to_string(), "[".to_string());
+        let user = compile_user_patterns(&config);
+        let claude = user.get("claude").unwrap();
+        assert!(claude.waiting.is_some());
+        assert!(claude.error.is_none(), "invalid regex must be dropped");
+
+        let prompt = "wrote the release notes\nDeploy now?\n> \n";
+        assert_eq!(
+            detect_status_with_patterns(prompt, "claude", Some(claude)),
+            Status::Waiting
+        );
+        assert_eq!(detect_status_from_content(prompt, "claude"), Status::Idle);
+        // The built-in error pattern still applies where the user set none.
+        let claude_api_error =
+            "> fix the build\n  ⎿  API Error: 529 {\"type\":\"overloaded_error\"}\n\n> \n";
+        assert_eq!(
+            detect_status_with_patterns(claude_api_error, "claude", Some(claude)),
+            Status::Error
+        );
+    }
+
+    #[test]
+    fn test_builtin_status_patterns_compile_and_match_transcripts() {
+        for agent in crate::agents::AGENTS {
+            let compiled = builtin_status_patterns(agent.name);
+            assert_eq!(
+                compiled.waiting.is_some(),
+                agent.status_patterns.waiting.is_some(),
+                "{} waiting pattern must compile",
+                agent.name
+            );
+            assert_eq!(
+                compiled.error.is_some(),
+                agent.status_patterns.error.is_some(),
+                "{} error pattern must compile",
+                agent.name
+            );
+        }
+
+        let claude_trust = "Do you trust the files in this folder?\n\n/home/me/project\n\n❯ 1. Yes, proceed\n  2. No, exit\n";
+        assert_eq!(
+            detect_status_from_content(claude_trust, "claude"),
+            Status::Waiting
+        );
+        let claude_api_error =
+            "> fix the build\n  ⎿  API Error: 529 {\"type\":\"overloaded_error\"}\n\n> \n";
+        assert_eq!(
+            detect_status_from_content(claude_api_error, "claude"),
+            Status::Error
+        );
+
+        let opencode_permission =
+            "┃ Permission required\n┃ Run: rm -rf build\n┃ enter accept  a accept always  d deny\n";
+        assert_eq!(
+            detect_status_from_content(opencode_permission, "opencode"),
+            Status::Waiting
+        );
+        let opencode_error = "┃ AI_APICallError: Unauthorized\n\n> \n";
+        assert_eq!(
+            detect_status_from_content(opencode_error, "opencode"),
+            Status::Error
+        );
+    }
+
     #[test]
     fn test_detect_cursor_status_running_on_live_activity() {
         let content = "\
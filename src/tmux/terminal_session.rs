@@ -11,12 +11,10 @@ use super::utils::{
     append_clipboard_passthrough_args, append_mouse_on_args, append_pane_base_index_args,
     append_remain_on_exit_args, append_window_size_args, is_pane_dead, sanitize_session_name,
 };
-use super::{
-    refresh_session_cache, session_exists_from_cache, CONTAINER_TERMINAL_PREFIX, TERMINAL_PREFIX,
-};
+use super::{refresh_session_cache, session_exists, CONTAINER_TERMINAL_PREFIX, TERMINAL_PREFIX};
 use crate::cli::truncate_id;
 use crate::process;
-use crate::session::config::should_apply_tmux_clipboard;
+use crate::session::config::{should_apply_tmux_clipboard, TmuxTerminalSplit};
 
 /// Classifies a paired terminal: adjusts the tmux session prefix and the
 /// human-readable label used in error messages.
@@ -64,15 +62,7 @@ impl PairedTerminal {
     }
 
     fn exists(&self) -> bool {
-        if let Some(exists) = session_exists_from_cache(&self.name) {
-            return exists;
-        }
-
-        Command::new("tmux")
-            .args(["has-session", "-t", &self.name])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false)
+        session_exists(&self.name)
     }
 
     fn is_pane_dead(&self) -> bool {
@@ -116,6 +106,72 @@ impl PairedTerminal {
         Ok(())
     }
 
+    /// Apply the configured startup layout to a freshly created terminal.
+    /// With a split, a second pane opens in `working_dir` running
+    /// `pane_command` (the first pane's command, so a container terminal's
+    /// split is also inside the container) and `startup_line` is typed into
+    /// it; without one, the line is typed into the first pane. Typing it
+    /// rather than making it the pane command leaves an interactive shell
+    /// behind once the command finishes. The first pane stays active so the
+    /// user lands on the plain shell.
+    fn apply_startup_layout(
+        &self,
+        split: TmuxTerminalSplit,
+        startup_line: Option<&str>,
+        working_dir: &str,
+        pane_command: Option<&str>,
+    ) -> Result<()> {
+        let first_pane = format!("{}:^.0", self.name);
+        let split_flag = match split {
+            TmuxTerminalSplit::None => None,
+            TmuxTerminalSplit::Horizontal => Some("-h"),
+            TmuxTerminalSplit::Vertical => Some("-v"),
+        };
+        let target = match split_flag {
+            None => first_pane,
+            Some(flag) => {
+                let mut args = vec![
+                    "split-window",
+                    flag,
+                    "-d",
+                    "-P",
+                    "-F",
+                    "#{pane_id}",
+                    "-t",
+                    &first_pane,
+                    "-c",
+                    working_dir,
+                ];
+                args.extend(pane_command);
+                let output = Command::new("tmux").args(&args).output()?;
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    bail!("Failed to split {}: {}", self.kind.label(), stderr);
+                }
+                String::from_utf8_lossy(&output.stdout).trim().to_string()
+            }
+        };
+
+        if let Some(line) = startup_line {
+            for keys in [&["-l", "--", line][..], &["Enter"][..]] {
+                let output = Command::new("tmux")
+                    .args(["send-keys", "-t", &target])
+                    .args(keys)
+                    .output()?;
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    bail!(
+                        "Failed to send startup command to {}: {}",
+                        self.kind.label(),
+                        stderr
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn kill(&self) -> Result<()> {
         if !self.exists() {
             return Ok(());
@@ -212,6 +268,17 @@ impl TerminalSession {
         self.inner.create_with_size(working_dir, command, size)
     }
 
+    pub fn apply_startup_layout(
+        &self,
+        split: TmuxTerminalSplit,
+        startup_line: Option<&str>,
+        working_dir: &str,
+        pane_command: Option<&str>,
+    ) -> Result<()> {
+        self.inner
+            .apply_startup_layout(split, startup_line, working_dir, pane_command)
+    }
+
     pub fn kill(&self) -> Result<()> {
         self.inner.kill()
     }
@@ -263,6 +330,17 @@ impl ContainerTerminalSession {
         self.inner.create_with_size(working_dir, command, size)
     }
 
+    pub fn apply_startup_layout(
+        &self,
+        split: TmuxTerminalSplit,
+        startup_line: Option<&str>,
+        working_dir: &str,
+        pane_command: Option<&str>,
+    ) -> Result<()> {
+        self.inner
+            .apply_startup_layout(split, startup_line, working_dir, pane_command)
+    }
+
     pub fn kill(&self) -> Result<()> {
         self.inner.kill()
     }
@@ -328,6 +406,88 @@ mod tests {
             .unwrap_or(false)
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_apply_startup_layout_splits_and_runs_command_in_second_pane() {
+        if !tmux_available() {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+
+        let guard = TmuxTestSession::new("aoe_test_terminal_layout");
+        let session_name = guard.name().to_string();
+        let session = TerminalSession {
+            inner: PairedTerminal {
+                name: session_name.clone(),
+                kind: TerminalKind::Host,
+            },
+        };
+        let dir = tempfile::TempDir::new().unwrap();
+        let dir_str = dir.path().to_str().unwrap();
+
+        let output = Command::new("tmux")
+            .args([
+                "new-session",
+                "-d",
+                "-s",
+                &session_name,
+                "-x",
+                "80",
+                "-y",
+                "24",
+                "-c",
+                dir_str,
+                "sh",
+            ])
+            .output()
+            .expect("tmux new-session");
+        assert!(output.status.success());
+
+        session
+            .apply_startup_layout(
+                TmuxTerminalSplit::Horizontal,
+                Some("echo aoe-startup-ran"),
+                dir_str,
+                None,
+            )
+            .expect("apply startup layout");
+
+        let output = Command::new("tmux")
+            .args([
+                "list-panes",
+                "-t",
+                &session_name,
+                "-F",
+                "#{pane_index}:#{pane_active}",
+            ])
+            .output()
+            .expect("tmux list-panes");
+        let panes = String::from_utf8_lossy(&output.stdout);
+        let panes: Vec<&str> = panes.lines().collect();
+        assert_eq!(panes.len(), 2, "split should add a second pane: {panes:?}");
+        assert!(
+            panes[0].ends_with(":1"),
+            "first pane should stay active: {panes:?}"
+        );
+
+        let mut second = String::new();
+        for _ in 0..50 {
+            let output = Command::new("tmux")
+                .args(["capture-pane", "-p", "-t", &format!("{session_name}:^.1")])
+                .output()
+                .expect("tmux capture-pane");
+            second = String::from_utf8_lossy(&output.stdout).to_string();
+            if second.lines().any(|l| l.trim() == "aoe-startup-ran") {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        assert!(
+            second.lines().any(|l| l.trim() == "aoe-startup-ran"),
+            "startup command should run in the second pane, got: {second:?}"
+        );
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_terminal_session_is_pane_dead_after_command_exits() {
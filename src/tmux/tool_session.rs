@@ -8,7 +8,7 @@ use super::utils::{
     append_clipboard_passthrough_args, append_mouse_on_args, append_pane_base_index_args,
     append_remain_on_exit_args, append_window_size_args, is_pane_dead, sanitize_session_name,
 };
-use super::{refresh_session_cache, session_exists_from_cache, TOOL_PREFIX};
+use super::{refresh_session_cache, session_exists, TOOL_PREFIX};
 use crate::cli::truncate_id;
 use crate::process;
 use crate::session::config::should_apply_tmux_clipboard;
@@ -36,15 +36,7 @@ impl ToolSession {
     }
 
     pub fn exists(&self) -> bool {
-        if let Some(exists) = session_exists_from_cache(&self.name) {
-            return exists;
-        }
-
-        Command::new("tmux")
-            .args(["has-session", "-t", &self.name])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false)
+        session_exists(&self.name)
     }
 
     pub fn is_pane_dead(&self) -> bool {
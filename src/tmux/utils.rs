@@ -82,6 +82,13 @@ pub fn sanitize_session_name(name: &str) -> String {
 ///
 /// Note: the `-p` (pane-level) flag requires tmux >= 3.0.
 pub fn append_remain_on_exit_args(args: &mut Vec<String>, target: &str) {
+    append_remain_on_exit_setting_args(args, target, true);
+}
+
+/// Like `append_remain_on_exit_args`, but can also force the option off, for
+/// panes that should close with their command even when the user's tmux
+/// config turns `remain-on-exit` on globally.
+pub fn append_remain_on_exit_setting_args(args: &mut Vec<String>, target: &str, on: bool) {
     args.extend([
         ";".to_string(),
         "set-option".to_string(),
@@ -89,7 +96,7 @@ pub fn append_remain_on_exit_args(args: &mut Vec<String>, target: &str) {
         "-t".to_string(),
         target.to_string(),
         "remain-on-exit".to_string(),
-        "on".to_string(),
+        if on { "on" } else { "off" }.to_string(),
     ]);
 }
 
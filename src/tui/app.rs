@@ -11,6 +11,7 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use super::attached_status_hooks::AttachedStatusHookWatcher;
+use super::components::progress::ProgressHandle;
 use super::home::{HomeView, TerminalMode};
 use super::status_poller::StatusUpdate;
 use super::styles::Theme;
@@ -87,6 +88,9 @@ pub struct App {
     update_rx: Option<tokio::sync::oneshot::Receiver<anyhow::Result<UpdateInfo>>>,
     update_status: Option<UpdateStatus>,
     update_status_rx: Option<tokio::sync::oneshot::Receiver<anyhow::Result<()>>>,
+    /// Download progress of a user-started background update, shown in the
+    /// home view's progress dialog until `poll_update_status` resolves it.
+    update_progress: Option<ProgressHandle>,
     /// Latest version the user dismissed via Ctrl+x. Persisted to
     /// `app_state.dismissed_update_version` so the snooze survives
     /// `aoe` restarts (per #1140). The banner stays hidden while the
@@ -101,6 +105,13 @@ pub struct App {
     /// In-flight `docker pull` of the sandbox image, kicked off when the user
     /// accepts the banner's confirm. Result promotes into a transient toast.
     image_pull_rx: Option<tokio::sync::oneshot::Receiver<anyhow::Result<()>>>,
+    /// Progress of the in-flight image pull, shown in the progress dialog.
+    image_pull_progress: Option<ProgressHandle>,
+    /// In-flight container CPU sample for the idle container reaper
+    /// (`sandbox.auto_stop_idle_containers`). `docker stats` blocks for a
+    /// couple of seconds, so it runs off the UI thread.
+    container_cpu_rx:
+        Option<tokio::sync::oneshot::Receiver<std::collections::HashMap<String, f64>>>,
     /// Registry digest the user dismissed via Ctrl+x on the image banner.
     /// Persisted to `app_state.dismissed_image_digest`; the banner stays hidden
     /// while the registry still resolves to this digest.
@@ -256,12 +267,12 @@ impl App {
         // Check if we need to show welcome or changelog dialogs
         let mut config = Config::load_or_warn();
 
-        // Theme is a global preference: read it from the global config, never
-        // profile-merged, so boot matches Settings-close and the web dashboard
-        // (see config::resolve_theme_name). Empty maps to the `default` builtin.
-        let theme_name = config.effective_theme_name();
-        let palette_mode = config.theme_palette_mode();
-        let theme = crate::tui::styles::load_theme_with_mode(&theme_name, palette_mode);
+        // Theme is never profile-merged: paint the global theme, or the
+        // filtered profile's own `theme_name`, so boot matches Settings-close
+        // (see config::resolve_theme_name_for_profile). Empty maps to the
+        // `default` builtin.
+        let theme_name = home.active_theme_name();
+        let theme = crate::tui::styles::load_configured_theme(&theme_name);
         let current_version = env!("CARGO_PKG_VERSION").to_string();
 
         if no_agents {
@@ -304,10 +315,13 @@ impl App {
             update_rx: None,
             update_status: None,
             update_status_rx: None,
+            update_progress: None,
             dismissed_update_version,
             image_update: None,
             image_update_rx: None,
             image_pull_rx: None,
+            image_pull_progress: None,
+            container_cpu_rx: None,
             dismissed_image_digest,
             event_stream: Some(EventStream::new()),
             // Initial state matches whatever `tui::run` did at startup: capture
@@ -447,8 +461,10 @@ impl App {
     where
         F: FnOnce() -> R,
     {
+        super::status_poller::set_refresh_paused(self.home.pause_refresh_while_attached());
         let watcher = AttachedStatusHookWatcher::start(self.home.attached_status_hook_sessions());
         let result = self.with_raw_mode_disabled(terminal, f);
+        super::status_poller::set_refresh_paused(false);
         let mut attached_status_updates = Vec::new();
 
         if let Some(watcher) = watcher {
@@ -479,8 +495,7 @@ impl App {
         // (Termius/mosh edge cases, 8-bit-only TTYs, etc.). Read from the
         // global config: theme (and its color_mode) is a global preference,
         // not profile-merged.
-        let palette_mode = crate::session::config::resolve_theme_palette_mode();
-        self.theme = crate::tui::styles::load_theme_with_mode(name, palette_mode);
+        self.theme = crate::tui::styles::load_configured_theme(name);
         self.needs_redraw = true;
     }
 
@@ -1181,6 +1196,11 @@ impl App {
                 last_status_refresh = std::time::Instant::now();
             }
 
+            if self.home.refresh_ahead_counts_if_due() {
+                refresh_needed = true;
+                needs_full_refresh = true;
+            }
+
             if self.home.apply_status_updates() {
                 refresh_needed = true;
                 needs_full_refresh = true;
@@ -1196,12 +1216,22 @@ impl App {
                 needs_full_refresh = true;
             }
 
+            if self.home.apply_background_task_result() {
+                refresh_needed = true;
+            }
+
             if last_session_idle_reap.elapsed() >= SESSION_IDLE_REAP_INTERVAL {
                 last_session_idle_reap = std::time::Instant::now();
                 if self.reap_idle_sessions() {
                     refresh_needed = true;
                     needs_full_refresh = true;
                 }
+                self.sample_idle_containers();
+            }
+
+            if self.poll_idle_containers() {
+                refresh_needed = true;
+                needs_full_refresh = true;
             }
 
             if self.home.apply_session_id_updates() {
@@ -1358,7 +1388,7 @@ impl App {
             // user can't see, which only adds load on top of the
             // already-busy preview refresh.
             if last_spinner_redraw.elapsed() >= SPINNER_REDRAW_INTERVAL
-                && self.home.has_animated_sessions()
+                && (self.home.has_animated_sessions() || self.home.progress_dialog.is_some())
                 && self.home.live_send.is_none()
             {
                 last_spinner_redraw = std::time::Instant::now();
@@ -1487,10 +1517,11 @@ impl App {
         let image_update = self
             .image_banner_active()
             .then_some(self.image_update.as_ref());
-        // Reset before the render so a frame that skips the preview path
-        // (dialog open, non-home view) reads as zero capture/parse rather
-        // than leaking the previous frame's durations.
+        // Reset before the render so a frame that skips the preview or list
+        // path (dialog open, non-home view) reads as zero capture/parse/rows
+        // rather than leaking the previous frame's numbers.
         self.home.preview_timings = Default::default();
+        self.home.list_rows_rendered = 0;
         self.home.render(
             frame,
             frame.area(),
@@ -1499,6 +1530,7 @@ impl App {
             status_text,
             image_update.flatten(),
         );
+        self.theme.apply_monochrome(frame.buffer_mut());
         // Sampled trace for frame-budget diagnostics. A full-frame trace on
         // every paint would dominate the log at `default_level = trace`, so
         // we only emit for (a) frames that break the 16ms / 60fps budget and
@@ -1519,6 +1551,7 @@ impl App {
                 frame_us = elapsed.as_micros() as u64,
                 capture_us = timings.capture.as_micros() as u64,
                 parse_us = timings.parse.as_micros() as u64,
+                list_rows = self.home.list_rows_rendered,
                 live = in_live,
                 width = frame.area().width,
                 height = frame.area().height,
@@ -1595,11 +1628,10 @@ impl App {
         // Auto mode: install in the background and suppress the banner.
         // The new binary is picked up on next launch; we do not restart
         // the TUI mid-session (avoids racing tmux attaches and partial
-        // writes to the binary while it is running).
-        if crate::session::get_update_settings()
-            .update_check_mode
-            .auto_installs()
-        {
+        // writes to the binary while it is running). `offline` forbids the
+        // download, so it falls through to the notify banner instead.
+        let settings = crate::session::get_update_settings();
+        if settings.update_check_mode.auto_installs() && !settings.offline {
             self.maybe_kick_off_auto_install(info.latest_version.clone());
             self.update_info = None;
             return false;
@@ -1700,6 +1732,11 @@ impl App {
         // transient success/failure toast once the pull resolves. Mirrors the
         // app-update flow, which is also persistent while the install runs.
         self.update_status = Some(UpdateStatus::persistent(format!("pulling {image}…")));
+        // `docker pull` reports no usable total, so the bar is indeterminate.
+        let progress = ProgressHandle::new(format!("Pulling {image}"));
+        self.home
+            .open_progress_dialog("Updating Sandbox Image", progress.clone());
+        self.image_pull_progress = Some(progress);
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.image_pull_rx = Some(rx);
         std::thread::spawn(move || {
@@ -1724,24 +1761,25 @@ impl App {
                 self.update_status = Some(UpdateStatus::transient(
                     "sandbox image updated. New sessions will use it.".into(),
                 ));
-                true
             }
             Ok(Err(e)) => {
                 self.update_status =
                     Some(UpdateStatus::transient(format!("image pull failed: {e}")));
-                true
             }
             Err(tokio::sync::oneshot::error::TryRecvError::Empty) => {
                 self.image_pull_rx = Some(rx);
-                false
+                return false;
             }
             Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
                 self.update_status = Some(UpdateStatus::transient(
                     "image pull ended unexpectedly".into(),
                 ));
-                true
             }
         }
+        if let Some(progress) = self.image_pull_progress.take() {
+            self.home.close_progress_dialog(&progress);
+        }
+        true
     }
 
     /// Kick off a background install when `update_check_mode = "auto"` and a
@@ -1753,6 +1791,13 @@ impl App {
     fn maybe_kick_off_auto_install(&mut self, version: String) {
         use crate::update::install::{detect_install_method, perform_update, InstallMethod};
 
+        // Re-read rather than trust the caller: `offline` may have been
+        // flipped in settings since the check that found this release.
+        if crate::session::get_update_settings().offline {
+            tracing::info!(target: "update.auto", "auto mode skipped: updates.offline is set");
+            return;
+        }
+
         // Defensive: if a prior auto- or manual update is still running,
         // do not start a second installer or overwrite `update_status_rx`.
         // Mirrors the guard in `Action::SpawnUpdate`.
@@ -1821,26 +1866,27 @@ impl App {
                 self.update_status = Some(UpdateStatus::persistent(
                     "update complete. Restart aoe to use the new version.".into(),
                 ));
-                true
             }
             Ok(Err(e)) => {
                 // Clear pending so a retry is allowed.
                 self.pending_install_version = None;
                 self.update_status = Some(UpdateStatus::transient(format!("update failed: {e}")));
-                true
             }
             Err(tokio::sync::oneshot::error::TryRecvError::Empty) => {
                 self.update_status_rx = Some(rx);
-                false
+                return false;
             }
             Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
                 self.pending_install_version = None;
                 self.update_status = Some(UpdateStatus::transient(
                     "update task ended unexpectedly".into(),
                 ));
-                true
             }
         }
+        if let Some(progress) = self.update_progress.take() {
+            self.home.close_progress_dialog(&progress);
+        }
+        true
     }
 
     /// Dispatch the confirmed update, choosing between a blocking suspend and a
@@ -1899,10 +1945,22 @@ impl App {
             self.pending_install_version = Some(version.clone());
             let (tx, rx) = tokio::sync::oneshot::channel();
             self.update_status_rx = Some(rx);
+            let progress = ProgressHandle::new(format!("Downloading v{version}"));
+            self.home
+                .open_progress_dialog("Updating aoe", progress.clone());
+            self.update_progress = Some(progress.clone());
             let handle = tokio::runtime::Handle::current();
             std::thread::spawn(move || {
+                let mut on_progress = |done: u64, total: Option<u64>| {
+                    progress.set_bytes(done, total);
+                    if total.is_some_and(|t| done >= t) {
+                        progress.set_label(format!("Installing v{version}"));
+                    }
+                };
                 let result = handle.block_on(crate::update::install::perform_update(
-                    &method, &version, None,
+                    &method,
+                    &version,
+                    Some(&mut on_progress),
                 ));
                 let _ = tx.send(result);
             });
@@ -2303,6 +2361,7 @@ impl App {
                         .request_stop(crate::tui::stop_poller::StopRequest {
                             session_id: cand.session_id.clone(),
                             instance,
+                            mode: crate::tui::stop_poller::StopMode::Session,
                         });
                     tracing::info!(
                         target: "tui.idle_reap",
@@ -2332,6 +2391,128 @@ impl App {
         reaped
     }
 
+    /// Kick off a background CPU sample of the sandbox containers when any
+    /// sandboxed session's profile has `sandbox.auto_stop_idle_containers` on.
+    /// [`Self::poll_idle_containers`] picks up the result.
+    fn sample_idle_containers(&mut self) {
+        if self.container_cpu_rx.is_some() {
+            return;
+        }
+        let mut enabled: std::collections::HashMap<String, bool> = std::collections::HashMap::new();
+        let any_enabled = self
+            .home
+            .instances()
+            .iter()
+            .filter(|inst| inst.is_sandboxed() && inst.container_idle_stopped_at.is_none())
+            .any(|inst| {
+                *enabled
+                    .entry(inst.effective_profile())
+                    .or_insert_with_key(|profile| {
+                        crate::session::profile_config::resolve_config_or_warn(profile)
+                            .sandbox
+                            .auto_stop_idle_containers
+                    })
+            });
+        if !any_enabled {
+            return;
+        }
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.container_cpu_rx = Some(rx);
+        std::thread::spawn(move || {
+            let _ = tx.send(crate::containers::batch_container_cpu());
+        });
+    }
+
+    /// Consume a finished container CPU sample and stop the containers of
+    /// sandboxed sessions that have been idle past their profile's
+    /// `sandbox.idle_container_stop_secs`. The sessions stay `Idle`; the next
+    /// attach relaunches the agent and starts the container again. Returns
+    /// true when any container was claimed.
+    fn poll_idle_containers(&mut self) -> bool {
+        let Some(mut rx) = self.container_cpu_rx.take() else {
+            return false;
+        };
+        let cpu = match rx.try_recv() {
+            Ok(cpu) => cpu,
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty) => {
+                self.container_cpu_rx = Some(rx);
+                return false;
+            }
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed) => return false,
+        };
+        let Ok(attached) = crate::tmux::attached_session_names() else {
+            return false;
+        };
+        let now = chrono::Utc::now();
+        let candidates = crate::session::idle_reap::idle_container_candidates(
+            self.home.instances(),
+            now,
+            &attached,
+            &cpu,
+            |profile| {
+                let sandbox =
+                    crate::session::profile_config::resolve_config_or_warn(profile).sandbox;
+                if sandbox.auto_stop_idle_containers {
+                    sandbox.idle_container_stop_secs
+                } else {
+                    0
+                }
+            },
+        );
+        let mut reaped = false;
+        for cand in candidates {
+            match crate::session::idle_reap::claim_idle_container_stop(
+                &cand.profile,
+                self.home.file_watch.clone(),
+                &cand.session_id,
+                now,
+                cand.threshold_secs,
+            ) {
+                Ok(Some(instance)) => {
+                    self.home.mutate_instance(&cand.session_id, |inst| {
+                        inst.container_idle_stopped_at = Some(now);
+                    });
+                    // `docker stop` waits out the container's grace period;
+                    // keep it off the UI thread like Action::StopSession.
+                    let session_id = cand.session_id.clone();
+                    std::thread::spawn(move || {
+                        if let Err(e) = instance.stop_container() {
+                            tracing::warn!(
+                                target: "tui.idle_reap",
+                                session = %session_id,
+                                error = %e,
+                                "idle container stop failed",
+                            );
+                        }
+                    });
+                    tracing::info!(
+                        target: "tui.idle_reap",
+                        session = %cand.session_id,
+                        profile = %cand.profile,
+                        threshold_secs = cand.threshold_secs,
+                        "stopped idle sandbox container",
+                    );
+                    reaped = true;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        target: "tui.idle_reap",
+                        session = %cand.session_id,
+                        error = %e,
+                        "idle container stop claim failed",
+                    );
+                }
+            }
+        }
+        if reaped {
+            if let Err(e) = self.home.save() {
+                tracing::error!(target: "tui.idle_reap", "failed to save after idle container reap: {e}");
+            }
+        }
+        reaped
+    }
+
     fn execute_action(
         &mut self,
         action: Action,
@@ -2363,6 +2544,24 @@ impl App {
                     let request = crate::tui::stop_poller::StopRequest {
                         session_id: id.clone(),
                         instance: inst.clone(),
+                        mode: crate::tui::stop_poller::StopMode::Session,
+                    };
+                    self.home
+                        .set_instance_status(&id, crate::session::Status::Stopped);
+                    self.home.save()?;
+                    self.home.stop_poller.request_stop(request);
+                }
+            }
+            Action::RebuildContainer(id) => {
+                if let Some(inst) = self.home.get_instance(&id) {
+                    // Same worker as StopSession: removing and recreating the
+                    // container blocks on the runtime, so keep it off the UI
+                    // thread. The session ends up Stopped with a fresh
+                    // container that the next attach starts.
+                    let request = crate::tui::stop_poller::StopRequest {
+                        session_id: id.clone(),
+                        instance: inst.clone(),
+                        mode: crate::tui::stop_poller::StopMode::RebuildContainer,
                     };
                     self.home
                         .set_instance_status(&id, crate::session::Status::Stopped);
@@ -2416,6 +2615,27 @@ impl App {
                     }
                 }
             }
+            Action::RunCommand(id, command) => {
+                // Same shell choice as `open_run_command_dialog`.
+                let sandboxed = self
+                    .home
+                    .get_instance(&id)
+                    .is_some_and(|inst| inst.is_sandboxed());
+                let mode = if sandboxed {
+                    TerminalMode::Container
+                } else {
+                    TerminalMode::Host
+                };
+                self.update_status = Some(UpdateStatus::transient("Starting shell...".into()));
+                self.draw(terminal)?;
+                self.home.execute_send_message(&id, &command);
+                self.update_status = None;
+                // A failed send leaves an info dialog up; stay in the TUI so
+                // the user sees it instead of attaching to an idle shell.
+                if self.home.info_dialog.is_none() {
+                    self.attach_terminal(&id, mode, terminal)?;
+                }
+            }
             Action::EnterLiveSend(id) => {
                 // Same revive flow as SendMessage so cold-start (Docker,
                 // agent splash) gives the user "Reviving..." feedback.
@@ -2628,12 +2848,15 @@ impl App {
             None => return Ok(()),
         };
         // The non-live preview may have left the window pinned to manual
-        // sizing at the (smaller) preview dimensions. Restore `window-size
-        // latest` so the attaching client resizes it to the full terminal,
-        // and drop the preview-resize dedup so the next render re-asserts the
-        // preview geometry against the now-grown window instead of leaving the
-        // top clipped.
-        tmux_session.reset_size_to_latest_client();
+        // sizing at the (smaller) preview dimensions. Size it to this terminal
+        // (which also restores `window-size latest`) so the agent redraws at
+        // the full geometry, and drop the preview-resize dedup so the next
+        // render re-asserts the preview geometry against the now-grown window
+        // instead of leaving the top clipped.
+        match crate::terminal::get_size() {
+            Some((cols, rows)) => tmux_session.resize(cols, rows),
+            None => tmux_session.reset_size_to_latest_client(),
+        }
         self.home.clear_preview_pane_sync();
         let (attach_result, attached_status_updates) =
             self.with_attached_status_hooks(terminal, || tmux_session.attach())?;
@@ -2857,6 +3080,11 @@ impl App {
 
         self.needs_redraw = true;
 
+        // Reload (or roll back) a raw config edit started from settings
+        if let Some(ref mut settings) = self.home.settings_view {
+            settings.finish_raw_edit();
+        }
+
         // Refresh diff view if it's open (file may have changed)
         if let Some(ref mut diff_view) = self.home.diff_view {
             if let Err(e) = diff_view.refresh_files() {
@@ -2880,6 +3108,7 @@ pub enum Action {
     AttachTerminal(String, TerminalMode),
     EditFile(PathBuf),
     StopSession(String),
+    RebuildContainer(String),
     SetTheme(String),
     SpawnUpdate(crate::update::install::InstallMethod, String),
     SetTransientStatus(String),
@@ -2887,6 +3116,9 @@ pub enum Action {
     /// available" banner's confirm. Deferred to `execute_action` so the loop
     /// can show a "pulling…" status before the blocking pull starts.
     SpawnImagePull(String),
+    /// Run a one-off command in a session's paired shell, then attach to
+    /// that shell so its output streams in the terminal.
+    RunCommand(String, String),
     /// Send a message to a session. Deferred to `execute_action` (rather
     /// than handled inline in the dialog Submit branch) so the app loop
     /// can render a "Reviving..." status before the potentially-slow
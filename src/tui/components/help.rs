@@ -89,7 +89,7 @@ fn shortcuts(strict: bool, live_on_enter: bool) -> Vec<(&'static str, Vec<(Strin
         "Drag".to_string(),
         "Select + copy preview (live mode)".to_string(),
     ));
-    other.push(("Ctrl+K".to_string(), "Command palette".to_string()));
+    other.push(("Ctrl+K/:".to_string(), "Command palette".to_string()));
 
     // Navigation is mode-invariant except the collapse row: in non-strict mode
     // bare `h` is the contextual snooze key, so only `<-` is advertised for
@@ -477,7 +477,7 @@ mod tests {
 
     #[test]
     fn help_lists_command_palette() {
-        // Asserts both keymaps surface the Ctrl+K command palette entry in
+        // Asserts both keymaps surface the Ctrl+K/: command palette entry in
         // their "Other" section so users can discover the palette from `?`.
         for strict in [false, true] {
             let all = shortcuts(strict, false);
@@ -488,8 +488,8 @@ mod tests {
             let (_, keys) = other;
             assert!(
                 keys.iter()
-                    .any(|(k, desc)| *k == "Ctrl+K" && desc.contains("Command palette")),
-                "Other section should contain Ctrl+K Command palette (strict={strict})"
+                    .any(|(k, desc)| *k == "Ctrl+K/:" && desc.contains("Command palette")),
+                "Other section should contain Ctrl+K/: Command palette (strict={strict})"
             );
         }
     }
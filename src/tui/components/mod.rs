@@ -8,6 +8,8 @@ mod help;
 pub(crate) mod hover;
 mod list_picker;
 pub(crate) mod preview;
+pub(crate) mod progress;
+pub(crate) mod relative_time;
 pub(crate) mod scroll;
 mod text_input;
 mod tool_config;
@@ -6,7 +6,11 @@ use ansi_to_tui::IntoText;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-use crate::session::Instance;
+use super::relative_time::format_relative_age;
+use crate::containers::image_update::split_pinned_image;
+use crate::session::config::DisplayConfig;
+use crate::session::{Instance, SandboxInfo, Status};
+use crate::tmux::PaneInfo;
 use crate::tui::styles::Theme;
 
 /// Light value type the renderers consume in place of a raw `&str`.
@@ -32,7 +36,9 @@ impl<'a> CachedPreview<'a> {
 }
 
 /// Row count of the Agent-view info header (profile/tool, path, status,
-/// optional sandbox line, optional worktree block) for `instance`.
+/// optional panes line, optional sandbox line, optional worktree block) for
+/// `instance`. `panes` is the session's `tmux list-panes`; the panes line
+/// shows only when it is non-empty.
 ///
 /// Exposed at the module level so callers outside `Preview::render_with_cache`
 /// can compute the same split. In particular, the live-send sync resize
@@ -44,18 +50,85 @@ impl<'a> CachedPreview<'a> {
 /// `Borders::TOP` block consumes one row). If the agent renders into a
 /// taller pane than the visible output area, the top of its output gets
 /// clipped on every frame and the user sees content shifted up.
-pub fn agent_info_height(instance: &Instance) -> u16 {
+pub fn agent_info_height(instance: &Instance, panes: &[PaneInfo]) -> u16 {
     let base: u16 = 3; // profile+tool / path / status
+    let base = base + u16::from(!panes.is_empty());
     let sandbox_lines: u16 = if instance.is_sandboxed() { 1 } else { 0 };
+    let notes_lines: u16 = if instance.notes.is_some() { 1 } else { 0 };
     if let Some(wt) = instance.worktree_info.as_ref() {
         // blank + header + branch + main (+ optional base)
         let base_branch_line: u16 = if wt.base_branch.is_some() { 1 } else { 0 };
-        base + sandbox_lines + 4 + base_branch_line
+        base + notes_lines + sandbox_lines + 4 + base_branch_line
     } else {
-        base + sandbox_lines
+        base + notes_lines + sandbox_lines
     }
 }
 
+/// The `Sandbox:` info row. A digest-pinned image (`sandbox.pin_image_digest`)
+/// also shows its tag and short digest so it's clear which build the
+/// container runs. A session that is stopped, or parked by the idle
+/// container reaper, still has its container; the row says it is stopped.
+fn sandbox_line<'a>(instance: &Instance, sandbox: &'a SandboxInfo, theme: &Theme) -> Line<'a> {
+    let mut spans = vec![
+        Span::styled("Sandbox: ", Style::default().fg(theme.dimmed)),
+        Span::styled(&sandbox.container_name, Style::default().fg(theme.sandbox)),
+    ];
+    if instance.status == Status::Stopped || instance.container_idle_stopped_at.is_some() {
+        spans.push(Span::styled(
+            " (stopped)",
+            Style::default().fg(theme.dimmed),
+        ));
+    }
+    if let (tag, Some(digest)) = split_pinned_image(&sandbox.image) {
+        let short = digest.get(..19).unwrap_or(digest);
+        spans.push(Span::styled(
+            format!("  {tag} @ {short}"),
+            Style::default().fg(theme.dimmed),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// The `Panes:` info row: window and pane counts, then each pane's command.
+/// Agents and other programs are highlighted, idle shells dimmed, and the
+/// pane an attach lands on is bold.
+fn panes_line<'a>(panes: &'a [PaneInfo], theme: &Theme) -> Line<'a> {
+    let windows = panes
+        .iter()
+        .map(|p| p.window_index)
+        .collect::<std::collections::BTreeSet<_>>()
+        .len();
+    let plural = |n: usize, word: &str| format!("{n} {word}{}", if n == 1 { "" } else { "s" });
+    let mut spans = vec![
+        Span::styled("Panes:   ", Style::default().fg(theme.dimmed)),
+        Span::styled(
+            format!(
+                "{}, {} ",
+                plural(windows, "window"),
+                plural(panes.len(), "pane")
+            ),
+            Style::default().fg(theme.text),
+        ),
+    ];
+    for (i, pane) in panes.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" · ", Style::default().fg(theme.dimmed)));
+        } else {
+            spans.push(Span::raw(" "));
+        }
+        let mut style = if pane.is_shell() {
+            Style::default().fg(theme.dimmed)
+        } else {
+            Style::default().fg(theme.accent)
+        };
+        if pane.active {
+            style = style.bold();
+        }
+        spans.push(Span::styled(pane.command.as_str(), style));
+    }
+    Line::from(spans)
+}
+
 /// Row count of the Terminal-view (and Tool-view) info header
 /// (title / path / status, plus one optional sandbox row) for
 /// `instance`.
@@ -200,10 +273,7 @@ impl Preview {
             ];
             if let Some(sandbox) = &instance.sandbox_info {
                 if sandbox.enabled {
-                    info_lines.push(Line::from(vec![
-                        Span::styled("Sandbox: ", Style::default().fg(theme.dimmed)),
-                        Span::styled(&sandbox.container_name, Style::default().fg(theme.sandbox)),
-                    ]));
+                    info_lines.push(sandbox_line(instance, sandbox, theme));
                 }
             }
             frame.render_widget(Paragraph::new(info_lines), info_area);
@@ -277,10 +347,12 @@ impl Preview {
         frame: &mut Frame,
         area: Rect,
         instance: &Instance,
+        panes: &[PaneInfo],
         cached_output: CachedPreview<'_>,
         scroll_offset: u16,
         theme: &Theme,
         idle_decay_window: Duration,
+        display: &DisplayConfig,
         compact: bool,
         show_info: bool,
     ) {
@@ -288,9 +360,18 @@ impl Preview {
         // viewport compact, `PreviewLayout::compute` returns `info: None` /
         // `banner: None` and the output claims the whole pane (the outer block
         // already says "Preview", so an inner banner would be redundant chrome).
-        let layout = PreviewLayout::compute(area, compact, show_info, agent_info_height(instance));
+        let layout =
+            PreviewLayout::compute(area, compact, show_info, agent_info_height(instance, panes));
         if let Some(info_area) = layout.info {
-            Self::render_info(frame, info_area, instance, theme, idle_decay_window);
+            Self::render_info(
+                frame,
+                info_area,
+                instance,
+                panes,
+                theme,
+                idle_decay_window,
+                display,
+            );
         }
         Self::render_output_cached(
             frame,
@@ -307,8 +388,10 @@ impl Preview {
         frame: &mut Frame,
         area: Rect,
         instance: &Instance,
+        panes: &[PaneInfo],
         theme: &Theme,
         idle_decay_window: Duration,
+        display: &DisplayConfig,
     ) {
         let mut info_lines = Vec::new();
 
@@ -355,16 +438,33 @@ impl Preview {
                         crate::session::Status::Creating => theme.accent,
                     }),
                 ),
+                Span::styled("  Created: ", Style::default().fg(theme.dimmed)),
+                Span::styled(
+                    format!(
+                        "{} ({} ago)",
+                        display.format_timestamp(instance.created_at),
+                        format_relative_age(Some(instance.created_at))
+                    ),
+                    Style::default().fg(theme.text),
+                ),
             ]),
         ]);
 
+        if !panes.is_empty() {
+            info_lines.push(panes_line(panes, theme));
+        }
+
+        if let Some(notes) = &instance.notes {
+            info_lines.push(Line::from(vec![
+                Span::styled("Notes:   ", Style::default().fg(theme.dimmed)),
+                Span::styled(notes, Style::default().fg(theme.text).italic()),
+            ]));
+        }
+
         // Add sandbox information if present
         if let Some(sandbox) = &instance.sandbox_info {
             if sandbox.enabled {
-                info_lines.push(Line::from(vec![
-                    Span::styled("Sandbox: ", Style::default().fg(theme.dimmed)),
-                    Span::styled(&sandbox.container_name, Style::default().fg(theme.sandbox)),
-                ]));
+                info_lines.push(sandbox_line(instance, sandbox, theme));
             }
         }
 
@@ -569,6 +669,62 @@ fn shorten_path(path: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sandbox_line_shows_pinned_image_tag_and_short_digest() {
+        let theme = Theme::default();
+        let mut sandbox = SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".into(),
+            container_name: "ctr".into(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        };
+        let instance = Instance::new("t", "/tmp/t");
+        let text = |s: &SandboxInfo| -> String {
+            sandbox_line(&instance, s, &theme)
+                .spans
+                .iter()
+                .map(|sp| sp.content.to_string())
+                .collect()
+        };
+        assert_eq!(text(&sandbox), "Sandbox: ctr");
+        sandbox.image = format!("ubuntu:latest@sha256:{}", "0123456789abcdef".repeat(4));
+        assert_eq!(
+            text(&sandbox),
+            "Sandbox: ctr  ubuntu:latest @ sha256:0123456789ab"
+        );
+    }
+
+    #[test]
+    fn test_sandbox_line_marks_stopped_container() {
+        let theme = Theme::default();
+        let sandbox = SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".into(),
+            container_name: "ctr".into(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        };
+        let text = |instance: &Instance| -> String {
+            sandbox_line(instance, &sandbox, &theme)
+                .spans
+                .iter()
+                .map(|sp| sp.content.to_string())
+                .collect()
+        };
+        let mut instance = Instance::new("t", "/tmp/t");
+        instance.status = Status::Idle;
+        assert_eq!(text(&instance), "Sandbox: ctr");
+        instance.status = Status::Stopped;
+        assert_eq!(text(&instance), "Sandbox: ctr (stopped)");
+    }
+
     #[test]
     fn test_shorten_path_with_home() {
         if let Some(home) = dirs::home_dir() {
@@ -770,34 +926,36 @@ mod tests {
                 container_name: "ctr".into(),
                 extra_env: None,
                 custom_instruction: None,
+                cpu_limit: None,
+                memory_limit: None,
             }
         }
 
         #[test]
         fn plain_session_is_three_rows() {
             let inst = Instance::new("plain", "/tmp/plain");
-            assert_eq!(agent_info_height(&inst), 3);
+            assert_eq!(agent_info_height(&inst, &[]), 3);
         }
 
         #[test]
         fn sandboxed_adds_one_row() {
             let mut inst = Instance::new("sandboxed", "/tmp/sandboxed");
             inst.sandbox_info = Some(enabled_sandbox());
-            assert_eq!(agent_info_height(&inst), 4);
+            assert_eq!(agent_info_height(&inst, &[]), 4);
         }
 
         #[test]
         fn worktree_without_base_branch_adds_four_rows() {
             let mut inst = Instance::new("wt", "/tmp/wt");
             inst.worktree_info = Some(worktree(None));
-            assert_eq!(agent_info_height(&inst), 3 + 4);
+            assert_eq!(agent_info_height(&inst, &[]), 3 + 4);
         }
 
         #[test]
         fn worktree_with_base_branch_adds_five_rows() {
             let mut inst = Instance::new("wt-base", "/tmp/wt-base");
             inst.worktree_info = Some(worktree(Some("main")));
-            assert_eq!(agent_info_height(&inst), 3 + 4 + 1);
+            assert_eq!(agent_info_height(&inst, &[]), 3 + 4 + 1);
         }
 
         #[test]
@@ -805,7 +963,34 @@ mod tests {
             let mut inst = Instance::new("both", "/tmp/both");
             inst.sandbox_info = Some(enabled_sandbox());
             inst.worktree_info = Some(worktree(Some("main")));
-            assert_eq!(agent_info_height(&inst), 3 + 1 + 4 + 1);
+            assert_eq!(agent_info_height(&inst, &[]), 3 + 1 + 4 + 1);
+        }
+
+        #[test]
+        fn notes_add_one_row() {
+            let mut inst = Instance::new("notes", "/tmp/notes");
+            inst.notes = Some("investigating flaky test".to_string());
+            assert_eq!(agent_info_height(&inst, &[]), 4);
+            inst.worktree_info = Some(worktree(None));
+            assert_eq!(agent_info_height(&inst, &[]), 3 + 1 + 4);
+        }
+
+        #[test]
+        fn panes_add_one_row() {
+            let inst = Instance::new("panes", "/tmp/panes");
+            let pane = |command: &str| crate::tmux::PaneInfo {
+                window_index: 0,
+                pane_index: 0,
+                command: command.to_string(),
+                active: true,
+                title: String::new(),
+            };
+            assert_eq!(agent_info_height(&inst, &[pane("claude")]), 4);
+            assert_eq!(
+                agent_info_height(&inst, &[pane("claude"), pane("zsh")]),
+                4,
+                "one row regardless of pane count"
+            );
         }
 
         #[test]
@@ -814,7 +999,7 @@ mod tests {
             let mut sandbox = enabled_sandbox();
             sandbox.enabled = false;
             inst.sandbox_info = Some(sandbox);
-            assert_eq!(agent_info_height(&inst), 3);
+            assert_eq!(agent_info_height(&inst, &[]), 3);
         }
     }
 
@@ -835,6 +1020,8 @@ mod tests {
                 container_name: "ctr".into(),
                 extra_env: None,
                 custom_instruction: None,
+                cpu_limit: None,
+                memory_limit: None,
             }
         }
 
@@ -0,0 +1,196 @@
+//! Progress bar for long-running operations (image pull, worktree setup,
+//! self-update).
+//!
+//! The work runs on a background thread and reports through a
+//! [`ProgressHandle`]; the render side takes a [`ProgressHandle::snapshot`]
+//! each frame and draws it with [`progress_bar_line`]. A known fraction draws
+//! a determinate bar with a percentage; an unknown one draws a block bouncing
+//! across the track.
+
+use std::sync::{Arc, Mutex};
+
+use ratatui::prelude::*;
+
+use crate::tui::styles::Theme;
+
+/// Frame length of the indeterminate animation.
+const TICK_MS: u128 = 80;
+
+/// Point-in-time view of an operation's progress.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProgressState {
+    /// What is happening right now, e.g. `Pulling ghcr.io/...`.
+    pub label: String,
+    /// Completed share in `0.0..=1.0`, or `None` when the total is unknown.
+    pub fraction: Option<f64>,
+    /// Secondary detail such as transferred bytes. Empty when there is none.
+    pub detail: String,
+}
+
+/// Shared, cloneable progress sink. The background task keeps one clone and
+/// updates it; the dialog keeps another and reads it when drawing.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressHandle(Arc<Mutex<ProgressState>>);
+
+impl ProgressHandle {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self(Arc::new(Mutex::new(ProgressState {
+            label: label.into(),
+            ..ProgressState::default()
+        })))
+    }
+
+    pub fn set_label(&self, label: impl Into<String>) {
+        self.update(|state| state.label = label.into());
+    }
+
+    /// Report a byte transfer. Without a (non-zero) total the bar stays
+    /// indeterminate and only the transferred amount is shown.
+    pub fn set_bytes(&self, done: u64, total: Option<u64>) {
+        let total = total.filter(|&t| t > 0);
+        self.update(|state| {
+            state.fraction = total.map(|t| (done as f64 / t as f64).clamp(0.0, 1.0));
+            state.detail = match total {
+                Some(t) => format!("{} / {}", format_bytes(done), format_bytes(t)),
+                None => format_bytes(done),
+            };
+        });
+    }
+
+    pub fn snapshot(&self) -> ProgressState {
+        self.0.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    /// Whether both handles report to the same operation.
+    pub fn same_operation(&self, other: &ProgressHandle) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ProgressState)) {
+        // A poisoned lock means the worker panicked mid-update; the state is
+        // display-only, so keep writing rather than propagating the panic.
+        let mut state = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut state);
+    }
+}
+
+/// Animation frame for indeterminate bars, derived from the wall clock so
+/// every bar on screen moves in step without threading a counter around.
+pub fn animation_tick() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| (d.as_millis() / TICK_MS) as u64)
+        .unwrap_or(0)
+}
+
+/// One row of progress bar, `width` columns wide. Filled cells use
+/// `theme.accent` and the empty track `theme.dimmed`. A determinate bar
+/// reserves five columns for a right-aligned percentage.
+pub fn progress_bar_line(
+    width: u16,
+    fraction: Option<f64>,
+    tick: u64,
+    theme: &Theme,
+) -> Line<'static> {
+    let filled_style = Style::default().fg(theme.accent);
+    let track_style = Style::default().fg(theme.dimmed);
+    let width = width as usize;
+
+    match fraction {
+        Some(fraction) => {
+            let fraction = fraction.clamp(0.0, 1.0);
+            let bar_width = width.saturating_sub(5);
+            let filled = ((fraction * bar_width as f64).round() as usize).min(bar_width);
+            let percent = format!(" {:>3}%", (fraction * 100.0).floor() as u32);
+            Line::from(vec![
+                Span::styled("█".repeat(filled), filled_style),
+                Span::styled("░".repeat(bar_width - filled), track_style),
+                Span::styled(percent, Style::default().fg(theme.text)),
+            ])
+        }
+        None => {
+            let block = (width / 5).max(1).min(width);
+            let travel = width - block;
+            let pos = if travel == 0 {
+                0
+            } else {
+                // Bounce: run right for `travel` frames, then back.
+                let phase = (tick % (2 * travel as u64)) as usize;
+                if phase <= travel {
+                    phase
+                } else {
+                    2 * travel - phase
+                }
+            };
+            Line::from(vec![
+                Span::styled("░".repeat(pos), track_style),
+                Span::styled("█".repeat(block), filled_style),
+                Span::styled("░".repeat(travel - pos), track_style),
+            ])
+        }
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const MIB: f64 = 1024.0 * 1024.0;
+    if bytes < 1024 * 1024 {
+        format!("{:.0} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / MIB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_determinate_bar_fills_proportionally() {
+        let theme = Theme::default();
+        let line = progress_bar_line(25, Some(0.5), 0, &theme);
+        assert_eq!(
+            text(&line),
+            format!("{}{}  50%", "█".repeat(10), "░".repeat(10))
+        );
+        assert_eq!(line.width(), 25);
+
+        let full = progress_bar_line(25, Some(1.5), 0, &theme);
+        assert_eq!(text(&full), format!("{} 100%", "█".repeat(20)));
+    }
+
+    #[test]
+    fn test_indeterminate_bar_bounces_within_width() {
+        let theme = Theme::default();
+        let start = progress_bar_line(20, None, 0, &theme);
+        assert!(text(&start).starts_with("████░"));
+        let end = progress_bar_line(20, None, 16, &theme);
+        assert!(text(&end).ends_with("░████"));
+        let back = progress_bar_line(20, None, 17, &theme);
+        assert!(text(&back).ends_with("████░"));
+        for tick in 0..40 {
+            assert_eq!(progress_bar_line(20, None, tick, &theme).width(), 20);
+        }
+        assert_eq!(progress_bar_line(0, None, 3, &theme).width(), 0);
+    }
+
+    #[test]
+    fn test_handle_reports_bytes_across_clones() {
+        let handle = ProgressHandle::new("Downloading");
+        let worker = handle.clone();
+        worker.set_bytes(5 * 1024 * 1024, Some(10 * 1024 * 1024));
+        let state = handle.snapshot();
+        assert_eq!(state.label, "Downloading");
+        assert_eq!(state.fraction, Some(0.5));
+        assert_eq!(state.detail, "5.0 MiB / 10.0 MiB");
+        assert!(handle.same_operation(&worker));
+        assert!(!handle.same_operation(&ProgressHandle::new("Downloading")));
+
+        worker.set_bytes(512 * 1024, None);
+        assert_eq!(handle.snapshot().fraction, None);
+        assert_eq!(handle.snapshot().detail, "512 KiB");
+    }
+}
@@ -0,0 +1,98 @@
+//! Compact relative-time formatting shared by the session list's activity
+//! column and the preview header's created age.
+
+use chrono::{DateTime, Utc};
+
+/// Format a timestamp as a compact relative age (e.g. `3m`, `2h`, `4d`,
+/// `2mo`, `3y`). Returns an empty string for `None` so callers can
+/// unconditionally substitute the result without guarding for absence.
+/// Future timestamps (clock skew between hosts writing the session file)
+/// clamp to `<1m` rather than going negative.
+pub(crate) fn format_relative_age(ts: Option<DateTime<Utc>>) -> String {
+    match ts {
+        Some(ts) => format_relative_age_at(ts, Utc::now()),
+        None => String::new(),
+    }
+}
+
+fn format_relative_age_at(ts: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = (now - ts).num_seconds();
+    if secs < 60 {
+        return "<1m".to_string();
+    }
+    let mins = secs / 60;
+    if mins < 60 {
+        return format!("{}m", mins);
+    }
+    let hours = mins / 60;
+    if hours < 24 {
+        return format!("{}h", hours);
+    }
+    let days = hours / 24;
+    if days < 30 {
+        return format!("{}d", days);
+    }
+    if days < 365 {
+        return format!("{}mo", days / 30);
+    }
+    format!("{}y", days / 365)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none_returns_empty() {
+        assert_eq!(format_relative_age(None), "");
+    }
+
+    #[test]
+    fn future_timestamp_returns_less_than_1m() {
+        let future = Utc::now() + chrono::Duration::hours(1);
+        assert_eq!(format_relative_age(Some(future)), "<1m");
+    }
+
+    #[test]
+    fn recent_returns_less_than_1m() {
+        let recent = Utc::now() - chrono::Duration::seconds(30);
+        assert_eq!(format_relative_age(Some(recent)), "<1m");
+    }
+
+    #[test]
+    fn minutes() {
+        let ts = Utc::now() - chrono::Duration::minutes(5);
+        assert_eq!(format_relative_age(Some(ts)), "5m");
+    }
+
+    #[test]
+    fn hours() {
+        let ts = Utc::now() - chrono::Duration::hours(3);
+        assert_eq!(format_relative_age(Some(ts)), "3h");
+    }
+
+    #[test]
+    fn days() {
+        let ts = Utc::now() - chrono::Duration::days(7);
+        assert_eq!(format_relative_age(Some(ts)), "7d");
+    }
+
+    #[test]
+    fn months() {
+        let ts = Utc::now() - chrono::Duration::days(60);
+        assert_eq!(format_relative_age(Some(ts)), "2mo");
+    }
+
+    #[test]
+    fn very_old_sessions_switch_to_years() {
+        let now = Utc::now();
+        assert_eq!(
+            format_relative_age_at(now - chrono::Duration::days(364), now),
+            "12mo"
+        );
+        assert_eq!(
+            format_relative_age_at(now - chrono::Duration::days(365 * 40), now),
+            "40y"
+        );
+    }
+}
@@ -3,9 +3,12 @@
 //! This handles the potentially slow Docker operations (image pull, container creation)
 //! in a background thread so the UI remains responsive.
 
+use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
 
+use crate::git::error::GitError;
+use crate::git::OnExists;
 use crate::session::builder::{self, CreatedWorktree, InstanceParams};
 use crate::session::repo_config::{self, HookProgress, HooksConfig};
 use crate::session::Instance;
@@ -33,6 +36,21 @@ pub enum CreationResult {
         warnings: Vec<String>,
     },
     Error(String),
+    /// The worktree path was already on disk and the request used
+    /// `OnExists::Error`. Carries the request back so the UI can ask the
+    /// user to adopt or recreate and resubmit it.
+    WorktreeExists {
+        data: Box<NewSessionData>,
+        hooks: Option<HooksConfig>,
+        path: PathBuf,
+    },
+    /// The branch is already checked out in another worktree, at `path`
+    /// when git could tell us which one. The UI offers to jump to the
+    /// session using it.
+    BranchCheckedOut {
+        branch: String,
+        path: Option<PathBuf>,
+    },
 }
 
 /// Serializable worktree info for passing across thread boundary
@@ -108,6 +126,13 @@ impl CreationPoller {
             .filter_map(|i| i.worktree_info.as_ref().map(|w| w.branch.as_str()))
             .collect();
 
+        // Kept for the collision prompt; only single-repo worktree sessions
+        // honor `worktree_on_exists`, so only they can be retried with it.
+        let retry = (data.worktree_enabled
+            && data.extra_repo_paths.is_empty()
+            && data.worktree_on_exists == OnExists::Error)
+            .then(|| data.clone());
+
         let params = InstanceParams {
             title: data.title,
             path: data.path.clone(),
@@ -117,20 +142,47 @@ impl CreationPoller {
             worktree_branch: data.worktree_branch,
             create_new_branch: data.create_new_branch,
             base_branch: data.base_branch,
+            worktree_on_exists: data.worktree_on_exists,
             sandbox: data.sandbox,
             sandbox_image: data.sandbox_image,
             yolo_mode: data.yolo_mode,
+            allow_unsandboxed_yolo: data.allow_unsandboxed_yolo,
             extra_env: data.extra_env,
+            cpu_limit: data.cpu_limit,
+            memory_limit: data.memory_limit,
+            custom_instruction: data.custom_instruction,
             extra_args: data.extra_args,
             command_override: data.command_override,
             extra_repo_paths: data.extra_repo_paths,
             scratch: data.scratch,
+            auto_create_terminal: data.auto_create_terminal,
         };
 
         let build_result =
             match builder::build_instance(params, &existing_titles, &existing_branches, &profile) {
                 Ok(r) => r,
-                Err(e) => return CreationResult::Error(format!("{:#}", e)),
+                Err(e) => {
+                    if let (Some(data), Some(GitError::WorktreeAlreadyExists(path))) =
+                        (retry, e.downcast_ref::<GitError>())
+                    {
+                        return CreationResult::WorktreeExists {
+                            data: Box::new(data),
+                            hooks,
+                            path: path.clone(),
+                        };
+                    }
+                    if let Some(GitError::BranchAlreadyCheckedOut {
+                        branch,
+                        existing_path,
+                    }) = e.downcast_ref::<GitError>()
+                    {
+                        return CreationResult::BranchCheckedOut {
+                            branch: branch.clone(),
+                            path: existing_path.clone(),
+                        };
+                    }
+                    return CreationResult::Error(format!("{:#}", e));
+                }
             };
 
         let mut instance = build_result.instance;
@@ -80,6 +80,7 @@ mod tests {
             force_delete: false,
             detach_hooks: true,
             keep_scratch: false,
+            keep_unmerged_branch: false,
         });
 
         let mut result = None;
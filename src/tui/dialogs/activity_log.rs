@@ -0,0 +1,209 @@
+//! Activity log dialog: browse the recent record of sessions created and
+//! deleted, containers started and stopped, and worktrees and branches
+//! removed. Destructive entries are highlighted, and `d` narrows the list to
+//! them for auditing.
+
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use super::DialogResult;
+use crate::session::activity::ActivityRecord;
+use crate::tui::styles::Theme;
+
+pub struct ActivityLogDialog {
+    /// Newest first.
+    records: Vec<ActivityRecord>,
+    destructive_only: bool,
+    scroll_offset: usize,
+}
+
+impl ActivityLogDialog {
+    pub fn new(records: Vec<ActivityRecord>) -> Self {
+        Self {
+            records,
+            destructive_only: false,
+            scroll_offset: 0,
+        }
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &ActivityRecord> {
+        let destructive_only = self.destructive_only;
+        self.records
+            .iter()
+            .filter(move |r| !destructive_only || r.kind.is_destructive())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<()> {
+        let max_scroll = self.visible().count().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => DialogResult::Cancel,
+            KeyCode::Char('d') => {
+                self.destructive_only = !self.destructive_only;
+                self.scroll_offset = 0;
+                DialogResult::Continue
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.scroll_offset = (self.scroll_offset + 1).min(max_scroll);
+                DialogResult::Continue
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                DialogResult::Continue
+            }
+            KeyCode::PageDown => {
+                self.scroll_offset = (self.scroll_offset + 10).min(max_scroll);
+                DialogResult::Continue
+            }
+            KeyCode::PageUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+                DialogResult::Continue
+            }
+            KeyCode::Home | KeyCode::Char('g') => {
+                self.scroll_offset = 0;
+                DialogResult::Continue
+            }
+            KeyCode::End | KeyCode::Char('G') => {
+                self.scroll_offset = max_scroll;
+                DialogResult::Continue
+            }
+            _ => DialogResult::Continue,
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let dialog_width = (area.width * 85 / 100).clamp(60, 120);
+        let dialog_height = (area.height * 80 / 100).clamp(12, 40);
+        let dialog_area = super::centered_rect(area, dialog_width, dialog_height);
+
+        frame.render_widget(Clear, dialog_area);
+
+        let title = if self.destructive_only {
+            " Activity Log (destructive only) "
+        } else {
+            " Activity Log "
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+            .title(title)
+            .title_style(Style::default().fg(theme.title).bold());
+
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let lines: Vec<Line> = self
+            .visible()
+            .skip(self.scroll_offset)
+            .take(chunks[0].height as usize)
+            .map(|record| record_line(record, theme))
+            .collect();
+        if lines.is_empty() {
+            let empty = if self.destructive_only {
+                "No destructive actions recorded."
+            } else {
+                "No activity recorded yet."
+            };
+            frame.render_widget(
+                Paragraph::new(empty).style(Style::default().fg(theme.dimmed)),
+                chunks[0],
+            );
+        } else {
+            frame.render_widget(Paragraph::new(lines), chunks[0]);
+        }
+
+        let filter_label = if self.destructive_only {
+            " show all  "
+        } else {
+            " destructive only  "
+        };
+        let hint = Line::from(vec![
+            Span::styled("j/k", Style::default().fg(theme.hint)),
+            Span::styled(" scroll  ", Style::default().fg(theme.dimmed)),
+            Span::styled("d", Style::default().fg(theme.hint)),
+            Span::styled(filter_label, Style::default().fg(theme.dimmed)),
+            Span::styled("Esc", Style::default().fg(theme.hint)),
+            Span::styled(" close", Style::default().fg(theme.dimmed)),
+        ]);
+        frame.render_widget(Paragraph::new(hint), chunks[1]);
+    }
+}
+
+fn record_line<'a>(record: &'a ActivityRecord, theme: &Theme) -> Line<'a> {
+    let kind_style = if record.kind.is_destructive() {
+        Style::default().fg(theme.error)
+    } else {
+        Style::default().fg(theme.accent)
+    };
+    let mut spans = vec![
+        Span::styled(
+            record
+                .at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S  ")
+                .to_string(),
+            Style::default().fg(theme.dimmed),
+        ),
+        Span::styled(format!("{:<18}", record.kind.label()), kind_style),
+        Span::styled(record.title.as_str(), Style::default().fg(theme.text)),
+    ];
+    if !record.detail.is_empty() {
+        spans.push(Span::styled(
+            format!("  {}", record.detail),
+            Style::default().fg(theme.dimmed),
+        ));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::activity::ActivityKind;
+    use chrono::Utc;
+    use crossterm::event::KeyModifiers;
+
+    fn record(kind: ActivityKind, title: &str) -> ActivityRecord {
+        ActivityRecord {
+            at: Utc::now(),
+            kind,
+            session_id: title.to_string(),
+            title: title.to_string(),
+            detail: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_destructive_filter_and_scroll_bounds() {
+        let mut dialog = ActivityLogDialog::new(vec![
+            record(ActivityKind::BranchDeleted, "a"),
+            record(ActivityKind::SessionCreated, "b"),
+            record(ActivityKind::SessionDeleted, "c"),
+        ]);
+        assert_eq!(dialog.visible().count(), 3);
+
+        let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        for _ in 0..5 {
+            dialog.handle_key(press(KeyCode::Char('j')));
+        }
+        assert_eq!(dialog.scroll_offset, 2);
+
+        dialog.handle_key(press(KeyCode::Char('d')));
+        let titles: Vec<_> = dialog.visible().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, ["a", "c"]);
+        assert_eq!(dialog.scroll_offset, 0);
+
+        assert!(matches!(
+            dialog.handle_key(press(KeyCode::Esc)),
+            DialogResult::Cancel
+        ));
+    }
+}
@@ -19,6 +19,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::*;
 
 use super::DialogResult;
+use crate::session::config::DisplayConfig;
 use crate::tui::components::hover::{paint_hover_bg, HoverState};
 use crate::tui::styles::Theme;
 use crate::update::{get_cached_releases, ReleaseInfo};
@@ -93,7 +94,8 @@ enum DisplayLine {
 impl ChangelogDialog {
     pub fn new(from_version: Option<String>) -> Self {
         let releases = get_cached_releases(from_version.as_deref());
-        let display_lines = build_display_lines(&releases);
+        let display = crate::session::Config::load_or_warn().display;
+        let display_lines = build_display_lines(&releases, &display);
         Self {
             scroll_offset: 0,
             display_lines,
@@ -297,7 +299,7 @@ fn render_line<'a>(line: &DisplayLine, theme: &Theme, separator_width: usize) ->
     }
 }
 
-fn build_display_lines(releases: &[ReleaseInfo]) -> Vec<DisplayLine> {
+fn build_display_lines(releases: &[ReleaseInfo], display: &DisplayConfig) -> Vec<DisplayLine> {
     let mut lines = Vec::new();
 
     if releases.is_empty() {
@@ -310,11 +312,12 @@ fn build_display_lines(releases: &[ReleaseInfo]) -> Vec<DisplayLine> {
             lines.push(DisplayLine::Empty);
         }
 
-        let date = release
-            .published_at
-            .as_deref()
-            .and_then(|s| s.split('T').next())
-            .map(str::to_owned);
+        let date = release.published_at.as_deref().map(|s| {
+            match chrono::DateTime::parse_from_rfc3339(s) {
+                Ok(ts) => display.format_timestamp(ts.to_utc()),
+                Err(_) => s.split('T').next().unwrap_or(s).to_owned(),
+            }
+        });
 
         lines.push(DisplayLine::VersionHeader {
             version: release.version.clone(),
@@ -629,7 +632,7 @@ mod tests {
 
     #[test]
     fn empty_releases_emits_placeholder() {
-        let lines = build_display_lines(&[]);
+        let lines = build_display_lines(&[], &DisplayConfig::default());
         assert_eq!(lines.len(), 1);
         assert!(matches!(lines[0], DisplayLine::NoReleases));
     }
@@ -774,7 +777,11 @@ mod tests {
             body: "## [1.0.1](https://x/y/releases/tag/v1.0.1) - 2026-05-19\n\n**Full Changelog**: https://x/y/compare/v1.0.0...v1.0.1\n".into(),
             published_at: Some("2026-05-19T10:00:00Z".into()),
         };
-        let lines = build_display_lines(std::slice::from_ref(&release));
+        let display = DisplayConfig {
+            timezone: "utc".into(),
+            datetime_format: "%Y-%m-%d".into(),
+        };
+        let lines = build_display_lines(std::slice::from_ref(&release), &display);
         // VersionHeader, Separator, Empty, NoUserFacingChanges.
         assert_eq!(lines.len(), 4);
         match &lines[0] {
@@ -784,6 +791,16 @@ mod tests {
             }
             _ => panic!("expected version header first"),
         }
+
+        let shifted = DisplayConfig {
+            timezone: "-11:00".into(),
+            datetime_format: "%d/%m %H:%M".into(),
+        };
+        let lines = build_display_lines(std::slice::from_ref(&release), &shifted);
+        assert!(matches!(
+            &lines[0],
+            DisplayLine::VersionHeader { date: Some(d), .. } if d == "18/05 23:00"
+        ));
         assert!(matches!(
             lines.last().unwrap(),
             DisplayLine::NoUserFacingChanges
@@ -804,7 +821,7 @@ mod tests {
                 published_at: None,
             },
         ];
-        let lines = build_display_lines(&releases);
+        let lines = build_display_lines(&releases, &DisplayConfig::default());
         let version_headers: Vec<_> = lines
             .iter()
             .filter_map(|l| match l {
@@ -78,6 +78,9 @@ pub struct PaletteCommand {
     pub keywords: Vec<&'static str>,
     /// Human-readable hotkey shown on the right (e.g. "n", "Ctrl+D"). Empty if no binding.
     pub hotkey: String,
+    /// Longer description shown under the list for the selected entry. Taken
+    /// from the help overlay's text so both surfaces describe an action alike.
+    pub description: Option<&'static str>,
     pub payload: PaletteAction,
 }
 
@@ -100,6 +103,13 @@ pub fn builtin_commands(serve_enabled: bool, strict_hotkeys: bool) -> Vec<Palett
                 group: meta.group,
                 keywords: meta.keywords.to_vec(),
                 hotkey: bindings::label(b.id, strict_hotkeys),
+                // Help text is often a terser form of the title ("Projects"
+                // vs "Manage projects"); only keep it when it says more.
+                description: b
+                    .help
+                    .as_ref()
+                    .map(|h| h.desc)
+                    .filter(|d| !meta.title.to_lowercase().contains(&d.to_lowercase())),
                 payload: PaletteAction::Invoke(b.id),
             })
         })
@@ -111,6 +121,7 @@ pub fn builtin_commands(serve_enabled: bool, strict_hotkeys: bool) -> Vec<Palett
         group: PaletteGroup::Actions,
         keywords: vec!["open", "enter"],
         hotkey: "Enter".to_string(),
+        description: None,
         payload: PaletteAction::Activate,
     });
     cmds.push(PaletteCommand {
@@ -128,6 +139,7 @@ pub fn builtin_commands(serve_enabled: bool, strict_hotkeys: bool) -> Vec<Palett
             "interrupt",
         ],
         hotkey: "Tab".to_string(),
+        description: None,
         payload: PaletteAction::LiveSend,
     });
 
@@ -166,6 +178,12 @@ impl CommandPaletteDialog {
         dialog
     }
 
+    /// Description of the highlighted entry, if it has one.
+    fn selected_description(&self) -> Option<&'static str> {
+        let &idx = self.matches.get(self.selected)?;
+        self.entries[idx].description
+    }
+
     pub fn handle_click(&mut self, col: u16, row: u16) -> DialogResult<PaletteAction> {
         if !self
             .dialog_area
@@ -317,6 +335,7 @@ impl CommandPaletteDialog {
                 Constraint::Length(1), // input
                 Constraint::Length(1), // separator
                 Constraint::Min(1),    // list
+                Constraint::Length(1), // selected entry's description
                 Constraint::Length(1), // hint
             ])
             .split(inner);
@@ -424,6 +443,16 @@ impl CommandPaletteDialog {
         }
         frame.render_widget(Paragraph::new(lines[start..end].to_vec()), list_area);
 
+        if let Some(desc) = self.selected_description() {
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    truncate_with_ellipsis(desc, chunks[3].width as usize),
+                    Style::default().fg(theme.dimmed).italic(),
+                )),
+                chunks[3],
+            );
+        }
+
         // Hint footer
         let footer_left = Line::from(vec![
             Span::styled("↑↓", Style::default().fg(theme.hint)),
@@ -433,7 +462,7 @@ impl CommandPaletteDialog {
             Span::styled("Esc", Style::default().fg(theme.hint)),
             Span::raw(" close"),
         ]);
-        frame.render_widget(Paragraph::new(footer_left), chunks[3]);
+        frame.render_widget(Paragraph::new(footer_left), chunks[4]);
     }
 }
 
@@ -560,6 +589,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn descriptions_come_from_help_text_and_skip_repeats() {
+        let cmds = builtin_commands(false, false);
+        let restart = cmds.iter().find(|c| c.id == "restart").unwrap();
+        assert_eq!(restart.description, Some("Restart session (also F5)"));
+        let projects = cmds.iter().find(|c| c.id == "projects").unwrap();
+        assert_eq!(
+            projects.description, None,
+            "\"Projects\" adds nothing to the title"
+        );
+
+        let mut dialog = make_dialog();
+        for c in "restart session".chars() {
+            dialog.handle_key(ke(KeyCode::Char(c)));
+        }
+        assert_eq!(
+            dialog.selected_description(),
+            Some("Restart session (also F5)")
+        );
+    }
+
     #[test]
     fn keywords_match_searches() {
         // "Move session to group" complaint from issue #889: searching for
@@ -679,6 +729,7 @@ mod tests {
             group: PaletteGroup::Sessions,
             keywords: vec!["session"],
             hotkey: String::new(),
+            description: None,
             payload: PaletteAction::JumpToCursor(7),
         }];
         let mut dialog = CommandPaletteDialog::new(entries);
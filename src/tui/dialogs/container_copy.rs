@@ -0,0 +1,187 @@
+//! Copy-out-of-container dialog.
+//!
+//! Pulls a file or directory the agent produced outside the mounted project
+//! (e.g. under `/tmp`) from a sandboxed session's container onto the host.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use tui_input::backend::crossterm::EventHandler;
+use tui_input::Input;
+
+use super::DialogResult;
+use crate::tui::components::render_text_field;
+use crate::tui::styles::Theme;
+
+/// Data returned when the dialog is submitted. Both paths are trimmed but
+/// otherwise raw; relative paths are resolved by the caller.
+#[derive(Debug, Clone)]
+pub struct ContainerCopyData {
+    pub container_path: String,
+    pub host_path: String,
+}
+
+pub struct ContainerCopyDialog {
+    session_title: String,
+    container_path: Input,
+    host_path: Input,
+    /// 0 = container path, 1 = host destination.
+    focused_field: usize,
+}
+
+impl ContainerCopyDialog {
+    pub fn new(session_title: &str, default_host_path: &str) -> Self {
+        Self {
+            session_title: session_title.to_string(),
+            container_path: Input::default(),
+            host_path: Input::new(default_host_path.to_string()),
+            focused_field: 0,
+        }
+    }
+
+    fn focused_input(&mut self) -> &mut Input {
+        if self.focused_field == 0 {
+            &mut self.container_path
+        } else {
+            &mut self.host_path
+        }
+    }
+
+    /// Enter with an empty field stays open rather than submitting.
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<ContainerCopyData> {
+        match key.code {
+            KeyCode::Esc => DialogResult::Cancel,
+            KeyCode::Enter => {
+                let container_path = self.container_path.value().trim().to_string();
+                let host_path = self.host_path.value().trim().to_string();
+                if container_path.is_empty() {
+                    self.focused_field = 0;
+                    return DialogResult::Continue;
+                }
+                if host_path.is_empty() {
+                    self.focused_field = 1;
+                    return DialogResult::Continue;
+                }
+                DialogResult::Submit(ContainerCopyData {
+                    container_path,
+                    host_path,
+                })
+            }
+            KeyCode::Tab | KeyCode::Down | KeyCode::BackTab | KeyCode::Up => {
+                self.focused_field = 1 - self.focused_field;
+                DialogResult::Continue
+            }
+            _ => {
+                self.focused_input()
+                    .handle_event(&crossterm::event::Event::Key(key));
+                DialogResult::Continue
+            }
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        let input = self.focused_input();
+        for ch in text.chars().filter(|c| *c != '\n' && *c != '\r') {
+            input.handle(tui_input::InputRequest::InsertChar(ch));
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let dialog_area = super::centered_rect(area, 72, 11);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1))
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Copy From Container ")
+            .title_style(Style::default().fg(theme.title).bold());
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1), // session title
+                Constraint::Length(1), // spacer
+                Constraint::Length(1), // container path
+                Constraint::Length(1), // host path
+                Constraint::Length(1), // spacer
+                Constraint::Min(1),    // hint
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Session: ", Style::default().fg(theme.dimmed)),
+                Span::styled(&self.session_title, Style::default().fg(theme.text)),
+            ])),
+            chunks[0],
+        );
+
+        render_text_field(
+            frame,
+            chunks[2],
+            "From:",
+            &self.container_path,
+            self.focused_field == 0,
+            Some("path in the container, e.g. /tmp/report.pdf"),
+            theme,
+        );
+        render_text_field(
+            frame,
+            chunks[3],
+            "To:  ",
+            &self.host_path,
+            self.focused_field == 1,
+            None,
+            theme,
+        );
+
+        let hint = Line::from(vec![
+            Span::styled("Tab", Style::default().fg(theme.hint)),
+            Span::raw(" switch  "),
+            Span::styled("Enter", Style::default().fg(theme.hint)),
+            Span::raw(" copy  "),
+            Span::styled("Esc", Style::default().fg(theme.hint)),
+            Span::raw(" cancel"),
+        ]);
+        frame.render_widget(Paragraph::new(hint), chunks[5]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    #[test]
+    fn submits_container_path_with_prefilled_destination() {
+        let mut d = ContainerCopyDialog::new("s", "/home/me/proj");
+        d.handle_paste("/tmp/out.txt\n");
+        match d.handle_key(key(KeyCode::Enter)) {
+            DialogResult::Submit(data) => {
+                assert_eq!(data.container_path, "/tmp/out.txt");
+                assert_eq!(data.host_path, "/home/me/proj");
+            }
+            _ => panic!("expected submit"),
+        }
+    }
+
+    #[test]
+    fn empty_container_path_keeps_dialog_open() {
+        let mut d = ContainerCopyDialog::new("s", "/home/me/proj");
+        d.handle_key(key(KeyCode::Tab));
+        assert!(matches!(
+            d.handle_key(key(KeyCode::Enter)),
+            DialogResult::Continue
+        ));
+        assert_eq!(d.focused_field, 0);
+    }
+}
@@ -20,6 +20,9 @@ pub struct DeleteOptions {
     /// For scratch sessions: keep the scratch directory on disk instead of
     /// removing it. No effect when `DeleteDialogConfig.is_scratch` is false.
     pub keep_scratch: bool,
+    /// `delete_branch` only deletes a branch merged into its base
+    /// (`delete_branch_on_cleanup = "if-merged"`).
+    pub keep_unmerged_branch: bool,
 }
 
 /// Configuration for what cleanup options to show in the dialog
@@ -86,11 +89,13 @@ impl UnifiedDeleteDialog {
             delete_worktree: config.worktree_branch.is_some() && user_config.worktree.auto_cleanup,
             force_delete: false,
             delete_branch: config.worktree_branch.is_some()
-                && user_config.worktree.delete_branch_on_cleanup,
+                && user_config.worktree.delete_branch_on_cleanup.deletes(),
             delete_sandbox: config.has_sandbox && user_config.sandbox.auto_cleanup,
             // Scratch sessions default to remove. The user has to explicitly
             // opt in to keep the directory.
             keep_scratch: false,
+            keep_unmerged_branch: user_config.worktree.delete_branch_on_cleanup
+                == crate::session::BranchCleanup::IfMerged,
         };
 
         let initial_focus = if config.worktree_branch.is_some() {
@@ -416,11 +421,16 @@ impl UnifiedDeleteDialog {
 
                 let area = chunks[chunk_idx];
                 let branch_focused = self.focus == FocusElement::BranchCheckbox;
+                let branch_label = if self.options.keep_unmerged_branch {
+                    "Delete branch if merged"
+                } else {
+                    "Delete branch"
+                };
                 self.render_checkbox(
                     frame,
                     area,
                     theme,
-                    "Delete branch",
+                    branch_label,
                     Some(branch),
                     self.options.delete_branch,
                     branch_focused,
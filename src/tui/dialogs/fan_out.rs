@@ -0,0 +1,355 @@
+//! Fan-out dialog.
+//!
+//! Runs the same task under several agents at once: from one existing
+//! session, pick the tools to try and a group, and one new session per tool
+//! is created there. With worktrees on, each gets its own branch suffixed
+//! with the tool name so the attempts never share a checkout.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use tui_input::backend::crossterm::EventHandler;
+use tui_input::Input;
+
+use super::{DialogResult, NewSessionData};
+use crate::session::builder::branch_name_from_title;
+use crate::tui::components::checkbox::{checkbox_line, CheckboxStyle};
+use crate::tui::components::render_text_field;
+use crate::tui::styles::Theme;
+
+/// Data returned when the dialog is submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanOutData {
+    /// Selected tools, in the order they were listed.
+    pub tools: Vec<String>,
+    pub group: String,
+    pub worktree: bool,
+}
+
+impl FanOutData {
+    /// One session per selected tool, each a copy of `base` with the tool
+    /// swapped in and the title suffixed. With worktrees on, every session
+    /// gets a new branch `<branch_stem>-<tool>` cut from `base.base_branch`.
+    pub fn sessions(&self, base: &NewSessionData, branch_stem: &str) -> Vec<NewSessionData> {
+        self.tools
+            .iter()
+            .map(|tool| {
+                let mut data = base.clone();
+                data.tool = tool.clone();
+                data.title = format!("{} ({tool})", base.title);
+                data.group = self.group.clone();
+                data.worktree_enabled = self.worktree;
+                data.create_new_branch = self.worktree;
+                data.worktree_branch = self
+                    .worktree
+                    .then(|| format!("{branch_stem}-{}", branch_name_from_title(tool)));
+                data
+            })
+            .collect()
+    }
+}
+
+pub struct FanOutDialog {
+    session_title: String,
+    tools: Vec<String>,
+    selected: Vec<bool>,
+    group: Input,
+    /// `None` when the source path is not a git repo, so worktrees are not
+    /// offered.
+    worktree: Option<bool>,
+    /// Tool rows first, then the group input, then the worktree toggle.
+    focused_field: usize,
+}
+
+impl FanOutDialog {
+    pub fn new(
+        session_title: &str,
+        tools: &[String],
+        default_group: &str,
+        worktree: Option<bool>,
+    ) -> Self {
+        Self {
+            session_title: session_title.to_string(),
+            tools: tools.to_vec(),
+            selected: vec![true; tools.len()],
+            group: Input::new(default_group.to_string()),
+            worktree,
+            focused_field: 0,
+        }
+    }
+
+    fn group_field(&self) -> usize {
+        self.tools.len()
+    }
+
+    fn field_count(&self) -> usize {
+        self.tools.len() + 1 + usize::from(self.worktree.is_some())
+    }
+
+    fn toggle_focused(&mut self) {
+        if let Some(checked) = self.selected.get_mut(self.focused_field) {
+            *checked = !*checked;
+        } else if self.focused_field > self.group_field() {
+            if let Some(worktree) = &mut self.worktree {
+                *worktree = !*worktree;
+            }
+        }
+    }
+
+    /// Enter with no tool selected stays open rather than submitting.
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<FanOutData> {
+        let on_group = self.focused_field == self.group_field();
+        match key.code {
+            KeyCode::Esc => DialogResult::Cancel,
+            KeyCode::Enter => {
+                let tools: Vec<String> = self
+                    .tools
+                    .iter()
+                    .zip(&self.selected)
+                    .filter(|(_, &checked)| checked)
+                    .map(|(tool, _)| tool.clone())
+                    .collect();
+                if tools.is_empty() {
+                    self.focused_field = 0;
+                    return DialogResult::Continue;
+                }
+                DialogResult::Submit(FanOutData {
+                    tools,
+                    group: self.group.value().trim().to_string(),
+                    worktree: self.worktree.unwrap_or(false),
+                })
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                self.focused_field = (self.focused_field + 1) % self.field_count();
+                DialogResult::Continue
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused_field =
+                    (self.focused_field + self.field_count() - 1) % self.field_count();
+                DialogResult::Continue
+            }
+            KeyCode::Char(' ') if !on_group => {
+                self.toggle_focused();
+                DialogResult::Continue
+            }
+            _ if on_group => {
+                self.group.handle_event(&crossterm::event::Event::Key(key));
+                DialogResult::Continue
+            }
+            _ => DialogResult::Continue,
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        if self.focused_field != self.group_field() {
+            return;
+        }
+        for ch in text.chars().filter(|c| *c != '\n' && *c != '\r') {
+            self.group.handle(tui_input::InputRequest::InsertChar(ch));
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let height = 9 + self.field_count() as u16;
+        let dialog_area = super::centered_rect(area, 64, height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1))
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Fan Out ")
+            .title_style(Style::default().fg(theme.title).bold());
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1),                       // session title
+                Constraint::Length(1),                       // spacer
+                Constraint::Length(self.tools.len() as u16), // tools
+                Constraint::Length(1),                       // spacer
+                Constraint::Length(1),                       // group
+                Constraint::Length(1),                       // worktree
+                Constraint::Length(1),                       // spacer
+                Constraint::Min(1),                          // hint
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("From: ", Style::default().fg(theme.dimmed)),
+                Span::styled(&self.session_title, Style::default().fg(theme.text)),
+            ])),
+            chunks[0],
+        );
+
+        let style = CheckboxStyle::confirm(theme);
+        let tool_lines: Vec<Line> = self
+            .tools
+            .iter()
+            .zip(&self.selected)
+            .enumerate()
+            .map(|(i, (tool, &checked))| {
+                checkbox_line(
+                    theme,
+                    tool,
+                    None,
+                    0,
+                    checked,
+                    self.focused_field == i,
+                    style,
+                )
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(tool_lines), chunks[2]);
+
+        render_text_field(
+            frame,
+            chunks[4],
+            "Group:",
+            &self.group,
+            self.focused_field == self.group_field(),
+            Some("group for the new sessions"),
+            theme,
+        );
+
+        if let Some(worktree) = self.worktree {
+            frame.render_widget(
+                Paragraph::new(checkbox_line(
+                    theme,
+                    "New worktree per agent",
+                    None,
+                    0,
+                    worktree,
+                    self.focused_field > self.group_field(),
+                    style,
+                )),
+                chunks[5],
+            );
+        }
+
+        let hint = Line::from(vec![
+            Span::styled("Space", Style::default().fg(theme.hint)),
+            Span::raw(" toggle  "),
+            Span::styled("Tab", Style::default().fg(theme.hint)),
+            Span::raw(" next  "),
+            Span::styled("Enter", Style::default().fg(theme.hint)),
+            Span::raw(" create  "),
+            Span::styled("Esc", Style::default().fg(theme.hint)),
+            Span::raw(" cancel"),
+        ]);
+        frame.render_widget(Paragraph::new(hint), chunks[7]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::OnExists;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn tools() -> Vec<String> {
+        ["claude", "opencode", "codex"].map(String::from).to_vec()
+    }
+
+    fn base() -> NewSessionData {
+        NewSessionData {
+            profile: "default".to_string(),
+            title: "Fix login".to_string(),
+            path: "/repo".to_string(),
+            group: String::new(),
+            tool: "claude".to_string(),
+            worktree_enabled: false,
+            worktree_branch: None,
+            create_new_branch: false,
+            base_branch: Some("fix-login".to_string()),
+            worktree_on_exists: OnExists::Error,
+            extra_repo_paths: Vec::new(),
+            sandbox: false,
+            sandbox_image: String::new(),
+            yolo_mode: false,
+            allow_unsandboxed_yolo: false,
+            extra_env: Vec::new(),
+            cpu_limit: None,
+            memory_limit: None,
+            custom_instruction: None,
+            extra_args: String::new(),
+            command_override: String::new(),
+            scratch: false,
+            auto_create_terminal: false,
+        }
+    }
+
+    #[test]
+    fn submits_the_ticked_tools() {
+        let mut d = FanOutDialog::new("Fix login", &tools(), "fix-login", Some(true));
+        d.handle_key(key(KeyCode::Down));
+        d.handle_key(key(KeyCode::Char(' ')));
+        match d.handle_key(key(KeyCode::Enter)) {
+            DialogResult::Submit(data) => assert_eq!(
+                data,
+                FanOutData {
+                    tools: vec!["claude".to_string(), "codex".to_string()],
+                    group: "fix-login".to_string(),
+                    worktree: true,
+                }
+            ),
+            _ => panic!("expected submit"),
+        }
+    }
+
+    #[test]
+    fn nothing_ticked_keeps_dialog_open() {
+        let mut d = FanOutDialog::new("Fix login", &tools()[..1], "g", None);
+        d.handle_key(key(KeyCode::Char(' ')));
+        assert!(matches!(
+            d.handle_key(key(KeyCode::Enter)),
+            DialogResult::Continue
+        ));
+    }
+
+    #[test]
+    fn worktree_sessions_get_distinct_branches() {
+        let data = FanOutData {
+            tools: tools(),
+            group: "experiments".to_string(),
+            worktree: true,
+        };
+        let sessions = data.sessions(&base(), "fix-login");
+        let branches: Vec<_> = sessions
+            .iter()
+            .map(|s| s.worktree_branch.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            branches,
+            ["fix-login-claude", "fix-login-opencode", "fix-login-codex"]
+        );
+        assert!(sessions
+            .iter()
+            .all(|s| s.create_new_branch && s.group == "experiments"));
+        assert_eq!(sessions[2].title, "Fix login (codex)");
+        assert_eq!(sessions[2].tool, "codex");
+        assert_eq!(sessions[2].base_branch.as_deref(), Some("fix-login"));
+    }
+
+    #[test]
+    fn without_worktrees_sessions_share_the_path() {
+        let data = FanOutData {
+            tools: tools(),
+            group: String::new(),
+            worktree: false,
+        };
+        let sessions = data.sessions(&base(), "fix-login");
+        assert!(sessions
+            .iter()
+            .all(|s| !s.worktree_enabled && s.worktree_branch.is_none() && s.path == "/repo"));
+    }
+}
@@ -1,11 +1,14 @@
 //! TUI dialog components
 
+mod activity_log;
 mod changelog;
 mod command_palette;
 mod confirm;
+mod container_copy;
 mod context_menu;
 mod custom_instruction;
 mod delete_options;
+mod fan_out;
 mod group_delete_options;
 mod group_picker;
 mod hooks_install;
@@ -13,7 +16,9 @@ mod info;
 mod intro;
 mod new_session;
 mod no_agents;
+mod notes;
 mod profile_picker;
+mod progress;
 mod project_session_picker;
 mod projects;
 mod rename;
@@ -27,16 +32,20 @@ mod sort_picker;
 mod telemetry_consent;
 mod tool_picker;
 mod update_confirm;
+mod worktree_exists;
 mod worktree_name;
 
+pub use activity_log::ActivityLogDialog;
 pub use changelog::ChangelogDialog;
 pub use command_palette::{
     builtin_commands, CommandPaletteDialog, PaletteAction, PaletteCommand, PaletteGroup,
 };
 pub use confirm::ConfirmDialog;
+pub use container_copy::{ContainerCopyData, ContainerCopyDialog};
 pub use context_menu::{ContextMenuAction, ContextMenuDialog};
 pub use custom_instruction::CustomInstructionDialog;
 pub use delete_options::{DeleteDialogConfig, DeleteOptions, UnifiedDeleteDialog};
+pub use fan_out::{FanOutData, FanOutDialog};
 pub use group_delete_options::{GroupDeleteOptions, GroupDeleteOptionsDialog};
 pub use group_picker::GroupPickerDialog;
 pub use hooks_install::HooksInstallDialog;
@@ -45,7 +54,9 @@ pub use intro::{IntroDialog, IntroOutcome};
 pub(crate) use new_session::project_picker_label;
 pub use new_session::{NewSessionData, NewSessionDialog};
 pub use no_agents::{NoAgentsAction, NoAgentsDialog};
+pub use notes::NotesDialog;
 pub use profile_picker::{ProfileEntry, ProfilePickerAction, ProfilePickerDialog};
+pub use progress::ProgressDialog;
 pub use project_session_picker::ProjectSessionPickerDialog;
 pub use projects::ProjectsDialog;
 pub use rename::{RenameData, RenameDialog, RenameMode};
@@ -59,7 +70,8 @@ pub use sort_picker::SortPickerDialog;
 pub use telemetry_consent::TelemetryConsentDialog;
 pub use tool_picker::ToolPickerDialog;
 pub use update_confirm::UpdateConfirmDialog;
-pub use worktree_name::{WorktreeNameData, WorktreeNameDialog};
+pub use worktree_exists::WorktreeExistsDialog;
+pub use worktree_name::{WorktreeNameData, WorktreeNameDialog, WorktreeNameMode};
 
 pub enum DialogResult<T> {
     Continue,
@@ -14,6 +14,7 @@ use tui_input::Input;
 
 use super::DialogResult;
 use crate::containers::{self, ContainerRuntimeInterface};
+use crate::git::{GitWorktree, OnExists, WorktreePlan};
 use crate::session::config::{load_config, save_config, DefaultTerminalMode, SandboxConfig};
 use crate::session::profile_config::resolve_config_or_warn;
 use crate::session::repo_config::HookProgress;
@@ -25,6 +26,10 @@ use crate::tui::components::{
 };
 use path_input::PathGhostCompletion;
 
+/// (path, branch, create_new_branch, profile) a [`WorktreePlan`] was
+/// computed from.
+type WorktreePlanInputs = (String, String, bool, String);
+
 pub(super) struct FieldHelp {
     pub(super) name: &'static str,
     pub(super) description: &'static str,
@@ -81,7 +86,7 @@ pub(super) const FIELD_HELP: &[FieldHelp] = &[
     },
 ];
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct NewSessionData {
     pub profile: String,
     pub title: String,
@@ -95,14 +100,28 @@ pub struct NewSessionData {
     /// `create_new_branch` is true; ignored otherwise. `None` falls
     /// back to the repository's default branch. See #948.
     pub base_branch: Option<String>,
+    /// What to do when the worktree path is already taken. The dialog
+    /// always submits `Error`; the collision prompt resubmits with the
+    /// user's choice.
+    pub worktree_on_exists: OnExists,
     pub extra_repo_paths: Vec<String>,
     pub sandbox: bool,
     /// The sandbox image to use (always populated from the input field).
     pub sandbox_image: String,
     pub yolo_mode: bool,
+    /// The user confirmed YOLO mode without a sandbox. The dialog always
+    /// submits `false`; the home view's confirm prompt sets it.
+    pub allow_unsandboxed_yolo: bool,
     /// Additional environment entries for the container.
     /// `KEY` = pass through from host, `KEY=VALUE` = set explicitly.
     pub extra_env: Vec<String>,
+    /// Per-session container CPU limit; `None` uses `sandbox.cpu_limit`.
+    pub cpu_limit: Option<String>,
+    /// Per-session container memory limit; `None` uses `sandbox.memory_limit`.
+    pub memory_limit: Option<String>,
+    /// Initial instruction read from the dialog's prompt file; `None` uses
+    /// `sandbox.custom_instruction`.
+    pub custom_instruction: Option<String>,
     /// Extra arguments to append after the agent binary
     pub extra_args: String,
     /// Command override for the agent binary (replaces the default binary)
@@ -111,6 +130,8 @@ pub struct NewSessionData {
     /// `<app_dir>/scratch/<id>/` and persist `instance.scratch = true`.
     /// Mutually exclusive with worktree mode.
     pub scratch: bool,
+    /// Open the paired terminal alongside the agent on start.
+    pub auto_create_terminal: bool,
 }
 
 pub struct NewSessionDialog {
@@ -137,9 +158,24 @@ pub struct NewSessionDialog {
     pub(super) base_branch: Input,
     pub(super) sandbox_enabled: bool,
     pub(super) sandbox_image: Input,
+    /// Runtime whose local image store `sandbox_image` is checked against.
+    container_runtime: containers::ContainerRuntime,
+    /// Image presence drawn by the last render, so `tick` can redraw once a
+    /// background probe lands.
+    pub(super) shown_image_presence: Option<bool>,
+    /// Per-session resource limits. Empty means the profile's
+    /// `sandbox.cpu_limit` / `sandbox.memory_limit` applies.
+    pub(super) cpu_limit: Input,
+    pub(super) memory_limit: Input,
+    /// Path to a file holding the agent's initial instruction. Empty
+    /// means `sandbox.custom_instruction` applies.
+    pub(super) prompt_file: Input,
     pub(super) docker_available: bool,
     pub(super) yolo_mode: bool,
     pub(super) yolo_mode_default: bool,
+    /// "Terminal" checkbox: open the paired terminal when the session
+    /// starts. Seeded from `session.auto_create_terminal`.
+    pub(super) auto_create_terminal: bool,
     /// Additional repo paths for multi-repo workspace
     pub(super) workspace_repos: Vec<String>,
     /// Whether the workspace repos list is expanded (editing mode)
@@ -237,6 +273,9 @@ pub struct NewSessionDialog {
     /// Rects for the worktree-config overlay, keyed by
     /// `worktree_config_focused_field`.
     pub(super) worktree_config_rects: Vec<(usize, ratatui::layout::Rect)>,
+    /// Dry-run plan for the typed worktree branch, with the inputs it was
+    /// computed from so rendering only re-plans after an edit.
+    worktree_plan: Option<(WorktreePlanInputs, Option<WorktreePlan>)>,
 }
 
 /// Shared logic for handling key events in an editable list (env keys or env values).
@@ -327,6 +366,9 @@ fn build_inherited_settings(sandbox: &SandboxConfig) -> Vec<(String, String)> {
     if sandbox.mount_ssh {
         settings.push(("Mount SSH".to_string(), "yes".to_string()));
     }
+    if sandbox.mount_docker_socket {
+        settings.push(("Mount Docker Socket".to_string(), "yes".to_string()));
+    }
     if !sandbox.extra_volumes.is_empty() {
         settings.push((
             "Extra Volumes".to_string(),
@@ -363,7 +405,8 @@ impl NewSessionDialog {
             .unwrap_or_default();
 
         let available_tools: Vec<String> = tools.available_list().to_vec();
-        let docker_available = containers::get_container_runtime().is_available();
+        let container_runtime = containers::get_container_runtime();
+        let docker_available = container_runtime.is_available();
 
         // Load resolved config (global + profile + repo overrides from cwd)
         let config = crate::session::repo_config::resolve_config_with_repo_or_warn(
@@ -388,6 +431,13 @@ impl NewSessionDialog {
             .is_some_and(|a| a.host_only);
         let sandbox_enabled =
             docker_available && config.sandbox.enabled_by_default && !is_default_tool_host_only;
+
+        // Look the image up in the background so the sandbox overlay can say
+        // whether creating the session will pull it, without slowing the open.
+        let sandbox_image = container_runtime.effective_default_image();
+        if docker_available {
+            container_runtime.prefetch_image_presence(&sandbox_image);
+        }
         let worktree_enabled = config.worktree.enabled && !is_default_tool_host_only;
         let yolo_mode = config.session.yolo_mode_default;
 
@@ -461,12 +511,16 @@ impl NewSessionDialog {
             worktree_config_mode: false,
             worktree_config_focused_field: 0,
             sandbox_enabled,
-            sandbox_image: Input::new(
-                containers::get_container_runtime().effective_default_image(),
-            ),
+            sandbox_image: Input::new(sandbox_image),
+            container_runtime,
+            shown_image_presence: None,
+            cpu_limit: Input::default(),
+            memory_limit: Input::default(),
+            prompt_file: Input::default(),
             docker_available,
             yolo_mode,
             yolo_mode_default: yolo_mode,
+            auto_create_terminal: config.session.auto_create_terminal,
             extra_env,
             extra_env_overridden: false,
             env_list_expanded: false,
@@ -495,6 +549,7 @@ impl NewSessionDialog {
             sandbox_config_rects: Vec::new(),
             tool_config_rects: Vec::new(),
             worktree_config_rects: Vec::new(),
+            worktree_plan: None,
         }
     }
 
@@ -580,9 +635,33 @@ impl NewSessionDialog {
             }
         }
 
+        if self.sandbox_config_mode {
+            let presence = self.sandbox_image_presence();
+            // Probe a changed image once the user leaves the field, not on
+            // every keystroke.
+            if presence.is_none() && self.sandbox_focused_field != 0 && self.docker_available {
+                self.container_runtime
+                    .prefetch_image_presence(self.sandbox_image.value().trim());
+            }
+            if presence != self.shown_image_presence {
+                changed = true;
+            }
+        }
+
         changed
     }
 
+    /// Whether the sandbox image is already in the local store, as last
+    /// probed. `None` while unknown; creating the session then pulls it if
+    /// needed.
+    pub(super) fn sandbox_image_presence(&self) -> Option<bool> {
+        let image = self.sandbox_image.value().trim();
+        if !self.docker_available || image.is_empty() {
+            return None;
+        }
+        self.container_runtime.cached_image_presence(image)
+    }
+
     pub(super) fn selected_profile(&self) -> &str {
         &self.available_profiles[self.profile_index]
     }
@@ -613,6 +692,53 @@ impl NewSessionDialog {
         }
     }
 
+    /// Where the typed worktree branch would be checked out and whether the
+    /// branch exists, re-planned only when the path, branch, profile, or
+    /// new-branch toggle changed. `None` until a branch is typed (a name
+    /// derived from the title is de-duplicated at create time, so it can't
+    /// collide), for multi-repo workspaces, and outside a git repo.
+    pub(super) fn worktree_plan(&mut self) -> Option<&WorktreePlan> {
+        let branch = self.worktree_branch.value().trim();
+        if branch.is_empty() || !self.workspace_repos.is_empty() {
+            return None;
+        }
+        let inputs = (
+            path_input::expand_tilde(self.path.value().trim()),
+            branch.to_string(),
+            self.create_new_branch,
+            self.profile.clone(),
+        );
+        if self.worktree_plan.as_ref().map(|(k, _)| k) != Some(&inputs) {
+            let plan = self.compute_worktree_plan(&inputs);
+            self.worktree_plan = Some((inputs, plan));
+        }
+        self.worktree_plan
+            .as_ref()
+            .and_then(|(_, plan)| plan.as_ref())
+    }
+
+    fn compute_worktree_plan(&self, inputs: &WorktreePlanInputs) -> Option<WorktreePlan> {
+        let (path, branch, create_new_branch, profile) = inputs;
+        let path = std::path::Path::new(path);
+        if !GitWorktree::is_git_repo(path) {
+            return None;
+        }
+        let main_repo = GitWorktree::find_main_repo(path).ok()?;
+        let main_repo = main_repo.canonicalize().unwrap_or(main_repo);
+        let config = self.resolve_config_for_path(profile);
+        // Same template choice as the builder.
+        let template = if GitWorktree::is_bare_repo(&main_repo) {
+            &config.worktree.bare_repo_path_template
+        } else {
+            &config.worktree.path_template
+        };
+        GitWorktree::new(main_repo)
+            .ok()?
+            .with_worktree_root(config.worktree.root_path())
+            .plan_worktree(branch, template, "{session-id}", *create_new_branch)
+            .ok()
+    }
+
     fn refresh_inherited_sandbox_settings(&mut self) {
         if !self.sandbox_enabled || self.extra_env_overridden {
             return;
@@ -678,6 +804,7 @@ impl NewSessionDialog {
         // Reset sandbox/yolo defaults
         self.yolo_mode_default = config.session.yolo_mode_default;
         self.yolo_mode = self.yolo_mode_default;
+        self.auto_create_terminal = config.session.auto_create_terminal;
         self.sandbox_enabled = self.docker_available
             && config.sandbox.enabled_by_default
             && !self.selected_tool_host_only();
@@ -685,6 +812,12 @@ impl NewSessionDialog {
 
         // Reset sandbox image from resolved config (includes profile overrides)
         self.sandbox_image = Input::new(config.sandbox.default_image.clone());
+        if self.docker_available {
+            self.container_runtime
+                .prefetch_image_presence(&config.sandbox.default_image);
+        }
+        self.cpu_limit = Input::default();
+        self.memory_limit = Input::default();
 
         // Reset env entries and inherited settings
         if self.sandbox_enabled {
@@ -767,9 +900,15 @@ impl NewSessionDialog {
             sandbox_image: Input::new(
                 containers::get_container_runtime().effective_default_image(),
             ),
+            container_runtime: containers::get_container_runtime(),
+            shown_image_presence: None,
+            cpu_limit: Input::default(),
+            memory_limit: Input::default(),
+            prompt_file: Input::default(),
             docker_available: false,
             yolo_mode: false,
             yolo_mode_default: false,
+            auto_create_terminal: false,
             extra_env: Vec::new(),
             extra_env_overridden: false,
             env_list_expanded: false,
@@ -798,6 +937,7 @@ impl NewSessionDialog {
             sandbox_config_rects: Vec::new(),
             tool_config_rects: Vec::new(),
             worktree_config_rects: Vec::new(),
+            worktree_plan: None,
         }
     }
 
@@ -837,9 +977,15 @@ impl NewSessionDialog {
             sandbox_image: Input::new(
                 containers::get_container_runtime().effective_default_image(),
             ),
+            container_runtime: containers::get_container_runtime(),
+            shown_image_presence: None,
+            cpu_limit: Input::default(),
+            memory_limit: Input::default(),
+            prompt_file: Input::default(),
             docker_available: false,
             yolo_mode: false,
             yolo_mode_default: false,
+            auto_create_terminal: false,
             extra_env: Vec::new(),
             extra_env_overridden: false,
             env_list_expanded: false,
@@ -868,6 +1014,7 @@ impl NewSessionDialog {
             sandbox_config_rects: Vec::new(),
             tool_config_rects: Vec::new(),
             worktree_config_rects: Vec::new(),
+            worktree_plan: None,
         }
     }
 
@@ -1059,12 +1206,13 @@ impl NewSessionDialog {
             usize::MAX
         };
         let sandbox_field = if has_sandbox {
-            // No `fi += 1` here; sandbox is the last index we need to
-            // resolve, and the unused assignment trips clippy.
-            fi
+            let f = fi;
+            fi += 1;
+            f
         } else {
             usize::MAX
         };
+        let terminal_field = fi;
 
         if self.focused_field == profile_field {
             if self.available_profiles.len() > 1 {
@@ -1095,6 +1243,8 @@ impl NewSessionDialog {
             }
         } else if self.focused_field == yolo_mode_field {
             self.yolo_mode = !self.yolo_mode;
+        } else if self.focused_field == terminal_field {
+            self.auto_create_terminal = !self.auto_create_terminal;
         } else if self.focused_field == worktree_field {
             // Mirror the keyboard handler: worktree and scratch are
             // mutually exclusive, so a click on the worktree row while
@@ -1226,7 +1376,7 @@ impl NewSessionDialog {
         let is_host_only = self.selected_tool_host_only();
         let has_sandbox = self.docker_available && !is_host_only;
         let has_yolo = !self.selected_tool_always_yolo();
-        // Field order: [profile], path, title, [tool], [yolo], worktree, [sandbox], group
+        // Field order: [profile], path, title, [tool], [yolo], worktree, [sandbox], terminal, group
         // Worktree sub-options (new_branch, extra_repos) are in a Ctrl+P overlay.
         // Tool config (extra_args, command_override) is in a Ctrl+P overlay on tool field.
         // Sandbox sub-options are in a separate sandbox_config_mode overlay.
@@ -1261,6 +1411,8 @@ impl NewSessionDialog {
         } else {
             usize::MAX
         };
+        let terminal_field = fi;
+        fi += 1;
         let group_field = fi;
         fi += 1;
         let max_field = fi;
@@ -1318,6 +1470,14 @@ impl NewSessionDialog {
             }
             KeyCode::Enter => {
                 self.error_message = None;
+                if let Err(e) = self.validate_resource_limits() {
+                    self.error_message = Some(e);
+                    return DialogResult::Continue;
+                }
+                if let Err(e) = self.read_prompt_file() {
+                    self.error_message = Some(e);
+                    return DialogResult::Continue;
+                }
                 // Scratch sessions skip the path-existence check: the
                 // server (or `aoe add` CLI) provisions the scratch dir on
                 // submit.
@@ -1456,12 +1616,19 @@ impl NewSessionDialog {
                 self.yolo_mode = !self.yolo_mode;
                 DialogResult::Continue
             }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                if self.focused_field == terminal_field =>
+            {
+                self.auto_create_terminal = !self.auto_create_terminal;
+                DialogResult::Continue
+            }
             _ => {
                 if self.focused_field != profile_field
                     && self.focused_field != tool_field
                     && self.focused_field != worktree_field
                     && self.focused_field != sandbox_field
                     && self.focused_field != yolo_mode_field
+                    && self.focused_field != terminal_field
                 {
                     self.current_input_mut()
                         .handle_event(&crossterm::event::Event::Key(key));
@@ -1479,12 +1646,40 @@ impl NewSessionDialog {
         }
     }
 
+    /// Check the per-session limit overrides with the same rules the
+    /// settings screen applies for the configured runtime.
+    fn validate_resource_limits(&self) -> Result<(), String> {
+        if !self.sandbox_enabled {
+            return Ok(());
+        }
+        let runtime = containers::configured_runtime_name();
+        crate::session::validate_cpu_limit_for_runtime(self.cpu_limit.value().trim(), runtime)?;
+        crate::session::validate_memory_limit_for_runtime(self.memory_limit.value().trim(), runtime)
+    }
+
+    /// Read the sandbox prompt file, if one is set. Returns `Ok(None)` when
+    /// the field is blank or the session is not sandboxed.
+    fn read_prompt_file(&self) -> Result<Option<String>, String> {
+        let value = self.prompt_file.value().trim();
+        if !self.sandbox_enabled || value.is_empty() {
+            return Ok(None);
+        }
+        let path = path_input::expand_tilde(value);
+        crate::session::builder::read_instruction_file(std::path::Path::new(&path))
+            .map(Some)
+            .map_err(|e| format!("{:#}", e))
+    }
+
     /// Handle key events when in sandbox configuration mode.
     fn handle_sandbox_config_key(&mut self, key: KeyEvent) -> DialogResult<NewSessionData> {
-        // Sandbox config fields: 0=image, 1=env (inherited is always-visible, not focusable)
+        // Sandbox config fields: 0=image, 1=env, 2=cpu, 3=memory,
+        // 4=prompt file (inherited is always-visible, not focusable)
         const SANDBOX_IMAGE: usize = 0;
         const SANDBOX_ENV: usize = 1;
-        const SANDBOX_MAX: usize = 2;
+        const SANDBOX_CPU: usize = 2;
+        const SANDBOX_MEMORY: usize = 3;
+        const SANDBOX_PROMPT_FILE: usize = 4;
+        const SANDBOX_MAX: usize = 5;
 
         // Handle env list editing when expanded
         if self.env_list_expanded && self.sandbox_focused_field == SANDBOX_ENV {
@@ -1522,11 +1717,14 @@ impl NewSessionDialog {
                 DialogResult::Continue
             }
             _ => {
-                // Text input for image field only
-                if self.sandbox_focused_field == SANDBOX_IMAGE {
-                    self.sandbox_image
-                        .handle_event(&crossterm::event::Event::Key(key));
-                }
+                let input = match self.sandbox_focused_field {
+                    SANDBOX_IMAGE => &mut self.sandbox_image,
+                    SANDBOX_CPU => &mut self.cpu_limit,
+                    SANDBOX_MEMORY => &mut self.memory_limit,
+                    SANDBOX_PROMPT_FILE => &mut self.prompt_file,
+                    _ => return DialogResult::Continue,
+                };
+                input.handle_event(&crossterm::event::Event::Key(key));
                 DialogResult::Continue
             }
         }
@@ -1886,7 +2084,7 @@ impl NewSessionDialog {
         let base = if self.has_profile_selection() { 1 } else { 0 };
 
         let is_host_only = self.selected_tool_host_only();
-        // Field layout: [profile], title, path, [tool], [yolo], [worktree], [sandbox], group
+        // Field layout: [profile], title, path, [tool], [yolo], [worktree], [sandbox], terminal, group
         let mut fi = base + 2 + if has_tool_selection { 1 } else { 0 };
         if has_yolo {
             fi += 1;
@@ -1897,6 +2095,7 @@ impl NewSessionDialog {
         if self.docker_available && !is_host_only {
             fi += 1; // sandbox checkbox
         }
+        fi += 1; // terminal checkbox
         let group_field = fi;
 
         let path_field = self.path_field();
@@ -1927,6 +2126,12 @@ impl NewSessionDialog {
             &mut self.worktree_branch
         } else if self.sandbox_config_mode && self.sandbox_focused_field == 0 {
             &mut self.sandbox_image
+        } else if self.sandbox_config_mode && self.sandbox_focused_field == 2 {
+            &mut self.cpu_limit
+        } else if self.sandbox_config_mode && self.sandbox_focused_field == 3 {
+            &mut self.memory_limit
+        } else if self.sandbox_config_mode && self.sandbox_focused_field == 4 {
+            &mut self.prompt_file
         } else {
             self.current_input_mut()
         };
@@ -1967,6 +2172,7 @@ impl NewSessionDialog {
             worktree_branch,
             create_new_branch: self.create_new_branch,
             base_branch,
+            worktree_on_exists: OnExists::Error,
             extra_repo_paths: if !self.scratch && self.worktree_enabled {
                 self.workspace_repos.clone()
             } else {
@@ -1975,17 +2181,29 @@ impl NewSessionDialog {
             sandbox: self.sandbox_enabled,
             sandbox_image: self.sandbox_image.value().trim().to_string(),
             yolo_mode: self.yolo_mode || self.selected_tool_always_yolo(),
+            allow_unsandboxed_yolo: false,
             extra_env: if self.sandbox_enabled && self.extra_env_overridden {
                 self.extra_env.clone()
             } else {
                 Vec::new()
             },
+            cpu_limit: self.resource_limit_override(&self.cpu_limit),
+            memory_limit: self.resource_limit_override(&self.memory_limit),
+            // Validated on Enter; a file that vanished since then falls
+            // back to the configured instruction.
+            custom_instruction: self.read_prompt_file().ok().flatten(),
             extra_args: self.extra_args.value().trim().to_string(),
             command_override: self.command_override.value().trim().to_string(),
             scratch: self.scratch,
+            auto_create_terminal: self.auto_create_terminal,
         })
     }
 
+    fn resource_limit_override(&self, input: &Input) -> Option<String> {
+        let value = input.value().trim();
+        (self.sandbox_enabled && !value.is_empty()).then(|| value.to_string())
+    }
+
     fn handle_confirm_create_dir_key(&mut self, key: KeyEvent) -> DialogResult<NewSessionData> {
         let selected = self.confirm_create_dir.as_mut().unwrap();
         match key.code {
@@ -6,7 +6,7 @@ use tui_input::Input;
 
 use rattles::presets::prelude as spinners;
 
-use super::{NewSessionDialog, FIELD_HELP, HELP_DIALOG_WIDTH};
+use super::{path_input, NewSessionDialog, FIELD_HELP, HELP_DIALOG_WIDTH};
 use crate::tui::components::{
     focused_input_spans, input_scroll, profile_cycler_spans, render_text_field,
     render_text_field_with_ghost, render_tool_config_overlay, set_prefixed_input_cursor_position,
@@ -92,6 +92,7 @@ impl NewSessionDialog {
         if has_sandbox {
             constraints.push(Constraint::Length(2)); // Sandbox checkbox (summary only)
         }
+        constraints.push(Constraint::Length(2)); // Terminal checkbox
         constraints.push(Constraint::Length(2)); // Group (always, at the bottom)
 
         // For errors, calculate how many lines we need based on the text length.
@@ -170,7 +171,8 @@ impl NewSessionDialog {
         } else {
             usize::MAX
         };
-        let group_field = fi;
+        let terminal_field = fi;
+        let group_field = fi + 1;
 
         // Profile picker (only when multiple profiles)
         if has_profile_selection {
@@ -369,6 +371,42 @@ impl NewSessionDialog {
             ci += 1;
         }
 
+        // Terminal checkbox (always shown; sandboxed sessions get the
+        // container terminal)
+        let is_terminal_focused = self.focused_field == terminal_field;
+        let terminal_label_style = if is_terminal_focused {
+            Style::default().fg(theme.accent).underlined()
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let checkbox = if self.auto_create_terminal {
+            "[x]"
+        } else {
+            "[ ]"
+        };
+        let checkbox_style = if self.auto_create_terminal {
+            Style::default().fg(theme.accent).bold()
+        } else {
+            Style::default().fg(theme.dimmed)
+        };
+        let terminal_line = Line::from(vec![
+            Span::styled("Terminal:", terminal_label_style),
+            Span::raw(" "),
+            Span::styled(checkbox, checkbox_style),
+            Span::styled(
+                " Open a terminal on start",
+                if self.auto_create_terminal {
+                    Style::default().fg(theme.accent)
+                } else {
+                    Style::default().fg(theme.dimmed)
+                },
+            ),
+        ]);
+        let area = chunks[ci];
+        frame.render_widget(Paragraph::new(terminal_line), area);
+        self.focusable_rects.push((terminal_field, area));
+        ci += 1;
+
         // Group (always visible, at the bottom before hints)
         let group_placeholder =
             if !self.existing_groups.is_empty() && self.focused_field == group_field {
@@ -644,7 +682,7 @@ impl NewSessionDialog {
         self.sandbox_config_rects.clear();
         let dialog_width: u16 = 72;
 
-        // Sandbox config fields: image, env, inherited
+        // Sandbox config fields: image, env, cpu, memory, prompt file, inherited
         let env_list_height: u16 = if self.env_list_expanded {
             (2 + self.extra_env.len() as u16).clamp(4, 8)
         } else {
@@ -655,6 +693,9 @@ impl NewSessionDialog {
         let constraints = vec![
             Constraint::Length(2),                // Image
             Constraint::Length(env_list_height),  // Environment
+            Constraint::Length(2),                // CPU limit
+            Constraint::Length(2),                // Memory limit
+            Constraint::Length(2),                // Prompt file
             Constraint::Length(inherited_height), // Inherited settings
             Constraint::Min(1),                   // Hints
         ];
@@ -691,16 +732,34 @@ impl NewSessionDialog {
 
         let mut ci = 0;
 
-        // Image field
+        // Image field, with whether creating the session will pull it
+        let presence = self.sandbox_image_presence();
+        self.shown_image_presence = presence;
+        let presence_hint = match presence {
+            Some(true) => " image present",
+            Some(false) => " will be pulled",
+            None => "",
+        };
+        let image_cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(presence_hint.len() as u16),
+            ])
+            .split(chunks[ci]);
         render_text_field(
             frame,
-            chunks[ci],
+            image_cols[0],
             "Image:",
             &self.sandbox_image,
             self.sandbox_focused_field == 0,
             None,
             theme,
         );
+        frame.render_widget(
+            Paragraph::new(presence_hint).style(Style::default().fg(theme.dimmed)),
+            image_cols[1],
+        );
         self.sandbox_config_rects.push((0, chunks[ci]));
         ci += 1;
 
@@ -709,6 +768,37 @@ impl NewSessionDialog {
         self.sandbox_config_rects.push((1, chunks[ci]));
         ci += 1;
 
+        // Per-session resource limits; blank inherits the profile's limit
+        for (field, label, input) in [
+            (2, "CPU Limit:", &self.cpu_limit),
+            (3, "Memory Limit:", &self.memory_limit),
+        ] {
+            render_text_field(
+                frame,
+                chunks[ci],
+                label,
+                input,
+                self.sandbox_focused_field == field,
+                Some("profile default"),
+                theme,
+            );
+            self.sandbox_config_rects.push((field, chunks[ci]));
+            ci += 1;
+        }
+
+        // Initial instruction file; blank uses `sandbox.custom_instruction`
+        render_text_field(
+            frame,
+            chunks[ci],
+            "Prompt File:",
+            &self.prompt_file,
+            self.sandbox_focused_field == 4,
+            Some("configured instruction"),
+            theme,
+        );
+        self.sandbox_config_rects.push((4, chunks[ci]));
+        ci += 1;
+
         // Inherited settings (always visible, not focusable)
         self.render_inherited_field(frame, chunks[ci], theme);
         ci += 1;
@@ -765,6 +855,7 @@ impl NewSessionDialog {
             Constraint::Length(2),            // Name
             Constraint::Length(2),            // New Branch checkbox
             Constraint::Length(2),            // Base Branch
+            Constraint::Length(2),            // Plan
             Constraint::Length(repos_height), // Extra Repos
             Constraint::Min(1),               // Hints
         ];
@@ -867,14 +958,16 @@ impl NewSessionDialog {
             self.worktree_config_rects.push((2, chunks[2]));
         }
 
+        self.render_worktree_plan(frame, chunks[3], theme);
+
         // Extra Repos
         self.render_extra_repos_field(
             frame,
-            chunks[3],
+            chunks[4],
             self.worktree_config_focused_field == 3,
             theme,
         );
-        self.worktree_config_rects.push((3, chunks[3]));
+        self.worktree_config_rects.push((3, chunks[4]));
 
         // Hints
         let mut hint_spans = vec![
@@ -901,7 +994,7 @@ impl NewSessionDialog {
                 Span::raw(" back"),
             ];
         }
-        frame.render_widget(Paragraph::new(Line::from(hint_spans)), chunks[4]);
+        frame.render_widget(Paragraph::new(Line::from(hint_spans)), chunks[5]);
 
         if self.show_help {
             self.render_help_overlay(frame, area, theme);
@@ -920,6 +1013,43 @@ impl NewSessionDialog {
         }
     }
 
+    /// One-line dry run of the worktree the typed branch would create, so a
+    /// taken path or branch shows up before submit.
+    fn render_worktree_plan(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let branch = self.worktree_branch.value().trim().to_string();
+        let base = self.base_branch.value().trim().to_string();
+        let Some(plan) = self.worktree_plan() else {
+            return;
+        };
+        let mut spans = vec![Span::styled("Plan: ", Style::default().fg(theme.text))];
+        if let Some(conflict) = plan.conflict(&branch) {
+            spans.push(Span::styled(conflict, Style::default().fg(theme.error)));
+        } else {
+            let action = if plan.creates_branch {
+                let from = if base.is_empty() {
+                    "repo default"
+                } else {
+                    &base
+                };
+                format!("new branch from {from}")
+            } else if plan.branch_exists_locally {
+                "existing local branch".to_string()
+            } else {
+                format!(
+                    "tracking {}/{branch}",
+                    plan.branch_remote.as_deref().unwrap_or("origin")
+                )
+            };
+            let path = path_input::collapse_tilde(&fold_parent_dirs(&plan.path));
+            spans.push(Span::styled(path, Style::default().fg(theme.accent)));
+            spans.push(Span::styled(
+                format!("  ({action})"),
+                Style::default().fg(theme.dimmed),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
     fn render_env_field(&self, frame: &mut Frame, area: Rect, is_focused: bool, theme: &Theme) {
         let label_style = if is_focused {
             Style::default().fg(theme.accent).underlined()
@@ -1416,3 +1546,18 @@ impl NewSessionDialog {
         }
     }
 }
+
+/// Fold `..` components lexically for display: templates like
+/// `../{repo-name}-worktrees/{branch}` resolve to `<repo>/../...`.
+fn fold_parent_dirs(path: &std::path::Path) -> String {
+    let mut folded = std::path::PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir if folded.file_name().is_some() => {
+                folded.pop();
+            }
+            other => folded.push(other),
+        }
+    }
+    folded.to_string_lossy().to_string()
+}
@@ -92,7 +92,10 @@ fn test_tab_cycles_fields_single_tool() {
     assert_eq!(dialog.focused_field, 3); // worktree
 
     dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.focused_field, 4); // group
+    assert_eq!(dialog.focused_field, 4); // terminal
+
+    dialog.handle_key(key(KeyCode::Tab));
+    assert_eq!(dialog.focused_field, 5); // group
 
     dialog.handle_key(key(KeyCode::Tab));
     assert_eq!(dialog.focused_field, 0); // wrap to start
@@ -116,7 +119,10 @@ fn test_tab_cycles_fields_single_tool_with_worktree() {
     assert_eq!(dialog.focused_field, 3); // worktree
 
     dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.focused_field, 4); // group
+    assert_eq!(dialog.focused_field, 4); // terminal
+
+    dialog.handle_key(key(KeyCode::Tab));
+    assert_eq!(dialog.focused_field, 5); // group
 
     dialog.handle_key(key(KeyCode::Tab));
     assert_eq!(dialog.focused_field, 0); // wrap to start
@@ -140,7 +146,10 @@ fn test_tab_cycles_fields_multi_tool() {
     assert_eq!(dialog.focused_field, 4); // worktree branch
 
     dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.focused_field, 5); // group
+    assert_eq!(dialog.focused_field, 5); // terminal
+
+    dialog.handle_key(key(KeyCode::Tab));
+    assert_eq!(dialog.focused_field, 6); // group
 
     dialog.handle_key(key(KeyCode::Tab));
     assert_eq!(dialog.focused_field, 0); // wrap to start (no new_branch without worktree)
@@ -152,7 +161,10 @@ fn test_backtab_cycles_fields_reverse() {
     assert_eq!(dialog.focused_field, 0); // path
 
     dialog.handle_key(shift_key(KeyCode::BackTab));
-    assert_eq!(dialog.focused_field, 4); // group (last field without worktree/docker)
+    assert_eq!(dialog.focused_field, 5); // group (last field without worktree/docker)
+
+    dialog.handle_key(shift_key(KeyCode::BackTab));
+    assert_eq!(dialog.focused_field, 4); // terminal
 
     dialog.handle_key(shift_key(KeyCode::BackTab));
     assert_eq!(dialog.focused_field, 3); // worktree branch
@@ -411,7 +423,7 @@ fn test_ctrl_a_jumps_to_start_of_path() {
 #[test]
 fn test_char_input_to_group() {
     let mut dialog = single_tool_dialog();
-    dialog.focused_field = 4; // group (single tool, single profile: path=0, title=1, yolo=2, worktree=3, group=4)
+    dialog.focused_field = 5; // group (single tool, single profile: path=0, title=1, yolo=2, worktree=3, terminal=4, group=5)
     dialog.handle_key(key(KeyCode::Char('w')));
     dialog.handle_key(key(KeyCode::Char('o')));
     dialog.handle_key(key(KeyCode::Char('r')));
@@ -602,12 +614,13 @@ fn test_new_branch_field_hidden_without_worktree() {
     let mut dialog = single_tool_dialog();
     assert_eq!(dialog.focused_field, 0);
 
-    // Tab through (single profile): title(0) -> path(1) -> yolo(2) -> worktree(3) -> group(4) -> wrap to 0
+    // Tab through (single profile): title(0) -> path(1) -> yolo(2) -> worktree(3) -> terminal(4) -> group(5) -> wrap to 0
     dialog.handle_key(key(KeyCode::Tab)); // 1 (path)
     dialog.handle_key(key(KeyCode::Tab)); // 2 (yolo)
     dialog.handle_key(key(KeyCode::Tab)); // 3 (worktree)
-    dialog.handle_key(key(KeyCode::Tab)); // 4 (group)
-    assert_eq!(dialog.focused_field, 4);
+    dialog.handle_key(key(KeyCode::Tab)); // 4 (terminal)
+    dialog.handle_key(key(KeyCode::Tab)); // 5 (group)
+    assert_eq!(dialog.focused_field, 5);
     dialog.handle_key(key(KeyCode::Tab)); // Should wrap to 0
     assert_eq!(dialog.focused_field, 0);
 }
@@ -696,14 +709,17 @@ fn test_tab_skips_sandbox_options_in_main_form() {
     dialog.sandbox_enabled = true;
 
     // With sandbox enabled, sandbox sub-options are in separate mode now.
-    // Main form (single profile): title(0), path(1), tool(2), yolo(3), worktree(4), sandbox(5), group(6)
+    // Main form (single profile): title(0), path(1), tool(2), yolo(3), worktree(4), sandbox(5), terminal(6), group(7)
     for _ in 0..5 {
         dialog.handle_key(key(KeyCode::Tab));
     }
     assert_eq!(dialog.focused_field, 5); // sandbox field
 
     dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.focused_field, 6); // group field (no sandbox sub-options inline)
+    assert_eq!(dialog.focused_field, 6); // terminal (no sandbox sub-options inline)
+
+    dialog.handle_key(key(KeyCode::Tab));
+    assert_eq!(dialog.focused_field, 7); // group field
 
     dialog.handle_key(key(KeyCode::Tab));
     assert_eq!(dialog.focused_field, 0); // wrap to start
@@ -715,14 +731,17 @@ fn test_tab_skips_sandbox_when_disabled() {
     dialog.docker_available = true;
     dialog.sandbox_enabled = false;
 
-    // Single profile: title(0), path(1), tool(2), yolo(3), worktree(4), sandbox(5), group(6)
+    // Single profile: title(0), path(1), tool(2), yolo(3), worktree(4), sandbox(5), terminal(6), group(7)
     for _ in 0..5 {
         dialog.handle_key(key(KeyCode::Tab));
     }
     assert_eq!(dialog.focused_field, 5); // sandbox field
 
     dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.focused_field, 6); // group field
+    assert_eq!(dialog.focused_field, 6); // terminal
+
+    dialog.handle_key(key(KeyCode::Tab));
+    assert_eq!(dialog.focused_field, 7); // group field
 
     dialog.handle_key(key(KeyCode::Tab));
     assert_eq!(dialog.focused_field, 0); // wrap to start
@@ -823,6 +842,25 @@ fn test_sandbox_image_input_in_config_mode() {
     assert_eq!(dialog.sandbox_image.value(), expected);
 }
 
+#[test]
+fn test_sandbox_image_presence_redraws_when_probe_lands() {
+    use crate::containers;
+    let image = "aoe-test/presence-hint:latest";
+    let mut dialog = multi_tool_dialog();
+    dialog.docker_available = true;
+    dialog.sandbox_enabled = true;
+    dialog.sandbox_config_mode = true;
+    dialog.sandbox_focused_field = 0; // editing the image, so no probe starts
+    dialog.sandbox_image = Input::new(image.to_string());
+
+    assert_eq!(dialog.sandbox_image_presence(), None);
+    assert!(!dialog.tick());
+
+    containers::image_presence::cache().record(containers::runtime_binary(), image, false);
+    assert_eq!(dialog.sandbox_image_presence(), Some(false));
+    assert!(dialog.tick());
+}
+
 #[test]
 fn test_yolo_mode_disabled_by_default() {
     let dialog = multi_tool_dialog();
@@ -1438,11 +1476,84 @@ fn test_sandbox_config_mode_tab_cycles() {
     dialog.sandbox_config_mode = true;
     dialog.sandbox_focused_field = 0;
 
-    dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.sandbox_focused_field, 1);
+    for expected in [1, 2, 3, 4, 0] {
+        dialog.handle_key(key(KeyCode::Tab));
+        assert_eq!(dialog.sandbox_focused_field, expected);
+    }
+}
 
-    dialog.handle_key(key(KeyCode::Tab));
-    assert_eq!(dialog.sandbox_focused_field, 0); // wrap
+#[test]
+fn test_sandbox_resource_limits_submit_as_overrides() {
+    let mut dialog = multi_tool_dialog();
+    dialog.docker_available = true;
+    dialog.sandbox_enabled = true;
+    dialog.sandbox_config_mode = true;
+    dialog.sandbox_focused_field = 3; // memory limit
+    for c in "16g".chars() {
+        dialog.handle_key(key(KeyCode::Char(c)));
+    }
+    dialog.handle_key(key(KeyCode::Esc));
+
+    match dialog.handle_key(key(KeyCode::Enter)) {
+        DialogResult::Submit(data) => {
+            assert_eq!(data.memory_limit.as_deref(), Some("16g"));
+            assert_eq!(data.cpu_limit, None, "blank CPU limit inherits the profile");
+        }
+        _ => panic!("Expected Submit"),
+    }
+}
+
+#[test]
+fn test_invalid_sandbox_resource_limit_blocks_submit() {
+    let mut dialog = multi_tool_dialog();
+    dialog.docker_available = true;
+    dialog.sandbox_enabled = true;
+    dialog.memory_limit = Input::new("512".to_string());
+
+    let result = dialog.handle_key(key(KeyCode::Enter));
+    assert!(matches!(result, DialogResult::Continue));
+    assert!(dialog
+        .error_message
+        .as_deref()
+        .is_some_and(|e| e.contains("Memory limit")));
+
+    // Limits are ignored entirely once the sandbox is off.
+    dialog.sandbox_enabled = false;
+    assert!(matches!(
+        dialog.handle_key(key(KeyCode::Enter)),
+        DialogResult::Submit(_)
+    ));
+}
+
+#[test]
+fn test_sandbox_prompt_file_submits_its_contents() {
+    let tmp = tempfile::tempdir().unwrap();
+    let prompt = tmp.path().join("prompt.md");
+    let mut dialog = multi_tool_dialog();
+    dialog.docker_available = true;
+    dialog.sandbox_enabled = true;
+    dialog.prompt_file = Input::new(prompt.to_string_lossy().to_string());
+
+    // A missing file blocks submission with the path in the message.
+    assert!(matches!(
+        dialog.handle_key(key(KeyCode::Enter)),
+        DialogResult::Continue
+    ));
+    assert!(dialog
+        .error_message
+        .as_deref()
+        .is_some_and(|e| e.contains("prompt.md")));
+
+    std::fs::write(&prompt, "review the open PR\n").unwrap();
+    match dialog.handle_key(key(KeyCode::Enter)) {
+        DialogResult::Submit(data) => {
+            assert_eq!(
+                data.custom_instruction.as_deref(),
+                Some("review the open PR")
+            );
+        }
+        _ => panic!("Expected Submit"),
+    }
 }
 
 #[test]
@@ -1651,3 +1762,56 @@ fn click_on_worktree_while_scratch_on_surfaces_error_not_toggle() {
     );
     assert!(dialog.error_message.is_some());
 }
+
+#[test]
+fn test_terminal_checkbox_submits_auto_create_terminal() {
+    let mut dialog = single_tool_dialog();
+    assert!(!dialog.auto_create_terminal);
+    dialog.focused_field = 4; // terminal
+    dialog.handle_key(key(KeyCode::Char(' ')));
+    assert!(dialog.auto_create_terminal);
+
+    match dialog.handle_key(key(KeyCode::Enter)) {
+        DialogResult::Submit(data) => assert!(data.auto_create_terminal),
+        _ => panic!("Expected Submit"),
+    }
+}
+
+#[test]
+#[serial_test::serial]
+fn test_worktree_plan_follows_branch_and_new_branch_toggle() {
+    let repo_dir = tempfile::tempdir().expect("repo dir");
+    let repo = git2::Repository::init(repo_dir.path()).expect("init");
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let tree = repo
+        .find_tree(repo.index().unwrap().write_tree().unwrap())
+        .unwrap();
+    let head = repo
+        .commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+        .unwrap();
+    repo.branch("taken", &repo.find_commit(head).unwrap(), false)
+        .unwrap();
+
+    let mut dialog = single_tool_dialog();
+    dialog.path = Input::new(repo_dir.path().to_string_lossy().to_string());
+    assert!(dialog.worktree_plan().is_none(), "no plan before a branch");
+
+    dialog.worktree_branch = Input::new("taken".to_string());
+    dialog.create_new_branch = true;
+    let plan = dialog.worktree_plan().expect("plan").clone();
+    assert_eq!(
+        plan.conflict("taken").as_deref(),
+        Some("branch 'taken' already exists")
+    );
+
+    dialog.create_new_branch = false;
+    let plan = dialog.worktree_plan().expect("plan").clone();
+    assert!(plan.branch_exists_locally && !plan.creates_branch);
+    assert_eq!(plan.conflict("taken"), None);
+
+    dialog.worktree_branch = Input::new("feat/fresh".to_string());
+    dialog.create_new_branch = true;
+    let plan = dialog.worktree_plan().expect("plan").clone();
+    assert_eq!(plan.conflict("feat/fresh"), None);
+    assert!(plan.path.to_string_lossy().contains("feat-fresh"));
+}
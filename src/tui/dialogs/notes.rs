@@ -0,0 +1,141 @@
+//! Edit-session-notes dialog.
+//!
+//! Single-line free-text note attached to a session ("investigating flaky
+//! test X"). Shown in the preview info header and matched by `/` search.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use tui_input::backend::crossterm::EventHandler;
+use tui_input::Input;
+
+use super::DialogResult;
+use crate::tui::components::render_text_field;
+use crate::tui::styles::Theme;
+
+pub struct NotesDialog {
+    session_title: String,
+    notes: Input,
+}
+
+impl NotesDialog {
+    pub fn new(session_title: &str, current_notes: Option<&str>) -> Self {
+        Self {
+            session_title: session_title.to_string(),
+            notes: Input::new(current_notes.unwrap_or_default().to_string()),
+        }
+    }
+
+    /// Submits the trimmed note; `None` clears it.
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<Option<String>> {
+        match key.code {
+            KeyCode::Esc => DialogResult::Cancel,
+            KeyCode::Enter => {
+                let notes = self.notes.value().trim();
+                DialogResult::Submit((!notes.is_empty()).then(|| notes.to_string()))
+            }
+            _ => {
+                self.notes.handle_event(&crossterm::event::Event::Key(key));
+                DialogResult::Continue
+            }
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        for ch in text.chars().filter(|c| *c != '\n' && *c != '\r') {
+            self.notes.handle(tui_input::InputRequest::InsertChar(ch));
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let dialog_area = super::centered_rect(area, 64, 9);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1))
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Session Notes ")
+            .title_style(Style::default().fg(theme.title).bold());
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1), // session title
+                Constraint::Length(1), // spacer
+                Constraint::Length(1), // notes field
+                Constraint::Length(1), // spacer
+                Constraint::Min(1),    // hint
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Session: ", Style::default().fg(theme.dimmed)),
+                Span::styled(&self.session_title, Style::default().fg(theme.text)),
+            ])),
+            chunks[0],
+        );
+
+        render_text_field(
+            frame,
+            chunks[2],
+            "Notes:",
+            &self.notes,
+            true,
+            Some("what is this session doing?"),
+            theme,
+        );
+
+        let hint = Line::from(vec![
+            Span::styled("Enter", Style::default().fg(theme.hint)),
+            Span::raw(" save (empty clears)  "),
+            Span::styled("Esc", Style::default().fg(theme.hint)),
+            Span::raw(" cancel"),
+        ]);
+        frame.render_widget(Paragraph::new(hint), chunks[4]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    #[test]
+    fn prefills_and_submits_trimmed_notes() {
+        let mut d = NotesDialog::new("s", Some("flaky"));
+        for c in " test ".chars() {
+            d.handle_key(key(KeyCode::Char(c)));
+        }
+        match d.handle_key(key(KeyCode::Enter)) {
+            DialogResult::Submit(notes) => assert_eq!(notes.as_deref(), Some("flaky test")),
+            _ => panic!("expected submit"),
+        }
+    }
+
+    #[test]
+    fn empty_notes_submit_clears() {
+        let mut d = NotesDialog::new("s", None);
+        d.handle_key(key(KeyCode::Char(' ')));
+        assert!(matches!(
+            d.handle_key(key(KeyCode::Enter)),
+            DialogResult::Submit(None)
+        ));
+    }
+
+    #[test]
+    fn paste_drops_newlines() {
+        let mut d = NotesDialog::new("s", None);
+        d.handle_paste("line one\nline two\r\n");
+        assert_eq!(d.notes.value(), "line oneline two");
+    }
+}
@@ -0,0 +1,148 @@
+//! Progress dialog for long-running background operations.
+//!
+//! The operation keeps running whether or not the dialog is open: Esc only
+//! hides it, and the status line still reports the outcome when the work
+//! finishes.
+
+use std::time::Instant;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use super::DialogResult;
+use crate::tui::components::progress::{animation_tick, progress_bar_line, ProgressHandle};
+use crate::tui::styles::Theme;
+
+pub struct ProgressDialog {
+    title: String,
+    handle: ProgressHandle,
+    started: Instant,
+}
+
+impl ProgressDialog {
+    pub fn new(title: &str, handle: ProgressHandle) -> Self {
+        Self {
+            title: title.to_string(),
+            handle,
+            started: Instant::now(),
+        }
+    }
+
+    /// The handle the background task reports through; the owner compares
+    /// it on completion so a finished operation closes only its own dialog.
+    pub fn handle(&self) -> &ProgressHandle {
+        &self.handle
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => DialogResult::Cancel,
+            _ => DialogResult::Continue,
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let dialog_area = super::centered_rect(area, 60, 9);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+            .title(format!(" {} ", self.title))
+            .title_style(Style::default().fg(theme.title).bold());
+
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let state = self.handle.snapshot();
+        frame.render_widget(
+            Paragraph::new(state.label.as_str()).style(Style::default().fg(theme.text)),
+            chunks[0],
+        );
+        frame.render_widget(
+            Paragraph::new(progress_bar_line(
+                chunks[1].width,
+                state.fraction,
+                animation_tick(),
+                theme,
+            )),
+            chunks[1],
+        );
+
+        let elapsed = self.started.elapsed().as_secs();
+        let mut status = format!("{}:{:02} elapsed", elapsed / 60, elapsed % 60);
+        if !state.detail.is_empty() {
+            status = format!("{}  ·  {}", state.detail, status);
+        }
+        frame.render_widget(
+            Paragraph::new(status).style(Style::default().fg(theme.dimmed)),
+            chunks[2],
+        );
+
+        let hint = Line::from(vec![
+            Span::styled("Esc", Style::default().fg(theme.hint)),
+            Span::styled(
+                " hide (keeps running in the background)",
+                Style::default().fg(theme.dimmed),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(hint).alignment(Alignment::Center), chunks[4]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_esc_hides_and_other_keys_are_swallowed() {
+        let mut dialog = ProgressDialog::new("Pulling Image", ProgressHandle::new("x"));
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert!(matches!(dialog.handle_key(esc), DialogResult::Cancel));
+        assert!(matches!(dialog.handle_key(q), DialogResult::Continue));
+    }
+
+    #[test]
+    fn test_render_shows_updates_from_background_handle() {
+        let handle = ProgressHandle::new("Downloading v2.0.0");
+        let mut dialog = ProgressDialog::new("Updating", handle.clone());
+        std::thread::spawn(move || handle.set_bytes(3 * 1024 * 1024, Some(4 * 1024 * 1024)))
+            .join()
+            .unwrap();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|f| dialog.render(f, f.area(), &Theme::default()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let screen: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(screen.contains("Downloading v2.0.0"));
+        assert!(screen.contains("75%"));
+        assert!(screen.contains("3.0 MiB / 4.0 MiB"));
+    }
+}
@@ -15,6 +15,7 @@ const OPTIONS: &[SortOrder] = &[
     SortOrder::Oldest,
     SortOrder::AZ,
     SortOrder::ZA,
+    SortOrder::Custom,
 ];
 
 pub struct SortPickerDialog {
@@ -0,0 +1,103 @@
+//! Worktree path collision prompt.
+//!
+//! Opens when background creation fails because the computed worktree path
+//! is already on disk. The user picks adopt or recreate and the original
+//! request is resubmitted with that `OnExists` policy.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use super::{DialogResult, NewSessionData};
+use crate::git::OnExists;
+use crate::session::HooksConfig;
+use crate::tui::styles::Theme;
+
+pub struct WorktreeExistsDialog {
+    path: String,
+    data: NewSessionData,
+    hooks: Option<HooksConfig>,
+}
+
+impl WorktreeExistsDialog {
+    pub fn new(path: &str, data: NewSessionData, hooks: Option<HooksConfig>) -> Self {
+        Self {
+            path: path.to_string(),
+            data,
+            hooks,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<OnExists> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => DialogResult::Cancel,
+            KeyCode::Char('a') | KeyCode::Char('A') => DialogResult::Submit(OnExists::Adopt),
+            KeyCode::Char('r') | KeyCode::Char('R') => DialogResult::Submit(OnExists::Recreate),
+            _ => DialogResult::Continue,
+        }
+    }
+
+    /// The original creation request with `on_exists` applied, ready to be
+    /// handed back to `request_creation`.
+    pub fn into_retry(self, on_exists: OnExists) -> (NewSessionData, Option<HooksConfig>) {
+        let mut data = self.data;
+        data.worktree_on_exists = on_exists;
+        (data, self.hooks)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let dialog_area = super::centered_rect(area, 64, 11);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.waiting))
+            .title(" Worktree Exists ")
+            .title_style(Style::default().fg(theme.waiting).bold());
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(2), // path
+                Constraint::Length(1), // spacer
+                Constraint::Length(1), // adopt
+                Constraint::Length(1), // recreate
+                Constraint::Length(1), // cancel
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::styled(
+                    "A worktree already exists at:",
+                    Style::default().fg(theme.dimmed),
+                ),
+                Line::styled(self.path.as_str(), Style::default().fg(theme.text)),
+            ]),
+            chunks[0],
+        );
+
+        let key_style = Style::default().fg(theme.waiting).bold();
+        let text_style = Style::default().fg(theme.text);
+        let options = [
+            ("a", "Adopt it (same branch only)", 2),
+            ("r", "Remove it and create a fresh one", 3),
+            ("Esc", "Cancel", 4),
+        ];
+        for (k, label, ci) in options {
+            frame.render_widget(
+                Paragraph::new(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(format!("[{k}]"), key_style),
+                    Span::raw("  "),
+                    Span::styled(label, text_style),
+                ])),
+                chunks[ci],
+            );
+        }
+    }
+}
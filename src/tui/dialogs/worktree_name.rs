@@ -3,6 +3,10 @@
 //! A focused dialog (separate from the title/group rename flow) for changing
 //! a managed worktree session's directory name, with an opt-in to also rename
 //! the git branch. See #1723.
+//!
+//! The same dialog, in [`WorktreeNameMode::Convert`], asks for the branch to
+//! move a plain-checkout session onto, with an opt-in to carry uncommitted
+//! changes over.
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::prelude::*;
@@ -14,35 +18,64 @@ use super::DialogResult;
 use crate::tui::components::render_text_field;
 use crate::tui::styles::Theme;
 
+/// What the dialog's name field and toggle stand for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorktreeNameMode {
+    /// Rename a managed worktree's directory; the toggle also renames the
+    /// branch.
+    EditWorkdir,
+    /// Convert a plain-checkout session into a worktree on the named branch;
+    /// the toggle carries uncommitted changes over.
+    Convert,
+}
+
 /// Data returned when the dialog is submitted.
 #[derive(Debug, Clone)]
 pub struct WorktreeNameData {
-    /// New workdir name (raw; sanitized downstream).
+    /// New workdir name, or the branch to convert onto (raw; sanitized
+    /// downstream).
     pub name: String,
     /// Whether to also rename the underlying git branch.
     pub rename_branch: bool,
+    /// Whether to carry uncommitted changes into the new worktree.
+    pub carry_changes: bool,
 }
 
 pub struct WorktreeNameDialog {
+    mode: WorktreeNameMode,
     current_dir: String,
     current_branch: String,
     new_name: Input,
-    rename_branch: bool,
-    /// 0 = name input, 1 = "rename branch" toggle.
+    toggled: bool,
+    /// 0 = name input, 1 = toggle.
     focused_field: usize,
 }
 
 impl WorktreeNameDialog {
     pub fn new(current_dir: &str, current_branch: &str) -> Self {
         Self {
+            mode: WorktreeNameMode::EditWorkdir,
             current_dir: current_dir.to_string(),
             current_branch: current_branch.to_string(),
             new_name: Input::default(),
-            rename_branch: false,
+            toggled: false,
             focused_field: 0,
         }
     }
 
+    /// Dialog for converting the checkout at `current_dir` (on
+    /// `current_branch`) into a worktree.
+    pub fn convert(current_dir: &str, current_branch: &str) -> Self {
+        Self {
+            mode: WorktreeNameMode::Convert,
+            ..Self::new(current_dir, current_branch)
+        }
+    }
+
+    pub fn mode(&self) -> WorktreeNameMode {
+        self.mode
+    }
+
     fn toggle_focused(&self) -> bool {
         self.focused_field == 1
     }
@@ -55,9 +88,11 @@ impl WorktreeNameDialog {
                 if name.is_empty() {
                     return DialogResult::Cancel;
                 }
+                let convert = self.mode == WorktreeNameMode::Convert;
                 DialogResult::Submit(WorktreeNameData {
                     name,
-                    rename_branch: self.rename_branch,
+                    rename_branch: self.toggled && !convert,
+                    carry_changes: self.toggled && convert,
                 })
             }
             KeyCode::Tab | KeyCode::Down => {
@@ -69,7 +104,7 @@ impl WorktreeNameDialog {
                 DialogResult::Continue
             }
             KeyCode::Char(' ') if self.toggle_focused() => {
-                self.rename_branch = !self.rename_branch;
+                self.toggled = !self.toggled;
                 DialogResult::Continue
             }
             _ => {
@@ -94,6 +129,20 @@ impl WorktreeNameDialog {
     }
 
     pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (title, field_label, toggle_label, submit_hint) = match self.mode {
+            WorktreeNameMode::EditWorkdir => (
+                " Edit Workdir Name ",
+                "New name:",
+                "Also rename git branch",
+                " save  ",
+            ),
+            WorktreeNameMode::Convert => (
+                " Convert to Worktree ",
+                "Branch:",
+                "Carry over uncommitted changes",
+                " convert  ",
+            ),
+        };
         let dialog_area = super::centered_rect(area, 54, 13);
         frame.render_widget(Clear, dialog_area);
 
@@ -102,7 +151,7 @@ impl WorktreeNameDialog {
             .border_type(BorderType::Rounded)
             .padding(Padding::horizontal(1))
             .border_style(Style::default().fg(theme.accent))
-            .title(" Edit Workdir Name ")
+            .title(title)
             .title_style(Style::default().fg(theme.title).bold());
         let inner = block.inner(dialog_area);
         frame.render_widget(block, dialog_area);
@@ -139,14 +188,14 @@ impl WorktreeNameDialog {
         render_text_field(
             frame,
             chunks[3],
-            "New name:",
+            field_label,
             &self.new_name,
             self.focused_field == 0,
             None,
             theme,
         );
 
-        let checkbox = if self.rename_branch { "[x]" } else { "[ ]" };
+        let checkbox = if self.toggled { "[x]" } else { "[ ]" };
         let toggle_style = if self.toggle_focused() {
             Style::default().fg(theme.accent)
         } else {
@@ -155,7 +204,7 @@ impl WorktreeNameDialog {
         frame.render_widget(
             Paragraph::new(Line::from(vec![
                 Span::styled(format!("{checkbox} "), toggle_style),
-                Span::styled("Also rename git branch", toggle_style),
+                Span::styled(toggle_label, toggle_style),
             ])),
             chunks[4],
         );
@@ -166,7 +215,7 @@ impl WorktreeNameDialog {
             Span::styled("Space", Style::default().fg(theme.hint)),
             Span::raw(" toggle  "),
             Span::styled("Enter", Style::default().fg(theme.hint)),
-            Span::raw(" save  "),
+            Span::raw(submit_hint),
             Span::styled("Esc", Style::default().fg(theme.hint)),
             Span::raw(" cancel"),
         ]);
@@ -228,6 +277,25 @@ mod tests {
         d.handle_key(key(KeyCode::Char(' ')));
         d.handle_key(key(KeyCode::Char('b')));
         assert_eq!(d.new_name.value(), "a b");
-        assert!(!d.rename_branch);
+        assert!(!d.toggled);
+    }
+
+    #[test]
+    fn convert_toggle_carries_changes_instead_of_renaming_branch() {
+        let mut d = WorktreeNameDialog::convert("repo", "main");
+        assert_eq!(d.mode(), WorktreeNameMode::Convert);
+        for c in "feat".chars() {
+            d.handle_key(key(KeyCode::Char(c)));
+        }
+        d.handle_key(key(KeyCode::Tab));
+        d.handle_key(key(KeyCode::Char(' ')));
+        match d.handle_key(key(KeyCode::Enter)) {
+            DialogResult::Submit(data) => {
+                assert_eq!(data.name, "feat");
+                assert!(data.carry_changes);
+                assert!(!data.rename_branch);
+            }
+            _ => panic!("expected submit"),
+        }
     }
 }
@@ -42,11 +42,47 @@ pub enum ActionId {
     AttachTerminal,
     ToggleView,
     SendMessage,
+    /// Run a one-off command in the selected session's shell (the paired
+    /// terminal, inside the container when sandboxed) and attach to watch
+    /// its output. Palette-only.
+    RunCommand,
     Stop,
+    /// Relaunch the selected session's agent inside its existing tmux pane,
+    /// keeping the worktree and container. Palette-only.
+    RestartAgent,
+    /// Remove and recreate the selected session's sandbox container from the
+    /// current config. Palette-only: destructive enough to not want a chord.
+    RebuildContainer,
+    /// Re-sync the selected session's agent config into the shared sandbox
+    /// dir now and offer to restart the agent. Palette-only.
+    SyncAgentConfig,
+    /// Copy a path out of the selected session's sandbox container onto the
+    /// host. Palette-only.
+    CopyFromContainer,
+    /// Create one session per chosen agent from the selected session, to
+    /// try the same task with several tools. Palette-only.
+    FanOut,
+    /// Run `git worktree prune` once in every repo referenced by a session.
+    /// Palette-only maintenance action.
+    PruneWorktrees,
     Delete,
     Rename,
     SetWorktreeName,
+    /// Move a plain-checkout session onto a new managed worktree.
+    /// Palette-only.
+    ConvertToWorktree,
+    /// Lock or unlock the selected session's worktree (`git worktree lock`
+    /// plus the session flag). Palette-only.
+    ToggleWorktreeLock,
+    /// Turn YOLO mode on or off for the selected existing session.
+    /// Palette-only.
+    ToggleYolo,
+    /// Edit the selected session's free-text notes.
+    EditNotes,
     Diff,
+    /// Browse the record of sessions, containers, worktrees and branches
+    /// aoe created or removed. Palette-only.
+    ActivityLog,
     Serve,
     Settings,
     Profiles,
@@ -58,9 +94,26 @@ pub enum ActionId {
     ToggleSnooze,
     ToggleContainer,
     TogglePreviewInfo,
+    /// Cycle the home layout: list + detail, list only, detail only.
+    CycleLayout,
     SortPicker,
     GroupBy,
+    /// Move the selected group up/down among its siblings and switch to the
+    /// Custom sort so the new order shows.
+    MoveGroupUp,
+    MoveGroupDown,
     NextWaiting,
+    /// Mute or unmute state-transition sounds for this run of the TUI. Not
+    /// persisted; `sound.enabled` in config is left untouched.
+    ToggleMute,
+    /// Copy the selected session's working directory to the clipboard (the
+    /// container path for sandboxed sessions).
+    CopyPath,
+    /// Copy the `aoe add` command that recreates the selected session.
+    /// Palette-only.
+    CopyAddCommand,
+    /// Open the selected session's working directory in the OS file manager.
+    RevealPath,
     /// Pin or unpin the selected project header (project view only). Pinning
     /// registers the repo so the project persists in the view without any
     /// sessions; unpinning removes the registry entry.
@@ -478,6 +531,61 @@ pub static BINDINGS: &[Binding] = &[
             serve_only: false,
         }),
     },
+    Binding {
+        id: ActionId::ToggleWorktreeLock,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Lock / unlock worktree",
+            keywords: &["worktree", "lock", "unlock", "protect", "keep", "pin"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::ToggleYolo,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Toggle YOLO mode",
+            keywords: &["yolo", "permissions", "auto-approve", "skip", "dangerous"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::RunCommand,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Run command in session shell",
+            keywords: &["exec", "shell", "one-off", "test", "terminal"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::EditNotes,
+        non_strict: &[k('a')],
+        strict: &[k('A')],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Actions,
+            desc: "Edit session notes",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Edit session notes",
+            keywords: &["notes", "note", "annotate", "description", "comment"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
     Binding {
         id: ActionId::Diff,
         non_strict: &[k('D')],
@@ -494,6 +602,19 @@ pub static BINDINGS: &[Binding] = &[
             serve_only: false,
         }),
     },
+    Binding {
+        id: ActionId::ActivityLog,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Open activity log",
+            keywords: &["history", "audit", "deleted", "removed"],
+            group: PaletteGroup::Views,
+            serve_only: false,
+        }),
+    },
     Binding {
         id: ActionId::Serve,
         non_strict: &[k('R')],
@@ -618,6 +739,51 @@ pub static BINDINGS: &[Binding] = &[
             serve_only: false,
         }),
     },
+    Binding {
+        id: ActionId::CopyPath,
+        non_strict: &[k('y')],
+        strict: &[k('Y')],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Actions,
+            desc: "Copy session path",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Copy session path to clipboard",
+            keywords: &["yank", "directory", "worktree", "clipboard"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::CopyAddCommand,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Copy session config as aoe add command",
+            keywords: &["reproduce", "share", "cli", "clipboard", "recreate"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::RevealPath,
+        non_strict: &[k('Y')],
+        strict: &[ctrl('y')],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Actions,
+            desc: "Open session path in file manager",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Reveal session path in file manager",
+            keywords: &["open", "finder", "folder", "directory", "explorer"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
     Binding {
         id: ActionId::TogglePreviewInfo,
         non_strict: &[k('i')],
@@ -634,6 +800,22 @@ pub static BINDINGS: &[Binding] = &[
             serve_only: false,
         }),
     },
+    Binding {
+        id: ActionId::CycleLayout,
+        non_strict: &[k('v')],
+        strict: &[k('V')],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Views,
+            desc: "Cycle layout (list/detail)",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Cycle layout: list + detail, list only, detail only",
+            keywords: &["layout", "compact", "fullscreen", "sidebar", "split"],
+            group: PaletteGroup::Views,
+            serve_only: false,
+        }),
+    },
     Binding {
         id: ActionId::SortPicker,
         // Shift+O sorts in both modes; bare `o` only outside strict.
@@ -667,6 +849,38 @@ pub static BINDINGS: &[Binding] = &[
             serve_only: false,
         }),
     },
+    Binding {
+        id: ActionId::MoveGroupUp,
+        non_strict: &[k('K')],
+        strict: &[k('K')],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Views,
+            desc: "Move group up (Custom sort)",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Move group up",
+            keywords: &["reorder", "order", "custom", "sort"],
+            group: PaletteGroup::Views,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::MoveGroupDown,
+        non_strict: &[k('J')],
+        strict: &[k('J')],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Views,
+            desc: "Move group down (Custom sort)",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Move group down",
+            keywords: &["reorder", "order", "custom", "sort"],
+            group: PaletteGroup::Views,
+            serve_only: false,
+        }),
+    },
     Binding {
         id: ActionId::NextWaiting,
         non_strict: &[k('w')],
@@ -674,15 +888,129 @@ pub static BINDINGS: &[Binding] = &[
         context: Context::Always,
         help: Some(HelpMeta {
             section: HelpSection::Attention,
-            desc: "Jump to next waiting/idle",
+            desc: "Jump to next waiting/error/idle",
         }),
         palette: Some(PaletteMeta {
-            title: "Jump to next waiting / idle session",
-            keywords: &["jump", "next", "waiting", "idle"],
+            title: "Jump to next session needing attention",
+            keywords: &["jump", "next", "waiting", "idle", "error", "attention"],
             group: PaletteGroup::Views,
             serve_only: false,
         }),
     },
+    Binding {
+        id: ActionId::ToggleMute,
+        non_strict: &[k('M')],
+        strict: &[],
+        context: Context::Always,
+        help: Some(HelpMeta {
+            section: HelpSection::Other,
+            desc: "Mute/unmute sounds",
+        }),
+        palette: Some(PaletteMeta {
+            title: "Mute / unmute sounds",
+            keywords: &["mute", "unmute", "sound", "silence", "audio"],
+            group: PaletteGroup::Settings,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::RebuildContainer,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Rebuild sandbox container",
+            keywords: &["recreate", "docker", "container", "sandbox", "config"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::CopyFromContainer,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Copy file out of sandbox container",
+            keywords: &[
+                "docker",
+                "cp",
+                "container",
+                "sandbox",
+                "artifact",
+                "download",
+            ],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::RestartAgent,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Restart agent in place",
+            keywords: &["relaunch", "respawn", "crash", "reload", "args"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::SyncAgentConfig,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Sync agent config into container",
+            keywords: &["refresh", "resync", "skills", "credentials", "sandbox"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::PruneWorktrees,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Prune stale worktrees",
+            keywords: &["git", "worktree", "prune", "cleanup", "maintenance"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::ConvertToWorktree,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Convert session to worktree",
+            keywords: &["worktree", "branch", "checkout", "move", "git"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
+    Binding {
+        id: ActionId::FanOut,
+        non_strict: &[],
+        strict: &[],
+        context: Context::Always,
+        help: None,
+        palette: Some(PaletteMeta {
+            title: "Fan out session to several agents",
+            keywords: &["clone", "duplicate", "experiment", "compare", "agents"],
+            group: PaletteGroup::Actions,
+            serve_only: false,
+        }),
+    },
 ];
 
 /// Stable palette/test id for an action (matches the legacy `builtin_commands`
@@ -695,11 +1023,23 @@ pub fn palette_id(id: ActionId) -> &'static str {
         ActionId::AttachTerminal => "attach-terminal",
         ActionId::ToggleView => "toggle-view",
         ActionId::SendMessage => "send-message",
+        ActionId::RunCommand => "run-command",
         ActionId::Stop => "stop",
+        ActionId::RestartAgent => "restart-agent",
+        ActionId::RebuildContainer => "rebuild-container",
+        ActionId::SyncAgentConfig => "sync-agent-config",
+        ActionId::CopyFromContainer => "copy-from-container",
+        ActionId::FanOut => "fan-out",
+        ActionId::PruneWorktrees => "prune-worktrees",
         ActionId::Delete => "delete",
         ActionId::Rename => "rename",
         ActionId::SetWorktreeName => "set-worktree-name",
+        ActionId::ConvertToWorktree => "convert-to-worktree",
+        ActionId::ToggleWorktreeLock => "toggle-worktree-lock",
+        ActionId::ToggleYolo => "toggle-yolo",
+        ActionId::EditNotes => "edit-notes",
         ActionId::Diff => "diff",
+        ActionId::ActivityLog => "activity-log",
         ActionId::Serve => "serve",
         ActionId::Settings => "settings",
         ActionId::Profiles => "profiles",
@@ -709,10 +1049,17 @@ pub fn palette_id(id: ActionId) -> &'static str {
         ActionId::ToggleFavorite => "favorite",
         ActionId::ToggleSnooze => "snooze",
         ActionId::TogglePreviewInfo => "toggle-preview-info",
+        ActionId::CycleLayout => "cycle-layout",
         ActionId::SortPicker => "pick-sort",
         ActionId::GroupBy => "pick-group-by",
+        ActionId::MoveGroupUp => "move-group-up",
+        ActionId::MoveGroupDown => "move-group-down",
         ActionId::Help => "help",
         ActionId::NextWaiting => "next-waiting",
+        ActionId::ToggleMute => "toggle-mute",
+        ActionId::CopyPath => "copy-path",
+        ActionId::CopyAddCommand => "copy-add-command",
+        ActionId::RevealPath => "reveal-path",
         ActionId::Quit => "quit",
         ActionId::ToolPicker => "tool-picker",
         ActionId::SearchStart => "search",
@@ -762,6 +1109,9 @@ mod tests {
             ('o', ActionId::SortPicker),
             ('g', ActionId::GroupBy),
             ('q', ActionId::Quit),
+            ('y', ActionId::CopyPath),
+            ('Y', ActionId::RevealPath),
+            ('v', ActionId::CycleLayout),
         ];
         for (ch, want) in cases {
             assert_eq!(
@@ -784,6 +1134,8 @@ mod tests {
             ('N', ActionId::NewSession),
             ('P', ActionId::Projects),
             ('O', ActionId::SortPicker),
+            ('Y', ActionId::CopyPath),
+            ('V', ActionId::CycleLayout),
         ];
         for (ch, want) in shifted {
             assert_eq!(resolve(&key(ch), true, &c), Some(want), "strict '{ch}'");
@@ -795,6 +1147,7 @@ mod tests {
             ('n', ActionId::NewFromSelection),
             ('p', ActionId::Profiles),
             ('g', ActionId::GroupBy),
+            ('y', ActionId::RevealPath),
         ];
         for (ch, want) in ctrled {
             assert_eq!(
@@ -810,7 +1163,7 @@ mod tests {
         // They fall through to the dispatcher's typing-guard, not an action.
         let c = ctx();
         for ch in [
-            'd', 'r', 't', 'n', 'p', 's', 'x', 'm', 'e', 'i', 'z', 'g', 'o',
+            'd', 'r', 't', 'n', 'p', 's', 'x', 'm', 'e', 'i', 'z', 'g', 'o', 'y', 'v',
         ] {
             assert_eq!(resolve(&key(ch), true, &c), None, "strict bare '{ch}'");
         }
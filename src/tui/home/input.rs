@@ -6,18 +6,20 @@ use tui_input::backend::crossterm::EventHandler;
 use tui_input::Input;
 
 use super::bindings::{self, ActionId};
-use super::{live_send, DragKind, HomeView, PreviewSelection, TerminalMode, ViewMode};
+use super::{live_send, DragKind, HomeView, PreviewSelection, TaskOutcome, TerminalMode, ViewMode};
 use crate::session::config::{load_config, save_config, GroupByMode, SortOrder};
 use crate::session::{list_profiles, repo_config, resolve_config_or_warn, Item, Status};
 use crate::tui::app::Action;
 #[cfg(feature = "serve")]
 use crate::tui::dialogs::ServeAction;
 use crate::tui::dialogs::{
-    builtin_commands, CommandPaletteDialog, ConfirmDialog, ContextMenuAction, ContextMenuDialog,
-    DeleteDialogConfig, DialogResult, GroupDeleteOptionsDialog, HooksInstallDialog, InfoDialog,
-    IntroOutcome, NewSessionData, NewSessionDialog, NoAgentsAction, PaletteAction, PaletteCommand,
-    PaletteGroup, ProfilePickerAction, ProjectsDialog, RenameDialog, RenameMode, RepoTrustAction,
-    RestartDialog, SendMessageDialog, UnifiedDeleteDialog, WorktreeNameDialog,
+    builtin_commands, ActivityLogDialog, CommandPaletteDialog, ConfirmDialog, ContainerCopyData,
+    ContainerCopyDialog, ContextMenuAction, ContextMenuDialog, DeleteDialogConfig, DeleteOptions,
+    DialogResult, FanOutData, FanOutDialog, GroupDeleteOptionsDialog, HooksInstallDialog,
+    InfoDialog, IntroOutcome, NewSessionData, NewSessionDialog, NoAgentsAction, NotesDialog,
+    PaletteAction, PaletteCommand, PaletteGroup, ProfilePickerAction, ProjectsDialog, RenameDialog,
+    RenameMode, RepoTrustAction, RestartDialog, SendMessageDialog, UnifiedDeleteDialog,
+    WorktreeNameDialog, WorktreeNameMode,
 };
 use crate::tui::diff::{DiffAction, DiffView};
 use crate::tui::responsive;
@@ -201,6 +203,65 @@ fn resolve_hook_install_agent(
         .filter(|agent| agent.hook_config.is_some())
 }
 
+/// The repo a fan-out starts from: the main checkout for a worktree
+/// session, so the new worktrees sit beside the source's rather than
+/// inside it.
+fn fan_out_repo_path(inst: &crate::session::Instance) -> String {
+    inst.worktree_info
+        .as_ref()
+        .map(|w| w.main_repo_path.clone())
+        .unwrap_or_else(|| inst.project_path.clone())
+}
+
+/// Branch name the fan-out's per-tool branches are built from: the source's
+/// worktree branch, else one derived from its title.
+fn fan_out_branch_stem(inst: &crate::session::Instance) -> String {
+    inst.worktree_info
+        .as_ref()
+        .map(|w| w.branch.clone())
+        .unwrap_or_else(|| crate::session::builder::branch_name_from_title(&inst.title))
+}
+
+/// Hooks for a fan-out session. There is no trust prompt mid fan-out, so
+/// repo hooks only run when the repo is already trusted, the same as
+/// skipping the prompt.
+fn fan_out_hooks(data: &NewSessionData) -> Option<crate::session::HooksConfig> {
+    use crate::session::TrustSurface;
+    match repo_config::check_repo_trust(std::path::Path::new(&data.path)) {
+        Ok(trust) => match trust.hooks {
+            TrustSurface::Trusted(h) => repo_config::merge_hooks_with_config(&data.profile, h),
+            _ => repo_config::resolve_global_profile_hooks(&data.profile),
+        },
+        Err(e) => {
+            tracing::warn!(target: "tui.input", "Failed to check repo trust: {}", e);
+            repo_config::resolve_global_profile_hooks(&data.profile)
+        }
+    }
+}
+
+/// Files listed by name in the agent-config sync dialog before the rest is
+/// collapsed into a count.
+const SYNC_SUMMARY_MAX_FILES: usize = 8;
+
+/// Dialog text listing the files an agent-config sync changed.
+fn sync_summary(changed: &[String]) -> String {
+    let noun = if changed.len() == 1 { "file" } else { "files" };
+    let mut lines = vec![format!("Updated {} {noun}:", changed.len())];
+    lines.extend(
+        changed
+            .iter()
+            .take(SYNC_SUMMARY_MAX_FILES)
+            .map(|f| format!("  {f}")),
+    );
+    if changed.len() > SYNC_SUMMARY_MAX_FILES {
+        lines.push(format!(
+            "  ...and {} more",
+            changed.len() - SYNC_SUMMARY_MAX_FILES
+        ));
+    }
+    lines.join("\n")
+}
+
 pub(super) fn parse_hotkey(s: &str) -> Option<(KeyCode, KeyModifiers)> {
     let (modifier, key) = s.split_once('+')?;
     if !modifier.eq_ignore_ascii_case("alt") {
@@ -751,6 +812,26 @@ impl HomeView {
                 None
             }
             "stop_session" => self.pending_stop_session.take().map(Action::StopSession),
+            "restart_session" => {
+                if let Err(e) = self.restart_selected_session(None, None, None, None) {
+                    tracing::warn!("restart_selected_session failed: {}", e);
+                    self.info_dialog = Some(InfoDialog::new(
+                        "Restart Failed",
+                        &format!("Could not restart session: {e}"),
+                    ));
+                }
+                None
+            }
+            "restart_agent" => {
+                if let Some(id) = self.pending_restart_agent.take() {
+                    self.restart_agent_in_place(&id);
+                }
+                None
+            }
+            "rebuild_container" => self
+                .pending_rebuild_container
+                .take()
+                .map(Action::RebuildContainer),
             "force_remove_session" => {
                 if let Some(session_id) = self.pending_force_remove_session.take() {
                     if let Err(e) = self.force_remove_session(&session_id) {
@@ -760,6 +841,13 @@ impl HomeView {
                 None
             }
             "pull_sandbox_image" => self.pending_image_pull.take().map(Action::SpawnImagePull),
+            "jump_to_session" => {
+                if let Some(session_id) = self.pending_jump_session.take() {
+                    self.new_dialog = None;
+                    self.select_and_reveal_session(&session_id);
+                }
+                None
+            }
             "quit_during_creation" => Some(Action::Quit),
             "quit" => Some(Action::Quit),
             _ => None,
@@ -832,9 +920,9 @@ impl HomeView {
         self.settings_view = None;
         self.confirm_dialog = None;
         self.settings_close_confirm = false;
-        // Theme is a global preference, not profile-merged: revert any live
-        // preview to the saved global theme so boot and Settings agree.
-        Action::SetTheme(crate::session::config::resolve_theme_name())
+        // Theme is never profile-merged: revert any live preview to the saved
+        // theme for the active profile filter so boot and Settings agree.
+        Action::SetTheme(self.active_theme_name())
     }
 
     pub fn handle_dialog_click(&mut self, col: u16, row: u16) -> bool {
@@ -922,8 +1010,11 @@ impl HomeView {
                     DialogResult::Cancel => {
                         self.confirm_dialog = None;
                         self.pending_stop_session = None;
+                        self.pending_rebuild_container = None;
+                        self.pending_restart_agent = None;
                         self.pending_force_remove_session = None;
                         self.pending_image_pull = None;
+                        self.pending_jump_session = None;
                         // The settings close path mirrors the keyboard
                         // route: Cancel here means "don't discard," so
                         // settings stays open and `settings_close_confirm`
@@ -962,6 +1053,10 @@ impl HomeView {
             let _ = view.handle_click(col, row);
             return true;
         }
+        if self.progress_dialog.is_some() || self.activity_log_dialog.is_some() {
+            // Nothing to click; swallow so the list underneath stays put.
+            return true;
+        }
         if let Some(dialog) = &self.info_dialog {
             if let Some(DialogResult::Cancel) = dialog.handle_click(col, row) {
                 self.info_dialog = None;
@@ -1022,7 +1117,7 @@ impl HomeView {
             if let Some(DialogResult::Submit(action)) = dialog.handle_click(col, row) {
                 match action {
                     NoAgentsAction::Recheck => {
-                        let tools = crate::tmux::AvailableTools::detect();
+                        let tools = crate::tmux::AvailableTools::redetect();
                         if tools.any_available() {
                             self.set_available_tools(tools);
                             self.no_agents_dialog = None;
@@ -1079,9 +1174,14 @@ impl HomeView {
             let _ = dialog.handle_click(col, row);
             return true;
         }
-        if self.worktree_name_dialog.is_some() {
-            // Keyboard-driven dialog; swallow clicks so the list underneath
-            // doesn't react while it's open.
+        if self.worktree_name_dialog.is_some()
+            || self.notes_dialog.is_some()
+            || self.container_copy_dialog.is_some()
+            || self.fan_out_dialog.is_some()
+            || self.worktree_exists_dialog.is_some()
+        {
+            // Keyboard-driven dialogs; swallow clicks so the list underneath
+            // doesn't react while they're open.
             return true;
         }
         if let Some(dialog) = &mut self.restart_dialog {
@@ -1170,6 +1270,26 @@ impl HomeView {
                             }
                         }
                         if let Some(data) = self.pending_hooks_install_data.take() {
+                            self.pending_dialog_click_action =
+                                self.maybe_confirm_unsandboxed_yolo(data);
+                        }
+                    }
+                }
+            }
+            return true;
+        }
+        if let Some(dialog) = &self.unsandboxed_yolo_dialog {
+            if let Some(result) = dialog.handle_click(col, row) {
+                match result {
+                    DialogResult::Continue => {}
+                    DialogResult::Cancel => {
+                        self.unsandboxed_yolo_dialog = None;
+                        self.pending_unsandboxed_yolo_data = None;
+                    }
+                    DialogResult::Submit(_) => {
+                        self.unsandboxed_yolo_dialog = None;
+                        if let Some(mut data) = self.pending_unsandboxed_yolo_data.take() {
+                            data.allow_unsandboxed_yolo = true;
                             self.pending_dialog_click_action =
                                 self.maybe_confirm_volume_ignores_globs(data);
                         }
@@ -1311,11 +1431,10 @@ impl HomeView {
                     self.settings_view = None;
                     // Refresh config-dependent state in case settings changed
                     self.refresh_from_config(crate::tui::home::ConfigRefreshOrigin::Interactive);
-                    // Reload the theme from the global config (theme is a global
-                    // preference, not profile-merged) so the repaint matches boot.
-                    return Some(Action::SetTheme(
-                        crate::session::config::resolve_theme_name(),
-                    ));
+                    // Reload the theme (global, or the filtered profile's
+                    // `theme_name`; never profile-merged) so the repaint
+                    // matches boot.
+                    return Some(Action::SetTheme(self.active_theme_name()));
                 }
                 SettingsAction::UnsavedChangesWarning => {
                     // Show confirmation dialog
@@ -1330,6 +1449,9 @@ impl HomeView {
                 SettingsAction::PreviewTheme(name) => {
                     return Some(Action::SetTheme(name));
                 }
+                SettingsAction::EditRawConfig(path) => {
+                    return Some(Action::EditFile(path));
+                }
             }
         }
 
@@ -1397,7 +1519,7 @@ impl HomeView {
                     return Some(Action::Quit);
                 }
                 DialogResult::Submit(NoAgentsAction::Recheck) => {
-                    let tools = crate::tmux::AvailableTools::detect();
+                    let tools = crate::tmux::AvailableTools::redetect();
                     if tools.any_available() {
                         self.set_available_tools(tools);
                         self.no_agents_dialog = None;
@@ -1471,6 +1593,20 @@ impl HomeView {
             return None;
         }
 
+        if let Some(dialog) = &mut self.progress_dialog {
+            if let DialogResult::Cancel = dialog.handle_key(key) {
+                self.progress_dialog = None;
+            }
+            return None;
+        }
+
+        if let Some(dialog) = &mut self.activity_log_dialog {
+            if let DialogResult::Cancel = dialog.handle_key(key) {
+                self.activity_log_dialog = None;
+            }
+            return None;
+        }
+
         if let Some(dialog) = &mut self.info_dialog {
             match dialog.handle_key(key) {
                 DialogResult::Continue => {}
@@ -1590,6 +1726,24 @@ impl HomeView {
                     }
                     // Resume session creation
                     if let Some(data) = self.pending_hooks_install_data.take() {
+                        return self.maybe_confirm_unsandboxed_yolo(data);
+                    }
+                }
+            }
+            return None;
+        }
+
+        if let Some(dialog) = &mut self.unsandboxed_yolo_dialog {
+            match dialog.handle_key(key) {
+                DialogResult::Continue => {}
+                DialogResult::Cancel => {
+                    self.unsandboxed_yolo_dialog = None;
+                    self.pending_unsandboxed_yolo_data = None;
+                }
+                DialogResult::Submit(_) => {
+                    self.unsandboxed_yolo_dialog = None;
+                    if let Some(mut data) = self.pending_unsandboxed_yolo_data.take() {
+                        data.allow_unsandboxed_yolo = true;
                         return self.maybe_confirm_volume_ignores_globs(data);
                     }
                 }
@@ -1703,7 +1857,7 @@ impl HomeView {
                         }
                     }
 
-                    return self.maybe_confirm_volume_ignores_globs(data);
+                    return self.maybe_confirm_unsandboxed_yolo(data);
                 }
             }
             return None;
@@ -1715,8 +1869,11 @@ impl HomeView {
                 DialogResult::Cancel => {
                     self.confirm_dialog = None;
                     self.pending_stop_session = None;
+                    self.pending_rebuild_container = None;
+                    self.pending_restart_agent = None;
                     self.pending_force_remove_session = None;
                     self.pending_image_pull = None;
+                    self.pending_jump_session = None;
                 }
                 DialogResult::Submit(_) => {
                     let action = dialog.action().to_string();
@@ -1804,11 +1961,16 @@ impl HomeView {
         }
 
         if let Some(dialog) = &mut self.worktree_name_dialog {
+            let mode = dialog.mode();
             match dialog.handle_key(key) {
                 DialogResult::Continue => {}
                 DialogResult::Cancel => {
                     self.worktree_name_dialog = None;
                 }
+                DialogResult::Submit(data) if mode == WorktreeNameMode::Convert => {
+                    self.worktree_name_dialog = None;
+                    self.convert_selected_to_worktree(&data.name, data.carry_changes);
+                }
                 DialogResult::Submit(data) => {
                     self.worktree_name_dialog = None;
                     if let Err(e) =
@@ -1824,6 +1986,69 @@ impl HomeView {
             return None;
         }
 
+        if let Some(dialog) = &mut self.notes_dialog {
+            match dialog.handle_key(key) {
+                DialogResult::Continue => {}
+                DialogResult::Cancel => {
+                    self.notes_dialog = None;
+                }
+                DialogResult::Submit(notes) => {
+                    self.notes_dialog = None;
+                    if let Err(e) = self.set_notes_for_selected(notes) {
+                        self.info_dialog = Some(InfoDialog::new(
+                            "Edit Notes Failed",
+                            &format!("Could not save the session notes: {e}"),
+                        ));
+                    }
+                }
+            }
+            return None;
+        }
+
+        if let Some(dialog) = &mut self.container_copy_dialog {
+            match dialog.handle_key(key) {
+                DialogResult::Continue => {}
+                DialogResult::Cancel => {
+                    self.container_copy_dialog = None;
+                }
+                DialogResult::Submit(data) => {
+                    self.container_copy_dialog = None;
+                    self.copy_from_container_for_selected(&data);
+                }
+            }
+            return None;
+        }
+
+        if let Some(dialog) = &mut self.fan_out_dialog {
+            match dialog.handle_key(key) {
+                DialogResult::Continue => {}
+                DialogResult::Cancel => {
+                    self.fan_out_dialog = None;
+                }
+                DialogResult::Submit(data) => {
+                    self.fan_out_dialog = None;
+                    self.fan_out_selected(&data);
+                }
+            }
+            return None;
+        }
+
+        if let Some(dialog) = &mut self.worktree_exists_dialog {
+            match dialog.handle_key(key) {
+                DialogResult::Continue => {}
+                DialogResult::Cancel => {
+                    self.worktree_exists_dialog = None;
+                }
+                DialogResult::Submit(on_exists) => {
+                    if let Some(dialog) = self.worktree_exists_dialog.take() {
+                        let (data, hooks) = dialog.into_retry(on_exists);
+                        self.request_creation(data, hooks);
+                    }
+                }
+            }
+            return None;
+        }
+
         if let Some(dialog) = &mut self.restart_dialog {
             match dialog.handle_key(key) {
                 DialogResult::Continue => {}
@@ -1932,6 +2157,8 @@ impl HomeView {
                         if let Err(e) = self.switch_profile(profile) {
                             tracing::error!(target: "tui.input", "Failed to switch profile: {}", e);
                         }
+                        // The new filter may carry its own theme.
+                        return Some(Action::SetTheme(self.active_theme_name()));
                     }
                     ProfilePickerAction::Created(name) => {
                         self.profile_picker_dialog = None;
@@ -1940,6 +2167,7 @@ impl HomeView {
                                 if let Err(e) = self.switch_profile(Some(name)) {
                                     tracing::error!(target: "tui.input", "Failed to switch to new profile: {}", e);
                                 }
+                                return Some(Action::SetTheme(self.active_theme_name()));
                             }
                             Err(e) => {
                                 self.info_dialog = Some(InfoDialog::new(
@@ -1977,10 +2205,15 @@ impl HomeView {
                     self.send_message_dialog = None;
                     self.pending_send_session = None;
                     self.pending_send_target = live_send::LiveSendTarget::Agent;
+                    self.pending_run_command = false;
                 }
                 DialogResult::Submit(message) => {
                     self.send_message_dialog = None;
+                    let run_command = std::mem::take(&mut self.pending_run_command);
                     if let Some(session_id) = self.pending_send_session.take() {
+                        if run_command {
+                            return Some(Action::RunCommand(session_id, message));
+                        }
                         // Defer the actual work to execute_action so the app
                         // loop can render a status indicator first. The send
                         // path may need to start a Docker container or wait
@@ -2039,12 +2272,19 @@ impl HomeView {
             return None;
         }
 
-        // Ctrl+K opens the command palette regardless of strict-hotkey mode.
-        // Activated here (before strict normalization) so the binding stays
-        // discoverable on every keymap.
-        if matches!(key.code, KeyCode::Char('k') | KeyCode::Char('K'))
-            && key.modifiers.contains(KeyModifiers::CONTROL)
-        {
+        // Ctrl+K (or `:`, as in vim) opens the command palette regardless of
+        // strict-hotkey mode. Activated here (before strict normalization) so
+        // the binding stays discoverable on every keymap.
+        let palette_key = match key.code {
+            KeyCode::Char('k') | KeyCode::Char('K') => {
+                key.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            KeyCode::Char(':') => !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT),
+            _ => false,
+        };
+        if palette_key {
             self.open_command_palette();
             return None;
         }
@@ -2221,6 +2461,9 @@ impl HomeView {
             ActionId::SearchStart => {
                 self.search_active = true;
                 self.search_query = Input::default();
+                // Titles and notes can change between searches without a
+                // list rebuild (rename, notes edit); start from fresh text.
+                self.search_index.invalidate();
             }
             ActionId::SearchNext => {
                 if self.search_matches.is_empty() {
@@ -2253,11 +2496,26 @@ impl HomeView {
                 };
             }
             ActionId::SendMessage => self.open_send_message_dialog(),
+            ActionId::RunCommand => self.open_run_command_dialog(),
             ActionId::Stop => self.stop_selected(),
+            ActionId::RestartAgent => self.restart_agent_for_selected(),
+            ActionId::RebuildContainer => self.rebuild_container_for_selected(),
+            ActionId::SyncAgentConfig => self.sync_agent_config_for_selected(),
+            ActionId::CopyFromContainer => self.open_container_copy_for_selected(),
+            ActionId::FanOut => self.open_fan_out_for_selected(),
+            ActionId::PruneWorktrees => self.prune_session_worktrees(),
             ActionId::Delete => self.open_delete_for_selected(),
             ActionId::Rename => self.open_rename_for_selected(),
             ActionId::SetWorktreeName => self.open_worktree_name_for_selected(),
+            ActionId::ConvertToWorktree => self.open_convert_to_worktree_for_selected(),
+            ActionId::ToggleWorktreeLock => self.toggle_worktree_lock_at_cursor(),
+            ActionId::ToggleYolo => self.toggle_yolo_at_cursor(),
+            ActionId::EditNotes => self.open_notes_for_selected(),
             ActionId::Diff => self.open_diff_for_selected(),
+            ActionId::ActivityLog => {
+                self.activity_log_dialog =
+                    Some(ActivityLogDialog::new(crate::session::activity::recent()));
+            }
             ActionId::Serve => self.open_serve(),
             ActionId::Settings => self.open_settings(),
             ActionId::Profiles => self.show_profile_picker(),
@@ -2286,10 +2544,17 @@ impl HomeView {
             }
             ActionId::ToggleContainer => self.toggle_container_for_selected(),
             ActionId::TogglePreviewInfo => self.toggle_preview_info(),
+            ActionId::CycleLayout => self.cycle_home_layout(),
             ActionId::SortPicker => self.show_sort_picker(),
             ActionId::GroupBy => self.show_group_picker(),
+            ActionId::MoveGroupUp => self.move_selected_group(true),
+            ActionId::MoveGroupDown => self.move_selected_group(false),
             ActionId::ToggleProjectPin => self.toggle_project_pin_at_cursor(),
             ActionId::NextWaiting => self.jump_to_next_waiting(),
+            ActionId::ToggleMute => self.sound_muted = !self.sound_muted,
+            ActionId::CopyPath => self.copy_path_for_selected(),
+            ActionId::CopyAddCommand => self.copy_add_command_for_selected(),
+            ActionId::RevealPath => self.reveal_path_for_selected(),
         }
         None
     }
@@ -2441,6 +2706,296 @@ impl HomeView {
         }
     }
 
+    fn restart_agent_for_selected(&mut self) {
+        let Some(session_id) = self.selected_session.clone() else {
+            return;
+        };
+        let Some(inst) = self.get_instance(&session_id) else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Creating) {
+            return;
+        }
+        if inst.status == Status::Running {
+            let message = format!(
+                "'{}' is working right now. Kill the agent and relaunch it \
+                 in place? The worktree and container are kept.",
+                inst.title
+            );
+            self.pending_restart_agent = Some(session_id);
+            self.confirm_dialog = Some(ConfirmDialog::new(
+                "Restart Agent",
+                &message,
+                "restart_agent",
+            ));
+            return;
+        }
+        self.restart_agent_in_place(&session_id);
+    }
+
+    fn rebuild_container_for_selected(&mut self) {
+        let Some(session_id) = &self.selected_session else {
+            return;
+        };
+        let Some(inst) = self.get_instance(session_id) else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Creating) {
+            return;
+        }
+        if !inst.is_sandboxed() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Not Sandboxed",
+                "Only sandboxed sessions have a container to rebuild.",
+            ));
+            return;
+        }
+        let message = format!(
+            "Rebuild the container for '{}' from the current config? \
+             The agent will be stopped. Anything stored inside the container \
+             outside the mounted project and agent config dirs will be lost.",
+            inst.title
+        );
+        self.pending_rebuild_container = Some(session_id.clone());
+        self.confirm_dialog = Some(ConfirmDialog::new(
+            "Rebuild Container",
+            &message,
+            "rebuild_container",
+        ));
+    }
+
+    /// Re-sync the selected sandboxed session's agent config from the host
+    /// and report what changed. The sandbox dirs are bind-mounted, so the
+    /// container sees the files at once; when the agent is running, offer a
+    /// restart for agents that only read their config at startup.
+    fn sync_agent_config_for_selected(&mut self) {
+        let Some(session_id) = self.selected_session.clone() else {
+            return;
+        };
+        let Some(inst) = self.get_instance(&session_id) else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Creating) {
+            return;
+        }
+        if !inst.is_sandboxed() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Not Sandboxed",
+                "Only sandboxed sessions have a container to sync agent config into.",
+            ));
+            return;
+        }
+        let title = inst.title.clone();
+        let tool = inst.tool.clone();
+        let profile = inst.effective_profile();
+        let agent_running = !matches!(inst.status, Status::Stopped | Status::Error);
+        let label = format!("Syncing agent config into '{title}'...");
+        // The sync copies and hashes the whole agent config dir, which can
+        // take a while for a large skills or plugins tree.
+        self.spawn_background_task("Syncing Agent Config", label, move || {
+            let changed =
+                match crate::session::container_config::sync_agent_config_now(&tool, &profile) {
+                    Ok(changed) => changed,
+                    Err(e) => {
+                        return TaskOutcome::Info(
+                            "Sync Failed".to_string(),
+                            format!("Could not sync agent config: {e}"),
+                        )
+                    }
+                };
+            if changed.is_empty() {
+                return TaskOutcome::Info(
+                    "Agent Config Synced".to_string(),
+                    format!("'{title}' already has the current agent config."),
+                );
+            }
+
+            let summary = sync_summary(&changed);
+            if !agent_running {
+                return TaskOutcome::Info(
+                    "Agent Config Synced".to_string(),
+                    format!("{summary}\nThe agent reads them when '{title}' starts."),
+                );
+            }
+            TaskOutcome::ConfirmRestartAgent {
+                id: session_id,
+                title: "Agent Config Synced".to_string(),
+                message: format!("{summary}\nRestart the agent in '{title}' so it picks them up?"),
+            }
+        });
+    }
+
+    fn open_container_copy_for_selected(&mut self) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Creating) {
+            return;
+        }
+        if !inst.is_sandboxed() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Not Sandboxed",
+                "Only sandboxed sessions have a container to copy files out of.",
+            ));
+            return;
+        }
+        self.container_copy_dialog =
+            Some(ContainerCopyDialog::new(&inst.title, &inst.project_path));
+    }
+
+    /// Open the fan-out dialog for the cursor's session. Scratch and
+    /// multi-repo sessions have no single repo to fan out from.
+    fn open_fan_out_for_selected(&mut self) {
+        if self.creating_stub_id.is_some() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Please Wait",
+                "A session is already being created. Wait for it to finish or press Ctrl+C to cancel.",
+            ));
+            return;
+        }
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Creating) {
+            return;
+        }
+        if inst.scratch || inst.workspace_info.is_some() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Cannot Fan Out",
+                "Fan-out needs a session on a single project directory.",
+            ));
+            return;
+        }
+        let repo_path = fan_out_repo_path(inst);
+        let worktree = crate::git::GitWorktree::is_git_repo(std::path::Path::new(&repo_path))
+            .then_some(inst.worktree_info.is_some());
+        self.fan_out_dialog = Some(FanOutDialog::new(
+            &inst.title,
+            self.available_tools.available_list(),
+            &fan_out_branch_stem(inst),
+            worktree,
+        ));
+    }
+
+    /// Queue one session per tool chosen in the [`FanOutDialog`], copying
+    /// the selected session's project, base branch, and sandbox settings.
+    /// The creation poller builds them one after another; see
+    /// `apply_creation_results`.
+    fn fan_out_selected(&mut self, fan_out: &FanOutData) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            return;
+        };
+        let sandbox = inst.sandbox_info.as_ref().filter(|s| s.enabled);
+        let base = NewSessionData {
+            profile: inst.source_profile.clone(),
+            title: inst.title.clone(),
+            path: fan_out_repo_path(inst),
+            group: String::new(),
+            tool: inst.tool.clone(),
+            worktree_enabled: false,
+            worktree_branch: None,
+            create_new_branch: false,
+            base_branch: inst.worktree_info.as_ref().map(|w| w.branch.clone()),
+            worktree_on_exists: crate::git::OnExists::Error,
+            extra_repo_paths: Vec::new(),
+            sandbox: sandbox.is_some(),
+            sandbox_image: sandbox.map(|s| s.image.clone()).unwrap_or_default(),
+            yolo_mode: inst.yolo_mode,
+            // The source session already went through the YOLO prompt.
+            allow_unsandboxed_yolo: inst.yolo_mode && sandbox.is_none(),
+            extra_env: sandbox
+                .and_then(|s| s.extra_env.clone())
+                .unwrap_or_default(),
+            cpu_limit: sandbox.and_then(|s| s.cpu_limit.clone()),
+            memory_limit: sandbox.and_then(|s| s.memory_limit.clone()),
+            custom_instruction: sandbox.and_then(|s| s.custom_instruction.clone()),
+            extra_args: String::new(),
+            command_override: String::new(),
+            scratch: false,
+            auto_create_terminal: inst.auto_create_terminal,
+        };
+        let hooks = fan_out_hooks(&base);
+        let mut queue: std::collections::VecDeque<_> = fan_out
+            .sessions(&base, &fan_out_branch_stem(inst))
+            .into_iter()
+            .map(|data| (data, hooks.clone()))
+            .collect();
+        if let Err(e) = self.check_session_limit(&base.profile, queue.len()) {
+            self.info_dialog = Some(InfoDialog::new("Session Limit Reached", &e.to_string()));
+            return;
+        }
+        let Some((first, hooks)) = queue.pop_front() else {
+            return;
+        };
+        self.fan_out_queue = Some(queue);
+        self.request_creation(first, hooks);
+    }
+
+    /// Run the copy for a submitted [`ContainerCopyDialog`]. Relative
+    /// container paths resolve against the session's container workdir and
+    /// relative host paths against its project directory.
+    fn copy_from_container_for_selected(&mut self, data: &ContainerCopyData) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            return;
+        };
+        let container_path = if data.container_path.starts_with('/') {
+            data.container_path.clone()
+        } else {
+            format!("{}/{}", inst.container_workdir(), data.container_path)
+        };
+        let host_path = std::path::Path::new(&inst.project_path).join(&data.host_path);
+        let container = crate::containers::DockerContainer::from_session_id(&inst.id);
+        let label = format!("Copying {container_path}");
+        // `docker cp` of a large tree can take a while; keep it off the UI
+        // thread.
+        self.spawn_background_task("Copying From Container", label, move || {
+            match container.copy_from(&container_path, &host_path) {
+                Ok(dest) => (
+                    "Copied From Container".to_string(),
+                    format!("Copied {container_path} to {}", dest.display()),
+                ),
+                Err(e) => (
+                    "Copy Failed".to_string(),
+                    format!("Could not copy {container_path}: {e}"),
+                ),
+            }
+        });
+    }
+
+    /// Prune stale worktree entries in every repo the loaded sessions use and
+    /// report the totals.
+    fn prune_session_worktrees(&mut self) {
+        // One `git worktree prune` per repo can add up across many repos.
+        let instances = self.instances.clone();
+        self.spawn_background_task(
+            "Pruning Worktrees",
+            "Pruning stale worktree entries...",
+            move || {
+                let results = crate::git::cleanup::prune_session_repos(&instances);
+                (
+                    "Prune Worktrees".to_string(),
+                    crate::git::cleanup::summarize_prune(&results),
+                )
+            },
+        );
+    }
+
     fn open_diff_for_selected(&mut self) {
         // Open diff view - requires a selected session.
         let Some(session_id) = &self.selected_session else {
@@ -2573,7 +3128,13 @@ impl HomeView {
             if let Some(inst) = self.get_instance(id) {
                 if inst.is_sandboxed() {
                     let id = id.clone();
-                    self.toggle_terminal_mode(&id);
+                    if let Err(e) = self.toggle_terminal_mode(&id) {
+                        tracing::warn!(
+                            target: "tui.home",
+                            "Failed to persist terminal_mode: {}",
+                            e
+                        );
+                    }
                 } else {
                     self.info_dialog = Some(InfoDialog::new(
                         "Not Available",
@@ -2584,6 +3145,91 @@ impl HomeView {
         }
     }
 
+    /// Copy the selected session's working directory to the clipboard. A
+    /// sandboxed agent sees the project at its container workdir, so that is
+    /// the path copied there, with the dialog saying so.
+    fn copy_path_for_selected(&mut self) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            self.info_dialog = Some(InfoDialog::new(
+                "No Session Selected",
+                "Select a session to copy its path.",
+            ));
+            return;
+        };
+        let message = if inst.is_sandboxed() {
+            let path = inst.container_workdir();
+            crate::tui::clipboard::copy_to_clipboard(&path);
+            format!("Copied {path}\n(path inside the sandbox container)")
+        } else {
+            crate::tui::clipboard::copy_to_clipboard(&inst.project_path);
+            format!("Copied {}", inst.project_path)
+        };
+        self.info_dialog = Some(InfoDialog::new("Path Copied", &message));
+    }
+
+    /// Copy the `aoe add` command line that recreates the selected session,
+    /// so a setup can be reproduced on another machine.
+    fn copy_add_command_for_selected(&mut self) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            self.info_dialog = Some(InfoDialog::new(
+                "No Session Selected",
+                "Select a session to copy its command.",
+            ));
+            return;
+        };
+        let profile = if inst.source_profile.is_empty() {
+            self.config_profile()
+        } else {
+            inst.source_profile.clone()
+        };
+        let command = crate::session::add_command::add_command_for(inst, &profile);
+        crate::tui::clipboard::copy_to_clipboard(&command);
+        self.info_dialog = Some(InfoDialog::new(
+            "Command Copied",
+            &format!("Copied {command}"),
+        ));
+    }
+
+    /// Open the selected session's host working directory in the OS file
+    /// manager. Sandboxed sessions bind-mount this directory, so the host
+    /// path is the right one to reveal for them too.
+    fn reveal_path_for_selected(&mut self) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            self.info_dialog = Some(InfoDialog::new(
+                "No Session Selected",
+                "Select a session to open its path.",
+            ));
+            return;
+        };
+        let path = std::path::PathBuf::from(&inst.project_path);
+        if !path.is_dir() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Path Not Found",
+                &format!("{} does not exist on this machine.", path.display()),
+            ));
+            return;
+        }
+        if let Err(e) = crate::process::open_in_file_manager(&path) {
+            tracing::warn!(target: "tui.home", "open_in_file_manager failed: {e}");
+            self.info_dialog = Some(InfoDialog::new(
+                "Could Not Open",
+                &format!("Failed to open {}: {e}", path.display()),
+            ));
+        }
+    }
+
     fn open_command_palette(&mut self) {
         let serve_enabled = cfg!(feature = "serve");
         let mut entries: Vec<PaletteCommand> = builtin_commands(serve_enabled, self.strict_hotkeys);
@@ -2597,6 +3243,7 @@ impl HomeView {
             group: PaletteGroup::Settings,
             keywords: vec!["exit", "close"],
             hotkey: bindings::label(ActionId::Quit, self.strict_hotkeys),
+            description: None,
             payload: PaletteAction::Invoke(ActionId::Quit),
         });
 
@@ -2630,6 +3277,7 @@ impl HomeView {
                         group: PaletteGroup::Sessions,
                         keywords: vec!["session", "jump", "select"],
                         hotkey: String::new(),
+                        description: None,
                         payload: PaletteAction::JumpToCursor(idx),
                     });
                 }
@@ -2654,6 +3302,7 @@ impl HomeView {
                         group: PaletteGroup::Groups,
                         keywords: vec!["group", "jump"],
                         hotkey: String::new(),
+                        description: None,
                         payload: PaletteAction::JumpToCursor(idx),
                     });
                 }
@@ -2676,6 +3325,7 @@ impl HomeView {
                 group: PaletteGroup::Actions,
                 keywords: vec!["tool", "session"],
                 hotkey: String::new(),
+                description: None,
                 payload: PaletteAction::ToolSession(name.clone()),
             });
         }
@@ -2739,11 +3389,16 @@ impl HomeView {
         }
 
         // Pass 1: forward-walk from cursor+1, wrapping, for the next Waiting
-        // session OR a freshly-stopped Idle session (within
-        // `idle_decay_window`). Both states are "needs your attention" and
+        // or crashed (Error) session OR a freshly-stopped Idle session (within
+        // `idle_decay_window`). All of these "need your attention" and
         // cycle together so repeated `w` taps move through the actionable
         // backlog regardless of which hook fired.
         let window = self.idle_decay_window;
+        let needs_attention = |inst: &crate::session::Instance| {
+            inst.status == Status::Waiting
+                || (inst.status == Status::Error && !inst.is_tmux_gone())
+                || matches!(inst.idle_age(), Some(age) if age < window)
+        };
         let start = (self.cursor + 1) % len;
         for i in 0..len - 1 {
             let idx = (start + i) % len;
@@ -2751,17 +3406,39 @@ impl HomeView {
                 Some(Item::Session { id, .. }) => id.clone(),
                 _ => continue,
             };
-            if let Some(inst) = self.get_instance(&id) {
-                let is_actionable = inst.status == Status::Waiting
-                    || matches!(inst.idle_age(), Some(age) if age < window);
-                if is_actionable {
-                    self.cursor = idx;
-                    self.update_selected();
-                    return;
-                }
+            if self.get_instance(&id).is_some_and(needs_attention) {
+                self.cursor = idx;
+                self.update_selected();
+                return;
             }
         }
 
+        // Pass 1b: a Waiting or Error session hidden inside a collapsed group
+        // still needs attention; expand its group to bring it into view.
+        // Archived and snoozed sessions are parked on purpose and stay hidden.
+        let hidden = self
+            .instances
+            .iter()
+            .filter(|inst| {
+                matches!(inst.status, Status::Waiting | Status::Error)
+                    && needs_attention(inst)
+                    && !inst.is_archived()
+                    && !inst.is_snoozed()
+                    && Some(&inst.id) != self.selected_session.as_ref()
+            })
+            .map(|inst| inst.id.clone())
+            .find(|id| {
+                !self
+                    .flat_items
+                    .iter()
+                    .any(|item| matches!(item, Item::Session { id: row, .. } if row == id))
+            });
+        if let Some(id) = hidden {
+            self.select_and_reveal_session(&id);
+            self.update_selected();
+            return;
+        }
+
         // Pass 2: fall back to the most-recently-accessed Idle session, skipping
         // the cursor. Sessions never attached (last_accessed_at == None) rank
         // last but remain eligible.
@@ -2802,7 +3479,7 @@ impl HomeView {
 
         self.info_dialog = Some(InfoDialog::new(
             "No Available Sessions",
-            "No sessions are currently waiting or idle.",
+            "No sessions are currently waiting, errored, or idle.",
         ));
     }
 
@@ -3013,7 +3690,7 @@ impl HomeView {
 
     fn apply_sort_order(&mut self, new_order: SortOrder) {
         self.sort_order = new_order;
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         if self.search_active && !self.search_query.value().is_empty() {
             self.update_search();
         } else {
@@ -3029,7 +3706,7 @@ impl HomeView {
 
     fn apply_group_by(&mut self, new_mode: GroupByMode) {
         self.group_by = new_mode;
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         self.reseat_cursor_after_rebuild();
         match load_config().map(|c| c.unwrap_or_default()) {
             Ok(mut config) => {
@@ -3061,7 +3738,7 @@ impl HomeView {
                 .unwrap_or(false);
             self.project_group_collapsed
                 .insert(path.to_string(), !collapsed);
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             return;
         }
         // Route to the correct profile's GroupTree
@@ -3071,12 +3748,55 @@ impl HomeView {
                 tree.toggle_collapsed(path);
             }
         }
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         if let Err(e) = self.save() {
             tracing::error!(target: "tui.input", "Failed to save group state: {}", e);
         }
     }
 
+    /// Move the selected group one place up or down among its siblings.
+    /// Switches to the Custom sort (the only one that honors the manual
+    /// order) and keeps the cursor on the moved group. No-op on sessions,
+    /// project headers and the synthetic Archived section.
+    pub(super) fn move_selected_group(&mut self, up: bool) {
+        if self.group_by == GroupByMode::Project {
+            return;
+        }
+        let Some(path) = self.selected_group.clone() else {
+            return;
+        };
+        if crate::session::is_archived_section_path(&path) {
+            return;
+        }
+        let Some(profile) = self.profile_for_cursor(self.cursor) else {
+            return;
+        };
+        let moved = self
+            .group_trees
+            .get_mut(&profile)
+            .is_some_and(|tree| tree.move_group(&path, up));
+        if !moved {
+            return;
+        }
+
+        if self.sort_order == SortOrder::Custom {
+            self.rebuild_flat_items();
+        } else {
+            self.apply_sort_order(SortOrder::Custom);
+        }
+        let idx = self.flat_items.iter().position(|item| {
+            matches!(item, Item::Group { path: p, profile: gp, .. }
+                if *p == path && gp.as_deref().is_none_or(|gp| gp == profile))
+        });
+        if let Some(idx) = idx {
+            self.cursor = idx;
+        }
+        self.update_selected();
+        if let Err(e) = self.save() {
+            tracing::error!(target: "tui.input", "Failed to save group order: {}", e);
+        }
+    }
+
     /// Route a mouse-wheel-up at (col, row) to the pane under the cursor:
     /// diff view (if open) → diff scroll; list pane → list cursor up;
     /// preview pane → preview scroll. Returns `true` if the UI should
@@ -3537,6 +4257,100 @@ impl HomeView {
         self.worktree_name_dialog = Some(WorktreeNameDialog::new(&current_dir, &wt.branch));
     }
 
+    /// Open the convert-to-worktree dialog for the selected session. Only
+    /// valid for a stopped single-repo session that does not already use a
+    /// worktree; other cases surface an info dialog explaining why.
+    pub(super) fn open_convert_to_worktree_for_selected(&mut self) {
+        let Some(inst) = self
+            .selected_session
+            .as_ref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            return;
+        };
+        let refusal = if inst.worktree_info.is_some() {
+            Some("This session already uses a worktree.")
+        } else if inst.is_workspace() {
+            Some("Workspace sessions cannot be converted to a single worktree.")
+        } else if inst.scratch {
+            Some("Scratch sessions have no repository to create a worktree from.")
+        } else if inst.status.blocks_worktree_edit() {
+            Some("Stop the session before converting it to a worktree.")
+        } else {
+            None
+        };
+        if let Some(message) = refusal {
+            self.info_dialog = Some(InfoDialog::new("Cannot Convert Session", message));
+            return;
+        }
+        let path = std::path::Path::new(&inst.project_path);
+        let current_dir = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or(&inst.project_path)
+            .to_string();
+        let current_branch = crate::git::GitWorktree::get_current_branch(path).unwrap_or_default();
+        self.worktree_name_dialog =
+            Some(WorktreeNameDialog::convert(&current_dir, &current_branch));
+    }
+
+    /// Start converting the selected session onto a worktree for `branch`.
+    /// A refusal is reported right away; the conversion itself reports
+    /// through [`Self::finish_worktree_conversion`].
+    fn convert_selected_to_worktree(&mut self, branch: &str, carry_changes: bool) {
+        if let Err(e) = self.convert_to_worktree_for_selected(branch, carry_changes) {
+            self.info_dialog = Some(InfoDialog::new(
+                "Convert to Worktree Failed",
+                &format!("Could not convert the session: {e}"),
+            ));
+        }
+    }
+
+    /// Persist a finished worktree conversion for session `id` and report
+    /// the result (including non-fatal warnings) in an info dialog.
+    pub(super) fn finish_worktree_conversion(
+        &mut self,
+        id: &str,
+        result: Result<crate::session::worktree_convert::WorktreeConvertOutcome, String>,
+    ) {
+        let persisted = result.and_then(|outcome| {
+            self.persist_worktree_conversion(id, &outcome)
+                .map(|()| outcome)
+                .map_err(|e| e.to_string())
+        });
+        match persisted {
+            Ok(outcome) => {
+                let mut message = format!(
+                    "The session now uses a worktree on branch '{}' at {}.",
+                    outcome.worktree_info.branch,
+                    outcome.new_path.display()
+                );
+                for warning in &outcome.warnings {
+                    message.push_str(&format!("\nWarning: {warning}"));
+                }
+                self.info_dialog = Some(InfoDialog::new("Converted to Worktree", &message));
+            }
+            Err(e) => {
+                self.info_dialog = Some(InfoDialog::new(
+                    "Convert to Worktree Failed",
+                    &format!("Could not convert the session: {e}"),
+                ));
+            }
+        }
+    }
+
+    /// Open the session-notes dialog for the selected session, prefilled with
+    /// its current note.
+    pub(super) fn open_notes_for_selected(&mut self) {
+        let Some(id) = self.selected_session.clone() else {
+            return;
+        };
+        let Some(inst) = self.get_instance(&id) else {
+            return;
+        };
+        self.notes_dialog = Some(NotesDialog::new(&inst.title, inst.notes.as_deref()));
+    }
+
     /// Open the delete dialog (or a force-remove confirm, or a group
     /// delete-options dialog) for the sidebar's current selection. Mirrors
     /// the gating of the historical `'d'` / `'D'` key handlers:
@@ -3563,6 +4377,17 @@ impl HomeView {
                 if inst.status == Status::Creating {
                     return;
                 }
+                if inst.worktree_locked {
+                    self.info_dialog = Some(InfoDialog::new(
+                        "Session Locked",
+                        &format!(
+                            "'{}' is locked. Unlock it from the command palette \
+                             (Lock / unlock worktree) before deleting.",
+                            inst.title
+                        ),
+                    ));
+                    return;
+                }
                 if inst.status == Status::Deleting {
                     let message = format!(
                         "'{}' is stuck deleting. Force remove it from the session list? \
@@ -3590,6 +4415,20 @@ impl HomeView {
                     is_scratch: inst.scratch,
                 };
 
+                // Opt-in shortcut: nothing besides the row itself would be
+                // removed, so the dialog has no choices to offer.
+                if self.quick_delete_clean_sessions
+                    && inst.status == Status::Stopped
+                    && config.worktree_branch.is_none()
+                    && !config.has_sandbox
+                    && !config.is_scratch
+                {
+                    if let Err(e) = self.delete_selected(&DeleteOptions::default()) {
+                        tracing::error!(target: "tui.input", "Failed to delete session: {}", e);
+                    }
+                    return;
+                }
+
                 let profile = self.config_profile();
                 self.unified_delete_dialog = Some(UnifiedDeleteDialog::new(
                     inst.title.clone(),
@@ -3923,6 +4762,18 @@ impl HomeView {
             dialog.handle_paste(text);
             return;
         }
+        if let Some(ref mut dialog) = self.notes_dialog {
+            dialog.handle_paste(text);
+            return;
+        }
+        if let Some(ref mut dialog) = self.container_copy_dialog {
+            dialog.handle_paste(text);
+            return;
+        }
+        if let Some(ref mut dialog) = self.fan_out_dialog {
+            dialog.handle_paste(text);
+            return;
+        }
         if let Some(ref mut dialog) = self.send_message_dialog {
             dialog.handle_paste(text);
             return;
@@ -4281,6 +5132,37 @@ impl HomeView {
         self.send_message_dialog = Some(dialog);
     }
 
+    /// Open the compose dialog for a one-off shell command in the selected
+    /// session. Unlike `open_send_message_dialog` the target ignores
+    /// `view_mode`: it is always a shell, never the agent prompt, and for
+    /// sandboxed sessions it is the container terminal so the command runs
+    /// with the container's workdir and environment.
+    pub(super) fn open_run_command_dialog(&mut self) {
+        let Some(inst) = self
+            .selected_session
+            .as_deref()
+            .and_then(|id| self.get_instance(id))
+        else {
+            return;
+        };
+        if matches!(inst.status, Status::Creating | Status::Deleting) {
+            return;
+        }
+        let target = if inst.is_sandboxed() {
+            live_send::LiveSendTarget::ContainerTerminal
+        } else {
+            live_send::LiveSendTarget::Terminal
+        };
+        let label = format!(
+            "Run in {}",
+            live_send::format_target_label(&inst.title, target)
+        );
+        self.pending_send_session = Some(inst.id.clone());
+        self.pending_send_target = target;
+        self.pending_run_command = true;
+        self.send_message_dialog = Some(SendMessageDialog::new(&label));
+    }
+
     /// Compose target for the current view: agent in Structured view, the
     /// paired host/container terminal in Terminal view. Tool view has
     /// no clean compose target (the tool owns the pane), so it falls
@@ -4353,6 +5235,18 @@ impl HomeView {
             dialog.handle_paste(&s);
             return;
         }
+        if let Some(ref mut dialog) = self.notes_dialog {
+            dialog.handle_paste(&s);
+            return;
+        }
+        if let Some(ref mut dialog) = self.container_copy_dialog {
+            dialog.handle_paste(&s);
+            return;
+        }
+        if let Some(ref mut dialog) = self.fan_out_dialog {
+            dialog.handle_paste(&s);
+            return;
+        }
 
         if let Some((id, title, target)) = self.resolve_send_target() {
             let label = live_send::format_target_label(&title, target);
@@ -4370,52 +5264,52 @@ impl HomeView {
         }
     }
 
+    /// Text `/` search fuzzy-matches against for a list row: title, path and
+    /// notes for sessions, name and path for groups.
+    fn search_haystack(&self, item: &Item) -> Option<String> {
+        match item {
+            Item::Session { id, .. } => {
+                let inst = self.get_instance(id)?;
+                Some(match inst.notes.as_deref() {
+                    Some(notes) => format!("{} {} {}", inst.title, inst.project_path, notes),
+                    None => format!("{} {}", inst.title, inst.project_path),
+                })
+            }
+            Item::Group { name, path, .. } => Some(format!("{} {}", name, path)),
+        }
+    }
+
+    /// Rows matching `query`, best first, via the incremental search index.
+    /// Rebuilds the index first when `flat_items` changed since it was built.
+    fn score_search_matches(&mut self, query: &str) -> Vec<usize> {
+        if !self.search_index.is_built() {
+            let haystacks: Vec<Option<String>> = self
+                .flat_items
+                .iter()
+                .map(|item| self.search_haystack(item))
+                .collect();
+            self.search_index.build(haystacks);
+        }
+        let (matches, scored) = self.search_index.search(query);
+        tracing::trace!(
+            target: "tui.search",
+            scored,
+            matched = matches.len(),
+            "search scored"
+        );
+        matches
+    }
+
     /// Re-score matches after a reload without moving the cursor.
     pub(super) fn refresh_search_matches(&mut self) {
-        let query = self.search_query.value();
+        let query = self.search_query.value().to_string();
         if query.is_empty() {
             self.search_matches.clear();
             self.search_match_index = 0;
             return;
         }
 
-        use nucleo_matcher::pattern::{Atom, AtomKind, CaseMatching, Normalization};
-        use nucleo_matcher::{Config, Matcher, Utf32Str};
-
-        let mut matcher = Matcher::new(Config::DEFAULT.match_paths());
-        let atom = Atom::new(
-            query,
-            CaseMatching::Ignore,
-            Normalization::Smart,
-            AtomKind::Fuzzy,
-            false,
-        );
-
-        let mut scored: Vec<(usize, u16)> = Vec::new();
-        let mut buf = Vec::new();
-
-        for (idx, item) in self.flat_items.iter().enumerate() {
-            let haystack = match item {
-                Item::Session { id, .. } => {
-                    if let Some(inst) = self.get_instance(id) {
-                        format!("{} {}", inst.title, inst.project_path)
-                    } else {
-                        continue;
-                    }
-                }
-                Item::Group { name, path, .. } => {
-                    format!("{} {}", name, path)
-                }
-            };
-
-            let haystack_utf32 = Utf32Str::new(&haystack, &mut buf);
-            if let Some(score) = atom.score(haystack_utf32, &mut matcher) {
-                scored.push((idx, score));
-            }
-        }
-
-        scored.sort_by_key(|a| std::cmp::Reverse(a.1));
-        self.search_matches = scored.into_iter().map(|(idx, _)| idx).collect();
+        self.search_matches = self.score_search_matches(&query);
         // Clamp match_index in case matches shrank
         if self.search_matches.is_empty() {
             self.search_match_index = 0;
@@ -4428,48 +5322,12 @@ impl HomeView {
         self.search_matches.clear();
         self.search_match_index = 0;
 
-        let query = self.search_query.value();
+        let query = self.search_query.value().to_string();
         if query.is_empty() {
             return;
         }
 
-        use nucleo_matcher::pattern::{Atom, AtomKind, CaseMatching, Normalization};
-        use nucleo_matcher::{Config, Matcher, Utf32Str};
-
-        let mut matcher = Matcher::new(Config::DEFAULT.match_paths());
-        let atom = Atom::new(
-            query,
-            CaseMatching::Ignore,
-            Normalization::Smart,
-            AtomKind::Fuzzy,
-            false,
-        );
-
-        let mut scored: Vec<(usize, u16)> = Vec::new();
-        let mut buf = Vec::new();
-
-        for (idx, item) in self.flat_items.iter().enumerate() {
-            let haystack = match item {
-                Item::Session { id, .. } => {
-                    if let Some(inst) = self.get_instance(id) {
-                        format!("{} {}", inst.title, inst.project_path)
-                    } else {
-                        continue;
-                    }
-                }
-                Item::Group { name, path, .. } => {
-                    format!("{} {}", name, path)
-                }
-            };
-
-            let haystack_utf32 = Utf32Str::new(&haystack, &mut buf);
-            if let Some(score) = atom.score(haystack_utf32, &mut matcher) {
-                scored.push((idx, score));
-            }
-        }
-
-        scored.sort_by_key(|a| std::cmp::Reverse(a.1));
-        self.search_matches = scored.into_iter().map(|(idx, _)| idx).collect();
+        self.search_matches = self.score_search_matches(&query);
 
         if let Some(&best) = self.search_matches.first() {
             self.cursor = best;
@@ -4477,6 +5335,30 @@ impl HomeView {
         }
     }
 
+    /// Ask before creating a session that would run YOLO mode on the host.
+    /// `builder::check_yolo_sandbox` refuses it without the override this
+    /// prompt grants.
+    fn maybe_confirm_unsandboxed_yolo(&mut self, data: NewSessionData) -> Option<Action> {
+        if crate::session::builder::check_yolo_sandbox(
+            &data.tool,
+            data.yolo_mode,
+            data.sandbox,
+            data.allow_unsandboxed_yolo,
+        )
+        .is_err()
+        {
+            self.unsandboxed_yolo_dialog = Some(ConfirmDialog::new(
+                "YOLO Without Sandbox",
+                "YOLO mode skips permission prompts, and without a sandbox the agent \
+                 runs with full access to this machine. Create the session anyway?",
+                "unsandboxed_yolo",
+            ));
+            self.pending_unsandboxed_yolo_data = Some(data);
+            return None;
+        }
+        self.maybe_confirm_volume_ignores_globs(data)
+    }
+
     /// Gate sandbox session creation on a one-time confirmation when the resolved
     /// config has glob `volume_ignores` (e.g. `**/bin`). Those entries are expanded
     /// against the workspace at create time, a point-in-time snapshot that won't
@@ -4634,6 +5516,17 @@ impl HomeView {
         data: NewSessionData,
         hooks: Option<crate::session::HooksConfig>,
     ) -> Option<Action> {
+        if let Err(e) = self.check_session_limit(&data.profile, 1) {
+            match &mut self.new_dialog {
+                Some(dialog) => dialog.set_error(e.to_string()),
+                None => {
+                    self.info_dialog =
+                        Some(InfoDialog::new("Session Limit Reached", &e.to_string()))
+                }
+            }
+            return None;
+        }
+
         let has_hooks = hooks
             .as_ref()
             .is_some_and(|h| !h.on_create.is_empty() || !h.on_launch.is_empty());
@@ -4665,6 +5558,20 @@ mod tests {
     use super::*;
     use crate::session::config::{SessionConfig, ToolSessionConfig};
 
+    #[test]
+    fn sync_summary_lists_files_and_collapses_the_rest() {
+        assert_eq!(
+            sync_summary(&[".claude/sandbox/settings.json".to_string()]),
+            "Updated 1 file:\n  .claude/sandbox/settings.json"
+        );
+        let many: Vec<String> = (0..10).map(|i| format!("f{i}")).collect();
+        let summary = sync_summary(&many);
+        assert!(summary.starts_with("Updated 10 files:"));
+        assert!(summary.contains("  f7"));
+        assert!(!summary.contains("  f8"));
+        assert!(summary.ends_with("...and 2 more"));
+    }
+
     #[test]
     fn format_target_label_distinguishes_terminal_panes() {
         // Users firing 'm' from Terminal view should see the dialog
@@ -4900,14 +5807,20 @@ mod tests {
             worktree_branch: None,
             create_new_branch: false,
             base_branch: None,
+            worktree_on_exists: crate::git::OnExists::Error,
             extra_repo_paths: Vec::new(),
             sandbox: true,
             sandbox_image: "ubuntu:latest".to_string(),
             yolo_mode: false,
+            allow_unsandboxed_yolo: false,
             extra_env: Vec::new(),
+            cpu_limit: None,
+            memory_limit: None,
+            custom_instruction: None,
             extra_args: String::new(),
             command_override: String::new(),
             scratch: false,
+            auto_create_terminal: false,
         }
     }
 
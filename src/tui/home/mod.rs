@@ -5,6 +5,7 @@ mod input;
 mod live_send;
 mod operations;
 mod render;
+mod search_index;
 
 #[cfg(test)]
 mod tests;
@@ -16,7 +17,8 @@ mod file_watch_tests;
 // detail of the home module. Tests that need to install it directly
 // go through the `super::live_send::LiveSendState` path.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
 use std::time::Instant;
 
 use ratatui::prelude::Rect;
@@ -24,27 +26,29 @@ use tui_input::Input;
 
 use crate::session::{
     append_archived_section, append_archived_section_by_project,
-    config::{load_config, save_config, GroupByMode, SortOrder},
-    flatten_sessions_by_attention, flatten_tree, flatten_tree_all_profiles, resolve_config_or_warn,
-    DefaultTerminalMode, EnsureReadyOutcome, Group, GroupTree, Instance, Item, Storage,
+    config::{load_config, save_config, GroupByMode, HomeLayout, SortOrder},
+    flatten_sessions_by_attention, flatten_tree, flatten_tree_all_profiles, hash_flatten_inputs,
+    resolve_config_or_warn, DefaultTerminalMode, EnsureReadyOutcome, Group, GroupTree, Instance,
+    Item, Storage,
 };
 use crate::tmux::AvailableTools;
 
+use super::components::progress::ProgressHandle;
 use super::creation_poller::{CreationPoller, CreationRequest};
 use super::deletion_poller::DeletionPoller;
 #[cfg(feature = "serve")]
 use super::dialogs::ServeView;
 use super::dialogs::{
-    ChangelogDialog, CommandPaletteDialog, ConfirmDialog, ContextMenuDialog,
-    GroupDeleteOptionsDialog, GroupPickerDialog, HooksInstallDialog, InfoDialog, IntroDialog,
-    NewSessionData, NewSessionDialog, NoAgentsDialog, ProfilePickerDialog,
-    ProjectSessionPickerDialog, ProjectsDialog, RenameDialog, RepoTrustDialog, RestartDialog,
-    SnoozeDurationDialog, SortPickerDialog, UnifiedDeleteDialog, UpdateConfirmDialog,
-    WorktreeNameDialog,
+    ActivityLogDialog, ChangelogDialog, CommandPaletteDialog, ConfirmDialog, ContainerCopyDialog,
+    ContextMenuDialog, FanOutDialog, GroupDeleteOptionsDialog, GroupPickerDialog,
+    HooksInstallDialog, InfoDialog, IntroDialog, NewSessionData, NewSessionDialog, NoAgentsDialog,
+    NotesDialog, ProfilePickerDialog, ProgressDialog, ProjectSessionPickerDialog, ProjectsDialog,
+    RenameDialog, RepoTrustDialog, RestartDialog, SnoozeDurationDialog, SortPickerDialog,
+    UnifiedDeleteDialog, UpdateConfirmDialog, WorktreeExistsDialog, WorktreeNameDialog,
 };
 use super::diff::DiffView;
 use super::settings::SettingsView;
-use super::status_poller::{StatusPoller, StatusUpdate};
+use super::status_poller::{PaneTarget, StatusPoller, StatusUpdate};
 use super::stop_poller::StopPoller;
 
 /// Extract a project group name from a session instance.
@@ -376,18 +380,66 @@ pub(super) const ICON_ERROR: &str = "✕";
 pub(super) const ICON_UNKNOWN: &str = "⠤";
 pub(super) const ICON_STOPPED: &str = "⠒";
 pub(super) const ICON_DELETING: &str = "✕";
+/// Agent row whose tmux session is gone (see `Instance::is_tmux_gone`):
+/// hollow rather than the crash cross, since Enter brings it back.
+pub(super) const ICON_TMUX_GONE: &str = "○";
 pub(super) const ICON_COLLAPSED: &str = "▶";
 pub(super) const ICON_EXPANDED: &str = "▼";
 /// Marks a pinned project header in project view. Geometric per DESIGN.md
 /// (clean readable glyphs, not emoji).
 pub(super) const ICON_PINNED: &str = "◆";
 
+/// How often the visible worktree sessions are checked for new commits.
+const AHEAD_COUNT_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Commits a worktree session's branch has over its base, as last counted
+/// for `branch` at `head`. Recounted only when either moves.
+pub(super) struct AheadCount {
+    branch: String,
+    head: git2::Oid,
+    pub(super) ahead: usize,
+}
+
 /// Hook progress for a session being created in the background
 pub(super) struct CreatingHookProgress {
     pub(super) hook_output: Vec<String>,
     pub(super) current_hook: Option<String>,
 }
 
+/// A palette action running on a worker thread, such as a copy out of a
+/// container. The worker sends back a [`TaskOutcome`] describing the dialog
+/// that reports it.
+pub(super) struct BackgroundTask {
+    progress: ProgressHandle,
+    rx: tokio::sync::oneshot::Receiver<TaskOutcome>,
+}
+
+/// How a finished [`BackgroundTask`] is reported. A plain `(title, message)`
+/// converts to [`TaskOutcome::Info`].
+pub(super) enum TaskOutcome {
+    /// Show `title` and `message` in an [`InfoDialog`].
+    Info(String, String),
+    /// Ask whether to restart the agent in session `id`, e.g. after a sync
+    /// that it only picks up on launch.
+    ConfirmRestartAgent {
+        id: String,
+        title: String,
+        message: String,
+    },
+    /// A worktree conversion of session `id` finished; the UI thread
+    /// persists the new path and reports the result.
+    WorktreeConverted {
+        id: String,
+        result: Result<crate::session::worktree_convert::WorktreeConvertOutcome, String>,
+    },
+}
+
+impl From<(String, String)> for TaskOutcome {
+    fn from((title, message): (String, String)) -> Self {
+        Self::Info(title, message)
+    }
+}
+
 /// Result delivered by a startup-recovery worker back to the TUI tick.
 struct RecoveryUpdate {
     instance_id: String,
@@ -457,6 +509,11 @@ pub struct HomeView {
     pub(super) group_delete_options_dialog: Option<GroupDeleteOptionsDialog>,
     pub(super) rename_dialog: Option<RenameDialog>,
     pub(super) worktree_name_dialog: Option<WorktreeNameDialog>,
+    pub(super) notes_dialog: Option<NotesDialog>,
+    pub(super) container_copy_dialog: Option<ContainerCopyDialog>,
+    pub(super) fan_out_dialog: Option<FanOutDialog>,
+    /// Opened when background creation hits an existing worktree path.
+    pub(super) worktree_exists_dialog: Option<WorktreeExistsDialog>,
     pub(super) restart_dialog: Option<RestartDialog>,
     /// Right-click popup on the sidebar list. Anchored to a screen
     /// position when opened; the renderer clamps it into view.
@@ -475,6 +532,12 @@ pub struct HomeView {
     pub(super) volume_ignores_glob_dialog: Option<ConfirmDialog>,
     /// Session data pending the volume_ignores glob expansion acknowledgment.
     pub(super) pending_volume_ignores_glob_data: Option<NewSessionData>,
+    /// Confirm shown before creating a session with YOLO mode but no
+    /// sandbox. Accepting sets `allow_unsandboxed_yolo` on the pending data
+    /// so `builder::check_yolo_sandbox` lets it through.
+    pub(super) unsandboxed_yolo_dialog: Option<ConfirmDialog>,
+    /// Session data pending the unsandboxed YOLO confirmation.
+    pub(super) pending_unsandboxed_yolo_data: Option<NewSessionData>,
     pub(super) intro_dialog: Option<IntroDialog>,
     /// Theme name queued by a click on the intro dialog (live preview or
     /// final pick). Drained by the `App` mouse handler after
@@ -497,6 +560,13 @@ pub struct HomeView {
     #[cfg(feature = "serve")]
     pub(super) serve_view: Option<ServeView>,
     pub(super) update_confirm_dialog: Option<UpdateConfirmDialog>,
+    /// Progress of a long-running background operation (image pull,
+    /// self-update). Hiding it leaves the operation running.
+    pub(super) progress_dialog: Option<ProgressDialog>,
+    /// Palette action running off the UI thread. Drained on every tick by
+    /// `apply_background_task_result`.
+    pub(super) background_task: Option<BackgroundTask>,
+    pub(super) activity_log_dialog: Option<ActivityLogDialog>,
     /// One-time opt-in popup for users who finished the walkthrough before
     /// telemetry existed. Startup gating keeps it from rendering over the
     /// changelog or the version update modal.
@@ -510,6 +580,11 @@ pub struct HomeView {
     /// instead of the agent. Defaults to Agent for the historical
     /// path (paste/dictation capture, palette compose).
     pub(super) pending_send_target: live_send::LiveSendTarget,
+    /// True while the send dialog is composing a one-off shell command
+    /// (`ActionId::RunCommand`). Submitting then yields
+    /// `Action::RunCommand`, which attaches to the shell after sending so
+    /// the command's output is visible.
+    pub(super) pending_run_command: bool,
     /// Which pane the next `Action::EnterLiveSend` should target.
     /// Set by `start_live_send` whenever it returns an action; read
     /// (and reset to Agent) by `prepare_live_send` so each action
@@ -578,10 +653,19 @@ pub struct HomeView {
     pub(super) pending_attach_after_warning: Option<String>,
     /// Session to stop after the confirmation dialog is accepted
     pub(super) pending_stop_session: Option<String>,
+    /// Session whose sandbox container is rebuilt after the confirmation
+    /// dialog is accepted
+    pub(super) pending_rebuild_container: Option<String>,
+    /// Session whose agent is relaunched in place after the confirmation
+    /// dialog is accepted
+    pub(super) pending_restart_agent: Option<String>,
     /// Sandbox image to pull after the "image update available" confirm dialog
     /// is accepted. Carries the image through the generic `ConfirmDialog`,
     /// which only knows its action string.
     pub(super) pending_image_pull: Option<String>,
+    /// Session to select after the "branch already open" confirm dialog is
+    /// accepted
+    pub(super) pending_jump_session: Option<String>,
     /// Session to force-remove after the confirmation dialog is accepted
     pub(super) pending_force_remove_session: Option<String>,
     /// Action emitted by a mouse-click on a modal dialog (e.g. clicking
@@ -596,6 +680,10 @@ pub struct HomeView {
     pub(super) search_query: Input,
     pub(super) search_matches: Vec<usize>,
     pub(super) search_match_index: usize,
+    /// Decoded row haystacks and the previous query's matches, so typing
+    /// narrows the last result instead of rescanning every row. Dropped on
+    /// every `flat_items` build or refresh and when a search starts.
+    search_index: search_index::SearchIndex,
 
     // Tool availability
     pub(super) available_tools: AvailableTools,
@@ -621,12 +709,21 @@ pub struct HomeView {
     pub(super) creating_hook_progress: HashMap<String, CreatingHookProgress>,
     /// The stub instance ID for the current background creation
     pub(super) creating_stub_id: Option<String>,
+    /// Sessions still to create for an in-flight fan-out, fed to the
+    /// creation poller one at a time. `Some` while a fan-out runs, even
+    /// once the queue drains, so its sessions don't each auto-attach.
+    pub(super) fan_out_queue:
+        Option<VecDeque<(NewSessionData, Option<crate::session::HooksConfig>)>>,
 
     // Performance: preview caching
     pub(super) preview_cache: PreviewCache,
     pub(super) terminal_preview_cache: PreviewCache,
     pub(super) container_terminal_preview_cache: PreviewCache,
     pub(super) tool_preview_cache: PreviewCache,
+    /// `tmux list-panes` for the selected session's agent tmux session,
+    /// shown as the preview header's `Panes:` row. Keyed by instance id and
+    /// fetched by the status poller (see `pane_info_target`).
+    pub(super) pane_info: Option<(String, Vec<crate::tmux::PaneInfo>)>,
 
     /// Per-frame timing of the preview pipeline's two latency-sensitive
     /// phases, reset by `App::render` before each `render` and populated
@@ -637,6 +734,22 @@ pub struct HomeView {
     /// break a live-send frame down into fork vs. parse vs. widget build.
     pub(super) preview_timings: PreviewTimings,
 
+    /// Rows of `flat_items` built and styled by the last `render_list`.
+    /// Bounded by the list viewport rather than the session count; the app
+    /// loop's render sampler logs it so a slow frame on a large list can be
+    /// told apart from a slow preview.
+    pub(super) list_rows_rendered: usize,
+    /// `flat_items` range the last `render_list` drew. Bounds the work in
+    /// `refresh_ahead_counts_if_due` to rows the user can see.
+    pub(super) list_visible_range: std::ops::Range<usize>,
+    /// Per-session `↑N` list badge data, keyed by instance id.
+    pub(super) ahead_counts: HashMap<String, AheadCount>,
+    pub(super) ahead_counts_refreshed_at: Option<Instant>,
+
+    /// Fingerprint of the inputs `flat_items` was last built from (see
+    /// `compute_flat_items_key`). `None` until the first build.
+    flat_items_key: Option<u64>,
+
     /// Mouse wheel offset for the preview pane, in lines back from the bottom.
     /// Reset to 0 whenever the selected session changes.
     pub(super) preview_scroll_offset: u16,
@@ -695,13 +808,15 @@ pub struct HomeView {
     /// session (same as pressing Enter on the selected row).
     pub(super) last_click: Option<(std::time::Instant, u16, u16)>,
 
-    // Terminal mode for sandboxed sessions (per-session, ephemeral)
-    pub(super) terminal_modes: HashMap<String, TerminalMode>,
     // Default terminal mode from config
     pub(super) default_terminal_mode: TerminalMode,
 
     // Sound config for state transition sounds
     pub(super) sound_config: crate::sound::SoundConfig,
+    pub(super) notification_config: crate::notifications::NotificationConfig,
+    /// Runtime mute toggled from the home view. Overrides `sound.enabled`
+    /// for this run only and is never written back to config.
+    pub(super) sound_muted: bool,
     pub(super) status_hook_config: crate::status_hooks::StatusHookConfig,
     pub(super) status_hook_configs: HashMap<String, crate::status_hooks::StatusHookConfig>,
 
@@ -710,6 +825,9 @@ pub struct HomeView {
     /// drive the breathe rattle and fresh-idle color, and by the `w`
     /// keybind to gate which Idle sessions are still "actionable".
     pub(super) idle_decay_window: std::time::Duration,
+    /// Timezone and format for absolute timestamps in the preview header.
+    /// Re-resolved on settings reload, like `idle_decay_window`.
+    pub(super) display_config: crate::session::config::DisplayConfig,
 
     // When true, letter-based action hotkeys require SHIFT (guard against
     // dictation / stray keystrokes triggering destructive actions).
@@ -719,6 +837,16 @@ pub struct HomeView {
     // confirmation first (guards against accidental exits, #1569).
     pub(super) confirm_before_quit: bool,
 
+    // When true, deleting a Stopped session with no worktree, container or
+    // scratch dir skips the delete dialog (`session.quick_delete_clean_sessions`).
+    pub(super) quick_delete_clean_sessions: bool,
+
+    // `session.max_sessions_warn` / `session.max_sessions` for the config
+    // profile: past the first the footer nudges toward cleanup, past the
+    // second new sessions are refused. 0 disables either.
+    pub(super) max_sessions_warn: u32,
+    pub(super) max_sessions: u32,
+
     // Number of live `aoe` TUI processes (including this one), refreshed on a
     // throttle from the app loop. The footer surfaces it when >1 so the user
     // knows another instance is attached (the two clash over agent pane sizes
@@ -793,6 +921,10 @@ pub struct HomeView {
     /// `app_state.show_preview_info`.
     pub(super) show_preview_info: bool,
 
+    /// Which panes are visible (list + detail, list only, detail only).
+    /// Cycled with `v` and persisted per profile in `app_state.home_layout`.
+    pub(super) home_layout: HomeLayout,
+
     /// Collapsed state of the synthetic "Archived" sidebar section.
     /// Defaults to `true` (collapsed) so archived rows stay tucked at the
     /// bottom until the user opts to see them. Persisted to
@@ -1221,6 +1353,7 @@ impl HomeView {
             DefaultTerminalMode::Container => TerminalMode::Container,
         };
         let sound_config = resolved.sound.clone();
+        let notification_config = resolved.notifications.clone();
         let status_hook_configs = Self::load_status_hook_configs(Self::status_hook_profile_names(
             active_profile.as_deref(),
             &storages,
@@ -1231,8 +1364,12 @@ impl HomeView {
             .unwrap_or_else(|| resolved.status_hooks.clone());
         let strict_hotkeys = resolved.session.strict_hotkeys;
         let confirm_before_quit = resolved.session.confirm_before_quit;
+        let quick_delete_clean_sessions = resolved.session.quick_delete_clean_sessions;
+        let max_sessions_warn = resolved.session.max_sessions_warn;
+        let max_sessions = resolved.session.max_sessions;
         let idle_decay_window =
             crate::tui::styles::idle_decay_window(resolved.theme.idle_decay_minutes);
+        let display_config = resolved.display.clone();
         let user_config = load_config().ok().flatten();
         let sort_order = user_config
             .as_ref()
@@ -1289,6 +1426,10 @@ impl HomeView {
             group_delete_options_dialog: None,
             rename_dialog: None,
             worktree_name_dialog: None,
+            notes_dialog: None,
+            container_copy_dialog: None,
+            fan_out_dialog: None,
+            worktree_exists_dialog: None,
             restart_dialog: None,
             context_menu: None,
             group_rename_context: None,
@@ -1298,6 +1439,8 @@ impl HomeView {
             pending_hooks_install_data: None,
             volume_ignores_glob_dialog: None,
             pending_volume_ignores_glob_data: None,
+            unsandboxed_yolo_dialog: None,
+            pending_unsandboxed_yolo_data: None,
             intro_dialog: None,
             pending_intro_theme: None,
             no_agents_dialog: None,
@@ -1314,10 +1457,14 @@ impl HomeView {
             #[cfg(feature = "serve")]
             serve_view: None,
             update_confirm_dialog: None,
+            progress_dialog: None,
+            background_task: None,
+            activity_log_dialog: None,
             telemetry_consent_dialog: None,
             send_message_dialog: None,
             pending_send_session: None,
             pending_send_target: live_send::LiveSendTarget::Agent,
+            pending_run_command: false,
             pending_live_send_target: live_send::LiveSendTarget::Agent,
             live_send: None,
             live_send_worker: None,
@@ -1331,12 +1478,16 @@ impl HomeView {
             pending_paste: None,
             pending_attach_after_warning: None,
             pending_stop_session: None,
+            pending_rebuild_container: None,
+            pending_restart_agent: None,
             pending_image_pull: None,
+            pending_jump_session: None,
             pending_force_remove_session: None,
             pending_dialog_click_action: None,
             search_active: false,
             search_query: Input::default(),
             search_matches: Vec::new(),
+            search_index: Default::default(),
             search_match_index: 0,
             available_tools,
             status_poller: StatusPoller::new(),
@@ -1348,11 +1499,18 @@ impl HomeView {
             on_launch_hooks_ran: HashSet::new(),
             creating_hook_progress: HashMap::new(),
             creating_stub_id: None,
+            fan_out_queue: None,
             preview_cache: PreviewCache::default(),
             preview_timings: PreviewTimings::default(),
+            list_rows_rendered: 0,
+            list_visible_range: 0..0,
+            ahead_counts: HashMap::new(),
+            ahead_counts_refreshed_at: None,
+            flat_items_key: None,
             terminal_preview_cache: PreviewCache::default(),
             container_terminal_preview_cache: PreviewCache::default(),
             tool_preview_cache: PreviewCache::default(),
+            pane_info: None,
             preview_scroll_offset: 0,
             preview_text_view: PreviewTextView::default(),
             preview_area: Rect::default(),
@@ -1364,15 +1522,20 @@ impl HomeView {
             list_inner_area: Rect::default(),
             mouse_pos: None,
             last_click: None,
-            terminal_modes: HashMap::new(),
             default_terminal_mode,
             sound_config,
+            notification_config,
+            sound_muted: false,
             status_hook_config,
             status_hook_configs,
             strict_hotkeys,
             confirm_before_quit,
+            quick_delete_clean_sessions,
+            max_sessions_warn,
+            max_sessions,
             active_tui_count: 1,
             idle_decay_window,
+            display_config,
             settings_view: None,
             settings_close_confirm: false,
             diff_view: None,
@@ -1392,6 +1555,10 @@ impl HomeView {
                 .as_ref()
                 .and_then(|c| c.app_state.show_preview_info)
                 .unwrap_or(true),
+            home_layout: user_config
+                .as_ref()
+                .and_then(|c| c.app_state.home_layout.get(&config_profile).copied())
+                .unwrap_or_default(),
             archived_section_collapsed: user_config
                 .as_ref()
                 .and_then(|c| c.app_state.archived_section_collapsed)
@@ -1520,7 +1687,7 @@ impl HomeView {
             .collect();
 
         view.refresh_registered_projects();
-        view.flat_items = view.build_flat_items();
+        view.rebuild_flat_items();
         view.update_selected();
         // Disk subscriptions stay scoped to the loaded storages: in
         // single-profile mode (`aoe --profile X`) the user opted into
@@ -1682,7 +1849,7 @@ impl HomeView {
         let prev_selected_session = self.selected_session.clone();
         let prev_selected_group = self.selected_group.clone();
 
-        self.flat_items = self.build_flat_items();
+        self.refresh_flat_items();
 
         // Try to restore cursor to the same session/group after rebuild
         let mut restored = false;
@@ -1941,7 +2108,7 @@ impl HomeView {
         // picks up the new inode.
         let global_invalidated = match self.config_watch_handles.get(&ConfigWatchKey::Global) {
             Some(entry) => {
-                let current_canonical = crate::session::get_app_dir()
+                let current_canonical = crate::session::get_config_dir()
                     .ok()
                     .and_then(|p| std::fs::canonicalize(&p).ok());
                 match current_canonical {
@@ -1995,7 +2162,7 @@ impl HomeView {
                     Some(e) => e,
                     None => return false,
                 };
-                let current_canonical = crate::session::get_profile_dir_path(name)
+                let current_canonical = crate::session::get_profile_config_dir_path(name)
                     .ok()
                     .and_then(|p| std::fs::canonicalize(&p).ok());
                 match current_canonical {
@@ -2039,7 +2206,7 @@ impl HomeView {
             .clear_config_watcher_init_failure();
 
         if global_needs_install {
-            match crate::session::get_app_dir() {
+            match crate::session::get_config_dir() {
                 Ok(app_dir) => {
                     let canonical_dir =
                         std::fs::canonicalize(&app_dir).unwrap_or_else(|_| app_dir.clone());
@@ -2117,7 +2284,7 @@ impl HomeView {
         }
 
         for name in &to_add {
-            let dir = match crate::session::get_profile_dir_path(name) {
+            let dir = match crate::session::get_profile_config_dir_path(name) {
                 Ok(p) => p,
                 Err(e) => {
                     tracing::warn!(
@@ -2361,27 +2528,54 @@ impl HomeView {
             .collect()
     }
 
+    /// Resolved `session.pause_refresh_while_attached` for the active
+    /// profile, read at attach time so a Settings change applies to the
+    /// next attach.
+    pub(super) fn pause_refresh_while_attached(&self) -> bool {
+        resolve_config_or_warn(&self.config_profile())
+            .session
+            .pause_refresh_while_attached
+    }
+
     /// Request a status refresh in the background (non-blocking).
     /// Call `apply_status_updates` to check for and apply results.
     pub fn request_status_refresh(&mut self) {
         if !self.pending_status_refresh {
             self.status_poller
-                .request_refresh(self.pollable_instances());
+                .request_refresh(self.pollable_instances(), self.pane_info_target());
             self.pending_status_refresh = true;
         }
     }
 
+    /// The selected session, whose pane list the preview header shows. `None`
+    /// when the header is hidden, so no pane listing runs for nothing.
+    fn pane_info_target(&self) -> Option<PaneTarget> {
+        if !self.show_preview_info {
+            return None;
+        }
+        let inst = self.get_instance(self.selected_session.as_ref()?)?;
+        Some(PaneTarget {
+            id: inst.id.clone(),
+            session_name: crate::tmux::Session::generate_name(&inst.id, &inst.title),
+        })
+    }
+
     /// Apply any pending status updates from the background poller.
     /// Returns true if updates were applied.
     pub fn apply_status_updates(&mut self) -> bool {
+        let mut applied = false;
+        if let Some(panes) = self.status_poller.try_recv_panes() {
+            self.pane_info = Some((panes.id, panes.panes));
+            applied = true;
+        }
         if let Some(updates) = self.status_poller.try_recv_updates() {
             for update in updates {
                 self.apply_one_status_update(update);
             }
             self.pending_status_refresh = false;
-            return true;
+            applied = true;
         }
-        false
+        applied
     }
 
     /// Apply a single status update from the poller. Extracted from the
@@ -2468,7 +2662,14 @@ impl HomeView {
         run_hooks: bool,
     ) {
         if play_sound {
-            crate::sound::play_for_transition(old, new, &self.sound_config);
+            crate::sound::play_for_transition(
+                &inst.id,
+                old,
+                new,
+                &self.sound_config,
+                self.sound_muted,
+            );
+            crate::notifications::notify_for_transition(inst, old, new, &self.notification_config);
         }
         if run_hooks {
             let hook_config = self.status_hook_config_for(inst);
@@ -2520,25 +2721,98 @@ impl HomeView {
     /// Apply the result of a background stop. Returns true if an instance was
     /// updated so the caller can trigger a redraw.
     pub fn apply_stop_results(&mut self) -> bool {
+        match self.stop_poller.try_recv_result() {
+            Some(result) => {
+                self.apply_stop_result(result);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(super) fn apply_stop_result(&mut self, result: crate::tui::stop_poller::StopResult) {
         use crate::session::Status;
 
-        if let Some(result) = self.stop_poller.try_recv_result() {
-            if result.success {
-                // Status was already set to Stopped optimistically when the
-                // stop was requested; reassert it in case the disk reload or
-                // a race changed it, and clear any stale error.
-                self.set_instance_error(&result.session_id, None);
-                self.set_instance_status(&result.session_id, Status::Stopped);
-            } else {
-                self.set_instance_error(&result.session_id, result.error);
-                self.set_instance_status(&result.session_id, Status::Error);
+        if result.success {
+            // Status was already set to Stopped optimistically when the
+            // stop was requested; reassert it in case the disk reload or
+            // a race changed it, and clear any stale error.
+            self.set_instance_error(&result.session_id, None);
+            self.set_instance_status(&result.session_id, Status::Stopped);
+            if let Some(sandbox) = result.sandbox_info {
+                if let Err(e) = self
+                    .apply_user_action(&result.session_id, |inst| inst.sandbox_info = Some(sandbox))
+                {
+                    tracing::error!(target: "tui.home", "Failed to save rebuilt container: {}", e);
+                }
             }
-            if let Err(e) = self.save() {
-                tracing::error!(target: "tui.home", "Failed to save after stop: {}", e);
+        } else {
+            self.set_instance_error(&result.session_id, result.error);
+            self.set_instance_status(&result.session_id, Status::Error);
+        }
+        if let Err(e) = self.save() {
+            tracing::error!(target: "tui.home", "Failed to save after stop: {}", e);
+        }
+    }
+
+    /// Run `job` on a worker thread behind a progress dialog titled `title`,
+    /// then report the [`TaskOutcome`] it returns.
+    /// Only one task runs at a time; a second request while one is running
+    /// is refused with a notice instead of queued.
+    pub(super) fn spawn_background_task<O: Into<TaskOutcome>>(
+        &mut self,
+        title: &str,
+        label: impl Into<String>,
+        job: impl FnOnce() -> O + Send + 'static,
+    ) {
+        if self.background_task.is_some() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Operation In Progress",
+                "Wait for the running operation to finish before starting another.",
+            ));
+            return;
+        }
+        let progress = ProgressHandle::new(label);
+        self.open_progress_dialog(title, progress.clone());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(job().into());
+        });
+        self.background_task = Some(BackgroundTask { progress, rx });
+    }
+
+    /// Show the outcome of a finished [`BackgroundTask`]. Returns true when
+    /// the task resolved this tick.
+    pub fn apply_background_task_result(&mut self) -> bool {
+        let Some(mut task) = self.background_task.take() else {
+            return false;
+        };
+        let outcome = match task.rx.try_recv() {
+            Ok(outcome) => outcome,
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty) => {
+                self.background_task = Some(task);
+                return false;
+            }
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed) => TaskOutcome::Info(
+                "Operation Failed".to_string(),
+                "The operation ended unexpectedly.".to_string(),
+            ),
+        };
+        self.close_progress_dialog(&task.progress);
+        match outcome {
+            TaskOutcome::Info(title, message) => {
+                self.info_dialog = Some(InfoDialog::new(&title, &message));
+            }
+            TaskOutcome::ConfirmRestartAgent { id, title, message } => {
+                self.pending_restart_agent = Some(id);
+                self.confirm_dialog =
+                    Some(ConfirmDialog::new(&title, &message, "restart_agent").neutral());
+            }
+            TaskOutcome::WorktreeConverted { id, result } => {
+                self.finish_worktree_conversion(&id, result);
             }
-            return true;
         }
-        false
+        true
     }
 
     /// Apply any pending session ID updates from background pollers.
@@ -2801,7 +3075,7 @@ impl HomeView {
             let prev_selected_session = self.selected_session.clone();
             let prev_selected_group = self.selected_group.clone();
 
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
 
             let mut restored = false;
             if let Some(ref sid) = prev_selected_session {
@@ -3091,7 +3365,7 @@ impl HomeView {
             },
         );
         self.creating_stub_id = Some(stub_id.clone());
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
 
         // Move cursor to the new stub
         if let Some(pos) = self
@@ -3133,7 +3407,7 @@ impl HomeView {
             self.remove_instance(&stub_id);
             self.creating_hook_progress.remove(&stub_id);
             self.rebuild_group_trees();
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             self.update_selected();
         }
         self.new_dialog = None;
@@ -3142,12 +3416,32 @@ impl HomeView {
     /// Apply any pending creation results from the background poller.
     /// Returns Some(session_id) if creation succeeded and we should attach.
     pub fn apply_creation_results(&mut self) -> Option<String> {
+        let result = self.creation_poller.try_recv_result()?;
+        let created = self.apply_creation_result(result);
+        let Some(queue) = &mut self.fan_out_queue else {
+            return created;
+        };
+        // Fan-out: start the next session instead of attaching. A failure
+        // or cancel stops the rest so the user isn't buried in errors.
+        match (created, queue.pop_front()) {
+            (Some(_), Some((data, hooks))) => self.request_creation(data, hooks),
+            (None, Some(_)) => {
+                tracing::warn!(target: "tui.home", "fan-out stopped after a failed creation");
+                self.fan_out_queue = None;
+            }
+            (_, None) => self.fan_out_queue = None,
+        }
+        None
+    }
+
+    fn apply_creation_result(
+        &mut self,
+        result: super::creation_poller::CreationResult,
+    ) -> Option<String> {
         use super::creation_poller::CreationResult;
         use crate::session::builder::{self, CreatedWorktree};
         use std::path::PathBuf;
 
-        let result = self.creation_poller.try_recv_result()?;
-
         // Clean up the stub and progress tracking
         let stub_id = self.creating_stub_id.take();
         if let Some(ref id) = stub_id {
@@ -3173,7 +3467,7 @@ impl HomeView {
                 builder::cleanup_instance(instance, worktree.as_ref(), &[]);
             }
             self.rebuild_group_trees();
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             self.update_selected();
             return None;
         }
@@ -3251,7 +3545,7 @@ impl HomeView {
                 if let Some(id) = &stub_id {
                     self.remove_instance(id);
                     self.rebuild_group_trees();
-                    self.flat_items = self.build_flat_items();
+                    self.rebuild_flat_items();
                     self.update_selected();
                     // Hook failures carry multi-line output; size to fit so
                     // the actual error isn't clipped at the default 50x9.
@@ -3262,9 +3556,151 @@ impl HomeView {
                 }
                 None
             }
+            CreationResult::WorktreeExists { data, hooks, path } => {
+                if let Some(id) = &stub_id {
+                    self.remove_instance(id);
+                    self.rebuild_group_trees();
+                    self.rebuild_flat_items();
+                    self.update_selected();
+                }
+                self.worktree_exists_dialog = Some(WorktreeExistsDialog::new(
+                    &path.to_string_lossy(),
+                    *data,
+                    hooks,
+                ));
+                None
+            }
+            CreationResult::BranchCheckedOut { branch, path } => {
+                if let Some(id) = &stub_id {
+                    self.remove_instance(id);
+                    self.rebuild_group_trees();
+                    self.rebuild_flat_items();
+                    self.update_selected();
+                }
+                if let Some(dialog) = &mut self.new_dialog {
+                    dialog.set_loading(false);
+                }
+                self.show_branch_checked_out(&branch, path.as_deref());
+                None
+            }
         }
     }
 
+    /// Explain that `branch` is already checked out at `path`. When one of
+    /// our sessions runs in that worktree, offer to jump to it instead.
+    fn show_branch_checked_out(&mut self, branch: &str, path: Option<&Path>) {
+        let owner = path.and_then(|path| {
+            let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            self.instances.iter().find(|inst| {
+                let project = Path::new(&inst.project_path);
+                project
+                    .canonicalize()
+                    .unwrap_or_else(|_| project.to_path_buf())
+                    == path
+            })
+        });
+        if let Some(inst) = owner {
+            self.pending_jump_session = Some(inst.id.clone());
+            self.confirm_dialog = Some(
+                ConfirmDialog::new(
+                    "Branch Already Open",
+                    &format!(
+                        "Branch '{branch}' is already checked out for session '{}'.\nJump to it?",
+                        inst.title
+                    ),
+                    "jump_to_session",
+                )
+                .neutral(),
+            );
+            return;
+        }
+        let message = match path {
+            Some(path) => format!(
+                "Branch '{branch}' is already checked out at {}.",
+                path.display()
+            ),
+            None => format!("Branch '{branch}' is already checked out in another worktree."),
+        };
+        match &mut self.new_dialog {
+            Some(dialog) => dialog.set_error(message),
+            None => {
+                self.info_dialog = Some(InfoDialog::sized_to_fit("Branch Already Open", &message))
+            }
+        }
+    }
+
+    /// Recount commits ahead of base for the visible worktree sessions, at
+    /// most every `AHEAD_COUNT_REFRESH_INTERVAL`. Returns true when a badge
+    /// changed.
+    pub fn refresh_ahead_counts_if_due(&mut self) -> bool {
+        if self
+            .ahead_counts_refreshed_at
+            .is_some_and(|at| at.elapsed() < AHEAD_COUNT_REFRESH_INTERVAL)
+        {
+            return false;
+        }
+        self.ahead_counts_refreshed_at = Some(Instant::now());
+        self.refresh_ahead_counts()
+    }
+
+    /// Only reads HEAD for sessions whose cached count is still current;
+    /// the ahead walk runs when the branch or HEAD moved.
+    pub(super) fn refresh_ahead_counts(&mut self) -> bool {
+        let end = self.list_visible_range.end.min(self.flat_items.len());
+        let start = self.list_visible_range.start.min(end);
+        let mut changed = false;
+        for item in &self.flat_items[start..end] {
+            let Item::Session { id, .. } = item else {
+                continue;
+            };
+            let Some(inst) = self.get_instance(id) else {
+                continue;
+            };
+            let Some(branch) = inst.worktree_info.as_ref().map(|w| &w.branch) else {
+                continue;
+            };
+            if inst.is_archived()
+                || matches!(
+                    inst.status,
+                    crate::session::Status::Creating | crate::session::Status::Deleting
+                )
+            {
+                continue;
+            }
+            let path = std::path::Path::new(&inst.project_path);
+            let Ok(head) = crate::git::diff::head_commit_id(path) else {
+                continue;
+            };
+            if self
+                .ahead_counts
+                .get(id)
+                .is_some_and(|c| c.head == head && c.branch == *branch)
+            {
+                continue;
+            }
+            let upstream = crate::git::diff::upstream_ref(path);
+            let ahead = match crate::git::GitWorktree::new(path.to_path_buf())
+                .and_then(|wt| wt.commit_count_since_base(path, upstream.as_deref()))
+            {
+                Ok(since_base) => since_base.count(),
+                Err(e) => {
+                    tracing::debug!(target: "tui.home", session = %id, "ahead count failed: {e}");
+                    0
+                }
+            };
+            let previous = self.ahead_counts.insert(
+                id.clone(),
+                AheadCount {
+                    branch: branch.clone(),
+                    head,
+                    ahead,
+                },
+            );
+            changed |= previous.map(|c| c.ahead) != Some(ahead);
+        }
+        changed
+    }
+
     /// Check if on_launch hooks already ran for this session (and consume the flag).
     pub fn take_on_launch_hooks_ran(&mut self, session_id: &str) -> bool {
         self.on_launch_hooks_ran.remove(session_id)
@@ -3492,11 +3928,16 @@ impl HomeView {
             || self.group_delete_options_dialog.is_some()
             || self.rename_dialog.is_some()
             || self.worktree_name_dialog.is_some()
+            || self.notes_dialog.is_some()
+            || self.container_copy_dialog.is_some()
+            || self.fan_out_dialog.is_some()
+            || self.worktree_exists_dialog.is_some()
             || self.restart_dialog.is_some()
             || self.context_menu.is_some()
             || self.repo_trust_dialog.is_some()
             || self.hooks_install_dialog.is_some()
             || self.volume_ignores_glob_dialog.is_some()
+            || self.unsandboxed_yolo_dialog.is_some()
             || self.intro_dialog.is_some()
             || self.no_agents_dialog.is_some()
             || self.changelog_dialog.is_some()
@@ -3509,6 +3950,8 @@ impl HomeView {
             || self.tool_picker_dialog.is_some()
             || self.send_message_dialog.is_some()
             || self.update_confirm_dialog.is_some()
+            || self.progress_dialog.is_some()
+            || self.activity_log_dialog.is_some()
             || self.telemetry_consent_dialog.is_some()
             || serve_open
             || self.settings_view.is_some()
@@ -3530,11 +3973,16 @@ impl HomeView {
             || self.group_delete_options_dialog.is_some()
             || self.rename_dialog.is_some()
             || self.worktree_name_dialog.is_some()
+            || self.notes_dialog.is_some()
+            || self.container_copy_dialog.is_some()
+            || self.fan_out_dialog.is_some()
+            || self.worktree_exists_dialog.is_some()
             || self.restart_dialog.is_some()
             || self.context_menu.is_some()
             || self.repo_trust_dialog.is_some()
             || self.hooks_install_dialog.is_some()
             || self.volume_ignores_glob_dialog.is_some()
+            || self.unsandboxed_yolo_dialog.is_some()
             || self.intro_dialog.is_some()
             || self.no_agents_dialog.is_some()
             || self.changelog_dialog.is_some()
@@ -3547,6 +3995,8 @@ impl HomeView {
             || self.tool_picker_dialog.is_some()
             || self.send_message_dialog.is_some()
             || self.update_confirm_dialog.is_some()
+            || self.progress_dialog.is_some()
+            || self.activity_log_dialog.is_some()
             || self.telemetry_consent_dialog.is_some()
             || serve_open
             || self.settings_view.is_some()
@@ -3614,6 +4064,21 @@ impl HomeView {
         }
     }
 
+    /// Cycle list + detail -> list only -> detail only and remember the
+    /// choice for the current profile.
+    pub fn cycle_home_layout(&mut self) {
+        self.home_layout = self.home_layout.cycle();
+        if let Ok(mut config) = load_config().map(|c| c.unwrap_or_default()) {
+            config
+                .app_state
+                .home_layout
+                .insert(self.config_profile(), self.home_layout);
+            if let Err(e) = save_config(&config) {
+                tracing::warn!(target: "tui.home", "Failed to save config: {e}");
+            }
+        }
+    }
+
     pub fn toggle_preview_info(&mut self) {
         self.show_preview_info = !self.show_preview_info;
         if let Ok(mut config) = load_config().map(|c| c.unwrap_or_default()) {
@@ -3659,7 +4124,7 @@ impl HomeView {
                 tracing::warn!(target: "tui.home", "Failed to save config: {e}");
             }
         }
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         // Defensive cursor clamp + selection refresh. Today the only
         // call site routes through `toggle_group_collapsed` after the
         // cursor lands on the section header, and the header survives
@@ -3712,18 +4177,103 @@ impl HomeView {
         self.instance_map.get(id)
     }
 
+    /// Show `handle`'s progress in a dialog, replacing any dialog for an
+    /// earlier operation.
+    pub fn open_progress_dialog(&mut self, title: &str, handle: ProgressHandle) {
+        self.progress_dialog = Some(ProgressDialog::new(title, handle));
+    }
+
+    /// Close the progress dialog if it is still showing `handle`'s
+    /// operation. A no-op when the user already hid it.
+    pub fn close_progress_dialog(&mut self, handle: &ProgressHandle) {
+        if self
+            .progress_dialog
+            .as_ref()
+            .is_some_and(|d| d.handle().same_operation(handle))
+        {
+            self.progress_dialog = None;
+        }
+    }
+
     /// Returns true if any session has an animated status (Running, Waiting, Starting,
     /// Creating), which means the TUI needs periodic redraws for spinner animation.
+    /// Archived and snoozed rows render a static glyph whatever their status
+    /// (see `agent_row_icon`), so they never keep the redraw timer alive.
     pub fn has_animated_sessions(&self) -> bool {
         use crate::session::Status;
         self.instances.iter().any(|inst| {
             matches!(
                 inst.status,
                 Status::Running | Status::Waiting | Status::Starting | Status::Creating
-            )
+            ) && !inst.is_archived()
+                && !inst.is_snoozed()
         })
     }
 
+    /// Fingerprint of every input `build_flat_items` reads: the session
+    /// fields the flatten and sort code keys on, the group trees, and the
+    /// view's grouping, sort, filter and collapsed state. Cursor and
+    /// selection are deliberately absent, so moving through the list never
+    /// invalidates the flattened items.
+    fn compute_flat_items_key(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut state = std::collections::hash_map::DefaultHasher::new();
+        self.group_by.hash(&mut state);
+        self.sort_order.hash(&mut state);
+        self.active_profile.hash(&mut state);
+        self.storages.len().hash(&mut state);
+        self.archived_section_collapsed.hash(&mut state);
+        for inst in &self.instances {
+            hash_flatten_inputs(inst, &mut state);
+            if self.group_by == GroupByMode::Project {
+                project_group_name(inst).hash(&mut state);
+            }
+        }
+        let mut trees: Vec<_> = self.group_trees.iter().collect();
+        trees.sort_by_key(|(profile, _)| *profile);
+        for (profile, tree) in trees {
+            profile.hash(&mut state);
+            for group in tree.get_all_groups() {
+                group.path.hash(&mut state);
+                group.name.hash(&mut state);
+                group.collapsed.hash(&mut state);
+                group.archived_at.hash(&mut state);
+            }
+        }
+        let mut project_collapsed: Vec<_> = self.project_group_collapsed.iter().collect();
+        project_collapsed.sort();
+        project_collapsed.hash(&mut state);
+        for project in &self.registered_projects {
+            project.name.hash(&mut state);
+            project.path.hash(&mut state);
+        }
+        state.finish()
+    }
+
+    /// Rebuild `flat_items` unconditionally. For call sites that just made a
+    /// structural change themselves (sort, grouping, collapse, add/remove).
+    pub(super) fn rebuild_flat_items(&mut self) {
+        self.flat_items_key = Some(self.compute_flat_items_key());
+        self.flat_items = self.build_flat_items();
+        self.search_index.invalidate();
+    }
+
+    /// Rebuild `flat_items` only when an input changed since the last build,
+    /// returning whether it did. The disk-refresh heartbeat goes through here
+    /// so reloading an unchanged session list skips the flatten and sort.
+    pub(super) fn refresh_flat_items(&mut self) -> bool {
+        // Paths and notes feed search but not the flattened order, so the
+        // search index is dropped even when the list itself is reused.
+        self.search_index.invalidate();
+        let key = self.compute_flat_items_key();
+        if self.flat_items_key == Some(key) {
+            return false;
+        }
+        self.flat_items_key = Some(key);
+        self.flat_items = self.build_flat_items();
+        true
+    }
+
     pub(super) fn build_flat_items(&self) -> Vec<Item> {
         // Project grouping is honored across every sort order. Combined with
         // Attention sort, sessions sort by tier within each project and the
@@ -3741,49 +4291,42 @@ impl HomeView {
         // off behind group headers. Project grouping above opts into a
         // different shape on purpose (attention triage within explicit
         // project boundaries).
+        // Only a profile filter needs its own copy of the instances; the
+        // unfiltered view borrows `self.instances` so a rebuild with hundreds
+        // of sessions doesn't clone every `Instance` on each refresh.
+        let filtered: Option<Vec<Instance>> = self.active_profile.as_ref().map(|profile| {
+            self.instances
+                .iter()
+                .filter(|i| i.source_profile == *profile)
+                .cloned()
+                .collect()
+        });
+        let pool: &[Instance] = filtered.as_deref().unwrap_or(&self.instances);
+
         if self.sort_order == SortOrder::Attention {
-            let filtered: Vec<Instance> = if let Some(profile) = &self.active_profile {
-                self.instances
-                    .iter()
-                    .filter(|i| i.source_profile == *profile)
-                    .cloned()
-                    .collect()
-            } else {
-                self.instances.clone()
-            };
-            let mut items = flatten_sessions_by_attention(&filtered);
-            append_archived_section(&mut items, &filtered, self.archived_section_collapsed);
+            let mut items = flatten_sessions_by_attention(pool);
+            append_archived_section(&mut items, pool, self.archived_section_collapsed);
             return items;
         }
 
-        let (mut items, archive_pool) = if let Some(profile) = &self.active_profile {
-            let filtered: Vec<Instance> = self
-                .instances
-                .iter()
-                .filter(|i| i.source_profile == *profile)
-                .cloned()
-                .collect();
-            let items = match self.group_trees.get(profile) {
-                Some(tree) => flatten_tree(tree, &filtered, self.sort_order),
+        let mut items = if let Some(profile) = &self.active_profile {
+            match self.group_trees.get(profile) {
+                Some(tree) => flatten_tree(tree, pool, self.sort_order),
                 None => Vec::new(),
-            };
-            (items, filtered)
+            }
         } else if self.storages.len() <= 1 {
-            let items = match self.group_trees.values().next() {
-                Some(tree) => flatten_tree(tree, &self.instances, self.sort_order),
+            match self.group_trees.values().next() {
+                Some(tree) => flatten_tree(tree, pool, self.sort_order),
                 None => Vec::new(),
-            };
-            (items, self.instances.clone())
+            }
         } else {
-            let items =
-                flatten_tree_all_profiles(&self.instances, &self.group_trees, self.sort_order);
-            (items, self.instances.clone())
+            flatten_tree_all_profiles(pool, &self.group_trees, self.sort_order)
         };
 
         // Pin the synthetic Archived section to the bottom regardless of
         // sort order. Archived rows were filtered out of the natural flow
         // inside `flatten_tree` / `flatten_tree_all_profiles`.
-        append_archived_section(&mut items, &archive_pool, self.archived_section_collapsed);
+        append_archived_section(&mut items, pool, self.archived_section_collapsed);
         items
     }
 
@@ -3903,6 +4446,11 @@ impl HomeView {
         self.selected_group_profile = None;
         self.reload()?;
         self.refresh_from_config(ConfigRefreshOrigin::Interactive);
+        self.home_layout = load_config()
+            .ok()
+            .flatten()
+            .and_then(|c| c.app_state.home_layout.get(&self.config_profile()).copied())
+            .unwrap_or_default();
         // Invalidate preview caches since the visible sessions changed
         self.preview_cache = PreviewCache::default();
         self.terminal_preview_cache = PreviewCache::default();
@@ -3919,6 +4467,12 @@ impl HomeView {
         Ok(())
     }
 
+    /// Theme to paint for the current profile filter: the filtered profile's
+    /// `theme_name` if it sets one, otherwise the global theme.
+    pub(crate) fn active_theme_name(&self) -> String {
+        crate::session::config::resolve_theme_name_for_profile(self.active_profile.as_deref())
+    }
+
     /// Show the profile picker dialog with fresh data from disk.
     pub(super) fn show_profile_picker(&mut self) {
         use crate::session::list_profiles;
@@ -4022,7 +4576,7 @@ impl HomeView {
                     "stamp_last_accessed: failed to persist auto-unsink"
                 );
             }
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
         } else {
             self.mutate_instance(id, |inst| inst.touch_last_accessed());
         }
@@ -4506,6 +5060,7 @@ impl HomeView {
                         disk_g.name = tui_g.name.clone();
                         disk_g.collapsed = tui_g.collapsed;
                         disk_g.archived_at = tui_g.archived_at;
+                        disk_g.sort_order = tui_g.sort_order;
                     } else {
                         disk_groups.push(tui_g.clone());
                     }
@@ -4550,7 +5105,7 @@ impl HomeView {
             self.selected_session = None;
         }
         self.rebuild_group_trees();
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         if self.cursor >= self.flat_items.len() {
             self.cursor = self.flat_items.len().saturating_sub(1);
         }
@@ -5057,12 +5612,18 @@ impl HomeView {
         }
     }
 
-    /// Get the terminal mode for a session (uses config default if not set)
+    /// Get the terminal mode for a session. The session's persisted
+    /// override wins; otherwise the config default applies.
     pub fn get_terminal_mode(&self, session_id: &str) -> TerminalMode {
-        self.terminal_modes
+        match self
+            .instance_map
             .get(session_id)
-            .copied()
-            .unwrap_or(self.default_terminal_mode)
+            .and_then(|inst| inst.terminal_mode)
+        {
+            Some(DefaultTerminalMode::Host) => TerminalMode::Host,
+            Some(DefaultTerminalMode::Container) => TerminalMode::Container,
+            None => self.default_terminal_mode,
+        }
     }
 
     /// The profile whose config the view should resolve. The active profile
@@ -5297,7 +5858,7 @@ impl HomeView {
                     }
                 }
             }
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
         }
         if let Some(pos) = self
             .flat_items
@@ -5365,14 +5926,19 @@ impl HomeView {
             DefaultTerminalMode::Container => TerminalMode::Container,
         };
         self.sound_config = config.sound.clone();
+        self.notification_config = config.notifications.clone();
         self.status_hook_config = config.status_hooks.clone();
         self.refresh_status_hook_config_cache();
         self.strict_hotkeys = config.session.strict_hotkeys;
         self.confirm_before_quit = config.session.confirm_before_quit;
+        self.quick_delete_clean_sessions = config.session.quick_delete_clean_sessions;
+        self.max_sessions_warn = config.session.max_sessions_warn;
+        self.max_sessions = config.session.max_sessions;
         self.row_tag_mode = config.session.row_tag;
         self.profile_default_attach_mode = config.session.default_attach_mode;
         self.idle_decay_window =
             crate::tui::styles::idle_decay_window(config.theme.idle_decay_minutes);
+        self.display_config = config.display.clone();
         self.tool_configs = config.tools;
         self.tool_hotkey_cache = input::build_tool_hotkey_cache(&self.tool_configs);
         let hotkey_warnings = input::validate_tool_hotkeys(&self.tool_configs);
@@ -5386,8 +5952,9 @@ impl HomeView {
             ));
         }
         // Watcher path: stash for tick-loop dispatch (App owns theme state).
-        // Reads via `resolve_theme_name` (global-only by contract), not
-        // `config.theme.name` which would carry a stale per-profile override.
+        // Reads via `active_theme_name` (global theme, or the filtered
+        // profile's `theme_name`), not `config.theme.name` which would carry
+        // a stale per-profile `[theme]` override.
         // Guard is load-bearing: Interactive already returns
         // `Action::SetTheme` directly from input handlers, so stashing
         // unconditionally would double-dispatch on every settings save.
@@ -5399,7 +5966,7 @@ impl HomeView {
         // here; acceptable since `set_theme` is idempotent and the race
         // window is microseconds wide.
         if matches!(origin, ConfigRefreshOrigin::Watcher) {
-            self.pending_watcher_theme = Some(crate::session::config::resolve_theme_name());
+            self.pending_watcher_theme = Some(self.active_theme_name());
         }
     }
 
@@ -5488,14 +6055,14 @@ impl HomeView {
         }
     }
 
-    /// Toggle terminal mode between Container and Host for a session
-    pub fn toggle_terminal_mode(&mut self, session_id: &str) {
-        let current = self.get_terminal_mode(session_id);
-        let new_mode = match current {
-            TerminalMode::Container => TerminalMode::Host,
-            TerminalMode::Host => TerminalMode::Container,
+    /// Toggle terminal mode between Container and Host for a session and
+    /// persist the choice on the instance so it survives a restart.
+    pub fn toggle_terminal_mode(&mut self, session_id: &str) -> anyhow::Result<()> {
+        let new_mode = match self.get_terminal_mode(session_id) {
+            TerminalMode::Container => DefaultTerminalMode::Host,
+            TerminalMode::Host => DefaultTerminalMode::Container,
         };
-        self.terminal_modes.insert(session_id.to_string(), new_mode);
+        self.apply_user_action(session_id, |inst| inst.terminal_mode = Some(new_mode))
     }
 
     pub fn start_container_terminal_for_instance_with_size(
@@ -5503,7 +6070,13 @@ impl HomeView {
         id: &str,
         size: Option<(u16, u16)>,
     ) -> anyhow::Result<()> {
-        self.try_mutate_instance(id, |inst| inst.start_container_terminal_with_size(size))
-            .map(|_| ())
+        // Record the failure (runtime down, exec refused) on the session so
+        // it stays visible after the caller's toast or dialog is gone, and
+        // clear it once a retry gets through.
+        let result =
+            self.try_mutate_instance(id, |inst| inst.start_container_terminal_with_size(size));
+        let error = result.as_ref().err().map(|e| e.to_string());
+        self.set_instance_error(id, error);
+        result.map(|_| ())
     }
 }
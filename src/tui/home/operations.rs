@@ -1,11 +1,12 @@
 //! Session operations for HomeView (create, delete, rename)
 
 use crate::session::builder::{self, InstanceParams};
+use crate::session::config::{count_active_sessions, SessionConfig};
 use crate::session::{list_profiles, GroupTree, Item, Status, Storage};
 use crate::tui::deletion_poller::DeletionRequest;
 use crate::tui::dialogs::{DeleteOptions, GroupDeleteOptions, NewSessionData};
 
-use super::HomeView;
+use super::{HomeView, TaskOutcome};
 
 /// Compact human readable label for the snooze status line (`"30 min"`,
 /// `"1 hr"`, `"24 hr"`, `"2 hr 30 min"`). The picker only ever submits
@@ -181,10 +182,49 @@ impl HomeView {
         }
 
         self.refresh_registered_projects();
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         self.update_selected();
     }
 
+    /// `session.max_sessions_warn` / `session.max_sessions` for `profile`.
+    /// The config profile's values are cached on the view; any other target
+    /// profile is resolved from its own config.
+    fn session_limits(&self, profile: &str) -> SessionConfig {
+        if profile != self.config_profile() {
+            return crate::session::profile_config::resolve_config_or_warn(profile).session;
+        }
+        SessionConfig {
+            max_sessions_warn: self.max_sessions_warn,
+            max_sessions: self.max_sessions,
+            ..SessionConfig::default()
+        }
+    }
+
+    fn active_session_count(&self, profile: &str) -> usize {
+        count_active_sessions(
+            self.instances()
+                .iter()
+                .filter(|i| i.source_profile == profile),
+        )
+    }
+
+    /// Refuse to create `adding` more sessions in `profile` when that would
+    /// go past `session.max_sessions`.
+    pub(super) fn check_session_limit(&self, profile: &str, adding: usize) -> anyhow::Result<()> {
+        self.session_limits(profile)
+            .check_session_limit(self.active_session_count(profile), adding)
+    }
+
+    /// Active session count for the config profile when it is past
+    /// `session.max_sessions_warn`, for the footer's cleanup hint.
+    pub(super) fn session_count_warning(&self) -> Option<usize> {
+        let profile = self.config_profile();
+        let active = self.active_session_count(&profile);
+        self.session_limits(&profile)
+            .exceeds_session_warn(active)
+            .then_some(active)
+    }
+
     pub(super) fn create_session(&mut self, data: NewSessionData) -> anyhow::Result<String> {
         let target_profile = data.profile.clone();
 
@@ -212,14 +252,20 @@ impl HomeView {
             worktree_branch: data.worktree_branch,
             create_new_branch: data.create_new_branch,
             base_branch: data.base_branch,
+            worktree_on_exists: data.worktree_on_exists,
             sandbox: data.sandbox,
             sandbox_image: data.sandbox_image,
             yolo_mode: data.yolo_mode,
+            allow_unsandboxed_yolo: data.allow_unsandboxed_yolo,
             extra_env: data.extra_env,
+            cpu_limit: data.cpu_limit,
+            memory_limit: data.memory_limit,
+            custom_instruction: data.custom_instruction,
             extra_args: data.extra_args,
             command_override: data.command_override,
             extra_repo_paths: data.extra_repo_paths,
             scratch: data.scratch,
+            auto_create_terminal: data.auto_create_terminal,
         };
 
         let build_result = builder::build_instance(
@@ -427,7 +473,7 @@ impl HomeView {
                 // Rebuild the visible row list too; otherwise the row still
                 // renders under the old profile until the next reload, and
                 // any follow-up keybind hits stale cursor state.
-                self.flat_items = self.build_flat_items();
+                self.rebuild_flat_items();
             }
         }
 
@@ -507,6 +553,25 @@ impl HomeView {
         Ok(())
     }
 
+    /// Relaunch a session's agent inside its existing tmux pane. Failures
+    /// surface in an info dialog rather than flipping the row to Error.
+    pub(super) fn restart_agent_in_place(&mut self, id: &str) {
+        let size = crate::terminal::get_size();
+        let result = self
+            .try_mutate_instance_writeback_on_err(id, |inst| inst.restart_agent_in_place(size))
+            .and_then(|_| {
+                self.mutate_instance(id, |inst| inst.touch_last_accessed());
+                self.save()
+            });
+        if let Err(e) = result {
+            tracing::warn!(target: "tui.home", "restart_agent_in_place failed: {}", e);
+            self.info_dialog = Some(crate::tui::dialogs::InfoDialog::new(
+                "Restart Failed",
+                &format!("Could not restart the agent: {e}"),
+            ));
+        }
+    }
+
     pub(super) fn delete_selected(&mut self, options: &DeleteOptions) -> anyhow::Result<()> {
         if let Some(id) = &self.selected_session {
             let id = id.clone();
@@ -523,6 +588,7 @@ impl HomeView {
                     force_delete: options.force_delete,
                     detach_hooks: true,
                     keep_scratch: options.keep_scratch,
+                    keep_unmerged_branch: options.keep_unmerged_branch,
                 };
                 self.deletion_poller.request_deletion(request);
             }
@@ -624,6 +690,9 @@ impl HomeView {
                         // keep-scratch toggle; scratch dirs in a group
                         // delete are removed unconditionally.
                         keep_scratch: false,
+                        // Branch deletion is an explicit choice in the
+                        // group dialog, so it is not gated on merge state.
+                        keep_unmerged_branch: false,
                     };
                     self.deletion_poller.request_deletion(request);
                 }
@@ -638,7 +707,7 @@ impl HomeView {
                 }
             }
             self.save()?;
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
         }
         Ok(())
     }
@@ -731,6 +800,26 @@ impl HomeView {
             }
         }
 
+        // Keep the cursor on the group under its new name; reload restores
+        // selection by path, and the old path no longer exists.
+        let select_renamed = |view: &mut Self| -> anyhow::Result<()> {
+            view.selected_session = None;
+            view.selected_group = Some(new_path.to_string());
+            view.selected_group_profile = Some(target_profile.to_string());
+            view.reload()
+        };
+
+        let Some(new_profile) = new_profile.filter(|p| *p != ctx.old_profile) else {
+            // Same-profile rename: one locked write moves the sessions and
+            // the group tree (with its collapsed/archived state) together.
+            let storage = self
+                .storages
+                .get(&ctx.old_profile)
+                .ok_or_else(|| anyhow::anyhow!("Profile '{}' is not loaded", ctx.old_profile))?;
+            storage.rename_group(&ctx.old_path, new_path)?;
+            return select_renamed(self);
+        };
+
         let old_prefix = format!("{}/", ctx.old_path);
 
         // Collect sessions belonging to this group and its descendants
@@ -744,7 +833,7 @@ impl HomeView {
             .map(|i| i.id.clone())
             .collect();
 
-        // Update group_path (and optionally source_profile) for all affected sessions
+        // Move every affected session into the target profile
         for id in &affected_ids {
             let new_group_path = if new_path != ctx.old_path {
                 let inst = self.get_instance(id);
@@ -760,45 +849,34 @@ impl HomeView {
                 }
             };
 
-            if let Some(tp) = new_profile {
-                self.move_to_profile(id, tp, new_group_path.clone())?;
-            } else {
-                self.apply_user_action(id, |inst| {
-                    inst.group_path = new_group_path.clone();
-                })?;
-            }
+            self.move_to_profile(id, new_profile, new_group_path)?;
         }
 
-        // Ensure target profile storage exists when moving across profiles
-        if let Some(tp) = new_profile {
-            if tp != ctx.old_profile && !self.storages.contains_key(tp) {
-                self.storages
-                    .insert(tp.to_string(), Storage::new(tp, self.file_watch.clone())?);
-            }
+        // Ensure target profile storage exists
+        if !self.storages.contains_key(new_profile) {
+            self.storages.insert(
+                new_profile.to_string(),
+                Storage::new(new_profile, self.file_watch.clone())?,
+            );
         }
 
         let path_changed = new_path != ctx.old_path;
-        let profile_changed = new_profile.is_some_and(|p| p != ctx.old_profile);
 
         // Capture old_path and its descendants from the pre-rebuild tree:
         // rebuild_group_trees below derives groups from instance.group_path,
         // which the loop above already migrated, so the old paths are about
         // to disappear from the in-memory tree.
-        let stale_paths: Vec<String> = if path_changed || profile_changed {
-            let prefix = format!("{}/", ctx.old_path);
-            self.group_trees
-                .get(&ctx.old_profile)
-                .map(|tree| {
-                    tree.get_all_groups()
-                        .into_iter()
-                        .map(|g| g.path)
-                        .filter(|p| p == &ctx.old_path || p.starts_with(&prefix))
-                        .collect()
-                })
-                .unwrap_or_else(|| vec![ctx.old_path.clone()])
-        } else {
-            Vec::new()
-        };
+        let stale_paths: Vec<String> = self
+            .group_trees
+            .get(&ctx.old_profile)
+            .map(|tree| {
+                tree.get_all_groups()
+                    .into_iter()
+                    .map(|g| g.path)
+                    .filter(|p| p == &ctx.old_path || p.starts_with(&old_prefix))
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![ctx.old_path.clone()]);
 
         // Rebuild trees from the updated instance list
         self.rebuild_group_trees();
@@ -808,23 +886,18 @@ impl HomeView {
                 tree.rename_group(&ctx.old_path, new_path);
             }
         }
-        if path_changed || profile_changed {
-            self.pending_group_deletions
-                .entry(ctx.old_profile.clone())
-                .or_default()
-                .extend(stale_paths);
-        }
-
-        // When moving to a different profile, ensure the new path exists in the target tree
-        if let Some(tp) = new_profile {
-            if let Some(tree) = self.group_trees.get_mut(tp) {
-                tree.create_group(new_path);
-            }
+        self.pending_group_deletions
+            .entry(ctx.old_profile.clone())
+            .or_default()
+            .extend(stale_paths);
+
+        // Ensure the new path exists in the target tree
+        if let Some(tree) = self.group_trees.get_mut(new_profile) {
+            tree.create_group(new_path);
         }
 
         self.save()?;
-        self.reload()?;
-        Ok(())
+        select_renamed(self)
     }
 
     /// Edit the selected session's worktree workdir name: move the worktree
@@ -877,6 +950,99 @@ impl HomeView {
         Ok(())
     }
 
+    /// Create a managed worktree for `branch` from the selected session's
+    /// checkout on a background task. Creating the worktree and stashing and
+    /// re-applying changes all shell out to git, so they stay off the UI
+    /// thread; a sandbox container is discarded there too so the next start
+    /// recreates it against the worktree. The task resolves to
+    /// [`TaskOutcome::WorktreeConverted`], which `persist_worktree_conversion`
+    /// writes back. Errors here are the up-front refusals.
+    pub(super) fn convert_to_worktree_for_selected(
+        &mut self,
+        branch: &str,
+        carry_changes: bool,
+    ) -> anyhow::Result<()> {
+        let Some(id) = self.selected_session.clone() else {
+            return Ok(());
+        };
+        let snapshot = self.get_instance(&id).map(|i| {
+            (
+                i.status,
+                i.project_path.clone(),
+                i.effective_profile(),
+                i.is_sandboxed(),
+            )
+        });
+        let Some((status, project_path, profile, sandboxed)) = snapshot else {
+            anyhow::bail!("Session not found");
+        };
+        // The tmux pane and any sandbox container are rooted at the old
+        // path; re-pointing them requires a restart.
+        if status.blocks_worktree_edit()
+            || crate::session::worktree_edit::sandbox_container_holds_worktree(&id, sandboxed)
+        {
+            anyhow::bail!("Stop the session before converting it to a worktree");
+        }
+
+        let branch = branch.to_string();
+        let label = format!("Creating worktree for branch '{branch}'...");
+        self.spawn_background_task("Converting to Worktree", label, move || {
+            let config = crate::session::profile_config::resolve_config_or_warn(&profile);
+            let result = crate::session::worktree_convert::convert_to_worktree(
+                crate::session::worktree_convert::WorktreeConvertRequest {
+                    current_path: std::path::Path::new(&project_path),
+                    branch: &branch,
+                    base_branch: None,
+                    carry_changes,
+                    profile: &profile,
+                    config: &config.worktree,
+                    session_id: &id,
+                },
+            )
+            .map_err(|e| e.to_string());
+            if result.is_ok() {
+                crate::session::worktree_edit::discard_sandbox_container_after_move(&id, sandboxed);
+            }
+            TaskOutcome::WorktreeConverted { id, result }
+        });
+        Ok(())
+    }
+
+    /// Point session `id` at the worktree a finished conversion created,
+    /// persisting `project_path` and `worktree_info` through
+    /// `apply_user_action`.
+    pub(super) fn persist_worktree_conversion(
+        &mut self,
+        id: &str,
+        outcome: &crate::session::worktree_convert::WorktreeConvertOutcome,
+    ) -> anyhow::Result<()> {
+        let new_path = outcome.new_path.to_string_lossy().to_string();
+        let worktree_info = outcome.worktree_info.clone();
+
+        self.apply_user_action(id, |inst| {
+            inst.project_path = new_path.clone();
+            inst.worktree_info = Some(worktree_info.clone());
+        })
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Worktree was created at {new_path}, but persisting the new session metadata failed: {e}. Remove the worktree with 'git worktree remove' before retrying."
+            )
+        })?;
+
+        self.rebuild_group_trees();
+        self.save()?;
+        self.reload()?;
+        Ok(())
+    }
+
+    /// Set (or clear, with `None`) the selected session's free-text notes.
+    pub(super) fn set_notes_for_selected(&mut self, notes: Option<String>) -> anyhow::Result<()> {
+        let Some(id) = self.selected_session.clone() else {
+            return Ok(());
+        };
+        self.apply_user_action(&id, |inst| inst.notes = notes)
+    }
+
     pub(super) fn rename_selected(
         &mut self,
         new_title: &str,
@@ -1131,7 +1297,7 @@ impl HomeView {
         };
         if is_snoozed {
             self.apply_user_action(&id, |inst| inst.unsnooze())?;
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             return Ok(Some(format!("Woke: {}", title)));
         }
 
@@ -1156,7 +1322,7 @@ impl HomeView {
             .map(|i| i.title.clone())
             .unwrap_or_default();
         self.apply_user_action(id, |inst| inst.snooze(minutes))?;
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         if self.sort_order == crate::session::config::SortOrder::Attention {
             self.select_top_attention(None);
         }
@@ -1190,10 +1356,142 @@ impl HomeView {
         } else {
             self.apply_user_action(&id, |inst| inst.favorite())?;
         }
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         Ok(())
     }
 
+    /// Lock or unlock the cursor's worktree session. The git lock is applied
+    /// first so the persisted flag never claims a lock git does not hold.
+    pub(super) fn toggle_worktree_lock_at_cursor(&mut self) {
+        use crate::tui::dialogs::InfoDialog;
+
+        let Some(id) = self.selected_session.clone() else {
+            return;
+        };
+        let Some(inst) = self.instances.iter().find(|i| i.id == id) else {
+            return;
+        };
+        if inst.worktree_info.is_none() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Not a Worktree Session",
+                "Only worktree sessions can be locked.",
+            ));
+            return;
+        }
+        let locked = !inst.worktree_locked;
+        match crate::session::worktree_lock::set_worktree_locked(inst, locked) {
+            Ok(()) => {}
+            // Nothing left on disk to protect: clear the flag so the session
+            // can be deleted, but say why git was not consulted.
+            Err(e @ crate::git::error::GitError::WorktreeNotFound(_)) if !locked => {
+                self.info_dialog = Some(InfoDialog::new(
+                    "Worktree Missing",
+                    &format!("{e}. The session was unlocked without touching git."),
+                ));
+            }
+            Err(e) => {
+                self.info_dialog = Some(InfoDialog::new(
+                    "Worktree Lock Failed",
+                    &format!("Could not update the worktree lock: {e}"),
+                ));
+                return;
+            }
+        }
+        if let Err(e) = self.apply_user_action(&id, |inst| inst.worktree_locked = locked) {
+            tracing::error!(target: "tui.home", "toggle_worktree_lock_at_cursor failed: {}", e);
+        }
+    }
+
+    /// Flip YOLO mode on the selected session and persist it. The flag is
+    /// only read when the agent launches, so a live session is offered a
+    /// relaunch. Agents that take YOLO as an env var get it baked into the
+    /// sandbox container at creation, so sandboxed sessions of those agents
+    /// are offered a container rebuild instead, whether running or not.
+    pub(super) fn toggle_yolo_at_cursor(&mut self) {
+        use crate::agents::YoloMode;
+        use crate::tui::dialogs::{ConfirmDialog, InfoDialog};
+
+        let Some(id) = self.selected_session.clone() else {
+            return;
+        };
+        let Some(inst) = self.instances.iter().find(|i| i.id == id) else {
+            return;
+        };
+        if matches!(inst.status, Status::Deleting | Status::Creating) {
+            return;
+        }
+        let env_var_yolo = match crate::agents::get_agent(&inst.tool).and_then(|a| a.yolo.as_ref())
+        {
+            Some(YoloMode::AlwaysYolo) => {
+                self.info_dialog = Some(InfoDialog::new(
+                    "Not Available",
+                    &format!("{} always runs in YOLO mode.", inst.tool),
+                ));
+                return;
+            }
+            None => {
+                self.info_dialog = Some(InfoDialog::new(
+                    "Not Available",
+                    &format!("{} has no YOLO mode.", inst.tool),
+                ));
+                return;
+            }
+            Some(YoloMode::EnvVar(..)) => true,
+            Some(YoloMode::CliFlag(_)) => false,
+        };
+        let yolo = !inst.yolo_mode;
+        if builder::check_yolo_sandbox(&inst.tool, yolo, inst.is_sandboxed(), false).is_err() {
+            self.info_dialog = Some(InfoDialog::new(
+                "Not Available",
+                "YOLO mode can only be turned on for sandboxed sessions: on the host the \
+                 agent would skip permission prompts with full access to this machine.",
+            ));
+            return;
+        }
+        let title = inst.title.clone();
+        let needs_rebuild = env_var_yolo && inst.is_sandboxed();
+        let is_live = !matches!(inst.status, Status::Stopped | Status::Error);
+
+        if let Err(e) = self.apply_user_action(&id, |inst| inst.yolo_mode = yolo) {
+            tracing::error!(target: "tui.home", "toggle_yolo_at_cursor failed: {}", e);
+            return;
+        }
+
+        let state = if yolo { "on" } else { "off" };
+        if needs_rebuild {
+            self.pending_rebuild_container = Some(id);
+            self.confirm_dialog = Some(
+                ConfirmDialog::new(
+                    "YOLO Mode Changed",
+                    &format!(
+                        "YOLO mode is now {state} for '{title}'. This agent reads it from \
+                         the sandbox container's environment, so it takes effect once the \
+                         container is rebuilt. Rebuild now? The agent will be stopped."
+                    ),
+                    "rebuild_container",
+                )
+                .neutral(),
+            );
+        } else if is_live {
+            self.confirm_dialog = Some(
+                ConfirmDialog::new(
+                    "YOLO Mode Changed",
+                    &format!(
+                        "YOLO mode is now {state} for '{title}'. The running agent keeps \
+                         its old setting until it is relaunched. Restart it now?"
+                    ),
+                    "restart_session",
+                )
+                .neutral(),
+            );
+        } else {
+            self.info_dialog = Some(InfoDialog::new(
+                "YOLO Mode Changed",
+                &format!("YOLO mode is now {state} for '{title}'. It applies the next time the session starts."),
+            ));
+        }
+    }
+
     /// The session the cursor should land on after the cursor's row is
     /// archived away: the nearest non-archived session below the cursor,
     /// else the nearest one above. `None` when no other active session is
@@ -1248,7 +1546,7 @@ impl HomeView {
         };
         if is_archived {
             self.apply_user_action(&id, |inst| inst.unarchive())?;
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             // Re-seat the cursor on the just-unarchived session. After the
             // flat_items rebuild the row jumps from tier 99 to its real
             // tier, so without this the cursor stays at the old index and
@@ -1281,7 +1579,7 @@ impl HomeView {
             // cursor advances to the next item that needs attention. That path
             // already lands selection on a live row, so it never showed the
             // dead-pane/selection-swap jank the default sort did.
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             self.select_top_attention(None);
             // select_top_attention is a no-op when no session row is visible
             // (the archived row sank into a collapsed Archived section and
@@ -1305,7 +1603,7 @@ impl HomeView {
             // motivated the old follow-the-row behavior (#2025). The
             // Archived section is not auto-revealed; its header already
             // shows the updated count as feedback.
-            self.flat_items = self.build_flat_items();
+            self.rebuild_flat_items();
             match successor {
                 Some(next) => self.select_session_by_id(&next),
                 None => {
@@ -1382,7 +1680,7 @@ impl HomeView {
         }
         self.bulk_apply_user_action(&ids, |inst| inst.archive())?;
         self.reveal_archived_section();
-        self.flat_items = self.build_flat_items();
+        self.rebuild_flat_items();
         // The project header vanishes once its last active member is archived
         // (project headers are seeded from live sessions only), so the cursor's
         // old index may now point past the list end; clamp and re-resolve.
@@ -7,14 +7,17 @@ use std::time::{Duration, Instant};
 
 use rattles::presets::prelude as spinners;
 
+use super::bindings::{self, ActionId};
 use super::{
     get_indent, live_send, HomeView, TerminalMode, ViewMode, ICON_COLLAPSED, ICON_DELETING,
-    ICON_ERROR, ICON_EXPANDED, ICON_IDLE, ICON_PINNED, ICON_STOPPED, ICON_UNKNOWN,
+    ICON_ERROR, ICON_EXPANDED, ICON_IDLE, ICON_PINNED, ICON_STOPPED, ICON_TMUX_GONE, ICON_UNKNOWN,
 };
 use crate::containers::image_update::ImageUpdate;
-use crate::session::config::{GroupByMode, SortOrder};
+use crate::session::config::{GroupByMode, HomeLayout, SortOrder};
 use crate::session::{Item, Status};
 use crate::tui::components::preview::{self, CachedPreview};
+use crate::tui::components::progress::{animation_tick, progress_bar_line};
+use crate::tui::components::relative_time::format_relative_age;
 use crate::tui::components::{
     format_scroll_indicator, set_prefixed_input_cursor_position, HelpOverlay, Preview,
 };
@@ -213,6 +216,7 @@ pub(crate) fn agent_row_icon(inst: &crate::session::Instance) -> &'static str {
         Status::Idle => ICON_IDLE,
         Status::Unknown => ICON_UNKNOWN,
         Status::Stopped => ICON_STOPPED,
+        Status::Error if inst.is_tmux_gone() => ICON_TMUX_GONE,
         Status::Error => ICON_ERROR,
         Status::Starting => spinner_starting(&inst.created_at),
         Status::Deleting => ICON_DELETING,
@@ -348,37 +352,6 @@ pub(crate) fn profile_short_code(profile: &str) -> String {
     code.to_lowercase()
 }
 
-/// Format a timestamp as a compact relative age (e.g. `3m`, `2h`, `4d`, `2mo`).
-/// Returns an empty string for `None` so callers can unconditionally substitute
-/// the result without guarding for absence.
-fn format_relative_age(ts: Option<DateTime<Utc>>) -> String {
-    let Some(ts) = ts else {
-        return String::new();
-    };
-    let now = Utc::now();
-    let secs = (now - ts).num_seconds();
-    if secs <= 0 {
-        return "<1m".to_string();
-    }
-    if secs < 60 {
-        return "<1m".to_string();
-    }
-    let mins = secs / 60;
-    if mins < 60 {
-        return format!("{}m", mins);
-    }
-    let hours = mins / 60;
-    if hours < 24 {
-        return format!("{}h", hours);
-    }
-    let days = hours / 24;
-    if days < 30 {
-        return format!("{}d", days);
-    }
-    let months = days / 30;
-    format!("{}mo", months)
-}
-
 /// Format a remaining snooze duration as a compact countdown string that
 /// fits in the `LAST_ACTIVITY_SLOT` (e.g. `23m`, `1h`, `5d`). Falls back
 /// to `<1m` for sub-minute remainders so the user sees "about to wake"
@@ -539,11 +512,17 @@ impl HomeView {
         // stacking gives the preview the full width.
         let available_width = main_chunks[0].width;
         self.main_area_width = available_width;
-        // Collapsed sidebar (live mode only): hand the whole main area to
-        // the preview so the agent pane fills the terminal. The live-send
-        // resize loop then reflows the agent to the wider geometry. Reset
-        // on live-send exit, so the list always returns in the home view.
-        if self.live_send.is_some() && self.sidebar_collapsed {
+        // Live mode keeps its own sidebar toggle (reset on live-send exit);
+        // otherwise the persisted home layout decides which panes show.
+        // Detail only (or a collapsed live sidebar) hands the whole main
+        // area to the preview; in live mode the live-send resize loop then
+        // reflows the agent to the wider geometry.
+        let layout = match &self.live_send {
+            Some(_) if self.sidebar_collapsed => HomeLayout::DetailOnly,
+            Some(_) => HomeLayout::Split,
+            None => self.home_layout,
+        };
+        if layout == HomeLayout::DetailOnly {
             self.divider_col = None;
             // render_list is skipped, so its hit-test rects would otherwise
             // keep last frame's values and a click in the now-preview area
@@ -553,6 +532,15 @@ impl HomeView {
             self.list_area = Rect::default();
             self.list_inner_area = Rect::default();
             self.render_preview(frame, main_chunks[0], theme);
+        } else if layout == HomeLayout::ListOnly {
+            // No preview: clear its hit-test rects (as the diff takeover
+            // does) and stop the capture worker feeding it.
+            self.divider_col = None;
+            self.preview_area = Rect::default();
+            self.preview_pane_area = Rect::default();
+            self.preview_outer_area = Rect::default();
+            self.sync_preview_capture_worker(None);
+            self.render_list(frame, main_chunks[0], theme);
         } else if available_width < responsive::STACKED_BREAKPOINT {
             let main_height = main_chunks[0].height;
             let list_height = responsive::stacked_list_height(main_height);
@@ -651,9 +639,14 @@ impl HomeView {
             group_delete_options_dialog,
             rename_dialog,
             worktree_name_dialog,
+            notes_dialog,
+            container_copy_dialog,
+            fan_out_dialog,
+            worktree_exists_dialog,
             restart_dialog,
             hooks_install_dialog,
             volume_ignores_glob_dialog,
+            unsandboxed_yolo_dialog,
             repo_trust_dialog,
             intro_dialog,
             no_agents_dialog,
@@ -670,6 +663,8 @@ impl HomeView {
             tool_picker_dialog,
             send_message_dialog,
             update_confirm_dialog,
+            activity_log_dialog,
+            progress_dialog,
             // context_menu renders last so its small popup sits on top of
             // any underlying dialog (e.g. an info dialog opened by a
             // gated rename/delete attempt).
@@ -732,8 +727,15 @@ impl HomeView {
         // ratatui's `title_bottom` so it renders on the existing border and
         // never intersects row content.
         let sort_indicator = format!(" sort: {} ", self.sort_order.label());
+        // The preview's left border doubles as the list's right edge; with
+        // no preview beside it the list closes its own box.
+        let borders = if self.live_send.is_none() && self.home_layout == HomeLayout::ListOnly {
+            Borders::ALL
+        } else {
+            Borders::TOP | Borders::LEFT | Borders::BOTTOM
+        };
         let block = Block::default()
-            .borders(Borders::TOP | Borders::LEFT | Borders::BOTTOM)
+            .borders(borders)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(border_color))
             .title(title)
@@ -784,20 +786,19 @@ impl HomeView {
             )));
         }
 
+        // Only the visible window is built and styled, so frame cost tracks
+        // the viewport height rather than the number of sessions.
+        let window = scroll.scroll_offset..scroll.scroll_offset + scroll.list_visible;
+        self.list_visible_range = window.clone();
         let hover_idx = self.hovered_index();
-        for (i, item) in self
-            .flat_items
-            .iter()
-            .skip(scroll.scroll_offset)
-            .take(scroll.list_visible)
-            .enumerate()
-        {
-            let abs_idx = i + scroll.scroll_offset;
+        let mut rows_built = 0;
+        for (abs_idx, item) in window.clone().zip(&self.flat_items[window]) {
             let is_selected = abs_idx == self.cursor;
             let is_hovered = !is_selected && Some(abs_idx) == hover_idx;
             let is_match =
                 !self.search_matches.is_empty() && self.search_matches.contains(&abs_idx);
             let mut line = self.render_item_line(item, is_selected, is_match, theme, inner.width);
+            rows_built += 1;
             // Selection wins over hover: when the mouse is over the
             // already-selected row, keep the brighter selected bg rather
             // than the dimmer hover bg.
@@ -815,6 +816,7 @@ impl HomeView {
             }
             lines.push(line);
         }
+        self.list_rows_rendered = rows_built;
 
         if scroll.has_more_below {
             let remaining = self.flat_items.len() - scroll.scroll_offset - scroll.list_visible;
@@ -889,9 +891,14 @@ impl HomeView {
             || self.group_delete_options_dialog.is_some()
             || self.rename_dialog.is_some()
             || self.worktree_name_dialog.is_some()
+            || self.notes_dialog.is_some()
+            || self.container_copy_dialog.is_some()
+            || self.fan_out_dialog.is_some()
+            || self.worktree_exists_dialog.is_some()
             || self.repo_trust_dialog.is_some()
             || self.hooks_install_dialog.is_some()
             || self.volume_ignores_glob_dialog.is_some()
+            || self.unsandboxed_yolo_dialog.is_some()
             || self.intro_dialog.is_some()
             || self.no_agents_dialog.is_some()
             || self.changelog_dialog.is_some()
@@ -905,6 +912,8 @@ impl HomeView {
             || self.command_palette.is_some()
             || self.send_message_dialog.is_some()
             || self.update_confirm_dialog.is_some()
+            || self.progress_dialog.is_some()
+            || self.activity_log_dialog.is_some()
             || serve_open
     }
 
@@ -1008,6 +1017,7 @@ impl HomeView {
                                 Status::Idle => ICON_IDLE,
                                 Status::Unknown => ICON_UNKNOWN,
                                 Status::Stopped => ICON_STOPPED,
+                                Status::Error if inst.is_tmux_gone() => ICON_TMUX_GONE,
                                 Status::Error => ICON_ERROR,
                                 Status::Starting => spinner_starting(&inst.created_at),
                                 Status::Deleting => ICON_DELETING,
@@ -1021,6 +1031,7 @@ impl HomeView {
                                 }
                                 Status::Unknown => theme.waiting,
                                 Status::Stopped => theme.dimmed,
+                                Status::Error if inst.is_tmux_gone() => theme.waiting,
                                 Status::Error => theme.error,
                                 Status::Starting => theme.dimmed,
                                 Status::Deleting => theme.waiting,
@@ -1210,6 +1221,28 @@ impl HomeView {
                         ));
                     }
                 }
+                if let Some(ahead) = self.ahead_counts.get(id).filter(|c| c.ahead > 0) {
+                    let ahead_style = Style::default().fg(theme.accent);
+                    line_spans.push(Span::styled(
+                        format!("  ↑{}", ahead.ahead),
+                        if is_selected {
+                            selected_row_style(ahead_style, theme)
+                        } else {
+                            ahead_style
+                        },
+                    ));
+                }
+                if inst.worktree_locked {
+                    let lock_style = Style::default().fg(theme.waiting);
+                    line_spans.push(Span::styled(
+                        "  [locked]",
+                        if is_selected {
+                            selected_row_style(lock_style, theme)
+                        } else {
+                            lock_style
+                        },
+                    ));
+                }
 
                 // Per-row tag. The mode is config-driven (see
                 // `SessionConfig.row_tag` and the Settings UI "Row Tag"
@@ -1542,6 +1575,16 @@ impl HomeView {
         true
     }
 
+    /// The cached pane list for the selected session, empty when it is
+    /// stale for another selection or its tmux session is gone (which hides
+    /// the `Panes:` row).
+    pub(super) fn selected_panes(&self) -> &[crate::tmux::PaneInfo] {
+        match (&self.pane_info, &self.selected_session) {
+            (Some((cached, panes)), Some(id)) if cached == id => panes,
+            _ => &[],
+        }
+    }
+
     pub(super) fn refresh_preview_cache_if_needed(&mut self, width: u16, height: u16) {
         // The off-thread `LiveCaptureWorker` (retargeted to this pane by
         // `sync_preview_capture_worker` in `render_preview`) keeps fresh
@@ -1840,6 +1883,7 @@ impl HomeView {
                             ),
                             Status::Unknown => (ICON_UNKNOWN, theme.waiting),
                             Status::Stopped => (ICON_STOPPED, theme.dimmed),
+                            Status::Error if inst.is_tmux_gone() => (ICON_TMUX_GONE, theme.waiting),
                             Status::Error => (ICON_ERROR, theme.error),
                             Status::Starting => (spinner_starting(&inst.created_at), theme.dimmed),
                             Status::Deleting => (ICON_DELETING, theme.waiting),
@@ -1964,9 +2008,7 @@ impl HomeView {
                 .selected_session
                 .as_ref()
                 .and_then(|id| self.get_instance(id))
-                .is_some_and(|inst| {
-                    inst.last_error.as_deref() == Some(crate::session::TMUX_SESSION_GONE_ERROR)
-                });
+                .is_some_and(|inst| inst.is_tmux_gone());
 
         // Keep the off-thread capture worker pointed at whatever pane this
         // view shows (and tuned to live-send vs. idle cadence) before any
@@ -2020,7 +2062,7 @@ impl HomeView {
                                 inner,
                                 compact,
                                 self.show_preview_info,
-                                preview::agent_info_height(inst),
+                                preview::agent_info_height(inst, self.selected_panes()),
                             )
                             .output
                         })
@@ -2052,10 +2094,12 @@ impl HomeView {
                                 frame,
                                 inner,
                                 inst,
+                                self.selected_panes(),
                                 CachedPreview::from_text(self.preview_cache.parsed_text.as_ref()),
                                 self.preview_scroll_offset,
                                 theme,
                                 self.idle_decay_window,
+                                &self.display_config,
                                 compact,
                                 self.show_preview_info,
                             );
@@ -2386,7 +2430,14 @@ impl HomeView {
                     Style::default().fg(theme.accent),
                 ),
             ]),
-            Line::from(""),
+            // Worktree setup and container start report no fraction, so the
+            // bar stays indeterminate until the session is ready.
+            progress_bar_line(
+                area.width.saturating_sub(2).min(40),
+                None,
+                animation_tick(),
+                theme,
+            ),
         ];
         frame.render_widget(Paragraph::new(info_lines), chunks[0]);
 
@@ -2508,7 +2559,12 @@ impl HomeView {
             Line::from(vec![
                 Span::styled("Press ", Style::default().fg(theme.dimmed)),
                 Span::styled("Enter", Style::default().fg(theme.hint).bold()),
-                Span::styled(" to start it.", Style::default().fg(theme.dimmed)),
+                Span::styled(" to start it, or ", Style::default().fg(theme.dimmed)),
+                Span::styled(
+                    bindings::label(ActionId::Restart, self.strict_hotkeys),
+                    Style::default().fg(theme.hint).bold(),
+                ),
+                Span::styled(" to restart it.", Style::default().fg(theme.dimmed)),
             ]),
         ];
         let para = Paragraph::new(lines).alignment(Alignment::Center);
@@ -2704,6 +2760,30 @@ impl HomeView {
             ));
         }
 
+        // Session-count hint: past `session.max_sessions_warn`, nudge toward
+        // archiving or deleting finished sessions. Never blocks anything.
+        if let Some(active) = self.session_count_warning() {
+            groups.push((
+                0,
+                vec![Span::styled(
+                    format!(" \u{26A0} {active} sessions: archive or clean up "),
+                    Style::default().fg(theme.waiting).bold(),
+                )],
+            ));
+        }
+
+        // Mute indicator: the runtime mute overrides `sound.enabled` without
+        // touching config, so keep it visible for as long as it is active.
+        if self.sound_muted {
+            groups.push((
+                0,
+                vec![Span::styled(
+                    " \u{1F507} Muted ",
+                    Style::default().fg(theme.waiting).bold(),
+                )],
+            ));
+        }
+
         // Pending-paste indicator: text was captured at the home view but
         // couldn't be routed yet (no runnable session selected). Surface a
         // high-priority hint so the user knows the paste/dictation didn't
@@ -2953,7 +3033,7 @@ mod tests {
 
     #[test]
     fn selected_row_style_preserves_readable_status_color() {
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let style = Style::default().fg(theme.running);
 
         assert_eq!(selected_row_style(style, &theme).fg, Some(theme.running));
@@ -2961,7 +3041,7 @@ mod tests {
 
     #[test]
     fn selected_row_style_sets_text_for_default_foreground() {
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let style = Style::default();
 
         assert_eq!(selected_row_style(style, &theme).fg, Some(theme.text));
@@ -2969,7 +3049,7 @@ mod tests {
 
     #[test]
     fn selected_row_style_falls_back_when_color_clashes() {
-        let mut theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let mut theme = crate::tui::styles::load_theme("empire");
         theme.dimmed = theme.session_selection;
         let style = Style::default().fg(theme.dimmed);
 
@@ -3113,47 +3193,6 @@ mod tests {
         assert_eq!(profile_short_code(""), "");
     }
 
-    #[test]
-    fn format_relative_age_none_returns_empty() {
-        assert_eq!(format_relative_age(None), "");
-    }
-
-    #[test]
-    fn format_relative_age_future_timestamp_returns_less_than_1m() {
-        let future = Utc::now() + chrono::Duration::hours(1);
-        assert_eq!(format_relative_age(Some(future)), "<1m");
-    }
-
-    #[test]
-    fn format_relative_age_recent_returns_less_than_1m() {
-        let recent = Utc::now() - chrono::Duration::seconds(30);
-        assert_eq!(format_relative_age(Some(recent)), "<1m");
-    }
-
-    #[test]
-    fn format_relative_age_minutes() {
-        let ts = Utc::now() - chrono::Duration::minutes(5);
-        assert_eq!(format_relative_age(Some(ts)), "5m");
-    }
-
-    #[test]
-    fn format_relative_age_hours() {
-        let ts = Utc::now() - chrono::Duration::hours(3);
-        assert_eq!(format_relative_age(Some(ts)), "3h");
-    }
-
-    #[test]
-    fn format_relative_age_days() {
-        let ts = Utc::now() - chrono::Duration::days(7);
-        assert_eq!(format_relative_age(Some(ts)), "7d");
-    }
-
-    #[test]
-    fn format_relative_age_months() {
-        let ts = Utc::now() - chrono::Duration::days(60);
-        assert_eq!(format_relative_age(Some(ts)), "2mo");
-    }
-
     #[test]
     fn capture_lines_for_adds_buffer_to_height() {
         assert_eq!(capture_lines_for(30, 0), 50);
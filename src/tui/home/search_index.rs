@@ -0,0 +1,140 @@
+//! Incremental index behind the home list's `/` search.
+//!
+//! Scoring every row on every keystroke used to re-format and re-decode each
+//! row's haystack (title, path, notes) from scratch. The index keeps the
+//! decoded haystacks for the current `flat_items` and remembers which rows
+//! the previous query matched. Fuzzy matching is a subsequence test, so when
+//! the new query only extends the previous one, every row it can match was
+//! already in the previous match set and only those rows are rescored.
+//! Scores and ordering are identical to a full scan.
+
+use nucleo_matcher::pattern::{Atom, AtomKind, CaseMatching, Normalization};
+use nucleo_matcher::{Config, Matcher, Utf32String};
+
+#[derive(Default)]
+pub(super) struct SearchIndex {
+    /// Decoded haystack per `flat_items` row; `None` for rows that cannot be
+    /// searched. Empty when the index needs rebuilding.
+    haystacks: Vec<Option<Utf32String>>,
+    built: bool,
+    /// The last scored query and the rows (ascending) it matched.
+    last_query: String,
+    last_matches: Vec<usize>,
+}
+
+impl SearchIndex {
+    /// Drop the cached haystacks. Called whenever `flat_items` or a row's
+    /// searchable text may have changed; the next query rebuilds.
+    pub(super) fn invalidate(&mut self) {
+        self.haystacks.clear();
+        self.built = false;
+        self.last_query.clear();
+        self.last_matches.clear();
+    }
+
+    pub(super) fn is_built(&self) -> bool {
+        self.built
+    }
+
+    /// Install fresh haystacks, one per `flat_items` row.
+    pub(super) fn build(&mut self, haystacks: impl IntoIterator<Item = Option<String>>) {
+        self.haystacks = haystacks
+            .into_iter()
+            .map(|h| h.map(Utf32String::from))
+            .collect();
+        self.built = true;
+        self.last_query.clear();
+        self.last_matches.clear();
+    }
+
+    /// Rows matching `query`, best score first (ties keep list order).
+    /// Returns the matches and how many rows were scored to find them.
+    pub(super) fn search(&mut self, query: &str) -> (Vec<usize>, usize) {
+        let narrowing = !self.last_query.is_empty() && query.starts_with(&self.last_query);
+        let candidates: Vec<usize> = if narrowing {
+            std::mem::take(&mut self.last_matches)
+        } else {
+            (0..self.haystacks.len()).collect()
+        };
+
+        let mut matcher = Matcher::new(Config::DEFAULT.match_paths());
+        let atom = Atom::new(
+            query,
+            CaseMatching::Ignore,
+            Normalization::Smart,
+            AtomKind::Fuzzy,
+            false,
+        );
+
+        let mut scored: Vec<(usize, u16)> = Vec::new();
+        for &idx in &candidates {
+            let Some(Some(haystack)) = self.haystacks.get(idx) else {
+                continue;
+            };
+            if let Some(score) = atom.score(haystack.slice(..), &mut matcher) {
+                scored.push((idx, score));
+            }
+        }
+
+        self.last_query = query.to_string();
+        self.last_matches = scored.iter().map(|&(idx, _)| idx).collect();
+
+        scored.sort_by_key(|a| std::cmp::Reverse(a.1));
+        (
+            scored.into_iter().map(|(idx, _)| idx).collect(),
+            candidates.len(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(rows: &[&str]) -> SearchIndex {
+        let mut index = SearchIndex::default();
+        index.build(rows.iter().map(|r| Some(r.to_string())));
+        index
+    }
+
+    #[test]
+    fn narrowing_matches_a_full_scan() {
+        let areas = [
+            "api", "billing", "checkout", "docs", "infra", "mobile", "search", "web",
+        ];
+        let rows: Vec<String> = (0..300)
+            .map(|i| {
+                let area = areas[i % areas.len()];
+                format!("{area}-agent-{i} /home/me/src/{area}")
+            })
+            .collect();
+        let refs: Vec<&str> = rows.iter().map(String::as_str).collect();
+        let mut incremental = index(&refs);
+
+        let query = "checkout-agent";
+        let mut scored_per_key = Vec::new();
+        for end in 1..=query.len() {
+            let prefix = &query[..end];
+            let (matches, scored) = incremental.search(prefix);
+            let (full, _) = index(&refs).search(prefix);
+            assert_eq!(matches, full, "narrowed results diverged at {prefix:?}");
+            scored_per_key.push(scored);
+        }
+
+        // The first keystroke scans every row; each later one only rescans
+        // the previous matches, so work shrinks as the query grows.
+        assert_eq!(scored_per_key[0], rows.len());
+        assert!(scored_per_key.windows(2).all(|w| w[1] <= w[0]));
+        assert!(*scored_per_key.last().unwrap() < rows.len() / 4);
+    }
+
+    #[test]
+    fn editing_the_query_rescans_from_scratch() {
+        let mut index = index(&["alpha", "beta", "alphabet"]);
+        assert_eq!(index.search("alp").0.len(), 2);
+        // Backspace: not an extension, so rows dropped earlier come back.
+        let (matches, scored) = index.search("b");
+        assert_eq!(scored, 3);
+        assert_eq!(matches.len(), 2);
+    }
+}
@@ -5,8 +5,8 @@ use serial_test::serial;
 use tempfile::TempDir;
 use tui_input::Input;
 
-use super::{ConfigRefreshOrigin, ConfigWatchKey, HomeView, ViewMode};
-use crate::session::{GroupTree, Instance, Item, Storage};
+use super::{ConfigRefreshOrigin, ConfigWatchKey, HomeView, TerminalMode, ViewMode};
+use crate::session::{DefaultTerminalMode, GroupTree, Instance, Item, Storage};
 use crate::tmux::AvailableTools;
 use crate::tui::app::Action;
 use crate::tui::dialogs::{InfoDialog, NewSessionDialog};
@@ -39,7 +39,7 @@ fn create_test_env_empty() -> TestEnv {
     )
     .unwrap();
     view.group_by = GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     TestEnv { _temp: temp, view }
 }
@@ -215,6 +215,40 @@ fn interactive_refresh_does_not_stash_pending_watcher_theme() {
     );
 }
 
+#[test]
+#[serial]
+fn profile_theme_name_applies_only_while_filtered_to_that_profile() {
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+    let _storage = Storage::new_unwatched("test").unwrap();
+    let _other = Storage::new_unwatched("other").unwrap();
+    let global_config = crate::session::get_app_dir().unwrap().join("config.toml");
+    std::fs::write(&global_config, "[theme]\nname = \"zinc\"\n").unwrap();
+    let profile = crate::session::ProfileConfig {
+        theme_name: Some("dracula".to_string()),
+        ..Default::default()
+    };
+    crate::session::save_profile_config("test", &profile).unwrap();
+
+    let mut view = HomeView::new(
+        Some("test".to_string()),
+        AvailableTools::with_tools(&["claude"]),
+        crate::file_watch::FileWatchService::noop(),
+    )
+    .unwrap();
+    assert_eq!(view.active_theme_name(), "dracula");
+
+    view.switch_profile(Some("other".to_string())).unwrap();
+    assert_eq!(view.active_theme_name(), "zinc");
+
+    view.switch_profile(None).unwrap();
+    assert_eq!(
+        view.active_theme_name(),
+        "zinc",
+        "all-profiles mode keeps the global theme"
+    );
+}
+
 #[test]
 #[serial]
 fn take_pending_watcher_theme_clears_the_field() {
@@ -330,7 +364,7 @@ fn create_test_env_with_sessions(count: usize) -> TestEnv {
     )
     .unwrap();
     view.group_by = GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     TestEnv { _temp: temp, view }
 }
@@ -369,7 +403,7 @@ fn create_test_env_with_groups() -> TestEnv {
     )
     .unwrap();
     view.group_by = GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     TestEnv { _temp: temp, view }
 }
@@ -414,7 +448,7 @@ fn create_test_env_with_mixed_sessions() -> TestEnv {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     TestEnv { _temp: temp, view }
 }
@@ -504,6 +538,105 @@ fn preview_info_follows_flag_and_never_auto_shows_in_live() {
     );
 }
 
+#[test]
+#[serial]
+fn home_layout_cycles_panes_and_persists_per_profile() {
+    use crate::session::config::{load_config, HomeLayout};
+    use crate::tui::styles::load_theme;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let mut env = create_test_env_with_sessions(1);
+    let id = env.view.instances()[0].id.clone();
+    env.view.select_session_by_id(&id);
+    let theme = load_theme("empire");
+    let draw = |view: &mut HomeView| {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                view.render(f, area, &theme, None, None, None);
+            })
+            .unwrap();
+    };
+
+    assert_eq!(env.view.home_layout, HomeLayout::Split);
+    draw(&mut env.view);
+    assert!(env.view.list_area.width > 0 && env.view.preview_area.width > 0);
+
+    env.view.handle_key(key(KeyCode::Char('v')), None);
+    assert_eq!(env.view.home_layout, HomeLayout::ListOnly);
+    draw(&mut env.view);
+    assert_eq!(env.view.list_area.width, 120, "list takes the full width");
+    assert_eq!(env.view.preview_area, ratatui::layout::Rect::default());
+
+    env.view.handle_key(key(KeyCode::Char('v')), None);
+    assert_eq!(env.view.home_layout, HomeLayout::DetailOnly);
+    draw(&mut env.view);
+    assert_eq!(env.view.list_area, ratatui::layout::Rect::default());
+    assert!(
+        env.view.preview_area.width > 100,
+        "preview takes the full width"
+    );
+
+    let saved = load_config().unwrap().unwrap().app_state.home_layout;
+    assert_eq!(saved.get("test"), Some(&HomeLayout::DetailOnly));
+    assert_eq!(saved.len(), 1, "only the active profile is recorded");
+
+    env.view.handle_key(key(KeyCode::Char('v')), None);
+    assert_eq!(env.view.home_layout, HomeLayout::Split);
+}
+
+#[test]
+#[serial]
+fn list_render_work_scales_with_viewport_not_session_count() {
+    use crate::tui::styles::load_theme;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let theme = load_theme("empire");
+    let rows_for = |sessions: usize, height: u16| {
+        let mut env = create_test_env_with_sessions(sessions);
+        // Park the cursor mid-list so both indicators and a scrolled window
+        // are exercised, not just the first page.
+        env.view.cursor = env.view.flat_items.len() / 2;
+        let mut terminal = Terminal::new(TestBackend::new(120, height)).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                env.view.render(f, area, &theme, None, None, None);
+            })
+            .unwrap();
+        // The counter must match the session rows that actually reached
+        // the screen, so it measures the render loop rather than echoing
+        // the viewport size.
+        let list = env.view.list_inner_area;
+        let buffer = terminal.backend().buffer();
+        let drawn = (list.top()..list.bottom())
+            .filter(|&y| {
+                let row: String = (list.left()..list.right())
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect();
+                row.contains("session")
+            })
+            .count();
+        assert_eq!(env.view.list_rows_rendered, drawn);
+        env.view.list_rows_rendered
+    };
+
+    let small_list = rows_for(100, 24);
+    let large_list = rows_for(1000, 24);
+    assert!(small_list > 0);
+    assert_eq!(
+        small_list, large_list,
+        "10x the sessions must not change how many rows a frame builds"
+    );
+    assert!(
+        rows_for(1000, 48) > large_list,
+        "a taller viewport should build more rows"
+    );
+}
+
 #[test]
 #[serial]
 fn preview_visible_rows_equal_output_area_with_info_shown() {
@@ -797,6 +930,59 @@ fn test_cursor_bounds_at_bottom() {
     assert_eq!(env.view.cursor, 4);
 }
 
+#[test]
+#[serial]
+fn navigation_keeps_flat_items_cached_but_structural_changes_rebuild() {
+    use crate::session::config::SortOrder;
+
+    let mut env = create_test_env_with_groups();
+    for code in [
+        KeyCode::Down,
+        KeyCode::Char('j'),
+        KeyCode::PageDown,
+        KeyCode::End,
+        KeyCode::Home,
+        KeyCode::Up,
+    ] {
+        env.view.handle_key(key(code), None);
+        assert!(
+            !env.view.refresh_flat_items(),
+            "{code:?} must not invalidate the flattened list"
+        );
+    }
+
+    // A heartbeat reload of an unchanged profile keeps the cached list.
+    env.view.reload().unwrap();
+    assert!(!env.view.refresh_flat_items());
+
+    let id = env.view.instances()[0].id.clone();
+    env.view
+        .mutate_instance(&id, |inst| inst.group_path = "elsewhere".to_string());
+    assert!(
+        env.view.refresh_flat_items(),
+        "regrouping a session rebuilds"
+    );
+
+    env.view.sort_order = SortOrder::ZA;
+    assert!(env.view.refresh_flat_items(), "changing the sort rebuilds");
+
+    env.view
+        .mutate_instance(&id, |inst| inst.status = crate::session::Status::Waiting);
+    env.view.sort_order = SortOrder::Attention;
+    env.view.refresh_flat_items();
+    env.view
+        .mutate_instance(&id, |inst| inst.status = crate::session::Status::Idle);
+    assert!(
+        env.view.refresh_flat_items(),
+        "a status change reorders the Attention sort"
+    );
+
+    if let Some(tree) = env.view.group_trees.get_mut("test") {
+        tree.toggle_collapsed("work");
+    }
+    assert!(env.view.refresh_flat_items(), "collapsing a group rebuilds");
+}
+
 #[test]
 #[serial]
 fn test_page_down() {
@@ -937,7 +1123,7 @@ fn test_enter_on_acp_session_opens_structured_view() {
     )
     .unwrap();
     view.group_by = GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.cursor = 1;
     view.update_selected();
 
@@ -1024,6 +1210,48 @@ fn test_d_on_session_opens_delete_dialog() {
     assert!(env.view.unified_delete_dialog.is_some());
 }
 
+#[test]
+#[serial]
+fn test_quick_delete_skips_dialog_only_for_clean_stopped_sessions() {
+    use crate::session::Status;
+
+    let mut env = create_test_env_with_sessions(3);
+    env.view.quick_delete_clean_sessions = true;
+    env.view.update_selected();
+    let id = env.view.selected_session.clone().unwrap();
+
+    // Not stopped: still asks
+    env.view.handle_key(key(KeyCode::Char('d')), None);
+    assert!(env.view.unified_delete_dialog.take().is_some());
+
+    // Stopped but sandboxed: still asks
+    env.view.mutate_instance(&id, |inst| {
+        inst.status = Status::Stopped;
+        inst.sandbox_info = Some(crate::session::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: "test-container".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        });
+    });
+    env.view.handle_key(key(KeyCode::Char('d')), None);
+    assert!(env.view.unified_delete_dialog.take().is_some());
+
+    // Stopped with nothing to clean up: deleted straight away
+    env.view
+        .mutate_instance(&id, |inst| inst.sandbox_info = None);
+    env.view.handle_key(key(KeyCode::Char('d')), None);
+    assert!(env.view.unified_delete_dialog.is_none());
+    assert_eq!(
+        env.view.get_instance(&id).map(|i| i.status),
+        Some(Status::Deleting)
+    );
+}
+
 #[test]
 #[serial]
 fn test_d_on_group_with_sessions_opens_group_delete_options_dialog() {
@@ -1094,6 +1322,60 @@ fn test_search_matches_path() {
     assert!(!env.view.search_matches.is_empty());
 }
 
+/// Notes set through the `a` dialog persist to storage and are matched by
+/// `/` search alongside title and path.
+#[test]
+#[serial]
+fn test_edit_notes_persists_and_is_searchable() {
+    let mut env = create_test_env_with_sessions(3);
+    let id = env.view.selected_session.clone().unwrap();
+
+    env.view.handle_key(key(KeyCode::Char('a')), None);
+    assert!(env.view.notes_dialog.is_some());
+    for ch in "flaky xyzzy".chars() {
+        env.view.handle_key(key(KeyCode::Char(ch)), None);
+    }
+    env.view.handle_key(key(KeyCode::Enter), None);
+    assert!(env.view.notes_dialog.is_none());
+    assert_eq!(
+        env.view.get_instance(&id).unwrap().notes.as_deref(),
+        Some("flaky xyzzy")
+    );
+    let stored = Storage::new_unwatched("test").unwrap().load().unwrap();
+    let stored = stored.iter().find(|i| i.id == id).unwrap();
+    assert_eq!(stored.notes.as_deref(), Some("flaky xyzzy"));
+
+    env.view.search_query = Input::new("xyzzy".to_string());
+    env.view.update_search();
+    assert_eq!(env.view.search_matches.len(), 1);
+    if let Item::Session { id: matched, .. } = &env.view.flat_items[env.view.search_matches[0]] {
+        assert_eq!(matched, &id);
+    } else {
+        panic!("expected the annotated session to match");
+    }
+}
+
+#[test]
+#[serial]
+fn test_toggle_terminal_mode_persists_per_session_override() {
+    let mut env = create_test_env_with_sessions(2);
+    let id = env.view.instances[0].id.clone();
+    let other = env.view.instances[1].id.clone();
+    assert_eq!(env.view.get_terminal_mode(&id), TerminalMode::Host);
+
+    env.view.toggle_terminal_mode(&id).unwrap();
+    assert_eq!(env.view.get_terminal_mode(&id), TerminalMode::Container);
+    // The override is per session; the other row keeps the config default.
+    assert_eq!(env.view.get_terminal_mode(&other), TerminalMode::Host);
+
+    let stored = Storage::new_unwatched("test").unwrap().load().unwrap();
+    let stored = stored.iter().find(|i| i.id == id).unwrap();
+    assert_eq!(stored.terminal_mode, Some(DefaultTerminalMode::Container));
+
+    env.view.toggle_terminal_mode(&id).unwrap();
+    assert_eq!(env.view.get_terminal_mode(&id), TerminalMode::Host);
+}
+
 #[test]
 #[serial]
 fn test_search_matches_group_name() {
@@ -1461,7 +1743,7 @@ fn test_uppercase_p_picker_switch_profile() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // Open picker
@@ -1474,8 +1756,9 @@ fn test_uppercase_p_picker_switch_profile() {
     view.handle_key(key(KeyCode::Down), None);
     view.handle_key(key(KeyCode::Down), None);
     let action = view.handle_key(key(KeyCode::Enter), None);
-    // Profile switch is handled internally, no Action returned
-    assert_eq!(action, None);
+    // Profile switch is handled internally; the only Action is the theme
+    // repaint for the new filter (which may carry its own `theme_name`).
+    assert!(matches!(action, Some(Action::SetTheme(_))));
     assert_eq!(view.active_profile, Some("second".to_string()));
     assert!(view.profile_picker_dialog.is_none());
 }
@@ -1671,6 +1954,8 @@ fn create_test_env_with_group_sessions() -> TestEnv {
         container_name: "test-container".to_string(),
         extra_env: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
     });
     instances.push(inst3);
 
@@ -1697,7 +1982,7 @@ fn create_test_env_with_group_sessions() -> TestEnv {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     TestEnv { _temp: temp, view }
 }
@@ -1744,7 +2029,7 @@ fn test_group_has_managed_worktrees() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     assert!(view.group_has_managed_worktrees("work", "work/"));
@@ -1769,6 +2054,8 @@ fn test_group_has_containers() {
         container_name: "test-container".to_string(),
         extra_env: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
     });
 
     let mut inst2 = Instance::new("other-session", "/tmp/other");
@@ -1793,7 +2080,7 @@ fn test_group_has_containers() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     assert!(view.group_has_containers("work", "work/"));
@@ -1909,7 +2196,7 @@ fn test_archive_selected_group_project_mode() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Project;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
 
     // Select the "alpha" project header.
     for (i, item) in view.flat_items.iter().enumerate() {
@@ -2077,7 +2364,7 @@ fn test_delete_group_with_sessions_respects_worktree_option() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // Select the work group
@@ -2119,6 +2406,8 @@ fn test_delete_group_with_sessions_respects_container_option() {
         container_name: "test-container".to_string(),
         extra_env: None,
         custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
     });
 
     {
@@ -2140,7 +2429,7 @@ fn test_delete_group_with_sessions_respects_container_option() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // Select the work group
@@ -2577,7 +2866,7 @@ fn test_non_strict_h_snoozes_only_in_attention_sort() {
     // scenario and assert that `h` opens the snooze duration dialog
     // (the actual snooze fires when the user picks a duration).
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let session_idx = env
         .view
         .flat_items
@@ -2599,7 +2888,7 @@ fn test_non_strict_h_snoozes_only_in_attention_sort() {
     // Now flip back to a non-Attention sort and confirm `h` falls
     // through to the collapse handler instead of snoozing.
     env.view.sort_order = SortOrder::Newest;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let group_idx = env
         .view
         .flat_items
@@ -2652,7 +2941,7 @@ fn attention_env_running_then_waiting() -> (TestEnv, usize, usize) {
     view.strict_hotkeys = false;
     view.group_by = GroupByMode::Manual;
     view.sort_order = SortOrder::Attention;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     let env = TestEnv { _temp: temp, view };
 
@@ -2700,6 +2989,82 @@ fn test_non_strict_w_jumps_to_next_waiting_in_attention_sort() {
     );
 }
 
+#[test]
+#[serial]
+fn test_w_cycles_errors_and_reveals_sessions_in_collapsed_groups() {
+    use crate::session::config::{GroupByMode, SortOrder};
+    use crate::session::Status;
+
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+    let storage = Storage::new_unwatched("test").unwrap();
+    let mut running = Instance::new("running", "/tmp/running");
+    running.status = Status::Running;
+    let mut crashed = Instance::new("crashed", "/tmp/crashed");
+    crashed.status = Status::Error;
+    crashed.last_error = Some("agent exited with status 1".to_string());
+    let mut waiting = Instance::new("waiting", "/tmp/waiting");
+    waiting.status = Status::Waiting;
+    waiting.group_path = "work".to_string();
+    let running_id = running.id.clone();
+    let waiting_id = waiting.id.clone();
+    let crashed_id = crashed.id.clone();
+    let instances = vec![running, crashed, waiting];
+    storage
+        .update(|i, g| {
+            *i = instances.to_vec();
+            *g = GroupTree::new_with_groups(&instances, &[]).get_all_groups();
+            Ok(())
+        })
+        .unwrap();
+
+    let mut view = HomeView::new(
+        Some("test".to_string()),
+        AvailableTools::with_tools(&["claude"]),
+        crate::file_watch::FileWatchService::noop(),
+    )
+    .unwrap();
+    view.strict_hotkeys = false;
+    view.group_by = GroupByMode::Manual;
+    view.sort_order = SortOrder::Newest;
+    if let Some(tree) = view.group_trees.get_mut("test") {
+        tree.set_collapsed("work", true);
+    }
+    view.rebuild_flat_items();
+    let mut env = TestEnv { _temp: temp, view };
+    let visible = |env: &TestEnv, want: &str| {
+        env.view
+            .flat_items
+            .iter()
+            .any(|item| matches!(item, Item::Session { id, .. } if id == want))
+    };
+    assert!(!visible(&env, &waiting_id), "waiting row starts hidden");
+
+    env.view.select_session_by_id(&running_id);
+    env.view.handle_key(key(KeyCode::Char('w')), None);
+    assert_eq!(
+        env.view.selected_session.as_deref(),
+        Some(crashed_id.as_str())
+    );
+
+    env.view.handle_key(key(KeyCode::Char('w')), None);
+    assert_eq!(
+        env.view.selected_session.as_deref(),
+        Some(waiting_id.as_str())
+    );
+    assert!(
+        visible(&env, &waiting_id),
+        "jumping expands the collapsed group"
+    );
+
+    // Both attention rows are now visible, so `w` wraps between them.
+    env.view.handle_key(key(KeyCode::Char('w')), None);
+    assert_eq!(
+        env.view.selected_session.as_deref(),
+        Some(crashed_id.as_str())
+    );
+}
+
 #[test]
 #[serial]
 fn test_strict_mode_ctrl_g_opens_group_picker() {
@@ -2949,6 +3314,21 @@ fn test_command_palette_diff_invokes_diff_in_strict_mode() {
     );
 }
 
+#[test]
+#[serial]
+fn test_colon_opens_command_palette_in_both_keymaps() {
+    for strict in [false, true] {
+        let mut env = create_test_env_with_sessions(1);
+        env.view.strict_hotkeys = strict;
+        env.view
+            .handle_key(KeyEvent::new(KeyCode::Char(':'), KeyModifiers::SHIFT), None);
+        assert!(
+            env.view.command_palette.is_some(),
+            "`:` opens the palette (strict={strict})"
+        );
+    }
+}
+
 #[test]
 #[serial]
 fn test_f5_and_e_both_open_restart_dialog() {
@@ -3173,7 +3553,7 @@ fn test_all_profiles_view_loads_from_multiple_profiles() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     assert_eq!(view.instances().len(), 2);
@@ -3224,7 +3604,7 @@ fn test_filtered_view_loads_single_profile() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     assert_eq!(view.instances().len(), 1);
@@ -3265,7 +3645,7 @@ fn test_all_profiles_view_has_no_profile_headers() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // All items should be sessions (no profile headers)
@@ -3311,7 +3691,7 @@ fn test_all_profiles_view_shows_all_sessions_flat() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // All sessions from all profiles should be visible at depth 0
@@ -3355,7 +3735,7 @@ fn test_default_row_tag_mode_renders_no_tag() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // Default `row_tag_mode` is `None`; no row should carry a bracketed tag.
@@ -3403,7 +3783,7 @@ fn test_row_tag_auto_renders_profile_in_all_profiles_view() {
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
     view.row_tag_mode = crate::session::config::RowTagMode::Auto;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     let mut seen = 0;
@@ -3455,7 +3835,7 @@ fn test_row_tag_auto_omits_tag_in_filtered_view() {
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
     view.row_tag_mode = crate::session::config::RowTagMode::Auto;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     let code = super::render::profile_short_code("alpha");
@@ -3503,7 +3883,7 @@ fn test_row_tag_profile_renders_in_filtered_view() {
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
     view.row_tag_mode = crate::session::config::RowTagMode::Profile;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     let code = super::render::profile_short_code("alpha");
@@ -3562,7 +3942,7 @@ fn test_row_tag_branch_dedups_with_divergence_display() {
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
     view.row_tag_mode = crate::session::config::RowTagMode::Branch;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // No bracketed `[...]` tag on this row: divergence display owns the
@@ -3616,7 +3996,7 @@ fn test_row_tag_branch_renders_when_title_matches_branch() {
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
     view.row_tag_mode = crate::session::config::RowTagMode::Branch;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // The tag uses the last `/`-segment of the branch, truncated to 8
@@ -3637,6 +4017,148 @@ fn test_row_tag_branch_renders_when_title_matches_branch() {
     }
 }
 
+/// Worktree rows show `↑N` once their branch has commits the base lacks,
+/// and an unchanged HEAD is not recounted.
+#[test]
+#[serial]
+fn test_ahead_badge_counts_branch_commits() {
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+
+    let repo_dir = temp.path().join("repo");
+    let repo = git2::Repository::init(&repo_dir).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let commit = |message: &str| {
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+            .unwrap();
+    };
+    commit("initial");
+    let base = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.branch("main", &base, true).unwrap();
+    repo.branch("feature", &base, false).unwrap();
+    repo.set_head("refs/heads/feature").unwrap();
+    commit("one");
+    commit("two");
+
+    let storage = Storage::new_unwatched("alpha").unwrap();
+    let mut inst = Instance::new("feature", repo_dir.to_str().unwrap());
+    inst.worktree_info = Some(crate::session::WorktreeInfo {
+        branch: "feature".to_string(),
+        main_repo_path: repo_dir.to_string_lossy().into_owned(),
+        managed_by_aoe: true,
+        created_at: chrono::Utc::now(),
+        base_branch: None,
+    });
+    storage
+        .update(|i, _| {
+            *i = vec![inst.clone()];
+            Ok(())
+        })
+        .unwrap();
+
+    let tools = AvailableTools::with_tools(&["claude"]);
+    let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
+    view.group_by = crate::session::config::GroupByMode::Manual;
+    view.rebuild_flat_items();
+    view.list_visible_range = 0..view.flat_items.len();
+
+    assert!(view.refresh_ahead_counts());
+    assert!(
+        !view.refresh_ahead_counts(),
+        "unchanged HEAD is not recounted"
+    );
+    let row = view
+        .flat_items
+        .iter()
+        .find(|item| matches!(item, Item::Session { .. }))
+        .unwrap();
+    assert!(rendered_row_text(&view, row).contains("↑2"));
+}
+
+/// Converting a plain-checkout session creates a managed worktree, points
+/// the session at it, and persists both fields.
+#[test]
+#[serial]
+fn test_convert_selected_session_to_worktree() {
+    let temp = TempDir::new().unwrap();
+    setup_test_home(&temp);
+
+    let repo_dir = temp.path().join("repo");
+    std::fs::create_dir_all(&repo_dir).unwrap();
+    let git = |args: &[&str]| {
+        let out = std::process::Command::new("git")
+            .args(args)
+            .current_dir(&repo_dir)
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-q", "-b", "main"]);
+    git(&[
+        "-c",
+        "user.name=t",
+        "-c",
+        "user.email=t@example.com",
+        "commit",
+        "-q",
+        "--allow-empty",
+        "-m",
+        "init",
+    ]);
+
+    let storage = Storage::new_unwatched("test").unwrap();
+    let mut inst = Instance::new("plain", repo_dir.to_str().unwrap());
+    inst.source_profile = "test".to_string();
+    let id = inst.id.clone();
+    storage
+        .update(|i, _| {
+            *i = vec![inst.clone()];
+            Ok(())
+        })
+        .unwrap();
+
+    let tools = AvailableTools::with_tools(&["claude"]);
+    let mut view = HomeView::new(
+        Some("test".to_string()),
+        tools,
+        crate::file_watch::FileWatchService::noop(),
+    )
+    .unwrap();
+    view.selected_session = Some(id.clone());
+
+    view.convert_to_worktree_for_selected("feat", false)
+        .unwrap();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+    while !view.apply_background_task_result() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "conversion did not finish"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    let dialog = view.info_dialog.take().expect("the result is reported");
+    assert_eq!(dialog.title(), "Converted to Worktree");
+
+    let (persisted, _) = storage.load_with_groups().unwrap();
+    let persisted = persisted.iter().find(|i| i.id == id).unwrap();
+    assert!(std::path::Path::new(&persisted.project_path).is_dir());
+    assert_ne!(persisted.project_path, repo_dir.to_string_lossy());
+    assert_eq!(
+        persisted.worktree_info.as_ref().map(|w| w.branch.as_str()),
+        Some("feat")
+    );
+
+    // A session that already uses a worktree is refused up front.
+    view.selected_session = Some(id);
+    view.open_convert_to_worktree_for_selected();
+    assert!(view.worktree_name_dialog.is_none());
+    assert!(view.info_dialog.is_some());
+}
+
 /// Legacy `Instance::new` left `source_profile` empty before the per-profile
 /// plumbing landed. The render branch must skip the tag entirely in that
 /// case rather than emit a literal `  []`.
@@ -3663,7 +4185,7 @@ fn test_row_tag_auto_skips_for_empty_source_profile() {
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
     view.row_tag_mode = crate::session::config::RowTagMode::Auto;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     for item in &view.flat_items {
@@ -3704,7 +4226,7 @@ fn test_create_session_in_all_mode_is_findable() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     let data = NewSessionData {
@@ -3717,14 +4239,20 @@ fn test_create_session_in_all_mode_is_findable() {
         worktree_branch: None,
         create_new_branch: false,
         base_branch: None,
+        worktree_on_exists: crate::git::OnExists::Error,
         extra_repo_paths: Vec::new(),
         sandbox: false,
         sandbox_image: String::new(),
         yolo_mode: false,
+        allow_unsandboxed_yolo: false,
         extra_env: Vec::new(),
+        cpu_limit: None,
+        memory_limit: None,
+        custom_instruction: None,
         extra_args: String::new(),
         command_override: String::new(),
         scratch: false,
+        auto_create_terminal: false,
     };
 
     let session_id = view.create_session(data).unwrap();
@@ -3779,7 +4307,7 @@ fn test_save_preserves_per_profile_collapsed_state() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // Verify per-profile collapsed state is preserved
@@ -3886,7 +4414,7 @@ fn test_delete_group_scoped_to_owning_profile() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     // Both profiles should have a "work" group
@@ -4062,7 +4590,7 @@ fn test_group_context_menu_new_session_prefills_path_in_project_mode() {
 
     let mut env = create_test_env_with_groups();
     env.view.group_by = GroupByMode::Project;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // In project mode the group label is the repo basename ("work" from
     // "/tmp/work"), not the stored group_path.
@@ -4100,7 +4628,7 @@ fn test_session_context_menu_snooze_opens_duration_dialog() {
     // keybinding); dispatching it on an active session opens the duration
     // picker, the same path the keyboard takes.
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let session_idx = env
         .view
         .flat_items
@@ -4212,7 +4740,7 @@ fn test_shift_n_prefills_main_repo_path_for_worktree_session() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
     view.cursor = 0;
     view.update_selected();
@@ -4304,6 +4832,44 @@ fn test_rename_selected_group_path() {
         .find(|i| i.title == "work-project")
         .unwrap();
     assert_eq!(work_session.group_path, "projects");
+
+    // The cursor follows the group to its new path
+    assert_eq!(env.view.selected_group.as_deref(), Some("projects"));
+    assert!(matches!(
+        env.view.flat_items.get(env.view.cursor),
+        Some(Item::Group { path, .. }) if path == "projects"
+    ));
+}
+
+#[test]
+#[serial]
+fn test_move_group_switches_to_custom_sort_and_persists() {
+    let mut env = create_test_env_with_groups();
+    let group_cursor = |view: &HomeView, want: &str| {
+        view.flat_items
+            .iter()
+            .position(|item| matches!(item, Item::Group { path, .. } if path == want))
+            .unwrap()
+    };
+
+    env.view.cursor = group_cursor(&env.view, "personal");
+    env.view.update_selected();
+    env.view.handle_key(key(KeyCode::Char('K')), None);
+
+    assert_eq!(
+        env.view.sort_order,
+        crate::session::config::SortOrder::Custom
+    );
+    assert_eq!(env.view.selected_group.as_deref(), Some("personal"));
+    assert!(group_cursor(&env.view, "personal") < group_cursor(&env.view, "work"));
+
+    let (_, groups) = Storage::new_unwatched("test")
+        .unwrap()
+        .load_with_groups()
+        .unwrap();
+    let position = |path: &str| groups.iter().find(|g| g.path == path).unwrap().sort_order;
+    assert_eq!(position("personal"), Some(0));
+    assert_eq!(position("work"), Some(1));
 }
 
 #[test]
@@ -4337,7 +4903,7 @@ fn test_rename_selected_group_with_children() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     view.group_rename_context = Some(super::GroupRenameContext {
@@ -4445,7 +5011,7 @@ fn test_rename_group_removes_old_path() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     view.group_rename_context = Some(super::GroupRenameContext {
@@ -4488,7 +5054,7 @@ fn test_rename_group_empty_group() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     view.group_rename_context = Some(super::GroupRenameContext {
@@ -4541,7 +5107,7 @@ fn test_rename_group_duplicate_returns_error() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     view.group_rename_context = Some(super::GroupRenameContext {
@@ -4590,7 +5156,7 @@ fn test_rename_group_resort_az() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     view.group_rename_context = Some(super::GroupRenameContext {
@@ -4668,7 +5234,7 @@ fn test_apply_creation_results_returns_session_id() {
     )
     .unwrap();
     view.group_by = crate::session::config::GroupByMode::Manual;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
     view.update_selected();
 
     let data = NewSessionData {
@@ -4681,14 +5247,20 @@ fn test_apply_creation_results_returns_session_id() {
         worktree_branch: None,
         create_new_branch: false,
         base_branch: None,
+        worktree_on_exists: crate::git::OnExists::Error,
         extra_repo_paths: Vec::new(),
         sandbox: false,
         sandbox_image: String::new(),
         yolo_mode: false,
+        allow_unsandboxed_yolo: false,
         extra_env: Vec::new(),
+        cpu_limit: None,
+        memory_limit: None,
+        custom_instruction: None,
         extra_args: String::new(),
         command_override: String::new(),
         scratch: false,
+        auto_create_terminal: false,
     };
 
     // Use the async CreationPoller path (pass None hooks, non-sandbox,
@@ -4715,6 +5287,43 @@ fn test_apply_creation_results_returns_session_id() {
     );
 }
 
+#[test]
+#[serial]
+fn test_branch_checked_out_offers_jump_to_owning_session() {
+    use crate::tui::creation_poller::CreationResult;
+
+    let mut env = create_test_env_with_sessions(3);
+    let owner = env.view.instances[1].clone();
+
+    env.view
+        .apply_creation_result(CreationResult::BranchCheckedOut {
+            branch: "feature".to_string(),
+            path: Some(std::path::PathBuf::from(&owner.project_path)),
+        });
+    let dialog = env.view.confirm_dialog.as_ref().expect("jump confirm");
+    assert_eq!(dialog.action(), "jump_to_session");
+    assert_eq!(
+        env.view.pending_jump_session.as_deref(),
+        Some(owner.id.as_str())
+    );
+
+    env.view.confirm_dialog = None;
+    env.view.dispatch_confirm_submit("jump_to_session");
+    assert_eq!(
+        env.view.selected_session.as_deref(),
+        Some(owner.id.as_str())
+    );
+
+    // No session owns the worktree: explain where the branch is instead.
+    env.view
+        .apply_creation_result(CreationResult::BranchCheckedOut {
+            branch: "feature".to_string(),
+            path: Some(std::path::PathBuf::from("/tmp/elsewhere")),
+        });
+    assert!(env.view.confirm_dialog.is_none());
+    assert!(env.view.info_dialog.is_some());
+}
+
 #[test]
 fn test_project_group_name_uses_last_path_segment() {
     use super::project_group_name;
@@ -4961,6 +5570,10 @@ fn archived_running_session_renders_stopped_icon_not_spinner() {
         icon, ICON_STOPPED,
         "archived row must render stopped icon, not animated spinner"
     );
+    assert!(
+        !env.view.has_animated_sessions(),
+        "a static archived row must not keep the spinner redraw timer running"
+    );
 
     // Same expectation for snooze: a row snoozed into the future must not
     // animate even if it's also Running underneath.
@@ -4975,6 +5588,7 @@ fn archived_running_session_renders_stopped_icon_not_spinner() {
         ICON_STOPPED,
         "snoozed row must render stopped icon, not animated spinner"
     );
+    assert!(!env.view.has_animated_sessions());
 
     // Sanity: a plain Running row (no archive, no snooze) must NOT collapse
     // to ICON_STOPPED; otherwise the test would pass trivially because the
@@ -4990,6 +5604,37 @@ fn archived_running_session_renders_stopped_icon_not_spinner() {
         ICON_STOPPED,
         "non-archived Running row should keep its spinner; helper would be a no-op otherwise"
     );
+    assert!(env.view.has_animated_sessions());
+}
+
+#[test]
+#[serial]
+fn tmux_gone_session_renders_distinct_recoverable_icon() {
+    use super::render::agent_row_icon;
+    use super::{ICON_ERROR, ICON_TMUX_GONE};
+    use crate::session::{Status, TMUX_SESSION_GONE_ERROR};
+
+    let mut env = create_test_env_with_sessions(1);
+    let id = match env.view.flat_items.first() {
+        Some(Item::Session { id, .. }) => id.clone(),
+        _ => panic!("expected one session"),
+    };
+
+    env.view.mutate_instance(&id, |inst| {
+        inst.status = Status::Error;
+        inst.last_error = Some(TMUX_SESSION_GONE_ERROR.to_string());
+    });
+    let inst = env.view.get_instance(&id).expect("session present");
+    assert!(inst.is_tmux_gone());
+    assert_eq!(agent_row_icon(inst), ICON_TMUX_GONE);
+
+    // A real crash keeps its specific message and the error cross.
+    env.view.mutate_instance(&id, |inst| {
+        inst.last_error = Some("agent exited with status 1".to_string());
+    });
+    let inst = env.view.get_instance(&id).expect("session present");
+    assert!(!inst.is_tmux_gone());
+    assert_eq!(agent_row_icon(inst), ICON_ERROR);
 }
 
 #[test]
@@ -5029,7 +5674,7 @@ fn apply_status_update_skips_terminal_states() {
 #[serial]
 fn apply_stop_results_transitions_instance_to_stopped() {
     use crate::session::Status;
-    use crate::tui::stop_poller::StopRequest;
+    use crate::tui::stop_poller::{StopMode, StopRequest};
 
     let mut env = create_test_env_with_sessions(1);
     let id = match env.view.flat_items.first() {
@@ -5046,6 +5691,7 @@ fn apply_stop_results_transitions_instance_to_stopped() {
     env.view.stop_poller.request_stop(StopRequest {
         session_id: id.clone(),
         instance: inst,
+        mode: StopMode::Session,
     });
 
     // Poll the result-application path the main loop runs each frame.
@@ -5064,6 +5710,47 @@ fn apply_stop_results_transitions_instance_to_stopped() {
     assert_eq!(inst.last_error, None);
 }
 
+#[test]
+#[serial]
+fn apply_stop_result_persists_rebuilt_container_id() {
+    use crate::session::SandboxInfo;
+    use crate::tui::stop_poller::StopResult;
+
+    let mut env = create_test_env_with_sessions(1);
+    let id = match env.view.flat_items.first() {
+        Some(Item::Session { id, .. }) => id.clone(),
+        _ => panic!("expected the fixture to seed a single Session item"),
+    };
+    let sandbox = SandboxInfo {
+        enabled: true,
+        container_id: Some("rebuilt".to_string()),
+        image: "ubuntu:latest".to_string(),
+        container_name: "aoe-sandbox-rebuilt".to_string(),
+        extra_env: None,
+        custom_instruction: None,
+        cpu_limit: None,
+        memory_limit: None,
+    };
+
+    env.view.apply_stop_result(StopResult {
+        session_id: id.clone(),
+        success: true,
+        error: None,
+        sandbox_info: Some(sandbox),
+    });
+
+    let container_id = |inst: &Instance| {
+        inst.sandbox_info
+            .as_ref()
+            .and_then(|s| s.container_id.clone())
+    };
+    let inst = env.view.get_instance(&id).unwrap();
+    assert_eq!(container_id(inst).as_deref(), Some("rebuilt"));
+    let stored = Storage::new_unwatched("test").unwrap().load().unwrap();
+    let stored = stored.iter().find(|i| i.id == id).unwrap();
+    assert_eq!(container_id(stored).as_deref(), Some("rebuilt"));
+}
+
 #[test]
 #[serial]
 fn apply_status_update_runs_status_hook_on_transition() {
@@ -5556,6 +6243,63 @@ fn footer_hides_attention_workflow_hints_outside_attention_sort() {
     );
 }
 
+#[test]
+#[serial]
+fn delete_on_locked_session_asks_for_unlock_instead_of_opening_dialog() {
+    let mut env = create_test_env_with_sessions(1);
+    let id = env.view.instances[0].id.clone();
+    env.view.selected_session = Some(id.clone());
+    env.view
+        .mutate_instance(&id, |inst| inst.worktree_locked = true);
+
+    env.view.open_delete_for_selected();
+    assert!(env.view.unified_delete_dialog.is_none());
+    assert!(env.view.info_dialog.is_some());
+}
+
+#[test]
+#[serial]
+fn toggle_yolo_at_cursor_persists_and_offers_relaunch() {
+    let mut env = create_test_env_with_sessions(1);
+    let id = env.view.instances[0].id.clone();
+    env.view.selected_session = Some(id.clone());
+    assert!(!env.view.instances[0].yolo_mode);
+
+    // Host sessions cannot be switched into YOLO mode.
+    env.view.toggle_yolo_at_cursor();
+    assert!(!env.view.instances[0].yolo_mode);
+    assert!(env.view.info_dialog.take().is_some());
+
+    env.view.mutate_instance(&id, |inst| {
+        inst.sandbox_info = Some(crate::session::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: "aoe-test".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        })
+    });
+    env.view.toggle_yolo_at_cursor();
+    assert!(env.view.instances[0].yolo_mode);
+    let stored = Storage::new_unwatched("test").unwrap().load().unwrap();
+    assert!(stored.iter().find(|i| i.id == id).unwrap().yolo_mode);
+    // claude takes YOLO as a CLI flag, so a restart suffices; no rebuild.
+    let dialog = env.view.confirm_dialog.take().expect("relaunch prompt");
+    assert_eq!(dialog.action(), "restart_session");
+    assert!(env.view.pending_rebuild_container.is_none());
+
+    env.view
+        .mutate_instance(&id, |inst| inst.status = crate::session::Status::Stopped);
+    env.view.toggle_yolo_at_cursor();
+    assert!(!env.view.instances[0].yolo_mode);
+    // A stopped session picks the change up on its next start.
+    assert!(env.view.confirm_dialog.is_none());
+    assert!(env.view.info_dialog.is_some());
+}
+
 /// `toggle_favorite_at_cursor` flips the cursor's instance favorited state
 /// and persists the change. No toast: the row's visual treatment (bold +
 /// leading `* ` glyph) is the feedback.
@@ -5756,7 +6500,7 @@ fn archive_successor_skips_archived_rows() {
 fn archive_last_active_session_attention_sort_clears_selection() {
     let mut env = create_test_env_with_sessions(1);
     env.view.sort_order = crate::session::config::SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     env.view.archived_section_collapsed = true;
     env.view.cursor = 0;
     env.view.update_selected();
@@ -5996,7 +6740,7 @@ fn project_grouping_survives_attention_sort() {
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let group_count = env
         .view
@@ -6039,7 +6783,7 @@ fn project_grouping_sorts_sessions_by_attention_within_group() {
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let mut current_group: Option<String> = None;
     let mut alpha_session_order: Vec<String> = Vec::new();
@@ -6075,7 +6819,7 @@ fn project_groups_sort_by_top_attention_member() {
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let group_order: Vec<String> = env
         .view
@@ -6115,7 +6859,7 @@ fn pinned_project_without_sessions_shows_empty_header() {
 
     env.view.group_by = GroupByMode::Project;
     env.view.refresh_registered_projects();
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let group_names: Vec<String> = env
         .view
@@ -6143,7 +6887,7 @@ fn p_key_pins_project_on_header() {
 
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let alpha_idx = env
         .view
@@ -6178,7 +6922,7 @@ fn p_key_opens_projects_dialog_off_project_header() {
 
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Manual;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     env.view.cursor = 0;
     env.view.update_selected();
 
@@ -6198,7 +6942,7 @@ fn unpin_archived_only_project_leaves_main_flow() {
 
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // Pin beta.
     let beta_idx = env
@@ -6225,7 +6969,7 @@ fn unpin_archived_only_project_leaves_main_flow() {
             .apply_user_action(id, |inst| inst.archive())
             .unwrap();
     }
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // Now unpin via the cursor on the empty main-flow beta header.
     let beta_idx = env
@@ -6320,7 +7064,7 @@ fn stale_registry_entry_with_mismatched_archived_path_stays_pinned_and_unpinnabl
 
     view.group_by = GroupByMode::Project;
     view.refresh_registered_projects();
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
 
     // 1. The phantom: an empty otari header renders in the main flow.
     let otari_idx = view.flat_items.iter().position(|i| {
@@ -6378,7 +7122,7 @@ fn pinned_project_survives_losing_last_session() {
 
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let alpha_idx = env
         .view
@@ -6396,7 +7140,7 @@ fn pinned_project_survives_losing_last_session() {
     env.view
         .instances
         .retain(|i| super::project_group_name(i) != "alpha");
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let alpha_header = env.view.flat_items.iter().find_map(|i| match i {
         Item::Group {
@@ -6438,7 +7182,7 @@ fn same_basename_repos_pin_independently() {
 
     env.view.group_by = GroupByMode::Project;
     env.view.refresh_registered_projects();
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let api_idx = env
         .view
@@ -6489,7 +7233,7 @@ fn empty_pinned_project_new_session_uses_registered_path() {
     .unwrap();
     env.view.group_by = GroupByMode::Project;
     env.view.refresh_registered_projects();
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     assert_eq!(
         env.view.group_repo_path("lonely"),
@@ -6537,7 +7281,7 @@ fn all_profiles_view_includes_profile_scoped_pins() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = GroupByMode::Project;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
 
     let names: Vec<String> = view
         .flat_items
@@ -6596,7 +7340,7 @@ fn unpin_profile_scoped_pin_from_all_profiles_clears_header() {
     let tools = AvailableTools::with_tools(&["claude"]);
     let mut view = HomeView::new(None, tools, crate::file_watch::FileWatchService::noop()).unwrap();
     view.group_by = GroupByMode::Project;
-    view.flat_items = view.build_flat_items();
+    view.rebuild_flat_items();
 
     let idx = view
         .flat_items
@@ -6660,7 +7404,7 @@ fn unpin_clears_both_global_and_profile_entries_for_a_path() {
 
     env.view.group_by = GroupByMode::Project;
     env.view.refresh_registered_projects();
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let idx = env
         .view
@@ -6715,7 +7459,7 @@ fn group_by_toggle_preserves_selected_session() {
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Manual;
     env.view.sort_order = crate::session::config::SortOrder::Newest;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // Pick the last session in the Manual flat list; that's the row whose
     // index is most likely to be invalidated when project headers get
@@ -6770,7 +7514,7 @@ fn sort_order_toggle_preserves_selected_session() {
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
     env.view.sort_order = SortOrder::Newest;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // Pin the Running session inside alpha. Under Attention sort it sinks
     // below alpha-waiting, so its index will shift on the rebuild.
@@ -6810,7 +7554,7 @@ fn reseat_cursor_clamps_when_no_session_selected() {
 
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Project;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     env.view.selected_session = None;
     env.view.cursor = env.view.flat_items.len() + 50; // intentionally out of range
 
@@ -6833,7 +7577,7 @@ fn manual_grouping_attention_sort_stays_flat() {
     let mut env = create_test_env_two_projects_mixed_attention();
     env.view.group_by = GroupByMode::Manual;
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let group_count = env
         .view
@@ -7080,7 +7824,7 @@ fn favorite_decoration_gated_to_attention_sort() {
     // In Newest: row should NOT have the `* ` prefix or the bold/
     // underlined favorite styling.
     env.view.sort_order = SortOrder::Newest;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let item = env
         .view
         .flat_items
@@ -7102,7 +7846,7 @@ fn favorite_decoration_gated_to_attention_sort() {
 
     // Flip to Attention: the prefix returns.
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let item_attention = env
         .view
         .flat_items
@@ -7131,7 +7875,7 @@ fn snooze_decoration_gated_to_attention_sort() {
     env.view.mutate_instance(&id, |inst| inst.snooze(30));
 
     env.view.sort_order = SortOrder::Newest;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let item_newest = env
         .view
         .flat_items
@@ -7147,7 +7891,7 @@ fn snooze_decoration_gated_to_attention_sort() {
     );
 
     env.view.sort_order = SortOrder::Attention;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     let item_attention = env
         .view
         .flat_items
@@ -7179,7 +7923,7 @@ fn archived_section_pinned_to_bottom_in_every_sort() {
 
     for sort in [SortOrder::Newest, SortOrder::Attention, SortOrder::AZ] {
         env.view.sort_order = sort;
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
 
         // Archived row must NOT appear inline among the active sessions.
         let archived_inline = env
@@ -7272,7 +8016,7 @@ fn archived_section_nests_by_project_in_project_mode() {
         .apply_user_action(&beta_id, |inst| inst.archive())
         .unwrap();
     env.view.archived_section_collapsed = false;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // Find the Archived section header and walk forward.
     let arch_idx = env
@@ -7390,7 +8134,7 @@ fn archived_only_project_leaves_no_phantom_header() {
         .apply_user_action(&beta_error, |inst| inst.archive())
         .unwrap();
     env.view.instances.retain(|i| i.id != beta_running);
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // Count "beta" headers that live OUTSIDE the Archived section.
     let mut in_archived = false;
@@ -7431,7 +8175,7 @@ fn archived_section_collapsed_hides_project_sub_folders() {
         .apply_user_action(&alpha_id, |inst| inst.archive())
         .unwrap();
     env.view.archived_section_collapsed = true;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     let within_archive_items: Vec<&Item> = env
         .view
@@ -7486,7 +8230,7 @@ fn archived_project_sub_folder_collapse_hides_only_its_sessions() {
     env.view
         .project_group_collapsed
         .insert(archived_project_sub_path("alpha"), true);
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
 
     // alpha sub-folder must still appear as a header but with no session row
     // following it; beta sub-folder must still emit its session row.
@@ -7575,7 +8319,7 @@ fn archived_sub_folders_honor_sort_order() {
     let beta_sub = archived_project_sub_path("beta");
 
     env.view.sort_order = SortOrder::AZ;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     assert_eq!(
         first_sub_folder(&env).as_deref(),
         Some(alpha_sub.as_str()),
@@ -7583,7 +8327,7 @@ fn archived_sub_folders_honor_sort_order() {
     );
 
     env.view.sort_order = SortOrder::ZA;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     assert_eq!(
         first_sub_folder(&env).as_deref(),
         Some(beta_sub.as_str()),
@@ -7591,7 +8335,7 @@ fn archived_sub_folders_honor_sort_order() {
     );
 
     env.view.sort_order = SortOrder::Newest;
-    env.view.flat_items = env.view.build_flat_items();
+    env.view.rebuild_flat_items();
     assert_eq!(
         first_sub_folder(&env).as_deref(),
         Some(beta_sub.as_str()),
@@ -10378,14 +11122,20 @@ mod new_session_attach_mode {
             worktree_branch: None,
             create_new_branch: false,
             base_branch: None,
+            worktree_on_exists: crate::git::OnExists::Error,
             extra_repo_paths: Vec::new(),
             sandbox: false,
             sandbox_image: String::new(),
             yolo_mode: false,
+            allow_unsandboxed_yolo: false,
             extra_env: Vec::new(),
+            cpu_limit: None,
+            memory_limit: None,
+            custom_instruction: None,
             extra_args: String::new(),
             command_override: String::new(),
             scratch: false,
+            auto_create_terminal: false,
         }
     }
 
@@ -10411,6 +11161,48 @@ mod new_session_attach_mode {
             action
         );
     }
+
+    #[test]
+    #[serial]
+    fn max_sessions_blocks_creation_and_warn_shows_hint() {
+        let mut env = create_test_env_empty();
+        let project_dir = env._temp.path().join("limit-project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let mut existing = Instance::new("existing", "/tmp/existing");
+        existing.source_profile = "test".to_string();
+        env.view.add_instance(existing);
+
+        env.view.max_sessions_warn = 1;
+        assert_eq!(env.view.session_count_warning(), None);
+
+        env.view.max_sessions = 1;
+        let data = sync_path_session_data(project_dir.to_str().unwrap());
+        assert!(env.view.create_session_with_hooks(data, None).is_none());
+        assert_eq!(env.view.instances().len(), 1);
+        let dialog = env.view.info_dialog.as_ref().expect("limit dialog");
+        assert!(dialog.message().contains("max_sessions is 1"));
+
+        env.view.info_dialog = None;
+        env.view.max_sessions = 0;
+        let data = sync_path_session_data(project_dir.to_str().unwrap());
+        assert!(env.view.create_session_with_hooks(data, None).is_some());
+        assert_eq!(env.view.session_count_warning(), Some(2));
+    }
+
+    #[test]
+    #[serial]
+    fn max_sessions_uses_the_target_profiles_config() {
+        let mut env = create_test_env_empty();
+        crate::session::create_profile("other").unwrap();
+        let mut existing = Instance::new("existing", "/tmp/existing");
+        existing.source_profile = "other".to_string();
+        env.view.add_instance(existing);
+
+        // The config profile ("test") is capped; "other" keeps the default.
+        env.view.max_sessions = 1;
+        assert!(env.view.check_session_limit("test", 2).is_err());
+        assert!(env.view.check_session_limit("other", 2).is_ok());
+    }
 }
 
 /// Tests for the `default_attach_mode` setting that drives whether
@@ -10453,7 +11245,7 @@ mod default_attach_mode {
         // row produces Action::AttachSession.
         let mut env = create_test_env_empty();
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         let action = env.view.activate_selected_session();
@@ -10468,7 +11260,7 @@ mod default_attach_mode {
         let mut env = create_test_env_empty();
         write_global_default_attach_mode(NewSessionAttachMode::LiveSend);
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         let action = env.view.activate_selected_session();
@@ -10489,7 +11281,7 @@ mod default_attach_mode {
         let mut env = create_test_env_empty();
         write_global_default_attach_mode(NewSessionAttachMode::LiveSend);
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         env.view.view_mode = crate::tui::home::ViewMode::Terminal;
@@ -10506,7 +11298,7 @@ mod default_attach_mode {
         // live mode see no change.
         let mut env = create_test_env_empty();
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         env.view.view_mode = crate::tui::home::ViewMode::Terminal;
@@ -10528,7 +11320,7 @@ mod default_attach_mode {
         let mut env = create_test_env_empty();
         write_global_default_attach_mode(NewSessionAttachMode::LiveSend);
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         let action = env.view.handle_key(key(KeyCode::Tab), None);
@@ -10542,7 +11334,7 @@ mod default_attach_mode {
         // Tab keeps its historical live-send role.
         let mut env = create_test_env_empty();
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         let action = env.view.handle_key(key(KeyCode::Tab), None);
@@ -10558,7 +11350,7 @@ mod default_attach_mode {
         let mut env = create_test_env_empty();
         write_global_default_attach_mode(NewSessionAttachMode::LiveSend);
         let id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         env.view.view_mode = crate::tui::home::ViewMode::Terminal;
@@ -10581,7 +11373,7 @@ mod default_attach_mode {
         // paired terminal pane.
         let mut env = create_test_env_empty();
         let _id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         env.view.view_mode = crate::tui::home::ViewMode::Terminal;
@@ -10598,6 +11390,51 @@ mod default_attach_mode {
         );
     }
 
+    #[test]
+    #[serial]
+    fn run_command_targets_shell_and_submits_run_action() {
+        // The run-command dialog always targets a shell, never the agent,
+        // regardless of view mode, and its submit carries a distinct action
+        // so the app attaches to the shell afterwards.
+        let mut env = create_test_env_empty();
+        let id = add_session(&mut env.view, "session-one");
+        env.view.rebuild_flat_items();
+        env.view.cursor = 0;
+        env.view.update_selected();
+        env.view.open_run_command_dialog();
+        assert!(env.view.send_message_dialog.is_some());
+        assert_eq!(
+            env.view.pending_send_target,
+            crate::tui::home::live_send::LiveSendTarget::Terminal
+        );
+        for c in "ls".chars() {
+            let _ = env.view.handle_key(key(KeyCode::Char(c)), None);
+        }
+        let action = env.view.handle_key(key(KeyCode::Enter), None);
+        assert_eq!(action, Some(Action::RunCommand(id.clone(), "ls".into())));
+        assert!(!env.view.pending_run_command);
+
+        env.view.mutate_instance(&id, |inst| {
+            inst.sandbox_info = Some(crate::session::SandboxInfo {
+                enabled: true,
+                container_id: None,
+                image: "ubuntu:latest".to_string(),
+                container_name: "aoe-test".to_string(),
+                extra_env: None,
+                custom_instruction: None,
+                cpu_limit: None,
+                memory_limit: None,
+            })
+        });
+        env.view.open_run_command_dialog();
+        assert_eq!(
+            env.view.pending_send_target,
+            crate::tui::home::live_send::LiveSendTarget::ContainerTerminal
+        );
+        let _ = env.view.handle_key(key(KeyCode::Esc), None);
+        assert!(!env.view.pending_run_command);
+    }
+
     #[test]
     #[serial]
     fn start_live_send_in_terminal_view_targets_terminal_pane() {
@@ -10607,7 +11444,7 @@ mod default_attach_mode {
         // keystrokes to the paired terminal tmux pane.
         let mut env = create_test_env_empty();
         let _id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         env.view.view_mode = crate::tui::home::ViewMode::Terminal;
@@ -10640,7 +11477,7 @@ mod default_attach_mode {
         // to Some(false): Enter goes to tmux attach, Tab to live mode.
         let mut env = create_test_env_empty();
         let _id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         assert_eq!(env.view.help_live_on_enter(), Some(false));
@@ -10655,7 +11492,7 @@ mod default_attach_mode {
         let mut env = create_test_env_empty();
         write_global_default_attach_mode(NewSessionAttachMode::LiveSend);
         let _id = add_session(&mut env.view, "session-one");
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         assert_eq!(env.view.help_live_on_enter(), Some(true));
@@ -10699,7 +11536,7 @@ mod default_attach_mode {
         env.view.mutate_instance(&id, |inst| {
             inst.view = crate::session::View::Structured;
         });
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.cursor = 0;
         env.view.update_selected();
         let action = env.view.activate_selected_session();
@@ -11184,7 +12021,7 @@ mod save_field_merge {
 
         view.apply_user_action(&id, |inst| inst.archive())
             .expect("seed archive must persist");
-        view.flat_items = view.build_flat_items();
+        view.rebuild_flat_items();
         assert!(
             view.get_instance(&id).unwrap().is_archived(),
             "precondition: row archived in memory"
@@ -11361,7 +12198,7 @@ mod right_click_context_menu {
         // Attention sort surfaces the full session menu (Rename / Archive /
         // Snooze / Delete), so Delete is three Downs away.
         env.view.sort_order = SortOrder::Attention;
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         env.view.handle_right_click(5, 1);
         env.view.handle_key(key(KeyCode::Down), None);
         env.view.handle_key(key(KeyCode::Down), None);
@@ -11478,7 +12315,7 @@ mod right_click_context_menu {
 
         // Newest sort (the default): no Snooze row.
         env.view.sort_order = SortOrder::Newest;
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         assert!(env.view.handle_right_click(5, 1));
         assert!(
             !menu_actions(&env).contains(&ContextMenuAction::ToggleSnooze),
@@ -11488,7 +12325,7 @@ mod right_click_context_menu {
 
         // Attention sort: Snooze row present.
         env.view.sort_order = SortOrder::Attention;
-        env.view.flat_items = env.view.build_flat_items();
+        env.view.rebuild_flat_items();
         assert!(env.view.handle_right_click(5, 1));
         assert!(
             menu_actions(&env).contains(&ContextMenuAction::ToggleSnooze),
@@ -11809,7 +12646,7 @@ mod apply_session_id_updates {
         )
         .unwrap();
         view.group_by = GroupByMode::Manual;
-        view.flat_items = view.build_flat_items();
+        view.rebuild_flat_items();
         view.update_selected();
         view
     }
@@ -12137,3 +12974,123 @@ mod live_send_boot_size_tests {
         );
     }
 }
+
+#[test]
+#[serial]
+fn test_rebuild_container_requires_sandbox_then_confirms() {
+    use crate::session::SandboxInfo;
+
+    fn run_rebuild_from_palette(view: &mut HomeView) {
+        view.handle_key(
+            KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL),
+            None,
+        );
+        for ch in "rebuild sandbox".chars() {
+            view.handle_key(key(KeyCode::Char(ch)), None);
+        }
+        view.handle_key(key(KeyCode::Enter), None);
+    }
+
+    let mut env = create_test_env_with_sessions(1);
+    let id = env.view.selected_session.clone().unwrap();
+
+    run_rebuild_from_palette(&mut env.view);
+    assert!(env.view.confirm_dialog.is_none());
+    assert!(env.view.info_dialog.is_some());
+    env.view.info_dialog = None;
+
+    env.view.mutate_instance(&id, |inst| {
+        inst.sandbox_info = Some(SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: "test-container".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        });
+    });
+
+    run_rebuild_from_palette(&mut env.view);
+    assert!(env.view.confirm_dialog.is_some());
+    assert_eq!(env.view.pending_rebuild_container.as_deref(), Some(&*id));
+
+    let action = env.view.handle_key(key(KeyCode::Char('y')), None);
+    assert_eq!(action, Some(Action::RebuildContainer(id)));
+    assert!(env.view.pending_rebuild_container.is_none());
+}
+
+#[test]
+#[serial]
+fn test_restart_agent_confirms_only_while_running() {
+    let mut env = create_test_env_with_sessions(1);
+    let id = env.view.selected_session.clone().unwrap();
+    env.view
+        .set_instance_status(&id, crate::session::Status::Running);
+
+    env.view.handle_key(
+        KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL),
+        None,
+    );
+    for ch in "restart agent".chars() {
+        env.view.handle_key(key(KeyCode::Char(ch)), None);
+    }
+    env.view.handle_key(key(KeyCode::Enter), None);
+
+    let dialog = env.view.confirm_dialog.as_ref().expect("confirm dialog");
+    assert_eq!(dialog.action(), "restart_agent");
+    assert_eq!(env.view.pending_restart_agent.as_deref(), Some(&*id));
+
+    env.view.handle_key(key(KeyCode::Char('n')), None);
+    assert!(env.view.confirm_dialog.is_none());
+    assert!(env.view.pending_restart_agent.is_none());
+}
+
+/// `M` toggles a runtime-only mute that shows in the status bar and leaves
+/// the persisted `sound.enabled` alone.
+#[test]
+#[serial]
+fn test_toggle_mute_shows_indicator_without_touching_config() {
+    use crate::tui::styles::load_theme;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn render(view: &mut HomeView) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        let theme = load_theme("empire");
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                view.render(f, area, &theme, None, None, None);
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let mut out = String::new();
+        for y in 0..buf.area.height {
+            for x in 0..buf.area.width {
+                out.push_str(buf[(x, y)].symbol());
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    let mut env = create_test_env_with_sessions(1);
+    let enabled = env.view.sound_config.enabled;
+    assert!(!env.view.sound_muted);
+    assert!(!render(&mut env.view).contains("Muted"));
+
+    env.view.handle_key(key(KeyCode::Char('M')), None);
+    assert!(env.view.sound_muted);
+    assert_eq!(env.view.sound_config.enabled, enabled);
+    let out = render(&mut env.view);
+    assert!(
+        out.contains("Muted"),
+        "expected the mute indicator in the status bar.\nFull buffer:\n{out}"
+    );
+
+    env.view.handle_key(key(KeyCode::Char('M')), None);
+    assert!(!env.view.sound_muted);
+    assert!(!render(&mut env.view).contains("Muted"));
+}
@@ -112,7 +112,8 @@ pub async fn run(profile: &str, startup_warning: Option<String>) -> Result<()> {
     // This ensures we have release notes for the changelog dialog.
     if check_version_change()?.is_some() {
         let settings = get_update_settings();
-        if settings.update_check_mode.is_enabled() {
+        // Offline mode keeps whatever the cache already has.
+        if settings.update_check_mode.is_enabled() && !settings.offline {
             let current_version = env!("CARGO_PKG_VERSION");
             // Don't let a network issue block startup
             let _ = tokio::time::timeout(
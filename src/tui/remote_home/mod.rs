@@ -27,7 +27,7 @@ use serde::Deserialize;
 
 use crate::acp::client::discovery::DaemonEndpoint;
 use crate::acp::client::HttpClient;
-use crate::session::config::{resolve_theme_name, resolve_theme_palette_mode};
+use crate::session::config::resolve_theme_name;
 use crate::tui::styles::Theme;
 
 /// Subset of `/api/sessions`'s `SessionResponse` we need. `serde` skips
@@ -108,8 +108,7 @@ pub async fn run_standalone(endpoint: DaemonEndpoint) -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
     let mut event_stream = EventStream::new();
     let theme_name = resolve_theme_name();
-    let palette_mode = resolve_theme_palette_mode();
-    let theme = crate::tui::styles::load_theme_with_mode(&theme_name, palette_mode);
+    let theme = crate::tui::styles::load_configured_theme(&theme_name);
 
     let result = run(&mut terminal, &mut event_stream, &theme, endpoint).await;
 
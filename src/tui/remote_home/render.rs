@@ -34,6 +34,7 @@ pub fn render(frame: &mut Frame, area: Rect, theme: &Theme, state: &RemoteHomeSt
     render_header(frame, chunks[0], theme, state);
     render_list(frame, chunks[1], theme, state);
     render_footer(frame, chunks[2], theme, state);
+    theme.apply_monochrome(frame.buffer_mut());
 }
 
 fn render_header(frame: &mut Frame, area: Rect, theme: &Theme, state: &RemoteHomeState) {
@@ -165,7 +166,7 @@ mod tests {
 
     #[test]
     fn selected_row_style_preserves_readable_color() {
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let style = Style::default().fg(theme.text);
 
         assert_eq!(selected_row_style(style, &theme).fg, Some(theme.text));
@@ -173,7 +174,7 @@ mod tests {
 
     #[test]
     fn selected_row_style_sets_text_for_default_foreground() {
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let style = Style::default();
 
         assert_eq!(selected_row_style(style, &theme).fg, Some(theme.text));
@@ -181,7 +182,7 @@ mod tests {
 
     #[test]
     fn selected_row_style_falls_back_for_low_contrast_color() {
-        let mut theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let mut theme = crate::tui::styles::load_theme("empire");
         theme.dimmed = theme.session_selection;
         let style = Style::default().fg(theme.dimmed);
 
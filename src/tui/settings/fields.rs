@@ -136,6 +136,9 @@ pub enum FieldKind {
     },
     /// Profile-only description (no global counterpart to inherit).
     ProfileDescription,
+    /// Profile-only TUI theme (`ProfileConfig::theme_name`); unset inherits
+    /// the global theme.
+    ProfileTheme,
     /// Lifecycle hook list (`config.hooks.*`); not in the schema.
     Hook(HookField),
     /// Host environment list (`Config.environment`, root-level).
@@ -189,10 +192,14 @@ pub enum ListItemValidation {
     CustomAgent,
     /// `name=builtin`, where `builtin` is a known agent.
     DetectAs,
+    /// `name=regex`, a status-detection pattern for an agent.
+    StatusPattern,
     /// `name=command`, an ACP launch command split into argv (acp).
     AcpCmd,
     /// Host/sandbox env entry (`KEY=value` etc).
     EnvEntry,
+    /// `agent_name=KEY=VALUE ...`, extra container env for a known agent.
+    AgentContainerEnv,
 }
 
 impl SettingField {
@@ -207,6 +214,7 @@ impl SettingField {
         match &self.kind {
             FieldKind::Schema { section, field, .. } => format!("{section}.{field}"),
             FieldKind::ProfileDescription => "__profile.description".to_string(),
+            FieldKind::ProfileTheme => "__profile.theme_name".to_string(),
             FieldKind::Hook(h) => format!("hooks.{}", h.field()),
             FieldKind::HostEnvironment => "environment".to_string(),
             FieldKind::LoggingTarget(i) => format!("logging.targets.{i}"),
@@ -241,6 +249,9 @@ impl SettingField {
                     }
                     "custom_agents" => ListItemValidation::CustomAgent,
                     "agent_detect_as" => ListItemValidation::DetectAs,
+                    "agent_waiting_patterns" | "agent_error_patterns" => {
+                        ListItemValidation::StatusPattern
+                    }
                     "agent_acp_cmd" => ListItemValidation::AcpCmd,
                     _ => ListItemValidation::None,
                 }
@@ -250,6 +261,11 @@ impl SettingField {
             {
                 ListItemValidation::EnvEntry
             }
+            FieldKind::Schema { section, field, .. }
+                if section == "sandbox" && field == "agent_container_env" =>
+            {
+                ListItemValidation::AgentContainerEnv
+            }
             _ => ListItemValidation::None,
         }
     }
@@ -290,6 +306,18 @@ impl SettingField {
                 }
                 Ok(())
             }
+            // Resource limits: the schema rule is runtime-agnostic, so layer
+            // the configured runtime's own constraints on top.
+            (FieldKind::Schema { section, field, .. }, FieldValue::OptionalText(Some(limit)))
+                if section == "sandbox" && (field == "cpu_limit" || field == "memory_limit") =>
+            {
+                let runtime = crate::containers::configured_runtime_name();
+                if field == "cpu_limit" {
+                    crate::session::validate_cpu_limit_for_runtime(limit, runtime)
+                } else {
+                    crate::session::validate_memory_limit_for_runtime(limit, runtime)
+                }
+            }
             // Everything else: enforce the schema's server-authoritative rule.
             (FieldKind::Schema { validation, .. }, value) => {
                 validate_field_value(validation, value)
@@ -646,6 +674,7 @@ pub fn build_fields_for_category(
             has_override: overrides.description.is_some(),
             inherited_display: None,
         });
+        primary.push(build_profile_theme_row(overrides, base));
     }
 
     for desc in schema()
@@ -656,7 +685,11 @@ pub fn build_fields_for_category(
         // would write an override the read path ignores, stranding the value
         // (the empire->rose-pine flip). Enforces the documented `global_only`
         // semantics that nothing else was checking.
-        .filter(|d| scope == SettingsScope::Global || d.profile_overridable)
+        .filter(|d| match scope {
+            SettingsScope::Global => true,
+            SettingsScope::Profile => d.profile_overridable,
+            SettingsScope::Repo => d.repo_overridable,
+        })
     {
         // The per-target logging matrix expands one descriptor into N rows.
         if matches!(&desc.widget, WidgetKind::Custom { id } if id == "logging-targets") {
@@ -696,6 +729,36 @@ pub fn build_fields_for_category(
     }
 }
 
+/// First option of the profile theme picker: no profile theme, paint the
+/// global one.
+const PROFILE_THEME_INHERIT: &str = "(global theme)";
+
+/// The profile-only theme row (Theme tab, Profile scope).
+fn build_profile_theme_row(overrides: &ProfileConfig, base: &Config) -> SettingField {
+    let mut options = vec![PROFILE_THEME_INHERIT.to_string()];
+    options.extend(available_themes());
+    let selected = overrides
+        .theme_name
+        .as_deref()
+        .and_then(|name| options.iter().position(|o| o == name))
+        .unwrap_or(0);
+    SettingField {
+        kind: FieldKind::ProfileTheme,
+        label: "Profile Theme".to_string(),
+        description: "TUI theme painted while the dashboard is filtered to this profile, so \
+                      profiles look different at a glance. The all-profiles view, the web \
+                      dashboard, and the tmux status bar keep the global theme."
+            .to_string(),
+        value: FieldValue::Select { selected, options },
+        category: SettingsCategory::Theme,
+        has_override: overrides.theme_name.is_some(),
+        inherited_display: overrides
+            .theme_name
+            .is_some()
+            .then(|| base.effective_theme_name()),
+    }
+}
+
 /// Shared inputs for building rows in one category/scope pass.
 struct BuildCtx<'a> {
     scope: SettingsScope,
@@ -886,6 +949,20 @@ pub fn apply_field_to_config(
         return;
     }
 
+    // The profile theme likewise lives on the override struct, profile
+    // scope only.
+    if matches!(field.kind, FieldKind::ProfileTheme) {
+        if scope == SettingsScope::Profile {
+            if let FieldValue::Select { selected, options } = &field.value {
+                profile.theme_name = options
+                    .get(*selected)
+                    .filter(|name| *selected > 0 && !name.is_empty())
+                    .cloned();
+            }
+        }
+        return;
+    }
+
     // The profile description is stored on the override struct, not the
     // merged config, and only in profile/repo scope.
     if matches!(field.kind, FieldKind::ProfileDescription) {
@@ -991,6 +1068,9 @@ pub fn clear_override(field: &SettingField, profile: &mut ProfileConfig) {
         FieldKind::ProfileDescription => {
             profile.description = None;
         }
+        FieldKind::ProfileTheme => {
+            profile.theme_name = None;
+        }
         FieldKind::HostEnvironment => {
             let mut j = serde_json::to_value(&*profile).unwrap_or_else(|_| json!({}));
             if let Value::Object(map) = &mut j {
@@ -1024,6 +1104,91 @@ fn clear_override_path(profile: &mut ProfileConfig, section: &str, field: &str)
     }
 }
 
+// ---------------------------------------------------------------------------
+// Effective (merged) config view
+// ---------------------------------------------------------------------------
+
+/// Where the effective value of a field comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Nothing sets it; the built-in default applies.
+    Default,
+    /// Set in the global `config.toml`.
+    Global,
+    /// Overridden by the profile.
+    Profile,
+    /// Overridden by the repo's `.agent-of-empires/config.toml`.
+    Repo,
+}
+
+impl ConfigSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigSource::Default => "default",
+            ConfigSource::Global => "global",
+            ConfigSource::Profile => "profile",
+            ConfigSource::Repo => "repo",
+        }
+    }
+}
+
+/// One row of the read-only effective config view.
+#[derive(Debug, Clone)]
+pub struct EffectiveRow {
+    /// Dotted `section.field` key, as written in `config.toml`.
+    pub key: String,
+    pub value: String,
+    pub source: ConfigSource,
+}
+
+/// Resolve every schema field to the value a session in this profile (and
+/// repo, when `repo` is given) actually uses, with where it came from. Later
+/// layers win: repo over profile over global; a global value equal to the
+/// built-in default is reported as `Default`. Global-only fields ignore the
+/// profile and repo layers, matching the read path.
+pub fn effective_rows(
+    global: &Config,
+    profile: &ProfileConfig,
+    repo: Option<&ProfileConfig>,
+) -> Vec<EffectiveRow> {
+    let default_json = serde_json::to_value(Config::default()).unwrap_or_else(|_| json!({}));
+    let global_json = serde_json::to_value(global).unwrap_or_else(|_| json!({}));
+    let profile_json = serde_json::to_value(profile).unwrap_or_else(|_| json!({}));
+    let repo_json = repo
+        .and_then(|r| serde_json::to_value(r).ok())
+        .unwrap_or_else(|| json!({}));
+
+    schema()
+        .into_iter()
+        .map(|desc| {
+            let (section, field) = (desc.section.as_str(), desc.field.as_str());
+            let repo_value = json_at(&repo_json, section, field).filter(|_| desc.repo_overridable);
+            let profile_value =
+                json_at(&profile_json, section, field).filter(|_| desc.profile_overridable);
+            let global_value = json_at(&global_json, section, field);
+            let (current, source) = if let Some(v) = repo_value {
+                (Some(v), ConfigSource::Repo)
+            } else if let Some(v) = profile_value {
+                (Some(v), ConfigSource::Profile)
+            } else if global_value != json_at(&default_json, section, field) {
+                (global_value, ConfigSource::Global)
+            } else {
+                (global_value, ConfigSource::Default)
+            };
+            let value = match value_from_json(&desc.widget, current) {
+                FieldValue::List(items) if items.is_empty() => "(none)".to_string(),
+                FieldValue::List(items) => items.join(", "),
+                other => value_display_string(&other),
+            };
+            EffectiveRow {
+                key: desc.path(),
+                value,
+                source,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1412,4 +1577,66 @@ mod tests {
             vec!["hooks.on_create", "hooks.on_launch", "hooks.on_destroy"]
         );
     }
+
+    fn effective<'a>(rows: &'a [EffectiveRow], key: &str) -> &'a EffectiveRow {
+        rows.iter()
+            .find(|r| r.key == key)
+            .unwrap_or_else(|| panic!("missing row {key}"))
+    }
+
+    #[test]
+    fn effective_rows_report_provenance_per_layer() {
+        let mut global = Config::default();
+        global.worktree.enabled = !global.worktree.enabled;
+        let profile = profile_from(json!({ "sandbox": { "auto_cleanup": false } }));
+        let repo = profile_from(json!({ "session": { "yolo_mode_default": true } }));
+
+        let rows = effective_rows(&global, &profile, Some(&repo));
+        assert_eq!(
+            effective(&rows, "worktree.enabled").source,
+            ConfigSource::Global
+        );
+        let cleanup = effective(&rows, "sandbox.auto_cleanup");
+        assert_eq!(cleanup.source, ConfigSource::Profile);
+        assert_eq!(cleanup.value, "off");
+        assert_eq!(
+            effective(&rows, "session.yolo_mode_default").source,
+            ConfigSource::Repo
+        );
+        assert_eq!(
+            effective(&rows, "sandbox.extra_volumes_strict").source,
+            ConfigSource::Default
+        );
+    }
+
+    #[test]
+    fn profile_theme_row_applies_and_clears() {
+        let global = Config::default();
+        let mut profile = ProfileConfig::default();
+        let fields = build_fields_for_category(
+            SettingsCategory::Theme,
+            SettingsScope::Profile,
+            &global,
+            &profile,
+        );
+        let mut row = field(&fields, "__profile.theme_name").clone();
+        assert!(!row.has_override);
+        let FieldValue::Select { selected, options } = &mut row.value else {
+            panic!("profile theme row should be a select");
+        };
+        assert_eq!(*selected, 0, "unset profile theme inherits the global one");
+        let dracula = options.iter().position(|o| o == "dracula").unwrap();
+        *selected = dracula;
+
+        let mut global_mut = global.clone();
+        apply_field_to_config(&row, SettingsScope::Profile, &mut global_mut, &mut profile);
+        assert_eq!(profile.theme_name.as_deref(), Some("dracula"));
+        assert!(
+            !has_override_path(&profile, "theme", "name"),
+            "the profile theme must not write a [theme] override"
+        );
+
+        clear_override(&row, &mut profile);
+        assert!(profile.theme_name.is_none());
+    }
 }
@@ -19,6 +19,9 @@ pub enum SettingsAction {
     UnsavedChangesWarning,
     /// Live-preview a theme change (theme name)
     PreviewTheme(String),
+    /// Open the current scope's config file in `$EDITOR`; the caller hands
+    /// control back through [`SettingsView::finish_raw_edit`].
+    EditRawConfig(std::path::PathBuf),
 }
 
 impl SettingsView {
@@ -65,6 +68,31 @@ impl SettingsView {
             return SettingsAction::Continue;
         }
 
+        // Handle the effective config overlay: read-only, so only scrolling
+        // and closing do anything.
+        if let Some(rows) = &self.effective_rows {
+            let max_scroll = rows.len().saturating_sub(1);
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('v') | KeyCode::Char('q') => {
+                    self.effective_rows = None;
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.effective_scroll = self.effective_scroll.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.effective_scroll = (self.effective_scroll + 1).min(max_scroll);
+                }
+                KeyCode::PageUp => {
+                    self.effective_scroll = self.effective_scroll.saturating_sub(10);
+                }
+                KeyCode::PageDown => {
+                    self.effective_scroll = (self.effective_scroll + 10).min(max_scroll);
+                }
+                _ => {}
+            }
+            return SettingsAction::Continue;
+        }
+
         // Handle text editing mode
         if self.editing_input.is_some() {
             return self.handle_text_edit_key(key);
@@ -331,12 +359,25 @@ impl SettingsView {
                 SettingsAction::Continue
             }
 
+            // Edit the scope's config file as raw TOML in $EDITOR
+            (KeyCode::Char('e'), KeyModifiers::CONTROL) => match self.begin_raw_edit() {
+                Some(path) => SettingsAction::EditRawConfig(path),
+                None => SettingsAction::Continue,
+            },
+
             // Toggle help overlay
             (KeyCode::Char('?'), _) => {
                 self.show_help = true;
                 SettingsAction::Continue
             }
 
+            // Show the effective (merged) config with where each value
+            // comes from.
+            (KeyCode::Char('v'), _) => {
+                self.open_effective_view();
+                SettingsAction::Continue
+            }
+
             // Open the settings-wide search overlay. Any field with a
             // matching label or description (across every category) is
             // a hit; Enter jumps to that field.
@@ -572,8 +613,15 @@ impl SettingsView {
                                 Some(validate_custom_agent_entry(&text))
                             }
                             ListItemValidation::DetectAs => Some(validate_detect_as_entry(&text)),
+                            ListItemValidation::StatusPattern => {
+                                Some(validate_status_pattern_entry(&text))
+                            }
                             ListItemValidation::AcpCmd => Some(validate_acp_cmd_entry(&text)),
-                            ListItemValidation::None | ListItemValidation::EnvEntry => None,
+                            ListItemValidation::EnvEntry => Some(validate_env_entry_format(&text)),
+                            ListItemValidation::AgentContainerEnv => {
+                                Some(validate_agent_container_env_entry(&text))
+                            }
+                            ListItemValidation::None => None,
                         };
                         if let Some(Err(msg)) = validation_result {
                             self.error_message = Some(msg);
@@ -717,6 +765,7 @@ impl SettingsView {
             || self.custom_instruction_dialog.is_some()
             || self.show_help
             || self.search_input.is_some()
+            || self.effective_rows.is_some()
         {
             return None;
         }
@@ -780,7 +829,8 @@ impl SettingsView {
             || self.list_edit_state.is_some()
             || self.custom_instruction_dialog.is_some()
             || self.show_help
-            || self.search_input.is_some();
+            || self.search_input.is_some()
+            || self.effective_rows.is_some();
         let new_pos = if suppress { None } else { Some((col, row)) };
         if self.mouse_pos == new_pos {
             return false;
@@ -799,6 +849,14 @@ impl SettingsView {
 }
 
 /// Validate that an entry for AgentExtraArgs or AgentCommandOverride is in `agent_name=value` format.
+/// `agent_name=KEY=VALUE ...`: a known agent followed by whitespace-separated
+/// `KEY=VALUE` pairs.
+fn validate_agent_container_env_entry(text: &str) -> Result<(), String> {
+    validate_agent_key_value(text)?;
+    let (_, spec) = text.split_once('=').unwrap_or_default();
+    crate::session::parse_agent_container_env(spec).map(|_| ())
+}
+
 fn validate_agent_key_value(text: &str) -> Result<(), String> {
     let Some((key, value)) = text.split_once('=') else {
         let names = crate::agents::agent_names().join(", ");
@@ -895,10 +953,65 @@ fn validate_detect_as_entry(text: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Validate a status pattern entry: name=regex. The regex must compile.
+fn validate_status_pattern_entry(text: &str) -> Result<(), String> {
+    let Some((key, value)) = text.split_once('=') else {
+        return Err("Must be in name=regex format (e.g. my-agent=Continue\\?)".to_string());
+    };
+    if key.is_empty() {
+        return Err("Agent name cannot be empty".to_string());
+    }
+    if value.trim().is_empty() {
+        return Err("Pattern cannot be empty".to_string());
+    }
+    regex::Regex::new(value)
+        .map(|_| ())
+        .map_err(|e| format!("Invalid regex: {e}"))
+}
+
+/// Validate the shape of an env entry: `KEY=value` or a bare `KEY`
+/// (passthrough). The key must be a valid env var name; otherwise the entry
+/// would be dropped or rejected when the environment is built.
+fn validate_env_entry_format(text: &str) -> Result<(), String> {
+    let key = text.split_once('=').map_or(text, |(k, _)| k);
+    if key.is_empty() {
+        return Err("Must be in KEY=value format (or a bare KEY to pass through)".to_string());
+    }
+    if !crate::session::is_valid_env_name(key) {
+        return Err(format!(
+            "'{key}' is not a valid env var name (letters, digits and _, not starting with a digit)"
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_env_entry_format() {
+        assert!(validate_env_entry_format("GH_TOKEN=$GH_TOKEN").is_ok());
+        assert!(validate_env_entry_format("_X=a=b").is_ok());
+        assert!(validate_env_entry_format("HOME").is_ok());
+        assert!(validate_env_entry_format("=value").is_err());
+        assert!(validate_env_entry_format("1KEY=x").is_err());
+        assert!(validate_env_entry_format("MY-KEY=x").is_err());
+        assert!(validate_env_entry_format("KEY VALUE").is_err());
+    }
+
+    #[test]
+    fn test_validate_status_pattern_entry() {
+        assert!(validate_status_pattern_entry("my-agent=Continue\\? \\[y/N\\]").is_ok());
+        // Only the first '=' separates the name, so patterns may contain one.
+        assert!(validate_status_pattern_entry("my-agent=key=value").is_ok());
+        assert!(validate_status_pattern_entry("no-equals").is_err());
+        assert!(validate_status_pattern_entry("=x").is_err());
+        assert!(validate_status_pattern_entry("my-agent=").is_err());
+        let err = validate_status_pattern_entry("my-agent=(unclosed").unwrap_err();
+        assert!(err.contains("Invalid regex"));
+    }
+
     #[test]
     fn test_validate_agent_key_value_valid() {
         assert!(validate_agent_key_value("claude=my-wrapper").is_ok());
@@ -929,6 +1042,15 @@ mod tests {
         assert!(err.contains("not a known agent"));
     }
 
+    #[test]
+    fn test_validate_agent_container_env_entry() {
+        assert!(validate_agent_container_env_entry("claude=FOO=1 BAR='a b'").is_ok());
+        let err = validate_agent_container_env_entry("claude=FOO").unwrap_err();
+        assert!(err.contains("KEY=VALUE"));
+        let err = validate_agent_container_env_entry("nonexistent=FOO=1").unwrap_err();
+        assert!(err.contains("not a known agent"));
+    }
+
     // Tests for validate_custom_agent_entry
     #[test]
     fn test_validate_custom_agent_entry_valid() {
@@ -13,7 +13,9 @@ use crate::session::{
 };
 use crate::tui::dialogs::CustomInstructionDialog;
 
-pub use fields::{FieldValue, HookField, SettingField, SettingsCategory};
+pub use fields::{
+    ConfigSource, EffectiveRow, FieldValue, HookField, SettingField, SettingsCategory,
+};
 pub use input::SettingsAction;
 
 /// How long the "Settings saved" toast lingers before it auto-dismisses.
@@ -218,6 +220,23 @@ pub struct SettingsView {
     /// disturbs the keyboard cursor. Cleared on every keypress so
     /// hover doesn't linger after the user switches modalities.
     pub(super) mouse_pos: Option<(u16, u16)>,
+    /// Raw TOML edit handed off to `$EDITOR`, awaiting
+    /// [`Self::finish_raw_edit`] once the editor exits.
+    pub(super) raw_edit: Option<RawConfigEdit>,
+    /// Rows of the read-only effective config overlay (`v`). `Some` while
+    /// the overlay is open; resolved once on open from the live (possibly
+    /// unsaved) global, profile, and repo configs.
+    pub(super) effective_rows: Option<Vec<EffectiveRow>>,
+    /// Scroll offset (in rows) inside the effective config overlay.
+    pub(super) effective_scroll: usize,
+}
+
+/// A config file opened in `$EDITOR` from the settings view, with the
+/// contents it had beforehand so a rejected edit can be rolled back.
+pub(super) struct RawConfigEdit {
+    path: std::path::PathBuf,
+    /// `None` when the file did not exist before the edit.
+    previous: Option<String>,
 }
 
 impl SettingsView {
@@ -291,6 +310,9 @@ impl SettingsView {
             category_rects: Vec::new(),
             field_rects: Vec::new(),
             mouse_pos: None,
+            raw_edit: None,
+            effective_rows: None,
+            effective_scroll: 0,
         };
 
         // The constructor parks `selected_category` at 0, which is the
@@ -609,6 +631,7 @@ impl SettingsView {
                 crate::session::poller::set_session_id_poller_max_threads(
                     self.global_config.session.session_id_poller_max_threads,
                 );
+                crate::git::set_git_timeout_secs(self.global_config.worktree.git_timeout_secs);
                 // Reconcile the on-disk install id with the saved opt-in
                 // state: generate one when enabled, delete it on opt-out.
                 // Idempotent, so running it on every global save is safe.
@@ -634,6 +657,92 @@ impl SettingsView {
         Ok(())
     }
 
+    /// Start editing the current scope's config file as raw TOML. Returns the
+    /// path to open in `$EDITOR`, or `None` (with `error_message` set) when
+    /// the edit can't start. Unsaved structured edits must be saved or
+    /// discarded first so the reload can't silently drop them.
+    pub(super) fn begin_raw_edit(&mut self) -> Option<std::path::PathBuf> {
+        if self.has_changes {
+            self.error_message =
+                Some("Save or discard your changes before editing the raw config".to_string());
+            return None;
+        }
+        let path = match self.scope {
+            SettingsScope::Global => crate::session::config::config_path(),
+            SettingsScope::Profile => {
+                crate::session::profile_config::get_profile_config_path(&self.profile)
+            }
+            SettingsScope::Repo => {
+                self.error_message = Some(
+                    "Raw editing covers Global and Profile; edit the repo's .agent-of-empires/config.toml or .aoe.toml directly"
+                        .to_string(),
+                );
+                return None;
+            }
+        };
+        let path = match path {
+            Ok(p) => p,
+            Err(e) => {
+                self.error_message = Some(format!("Failed to locate config file: {e}"));
+                return None;
+            }
+        };
+        let previous = std::fs::read_to_string(&path).ok();
+        self.raw_edit = Some(RawConfigEdit {
+            path: path.clone(),
+            previous,
+        });
+        Some(path)
+    }
+
+    /// Reload the config after a raw edit. A file that fails to parse or
+    /// type-check is rolled back to its previous contents (the rejected text
+    /// is kept next to it as `config.toml.rejected`) and the view keeps its
+    /// prior state. No-op when no raw edit is pending.
+    pub fn finish_raw_edit(&mut self) {
+        let Some(edit) = self.raw_edit.take() else {
+            return;
+        };
+        let reloaded = match self.scope {
+            SettingsScope::Global => Config::load().map(|c| self.global_config = c),
+            SettingsScope::Profile => {
+                load_profile_config(&self.profile).map(|c| self.profile_config = c)
+            }
+            SettingsScope::Repo => return,
+        };
+        match reloaded {
+            Ok(()) => {
+                self.resolved_base =
+                    merge_configs(self.global_config.clone(), &self.profile_config);
+                self.snapshot_baseline();
+                self.rebuild_fields();
+                self.error_message = None;
+                self.success_message = Some("Config reloaded".to_string());
+                self.success_message_expires_at =
+                    Some(std::time::Instant::now() + SUCCESS_MESSAGE_TTL);
+            }
+            Err(e) => {
+                let rejected = edit.path.with_extension("toml.rejected");
+                if let Ok(text) = std::fs::read_to_string(&edit.path) {
+                    if let Err(e) = std::fs::write(&rejected, text) {
+                        tracing::warn!(target: "tui.settings", "Failed to keep rejected config edit: {e}");
+                    }
+                }
+                let restored = match &edit.previous {
+                    Some(text) => crate::session::atomic_write(&edit.path, text.as_bytes()),
+                    None => std::fs::remove_file(&edit.path).map_err(Into::into),
+                };
+                if let Err(e) = restored {
+                    tracing::warn!(target: "tui.settings", "Failed to restore config after rejected edit: {e}");
+                }
+                self.error_message = Some(format!(
+                    "Edit rejected, previous config kept ({e}). Your edit is in {}",
+                    rejected.display()
+                ));
+            }
+        }
+    }
+
     /// Drop the transient "Settings saved" toast once its window passes, so it
     /// fades even when the user leaves the keyboard idle. Returns whether the
     /// toast was cleared so the caller can request a redraw. Errors are sticky
@@ -660,6 +769,20 @@ impl SettingsView {
             || self.list_edit_state.is_some()
             || self.custom_instruction_dialog.is_some()
             || self.search_input.is_some()
+            || self.effective_rows.is_some()
+    }
+
+    /// Open the read-only effective config overlay for the current profile
+    /// (plus the repo layer when a repo config exists). Uses the configs as
+    /// currently edited, so unsaved changes show up too.
+    pub(super) fn open_effective_view(&mut self) {
+        let repo = self.repo_config.is_some().then_some(&self.repo_as_profile);
+        self.effective_rows = Some(fields::effective_rows(
+            &self.global_config,
+            &self.profile_config,
+            repo,
+        ));
+        self.effective_scroll = 0;
     }
 
     /// Open the settings-wide search overlay. Builds the initial hit
@@ -842,4 +965,49 @@ mod dirty_tracking_tests {
             "the post-save baseline tracks the saved value"
         );
     }
+
+    /// A valid raw edit is adopted as the new clean state; an invalid one is
+    /// rolled back on disk and leaves the in-memory config untouched.
+    #[test]
+    #[serial]
+    fn raw_edit_reloads_valid_toml_and_rolls_back_invalid() {
+        let (_temp, mut view) = fresh_view();
+        view.scope = SettingsScope::Profile;
+
+        let path = view.begin_raw_edit().expect("profile config is editable");
+        std::fs::write(&path, "description = \"edited by hand\"\n").unwrap();
+        view.finish_raw_edit();
+        assert_eq!(
+            view.profile_config.description.as_deref(),
+            Some("edited by hand")
+        );
+        assert!(!view.has_changes, "the reloaded file is the new baseline");
+        assert!(view.error_message.is_none());
+
+        let path = view.begin_raw_edit().unwrap();
+        std::fs::write(&path, "[worktree]\nenabled = \"yes\"\n").unwrap();
+        view.finish_raw_edit();
+        assert!(view.error_message.is_some(), "invalid edit is reported");
+        assert_eq!(
+            view.profile_config.description.as_deref(),
+            Some("edited by hand"),
+            "prior config is kept"
+        );
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("edited by hand"));
+        assert!(path.with_extension("toml.rejected").exists());
+    }
+
+    /// Raw editing would reload over pending structured edits, so it refuses
+    /// to start until they are saved or discarded.
+    #[test]
+    #[serial]
+    fn raw_edit_refuses_with_unsaved_changes() {
+        let (_temp, mut view) = fresh_view();
+        view.global_config.default_profile = "other".to_string();
+        view.recompute_dirty();
+        assert!(view.begin_raw_edit().is_none());
+        assert!(view.error_message.is_some());
+    }
 }
@@ -13,9 +13,12 @@ use ratatui::{
 use tui_input::Input;
 use unicode_width::UnicodeWidthStr;
 
+use super::fields::ListItemValidation;
 use super::{
-    CategoryRow, FieldValue, SettingsCategory, SettingsFocus, SettingsScope, SettingsView,
+    CategoryRow, ConfigSource, FieldValue, SettingsCategory, SettingsFocus, SettingsScope,
+    SettingsView,
 };
+use crate::session::{preview_env_entry, EnvEntryPreview};
 use crate::tui::components::set_input_cursor_position;
 use crate::tui::styles::Theme;
 
@@ -154,6 +157,10 @@ impl SettingsView {
             self.render_help_overlay(frame, area, theme);
         }
 
+        if self.effective_rows.is_some() {
+            self.render_effective_overlay(frame, area, theme);
+        }
+
         // Render the search overlay last so it sits above every other
         // surface (help, dialogs, etc.). The input handler already
         // gates other key dispatch on `search_input.is_some()`, but
@@ -950,6 +957,12 @@ impl SettingsView {
             ]);
             frame.render_widget(Paragraph::new(header), header_area);
 
+            // Environment entries show whether their `$VAR` references
+            // resolve on this host (never the values), so unresolved ones are
+            // visible before a session starts.
+            let show_env_preview =
+                self.fields[index].list_item_validation() == ListItemValidation::EnvEntry;
+
             // Render items
             for (i, item) in items.iter().enumerate() {
                 let item_y = area.y + 2 + i as u16;
@@ -985,6 +998,30 @@ impl SettingsView {
                     .filter(|_| i == list_state.selected_index && !list_state.adding_new)
                 {
                     self.render_list_item_with_cursor(frame, item_area, prefix, input, theme);
+                } else if show_env_preview {
+                    let preview = match preview_env_entry(item, |name| {
+                        std::env::var_os(name).is_some()
+                    }) {
+                        EnvEntryPreview::Value(v) => {
+                            Span::styled(format!("  → {}", v), Style::default().fg(theme.dimmed))
+                        }
+                        EnvEntryPreview::Set(name) => Span::styled(
+                            format!("  (${} set)", name),
+                            Style::default().fg(theme.dimmed),
+                        ),
+                        EnvEntryPreview::Unset(name) if name.is_empty() => {
+                            Span::styled("  (no variable name)", Style::default().fg(theme.waiting))
+                        }
+                        EnvEntryPreview::Unset(name) => Span::styled(
+                            format!("  (${} unset)", name),
+                            Style::default().fg(theme.waiting),
+                        ),
+                    };
+                    let line = Line::from(vec![
+                        Span::styled(format!("{}{}", prefix, item), style),
+                        preview,
+                    ]);
+                    frame.render_widget(Paragraph::new(line), item_area);
                 } else {
                     let display = format!("{}{}", prefix, item);
                     frame.render_widget(Paragraph::new(display).style(style), item_area);
@@ -1144,7 +1181,7 @@ impl SettingsView {
 
     fn render_help_overlay(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let dialog_width = 58u16;
-        let dialog_height = 28u16;
+        let dialog_height = 30u16;
 
         let x = area.x + (area.width.saturating_sub(dialog_width)) / 2;
         let y = area.y + (area.height.saturating_sub(dialog_height)) / 2;
@@ -1211,7 +1248,9 @@ impl SettingsView {
                 "Other",
                 vec![
                     ("/", "Search settings across all tabs"),
+                    ("v", "View effective config and its sources"),
                     ("Ctrl+s", "Save settings"),
+                    ("Ctrl+e", "Edit raw config in $EDITOR"),
                     ("?", "Toggle this help"),
                     ("q", "Close settings"),
                 ],
@@ -1362,6 +1401,88 @@ impl SettingsView {
         ]);
         frame.render_widget(Paragraph::new(footer), layout[3]);
     }
+
+    /// Render the read-only effective config overlay: one row per setting
+    /// with its resolved value and the layer it came from.
+    fn render_effective_overlay(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let Some(rows) = self.effective_rows.as_ref() else {
+            return;
+        };
+        let dialog_width = area.width.saturating_sub(8).clamp(40, 110);
+        let dialog_height = area.height.saturating_sub(4).clamp(10, 40);
+
+        let x = area.x + (area.width.saturating_sub(dialog_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(dialog_height)) / 2;
+        let dialog_area = Rect {
+            x,
+            y,
+            width: dialog_width.min(area.width),
+            height: dialog_height.min(area.height),
+        };
+
+        frame.render_widget(Clear, dialog_area);
+
+        let title = if self.repo_config.is_some() {
+            format!(" Effective config: {} + repo ", self.profile)
+        } else {
+            format!(" Effective config: {} ", self.profile)
+        };
+        let block = Block::default()
+            .style(Style::default().bg(theme.background))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.accent))
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+
+        let key_width = rows.iter().map(|r| r.key.width()).max().unwrap_or(0);
+        let visible = layout[0].height as usize;
+        let scroll_start = self
+            .effective_scroll
+            .min(rows.len().saturating_sub(visible));
+        let lines: Vec<Line> = rows
+            .iter()
+            .skip(scroll_start)
+            .take(visible)
+            .map(|row| {
+                let source_style = match row.source {
+                    ConfigSource::Default => Style::default().fg(theme.dimmed),
+                    ConfigSource::Global => Style::default().fg(theme.text),
+                    ConfigSource::Profile | ConfigSource::Repo => Style::default()
+                        .fg(theme.waiting)
+                        .add_modifier(Modifier::BOLD),
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:8} ", row.source.label()), source_style),
+                    Span::styled(
+                        format!("{:key_width$}  ", row.key),
+                        Style::default().fg(theme.dimmed),
+                    ),
+                    Span::styled(row.value.clone(), Style::default().fg(theme.text)),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), layout[0]);
+
+        let footer = Line::from(vec![
+            Span::styled("↑/↓ ", Style::default().fg(theme.waiting)),
+            Span::styled("scroll  ", Style::default().fg(theme.dimmed)),
+            Span::styled("Esc ", Style::default().fg(theme.waiting)),
+            Span::styled("close", Style::default().fg(theme.dimmed)),
+        ]);
+        frame.render_widget(Paragraph::new(footer), layout[1]);
+    }
 }
 
 #[cfg(test)]
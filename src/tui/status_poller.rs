@@ -12,8 +12,12 @@
 //!    based on their status. Hot (Running/Waiting/Starting) every cycle, Warm
 //!    (Idle/Unknown) every 5 cycles, Cold (Error) every 60 cycles, Frozen
 //!    (Stopped/Deleting) never.
+//!
+//! The poller also fetches the selected session's full pane list for the
+//! preview header, so its `tmux list-panes` never runs on the render path.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
@@ -27,6 +31,25 @@ const TIER_HOT: u64 = 1;
 const TIER_WARM: u64 = 5;
 const TIER_COLD: u64 = 60;
 
+/// How long the selected session's pane list is trusted before the poller
+/// refetches it. Windows and panes change rarely, so this only needs to keep
+/// up with a user splitting a pane by hand.
+const PANE_INFO_TTL: Duration = Duration::from_secs(2);
+
+/// Set while the TUI is blocked inside `tmux attach` with
+/// `session.pause_refresh_while_attached` on. The background poller skips
+/// its passes and the attached hook watcher skips container health, so no
+/// docker or tmux listing spawns compete with the attached terminal.
+static REFRESH_PAUSED: AtomicBool = AtomicBool::new(false);
+
+pub(super) fn set_refresh_paused(paused: bool) {
+    REFRESH_PAUSED.store(paused, Ordering::Relaxed);
+}
+
+pub(super) fn refresh_paused() -> bool {
+    REFRESH_PAUSED.load(Ordering::Relaxed)
+}
+
 fn polling_tier(status: Status) -> u64 {
     match status {
         Status::Running | Status::Waiting | Status::Starting => TIER_HOT,
@@ -36,6 +59,60 @@ fn polling_tier(status: Status) -> u64 {
     }
 }
 
+/// Whether `inst` is polled this cycle and its row depends on container
+/// health: a sandboxed session that is neither transitioning nor parked by
+/// the idle container reaper.
+fn needs_container_check(inst: &Instance, cycle: u64) -> bool {
+    let tier = polling_tier(inst.status);
+    tier != 0
+        && cycle % tier == 0
+        && inst.is_sandboxed()
+        && inst.container_idle_stopped_at.is_none()
+        && !matches!(
+            inst.status,
+            Status::Stopped | Status::Deleting | Status::Starting | Status::Creating
+        )
+}
+
+/// Refresh container health only when a sandboxed session is due for a
+/// container check this cycle, so host-only setups never touch the container
+/// runtime. The whole batch is kept: the 5s throttle can skip the cycle a
+/// warm-tier session is next due on, and it must still find its entry.
+fn refresh_container_states(
+    instances: &[Instance],
+    state: &mut StatusPollState,
+    probe: impl FnOnce() -> HashMap<String, bool>,
+) {
+    let any_due = instances
+        .iter()
+        .any(|inst| needs_container_check(inst, state.cycle_count));
+    if any_due && state.last_container_check.elapsed() >= state.container_check_interval {
+        state.container_states = probe();
+        state.last_container_check = Instant::now();
+    }
+}
+
+/// Session whose pane list the poller should fetch alongside a status
+/// refresh, for the preview header's `Panes:` row.
+#[derive(Debug, Clone)]
+pub struct PaneTarget {
+    pub id: String,
+    pub session_name: String,
+}
+
+/// Pane list fetched for a [`PaneTarget`].
+#[derive(Debug, Clone)]
+pub struct PaneInfoUpdate {
+    pub id: String,
+    pub panes: Vec<crate::tmux::PaneInfo>,
+}
+
+/// Whether the pane list for `id` must be refetched: the target changed or
+/// the last fetch is older than [`PANE_INFO_TTL`].
+fn pane_info_due(last: Option<&(String, Instant)>, id: &str) -> bool {
+    !last.is_some_and(|(cached, at)| cached == id && at.elapsed() < PANE_INFO_TTL)
+}
+
 /// Result of a status check for a single session
 #[derive(Debug, Clone)]
 pub struct StatusUpdate {
@@ -98,31 +175,36 @@ pub(super) fn poll_statuses_once(
         tier != 0 && state.cycle_count % tier == 0
     });
 
+    // One tmux spawn serves the whole pass: the pane listing also refreshes
+    // the session cache every `exists()` check below reads from.
     let pane_metadata = if any_pollable {
-        crate::tmux::refresh_session_cache();
         crate::tmux::batch_pane_metadata().unwrap_or_default()
     } else {
         HashMap::new()
     };
 
-    // Refresh container health if any sandboxed session exists and interval elapsed
-    let has_sandboxed = if any_pollable {
-        let sandboxed = instances.iter().any(|i| i.is_sandboxed());
-        if sandboxed && state.last_container_check.elapsed() >= state.container_check_interval {
-            state.container_states = crate::containers::batch_container_health();
-            state.last_container_check = Instant::now();
-        }
-        sandboxed
-    } else {
-        false
-    };
+    if !refresh_paused() {
+        refresh_container_states(&instances, state, crate::containers::batch_container_health);
+    }
+    let has_sandboxed = any_pollable && instances.iter().any(|i| i.is_sandboxed());
 
     // Periodically re-sync sandbox credentials from the macOS Keychain
     // so long-lived sessions don't lose auth mid-run.
-    if has_sandboxed && state.last_credential_refresh.elapsed() >= state.credential_refresh_interval
+    if has_sandboxed
+        && !refresh_paused()
+        && state.last_credential_refresh.elapsed() >= state.credential_refresh_interval
     {
         state.last_credential_refresh = Instant::now();
-        crate::session::container_config::refresh_agent_configs();
+        // Each profile can set its own sync excludes; refreshing the shared
+        // sandbox dirs once per profile only ever adds files.
+        let profiles: std::collections::BTreeSet<String> = instances
+            .iter()
+            .filter(|i| i.is_sandboxed())
+            .map(|i| i.effective_profile())
+            .collect();
+        for profile in &profiles {
+            crate::session::container_config::refresh_agent_configs(profile);
+        }
     }
 
     instances
@@ -136,12 +218,7 @@ pub(super) fn poll_statuses_once(
 
             // For sandboxed sessions, check if the container is dead before
             // falling through to tmux-based status detection.
-            if inst.is_sandboxed()
-                && !matches!(
-                    inst.status,
-                    Status::Stopped | Status::Deleting | Status::Starting | Status::Creating
-                )
-            {
+            if needs_container_check(&inst, state.cycle_count) {
                 if let Some(sandbox) = &inst.sandbox_info {
                     if let Some(&running) = state.container_states.get(&sandbox.container_name) {
                         if !running {
@@ -181,35 +258,63 @@ pub(super) fn poll_statuses_once(
 
 /// Background thread that polls session status without blocking the UI
 pub struct StatusPoller {
-    request_tx: mpsc::Sender<Vec<Instance>>,
+    request_tx: mpsc::Sender<(Vec<Instance>, Option<PaneTarget>)>,
     result_rx: mpsc::Receiver<Vec<StatusUpdate>>,
+    pane_rx: mpsc::Receiver<PaneInfoUpdate>,
     _handle: thread::JoinHandle<()>,
 }
 
 impl StatusPoller {
     pub fn new() -> Self {
-        let (request_tx, request_rx) = mpsc::channel::<Vec<Instance>>();
+        let (request_tx, request_rx) = mpsc::channel::<(Vec<Instance>, Option<PaneTarget>)>();
         let (result_tx, result_rx) = mpsc::channel::<Vec<StatusUpdate>>();
+        let (pane_tx, pane_rx) = mpsc::channel::<PaneInfoUpdate>();
 
         let handle = thread::spawn(move || {
-            Self::polling_loop(request_rx, result_tx);
+            Self::polling_loop(request_rx, result_tx, pane_tx);
         });
 
         Self {
             request_tx,
             result_rx,
+            pane_rx,
             _handle: handle,
         }
     }
 
     fn polling_loop(
-        request_rx: mpsc::Receiver<Vec<Instance>>,
+        request_rx: mpsc::Receiver<(Vec<Instance>, Option<PaneTarget>)>,
         result_tx: mpsc::Sender<Vec<StatusUpdate>>,
+        pane_tx: mpsc::Sender<PaneInfoUpdate>,
     ) {
         let mut state = StatusPollState::new();
-
-        while let Ok(instances) = request_rx.recv() {
-            let updates = poll_statuses_once(instances, &mut state);
+        let mut last_panes: Option<(String, Instant)> = None;
+
+        while let Ok((instances, pane_target)) = request_rx.recv() {
+            // A request queued just before an attach would otherwise run
+            // its subprocesses against the attached terminal.
+            let paused = refresh_paused();
+            let updates = if paused {
+                Vec::new()
+            } else {
+                poll_statuses_once(instances, &mut state)
+            };
+
+            if let Some(target) = pane_target.filter(|_| !paused) {
+                if pane_info_due(last_panes.as_ref(), &target.id) {
+                    let panes = crate::tmux::list_panes(&target.session_name);
+                    last_panes = Some((target.id.clone(), Instant::now()));
+                    if pane_tx
+                        .send(PaneInfoUpdate {
+                            id: target.id,
+                            panes,
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
 
             if result_tx.send(updates).is_err() {
                 break;
@@ -217,9 +322,10 @@ impl StatusPoller {
         }
     }
 
-    /// Request a status refresh for all given instances (non-blocking).
-    pub fn request_refresh(&self, instances: Vec<Instance>) {
-        let _ = self.request_tx.send(instances);
+    /// Request a status refresh for all given instances (non-blocking),
+    /// plus the pane list of `pane_target` when it is due.
+    pub fn request_refresh(&self, instances: Vec<Instance>, pane_target: Option<PaneTarget>) {
+        let _ = self.request_tx.send((instances, pane_target));
     }
 
     /// Try to receive status updates without blocking.
@@ -227,6 +333,11 @@ impl StatusPoller {
     pub fn try_recv_updates(&self) -> Option<Vec<StatusUpdate>> {
         self.result_rx.try_recv().ok()
     }
+
+    /// Try to receive a refreshed pane list without blocking.
+    pub fn try_recv_panes(&self) -> Option<PaneInfoUpdate> {
+        self.pane_rx.try_recv().ok()
+    }
 }
 
 impl Default for StatusPoller {
@@ -259,6 +370,16 @@ mod tests {
         assert_eq!(update.idle_entered_at, Some(ts));
     }
 
+    #[test]
+    fn pane_info_refetches_on_new_target_or_after_ttl() {
+        assert!(pane_info_due(None, "a"));
+        let fresh = ("a".to_string(), Instant::now());
+        assert!(!pane_info_due(Some(&fresh), "a"));
+        assert!(pane_info_due(Some(&fresh), "b"));
+        let stale = ("a".to_string(), Instant::now() - PANE_INFO_TTL);
+        assert!(pane_info_due(Some(&stale), "a"));
+    }
+
     #[test]
     fn test_polling_tier_hot() {
         assert_eq!(polling_tier(Status::Running), TIER_HOT);
@@ -307,4 +428,121 @@ mod tests {
         assert_eq!(first_cycle % TIER_WARM, 0, "first cycle must poll warm");
         assert_eq!(first_cycle % TIER_COLD, 0, "first cycle must poll cold");
     }
+
+    #[test]
+    fn paused_poller_answers_without_polling() {
+        let poller = StatusPoller::new();
+        let mut inst = Instance::new("paused-poll", "/tmp/paused-poll");
+        inst.status = Status::Running;
+
+        set_refresh_paused(true);
+        poller.request_refresh(vec![inst], None);
+        let updates = poller
+            .result_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("paused poller still answers the request");
+        set_refresh_paused(false);
+
+        assert!(updates.is_empty());
+    }
+
+    #[test]
+    fn poll_pass_skips_container_runtime_without_due_sandboxed_sessions() {
+        let mut host = Instance::new("host-only", "/tmp/host-only");
+        host.status = Status::Running;
+        let mut state = StatusPollState::new();
+
+        let before = crate::containers::health_probes();
+        poll_statuses_once(vec![host.clone()], &mut state);
+        assert_eq!(
+            crate::containers::health_probes(),
+            before,
+            "host-only sessions must never invoke the container runtime"
+        );
+
+        // A stopped sandboxed session has no container health to report.
+        let mut parked = Instance::new("parked", "/tmp/parked");
+        parked.status = Status::Stopped;
+        parked.sandbox_info = Some(crate::session::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: "aoe-sandbox-parked".to_string(),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        });
+        poll_statuses_once(vec![host, parked], &mut state);
+        assert_eq!(crate::containers::health_probes(), before);
+    }
+
+    fn sandboxed(title: &str, status: Status) -> Instance {
+        let mut inst = Instance::new(title, "/tmp/sandboxed");
+        inst.status = status;
+        inst.sandbox_info = Some(crate::session::SandboxInfo {
+            enabled: true,
+            container_id: None,
+            image: "ubuntu:latest".to_string(),
+            container_name: format!("aoe-sandbox-{title}"),
+            extra_env: None,
+            custom_instruction: None,
+            cpu_limit: None,
+            memory_limit: None,
+        });
+        inst
+    }
+
+    #[test]
+    fn idle_session_keeps_health_from_a_refresh_it_was_not_due_for() {
+        let running = sandboxed("running", Status::Running);
+        let idle = sandboxed("idle", Status::Idle);
+        let instances = vec![running, idle.clone()];
+        let mut state = StatusPollState::new();
+
+        // Cycle 1: only the hot session is due, and it triggers the refresh.
+        state.cycle_count = 1;
+        assert!(!needs_container_check(&idle, state.cycle_count));
+        refresh_container_states(&instances, &mut state, || {
+            HashMap::from([
+                ("aoe-sandbox-running".to_string(), true),
+                ("aoe-sandbox-idle".to_string(), false),
+            ])
+        });
+
+        // Cycle 5: the idle session is due, but the throttle skips the probe.
+        state.cycle_count = TIER_WARM;
+        assert!(needs_container_check(&idle, state.cycle_count));
+        refresh_container_states(&instances, &mut state, || {
+            panic!("throttled cycle must not probe")
+        });
+        assert_eq!(state.container_states.get("aoe-sandbox-idle"), Some(&false));
+    }
+
+    #[test]
+    fn full_poll_pass_probes_tmux_existence_once() {
+        // Skip where tmux is missing or has no server: the pass would fall
+        // back to per-session probes, which is the degraded path by design.
+        if crate::tmux::batch_pane_metadata().is_err() {
+            return;
+        }
+
+        let instances: Vec<Instance> = (0..8)
+            .map(|i| {
+                let mut inst = Instance::new(&format!("poll-probe-{i}"), "/tmp/poll-probe");
+                inst.status = Status::Idle;
+                inst
+            })
+            .collect();
+        let mut state = StatusPollState::new();
+
+        let before = crate::tmux::existence_probes();
+        let updates = poll_statuses_once(instances, &mut state);
+        assert_eq!(updates.len(), 8);
+        assert_eq!(
+            crate::tmux::existence_probes() - before,
+            1,
+            "one tmux listing should serve existence for every session"
+        );
+    }
 }
@@ -10,7 +10,7 @@ use std::sync::mpsc;
 use std::thread;
 
 use crate::session::stop::perform_stop;
-pub use crate::session::stop::{StopRequest, StopResult};
+pub use crate::session::stop::{StopMode, StopRequest, StopResult};
 
 pub struct StopPoller {
     request_tx: mpsc::Sender<StopRequest>,
@@ -82,6 +82,7 @@ mod tests {
         poller.request_stop(StopRequest {
             session_id: session_id.clone(),
             instance,
+            mode: StopMode::Session,
         });
 
         let mut result = None;
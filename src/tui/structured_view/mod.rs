@@ -35,7 +35,7 @@ use crate::acp::client::{
 };
 use crate::acp::elicitations::ElicitationResolution;
 use crate::acp::protocol::ApprovalDecisionWire;
-use crate::session::config::{resolve_theme_name, resolve_theme_palette_mode};
+use crate::session::config::resolve_theme_name;
 use crate::tui::styles::Theme;
 
 /// Per-keystroke redraw interval. The animations are minimal (just the
@@ -79,8 +79,7 @@ pub async fn run_standalone(session_id: &str) -> anyhow::Result<()> {
     let mut terminal = Terminal::new(backend)?;
     let mut event_stream = EventStream::new();
     let theme_name = resolve_theme_name();
-    let palette_mode = resolve_theme_palette_mode();
-    let theme = crate::tui::styles::load_theme_with_mode(&theme_name, palette_mode);
+    let theme = crate::tui::styles::load_configured_theme(&theme_name);
 
     let result = run(&mut terminal, &mut event_stream, &theme, session_id).await;
 
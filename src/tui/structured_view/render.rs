@@ -49,6 +49,7 @@ pub fn render(frame: &mut Frame, area: Rect, theme: &Theme, state: &StructuredVi
     } else if state.mention.is_some() {
         render_mention_picker(frame, chunks[3], theme, state);
     }
+    theme.apply_monochrome(frame.buffer_mut());
 }
 
 /// Up to this many queued prompts are previewed in the strip; the rest
@@ -1239,7 +1240,7 @@ mod tests {
         state.composer.insert_str("/comp");
         assert!(state.slash_picker_open());
 
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).expect("terminal");
         terminal
@@ -1277,7 +1278,7 @@ mod tests {
         state.move_slash_selection(last as i32);
         let last_name = state.slash_matches()[last].name.clone();
 
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let backend = TestBackend::new(40, 9);
         let mut terminal = Terminal::new(backend).expect("terminal");
         terminal
@@ -1432,7 +1433,7 @@ mod tests {
     }
 
     fn render_dump(state: &StructuredViewState, w: u16, h: u16) -> String {
-        let theme = crate::tui::styles::load_theme_with_mode("empire", false);
+        let theme = crate::tui::styles::load_theme("empire");
         let backend = TestBackend::new(w, h);
         let mut terminal = Terminal::new(backend).expect("terminal");
         terminal
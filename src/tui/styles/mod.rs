@@ -3,11 +3,13 @@
 //! The module is split into:
 //!   - `themes`: the `Theme` struct, its `Default` (Empire mirror), and palette downsampling
 //!   - `palette`: 24-bit RGB -> xterm-256 downsampling for `palette_mode`
+//!   - `monochrome`: the colorless theme and frame pass for `NO_COLOR`
 //!   - this file: builtin TOML embedding, custom theme discovery, load/serialize glue
 //!
 //! Public surface is re-exported here so callers keep `crate::tui::styles::*`.
 
 mod contrast;
+mod monochrome;
 mod palette;
 #[cfg(feature = "serve")]
 mod resolved;
@@ -20,6 +22,7 @@ pub use resolved::{resolve_theme, ResolvedTheme};
 pub use themes::ThemeAppearance;
 pub use themes::{idle_decay_window, Theme};
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use tracing::{debug, warn};
 
@@ -64,6 +67,14 @@ pub const BUILTIN_THEMES: &[BuiltinTheme] = &[
         name: "deep-ocean",
         source: include_str!("../../../themes/builtin/deep-ocean.toml"),
     },
+    BuiltinTheme {
+        name: "solarized",
+        source: include_str!("../../../themes/builtin/solarized.toml"),
+    },
+    BuiltinTheme {
+        name: "high-contrast",
+        source: include_str!("../../../themes/builtin/high-contrast.toml"),
+    },
 ];
 
 /// Iterator over builtin theme names, in declared order.
@@ -78,7 +89,9 @@ pub fn is_builtin_theme(name: &str) -> bool {
 
 /// Return the directory where custom theme TOML files are stored.
 pub fn custom_themes_dir() -> Option<PathBuf> {
-    crate::session::get_app_dir().ok().map(|d| d.join("themes"))
+    crate::session::get_config_dir()
+        .ok()
+        .map(|d| d.join("themes"))
 }
 
 /// Discover custom theme names from the themes directory.
@@ -177,14 +190,32 @@ pub fn load_theme(name: &str) -> Theme {
     parse_builtin(default)
 }
 
-/// Load a theme and, when `palette_mode` is true, convert every `Color::Rgb`
-/// field to `Color::Indexed` (nearest xterm-256 index). Use this from callers
-/// that have access to `ThemeConfig::color_mode`. Hex strings in the embedded
-/// and custom TOMLs deserialize to `Color::Rgb`; `palette_mode` consumers need
-/// xterm-256 `Color::Indexed`, so the downsample runs at the `Theme` level
-/// after parsing.
-pub fn load_theme_with_mode(name: &str, palette_mode: bool) -> Theme {
+/// Load `name` the way the user configured it: the global `[theme.overrides]`
+/// layered on, then downsampled when `color_mode` is palette. Monochrome mode
+/// (`color_mode = "monochrome"` or `NO_COLOR`) ignores `name` entirely. TUI
+/// entry points and the Settings live preview all go through here so every
+/// surface agrees. Reads the global config only; the theme is not
+/// profile-overridable (see `config::resolve_theme_name`).
+pub fn load_configured_theme(name: &str) -> Theme {
+    let config = crate::session::Config::load_or_warn();
+    if config.theme_monochrome() {
+        return Theme::monochrome();
+    }
+    load_theme_with_mode(name, &config.theme.overrides, config.theme_palette_mode())
+}
+
+/// Load a theme, apply per-field `overrides`, and when `palette_mode` is true
+/// convert every `Color::Rgb` field to `Color::Indexed` (nearest xterm-256
+/// index). Hex strings in the embedded and custom TOMLs (and in overrides)
+/// deserialize to `Color::Rgb`; `palette_mode` consumers need xterm-256
+/// `Color::Indexed`, so the downsample runs last, at the `Theme` level.
+fn load_theme_with_mode(
+    name: &str,
+    overrides: &BTreeMap<String, String>,
+    palette_mode: bool,
+) -> Theme {
     let mut theme = load_theme(name);
+    theme.apply_overrides(overrides);
     if palette_mode {
         theme.downsample_to_palette();
     }
@@ -204,13 +235,20 @@ mod tests {
 
     #[test]
     fn load_theme_with_mode_palette_yields_indexed() {
-        let theme = load_theme_with_mode("empire", true);
+        let theme = load_theme_with_mode("empire", &BTreeMap::new(), true);
         assert!(matches!(theme.title, Color::Indexed(_)));
     }
 
+    #[test]
+    fn load_theme_with_mode_downsamples_overrides_too() {
+        let overrides = BTreeMap::from([("title".to_string(), "#ff8700".to_string())]);
+        let theme = load_theme_with_mode("empire", &overrides, true);
+        assert_eq!(theme.title, Color::Indexed(208));
+    }
+
     #[test]
     fn load_theme_with_mode_truecolor_yields_rgb() {
-        let theme = load_theme_with_mode("empire", false);
+        let theme = load_theme_with_mode("empire", &BTreeMap::new(), false);
         assert!(matches!(theme.title, Color::Rgb(_, _, _)));
     }
 
@@ -262,6 +300,16 @@ mod tests {
             Color::Rgb(0x0f, 0x11, 0x1a),
             Color::Rgb(0x84, 0xff, 0xff),
         ),
+        (
+            "solarized",
+            Color::Rgb(0x00, 0x2b, 0x36),
+            Color::Rgb(0x26, 0x8b, 0xd2),
+        ),
+        (
+            "high-contrast",
+            Color::Rgb(0x00, 0x00, 0x00),
+            Color::Rgb(0xff, 0xff, 0x00),
+        ),
     ];
 
     #[test]
@@ -454,7 +502,7 @@ border = "#414868"
 
     #[test]
     fn test_builtin_themes_count() {
-        assert_eq!(BUILTIN_THEMES.len(), 8);
+        assert_eq!(BUILTIN_THEMES.len(), 10);
         let names: Vec<&str> = builtin_theme_names().collect();
         assert!(names.contains(&"zinc"));
         assert!(names.contains(&"empire"));
@@ -464,6 +512,8 @@ border = "#414868"
         assert!(names.contains(&"dracula"));
         assert!(names.contains(&"rose-pine"));
         assert!(names.contains(&"deep-ocean"));
+        assert!(names.contains(&"solarized"));
+        assert!(names.contains(&"high-contrast"));
     }
 
     #[test]
@@ -0,0 +1,134 @@
+//! Monochrome rendering for `color_mode = "monochrome"` and `NO_COLOR`.
+//!
+//! Widgets keep styling spans with `Theme` colors, so instead of teaching
+//! every render path a second emphasis scheme, the monochrome theme paints
+//! each emphasis role with a sentinel color and [`Theme::apply_monochrome`]
+//! rewrites the finished frame: every color becomes the terminal default and
+//! the sentinels become modifiers. Errors read bold + underlined, focus,
+//! titles and anything that wants attention read bold, and highlighted
+//! backgrounds (list selection, badges) read as reverse video.
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+
+use super::themes::ThemeSyntax;
+use super::Theme;
+
+// Sentinels, one per emphasis role. Never reach the terminal: the frame pass
+// replaces them before ratatui diffs the buffer. Odd near-black RGB values so
+// colors parsed out of agent output in the preview pane (stripped by the same
+// pass) practically never alias a role.
+const STRONG: Color = Color::Rgb(1, 2, 3);
+const ALERT: Color = Color::Rgb(1, 2, 4);
+const SELECTED: Color = Color::Rgb(1, 2, 5);
+
+impl Theme {
+    /// The theme monochrome mode forces regardless of the selected name.
+    /// Plain roles use the terminal's own foreground and background; emphasis
+    /// roles carry the sentinels [`Theme::apply_monochrome`] translates.
+    pub fn monochrome() -> Self {
+        Self {
+            background: Color::Reset,
+            border: Color::Reset,
+            terminal_border: STRONG,
+            selection: SELECTED,
+            session_selection: SELECTED,
+            title: STRONG,
+            text: Color::Reset,
+            dimmed: Color::Reset,
+            hint: Color::Reset,
+            running: Color::Reset,
+            waiting: STRONG,
+            fresh_idle: Color::Reset,
+            idle: Color::Reset,
+            error: ALERT,
+            terminal_active: STRONG,
+            group: STRONG,
+            search: STRONG,
+            accent: STRONG,
+            diff_add: Color::Reset,
+            diff_delete: Color::Reset,
+            diff_modified: Color::Reset,
+            diff_header: STRONG,
+            help_key: STRONG,
+            branch: Color::Reset,
+            sandbox: Color::Reset,
+            appearance: None,
+            syntax: ThemeSyntax::default(),
+            monochrome: true,
+        }
+    }
+
+    /// Strip color from a rendered frame, turning the monochrome sentinels
+    /// into bold / underline / reverse. No-op for color themes, so draw sites
+    /// call it unconditionally after rendering.
+    pub fn apply_monochrome(&self, buf: &mut Buffer) {
+        if !self.monochrome {
+            return;
+        }
+        for cell in &mut buf.content {
+            let mut cues = match cell.fg {
+                STRONG => Modifier::BOLD,
+                ALERT => Modifier::BOLD | Modifier::UNDERLINED,
+                _ => Modifier::empty(),
+            };
+            match cell.bg {
+                SELECTED | STRONG => cues |= Modifier::REVERSED,
+                ALERT => cues |= Modifier::REVERSED | Modifier::BOLD,
+                _ => {}
+            }
+            cell.fg = Color::Reset;
+            cell.bg = Color::Reset;
+            cell.underline_color = Color::Reset;
+            cell.modifier.insert(cues);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn apply_monochrome_maps_roles_to_modifiers() {
+        let theme = Theme::monochrome();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buf.set_string(0, 0, "e", Style::default().fg(theme.error));
+        buf.set_string(1, 0, "a", Style::default().fg(theme.accent));
+        buf.set_string(
+            2,
+            0,
+            "s",
+            Style::default().fg(theme.text).bg(theme.selection),
+        );
+        buf.set_string(3, 0, "p", Style::default().fg(Color::Rgb(200, 10, 10)));
+
+        theme.apply_monochrome(&mut buf);
+
+        let modifiers: Vec<Modifier> = buf.content.iter().map(|c| c.modifier).collect();
+        assert_eq!(
+            modifiers,
+            vec![
+                Modifier::BOLD | Modifier::UNDERLINED,
+                Modifier::BOLD,
+                Modifier::REVERSED,
+                Modifier::empty(),
+            ]
+        );
+        assert!(buf
+            .content
+            .iter()
+            .all(|c| c.fg == Color::Reset && c.bg == Color::Reset));
+    }
+
+    #[test]
+    fn apply_monochrome_leaves_color_themes_alone() {
+        let theme = Theme::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buf.set_string(0, 0, "x", Style::default().fg(theme.accent));
+        theme.apply_monochrome(&mut buf);
+        assert_eq!(buf.content[0].fg, theme.accent);
+    }
+}
@@ -1,9 +1,11 @@
 //! Built-in themes and the `Theme` palette struct.
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use super::palette::color_to_palette;
 
@@ -129,6 +131,11 @@ pub struct Theme {
     /// without a stray empty section.
     #[serde(default, skip_serializing_if = "ThemeSyntax::is_default")]
     pub syntax: ThemeSyntax,
+
+    /// Set only on [`Theme::monochrome`]; tells draw sites' frame pass to
+    /// strip color. Never read from or written to theme TOML.
+    #[serde(skip)]
+    pub monochrome: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -215,6 +222,7 @@ impl From<RawThemeDefaults> for Theme {
             sandbox: raw.sandbox,
             appearance: None,
             syntax: ThemeSyntax { shiki_theme: None },
+            monochrome: false,
         }
     }
 }
@@ -234,6 +242,43 @@ impl Default for Theme {
 }
 
 impl Theme {
+    /// Layer per-field color overrides (field name to hex, from
+    /// `[theme.overrides]`) onto this theme. Unknown fields and unparseable
+    /// colors are skipped with a warning so one typo does not discard the
+    /// rest.
+    pub fn apply_overrides(&mut self, overrides: &BTreeMap<String, String>) {
+        if overrides.is_empty() {
+            return;
+        }
+        let Ok(toml::Value::Table(mut table)) = toml::Value::try_from(&*self) else {
+            return;
+        };
+        for (field, color) in overrides {
+            // Color fields serialize as `#rrggbb`; anything else (unknown
+            // keys, `appearance`, `[syntax]`) is not overridable here.
+            if !matches!(table.get(field), Some(toml::Value::String(s)) if s.starts_with('#')) {
+                warn!(
+                    "Ignoring theme override for unknown color field '{}'",
+                    field
+                );
+                continue;
+            }
+            let mut candidate = table.clone();
+            candidate.insert(field.clone(), toml::Value::String(color.clone()));
+            if toml::Value::Table(candidate.clone())
+                .try_into::<Theme>()
+                .is_ok()
+            {
+                table = candidate;
+            } else {
+                warn!("Ignoring invalid theme override {} = '{}'", field, color);
+            }
+        }
+        if let Ok(theme) = toml::Value::Table(table).try_into() {
+            *self = theme;
+        }
+    }
+
     /// Color for an Idle session, given the elapsed time since it
     /// transitioned to Idle and the user-configured decay window.
     ///
@@ -429,6 +474,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_overrides_skips_unknown_fields_and_bad_colors() {
+        let base = load_theme("zinc");
+        let mut theme = base.clone();
+        let overrides = BTreeMap::from([
+            ("accent".to_string(), "#ff8700".to_string()),
+            ("not_a_field".to_string(), "#000000".to_string()),
+            ("error".to_string(), "nope".to_string()),
+            ("syntax".to_string(), "#000000".to_string()),
+            ("appearance".to_string(), "light".to_string()),
+        ]);
+        theme.apply_overrides(&overrides);
+
+        assert_eq!(theme.accent, Color::Rgb(0xff, 0x87, 0x00));
+        assert_eq!(theme.error, base.error);
+        assert_eq!(theme.background, base.background);
+        assert_eq!(theme.appearance, base.appearance);
+        assert_eq!(theme.syntax.shiki_theme, base.syntax.shiki_theme);
+    }
+
     #[test]
     fn test_hex_color_parse_invalid() {
         assert!(hex_color::parse_hex_color("#fff").is_err());
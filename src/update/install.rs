@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use tempfile::TempDir;
 
-use crate::update::is_newer_version;
+use crate::update::{is_newer_version, verify};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InstallMethod {
@@ -187,10 +187,19 @@ pub fn current_platform_string() -> Result<&'static str> {
 const DEFAULT_RELEASE_BASE: &str =
     "https://github.com/agent-of-empires/agent-of-empires/releases/download";
 
-fn release_tarball_url(version: &str, platform: &str) -> String {
+/// Directory URL every asset of release `version` lives under.
+fn release_url(version: &str) -> String {
     let base =
         std::env::var("AOE_UPDATE_BASE_URL").unwrap_or_else(|_| DEFAULT_RELEASE_BASE.to_string());
-    format!("{base}/v{version}/aoe-{platform}.tar.gz")
+    format!("{base}/v{version}")
+}
+
+fn tarball_asset_name(platform: &str) -> String {
+    format!("aoe-{platform}.tar.gz")
+}
+
+fn release_tarball_url(version: &str, platform: &str) -> String {
+    format!("{}/{}", release_url(version), tarball_asset_name(platform))
 }
 
 /// Download a release tarball to `dest`. Streams bytes; reports
@@ -341,8 +350,9 @@ pub fn parent_is_writable(binary_path: &Path) -> bool {
 }
 
 /// Perform an in-place tarball update at `binary_path`, fetching the
-/// release for `version`. Caller has already detected the install
-/// method and confirmed with the user.
+/// release for `version` and checking it against the release's published
+/// SHA-256. Caller has already detected the install method and confirmed
+/// with the user.
 pub async fn update_via_tarball(
     binary_path: &Path,
     version: &str,
@@ -356,9 +366,16 @@ pub async fn update_via_tarball(
     // Same-filesystem temp dir so the rename in atomic_replace works.
     let workdir = TempDir::new_in(parent).context("creating temp dir for update")?;
 
-    let tarball_path = workdir.path().join(format!("aoe-{platform}.tar.gz"));
-    let url = release_tarball_url(version, platform);
-    download_tarball(&url, &tarball_path, on_progress).await?;
+    let asset = tarball_asset_name(platform);
+    let tarball_path = workdir.path().join(&asset);
+    let expected = verify::expected_digest(&release_url(version), &asset).await?;
+    download_tarball(
+        &release_tarball_url(version, platform),
+        &tarball_path,
+        on_progress,
+    )
+    .await?;
+    verify::verify_file(&tarball_path, &expected)?;
 
     let extracted = extract_tarball(&tarball_path, workdir.path(), platform)?;
     sanity_check_binary(&extracted, version)?;
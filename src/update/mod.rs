@@ -1,14 +1,17 @@
 //! Update check functionality
 
 pub mod install;
+mod source;
+pub mod verify;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 use tracing::warn;
 
-use crate::session::{get_app_dir, get_update_settings};
+use crate::session::{get_app_dir, get_cache_dir, get_update_settings};
+use source::ReleaseSource;
 
 const GITHUB_OWNER: &str = "agent-of-empires";
 const GITHUB_REPO: &str = "agent-of-empires";
@@ -95,8 +98,30 @@ struct UpdateCache {
     releases: Vec<ReleaseInfo>,
 }
 
+const CACHE_FILE: &str = "update_cache.json";
+
 fn cache_path() -> Result<PathBuf> {
-    Ok(get_app_dir()?.join("update_cache.json"))
+    Ok(get_cache_dir()?.join(CACHE_FILE))
+}
+
+/// Delete the update cache, plus a copy left in the app dir from before
+/// `app.cache_dir` was set. Returns the files removed; the next check
+/// refetches.
+pub fn clear_cache() -> Result<Vec<PathBuf>> {
+    let mut candidates = vec![cache_path()?];
+    let legacy = get_app_dir()?.join(CACHE_FILE);
+    if !candidates.contains(&legacy) {
+        candidates.push(legacy);
+    }
+    let mut removed = Vec::new();
+    for path in candidates {
+        match fs::remove_file(&path) {
+            Ok(()) => removed.push(path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).with_context(|| format!("removing {}", path.display())),
+        }
+    }
+    Ok(removed)
 }
 
 fn load_cache() -> Option<UpdateCache> {
@@ -108,7 +133,7 @@ fn load_cache() -> Option<UpdateCache> {
 fn save_cache(cache: &UpdateCache) -> Result<()> {
     let path = cache_path()?;
     let content = serde_json::to_string_pretty(cache)?;
-    fs::write(&path, content)?;
+    crate::session::atomic_write(&path, content.as_bytes())?;
     Ok(())
 }
 
@@ -158,48 +183,28 @@ pub async fn check_for_update(current_version: &str, force: bool) -> Result<Upda
         }
     }
 
-    let client = crate::github::GitHubClient::unauthenticated(crate::github::GitHubClientConfig {
-        api_base: github_api_base(),
-        user_agent: crate::github::DEFAULT_USER_AGENT.to_string(),
-        timeout: std::time::Duration::from_secs(5),
-    })?;
-
-    // Fetch all releases (includes body/release notes)
-    let releases = match fetch_releases(&client).await {
-        Ok(r) => r,
-        Err(e) => {
-            tracing::debug!(target: "update.fetch", "Failed to fetch releases: {e}");
-            Vec::new()
-        }
-    };
-
-    let latest_version = releases
-        .first()
-        .map(|r| r.version.clone())
-        .unwrap_or_default();
-
-    if latest_version.is_empty() {
-        // Fall back to the latest-release endpoint if the releases list failed.
-        let release = client.latest_release(GITHUB_OWNER, GITHUB_REPO).await?;
-        let release_info = release_info_from(release);
-        let version = release_info.version.clone();
-
-        let cache = UpdateCache {
-            checked_at: chrono::Utc::now(),
-            latest_version: version.clone(),
-            releases: vec![release_info],
-        };
-        if let Err(e) = save_cache(&cache) {
-            warn!("Failed to save update cache: {}", e);
+    // Offline: answer from whatever cache exists, however stale, and never
+    // fetch. Only an explicit `force` (`aoe update --force`) goes online.
+    if settings.offline {
+        if !force {
+            let latest_version = load_cache().map(|c| c.latest_version).unwrap_or_default();
+            tracing::info!(target: "update.cache", latest = %latest_version, "offline; skipping fetch");
+            return Ok(UpdateInfo {
+                available: is_newer_version(&latest_version, current_version),
+                current_version: current_version.to_string(),
+                latest_version,
+            });
         }
-
-        return Ok(UpdateInfo {
-            available: is_newer_version(&version, current_version),
-            current_version: current_version.to_string(),
-            latest_version: version,
-        });
+        tracing::info!(target: "update.fetch", "offline overridden by force");
     }
 
+    // Fetch recent releases (includes body/release notes) from GitHub or
+    // the configured manifest.
+    let releases = source::for_settings(&settings)?.fetch_releases().await?;
+    let Some(latest_version) = releases.first().map(|r| r.version.clone()) else {
+        anyhow::bail!("release source returned no releases");
+    };
+
     let cache = UpdateCache {
         checked_at: chrono::Utc::now(),
         latest_version: latest_version.clone(),
@@ -225,20 +230,6 @@ pub async fn check_for_update(current_version: &str, force: bool) -> Result<Upda
     })
 }
 
-#[tracing::instrument(target = "update.fetch", skip_all)]
-async fn fetch_releases(client: &crate::github::GitHubClient) -> Result<Vec<ReleaseInfo>> {
-    let releases = client.list_releases(GITHUB_OWNER, GITHUB_REPO, 20).await?;
-    Ok(releases.into_iter().map(release_info_from).collect())
-}
-
-fn release_info_from(release: crate::github::GitHubRelease) -> ReleaseInfo {
-    ReleaseInfo {
-        version: release.tag_name.trim_start_matches('v').to_string(),
-        body: release.body.unwrap_or_default(),
-        published_at: release.published_at,
-    }
-}
-
 /// Get cached release notes, filtered to show only releases newer than from_version.
 /// Returns releases in newest-first order.
 pub fn get_cached_releases(from_version: Option<&str>) -> Vec<ReleaseInfo> {
@@ -349,17 +340,44 @@ pub fn cached_version_health(current: &str) -> (UpdateStatus, ReleasesBehind) {
     )
 }
 
-pub async fn print_update_notice() {
-    let settings = get_update_settings();
-    // CLI nag fires only when both the global mode allows notifications
-    // and the user has not opted out of CLI nags specifically.
-    if !settings.update_check_mode.notifies() || !settings.notify_in_cli {
-        return;
+/// How long a CLI command waits, after its own output, for an update check
+/// that has not finished yet. Past this the notice is dropped for this run.
+const NOTICE_GRACE: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// CLI update nag, checked in the background so it never delays a
+/// command's own output. Spawn it when the command starts and call
+/// [`UpdateNotice::show`] once the command has printed its results.
+pub struct UpdateNotice(Option<tokio::task::JoinHandle<Option<UpdateInfo>>>);
+
+impl UpdateNotice {
+    pub fn spawn() -> Self {
+        let settings = get_update_settings();
+        // CLI nag fires only when both the global mode allows notifications
+        // and the user has not opted out of CLI nags specifically.
+        if !settings.update_check_mode.notifies() || !settings.notify_in_cli {
+            return Self(None);
+        }
+        Self(Some(tokio::spawn(async {
+            check_for_update(env!("CARGO_PKG_VERSION"), false)
+                .await
+                .ok()
+        })))
     }
 
-    let version = env!("CARGO_PKG_VERSION");
-
-    if let Ok(info) = check_for_update(version, false).await {
+    /// Print the notice if the check found an update, waiting at most
+    /// [`NOTICE_GRACE`] for a check still in flight.
+    pub async fn show(self) {
+        let Some(handle) = self.0 else {
+            return;
+        };
+        let info = match tokio::time::timeout(NOTICE_GRACE, handle).await {
+            Ok(Ok(Some(info))) => info,
+            Ok(_) => return,
+            Err(_) => {
+                tracing::debug!(target: "update.fetch", "update check still running; notice skipped");
+                return;
+            }
+        };
         if info.available {
             eprintln!(
                 "\n💡 Update available: v{} → v{} (run: aoe update)",
@@ -0,0 +1,216 @@
+//! Where release metadata comes from.
+//!
+//! The update check only needs "the recent releases, newest first". GitHub
+//! is the default source; forks and internal mirrors can instead point
+//! `updates.manifest_url` at a static JSON manifest (see
+//! [`ManifestSource`] for the shape). [`for_settings`] picks the source.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::{github_api_base, version_parts, ReleaseInfo, GITHUB_OWNER, GITHUB_REPO};
+use crate::session::UpdatesConfig;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A place to fetch release metadata from.
+pub(super) trait ReleaseSource {
+    /// Recent releases, newest first. An empty list means the source had
+    /// nothing to offer; callers treat that like a failed fetch.
+    async fn fetch_releases(&self) -> Result<Vec<ReleaseInfo>>;
+}
+
+/// The configured source: the manifest when `manifest_url` is set, GitHub
+/// otherwise.
+pub(super) enum Source {
+    GitHub(GitHubSource),
+    Manifest(ManifestSource),
+}
+
+pub(super) fn for_settings(settings: &UpdatesConfig) -> Result<Source> {
+    match settings
+        .manifest_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|u| !u.is_empty())
+    {
+        Some(url) => Ok(Source::Manifest(ManifestSource::new(url)?)),
+        None => Ok(Source::GitHub(GitHubSource::new()?)),
+    }
+}
+
+impl ReleaseSource for Source {
+    async fn fetch_releases(&self) -> Result<Vec<ReleaseInfo>> {
+        match self {
+            Source::GitHub(s) => s.fetch_releases().await,
+            Source::Manifest(s) => s.fetch_releases().await,
+        }
+    }
+}
+
+/// GitHub releases for the upstream repo (or `AOE_UPDATE_API_BASE`).
+pub(super) struct GitHubSource {
+    client: crate::github::GitHubClient,
+}
+
+impl GitHubSource {
+    fn new() -> Result<Self> {
+        let client =
+            crate::github::GitHubClient::unauthenticated(crate::github::GitHubClientConfig {
+                api_base: github_api_base(),
+                user_agent: crate::github::DEFAULT_USER_AGENT.to_string(),
+                timeout: FETCH_TIMEOUT,
+            })?;
+        Ok(Self { client })
+    }
+}
+
+impl ReleaseSource for GitHubSource {
+    #[tracing::instrument(target = "update.fetch", skip_all)]
+    async fn fetch_releases(&self) -> Result<Vec<ReleaseInfo>> {
+        // The list endpoint carries release notes for the changelog; fall
+        // back to the latest-release endpoint if it fails or comes back empty.
+        match self
+            .client
+            .list_releases(GITHUB_OWNER, GITHUB_REPO, 20)
+            .await
+        {
+            Ok(releases) if !releases.is_empty() => {
+                return Ok(releases.into_iter().map(release_info_from).collect());
+            }
+            Ok(_) => {}
+            Err(e) => tracing::debug!(target: "update.fetch", "Failed to fetch releases: {e}"),
+        }
+        let release = self
+            .client
+            .latest_release(GITHUB_OWNER, GITHUB_REPO)
+            .await?;
+        Ok(vec![release_info_from(release)])
+    }
+}
+
+fn release_info_from(release: crate::github::GitHubRelease) -> ReleaseInfo {
+    ReleaseInfo {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        body: release.body.unwrap_or_default(),
+        published_at: release.published_at,
+    }
+}
+
+/// A JSON manifest served from any URL. The body is either a single release
+/// object or an array of them:
+///
+/// ```json
+/// [{"version": "1.2.0", "body": "notes", "published_at": "2026-01-02T00:00:00Z"}]
+/// ```
+///
+/// `version` is required (a leading `v` is dropped); `body` and
+/// `published_at` are optional. Entries are sorted newest first, so the
+/// manifest can list them in any order.
+pub(super) struct ManifestSource {
+    url: String,
+    http: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Manifest {
+    Many(Vec<ManifestRelease>),
+    One(ManifestRelease),
+}
+
+#[derive(Deserialize)]
+struct ManifestRelease {
+    version: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    published_at: Option<String>,
+}
+
+impl ManifestSource {
+    fn new(url: &str) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(crate::github::DEFAULT_USER_AGENT)
+            .timeout(FETCH_TIMEOUT)
+            .build()
+            .context("building manifest HTTP client")?;
+        Ok(Self {
+            url: url.to_string(),
+            http,
+        })
+    }
+}
+
+impl ReleaseSource for ManifestSource {
+    #[tracing::instrument(target = "update.fetch", skip_all, fields(url = %self.url))]
+    async fn fetch_releases(&self) -> Result<Vec<ReleaseInfo>> {
+        let body = self
+            .http
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .with_context(|| format!("fetching release manifest {}", self.url))?
+            .text()
+            .await
+            .with_context(|| format!("reading release manifest {}", self.url))?;
+        parse_manifest(&body).with_context(|| format!("parsing release manifest {}", self.url))
+    }
+}
+
+fn parse_manifest(body: &str) -> Result<Vec<ReleaseInfo>> {
+    let entries = match serde_json::from_str::<Manifest>(body)? {
+        Manifest::Many(entries) => entries,
+        Manifest::One(entry) => vec![entry],
+    };
+    let mut releases: Vec<ReleaseInfo> = entries
+        .into_iter()
+        .map(|r| ReleaseInfo {
+            version: r.version.trim().trim_start_matches('v').to_string(),
+            body: r.body.unwrap_or_default(),
+            published_at: r.published_at,
+        })
+        .filter(|r| !r.version.is_empty())
+        .collect();
+    releases.sort_by_key(|r| std::cmp::Reverse(version_parts(&r.version)));
+    Ok(releases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest_accepts_a_list_in_any_order() {
+        let releases = parse_manifest(
+            r#"[
+                {"version": "1.2.0"},
+                {"version": "v1.10.0", "body": "notes", "published_at": "2026-01-02T00:00:00Z"},
+                {"version": "1.9.3"}
+            ]"#,
+        )
+        .unwrap();
+        let versions: Vec<&str> = releases.iter().map(|r| r.version.as_str()).collect();
+        assert_eq!(versions, ["1.10.0", "1.9.3", "1.2.0"]);
+        assert_eq!(releases[0].body, "notes");
+        assert_eq!(
+            releases[0].published_at.as_deref(),
+            Some("2026-01-02T00:00:00Z")
+        );
+        assert_eq!(releases[1].body, "");
+    }
+
+    #[test]
+    fn parse_manifest_accepts_a_single_release() {
+        let releases = parse_manifest(r#"{"version": "2.0.0", "body": "big"}"#).unwrap();
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].version, "2.0.0");
+    }
+
+    #[test]
+    fn parse_manifest_rejects_entries_without_a_version() {
+        assert!(parse_manifest(r#"[{"body": "no version"}]"#).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! Integrity checks for downloaded release assets.
+//!
+//! Every release publishes SHA-256 digests in `shasum -a 256` format: a
+//! combined `SHA256SUMS` for the whole release and a `<asset>.sha256` next to
+//! each asset (older releases only have the latter). [`expected_digest`]
+//! fetches whichever exists and [`verify_file`] refuses an asset whose hash
+//! does not match, so nothing unverified is ever installed. Anything that
+//! downloads a release asset should go through here.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// Name of the combined checksum file published with each release.
+pub const SHA256SUMS: &str = "SHA256SUMS";
+
+const CHECKSUM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Parsed `shasum` / `sha256sum` output: asset file name to lowercase hex
+/// digest.
+#[derive(Debug)]
+pub struct Checksums {
+    digests: HashMap<String, String>,
+}
+
+impl Checksums {
+    /// Parse `<hex>  <name>` lines (`<hex> *<name>` for binary mode). Blank
+    /// lines and `#` comments are skipped; anything else malformed is an
+    /// error rather than a silently unverifiable asset.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut digests = HashMap::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (digest, name) = line
+                .split_once(char::is_whitespace)
+                .with_context(|| format!("malformed checksum line: {line:?}"))?;
+            let digest = digest.to_ascii_lowercase();
+            if digest.len() != 64 || !digest.bytes().all(|b| b.is_ascii_hexdigit()) {
+                anyhow::bail!("not a SHA-256 digest: {digest:?}");
+            }
+            let name = name.trim_start().trim_start_matches('*');
+            if name.is_empty() {
+                anyhow::bail!("checksum line has no file name: {line:?}");
+            }
+            digests.insert(name.to_string(), digest);
+        }
+        Ok(Self { digests })
+    }
+
+    pub fn digest_for(&self, asset: &str) -> Option<&str> {
+        self.digests.get(asset).map(String::as_str)
+    }
+}
+
+/// Fetch the published digest for `asset` from the release directory at
+/// `release_url` (the URL the asset itself lives under). Tries
+/// `SHA256SUMS` first and falls back to `<asset>.sha256` when the release
+/// has no combined file. Errors when neither lists the asset: an asset
+/// without a published checksum is not installed.
+pub async fn expected_digest(release_url: &str, asset: &str) -> Result<String> {
+    let client = reqwest::Client::builder()
+        .user_agent(crate::github::DEFAULT_USER_AGENT)
+        .timeout(CHECKSUM_TIMEOUT)
+        .build()?;
+    let release_url = release_url.trim_end_matches('/');
+
+    let candidates = [
+        format!("{release_url}/{SHA256SUMS}"),
+        format!("{release_url}/{asset}.sha256"),
+    ];
+    for url in &candidates {
+        let response = client
+            .get(url)
+            .send()
+            .await
+            .with_context(|| format!("fetching {url}"))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            tracing::debug!(target: "update.verify", %url, "checksum file not published");
+            continue;
+        }
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "checksum download failed: HTTP {} from {}",
+                response.status(),
+                url
+            );
+        }
+        let checksums = Checksums::parse(&response.text().await?)
+            .with_context(|| format!("reading checksums from {url}"))?;
+        return checksums
+            .digest_for(asset)
+            .map(str::to_string)
+            .with_context(|| format!("{url} has no checksum for {asset}"));
+    }
+    anyhow::bail!("release at {release_url} publishes no checksum for {asset}")
+}
+
+/// Lowercase hex SHA-256 of a file.
+pub fn sha256_file(path: &Path) -> Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let bytes =
+        std::fs::read(path).with_context(|| format!("reading {} to verify", path.display()))?;
+    Ok(Sha256::digest(&bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// Refuse a file whose SHA-256 does not match `expected`.
+pub fn verify_file(path: &Path, expected: &str) -> Result<()> {
+    let actual = sha256_file(path)?;
+    if !actual.eq_ignore_ascii_case(expected) {
+        anyhow::bail!(
+            "checksum mismatch for {}: expected {expected}, got {actual}. \
+             The download is corrupt or was tampered with.",
+            path.display()
+        );
+    }
+    tracing::debug!(target: "update.verify", path = %path.display(), "checksum verified");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // sha256("hello")
+    const HELLO: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    #[test]
+    fn parse_reads_shasum_text_and_binary_lines() {
+        let sums = Checksums::parse(&format!(
+            "# release checksums\n{}  aoe-linux-amd64.tar.gz\n\n{} *aoe-darwin-arm64.tar.gz\n",
+            HELLO.to_uppercase(),
+            "0".repeat(64)
+        ))
+        .unwrap();
+        assert_eq!(sums.digest_for("aoe-linux-amd64.tar.gz"), Some(HELLO));
+        assert_eq!(
+            sums.digest_for("aoe-darwin-arm64.tar.gz"),
+            Some("0".repeat(64).as_str())
+        );
+        assert_eq!(sums.digest_for("aoe-linux-arm64.tar.gz"), None);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_lines() {
+        assert!(Checksums::parse("not-a-digest  aoe.tar.gz").is_err());
+        assert!(Checksums::parse(HELLO).is_err());
+    }
+
+    #[test]
+    fn verify_file_rejects_a_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("aoe.tar.gz");
+        std::fs::write(&file, b"hello").unwrap();
+        assert!(verify_file(&file, HELLO).is_ok());
+        let err = verify_file(&file, &"0".repeat(64)).unwrap_err();
+        assert!(err.to_string().contains("checksum mismatch"), "{err}");
+    }
+}
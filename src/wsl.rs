@@ -0,0 +1,157 @@
+//! Windows Subsystem for Linux path handling.
+//!
+//! Under WSL, paths often arrive in Windows form: a project path typed as
+//! `C:\Users\me\repo`, or a worktree `.git` file written by Windows git
+//! (`gitdir: C:/Users/me/repo/.git/worktrees/feat`). Linux tools, Docker
+//! included, only understand the `/mnt/c/...` form, so these helpers convert
+//! drive-letter and `\\wsl$` paths before they reach bind mounts or worktree
+//! path math. Outside WSL every helper leaves paths untouched.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Whether this process runs inside WSL. Checked once: the interop env vars
+/// WSL sets, then the kernel release string, which names Microsoft on both
+/// WSL 1 and WSL 2.
+pub fn is_wsl() -> bool {
+    static IS_WSL: OnceLock<bool> = OnceLock::new();
+    *IS_WSL.get_or_init(|| {
+        if !cfg!(target_os = "linux") {
+            return false;
+        }
+        if std::env::var_os("WSL_DISTRO_NAME").is_some()
+            || std::env::var_os("WSL_INTEROP").is_some()
+        {
+            return true;
+        }
+        std::fs::read_to_string("/proc/sys/kernel/osrelease")
+            .map(|release| release.to_ascii_lowercase().contains("microsoft"))
+            .unwrap_or(false)
+    })
+}
+
+/// The WSL form of a Windows path, or `None` when `path` is not one.
+///
+/// `C:\Users\me` and `C:/Users/me` become `/mnt/c/Users/me`; a distro path
+/// such as `\\wsl$\Ubuntu\home\me` or `\\wsl.localhost\Ubuntu\home\me`
+/// becomes `/home/me`.
+pub fn windows_to_wsl_path(path: &str) -> Option<PathBuf> {
+    let bytes = path.as_bytes();
+    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        let rest = &path[2..];
+        if !rest.is_empty() && !rest.starts_with(['\\', '/']) {
+            // `C:foo` is relative to the drive's current dir; leave it alone.
+            return None;
+        }
+        let drive = (bytes[0] as char).to_ascii_lowercase();
+        let mut out = PathBuf::from(format!("/mnt/{drive}"));
+        out.extend(rest.split(['\\', '/']).filter(|s| !s.is_empty()));
+        return Some(out);
+    }
+
+    let unc = path.replace('\\', "/");
+    for prefix in ["//wsl$/", "//wsl.localhost/"] {
+        if let Some(rest) = unc.strip_prefix(prefix) {
+            // Drop the distro name; the rest is rooted at this distro's `/`.
+            let rest = rest.split_once('/').map_or("", |(_, r)| r);
+            let mut out = PathBuf::from("/");
+            out.extend(rest.split('/').filter(|s| !s.is_empty()));
+            return Some(out);
+        }
+    }
+    None
+}
+
+/// `path` in the form Linux tools expect: converted from Windows form when
+/// running under WSL, otherwise returned unchanged.
+pub fn normalize_host_path(path: &str) -> String {
+    normalize_host_path_for(path, is_wsl())
+}
+
+fn normalize_host_path_for(path: &str, wsl: bool) -> String {
+    if !wsl {
+        return path.to_string();
+    }
+    windows_to_wsl_path(path)
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// The Windows drive a path lives on: a `C:` prefix anywhere, or a
+/// `/mnt/<letter>` root under WSL. Paths on different drives have no
+/// relative path between them that Windows tools can follow.
+pub fn drive_letter(path: &Path) -> Option<char> {
+    drive_letter_for(path, is_wsl())
+}
+
+fn drive_letter_for(path: &Path, wsl: bool) -> Option<char> {
+    let mut components = path.components();
+    match components.next()? {
+        Component::Normal(first) => {
+            let first = first.to_str()?;
+            let bytes = first.as_bytes();
+            (bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+                .then(|| (bytes[0] as char).to_ascii_lowercase())
+        }
+        Component::RootDir if wsl => {
+            if components.next()? != Component::Normal("mnt".as_ref()) {
+                return None;
+            }
+            let Component::Normal(drive) = components.next()? else {
+                return None;
+            };
+            let drive = drive.to_str()?;
+            let mut chars = drive.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphabetic() => Some(c.to_ascii_lowercase()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_to_wsl_path_converts_drive_and_distro_paths() {
+        assert_eq!(
+            windows_to_wsl_path(r"C:\Users\me\repo"),
+            Some(PathBuf::from("/mnt/c/Users/me/repo"))
+        );
+        assert_eq!(
+            windows_to_wsl_path("D:/work/repo/.git/worktrees/feat"),
+            Some(PathBuf::from("/mnt/d/work/repo/.git/worktrees/feat"))
+        );
+        assert_eq!(windows_to_wsl_path(r"E:\"), Some(PathBuf::from("/mnt/e")));
+        assert_eq!(
+            windows_to_wsl_path(r"\\wsl$\Ubuntu\home\me\repo"),
+            Some(PathBuf::from("/home/me/repo"))
+        );
+        assert_eq!(
+            windows_to_wsl_path(r"\\wsl.localhost\Ubuntu\home\me"),
+            Some(PathBuf::from("/home/me"))
+        );
+        assert_eq!(windows_to_wsl_path("/home/me/repo"), None);
+        assert_eq!(windows_to_wsl_path("C:relative"), None);
+        assert_eq!(windows_to_wsl_path("relative/path"), None);
+    }
+
+    #[test]
+    fn test_normalize_host_path_only_converts_under_wsl() {
+        assert_eq!(normalize_host_path_for(r"C:\repo", false), r"C:\repo");
+        assert_eq!(normalize_host_path_for(r"C:\repo", true), "/mnt/c/repo");
+        assert_eq!(normalize_host_path_for("/home/me", true), "/home/me");
+    }
+
+    #[test]
+    fn test_drive_letter() {
+        assert_eq!(drive_letter_for(Path::new("C:/Users"), false), Some('c'));
+        assert_eq!(drive_letter_for(Path::new("/mnt/d/work"), true), Some('d'));
+        assert_eq!(drive_letter_for(Path::new("/mnt/d/work"), false), None);
+        assert_eq!(drive_letter_for(Path::new("/mnt/data/work"), true), None);
+        assert_eq!(drive_letter_for(Path::new("/home/me"), true), None);
+    }
+}
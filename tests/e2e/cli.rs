@@ -548,7 +548,13 @@ yolo_mode_default = true
     );
     std::fs::write(config_dir.join("config.toml"), config_content).expect("write config.toml");
 
-    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "YoloDefault"]);
+    let add_output = h.run_cli(&[
+        "add",
+        project.to_str().unwrap(),
+        "-t",
+        "YoloDefault",
+        "--i-know-what-im-doing",
+    ]);
     assert!(
         add_output.status.success(),
         "aoe add failed: {}",
@@ -570,7 +576,14 @@ fn test_cli_add_yolo_flag_without_config() {
     let h = TuiTestHarness::new("cli_add_yolo_flag");
     let project = h.project_path();
 
-    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "YoloFlag", "--yolo"]);
+    let add_output = h.run_cli(&[
+        "add",
+        project.to_str().unwrap(),
+        "-t",
+        "YoloFlag",
+        "--yolo",
+        "--i-know-what-im-doing",
+    ]);
     assert!(
         add_output.status.success(),
         "aoe add failed: {}",
@@ -586,6 +599,64 @@ fn test_cli_add_yolo_flag_without_config() {
     );
 }
 
+#[test]
+#[serial]
+fn test_cli_add_refuses_unsandboxed_yolo_without_override() {
+    let h = TuiTestHarness::new("cli_add_yolo_no_sandbox");
+    let project = h.project_path();
+
+    let add_output = h.run_cli(&["add", project.to_str().unwrap(), "-t", "YoloHost", "--yolo"]);
+    assert!(
+        !add_output.status.success(),
+        "aoe add --yolo without a sandbox should be refused"
+    );
+    assert!(
+        String::from_utf8_lossy(&add_output.stderr).contains("without a sandbox"),
+        "stderr should explain the refusal: {}",
+        String::from_utf8_lossy(&add_output.stderr)
+    );
+}
+
+/// The YOLO gate runs before any side effect, so a refused worktree add
+/// never creates the worktree.
+#[test]
+#[serial]
+fn test_cli_add_refuses_unsandboxed_yolo_before_creating_worktree() {
+    let h = TuiTestHarness::new("cli_add_yolo_no_worktree");
+    let repo = h.home_path().join("yolo-repo");
+    init_git_repo(&repo);
+
+    let add_output = h.run_cli(&[
+        "add",
+        repo.to_str().unwrap(),
+        "-w",
+        "feat/yolo",
+        "-b",
+        "-t",
+        "YoloWorktree",
+        "--yolo",
+    ]);
+    assert!(
+        !add_output.status.success(),
+        "aoe add --yolo without a sandbox should be refused"
+    );
+    assert!(
+        !String::from_utf8_lossy(&add_output.stdout).contains("Creating worktree"),
+        "no worktree should be created before the refusal"
+    );
+
+    let list = Command::new("git")
+        .args(["worktree", "list", "--porcelain"])
+        .current_dir(&repo)
+        .output()
+        .expect("git worktree list");
+    let worktrees = String::from_utf8_lossy(&list.stdout)
+        .lines()
+        .filter(|l| l.starts_with("worktree "))
+        .count();
+    assert_eq!(worktrees, 1, "only the main checkout should exist");
+}
+
 #[test]
 #[serial]
 fn test_cli_add_default_tool_no_config() {
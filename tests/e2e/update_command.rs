@@ -79,13 +79,10 @@ fn update_calls_brew_when_method_is_homebrew() {
     );
 }
 
-#[tokio::test]
-#[serial]
-async fn update_via_tarball_replaces_binary_at_target_path() {
-    // Build a minimal tar.gz containing a dummy `aoe-{platform}` script
-    // that prints the expected version when run with --version.
+/// Build a tar.gz holding a dummy `aoe-{platform}` script that prints
+/// `aoe 99.99.99` for `--version`. Returns the tarball bytes.
+fn build_release_tarball(platform: &str) -> Vec<u8> {
     let workdir = tempfile::tempdir().unwrap();
-    let platform = agent_of_empires::update::install::current_platform_string().unwrap();
     let dummy = workdir.path().join(format!("aoe-{platform}"));
     fs::write(&dummy, "#!/bin/sh\necho 'aoe 99.99.99'\n").unwrap();
     fs::set_permissions(&dummy, fs::Permissions::from_mode(0o755)).unwrap();
@@ -102,47 +99,105 @@ async fn update_via_tarball_replaces_binary_at_target_path() {
         .status()
         .unwrap();
     assert!(tar_status.success(), "tar czf failed");
+    fs::read(&tarball).unwrap()
+}
 
-    // Spin up an axum server that serves the tarball.
-    use std::net::TcpListener as StdListener;
-    let std_listener = StdListener::bind("127.0.0.1:0").unwrap();
-    std_listener.set_nonblocking(true).unwrap();
-    let port = std_listener.local_addr().unwrap().port();
-    let tarball_bytes = fs::read(&tarball).unwrap();
-    let path_for_route = format!("/v99.99.99/aoe-{platform}.tar.gz");
-    let app = axum::Router::new().route(
-        &path_for_route,
-        axum::routing::get(move || {
-            let body = tarball_bytes.clone();
-            async move { axum::body::Bytes::from(body) }
-        }),
-    );
-    let listener = tokio::net::TcpListener::from_std(std_listener).unwrap();
-    let server_handle = tokio::spawn(async move {
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Serve `/v99.99.99/aoe-{platform}.tar.gz` and a `SHA256SUMS` listing
+/// `sums_digest` for it. Returns the base URL and the server task.
+async fn serve_release(
+    platform: &str,
+    tarball_bytes: Vec<u8>,
+    sums_digest: String,
+) -> (String, tokio::task::JoinHandle<()>) {
+    let asset = format!("aoe-{platform}.tar.gz");
+    let sums = format!("{sums_digest}  {asset}\n");
+    let app = axum::Router::new()
+        .route(
+            &format!("/v99.99.99/{asset}"),
+            axum::routing::get(move || {
+                let body = tarball_bytes.clone();
+                async move { axum::body::Bytes::from(body) }
+            }),
+        )
+        .route(
+            "/v99.99.99/SHA256SUMS",
+            axum::routing::get(move || {
+                let body = sums.clone();
+                async move { body }
+            }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = tokio::spawn(async move {
         let _ = axum::serve(listener, app).await;
     });
+    (format!("http://127.0.0.1:{port}"), handle)
+}
 
-    // Pre-place a target binary at a writable path.
+/// Run `update_via_tarball` against `base_url` for a fresh `old binary`
+/// target. Returns the result and the target's contents afterwards.
+async fn run_tarball_update(base_url: &str) -> (anyhow::Result<()>, Vec<u8>) {
     let install_dir = tempfile::tempdir().unwrap();
     let target = install_dir.path().join("aoe");
     fs::write(&target, "old binary").unwrap();
     fs::set_permissions(&target, fs::Permissions::from_mode(0o755)).unwrap();
 
-    // Run update_via_tarball pointed at our local server.
     // SAFETY: tests run with #[serial], so concurrent env mutation is safe here.
     unsafe {
-        std::env::set_var("AOE_UPDATE_BASE_URL", format!("http://127.0.0.1:{port}"));
+        std::env::set_var("AOE_UPDATE_BASE_URL", base_url);
     }
     let result =
         agent_of_empires::update::install::update_via_tarball(&target, "99.99.99", None).await;
     unsafe {
         std::env::remove_var("AOE_UPDATE_BASE_URL");
     }
-    server_handle.abort();
+    (result, fs::read(&target).unwrap())
+}
+
+#[tokio::test]
+#[serial]
+async fn update_via_tarball_replaces_binary_at_target_path() {
+    let platform = agent_of_empires::update::install::current_platform_string().unwrap();
+    let tarball = build_release_tarball(platform);
+    let digest = sha256_hex(&tarball);
+    let (base_url, server) = serve_release(platform, tarball, digest).await;
+
+    let (result, contents) = run_tarball_update(&base_url).await;
+    server.abort();
     result.expect("update_via_tarball should succeed");
+    let contents = String::from_utf8(contents).unwrap();
+    assert!(
+        contents.contains("99.99.99"),
+        "target was not replaced: {contents}"
+    );
+}
 
-    // Assert the target was replaced.
-    let output = Command::new(&target).arg("--version").output().unwrap();
-    let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("99.99.99"), "stdout: {stdout}");
+#[tokio::test]
+#[serial]
+async fn update_via_tarball_refuses_a_tampered_asset() {
+    let platform = agent_of_empires::update::install::current_platform_string().unwrap();
+    let tarball = build_release_tarball(platform);
+    // Published checksum is for the genuine tarball; the server hands out
+    // a modified one.
+    let digest = sha256_hex(&tarball);
+    let mut tampered = tarball;
+    tampered.extend_from_slice(b"payload");
+    let (base_url, server) = serve_release(platform, tampered, digest).await;
+
+    let (result, contents) = run_tarball_update(&base_url).await;
+    server.abort();
+    let err = result.expect_err("tampered tarball must be rejected");
+    assert!(
+        format!("{err:#}").contains("checksum mismatch"),
+        "unexpected error: {err:#}"
+    );
+    assert_eq!(contents, b"old binary", "target must be left untouched");
 }
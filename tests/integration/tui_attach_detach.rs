@@ -286,3 +286,39 @@ fn test_attach_restart_failure_emits_transient_toast() {
          is recognizable in the bar."
     );
 }
+
+/// With `session.on_agent_exit = "respawn"` the pane runs the agent under
+/// `aoe __respawn-agent`. `attach_session` restarts a session whose pane is
+/// dead or running a shell, so a healthy wrapped agent must read as neither,
+/// or every attach would kill and relaunch it.
+#[test]
+#[serial_test::serial]
+fn test_attach_does_not_restart_running_respawn_wrapped_agent() {
+    if !tmux_available() {
+        eprintln!("Skipping test: tmux not available");
+        return;
+    }
+
+    let name = format!("aoe_test_respawn_wrap_{}", std::process::id());
+    let command = format!(
+        "exec '{}' __respawn-agent 'exec sleep 30'",
+        env!("CARGO_BIN_EXE_aoe")
+    );
+    let session = agent_of_empires::tmux::Session::from_name(&name);
+    session
+        .create_with_size("/tmp", Some(&command), None)
+        .expect("create wrapped session");
+    std::thread::sleep(std::time::Duration::from_millis(1000));
+
+    let pane_dead = session.is_pane_dead();
+    let running_shell = session.is_pane_running_shell();
+    let _ = Command::new("tmux")
+        .args(["kill-session", "-t", &name])
+        .output();
+
+    assert!(!pane_dead, "wrapped agent should still be running");
+    assert!(
+        !running_shell,
+        "the respawn wrapper must not look like a shell to the attach restart check"
+    );
+}
@@ -174,3 +174,95 @@ fn update_check_no_update_available_when_versions_match() {
     let stdout = String::from_utf8(output.stdout).unwrap();
     assert!(stdout.contains("available: false"), "stdout was: {stdout}");
 }
+
+/// Serve `body` as JSON at `/manifest.json`. Same thread-per-fixture shape
+/// as [`spawn_fixture`].
+fn spawn_manifest_fixture(body: String) -> FixtureServer {
+    let (port_tx, port_rx) = mpsc::channel::<u16>();
+    let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
+
+    thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("fixture runtime");
+        rt.block_on(async move {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+                .await
+                .expect("bind fixture port");
+            let port = listener.local_addr().expect("local_addr").port();
+            port_tx.send(port).expect("port channel");
+
+            let app = axum::Router::new().route(
+                "/manifest.json",
+                axum::routing::get(move || {
+                    let body = body.clone();
+                    async move {
+                        (
+                            [(axum::http::header::CONTENT_TYPE, "application/json")],
+                            body,
+                        )
+                    }
+                }),
+            );
+
+            let serve = axum::serve(listener, app);
+            tokio::select! {
+                _ = serve => {}
+                _ = tokio::task::spawn_blocking(move || {
+                    let _ = shutdown_rx.recv();
+                }) => {}
+            }
+        });
+    });
+
+    let port = port_rx.recv().expect("port from fixture");
+    FixtureServer {
+        base_url: format!("http://127.0.0.1:{port}"),
+        _shutdown: shutdown_tx,
+    }
+}
+
+#[test]
+fn update_check_reads_configured_manifest_instead_of_github() {
+    let fixture = spawn_manifest_fixture(
+        serde_json::json!([
+            {"version": "998.1.0"},
+            {"version": "v999.2.0", "body": "mirror notes", "published_at": "2026-04-29T00:00:00Z"},
+        ])
+        .to_string(),
+    );
+    let tmp = tempfile::TempDir::new().unwrap();
+    let app_dir = tmp.path().join(if cfg!(debug_assertions) {
+        "agent-of-empires-dev"
+    } else {
+        "agent-of-empires"
+    });
+    std::fs::create_dir_all(&app_dir).unwrap();
+    std::fs::write(
+        app_dir.join("config.toml"),
+        format!(
+            "[updates]\nmanifest_url = \"{}/manifest.json\"\n",
+            fixture.base_url
+        ),
+    )
+    .unwrap();
+
+    // Point GitHub at a closed port: the check must not need it.
+    let output = Command::new(aoe_binary())
+        .args(["update", "--check"])
+        .env("HOME", tmp.path())
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .env("AOE_UPDATE_API_BASE", "http://127.0.0.1:1")
+        .output()
+        .expect("running aoe update --check");
+
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("latest:  999.2.0"), "stdout was: {stdout}");
+    assert!(stdout.contains("available: true"), "stdout was: {stdout}");
+}
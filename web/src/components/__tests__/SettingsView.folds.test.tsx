@@ -86,7 +86,14 @@ const RAW_SCHEMA: Array<{
     section: "worktree",
     field: "delete_branch_on_cleanup",
     label: "Delete Branch on Cleanup",
-    widget: { kind: "toggle" },
+    widget: {
+      kind: "select",
+      options: [
+        { value: "never", label: "Never" },
+        { value: "if-merged", label: "If merged" },
+        { value: "always", label: "Always" },
+      ],
+    },
     advanced: true,
     web_write: ELEV,
   },
@@ -435,7 +442,9 @@ describe("Settings Advanced fold", () => {
 
     commit(fieldInputByLabel(container, "Bare Repo Template", "text"), "./{branch}");
     commit(fieldInputByLabel(container, "Workspace Path Template", "text"), "../wt-{branch}");
-    clickToggle(container, "Delete Branch on Cleanup");
+    fireEvent.change(selectByLabel(container, "Delete Branch on Cleanup"), {
+      target: { value: "if-merged" },
+    });
     clickToggle(container, "Init Submodules");
 
     await waitFor(() =>
@@ -444,7 +453,7 @@ describe("Settings Advanced fold", () => {
       }),
     );
     expect(vi.mocked(api.updateProfileSettings)).toHaveBeenCalledWith("main", {
-      worktree: { delete_branch_on_cleanup: true },
+      worktree: { delete_branch_on_cleanup: "if-merged" },
     });
   });
 
@@ -41,6 +41,9 @@ function listValidator(validation: SettingsValidation): ((value: string) => stri
         /^[A-Za-z_][A-Za-z0-9_]*(=.*)?$/.test(v) ? null : "Must be KEY or KEY=VALUE (letters, digits, underscores)";
     case "port_mapping_list":
       return (v) => (/^\d+:\d+$/.test(v) ? null : "Must be port:port (e.g. 3000:3000)");
+    case "config_sync_exclude_list":
+      return (v) =>
+        /^[^/].*\/[^/\\]+$/.test(v.trim()) ? null : "Must be <agent config dir>/<name> (e.g. .claude/projects)";
     default:
       return undefined;
   }
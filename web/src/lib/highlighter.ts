@@ -18,6 +18,8 @@ const SHIKI_THEME_IMPORTS: Record<string, () => Promise<unknown>> = {
   dracula: () => import("shiki/themes/dracula.mjs"),
   "rose-pine": () => import("shiki/themes/rose-pine.mjs"),
   "material-theme-ocean": () => import("shiki/themes/material-theme-ocean.mjs"),
+  "solarized-dark": () => import("shiki/themes/solarized-dark.mjs"),
+  "github-dark-high-contrast": () => import("shiki/themes/github-dark-high-contrast.mjs"),
 };
 
 /** Fallback Shiki themes when the resolver names a theme this bundle
@@ -471,9 +471,14 @@ export type SettingsValidation =
   | { rule: "range_u64"; min: number; max?: number }
   | { rule: "non_empty_string" }
   | { rule: "memory_limit" }
+  | { rule: "cpu_limit" }
   | { rule: "volume_list" }
   | { rule: "env_list" }
-  | { rule: "port_mapping_list" };
+  | { rule: "port_mapping_list" }
+  | { rule: "config_sync_exclude_list" }
+  | { rule: "launch_wrapper" }
+  | { rule: "timezone" }
+  | { rule: "datetime_format" };
 
 /** One configurable field. The dotted `${section}.${field}` is its stable id. */
 export interface SettingsFieldDescriptor {